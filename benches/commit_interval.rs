@@ -0,0 +1,137 @@
+use std::{
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use rusqlite::Connection;
+use zcash_warp::{
+    db::{chain::store_block, create_schema, notes::store_received_note},
+    warp::{
+        sync::{ReceivedNote, ReceivedTx},
+        BlockHeader, Witness,
+    },
+};
+
+/*
+    Latency of the sync commits with intermediate commit points
+
+    A sync commits the notes received in its range of blocks and the
+    new witnesses of all the notes of the wallet in one database
+    transaction. With `commit_interval` (DEFAULT_COMMIT_INTERVAL blocks
+    if it is not set), the range is cut into several syncs that each
+    commit their part. An interval of 0 here is a single commit.
+
+    For every interval, this synchronizes BLOCKS blocks with a
+    note every NOTE_SPACING blocks and prints the total time spent
+    in the commits and the longest one, i.e. how long the database
+    is locked at once.
+
+    cargo bench --bench commit_interval
+*/
+
+const BLOCKS: u32 = 100_000;
+const NOTE_SPACING: u32 = 20;
+const INTERVALS: &[u32] = &[0, 50_000, 10_000, 1_000];
+
+fn hash(tag: u8, i: u32) -> [u8; 32] {
+    let mut h = [0u8; 32];
+    h[0] = tag;
+    h[1..5].copy_from_slice(&i.to_le_bytes());
+    h
+}
+
+fn note(i: u32) -> ReceivedNote {
+    let height = i * NOTE_SPACING + 1;
+    ReceivedNote {
+        is_new: true,
+        id: 0,
+        account: 1,
+        position: i,
+        height,
+        address: [0u8; 43],
+        value: 100_000,
+        rcm: hash(1, i),
+        nf: hash(2, i),
+        rho: None,
+        vout: 0,
+        tx: ReceivedTx {
+            id: 0,
+            account: 1,
+            height,
+            txid: hash(3, i),
+            timestamp: height,
+            ivtx: 0,
+            value: 100_000,
+        },
+        spent: None,
+        witness: Witness {
+            position: i,
+            ..Witness::default()
+        },
+        coinbase: false,
+    }
+}
+
+/// Total and longest commit time
+fn sync(path: &Path, interval: u32) -> Result<(Duration, Duration)> {
+    let _ = std::fs::remove_file(path);
+    let mut connection = Connection::open(path)?;
+    connection.query_row("PRAGMA journal_mode = WAL", [], |_| Ok(()))?;
+    create_schema(&mut connection, "")?;
+
+    let interval = if interval == 0 { BLOCKS } else { interval };
+    let mut notes: Vec<ReceivedNote> = vec![];
+    let mut total = Duration::ZERO;
+    let mut longest = Duration::ZERO;
+    let mut start = 0;
+    while start < BLOCKS {
+        let end = (start + interval).min(BLOCKS);
+        // the notes of the previous syncs only get a new witness
+        for n in notes.iter_mut() {
+            n.is_new = false;
+        }
+        notes.extend(
+            (start..end)
+                .filter(|h| h % NOTE_SPACING == 0)
+                .map(|h| note(h / NOTE_SPACING)),
+        );
+
+        let commit_start = Instant::now();
+        let db_tx = connection.transaction()?;
+        store_received_note(&db_tx, end, &notes, true)?;
+        store_block(
+            &db_tx,
+            &BlockHeader {
+                height: end,
+                hash: hash(4, end),
+                prev_hash: hash(4, start),
+                timestamp: end,
+            },
+        )?;
+        db_tx.commit()?;
+        let elapsed = commit_start.elapsed();
+        total += elapsed;
+        longest = longest.max(elapsed);
+        start = end;
+    }
+    drop(connection);
+    let _ = std::fs::remove_file(path);
+    Ok((total, longest))
+}
+
+fn main() -> Result<()> {
+    let path = std::env::temp_dir().join("zcash-warp-commit-interval.db");
+    println!("{} blocks, {} notes", BLOCKS, BLOCKS / NOTE_SPACING);
+    println!("{:>10} {:>12} {:>12}", "interval", "total ms", "longest ms");
+    for &interval in INTERVALS {
+        let (total, longest) = sync(&path, interval)?;
+        println!(
+            "{:>10} {:>12} {:>12}",
+            interval,
+            total.as_millis(),
+            longest.as_millis()
+        );
+    }
+    Ok(())
+}
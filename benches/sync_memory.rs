@@ -0,0 +1,123 @@
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use rusqlite::Connection;
+use zcash_warp::{
+    coin::CoinDef,
+    db::create_schema,
+    lwd::rpc::{CompactBlock, CompactSaplingOutput, CompactTx},
+    network::Network,
+    types::CheckpointHeight,
+    warp::{sync::SaplingSync, Edge},
+};
+
+/*
+    Peak memory of the commitment tree update
+
+    `Synchronizer::add` updates the tree by strips of TREE_STRIP_SIZE
+    commitments, so that its memory does not grow with the number of
+    outputs in the batch of blocks.
+
+    For every batch size, this adds synthetic blocks of OUTPUTS_PER_BLOCK
+    Sapling outputs to a wallet without accounts and prints the peak RSS
+    of the process during `add`, above its RSS before the call, i.e.
+    without the blocks themselves.
+
+    Linux only, it reads the RSS from /proc/self.
+
+    cargo bench --bench sync_memory
+*/
+
+const START: u32 = 1_000_000;
+const OUTPUTS_PER_BLOCK: usize = 1_000;
+const BATCHES: &[usize] = &[100_000, 250_000, 500_000, 1_000_000];
+
+/// A commitment that is a canonical field element
+fn cmu(i: u32) -> Vec<u8> {
+    let mut h = vec![0u8; 32];
+    h[0..4].copy_from_slice(&i.to_le_bytes());
+    h
+}
+
+fn blocks(outputs: usize) -> Vec<CompactBlock> {
+    (0..outputs / OUTPUTS_PER_BLOCK)
+        .map(|b| {
+            let height = START + b as u32 + 1;
+            let outputs = (0..OUTPUTS_PER_BLOCK)
+                .map(|o| CompactSaplingOutput {
+                    cmu: cmu((b * OUTPUTS_PER_BLOCK + o) as u32),
+                    epk: vec![0u8; 32],
+                    ciphertext: vec![0u8; 52],
+                })
+                .collect();
+            CompactBlock {
+                height: height as u64,
+                hash: cmu(height),
+                time: height,
+                vtx: vec![CompactTx {
+                    index: 1,
+                    hash: cmu(height),
+                    outputs,
+                    ..CompactTx::default()
+                }],
+                ..CompactBlock::default()
+            }
+        })
+        .collect()
+}
+
+fn proc_status_kb(key: &str) -> Result<u64> {
+    let status = std::fs::read_to_string("/proc/self/status")?;
+    let value = status
+        .lines()
+        .find_map(|l| l.strip_prefix(key))
+        .ok_or(anyhow::anyhow!("No {} in /proc/self/status", key))?;
+    Ok(value.trim().trim_end_matches("kB").trim().parse()?)
+}
+
+/// Bring the peak RSS of the process back to its current RSS
+fn reset_peak_rss() -> Result<()> {
+    std::fs::write("/proc/self/clear_refs", "5")?;
+    Ok(())
+}
+
+/// Peak RSS increase in kB and duration of the update
+fn update_tree(coin: &CoinDef, outputs: usize) -> Result<(u64, Duration)> {
+    let connection = coin.connection()?;
+    let blocks = blocks(outputs);
+    let mut sync = SaplingSync::new(
+        coin,
+        &coin.network,
+        &connection,
+        CheckpointHeight(START),
+        0,
+        Edge::default(),
+    )?;
+    let rss = proc_status_kb("VmRSS:")?;
+    reset_peak_rss()?;
+    let start = Instant::now();
+    sync.add(&blocks)?;
+    let elapsed = start.elapsed();
+    let peak = proc_status_kb("VmHWM:")?;
+    Ok((peak.saturating_sub(rss), elapsed))
+}
+
+fn main() -> Result<()> {
+    let path = std::env::temp_dir().join("zcash-warp-sync-memory.db");
+    let _ = std::fs::remove_file(&path);
+    let mut connection = Connection::open(&path)?;
+    create_schema(&mut connection, "")?;
+    drop(connection);
+    let mut coin = CoinDef::from_network(0, Network::Main);
+    coin.set_path_password(&path.to_string_lossy(), "")?;
+
+    println!("{} outputs per block", OUTPUTS_PER_BLOCK);
+    println!("{:>10} {:>14} {:>10}", "outputs", "peak RSS kB", "ms");
+    for &outputs in BATCHES {
+        let (peak, elapsed) = update_tree(&coin, outputs)?;
+        println!("{:>10} {:>14} {:>10}", outputs, peak, elapsed.as_millis());
+    }
+    drop(coin);
+    let _ = std::fs::remove_file(&path);
+    Ok(())
+}
@@ -61,26 +61,131 @@ typedef struct CResult_u64 {
   uint32_t len;
 } CResult_u64;
 
+struct CResult_u8 c_set_ack_enabled(uint8_t coin, uint32_t account, bool enabled);
+
+struct CResult_bool c_is_ack_enabled(uint8_t coin, uint32_t account);
+
+struct CResult______u8 c_list_pending_acks(uint8_t coin, uint32_t account);
+
+struct CResult_u8 c_approve_ack(uint8_t coin, uint32_t id, bool approved);
+
+struct CResult_____c_char c_send_acks(uint8_t coin, uint32_t account);
+
+struct CResult______u8 c_get_address_usage(uint8_t coin, uint32_t account);
+
+struct CResult_bool c_is_change_address(uint8_t coin, char *address);
+
+struct CResult______u8 c_create_balance_proof(uint8_t coin, uint32_t account, uint32_t height);
+
+struct CResult_u64 c_verify_balance_proof(uint8_t coin, struct CParam proof);
+
 struct CResult_u8 c_add_contact(uint8_t coin,
                                 uint32_t account,
                                 char *name,
                                 char *address,
                                 bool saved);
 
+struct CResult______u8 c_export_transparent_descriptors(uint8_t coin,
+                                                        uint32_t account,
+                                                        char *passphrase,
+                                                        bool private_keys);
+
+struct CResult______u8 c_discover_accounts(uint8_t coin,
+                                           char *name,
+                                           char *seed,
+                                           char *passphrase,
+                                           uint32_t birth,
+                                           uint32_t max_gap);
+
+struct CResult______u8 c_check_lookalike_address(uint8_t coin, uint32_t account, char *address);
+
+struct CResult_u8 c_trust_address(uint8_t coin, uint32_t account, char *address);
+
+struct CResult_u8 c_set_account_lwd_url(uint8_t coin, uint32_t account, char *url);
+
+struct CResult_____c_char c_get_account_lwd_url(uint8_t coin, uint32_t account);
+
+struct CResult_u8 c_clear_account_lwd_url(uint8_t coin, uint32_t account);
+
+struct CResult_u32 c_compose_message(uint8_t coin,
+                                     uint32_t account,
+                                     uint32_t contact,
+                                     char *subject,
+                                     char *body);
+
+struct CResult_u32 c_compose_reply(uint8_t coin, uint32_t id_msg, char *body);
+
+struct CResult_u8 c_edit_draft(uint8_t coin, uint32_t id, char *subject, char *body);
+
+struct CResult_u8 c_queue_message(uint8_t coin, uint32_t id, bool queued);
+
+struct CResult_u8 c_delete_outgoing_message(uint8_t coin, uint32_t id);
+
+struct CResult______u8 c_list_outbox(uint8_t coin, uint32_t account);
+
+struct CResult_____c_char c_send_queued_messages(uint8_t coin, uint32_t account);
+
+struct CResult______u8 c_get_seed_verification_challenge(uint8_t coin, uint32_t account);
+
+struct CResult_bool c_verify_seed_answer(uint8_t coin, uint32_t account, char *answers);
+
 struct CResult______u8 c_get_txs(uint8_t coin, uint32_t account, uint32_t bc_height);
 
+struct CResult______u8 c_get_txs_with_address(uint8_t coin,
+                                              uint32_t account,
+                                              char *address,
+                                              uint32_t bc_height);
+
+struct CResult______u8 c_get_txs_with_contact(uint8_t coin,
+                                              uint32_t account,
+                                              uint32_t contact,
+                                              uint32_t bc_height);
+
+struct CResult______u8 c_get_group_txs(uint8_t coin, uint32_t group, uint32_t bc_height);
+
+struct CResult_u32 c_add_watch_only_address(uint8_t coin, uint32_t account, char *address);
+
+struct CResult_u8 c_remove_watch_only_address(uint8_t coin, uint32_t account, char *address);
+
+struct CResult_u8 c_set_webhook(uint8_t coin, uint32_t account, struct CParam webhook);
+
+struct CResult______u8 c_get_webhook(uint8_t coin, uint32_t account);
+
+struct CResult_u8 c_clear_webhook(uint8_t coin, uint32_t account);
+
 struct CResult_u8 c_create_schema(uint8_t coin, char *_version);
 
 struct CResult______u8 c_list_accounts(uint8_t coin);
 
+struct CResult______u8 c_list_accounts_in_group(uint8_t coin, uint32_t group);
+
 struct CResult______u8 c_list_account_transparent_addresses(uint8_t coin, uint32_t account);
 
 struct CResult_u8 c_change_account_dindex(uint8_t coin, uint32_t account, uint32_t dindex);
 
-struct CResult______u8 c_get_balance(uint8_t coin, uint32_t account, uint32_t height);
+struct CResult_u8 c_set_min_confirmations(uint8_t coin, uint32_t account, uint32_t confirmations);
+
+struct CResult_u32 c_get_min_confirmations(uint8_t coin, uint32_t account);
+
+struct CResult_u8 c_set_snap_policy(uint8_t coin, uint32_t account, uint8_t policy);
+
+struct CResult_u8 c_get_snap_policy(uint8_t coin, uint32_t account);
+
+struct CResult_u8 c_set_change_outputs(uint8_t coin, uint32_t account, uint8_t count);
+
+struct CResult_u8 c_get_change_outputs(uint8_t coin, uint32_t account);
+
+struct CResult______u8 c_get_balance(uint8_t coin,
+                                     uint32_t account,
+                                     uint32_t height,
+                                     uint32_t min_confirmations);
+
+struct CResult______u8 c_get_wallet_summary(uint8_t coin);
 
 struct CResult______u8 c_get_account_signing_capabilities(uint8_t coin, uint32_t account);
 
+struct CResult_bool c_is_transparent_only(uint8_t coin);
+
 struct CResult______u8 c_get_account_property(uint8_t coin, uint32_t account, char *name);
 
 struct CResult_u8 c_set_account_property(uint8_t coin,
@@ -97,17 +202,30 @@ struct CResult_bool c_is_valid_key(uint8_t coin, char *key);
 struct CResult_u32 c_create_new_account(uint8_t coin,
                                         char *name,
                                         char *key,
+                                        char *passphrase,
                                         uint32_t acc_index,
                                         uint32_t birth,
                                         uint8_t pools,
                                         bool is_new);
 
+struct CResult______u8 c_import_viewing_keys(uint8_t coin, struct CParam keys);
+
+struct CResult_bool c_has_account_passphrase(uint8_t coin, uint32_t account);
+
+struct CResult_bool c_check_account_passphrase(uint8_t coin, uint32_t account, char *passphrase);
+
 struct CResult_u8 c_hide_account(uint8_t coin, uint32_t account, bool hidden);
 
 struct CResult_u8 c_reorder_account(uint8_t coin, uint32_t account, uint32_t new_position);
 
 struct CResult_u8 c_edit_account_icon(uint8_t coin, uint32_t account, struct CParam image);
 
+struct CResult_u8 c_edit_account_color(uint8_t coin, uint32_t account, uint32_t color);
+
+struct CResult_u8 c_edit_account_emoji(uint8_t coin, uint32_t account, char *emoji);
+
+struct CResult_u8 c_edit_account_notes(uint8_t coin, uint32_t account, char *notes);
+
 struct CResult_u32 c_new_transparent_address(uint8_t coin, uint32_t account);
 
 struct CResult_u8 c_edit_account_name(uint8_t coin, uint32_t account, char *name);
@@ -116,14 +234,62 @@ struct CResult_u8 c_edit_account_birth(uint8_t coin, uint32_t account, uint32_t
 
 struct CResult_u8 c_delete_account(uint8_t coin, uint32_t account);
 
+struct CResult_u8 c_secure_delete_account(uint8_t coin, uint32_t account);
+
+struct CResult_u8 c_soft_delete_account(uint8_t coin, uint32_t account);
+
+struct CResult_u8 c_undelete_account(uint8_t coin, uint32_t account);
+
 struct CResult_u8 c_set_backup_reminder(uint8_t coin, uint32_t account, bool saved);
 
 struct CResult_u8 c_downgrade_account(uint8_t coin, uint32_t account, struct CParam capabilities);
 
+struct CResult_u32 c_create_account_group(uint8_t coin, char *name);
+
+struct CResult______u8 c_list_account_groups(uint8_t coin);
+
+struct CResult______u8 c_get_account_group(uint8_t coin, uint32_t id);
+
+struct CResult_u8 c_edit_account_group_name(uint8_t coin, uint32_t id, char *name);
+
+struct CResult_u8 c_delete_account_group(uint8_t coin, uint32_t id);
+
+struct CResult_u8 c_set_account_group(uint8_t coin, uint32_t account, uint32_t id);
+
+struct CResult______u8 c_get_group_balance(uint8_t coin, uint32_t id, uint32_t height);
+
+struct CResult_____c_char c_create_api_key(uint8_t coin,
+                                           char *name,
+                                           bool read_only,
+                                           uint64_t max_amount,
+                                           struct CParam accounts);
+
+struct CResult______u8 c_list_api_keys(uint8_t coin);
+
+struct CResult_u8 c_revoke_api_key(uint8_t coin, uint32_t id);
+
+struct CResult_u32 c_open_api_session(uint8_t coin, char *key);
+
+struct CResult_u8 c_close_api_session(uint32_t session);
+
+struct CResult_u8 c_authorize(uint32_t session, uint8_t operation, uint32_t account, uint64_t amount);
+
+struct CResult______u8 c_list_audit_log(uint8_t coin, uint32_t timestamp);
+
+struct CResult_u32 c_verify_audit_log(uint8_t coin);
+
 struct CResult______u8 c_get_sync_height(uint8_t coin);
 
 struct CResult_u8 c_rewind(uint8_t coin, uint32_t height);
 
+struct CResult_u32 c_rewind_offline(uint8_t coin, uint32_t height);
+
+struct CResult______u8 c_get_rewind_plan(uint8_t coin, uint32_t height);
+
+struct CResult______u8 c_rewind_with_plan(uint8_t coin, uint32_t height, bool dry_run);
+
+struct CResult______u8 c_get_block_info(uint8_t coin, uint32_t height);
+
 struct CResult______u8 c_list_checkpoints(uint8_t coin);
 
 struct CResult_u8 c_purge_checkpoints(uint8_t coin, uint32_t min_height);
@@ -138,10 +304,44 @@ struct CResult_u8 c_edit_contact_name(uint8_t coin, uint32_t id, char *name);
 
 struct CResult_u8 c_edit_contact_address(uint8_t coin, uint32_t id, char *address);
 
+struct CResult_u8 c_edit_contact_ack(uint8_t coin, uint32_t id, uint8_t ack);
+
 struct CResult_u8 c_delete_contact(uint8_t coin, uint32_t id);
 
 struct CResult_u8 c_on_contacts_saved(uint8_t coin, uint32_t account);
 
+struct CResult_u32 c_create_contact_group(uint8_t coin, uint32_t account, char *name);
+
+struct CResult______u8 c_list_contact_groups(uint8_t coin, uint32_t account);
+
+struct CResult______u8 c_get_contact_group(uint8_t coin, uint32_t id);
+
+struct CResult_u8 c_edit_contact_group_name(uint8_t coin, uint32_t id, char *name);
+
+struct CResult_u8 c_delete_contact_group(uint8_t coin, uint32_t id);
+
+struct CResult_u8 c_add_contact_to_group(uint8_t coin, uint32_t id, uint32_t contact);
+
+struct CResult_u8 c_remove_contact_from_group(uint8_t coin, uint32_t id, uint32_t contact);
+
+struct CResult_u32 c_store_draft(uint8_t coin, uint32_t account, char *name, struct CParam payment);
+
+struct CResult______u8 c_list_drafts(uint8_t coin, uint32_t account);
+
+struct CResult______u8 c_get_draft(uint8_t coin, uint32_t id);
+
+struct CResult_u8 c_edit_draft(uint8_t coin, uint32_t id, char *name, struct CParam payment);
+
+struct CResult_u8 c_delete_draft(uint8_t coin, uint32_t id);
+
+struct CResult______u8 c_get_events_since(uint8_t coin, uint32_t seq);
+
+struct CResult_u8 c_configure_logs(uint8_t level, bool sync_summaries, uint32_t capacity);
+
+struct CResult______u8 c_get_recent_logs(uint8_t coin, uint32_t n);
+
+struct CResult_u8 c_clear_logs(uint8_t coin);
+
 struct CResult______u8 c_list_unconfirmed_txs(uint8_t coin, uint32_t account);
 
 struct CResult_i64 c_get_unconfirmed_balance(uint8_t coin, uint32_t account);
@@ -154,12 +354,28 @@ struct CResult_u8 c_mark_read(uint8_t coin, uint32_t id, bool reverse);
 
 struct CResult______u8 c_get_unspent_notes(uint8_t coin, uint32_t account, uint32_t bc_height);
 
+struct CResult______u8 c_list_script_utxos(uint8_t coin, uint32_t account);
+
 struct CResult______u8 c_get_unspent_utxos(uint8_t coin, uint32_t account, uint32_t bc_height);
 
 struct CResult_u8 c_exclude_note(uint8_t coin, uint32_t id, bool reverse);
 
 struct CResult_u8 c_reverse_note_exclusion(uint8_t coin, uint32_t account);
 
+struct CResult_u32 c_save_note_profile(uint8_t coin, uint32_t account, char *name);
+
+struct CResult______u8 c_list_note_profiles(uint8_t coin, uint32_t account);
+
+struct CResult_u8 c_apply_note_profile(uint8_t coin, uint32_t account, uint32_t id);
+
+struct CResult_u8 c_delete_note_profile(uint8_t coin, uint32_t account, uint32_t id);
+
+struct CResult_u32 c_import_prices(uint8_t coin, char *path, char *currency);
+
+struct CResult______u8 c_list_servers(uint8_t coin);
+
+struct CResult_____c_char c_pick_best_server(uint8_t coin);
+
 struct CResult_u8 c_store_swap(uint8_t coin, uint32_t account, struct CParam swap);
 
 struct CResult______u8 c_list_swaps(uint8_t coin, uint32_t account);
@@ -168,39 +384,103 @@ struct CResult_u8 c_clear_swap_history(uint8_t coin, uint32_t account);
 
 struct CResult______u8 c_get_tx_details(uint8_t coin, uint32_t account, struct CParam txid);
 
-struct CResult_____c_char c_generate_random_mnemonic_phrase_os_rng(void);
+struct CResult______u8 c_list_expired_txs(uint8_t coin, uint32_t account);
+
+struct CResult_u8 c_dismiss_expired_tx(uint8_t coin, uint32_t id);
+
+struct CResult_u32 c_submit_job(uint8_t coin, struct CParam command);
+
+struct CResult______u8 c_poll_job(uint32_t id);
+
+struct CResult_u8 c_cancel_job(uint32_t id);
+
+struct CResult_____c_char c_generate_random_mnemonic_phrase_os_rng(char *language);
+
+struct CResult_____c_char c_get_mnemonic_language(char *phrase);
 
 struct CResult_u32 c_get_last_height(uint8_t coin);
 
 struct CResult_u64 c_ping(uint8_t coin, char *lwd_url);
 
+struct CResult______u8 c_test_servers(uint8_t coin);
+
 struct CResult_u8 c_init_sapling_prover(struct CParam spend, struct CParam output);
 
+struct CResult_u8 c_warm_up_provers(void);
+
+struct CResult______u8 c_get_proof_progress(void);
+
+struct CResult______u8 c_start_external_signing(uint8_t coin,
+                                                struct CParam summary,
+                                                uint32_t expiration_height);
+
+struct CResult______u8 c_complete_external_signing(uint32_t session, struct CParam signatures);
+
+struct CResult_u8 c_cancel_external_signing(uint32_t session);
+
+struct CResult_____c_char c_render_summary(struct CParam summary);
+
 struct CResult_u8 c_scan_transparent_addresses(uint8_t coin,
                                                uint32_t account,
                                                uint32_t external,
                                                uint32_t gap_limit);
 
+struct CResult______u8 c_sweep_wallet(uint8_t coin, char *dest_address, uint32_t confirmations);
+
+struct CResult______u8 c_sign_sweep(uint8_t coin, struct CParam bundle, uint32_t expiration_height);
+
 struct CResult_u8 c_retrieve_tx_details(uint8_t coin);
 
+struct CResult_u32 c_backfill_tx_details(uint8_t coin, uint32_t batch_size, uint32_t interval_ms);
+
 void c_setup(void);
 
 struct CResult_u8 c_configure(uint8_t coin, struct CParam config);
 
+struct CResult______u8 c_attach_file(struct CParam payment,
+                                     char *address,
+                                     char *name,
+                                     struct CParam data);
+
+struct CResult______u8 c_save_attachments(uint8_t coin,
+                                          uint32_t account,
+                                          struct CParam txid,
+                                          char *directory);
+
+struct CResult_u8 c_backup_wallet(uint8_t coin, char *path, char *password, bool incremental);
+
+struct CResult______u8 c_restore_wallet(uint8_t coin, char *path, char *password);
+
 struct CResult_u32 c_get_activation_date(uint8_t coin);
 
 struct CResult_u32 c_get_height_by_time(uint8_t coin, uint32_t time);
 
+struct CResult______u8 c_get_upgrade_status(uint8_t coin);
+
+struct CResult______u8 c_get_chain_status(uint8_t coin);
+
 struct CResult_u32 c_get_activation_height(uint8_t coin);
 
 struct CResult_u32 c_get_time_by_height(uint8_t coin, uint32_t height);
 
 struct CResult_u8 c_reset_chain(uint8_t coin, uint32_t height);
 
+struct CResult_____c_char c_export_checkpoint(uint8_t coin, uint32_t height);
+
+struct CResult_u32 c_import_checkpoint(uint8_t coin, char *tree_state, bool reset);
+
+struct CResult_u32 c_verify_chain(uint8_t coin);
+
 struct CResult______u8 c_split(struct CParam data, uint32_t threshold);
 
 struct CResult______u8 c_merge(struct CParam parts);
 
+struct CResult______u8 c_split_seed_phrase(char *phrase, uint32_t threshold, uint32_t count);
+
+struct CResult_____c_char c_merge_seed_phrase(struct CParam parts);
+
+struct CResult______u8 c_run_health_check(uint8_t coin);
+
 struct CResult_u8 c_check_db_password(char *path, char *password);
 
 struct CResult_u8 c_encrypt_db(uint8_t coin, char *password, char *new_db_path);
@@ -214,12 +494,17 @@ struct CResult_____c_char c_get_address(uint8_t coin,
 
 struct CResult_u8 c_set_db_path_password(uint8_t coin, char *path, char *password);
 
+struct CResult_u8 c_open_read_only(uint8_t coin, char *path, char *password);
+
 uint32_t c_schema_version(void);
 
 struct CResult_u8 c_create_db(char *path, char *password, char *version);
 
+struct CResult_u32 c_import_ecc_wallet(uint8_t coin, char *path, char *seed, char *passphrase);
+
 struct CResult______u8 c_derive_zip32_keys(uint8_t coin,
                                            uint32_t account,
+                                           char *passphrase,
                                            uint32_t acc_index,
                                            uint32_t addr_index,
                                            bool use_default);
@@ -241,7 +526,45 @@ struct CResult______u8 c_next_message_thread(uint8_t coin,
 struct CResult______u8 c_prepare_payment(uint8_t coin,
                                          uint32_t account,
                                          struct CParam payment,
-                                         char *redirect);
+                                         char *redirect,
+                                         uint8_t snap_policy);
+
+struct CResult______u8 c_prepare_payment_uri(uint8_t coin,
+                                             uint32_t account,
+                                             char *uri,
+                                             char *redirect);
+
+struct CResult______u8 c_prepare_sponsored_payment(uint8_t coin,
+                                                   uint32_t account,
+                                                   uint32_t sponsor,
+                                                   struct CParam payment,
+                                                   char *redirect);
+
+struct CResult______u8 c_prepare_draft(uint8_t coin, uint32_t id, char *redirect);
+
+struct CResult______u8 c_pay_group(uint8_t coin,
+                                   uint32_t account,
+                                   uint32_t group,
+                                   uint64_t amount,
+                                   bool split,
+                                   char *redirect);
+
+struct CResult______u8 c_transfer(uint8_t coin,
+                                  uint32_t src_account,
+                                  uint32_t dst_account,
+                                  uint64_t amount,
+                                  uint8_t pools);
+
+struct CResult______u8 c_prepare_tex_payment(uint8_t coin,
+                                             uint32_t account,
+                                             struct CParam payment,
+                                             char *redirect);
+
+struct CResult______u8 c_prepare_tex_disclosure(uint8_t coin,
+                                                uint32_t account,
+                                                struct CParam payment,
+                                                struct CParam txbytes,
+                                                char *redirect);
 
 struct CResult_bool c_can_sign(uint8_t coin, uint32_t account, struct CParam summary);
 
@@ -249,6 +572,13 @@ struct CResult______u8 c_sign(uint8_t coin, struct CParam summary, uint32_t expi
 
 struct CResult_____c_char c_tx_broadcast(uint8_t coin, struct CParam txbytes);
 
+struct CResult______u8 c_review_transaction(uint8_t coin, struct CParam summary, struct CParam txbytes);
+
+struct CResult_____c_char c_tx_broadcast_reviewed(uint8_t coin,
+                                                  struct CParam summary,
+                                                  struct CParam txbytes,
+                                                  bool force);
+
 struct CResult______u8 c_save_contacts(uint8_t coin,
                                        uint32_t account,
                                        uint32_t height,
@@ -260,8 +590,15 @@ struct CResult______u8 c_decode_address(uint8_t coin, char *address);
 
 struct CResult_____c_char c_filter_address(uint8_t coin, char *address, uint8_t pool_mask);
 
+struct CResult______u8 c_diagnose_address(uint8_t coin, char *address);
+
 struct CResult_____c_char c_make_payment_uri(uint8_t coin, struct CParam payment);
 
+struct CResult______u8 c_make_payment_qr(uint8_t coin,
+                                         struct CParam payment,
+                                         uint8_t format,
+                                         uint8_t ecc);
+
 struct CResult______u8 c_parse_payment_uri(uint8_t coin,
                                            char *uri,
                                            uint32_t height,
@@ -269,6 +606,12 @@ struct CResult______u8 c_parse_payment_uri(uint8_t coin,
 
 struct CResult_u8 c_is_valid_address_or_uri(uint8_t coin, char *s);
 
+struct CResult_____c_char c_sign_payment_uri(uint8_t coin, uint32_t account, char *uri);
+
+struct CResult______u8 c_verify_payment_uri(uint8_t coin, uint32_t account, char *uri);
+
+struct CResult______u8 c_get_note_witness(uint8_t coin, uint32_t id_note, uint32_t height);
+
 struct CResult_u8 c_encrypt_zip_database_files(struct CParam zip_db_config);
 
 struct CResult_u8 c_decrypt_zip_database_files(char *file_path,
@@ -1,5 +1,17 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde_json::{json, Value};
+use syn::{
+    Attribute, Expr, FnArg, GenericArgument, Item, Lit, Meta, PathArguments, ReturnType, Type,
+};
+
 fn main() {
     tonic_build();
+    create_ffi_descriptors();
     // create_c_bindings();
 }
 
@@ -26,3 +38,553 @@ fn create_c_bindings() {
         .expect("Unable to generate bindings")
         .write_to_file("./binding.h");
 }
+
+/*
+    FFI descriptors
+
+    The build reads the sources (from src/lib.rs, following the `mod`
+    declarations) and generates in OUT_DIR:
+    - zcash_warp.h, the C header of the `#[c_export]` functions and of
+    the `#[no_mangle]` functions,
+    - zcash_warp.json, the manifest of the same functions for the
+    Dart, Kotlin and Swift bindings: their C and Rust signatures,
+    the flatbuffer tables of their parameters and results, and their
+    documentation.
+
+    This works on stable rustc, unlike cbindgen with macro expansion,
+    because it only needs the signatures that `c_export` maps:
+    - the coin, network, connection and client parameters become the
+    coin index, the first parameter of the C function,
+    - strings are `char *`, byte arrays and flatbuffer tables (`XxxT`)
+    are a `CParam`,
+    - the result is a `CResult` of the value, a C string, or bytes for
+    the byte arrays and the flatbuffer tables.
+
+    Another type fails the build. The copies at the root of the crate
+    are the published ones, and the `ffi` test checks that they are up
+    to date (`UPDATE_FFI=1 cargo test --test ffi` updates them).
+
+    The functions of a module under a feature are in a
+    `#if defined(ZCASH_WARP_FEATURE_<FEATURE>)` block of the header.
+*/
+
+const FFI_LINE_LENGTH: usize = 100;
+
+struct FfiParam {
+    name: String,
+    rust: String,
+    c: String,
+    kind: &'static str,
+    table: Option<String>,
+}
+
+struct FfiResult {
+    rust: String,
+    c: String,
+    kind: &'static str,
+    table: Option<String>,
+    list: bool,
+}
+
+struct FfiFunction {
+    name: String,
+    rust: String,
+    feature: Option<String>,
+    is_async: bool,
+    coin: bool,
+    doc: String,
+    params: Vec<FfiParam>,
+    result: FfiResult,
+}
+
+fn create_ffi_descriptors() {
+    let crate_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR").unwrap());
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    let mut functions = vec![];
+    scan_module_file(
+        &crate_dir.join("src/lib.rs"),
+        "",
+        None,
+        true,
+        &mut functions,
+    );
+    fs::write(out_dir.join("zcash_warp.h"), ffi_header(&functions)).unwrap();
+    fs::write(out_dir.join("zcash_warp.json"), ffi_manifest(&functions)).unwrap();
+}
+
+fn scan_module_file(
+    path: &Path,
+    module: &str,
+    feature: Option<String>,
+    is_mod_rs: bool,
+    functions: &mut Vec<FfiFunction>,
+) {
+    println!("cargo:rerun-if-changed={}", path.display());
+    let source = fs::read_to_string(path).unwrap();
+    let file = syn::parse_file(&source)
+        .unwrap_or_else(|e| panic!("Cannot parse {}: {}", path.display(), e));
+    let dir = if is_mod_rs {
+        path.parent().unwrap().to_path_buf()
+    } else {
+        path.with_extension("")
+    };
+    scan_items(&file.items, &dir, module, feature, functions);
+}
+
+fn scan_items(
+    items: &[Item],
+    dir: &Path,
+    module: &str,
+    feature: Option<String>,
+    functions: &mut Vec<FfiFunction>,
+) {
+    for item in items {
+        match item {
+            Item::Mod(m) => {
+                if is_cfg_test(&m.attrs) || has_attr(&m.attrs, "path") {
+                    // tests and generated code
+                    continue;
+                }
+                let name = m.ident.to_string();
+                let sub_module = if module.is_empty() {
+                    name.clone()
+                } else {
+                    format!("{module}::{name}")
+                };
+                let feature = cfg_feature(&m.attrs).or(feature.clone());
+                match &m.content {
+                    Some((_, items)) => {
+                        scan_items(items, &dir.join(&name), &sub_module, feature, functions)
+                    }
+                    None => {
+                        let file = dir.join(format!("{name}.rs"));
+                        if file.exists() {
+                            scan_module_file(&file, &sub_module, feature, false, functions);
+                        } else {
+                            let file = dir.join(&name).join("mod.rs");
+                            scan_module_file(&file, &sub_module, feature, true, functions);
+                        }
+                    }
+                }
+            }
+            Item::Fn(f) => {
+                let c_export = has_attr(&f.attrs, "c_export");
+                let no_mangle = has_attr(&f.attrs, "no_mangle") && f.sig.abi.is_some();
+                if !c_export && !no_mangle {
+                    continue;
+                }
+                let feature = cfg_feature(&f.attrs).or(feature.clone());
+                functions.push(ffi_function(f, module, feature, c_export));
+            }
+            _ => {}
+        }
+    }
+}
+
+fn ffi_function(
+    f: &syn::ItemFn,
+    module: &str,
+    feature: Option<String>,
+    c_export: bool,
+) -> FfiFunction {
+    let rust_name = f.sig.ident.to_string();
+    let name = if c_export {
+        format!("c_{rust_name}")
+    } else {
+        rust_name.clone()
+    };
+    let mut coin = false;
+    let mut params = vec![];
+    for arg in f.sig.inputs.iter() {
+        let FnArg::Typed(arg) = arg else {
+            panic!("{rust_name}: methods cannot be exported");
+        };
+        let syn::Pat::Ident(pat) = &*arg.pat else {
+            panic!("{rust_name}: unsupported parameter pattern");
+        };
+        let param_name = pat.ident.to_string();
+        let rust = rust_type(&arg.ty);
+        let param = if c_export {
+            match rust.as_str() {
+                "&CoinDef" | "&Network" | "&Connection" | "&mut Connection" | "&mut Client" => {
+                    coin = true;
+                    continue;
+                }
+                _ => export_param(&rust_name, param_name, rust),
+            }
+        } else {
+            raw_param(&rust_name, param_name, rust)
+        };
+        params.push(param);
+    }
+    if coin {
+        params.insert(
+            0,
+            FfiParam {
+                name: "coin".to_string(),
+                rust: "u8".to_string(),
+                c: "uint8_t".to_string(),
+                kind: "coin",
+                table: None,
+            },
+        );
+    }
+    let result = match &f.sig.output {
+        ReturnType::Default => "()".to_string(),
+        ReturnType::Type(_, ty) => rust_type(ty),
+    };
+    let result = if c_export {
+        export_result(&rust_name, result)
+    } else {
+        raw_result(&rust_name, result)
+    };
+
+    FfiFunction {
+        name,
+        rust: format!("{module}::{rust_name}"),
+        feature,
+        is_async: f.sig.asyncness.is_some(),
+        coin: params.iter().any(|p| p.kind == "coin"),
+        doc: doc_comment(&f.attrs),
+        params,
+        result,
+    }
+}
+
+/// Parameter of a `c_export` function
+fn export_param(function: &str, name: String, rust: String) -> FfiParam {
+    let (c, kind, table) = match rust.as_str() {
+        "&str" => ("char *", "string", None),
+        "&[u8]" => ("struct CParam", "bytes", None),
+        t => match c_value_type(t) {
+            Some(c) => (c, "value", None),
+            None => match flatbuffer_table(t.strip_prefix('&').unwrap_or_default()) {
+                Some(table) => ("struct CParam", "flatbuffer", Some(table)),
+                None => panic!("{function}: unsupported parameter type {t}"),
+            },
+        },
+    };
+    FfiParam {
+        name,
+        rust,
+        c: c.to_string(),
+        kind,
+        table,
+    }
+}
+
+/// Result of a `c_export` function
+fn export_result(function: &str, rust: String) -> FfiResult {
+    let value = rust
+        .strip_prefix("Result<")
+        .and_then(|r| r.strip_suffix('>'))
+        .unwrap_or_else(|| panic!("{function}: the result must be a Result"));
+    let (c, kind, table, list) = match value {
+        "()" => ("CResult_u8".to_string(), "unit", None, false),
+        "String" => ("CResult_____c_char".to_string(), "string", None, false),
+        "Vec<u8>" => ("CResult______u8".to_string(), "bytes", None, false),
+        t if c_value_type(t).is_some() => (format!("CResult_{t}"), "value", None, false),
+        t => {
+            let (item, list) = match t.strip_prefix("Vec<").and_then(|t| t.strip_suffix('>')) {
+                Some(item) => (item, true),
+                None => (t, false),
+            };
+            match flatbuffer_table(item) {
+                Some(table) => (
+                    "CResult______u8".to_string(),
+                    "flatbuffer",
+                    Some(table),
+                    list,
+                ),
+                None => panic!("{function}: unsupported result type {t}"),
+            }
+        }
+    };
+    FfiResult {
+        rust,
+        c: format!("struct {c}"),
+        kind,
+        table,
+        list,
+    }
+}
+
+/// Parameter of a `#[no_mangle]` function
+fn raw_param(function: &str, name: String, rust: String) -> FfiParam {
+    let (c, kind) = match rust.as_str() {
+        "u8" if name == "coin" => ("uint8_t".to_string(), "coin"),
+        "*mut c_char" => ("char *".to_string(), "string"),
+        t => match c_value_type(t) {
+            Some(c) => (c.to_string(), "value"),
+            None => panic!("{function}: unsupported parameter type {t}"),
+        },
+    };
+    FfiParam {
+        name,
+        rust,
+        c,
+        kind,
+        table: None,
+    }
+}
+
+/// Result of a `#[no_mangle]` function
+fn raw_result(function: &str, rust: String) -> FfiResult {
+    let (c, kind) = match rust.as_str() {
+        "()" => ("void".to_string(), "unit"),
+        t => match t.strip_prefix("CResult<").and_then(|t| t.strip_suffix('>')) {
+            Some(v) if c_value_type(v).is_some() => (format!("struct CResult_{v}"), "value"),
+            _ => match c_value_type(t) {
+                Some(c) => (c.to_string(), "value"),
+                None => panic!("{function}: unsupported result type {t}"),
+            },
+        },
+    };
+    FfiResult {
+        rust,
+        c,
+        kind,
+        table: None,
+        list: false,
+    }
+}
+
+fn c_value_type(rust: &str) -> Option<&'static str> {
+    let c = match rust {
+        "u8" => "uint8_t",
+        "u32" => "uint32_t",
+        "u64" => "uint64_t",
+        "i64" => "int64_t",
+        "bool" => "bool",
+        _ => return None,
+    };
+    Some(c)
+}
+
+/// Name of the table of a flatbuffer object type
+fn flatbuffer_table(rust: &str) -> Option<String> {
+    let table = rust.strip_suffix('T')?;
+    let valid = table.starts_with(|c: char| c.is_ascii_uppercase())
+        && table.chars().all(|c| c.is_ascii_alphanumeric());
+    valid.then(|| table.to_string())
+}
+
+fn rust_type(ty: &Type) -> String {
+    match ty {
+        Type::Reference(r) => {
+            let m = if r.mutability.is_some() { "mut " } else { "" };
+            format!("&{m}{}", rust_type(&r.elem))
+        }
+        Type::Ptr(p) => {
+            let m = if p.mutability.is_some() {
+                "mut"
+            } else {
+                "const"
+            };
+            format!("*{m} {}", rust_type(&p.elem))
+        }
+        Type::Slice(s) => format!("[{}]", rust_type(&s.elem)),
+        Type::Tuple(t) if t.elems.is_empty() => "()".to_string(),
+        Type::Path(p) => {
+            let segment = p.path.segments.last().unwrap();
+            let mut name = segment.ident.to_string();
+            if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                let args = args
+                    .args
+                    .iter()
+                    .map(|a| match a {
+                        GenericArgument::Type(t) => rust_type(t),
+                        _ => panic!("Unsupported type argument"),
+                    })
+                    .collect::<Vec<_>>();
+                name = format!("{name}<{}>", args.join(", "));
+            }
+            name
+        }
+        _ => panic!("Unsupported type"),
+    }
+}
+
+fn has_attr(attrs: &[Attribute], name: &str) -> bool {
+    attrs.iter().any(|a| a.path().is_ident(name))
+}
+
+fn is_cfg_test(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|a| {
+        a.path().is_ident("cfg") && a.parse_args::<syn::Ident>().is_ok_and(|i| i == "test")
+    })
+}
+
+/// Feature of a `#[cfg(feature = "...")]` attribute
+fn cfg_feature(attrs: &[Attribute]) -> Option<String> {
+    attrs.iter().find_map(|a| {
+        if !a.path().is_ident("cfg") {
+            return None;
+        }
+        let nv = a.parse_args::<syn::MetaNameValue>().ok()?;
+        if !nv.path.is_ident("feature") {
+            return None;
+        }
+        match nv.value {
+            Expr::Lit(syn::ExprLit {
+                lit: Lit::Str(s), ..
+            }) => Some(s.value()),
+            _ => None,
+        }
+    })
+}
+
+fn doc_comment(attrs: &[Attribute]) -> String {
+    let lines = attrs
+        .iter()
+        .filter_map(|a| match &a.meta {
+            Meta::NameValue(nv) if nv.path.is_ident("doc") => match &nv.value {
+                Expr::Lit(syn::ExprLit {
+                    lit: Lit::Str(s), ..
+                }) => Some(s.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .map(|l| l.strip_prefix(' ').map(str::to_string).unwrap_or(l))
+        .collect::<Vec<_>>();
+    lines.join("\n")
+}
+
+fn ffi_header(functions: &[FfiFunction]) -> String {
+    let config = cbindgen::Config::from_file("cbindgen.toml").unwrap();
+    let mut header = String::new();
+    header.push_str("/* Generated by build.rs from the exported functions, do not edit */\n");
+    header.push_str(&config.after_includes.unwrap_or_default());
+    header.push_str("\n\n");
+
+    // result and parameter types, in the order of first use
+    let mut types: Vec<&str> = vec![];
+    for f in functions.iter() {
+        let cs = std::iter::once(f.result.c.as_str()).chain(f.params.iter().map(|p| p.c.as_str()));
+        for c in cs {
+            if let Some(t) = c.strip_prefix("struct ") {
+                if !types.contains(&t) {
+                    types.push(t);
+                }
+            }
+        }
+    }
+    for t in types {
+        let Some(v) = t.strip_prefix("CResult_") else {
+            header.push_str(&format!(
+                "typedef struct {t} {{\n  uint8_t *value;\n  uint32_t len;\n}} {t};\n\n"
+            ));
+            continue;
+        };
+        let value = match v {
+            "____c_char" => "char *value".to_string(),
+            "_____u8" => "const uint8_t *value".to_string(),
+            v => format!("{} value", c_value_type(v).unwrap()),
+        };
+        header.push_str(&format!(
+            "typedef struct {t} {{\n  {value};\n  char *error;\n  uint32_t len;\n}} {t};\n\n"
+        ));
+    }
+
+    let mut feature: Option<&str> = None;
+    for f in functions.iter() {
+        if f.feature.as_deref() != feature {
+            if feature.is_some() {
+                header.push_str("#endif\n\n");
+            }
+            if let Some(name) = &f.feature {
+                let define = name.to_uppercase().replace('-', "_");
+                header.push_str(&format!("#if defined(ZCASH_WARP_FEATURE_{define})\n"));
+            }
+            feature = f.feature.as_deref();
+        }
+        let params = f
+            .params
+            .iter()
+            .map(|p| {
+                if p.c.ends_with('*') {
+                    format!("{}{}", p.c, p.name)
+                } else {
+                    format!("{} {}", p.c, p.name)
+                }
+            })
+            .collect::<Vec<_>>();
+        let prefix = format!("{} {}(", f.result.c, f.name);
+        let line = if params.is_empty() {
+            format!("{prefix}void);")
+        } else {
+            let line = format!("{prefix}{});", params.join(", "));
+            if line.len() <= FFI_LINE_LENGTH {
+                line
+            } else {
+                let indent = format!(",\n{}", " ".repeat(prefix.len()));
+                format!("{prefix}{});", params.join(indent.as_str()))
+            }
+        };
+        header.push_str(&line);
+        header.push_str("\n\n");
+    }
+    if feature.is_some() {
+        header.push_str("#endif\n");
+    }
+    format!("{}\n", header.trim_end())
+}
+
+fn ffi_manifest(functions: &[FfiFunction]) -> String {
+    let functions = functions
+        .iter()
+        .map(|f| {
+            let params = f
+                .params
+                .iter()
+                .map(|p| {
+                    json!({
+                        "name": p.name,
+                        "rust": p.rust,
+                        "c": p.c,
+                        "kind": p.kind,
+                        "table": p.table,
+                    })
+                })
+                .collect::<Vec<_>>();
+            json!({
+                "name": f.name,
+                "rust": f.rust,
+                "feature": f.feature,
+                "async": f.is_async,
+                "coin": f.coin,
+                "doc": f.doc,
+                "params": params,
+                "result": {
+                    "rust": f.result.rust,
+                    "c": f.result.c,
+                    "kind": f.result.kind,
+                    "table": f.result.table,
+                    "list": f.result.list,
+                },
+            })
+        })
+        .collect::<Vec<_>>();
+    let results = [
+        ("CResult_u8", "uint8_t"),
+        ("CResult_u32", "uint32_t"),
+        ("CResult_u64", "uint64_t"),
+        ("CResult_i64", "int64_t"),
+        ("CResult_bool", "bool"),
+        ("CResult_____c_char", "char *"),
+        ("CResult______u8", "const uint8_t *"),
+    ]
+    .into_iter()
+    .map(|(n, v)| (n.to_string(), Value::from(v)))
+    .collect::<BTreeMap<_, _>>();
+    let manifest = json!({
+        "library": "zcash_warp",
+        "header": "zcash_warp.h",
+        "schema": "flatbuffers/data.fbs",
+        "results": results,
+        "functions": functions,
+    });
+    format!("{}\n", serde_json::to_string_pretty(&manifest).unwrap())
+}
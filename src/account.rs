@@ -1,4 +1,14 @@
+pub mod ack;
 pub mod address;
+pub mod attestation;
 pub mod contacts;
+pub mod descriptor;
+pub mod discovery;
+pub mod lookalike;
+pub mod lwd;
+pub mod outbox;
 pub mod pools;
+pub mod seed_verification;
 pub mod txs;
+pub mod watch_only;
+pub mod webhook;
@@ -0,0 +1,281 @@
+use anyhow::Result;
+use rand::rngs::OsRng;
+use rusqlite::{params, Connection};
+use zcash_keys::address::Address as RecipientAddress;
+
+use crate::{
+    coin::CoinDef,
+    data::fb::{Acknowledgment, AcknowledgmentT, PaymentRequestT, RecipientT, UserMemoT},
+    db::{
+        account::{get_account_property, list_accounts, set_account_property},
+        api_keys::{check_access, Operation},
+        chain::{get_sync_height, SNAP_ACCOUNT_DEFAULT},
+    },
+    lwd::get_last_height,
+    network::Network,
+    utils::{
+        pay::{broadcast_tx, plan_payment, PaymentOptions},
+        to_txid_str,
+    },
+    Client, Hash, EXPIRATION_HEIGHT_DELTA,
+};
+use warp_macros::c_export;
+
+const ACK_PROP: &str = "ack";
+
+const ACK_PENDING: u8 = 0;
+const ACK_APPROVED: u8 = 1;
+const ACK_SENT: u8 = 2;
+const ACK_REJECTED: u8 = 3;
+
+const CONTACT_ACK_ALWAYS: u8 = 1;
+const CONTACT_ACK_NEVER: u8 = 2;
+
+/*
+    Payment acknowledgments (read receipts)
+
+    When enabled for an account, every incoming message received after
+    that point and that has a reply-to address is queued for acknowledgment
+    once it is confirmed.
+    Depending on the setting of the contact with that address,
+    the acknowledgment is approved automatically, ignored,
+    or waits for the user's approval (unknown senders always wait).
+    Approved acknowledgments are sent together in a single transaction
+    of zero value outputs with an "ACK" memo.
+*/
+
+#[c_export]
+pub fn set_ack_enabled(connection: &Connection, account: u32, enabled: bool) -> Result<()> {
+    // remember from which height we acknowledge payments
+    let height = if enabled {
+        get_sync_height(connection)?.height.max(1)
+    } else {
+        0
+    };
+    set_account_property(connection, account, ACK_PROP, &height.to_le_bytes())
+}
+
+#[c_export]
+pub fn is_ack_enabled(connection: &Connection, account: u32) -> Result<bool> {
+    Ok(get_ack_height(connection, account)? != 0)
+}
+
+fn get_ack_height(connection: &Connection, account: u32) -> Result<u32> {
+    let value = get_account_property(connection, account, ACK_PROP)?;
+    let height = value
+        .try_into()
+        .map(u32::from_le_bytes)
+        .unwrap_or_default();
+    Ok(height)
+}
+
+#[c_export]
+pub fn list_pending_acks(connection: &Connection, account: u32) -> Result<Vec<AcknowledgmentT>> {
+    list_acks(connection, account, ACK_PENDING)
+}
+
+#[c_export]
+pub fn approve_ack(connection: &Connection, id: u32, approved: bool) -> Result<()> {
+    let status = if approved { ACK_APPROVED } else { ACK_REJECTED };
+    connection.execute(
+        "UPDATE acks SET status = ?2 WHERE id_ack = ?1 AND status = ?3",
+        params![id, status, ACK_PENDING],
+    )?;
+    Ok(())
+}
+
+/// Send the approved acknowledgments of the account.
+/// Returns the txid or an empty string if there was nothing to send
+#[c_export]
+pub async fn send_acks(coin: &CoinDef, session: u32, account: u32) -> Result<String> {
+    let connection = coin.connection()?;
+    check_access(&connection, session, &Operation::Pay { account, amount: 0 })?;
+    let mut client = coin.connect_lwd()?;
+    send_approved_acks(coin, &connection, &mut client, account).await
+}
+
+async fn send_approved_acks(
+    coin: &CoinDef,
+    connection: &Connection,
+    client: &mut Client,
+    account: u32,
+) -> Result<String> {
+    let acks = list_acks(connection, account, ACK_APPROVED)?;
+    if acks.is_empty() {
+        return Ok(String::new());
+    }
+    let recipients = acks
+        .iter()
+        .map(|ack| {
+            let txid: Hash = ack.txid.clone().unwrap().try_into().unwrap();
+            let memo = UserMemoT {
+                reply_to: false,
+                sender: None,
+                recipient: ack.address.clone(),
+                subject: Some("ACK".to_string()),
+                body: Some(format!("Received {}", to_txid_str(&txid))),
+            };
+            RecipientT {
+                address: ack.address.clone(),
+                amount: 0,
+                pools: 6,
+                memo: Some(Box::new(memo)),
+                memo_bytes: None,
+                src_pools: 0,
+            }
+            .normalize_memo()
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let network = &coin.network;
+    let height = get_sync_height(connection)?.height;
+    // the wallet may be behind the server, the transaction must
+    // not expire before it is mined
+    let expiration_height = get_last_height(client).await?.max(height) + EXPIRATION_HEIGHT_DELTA;
+    let payment = PaymentRequestT {
+        recipients: Some(recipients),
+        src_pools: 7,
+        sender_pay_fees: true,
+        use_change: true,
+        height,
+        expiration: 0,
+        decoy_outputs: 0,
+        from_address: None,
+        allow_high_fee: false,
+        change_outputs: 0,
+        allow_change_address: false,
+        allow_lookalike_address: false,
+    };
+    let utx = plan_payment(
+        network,
+        connection,
+        client,
+        account,
+        &payment,
+        &PaymentOptions::new(coin, SNAP_ACCOUNT_DEFAULT, ""),
+    )
+    .await?;
+    let txb = utx.build(network, connection, expiration_height, OsRng)?;
+    let txid = broadcast_tx(network, connection, client, &txb).await?;
+
+    let mut s = connection.prepare("UPDATE acks SET status = ?2 WHERE id_ack = ?1")?;
+    for ack in acks.iter() {
+        s.execute(params![ack.id, ACK_SENT])?;
+    }
+    Ok(txid)
+}
+
+/// Post sync hook: queue the acknowledgments of the confirmed
+/// incoming messages and send the approved ones.
+/// Nothing is sent until the wallet has caught up with the server,
+/// and an account that fails does not hold back the others
+pub async fn process_acks(coin: &CoinDef, connection: &Connection, height: u32) -> Result<()> {
+    let mut client = coin.connect_lwd()?;
+    if height < get_last_height(&mut client).await? {
+        return Ok(());
+    }
+    let confirmations = coin.config.confirmations.max(1);
+    let accounts = list_accounts(coin, connection)?;
+    for a in accounts.items.iter().flatten() {
+        if let Err(e) =
+            process_account_acks(coin, connection, &mut client, a.id, height, confirmations).await
+        {
+            tracing::warn!("Acknowledgments of account {}: {}", a.id, e);
+        }
+    }
+    Ok(())
+}
+
+async fn process_account_acks(
+    coin: &CoinDef,
+    connection: &Connection,
+    client: &mut Client,
+    account: u32,
+    height: u32,
+    confirmations: u32,
+) -> Result<()> {
+    let from_height = get_ack_height(connection, account)?;
+    if from_height == 0 {
+        return Ok(());
+    }
+    queue_acks(
+        &coin.network,
+        connection,
+        account,
+        from_height,
+        height,
+        confirmations,
+    )?;
+    send_approved_acks(coin, connection, client, account).await?;
+    Ok(())
+}
+
+fn queue_acks(
+    network: &Network,
+    connection: &Connection,
+    account: u32,
+    from_height: u32,
+    height: u32,
+    confirmations: u32,
+) -> Result<()> {
+    let mut s = connection.prepare(
+        "SELECT m.id_msg, m.txid, m.sender, m.height, c.ack FROM msgs m
+        LEFT JOIN contacts c ON c.account = m.account AND c.address = m.sender
+        LEFT JOIN acks a ON a.account = m.account AND a.msg = m.id_msg
+        WHERE m.account = ?1 AND m.incoming AND m.sender IS NOT NULL AND m.sender <> ''
+        AND m.height > ?4 AND m.height + ?3 - 1 <= ?2 AND a.id_ack IS NULL",
+    )?;
+    let rows = s.query_map(params![account, height, confirmations, from_height], |r| {
+        Ok((
+            r.get::<_, u32>(0)?,
+            r.get::<_, Vec<u8>>(1)?,
+            r.get::<_, String>(2)?,
+            r.get::<_, u32>(3)?,
+            r.get::<_, Option<u8>>(4)?,
+        ))
+    })?;
+    let mut s_insert = connection.prepare(
+        "INSERT INTO acks(account, msg, txid, address, height, status)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6) ON CONFLICT DO NOTHING",
+    )?;
+    for r in rows {
+        let (id_msg, txid, address, msg_height, contact_ack) = r?;
+        let status = match contact_ack {
+            _ if !has_shielded_receiver(network, &address) => ACK_REJECTED,
+            Some(CONTACT_ACK_ALWAYS) => ACK_APPROVED,
+            Some(CONTACT_ACK_NEVER) => ACK_REJECTED,
+            _ => ACK_PENDING,
+        };
+        s_insert.execute(params![account, id_msg, txid, address, msg_height, status])?;
+    }
+    Ok(())
+}
+
+/// Memos can only be sent to shielded addresses
+fn has_shielded_receiver(network: &Network, address: &str) -> bool {
+    match RecipientAddress::decode(network, address) {
+        Some(RecipientAddress::Sapling(_)) => true,
+        Some(RecipientAddress::Unified(ua)) => ua.sapling().is_some() || ua.orchard().is_some(),
+        _ => false,
+    }
+}
+
+fn list_acks(connection: &Connection, account: u32, status: u8) -> Result<Vec<AcknowledgmentT>> {
+    let mut s = connection.prepare(
+        "SELECT id_ack, msg, txid, address, height FROM acks
+        WHERE account = ?1 AND status = ?2 ORDER BY height",
+    )?;
+    let rows = s.query_map(params![account, status], |r| {
+        Ok(AcknowledgmentT {
+            id: r.get(0)?,
+            account,
+            id_msg: r.get(1)?,
+            txid: Some(r.get::<_, Vec<u8>>(2)?),
+            address: Some(r.get::<_, String>(3)?),
+            height: r.get(4)?,
+            status,
+        })
+    })?;
+    let acks = rows.collect::<Result<Vec<_>, _>>()?;
+    Ok(acks)
+}
@@ -1,16 +1,21 @@
 use anyhow::Result;
 use bech32::{Bech32m, Hrp};
-use rusqlite::Connection;
+use orchard::keys::Scope;
+use rusqlite::{params, Connection, OptionalExtension as _};
+use sapling_crypto::PaymentAddress;
 use zcash_client_backend::encoding::AddressCodec;
 use zcash_keys::keys::UnifiedAddressRequest;
 use zcash_primitives::legacy::TransparentAddress;
 use zip32::DiversifierIndex;
 
 use crate::{
-    db::account::get_account_info,
+    data::fb::{AddressUsage, AddressUsageT},
+    db::account::{get_account_info, update_account_addresses},
     network::Network,
-    types::{PoolMask, TransparentAccountInfo},
+    types::{AccountInfo, PoolMask, TransparentAccountInfo},
+    utils::ua::{split_address, ua_of_orchard},
 };
+use warp_macros::c_export;
 
 pub fn get_diversified_address(
     network: &Network,
@@ -76,3 +81,182 @@ pub fn convert_tex_address(network: &Network, address: &str, to_tex: bool) -> Re
         Ok(address)
     }
 }
+
+/// Number of received notes/utxos and height of the last one,
+/// for every address of the account that received funds.
+/// Shielded addresses are distinguished by their diversifier
+#[c_export]
+pub fn get_address_usage(
+    network: &Network,
+    connection: &Connection,
+    account: u32,
+) -> Result<Vec<AddressUsageT>> {
+    let ai = get_account_info(network, connection, account)?;
+    let mut usage = vec![];
+
+    let mut s = connection.prepare(
+        "SELECT a.address, u.addr_index, COUNT(*), MAX(u.height) FROM utxos u
+        JOIN t_addresses a ON u.account = a.account
+            AND u.external = a.external
+            AND u.addr_index = a.addr_index
+        WHERE u.account = ?1 GROUP BY u.external, u.addr_index",
+    )?;
+    let rows = s.query_map([account], |r| {
+        Ok(AddressUsageT {
+            pool: 0,
+            address: Some(r.get::<_, String>(0)?),
+            addr_index: r.get(1)?,
+            count: r.get(2)?,
+            last_height: r.get(3)?,
+        })
+    })?;
+    for r in rows {
+        usage.push(r?);
+    }
+
+    let mut s = connection.prepare(
+        "SELECT address, orchard, COUNT(*), MAX(height) FROM notes
+        WHERE account = ?1 GROUP BY address, orchard",
+    )?;
+    let rows = s.query_map([account], |r| {
+        Ok((
+            r.get::<_, [u8; 43]>(0)?,
+            r.get::<_, bool>(1)?,
+            r.get::<_, u32>(2)?,
+            r.get::<_, u32>(3)?,
+        ))
+    })?;
+    for r in rows {
+        let (address, orchard, count, last_height) = r?;
+        let (pool, address, di) = if orchard {
+            let oi = ai.orchard.as_ref();
+            let addr = orchard::Address::from_raw_address_bytes(&address).unwrap();
+            let di = oi.and_then(|oi| oi.vk.to_ivk(Scope::External).diversifier_index(&addr));
+            (2, ua_of_orchard(&addr).encode(network), di)
+        } else {
+            let si = ai.sapling.as_ref();
+            let pa = PaymentAddress::from_bytes(&address).unwrap();
+            let di = si.and_then(|si| si.vk.decrypt_diversifier(&pa)).map(|(di, _)| di);
+            (1, pa.encode(network), di)
+        };
+        let addr_index = di.and_then(|di| u32::try_from(di).ok()).unwrap_or_default();
+        usage.push(AddressUsageT {
+            pool,
+            address: Some(address),
+            addr_index,
+            count,
+            last_height,
+        });
+    }
+
+    Ok(usage)
+}
+
+/// True if the current address of the account has received funds
+pub fn is_address_used(
+    network: &Network,
+    connection: &Connection,
+    ai: &AccountInfo,
+) -> Result<bool> {
+    let mut s = connection.prepare("SELECT 1 FROM notes WHERE account = ?1 AND address = ?2")?;
+    if let Some(si) = ai.sapling.as_ref() {
+        if s.exists(params![ai.account, si.addr.to_bytes()])? {
+            return Ok(true);
+        }
+    }
+    if let Some(oi) = ai.orchard.as_ref() {
+        if s.exists(params![ai.account, oi.addr.to_raw_address_bytes()])? {
+            return Ok(true);
+        }
+    }
+    if let Some(ti) = ai.transparent.as_ref() {
+        let used = connection
+            .prepare(
+                "SELECT 1 FROM utxos u JOIN t_addresses a ON u.account = a.account
+                AND u.external = a.external AND u.addr_index = a.addr_index
+                WHERE u.account = ?1 AND a.address = ?2",
+            )?
+            .exists(params![ai.account, ti.addr.encode(network)])?;
+        if used {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Move the account to its next diversified address if
+/// the current one has already received funds.
+/// Accounts with a single transparent key cannot rotate
+pub fn rotate_used_address(
+    network: &Network,
+    connection: &Connection,
+    account: u32,
+) -> Result<()> {
+    let ai = get_account_info(network, connection, account)?;
+    if ai.transparent.as_ref().is_some_and(|ti| ti.vk.is_none()) {
+        return Ok(());
+    }
+    if !is_address_used(network, connection, &ai)? {
+        return Ok(());
+    }
+    let dindex = ai.next_addr_index(true)?;
+    let ai = ai.clone_with_addr_index(network, dindex)?;
+    update_account_addresses(network, connection, &ai)?;
+    tracing::info!("Account {} moved to address index {}", account, dindex);
+    Ok(())
+}
+
+/// True if `address` is a transparent change address of the wallet
+/// (or an address that has one as its transparent receiver).
+/// These addresses are internal and should not be given out
+#[c_export]
+pub fn is_change_address(
+    network: &Network,
+    connection: &Connection,
+    address: &str,
+) -> Result<bool> {
+    Ok(change_address_account(network, connection, address)?.is_some())
+}
+
+/// Account of a transparent change address: an address of the
+/// change scope of t_addresses, or a change address of an account
+/// up to its change index (see `to_change_address`)
+pub fn change_address_account(
+    network: &Network,
+    connection: &Connection,
+    address: &str,
+) -> Result<Option<u32>> {
+    let Ok((Some(taddr), _, _, _)) = split_address(network, address) else {
+        return Ok(None);
+    };
+    let taddr_str = taddr.encode(network);
+    let account = connection
+        .query_row(
+            "SELECT account FROM t_addresses WHERE external = 1 AND address = ?1",
+            [&taddr_str],
+            |r| r.get::<_, u32>(0),
+        )
+        .optional()?;
+    if account.is_some() {
+        return Ok(account);
+    }
+    let mut s = connection.prepare("SELECT id_account FROM accounts WHERE deleted IS NULL")?;
+    let accounts = s
+        .query_map([], |r| r.get::<_, u32>(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+    for account in accounts {
+        let ai = get_account_info(network, connection, account)?;
+        let Some(ti) = ai.transparent.as_ref() else {
+            continue;
+        };
+        let (Some(vk), Some(change_index)) = (ti.vk.as_ref(), ti.change_index) else {
+            continue;
+        };
+        let found =
+            (0..=change_index).any(|i| TransparentAccountInfo::derive_address(vk, 1, i) == taddr);
+        if found {
+            return Ok(Some(account));
+        }
+    }
+    Ok(None)
+}
@@ -0,0 +1,189 @@
+use anyhow::Result;
+use jubjub::Fr;
+use orchard::note::{ExtractedNoteCommitment, RandomSeed, Rho};
+use rusqlite::Connection;
+use sapling_crypto::{value::NoteValue, PaymentAddress, Rseed};
+
+use crate::{
+    data::fb::{BalanceProof, BalanceProofT, NoteProofT},
+    db::{chain::snap_to_checkpoint, notes::list_received_notes},
+    fb_unwrap,
+    lwd::get_tree_state,
+    types::CheckpointHeight,
+    utils::chain::get_cached_tree_state,
+    warp::{
+        hasher::{empty_roots, OrchardHasher, SaplingHasher},
+        sync::witnesses::rebuild_witnesses,
+        AuthPath, Hasher, MERKLE_DEPTH,
+    },
+    Client, Hash,
+};
+use warp_macros::c_export;
+
+/*
+    Balance attestation
+
+    Proves the holdings of an account at a checkpoint without
+    any spending capability (a viewing key is enough).
+    For every unspent shielded note, the attestation has the note
+    plaintext (address, value, rseed, rho) and the merkle path of
+    its commitment to the anchor of the checkpoint.
+    The verifier recomputes the note commitments, checks the paths
+    and compares the anchors with the tree state published by its own server.
+
+    The attestation does not prove that the notes are still unspent
+    after the checkpoint height.
+*/
+
+/// Unspent sapling and orchard notes of the account at the checkpoint
+/// at or before `height`
+#[c_export]
+pub async fn create_balance_proof(
+    connection: &Connection,
+    client: &mut Client,
+    account: u32,
+    height: u32,
+) -> Result<BalanceProofT> {
+    let cp_height = snap_to_checkpoint(connection, height)?;
+    rebuild_witnesses(connection, client, cp_height).await?;
+    let (s_tree, o_tree) = get_cached_tree_state(connection, client, cp_height).await?;
+    let sap_hasher = SaplingHasher::default();
+    let orch_hasher = OrchardHasher::default();
+    let s_edge = s_tree.to_edge(&sap_hasher);
+    let o_edge = o_tree.to_edge(&orch_hasher);
+    let edges = [s_edge.to_auth_path(&sap_hasher), o_edge.to_auth_path(&orch_hasher)];
+    let er = [empty_roots(&sap_hasher), empty_roots(&orch_hasher)];
+
+    let mut notes = vec![];
+    for (i, orchard) in [false, true].into_iter().enumerate() {
+        for note in list_received_notes(connection, account, cp_height, orchard, 0)? {
+            let auth_path = note.witness.build_auth_path(&edges[i], &er[i]);
+            notes.push(NoteProofT {
+                pool: i as u8 + 1,
+                position: note.position,
+                value: note.value,
+                address: Some(note.address.to_vec()),
+                rseed: Some(note.rcm.to_vec()),
+                rho: note.rho.map(|rho| rho.to_vec()),
+                cmx: Some(note.witness.value.to_vec()),
+                path: Some(auth_path.0.concat()),
+            });
+        }
+    }
+    let total = notes.iter().map(|n| n.value).sum::<u64>();
+
+    Ok(BalanceProofT {
+        account,
+        height: cp_height.0,
+        sapling_root: Some(s_edge.root(&sap_hasher).to_vec()),
+        orchard_root: Some(o_edge.root(&orch_hasher).to_vec()),
+        total,
+        notes: Some(notes),
+    })
+}
+
+/// Verify a balance attestation against the tree state of the server.
+/// Returns the total value of the notes
+#[c_export]
+pub async fn verify_balance_proof(client: &mut Client, proof: &BalanceProofT) -> Result<u64> {
+    let (s_tree, o_tree) = get_tree_state(client, CheckpointHeight(proof.height)).await?;
+    let sap_hasher = SaplingHasher::default();
+    let orch_hasher = OrchardHasher::default();
+    let roots = [
+        s_tree.to_edge(&sap_hasher).root(&sap_hasher),
+        o_tree.to_edge(&orch_hasher).root(&orch_hasher),
+    ];
+    if fb_unwrap!(proof.sapling_root)[..] != roots[0] {
+        anyhow::bail!("Sapling anchor does not match the tree state at {}", proof.height);
+    }
+    if fb_unwrap!(proof.orchard_root)[..] != roots[1] {
+        anyhow::bail!("Orchard anchor does not match the tree state at {}", proof.height);
+    }
+
+    let mut total = 0;
+    for note in fb_unwrap!(proof.notes).iter() {
+        let cmx = note_commitment(note)?;
+        if fb_unwrap!(note.cmx)[..] != cmx {
+            anyhow::bail!("Invalid note commitment at position {}", note.position);
+        }
+        let path: [Hash; MERKLE_DEPTH as usize] = fb_unwrap!(note.path)
+            .chunks_exact(32)
+            .map(|h| h.try_into().unwrap())
+            .collect::<Vec<Hash>>()
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Invalid merkle path"))?;
+        let path = AuthPath(path);
+        let root = match note.pool {
+            1 => path_root(&sap_hasher, &cmx, note.position, &path),
+            2 => path_root(&orch_hasher, &cmx, note.position, &path),
+            _ => anyhow::bail!("Invalid pool {}", note.pool),
+        };
+        if root != roots[note.pool as usize - 1] {
+            anyhow::bail!("Invalid merkle path at position {}", note.position);
+        }
+        total += note.value;
+    }
+    if total != proof.total {
+        anyhow::bail!("Total {} does not match the notes ({})", proof.total, total);
+    }
+    Ok(total)
+}
+
+fn note_commitment(note: &NoteProofT) -> Result<Hash> {
+    let address: [u8; 43] = fb_unwrap!(note.address)[..]
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Invalid address"))?;
+    let rseed: Hash = fb_unwrap!(note.rseed)[..]
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Invalid rseed"))?;
+    let cmx = match note.pool {
+        1 => {
+            let recipient = PaymentAddress::from_bytes(&address)
+                .ok_or(anyhow::anyhow!("Invalid sapling address"))?;
+            let rcm = Option::<Fr>::from(Fr::from_bytes(&rseed))
+                .ok_or(anyhow::anyhow!("Invalid rcm"))?;
+            let note = sapling_crypto::Note::from_parts(
+                recipient,
+                NoteValue::from_raw(note.value),
+                Rseed::BeforeZip212(rcm),
+            );
+            note.cmu().to_bytes()
+        }
+        2 => {
+            let recipient = orchard::Address::from_raw_address_bytes(&address);
+            let recipient = Option::<orchard::Address>::from(recipient)
+                .ok_or(anyhow::anyhow!("Invalid orchard address"))?;
+            let rho: Hash = fb_unwrap!(note.rho)[..]
+                .try_into()
+                .map_err(|_| anyhow::anyhow!("Invalid rho"))?;
+            let rho =
+                Option::<Rho>::from(Rho::from_bytes(&rho)).ok_or(anyhow::anyhow!("Invalid rho"))?;
+            let rseed = Option::<RandomSeed>::from(RandomSeed::from_bytes(rseed, &rho))
+                .ok_or(anyhow::anyhow!("Invalid rseed"))?;
+            let note = Option::<orchard::Note>::from(orchard::Note::from_parts(
+                recipient,
+                orchard::value::NoteValue::from_raw(note.value),
+                rho,
+                rseed,
+            ))
+            .ok_or(anyhow::anyhow!("Invalid orchard note"))?;
+            ExtractedNoteCommitment::from(note.commitment()).to_bytes()
+        }
+        _ => anyhow::bail!("Invalid pool {}", note.pool),
+    };
+    Ok(cmx)
+}
+
+fn path_root<H: Hasher>(h: &H, leaf: &Hash, position: u32, path: &AuthPath) -> Hash {
+    let mut hash = *leaf;
+    let mut p = position;
+    for i in 0..MERKLE_DEPTH as usize {
+        hash = if p & 1 == 0 {
+            h.combine(i as u8, &hash, &path.0[i])
+        } else {
+            h.combine(i as u8, &path.0[i], &hash)
+        };
+        p /= 2;
+    }
+    hash
+}
@@ -14,14 +14,15 @@ use zcash_primitives::{
 };
 
 use crate::{
+    account::lookalike::check_lookalike_address,
     data::fb::{ContactCardT, PaymentRequestT, RecipientT},
     db::{
         account::get_account_info,
-        contacts::{get_unsaved_contacts, store_contact},
+        contacts::{get_contact_group, get_unsaved_contacts, list_group_contacts, store_contact},
     },
-    pay::{make_payment, UnsignedTransaction},
-    types::{CheckpointHeight, PoolMask},
-    warp::legacy::CommitmentTreeFrontier,
+    pay::Error,
+    types::PoolMask,
+    EXPIRATION_HEIGHT_DELTA,
 };
 
 use warp_macros::c_export;
@@ -35,6 +36,13 @@ pub fn add_contact(
     address: &str,
     saved: bool,
 ) -> Result<()> {
+    if let Some(w) = check_lookalike_address(connection, account, address)?.first() {
+        return Err(Error::LookalikeAddress(
+            address.to_string(),
+            w.similar.clone().unwrap_or_default(),
+        )
+        .into());
+    }
     let contact = ContactCardT {
         id: 0,
         account,
@@ -69,16 +77,14 @@ pub fn serialize_contacts(contacts: &[ContactV1]) -> Result<Vec<Memo>> {
 
 const MIN_AMOUNT: u64 = 10_000;
 
-pub fn commit_unsaved_contacts(
+/// Payment to self that saves the new contacts in its memos
+pub fn make_contacts_payment(
     network: &Network,
     connection: &Connection,
     account: u32,
     src_pools: u8,
-    cp_height: CheckpointHeight,
-    s: &CommitmentTreeFrontier,
-    o: &CommitmentTreeFrontier,
-    redirect: Option<String>,
-) -> anyhow::Result<UnsignedTransaction> {
+    height: u32,
+) -> Result<PaymentRequestT> {
     let ai = get_account_info(network, connection, account)?;
     let address = ai.to_address(network, PoolMask(src_pools)).unwrap();
     let contacts = get_unsaved_contacts(connection, account)?;
@@ -101,6 +107,7 @@ pub fn commit_unsaved_contacts(
                 memo: None,
                 pools: 7,
                 memo_bytes: Some(memo.as_slice().to_vec()),
+                src_pools: 0,
             }
         })
         .collect::<Vec<_>>();
@@ -109,11 +116,77 @@ pub fn commit_unsaved_contacts(
         src_pools,
         sender_pay_fees: true,
         use_change: true,
-        height: cp_height.0,
-        expiration: cp_height.0 + 50,
+        height,
+        expiration: height + 50,
+        decoy_outputs: 0,
+        from_address: None,
+        allow_high_fee: false,
+        change_outputs: 0,
+        allow_change_address: false,
+        allow_lookalike_address: false,
+    };
+    Ok(payment)
+}
+
+/// Payment to every member of a contact group.
+/// With `split`, the members share `amount`, otherwise each of them gets `amount`
+pub fn make_group_payment(
+    connection: &Connection,
+    account: u32,
+    group: u32,
+    amount: u64,
+    split: bool,
+    height: u32,
+) -> Result<PaymentRequestT> {
+    let g = get_contact_group(connection, group)?;
+    if g.account != account {
+        anyhow::bail!("Contact group {} is not from account {}", group, account);
+    }
+    let contacts = list_group_contacts(connection, group)?;
+    if contacts.is_empty() {
+        anyhow::bail!("Contact group {} has no member", group);
+    }
+    let n = contacts.len() as u64;
+    let amounts = if split {
+        if amount < n {
+            anyhow::bail!("Amount {} is too small to split between {} contacts", amount, n);
+        }
+        // the remainder goes to the first members, one zat each
+        let share = amount / n;
+        let remainder = amount % n;
+        (0..n)
+            .map(|i| share + if i < remainder { 1 } else { 0 })
+            .collect::<Vec<_>>()
+    } else {
+        vec![amount; contacts.len()]
+    };
+    let recipients = contacts
+        .into_iter()
+        .zip(amounts)
+        .map(|(c, amount)| RecipientT {
+            address: c.address,
+            amount,
+            pools: 7,
+            memo: None,
+            memo_bytes: None,
+            src_pools: 0,
+        })
+        .collect::<Vec<_>>();
+    let payment = PaymentRequestT {
+        recipients: Some(recipients),
+        src_pools: 7,
+        sender_pay_fees: true,
+        use_change: true,
+        height,
+        expiration: height + EXPIRATION_HEIGHT_DELTA,
+        decoy_outputs: 0,
+        from_address: None,
+        allow_high_fee: false,
+        change_outputs: 0,
+        allow_change_address: false,
+        allow_lookalike_address: false,
     };
-    let utx = make_payment(network, connection, account, &payment, s, o, redirect)?;
-    Ok(utx)
+    Ok(payment)
 }
 
 pub trait ChunkedMemoData {
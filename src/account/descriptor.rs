@@ -0,0 +1,191 @@
+use anyhow::Result;
+use bip32::{ExtendedPrivateKey, Prefix};
+use rusqlite::Connection;
+use secp256k1::SecretKey;
+use zcash_primitives::consensus::NetworkConstants as _;
+
+use crate::{
+    data::fb::TransparentDescriptorsT,
+    db::{
+        account::get_account_info,
+        account_manager::{get_seed_phrase, parse_seed_phrase},
+        api_keys::{check_access, Operation},
+    },
+    keys::{decode_extended_private_key, decode_extended_public_key},
+    network::Network,
+};
+use warp_macros::c_export;
+
+/*
+    Output descriptors
+
+    The transparent keys of an account can be exported as output
+    descriptors (BIP-380) for the tools that build and sign PSBTs.
+    Zcash transparent addresses are P2PKH, so the descriptors use
+    the `pkh` script with the BIP-44 account key:
+
+        pkh([fingerprint/44'/133'/account']xpub.../0/<index>)#checksum
+
+    where the index is the wildcard `*`.
+
+    There is one descriptor for the receive addresses (0) and one
+    for the change addresses (1). The key origin (the fingerprint of
+    the master key and the derivation path) is only known for the
+    accounts that have a seed. The other accounts export the key
+    without its origin. With `private_keys`, the key is the xprv.
+
+    A descriptor is also a key for `create_new_account`: it creates
+    the transparent account of its key. The checksum is optional,
+    but a wrong checksum or the coin type of another network is
+    an error.
+*/
+
+const INPUT_CHARSET: &str =
+    "0123456789()[],'/*abcdefgh@:$%{}IJKLMNOPQRSTUVWXYZ&+-.;<=>?!^_|~ijklmnopqrstuvwxyzABCDEFGH`#\"\\ ";
+const CHECKSUM_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+#[c_export]
+pub fn export_transparent_descriptors(
+    network: &Network,
+    connection: &Connection,
+    session: u32,
+    account: u32,
+    passphrase: &str,
+    private_keys: bool,
+) -> Result<TransparentDescriptorsT> {
+    if private_keys {
+        check_access(connection, session, &Operation::Admin)?;
+    }
+    let ai = get_account_info(network, connection, account)?;
+    let ti = ai
+        .transparent
+        .as_ref()
+        .ok_or(anyhow::anyhow!("Account {} has no transparent key", account))?;
+    let key = if private_keys {
+        let xsk = ti.xsk.as_ref().ok_or(anyhow::anyhow!(
+            "Account {} has no transparent extended private key",
+            account
+        ))?;
+        xsk.clone().into_inner().to_string(Prefix::XPRV).to_string()
+    } else {
+        let vk = ti.vk.as_ref().ok_or(anyhow::anyhow!(
+            "Account {} has no transparent extended public key",
+            account
+        ))?;
+        vk.clone().into_inner().to_string(Prefix::XPUB)
+    };
+    let origin = if ai.seed.is_some() {
+        let (phrase, passphrase) = get_seed_phrase(network, connection, account, passphrase)?;
+        let seed = parse_seed_phrase(&phrase, &passphrase)?;
+        let master = ExtendedPrivateKey::<SecretKey>::new(seed.as_bytes())?;
+        let fingerprint = master.public_key().fingerprint();
+        format!(
+            "[{}/44'/{}'/{}']",
+            hex::encode(fingerprint),
+            network.coin_type(),
+            ai.aindex
+        )
+    } else {
+        String::new()
+    };
+    let descriptor = |scope: u32| add_checksum(&format!("pkh({origin}{key}/{scope}/*)"));
+    Ok(TransparentDescriptorsT {
+        receive: Some(descriptor(0)?),
+        change: Some(descriptor(1)?),
+    })
+}
+
+/// Extended key of a transparent descriptor
+pub fn parse_transparent_descriptor(network: &Network, descriptor: &str) -> Result<String> {
+    let descriptor = match descriptor.split_once('#') {
+        Some((d, checksum)) => {
+            if descriptor_checksum(d)? != checksum {
+                anyhow::bail!("Invalid descriptor checksum");
+            }
+            d
+        }
+        None => descriptor,
+    };
+    let key = descriptor
+        .strip_prefix("pkh(")
+        .and_then(|d| d.strip_suffix(')'))
+        .ok_or(anyhow::anyhow!("Only pkh descriptors are supported"))?;
+    let key = match key.strip_prefix('[') {
+        Some(k) => {
+            let (origin, key) = k
+                .split_once(']')
+                .ok_or(anyhow::anyhow!("Invalid key origin"))?;
+            let path = origin.split('/').skip(1).collect::<Vec<_>>();
+            let coin_type = format!("{}'", network.coin_type());
+            if path.len() != 3 || path[0] != "44'" || path[1] != coin_type {
+                anyhow::bail!("The key origin is not a BIP-44 account of this network");
+            }
+            key
+        }
+        None => key,
+    };
+    let key = ["/0/*", "/1/*", "/<0;1>/*"]
+        .iter()
+        .find_map(|suffix| key.strip_suffix(suffix))
+        .ok_or(anyhow::anyhow!("Invalid descriptor derivation"))?;
+    if decode_extended_public_key(key).is_err() && decode_extended_private_key(key).is_err() {
+        anyhow::bail!("Invalid extended key");
+    }
+    Ok(key.to_string())
+}
+
+fn add_checksum(descriptor: &str) -> Result<String> {
+    Ok(format!("{}#{}", descriptor, descriptor_checksum(descriptor)?))
+}
+
+fn polymod(c: u64, val: u64) -> u64 {
+    let c0 = c >> 35;
+    let mut c = ((c & 0x7ffffffff) << 5) ^ val;
+    for (i, g) in [
+        0xf5dee51989,
+        0xa9fdca3312,
+        0x1bab10e32d,
+        0x3706b1677a,
+        0x644d626ffd,
+    ]
+    .iter()
+    .enumerate()
+    {
+        if c0 & (1 << i) != 0 {
+            c ^= g;
+        }
+    }
+    c
+}
+
+/// Checksum of BIP-380
+fn descriptor_checksum(descriptor: &str) -> Result<String> {
+    let mut c = 1u64;
+    let mut cls = 0u64;
+    let mut cls_count = 0;
+    for ch in descriptor.chars() {
+        let pos = INPUT_CHARSET
+            .find(ch)
+            .ok_or(anyhow::anyhow!("Invalid character {} in descriptor", ch))?
+            as u64;
+        c = polymod(c, pos & 31);
+        cls = cls * 3 + (pos >> 5);
+        cls_count += 1;
+        if cls_count == 3 {
+            c = polymod(c, cls);
+            cls = 0;
+            cls_count = 0;
+        }
+    }
+    if cls_count > 0 {
+        c = polymod(c, cls);
+    }
+    for _ in 0..8 {
+        c = polymod(c, 0);
+    }
+    c ^= 1;
+    let checksum = (0..8)
+        .map(|j| CHECKSUM_CHARSET[((c >> (5 * (7 - j))) & 31) as usize] as char)
+        .collect::<String>();
+    Ok(checksum)
+}
@@ -0,0 +1,209 @@
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+use orchard::keys::Scope;
+use rusqlite::Connection;
+
+use crate::{
+    data::fb::{DiscoveredAccount, DiscoveredAccountT},
+    db::account_manager::{account_fingerprints, list_account_fingerprints, store_account_keys},
+    keys::AccountKeys,
+    lwd::{get_compact_block_range, get_last_height, get_transparent},
+    lwd_retry,
+    network::Network,
+    types::TransparentAccountInfo,
+    warp::{try_orchard_decrypt, try_sapling_decrypt},
+    Client,
+};
+use warp_macros::c_export;
+
+/*
+    Account discovery
+
+    Other wallets may have used the account indices 1, 2, ... of
+    a seed. The discovery derives the accounts of the seed in order
+    and looks for their activity on the server:
+    - the transactions of their first transparent addresses since
+    the birth height,
+    - the notes that they received in the last DISCOVERY_PROBE_BLOCKS
+    blocks (by trial decryption of the compact blocks).
+
+    It stops after `max_gap` inactive indices in a row, and creates
+    an account for every active index (and for the index 0) that
+    is not in the wallet already. The shielded probe is bounded: an
+    index that only has older shielded activity is not found and
+    must be added with its account index.
+*/
+
+/// Transparent addresses checked for each account index
+const DISCOVERY_ADDRESSES: u32 = 5;
+/// Number of blocks of the shielded probe, before the tip
+pub const DISCOVERY_PROBE_BLOCKS: u32 = 10_000;
+
+#[c_export]
+pub async fn discover_accounts(
+    network: &Network,
+    connection: &mut Connection,
+    client: &mut Client,
+    name: &str,
+    seed: &str,
+    passphrase: &str,
+    birth: u32,
+    max_gap: u32,
+) -> Result<Vec<DiscoveredAccountT>> {
+    let tip = get_last_height(client).await?;
+    let mut active = BTreeSet::new();
+    let mut checked = 0;
+    loop {
+        let end = active.last().map(|a| a + 1).unwrap_or_default() + max_gap.max(1);
+        if checked >= end {
+            break;
+        }
+        let keys = (checked..end)
+            .map(|aindex| AccountKeys::from_seed(network, seed, passphrase, aindex))
+            .collect::<Result<Vec<_>>>()?;
+        for ak in keys.iter() {
+            if has_transparent_activity(network, client, ak, birth, tip).await? {
+                tracing::info!("Account index {} has transparent transactions", ak.aindex);
+                active.insert(ak.aindex);
+            }
+        }
+        let keys = keys
+            .into_iter()
+            .filter(|ak| !active.contains(&ak.aindex))
+            .collect::<Vec<_>>();
+        let probe_start = birth.max(tip.saturating_sub(DISCOVERY_PROBE_BLOCKS));
+        for aindex in probe_shielded(network, client, &keys, probe_start, tip).await? {
+            tracing::info!("Account index {} has shielded notes", aindex);
+            active.insert(aindex);
+        }
+        checked = end;
+    }
+    active.insert(0);
+
+    let has_passphrase = !passphrase.is_empty();
+    let db_tx = connection.transaction()?;
+    let known = list_account_fingerprints(&db_tx)?;
+    let mut accounts = vec![];
+    for aindex in active {
+        let ak = AccountKeys::from_seed(network, seed, passphrase, aindex)?;
+        let fingerprints = account_fingerprints(network, &ak, 7)?;
+        let existing = fingerprints.iter().find_map(|fp| known.get(fp)).copied();
+        let (account, created) = match existing {
+            Some(account) => (account, false),
+            None => {
+                let name = if aindex == 0 {
+                    name.to_string()
+                } else {
+                    format!("{name} #{aindex}")
+                };
+                let account = store_account_keys(
+                    network,
+                    &db_tx,
+                    &name,
+                    &ak,
+                    has_passphrase,
+                    aindex,
+                    birth,
+                    7,
+                    false,
+                )?;
+                (account, true)
+            }
+        };
+        accounts.push(DiscoveredAccountT {
+            account,
+            aindex,
+            created,
+        });
+    }
+    db_tx.commit()?;
+    Ok(accounts)
+}
+
+async fn has_transparent_activity(
+    network: &Network,
+    client: &mut Client,
+    ak: &AccountKeys,
+    start: u32,
+    end: u32,
+) -> Result<bool> {
+    let Some(tvk) = ak.tvk.as_ref() else {
+        return Ok(false);
+    };
+    let mut addr_indices = (0..DISCOVERY_ADDRESSES).collect::<Vec<_>>();
+    if !addr_indices.contains(&ak.dindex) {
+        addr_indices.push(ak.dindex);
+    }
+    for addr_index in addr_indices {
+        let taddr = TransparentAccountInfo::derive_address(tvk, 0, addr_index);
+        let txs = lwd_retry!(get_transparent(
+            network, client, ak.aindex, 0, addr_index, taddr, start, end,
+        ))?;
+        if !txs.is_empty() {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Account indices of `keys` that received a note between
+/// `start` and `end`
+async fn probe_shielded(
+    network: &Network,
+    client: &mut Client,
+    keys: &[AccountKeys],
+    start: u32,
+    end: u32,
+) -> Result<BTreeSet<u32>> {
+    if keys.is_empty() || start > end {
+        return Ok(BTreeSet::new());
+    }
+    let sapling_ivks = keys
+        .iter()
+        .filter_map(|ak| ak.svk.as_ref().map(|svk| (ak.aindex, svk.fvk().vk.ivk())))
+        .collect::<Vec<_>>();
+    let orchard_ivks = keys
+        .iter()
+        .filter_map(|ak| {
+            ak.ovk
+                .as_ref()
+                .map(|ovk| (ak.aindex, ovk.to_ivk(Scope::External)))
+        })
+        .collect::<Vec<_>>();
+
+    let (mut sender, receiver) = std::sync::mpsc::channel();
+    let mut blocks = get_compact_block_range(client, start, end).await?;
+    while let Some(block) = blocks.message().await? {
+        let height = block.height as u32;
+        for (ivtx, vtx) in block.vtx.iter().enumerate() {
+            for (vout, o) in vtx.outputs.iter().enumerate() {
+                try_sapling_decrypt(
+                    network,
+                    &sapling_ivks,
+                    height,
+                    block.time,
+                    ivtx as u32,
+                    vout as u32,
+                    o,
+                    &mut sender,
+                )?;
+            }
+            for (vout, a) in vtx.actions.iter().enumerate() {
+                try_orchard_decrypt(
+                    network,
+                    &orchard_ivks,
+                    height,
+                    block.time,
+                    ivtx as u32,
+                    vout as u32,
+                    a,
+                    &mut sender,
+                )?;
+            }
+        }
+    }
+    drop(sender);
+    let found = receiver.iter().map(|n| n.account).collect();
+    Ok(found)
+}
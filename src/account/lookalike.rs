@@ -0,0 +1,102 @@
+use anyhow::Result;
+use rusqlite::{params, Connection};
+
+use crate::data::fb::{AddressWarning, AddressWarningT};
+use warp_macros::c_export;
+
+const PREFIX_LEN: usize = 6;
+const SUFFIX_LEN: usize = 6;
+const RECENT_TXS: u32 = 100;
+
+/*
+    Address poisoning: an attacker sends dust from an address
+    that starts and ends like one the user pays regularly, hoping the
+    user copies it from the transaction history.
+    We flag an address that shares its first and last characters
+    (after the HRP) with a contact or a recent counterparty but is
+    not the same address.
+*/
+
+/// Skip the HRP of bech32 addresses and the version of base58 addresses
+fn payload(address: &str) -> &str {
+    if ["t1", "t3", "tm", "t2"].iter().any(|p| address.starts_with(p)) {
+        return address.get(2..).unwrap_or_default();
+    }
+    // '1' is not in the bech32 charset, the last one is the separator
+    match address.rfind('1') {
+        Some(i) => &address[i + 1..],
+        None => address,
+    }
+}
+
+fn is_lookalike(a: &str, b: &str) -> bool {
+    if a == b {
+        return false;
+    }
+    let (a, b) = (payload(a), payload(b));
+    // compare chars, the address may come from a user and not be ASCII
+    let (a, b) = (a.chars().collect::<Vec<_>>(), b.chars().collect::<Vec<_>>());
+    if a.len() < PREFIX_LEN + SUFFIX_LEN || b.len() < PREFIX_LEN + SUFFIX_LEN {
+        return false;
+    }
+    a[..PREFIX_LEN] == b[..PREFIX_LEN] && a[a.len() - SUFFIX_LEN..] == b[b.len() - SUFFIX_LEN..]
+}
+
+#[c_export]
+pub fn check_lookalike_address(
+    connection: &Connection,
+    account: u32,
+    address: &str,
+) -> Result<Vec<AddressWarningT>> {
+    if is_trusted_address(connection, account, address)? {
+        return Ok(vec![]);
+    }
+    let mut warnings = vec![];
+    let mut s = connection.prepare("SELECT address FROM contacts WHERE account = ?1")?;
+    let rows = s.query_map([account], |r| r.get::<_, String>(0))?;
+    for r in rows {
+        let contact = r?;
+        if is_lookalike(address, &contact) {
+            warnings.push(AddressWarningT {
+                address: Some(address.to_string()),
+                similar: Some(contact),
+                source: Some("contact".to_string()),
+            });
+        }
+    }
+    let mut s = connection.prepare(
+        "SELECT DISTINCT address FROM
+        (SELECT address FROM txs WHERE account = ?1 AND address IS NOT NULL
+        ORDER BY height DESC LIMIT ?2)",
+    )?;
+    let rows = s.query_map(params![account, RECENT_TXS], |r| r.get::<_, String>(0))?;
+    for r in rows {
+        let counterparty = r?;
+        if is_lookalike(address, &counterparty) {
+            warnings.push(AddressWarningT {
+                address: Some(address.to_string()),
+                similar: Some(counterparty),
+                source: Some("tx".to_string()),
+            });
+        }
+    }
+    Ok(warnings)
+}
+
+/// Let the user confirm that an address flagged as a lookalike is legitimate
+#[c_export]
+pub fn trust_address(connection: &Connection, account: u32, address: &str) -> Result<()> {
+    connection.execute(
+        "INSERT INTO trusted_addresses(account, address)
+        VALUES (?1, ?2) ON CONFLICT DO NOTHING",
+        params![account, address],
+    )?;
+    Ok(())
+}
+
+pub fn is_trusted_address(connection: &Connection, account: u32, address: &str) -> Result<bool> {
+    let mut s = connection
+        .prepare("SELECT 1 FROM trusted_addresses WHERE account = ?1 AND address = ?2")?;
+    let trusted = s.exists(params![account, address])?;
+    Ok(trusted)
+}
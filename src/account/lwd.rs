@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use rusqlite::{params, Connection};
+
+use crate::{
+    coin::{connect_lwd, CoinDef},
+    db::{
+        account::{get_account_property, set_account_property},
+        api_keys::{check_access, Operation},
+    },
+    Client,
+};
+use warp_macros::c_export;
+
+const LWD_URL_PROP: &str = "lwd_url";
+
+/*
+    Per account server
+
+    An account can use its own lightwalletd server, for example to
+    avoid linking accounts that belong to different identities.
+    The server of the account is used by the operations that query
+    the server about this account only: the scan of the transparent
+    addresses and the retrieval of the transaction details.
+
+    The sync downloads the blocks of every account at once, from the
+    server of the wallet, and so does the mempool. The server of
+    the wallet learns nothing about the accounts from the compact
+    blocks, but it is still used for the broadcast of transactions.
+*/
+
+#[c_export]
+pub fn set_account_lwd_url(
+    connection: &Connection,
+    session: u32,
+    account: u32,
+    url: &str,
+) -> Result<()> {
+    check_access(connection, session, &Operation::Admin)?;
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        anyhow::bail!("Invalid server url {}", url);
+    }
+    set_account_property(connection, account, LWD_URL_PROP, url.as_bytes())
+}
+
+/// Server of the account, or an empty string if the account
+/// uses the server of the wallet
+#[c_export]
+pub fn get_account_lwd_url(connection: &Connection, account: u32) -> Result<String> {
+    let value = get_account_property(connection, account, LWD_URL_PROP)?;
+    let url = String::from_utf8(value)?;
+    Ok(url)
+}
+
+#[c_export]
+pub fn clear_account_lwd_url(connection: &Connection, session: u32, account: u32) -> Result<()> {
+    check_access(connection, session, &Operation::Admin)?;
+    connection.execute(
+        "DELETE FROM props WHERE account = ?1 AND name = ?2",
+        params![account, LWD_URL_PROP],
+    )?;
+    Ok(())
+}
+
+/// Client of the server of the account
+pub async fn connect_account_lwd(
+    coin: &CoinDef,
+    connection: &Connection,
+    account: u32,
+) -> Result<Client> {
+    let url = get_account_lwd_url(connection, account)?;
+    connect_url(coin, &url).await
+}
+
+async fn connect_url(coin: &CoinDef, url: &str) -> Result<Client> {
+    if url.is_empty() {
+        coin.connect_lwd()
+    } else {
+        connect_lwd(url).await
+    }
+}
+
+/// Clients of the servers of several accounts, connected on first use
+pub struct AccountClients<'a> {
+    coin: &'a CoinDef,
+    clients: HashMap<String, Client>,
+}
+
+impl<'a> AccountClients<'a> {
+    pub fn new(coin: &'a CoinDef) -> Self {
+        Self {
+            coin,
+            clients: HashMap::new(),
+        }
+    }
+
+    /// `url` is the server of the account (see `get_account_lwd_url`)
+    pub async fn get(&mut self, url: String) -> Result<&mut Client> {
+        if !self.clients.contains_key(&url) {
+            let client = connect_url(self.coin, &url).await?;
+            self.clients.insert(url.clone(), client);
+        }
+        Ok(self.clients.get_mut(&url).unwrap())
+    }
+}
@@ -0,0 +1,240 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+use rand::rngs::OsRng;
+use rusqlite::{params, Connection, OptionalExtension as _};
+
+use crate::{
+    account::address::get_diversified_address,
+    coin::CoinDef,
+    data::fb::{OutgoingMessage, OutgoingMessageT, PaymentRequestT, RecipientT, UserMemoT},
+    db::{
+        api_keys::{check_access, Operation},
+        chain::{get_sync_height, SNAP_ACCOUNT_DEFAULT},
+        messages::get_message,
+    },
+    types::PoolMask,
+    utils::pay::{broadcast_tx, plan_payment, PaymentOptions},
+    EXPIRATION_HEIGHT_DELTA,
+};
+use warp_macros::c_export;
+
+pub const MSG_DRAFT: u8 = 0;
+pub const MSG_QUEUED: u8 = 1;
+pub const MSG_SENT: u8 = 2;
+
+/*
+    Outbox
+
+    Messages that are not attached to a payment. They are written
+    as drafts, queued when they are ready, and all the queued
+    messages of an account are sent together in a single transaction
+    of zero value outputs (it only pays the fees).
+
+    The memos use the same format as the messages of the payments:
+    the subject and our address as the reply-to. Once the transaction
+    is mined, the sync finds them like any other outgoing message, and
+    they belong to the thread of their subject in the message
+    navigation. A reply keeps the subject of the original message.
+*/
+
+/// New draft to a contact of the account. Returns its id
+#[c_export]
+pub fn compose_message(
+    connection: &Connection,
+    account: u32,
+    contact: u32,
+    subject: &str,
+    body: &str,
+) -> Result<u32> {
+    let address = connection
+        .query_row(
+            "SELECT address FROM contacts WHERE id_contact = ?1 AND account = ?2",
+            params![contact, account],
+            |r| r.get::<_, String>(0),
+        )
+        .optional()?
+        .ok_or(anyhow::anyhow!("Contact {} not found", contact))?;
+    store_draft(connection, account, Some(contact), &address, subject, body)
+}
+
+/// New draft that replies to the message `id_msg`, in the same thread
+#[c_export]
+pub fn compose_reply(connection: &Connection, id_msg: u32, body: &str) -> Result<u32> {
+    let msg = get_message(connection, id_msg)?;
+    let memo = msg.memo.unwrap_or_default();
+    let address = memo
+        .sender
+        .filter(|s| !s.is_empty())
+        .ok_or(anyhow::anyhow!("Message {} has no reply address", id_msg))?;
+    let subject = memo.subject.unwrap_or_default();
+    store_draft(connection, msg.account, None, &address, &subject, body)
+}
+
+fn store_draft(
+    connection: &Connection,
+    account: u32,
+    contact: Option<u32>,
+    recipient: &str,
+    subject: &str,
+    body: &str,
+) -> Result<u32> {
+    let id = connection.query_row(
+        "INSERT INTO outbox(account, contact, recipient, subject, body, status, timestamp)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7) RETURNING id_outbox",
+        params![account, contact, recipient, subject, body, MSG_DRAFT, now()],
+        |r| r.get::<_, u32>(0),
+    )?;
+    Ok(id)
+}
+
+#[c_export]
+pub fn edit_draft(connection: &Connection, id: u32, subject: &str, body: &str) -> Result<()> {
+    let updated = connection.execute(
+        "UPDATE outbox SET subject = ?2, body = ?3, timestamp = ?4
+        WHERE id_outbox = ?1 AND status = ?5",
+        params![id, subject, body, now(), MSG_DRAFT],
+    )?;
+    if updated == 0 {
+        anyhow::bail!("Draft {} not found", id);
+    }
+    Ok(())
+}
+
+/// Queue a draft for the next send, or put it back in the drafts
+#[c_export]
+pub fn queue_message(connection: &Connection, id: u32, queued: bool) -> Result<()> {
+    let (from, to) = if queued {
+        (MSG_DRAFT, MSG_QUEUED)
+    } else {
+        (MSG_QUEUED, MSG_DRAFT)
+    };
+    let updated = connection.execute(
+        "UPDATE outbox SET status = ?3 WHERE id_outbox = ?1 AND status = ?2",
+        params![id, from, to],
+    )?;
+    if updated == 0 {
+        anyhow::bail!("Message {} cannot be changed", id);
+    }
+    Ok(())
+}
+
+#[c_export]
+pub fn delete_outgoing_message(connection: &Connection, id: u32) -> Result<()> {
+    connection.execute("DELETE FROM outbox WHERE id_outbox = ?1", [id])?;
+    Ok(())
+}
+
+/// Drafts, queued and sent messages of the account, newest first
+#[c_export]
+pub fn list_outbox(connection: &Connection, account: u32) -> Result<Vec<OutgoingMessageT>> {
+    list_outgoing_messages(connection, account, None)
+}
+
+fn list_outgoing_messages(
+    connection: &Connection,
+    account: u32,
+    status: Option<u8>,
+) -> Result<Vec<OutgoingMessageT>> {
+    let mut s = connection.prepare(
+        "SELECT id_outbox, contact, recipient, subject, body, status, timestamp, txid
+        FROM outbox WHERE account = ?1 AND (?2 IS NULL OR status = ?2)
+        ORDER BY id_outbox DESC",
+    )?;
+    let rows = s.query_map(params![account, status], |r| {
+        Ok(OutgoingMessageT {
+            id: r.get(0)?,
+            account,
+            contact: r.get::<_, Option<u32>>(1)?.unwrap_or_default(),
+            recipient: Some(r.get::<_, String>(2)?),
+            subject: Some(r.get::<_, String>(3)?),
+            body: Some(r.get::<_, String>(4)?),
+            status: r.get(5)?,
+            timestamp: r.get(6)?,
+            txid: r.get::<_, Option<Vec<u8>>>(7)?,
+        })
+    })?;
+    let msgs = rows.collect::<Result<Vec<_>, _>>()?;
+    Ok(msgs)
+}
+
+/// Send the queued messages of the account in one transaction.
+/// Returns the txid or an empty string if there was nothing to send
+#[c_export]
+pub async fn send_queued_messages(coin: &CoinDef, session: u32, account: u32) -> Result<String> {
+    let network = &coin.network;
+    let connection = coin.connection()?;
+    let connection = &connection;
+    check_access(connection, session, &Operation::Pay { account, amount: 0 })?;
+    let msgs = list_outgoing_messages(connection, account, Some(MSG_QUEUED))?;
+    if msgs.is_empty() {
+        return Ok(String::new());
+    }
+    let sender = get_diversified_address(network, connection, account, 0, PoolMask(6))?;
+    let recipients = msgs
+        .iter()
+        .map(|m| {
+            let memo = UserMemoT {
+                reply_to: sender.is_some(),
+                sender: sender.clone(),
+                recipient: m.recipient.clone(),
+                subject: m.subject.clone(),
+                body: m.body.clone(),
+            };
+            RecipientT {
+                address: m.recipient.clone(),
+                amount: 0,
+                pools: 6,
+                memo: Some(Box::new(memo)),
+                memo_bytes: None,
+                src_pools: 0,
+            }
+            .normalize_memo()
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut client = coin.connect_lwd()?;
+    let height = get_sync_height(connection)?.height;
+    let payment = PaymentRequestT {
+        recipients: Some(recipients),
+        src_pools: 7,
+        sender_pay_fees: true,
+        use_change: true,
+        height,
+        expiration: 0,
+        decoy_outputs: 0,
+        from_address: None,
+        allow_high_fee: false,
+        change_outputs: 0,
+        allow_change_address: false,
+        allow_lookalike_address: false,
+    };
+    let utx = plan_payment(
+        network,
+        connection,
+        &mut client,
+        account,
+        &payment,
+        &PaymentOptions::new(coin, SNAP_ACCOUNT_DEFAULT, ""),
+    )
+    .await?;
+    let txb = utx.build(network, connection, height + EXPIRATION_HEIGHT_DELTA, OsRng)?;
+    let txid = broadcast_tx(network, connection, &mut client, &txb).await?;
+
+    // stored in the byte order of the other tables
+    let mut txid_bytes = hex::decode(&txid)?;
+    txid_bytes.reverse();
+    let mut s = connection
+        .prepare("UPDATE outbox SET status = ?2, txid = ?3, timestamp = ?4 WHERE id_outbox = ?1")?;
+    for m in msgs.iter() {
+        s.execute(params![m.id, MSG_SENT, txid_bytes, now()])?;
+    }
+    Ok(txid)
+}
+
+fn now() -> u32 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as u32)
+        .unwrap_or_default()
+}
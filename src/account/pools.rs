@@ -12,7 +12,7 @@ use crate::{
     EXPIRATION_HEIGHT_DELTA,
 };
 
-pub fn transfer_pools<R: RngCore + CryptoRng>(
+pub fn transfer_pools<R: RngCore + CryptoRng + Send>(
     network: &Network,
     connection: &Connection,
     account: u32,
@@ -44,6 +44,7 @@ pub fn transfer_pools<R: RngCore + CryptoRng>(
             pools: 7,
             memo: None,
             memo_bytes: memo.clone(),
+            src_pools: 0,
         };
         recipients.push(p);
         amount -= a;
@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use rand::{rngs::OsRng, seq::index::sample};
+use rusqlite::{Connection, OptionalExtension as _};
+
+use crate::{
+    data::fb::{SeedChallenge, SeedChallengeT},
+    db::{
+        account_manager::{set_backup_reminder, split_stored_seed_phrase},
+        api_keys::{check_access, Operation},
+    },
+};
+use warp_macros::c_export;
+
+/*
+    Seed phrase verification
+
+    After the user has written down the seed phrase, the wallet asks
+    for a few of its words, picked at random, to check the backup.
+    The challenge only has the word positions and the answer is
+    checked here, so the phrase never goes through the FFI.
+
+    A challenge can be answered once: a wrong answer needs a new
+    challenge with other positions. A correct answer clears the
+    backup reminder of the account.
+*/
+
+const CHALLENGE_WORDS: usize = 3;
+
+lazy_static! {
+    static ref CHALLENGES: Mutex<HashMap<u32, Vec<u32>>> = Mutex::new(HashMap::new());
+}
+
+/// Pick random word positions (starting at 1) of the seed phrase of the account
+#[c_export]
+pub fn get_seed_verification_challenge(
+    connection: &Connection,
+    session: u32,
+    account: u32,
+) -> Result<SeedChallengeT> {
+    check_access(connection, session, &Operation::Admin)?;
+    let words = get_seed_words(connection, account)?;
+    let mut positions = sample(&mut OsRng, words.len(), CHALLENGE_WORDS.min(words.len()))
+        .into_iter()
+        .map(|i| i as u32 + 1)
+        .collect::<Vec<_>>();
+    positions.sort();
+    CHALLENGES.lock().insert(account, positions.clone());
+    Ok(SeedChallengeT {
+        account,
+        positions: Some(positions),
+    })
+}
+
+/// Check the words of the last challenge, separated by spaces
+/// and in the order of the positions
+#[c_export]
+pub fn verify_seed_answer(connection: &Connection, account: u32, answers: &str) -> Result<bool> {
+    let positions = CHALLENGES
+        .lock()
+        .remove(&account)
+        .ok_or(anyhow::anyhow!("No seed challenge for account {}", account))?;
+    let words = get_seed_words(connection, account)?;
+    let answers = answers.split_whitespace().collect::<Vec<_>>();
+    let correct = answers.len() == positions.len()
+        && positions
+            .iter()
+            .zip(answers.iter())
+            .all(|(p, a)| words[*p as usize - 1].eq_ignore_ascii_case(a));
+    if correct {
+        set_backup_reminder(connection, account, true)?;
+    }
+    Ok(correct)
+}
+
+fn get_seed_words(connection: &Connection, account: u32) -> Result<Vec<String>> {
+    let seed = connection
+        .query_row(
+            "SELECT seed FROM accounts WHERE id_account = ?1",
+            [account],
+            |r| r.get::<_, Option<String>>(0),
+        )
+        .optional()?
+        .ok_or(anyhow::anyhow!("No account {}", account))?
+        .ok_or(anyhow::anyhow!("Account {} has no seed", account))?;
+    // the legacy extra word is the passphrase, not a word of the phrase
+    let (seed, _) = split_stored_seed_phrase(&seed);
+    let words = seed
+        .split_whitespace()
+        .map(|w| w.to_string())
+        .collect::<Vec<_>>();
+    Ok(words)
+}
@@ -1,4 +1,14 @@
-use crate::{data::fb::TransactionInfoT, db::tx::list_txs};
+use crate::{
+    data::fb::TransactionInfoT,
+    db::{
+        account_groups::list_group_accounts,
+        api_keys::{check_access, Operation},
+        contacts::address_to_bytes,
+        tx::{list_txs, list_txs_with_contact, list_txs_with_receiver},
+    },
+    network::Network,
+    warp::sync::ExtendedReceivedTx,
+};
 use anyhow::Result;
 use rusqlite::Connection;
 
@@ -7,10 +17,66 @@ use warp_macros::c_export;
 #[c_export]
 pub fn get_txs(
     connection: &Connection,
+    session: u32,
     account: u32,
     bc_height: u32,
 ) -> Result<Vec<TransactionInfoT>> {
+    check_access(connection, session, &Operation::Read { account })?;
     let txs = list_txs(connection, account)?;
+    Ok(to_transaction_infos(txs, bc_height))
+}
+
+/// Transactions with this address as a counterparty, through
+/// any of its receivers
+#[c_export]
+pub fn get_txs_with_address(
+    network: &Network,
+    connection: &Connection,
+    session: u32,
+    account: u32,
+    address: &str,
+    bc_height: u32,
+) -> Result<Vec<TransactionInfoT>> {
+    check_access(connection, session, &Operation::Read { account })?;
+    let receiver = address_to_bytes(network, address)?;
+    let txs = list_txs_with_receiver(connection, account, &receiver)?;
+    Ok(to_transaction_infos(txs, bc_height))
+}
+
+/// Transactions with a contact as a counterparty
+#[c_export]
+pub fn get_txs_with_contact(
+    connection: &Connection,
+    session: u32,
+    account: u32,
+    contact: u32,
+    bc_height: u32,
+) -> Result<Vec<TransactionInfoT>> {
+    check_access(connection, session, &Operation::Read { account })?;
+    let txs = list_txs_with_contact(connection, account, contact)?;
+    Ok(to_transaction_infos(txs, bc_height))
+}
+
+/// Transactions of the accounts of an account group,
+/// the most recent first
+#[c_export]
+pub fn get_group_txs(
+    connection: &Connection,
+    session: u32,
+    group: u32,
+    bc_height: u32,
+) -> Result<Vec<TransactionInfoT>> {
+    let mut tis = vec![];
+    for account in list_group_accounts(connection, group)? {
+        check_access(connection, session, &Operation::Read { account })?;
+        let txs = list_txs(connection, account)?;
+        tis.extend(to_transaction_infos(txs, bc_height));
+    }
+    tis.sort_by(|a, b| (b.height, b.id).cmp(&(a.height, a.id)));
+    Ok(tis)
+}
+
+fn to_transaction_infos(txs: Vec<ExtendedReceivedTx>, bc_height: u32) -> Vec<TransactionInfoT> {
     let mut tis = vec![];
     for ertx in txs {
         let rtx = &ertx.rtx;
@@ -24,8 +90,10 @@ pub fn get_txs(
             address: ertx.address,
             contact: ertx.contact,
             memo: ertx.memo,
+            transfer: ertx.transfer.unwrap_or_default(),
+            account: rtx.account,
         };
         tis.push(ti);
     }
-    Ok(tis)
+    tis
 }
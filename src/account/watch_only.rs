@@ -0,0 +1,100 @@
+use anyhow::Result;
+use rusqlite::{params, Connection, OptionalExtension as _};
+use zcash_client_backend::encoding::AddressCodec as _;
+use zcash_primitives::legacy::TransparentAddress;
+
+use crate::{
+    db::{account_manager::store_transparent_address, notes::update_account_balances},
+    network::Network,
+};
+use warp_macros::c_export;
+
+/// Value of `external` in t_addresses and utxos for the
+/// watch-only addresses. `addr_index` only numbers them
+pub const WATCH_ONLY_SCOPE: u32 = 2;
+
+/*
+    Watch-only transparent addresses
+
+    Bare transparent addresses, for example of a cold storage, can be
+    added to an account to follow their funds. They have no keys and
+    no derivation path: they are stored in t_addresses without a
+    secret key, in their own scope so that they never collide with
+    the derived addresses (0 = receive, 1 = change).
+
+    The transparent sync tracks them like any other address of the
+    account and their history starts at the next scan of the account.
+    Their UTXOs are in the transaction history but they are
+    never selected for a payment, and the balance reports
+    them separately as `watch_only`.
+*/
+
+/// Returns the index of the address in the watch-only scope
+#[c_export]
+pub fn add_watch_only_address(
+    network: &Network,
+    connection: &Connection,
+    account: u32,
+    address: &str,
+) -> Result<u32> {
+    if TransparentAddress::decode(network, address).is_err() {
+        anyhow::bail!("Invalid transparent address {}", address);
+    }
+    let exists = connection.query_row(
+        "SELECT EXISTS(SELECT 1 FROM t_addresses WHERE account = ?1 AND address = ?2)",
+        params![account, address],
+        |r| r.get::<_, bool>(0),
+    )?;
+    if exists {
+        anyhow::bail!("Address {} is already in account {}", address, account);
+    }
+    let addr_index = connection.query_row(
+        "SELECT COALESCE(MAX(addr_index) + 1, 0) FROM t_addresses
+        WHERE account = ?1 AND external = ?2",
+        params![account, WATCH_ONLY_SCOPE],
+        |r| r.get::<_, u32>(0),
+    )?;
+    store_transparent_address(
+        connection,
+        account,
+        WATCH_ONLY_SCOPE,
+        addr_index,
+        None,
+        Some(address.to_string()),
+    )?;
+    Ok(addr_index)
+}
+
+/// Remove the address and the UTXOs that it received
+#[c_export]
+pub fn remove_watch_only_address(
+    connection: &mut Connection,
+    account: u32,
+    address: &str,
+) -> Result<()> {
+    let db_tx = connection.transaction()?;
+    let addr_index = db_tx
+        .query_row(
+            "SELECT addr_index FROM t_addresses
+            WHERE account = ?1 AND external = ?2 AND address = ?3",
+            params![account, WATCH_ONLY_SCOPE, address],
+            |r| r.get::<_, u32>(0),
+        )
+        .optional()?
+        .ok_or(anyhow::anyhow!("Watch-only address {} not found", address))?;
+    db_tx.execute(
+        "DELETE FROM utxos WHERE account = ?1 AND external = ?2 AND addr_index = ?3",
+        params![account, WATCH_ONLY_SCOPE, addr_index],
+    )?;
+    db_tx.execute(
+        "DELETE FROM t_addresses WHERE account = ?1 AND external = ?2 AND addr_index = ?3",
+        params![account, WATCH_ONLY_SCOPE, addr_index],
+    )?;
+    db_tx.execute(
+        "DELETE FROM t_scan_progress WHERE account = ?1 AND address = ?2",
+        params![account, address],
+    )?;
+    update_account_balances(&db_tx)?;
+    db_tx.commit()?;
+    Ok(())
+}
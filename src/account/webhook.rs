@@ -0,0 +1,349 @@
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Result;
+use hmac::{Hmac, Mac};
+use rusqlite::{params, Connection};
+use serde::Serialize;
+use sha2::Sha256;
+
+use crate::{
+    coin::CoinDef,
+    data::fb::{Webhook, WebhookT},
+    db::{
+        account::{get_account_property, list_accounts, set_account_property},
+        api_keys::{check_access, Operation},
+    },
+    utils::to_txid_str,
+    Hash,
+};
+use warp_macros::c_export;
+
+const WEBHOOK_PROP: &str = "webhook";
+const WEBHOOK_HEIGHT_PROP: &str = "webhook_height";
+/// Longest delay between two attempts to deliver an event
+const MAX_RETRY_DELAY: u64 = 3600;
+
+static DELIVERING: AtomicBool = AtomicBool::new(false);
+
+#[derive(Serialize, Debug)]
+pub struct WebhookEvent {
+    pub event: &'static str,
+    pub account: u32,
+    pub txid: String,
+    pub height: u32,
+    pub value: i64,
+    pub confirmations: u32,
+}
+
+#[c_export]
+pub fn set_webhook(
+    connection: &Connection,
+    session: u32,
+    account: u32,
+    webhook: &WebhookT,
+) -> Result<()> {
+    check_access(connection, session, &Operation::Admin)?;
+    let value = serde_json::to_vec(webhook)?;
+    set_account_property(connection, account, WEBHOOK_PROP, &value)?;
+    Ok(())
+}
+
+#[c_export]
+pub fn get_webhook(connection: &Connection, account: u32) -> Result<WebhookT> {
+    let value = get_account_property(connection, account, WEBHOOK_PROP)?;
+    if value.is_empty() {
+        return Ok(WebhookT::default());
+    }
+    let webhook = serde_json::from_slice::<WebhookT>(&value)?;
+    Ok(webhook)
+}
+
+#[c_export]
+pub fn clear_webhook(connection: &Connection, session: u32, account: u32) -> Result<()> {
+    check_access(connection, session, &Operation::Admin)?;
+    connection.execute(
+        "DELETE FROM props WHERE account = ?1 AND name IN (?2, ?3)",
+        params![account, WEBHOOK_PROP, WEBHOOK_HEIGHT_PROP],
+    )?;
+    connection.execute("DELETE FROM webhook_queue WHERE account = ?1", [account])?;
+    Ok(())
+}
+
+/*
+    Called in the db transaction of each sync batch.
+    For every account with a webhook, queue the incoming transactions
+    mined since the last notification ("received") and the ones that
+    reached the configured number of confirmations ("confirmed"),
+    and the broadcast transactions that expired unmined ("expired").
+
+    The events are signed and stored in `webhook_queue` with the
+    last notified height and the data of the batch, then, once the
+    batch is committed, `deliver_webhooks` posts them in the background,
+    so that a slow or unreachable endpoint does not hold the sync.
+    A rewind brings the last notified height back to the rewind height,
+    and the events of the dropped blocks are queued again when they
+    are synced again. An event stays in the queue until it is delivered,
+    with a delay between attempts that doubles up to MAX_RETRY_DELAY,
+    so events are delivered at least once, and in order for each
+    account.
+*/
+pub fn dispatch_webhooks(coin: &CoinDef, connection: &Connection, height: u32) -> Result<()> {
+    let accounts = list_accounts(coin, connection)?;
+    for a in accounts.items.iter().flatten() {
+        let webhook = get_webhook(connection, a.id)?;
+        let Some(url) = webhook.url.as_deref().filter(|u| !u.is_empty()) else {
+            continue;
+        };
+        let last_height = get_webhook_height(connection, a.id)?;
+        if last_height == 0 {
+            // do not replay the history of the account
+            set_webhook_height(connection, a.id, height)?;
+            continue;
+        }
+        if height <= last_height {
+            continue;
+        }
+        let events = list_webhook_events(
+            connection,
+            a.id,
+            last_height,
+            height,
+            webhook.confirmations.max(1),
+        )?;
+        let secret = webhook.secret.as_deref().unwrap_or_default();
+        for event in events.iter() {
+            queue_event(connection, url, secret, event)?;
+        }
+        set_webhook_height(connection, a.id, height)?;
+    }
+    Ok(())
+}
+
+/// Bring the last notified height of the webhooks back to `height`
+/// after a rewind. 0 does not replay the history of the accounts
+pub fn rewind_webhooks(connection: &Connection, height: u32) -> Result<()> {
+    let mut s = connection.prepare("SELECT account, value FROM props WHERE name = ?1")?;
+    let rows = s.query_map([WEBHOOK_HEIGHT_PROP], |r| {
+        Ok((r.get::<_, u32>(0)?, r.get::<_, Vec<u8>>(1)?))
+    })?;
+    let heights = rows.collect::<Result<Vec<_>, _>>()?;
+    for (account, value) in heights {
+        let last_height = value.try_into().map(u32::from_le_bytes).unwrap_or_default();
+        if last_height > height {
+            set_webhook_height(connection, account, height)?;
+        }
+    }
+    Ok(())
+}
+
+fn list_webhook_events(
+    connection: &Connection,
+    account: u32,
+    from_height: u32,
+    to_height: u32,
+    confirmations: u32,
+) -> Result<Vec<WebhookEvent>> {
+    let mut s = connection.prepare(
+        "SELECT txid, height, value FROM txs
+        WHERE account = ?1 AND value > 0
+        AND ((height > ?2 AND height <= ?3)
+        OR (height + ?4 - 1 > ?2 AND height + ?4 - 1 <= ?3))
+        ORDER BY height",
+    )?;
+    let rows = s.query_map(
+        params![account, from_height, to_height, confirmations],
+        |r| {
+            Ok((
+                r.get::<_, Hash>(0)?,
+                r.get::<_, u32>(1)?,
+                r.get::<_, i64>(2)?,
+            ))
+        },
+    )?;
+    let mut events = vec![];
+    for r in rows {
+        let (txid, height, value) = r?;
+        let txid = to_txid_str(&txid);
+        let conf_height = height + confirmations - 1;
+        if height > from_height {
+            events.push(WebhookEvent {
+                event: "received",
+                account,
+                txid: txid.clone(),
+                height,
+                value,
+                confirmations: to_height - height + 1,
+            });
+        }
+        if conf_height > from_height && conf_height <= to_height {
+            events.push(WebhookEvent {
+                event: "confirmed",
+                account,
+                txid,
+                height,
+                value,
+                confirmations,
+            });
+        }
+    }
+
+    // the value of an unmined transaction is unknown
+    let mut s = connection.prepare(
+        "SELECT txid, height FROM broadcast_txs
+        WHERE account = ?1 AND expired_height > ?2 AND expired_height <= ?3",
+    )?;
+    let rows = s.query_map(params![account, from_height, to_height], |r| {
+        Ok((r.get::<_, Hash>(0)?, r.get::<_, u32>(1)?))
+    })?;
+    for r in rows {
+        let (txid, height) = r?;
+        events.push(WebhookEvent {
+            event: "expired",
+            account,
+            txid: to_txid_str(&txid),
+            height,
+            value: 0,
+            confirmations: 0,
+        });
+    }
+    Ok(events)
+}
+
+fn queue_event(
+    connection: &Connection,
+    url: &str,
+    secret: &str,
+    event: &WebhookEvent,
+) -> Result<()> {
+    let body = serde_json::to_vec(event)?;
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())?;
+    mac.update(&body);
+    let signature = hex::encode(mac.finalize().into_bytes());
+    connection.execute(
+        "INSERT INTO webhook_queue(account, url, body, signature, attempts, next_attempt)
+        VALUES (?1, ?2, ?3, ?4, 0, 0)",
+        params![event.account, url, body, signature],
+    )?;
+    Ok(())
+}
+
+/// Start the delivery of the queued events, unless it is running
+pub fn spawn_delivery(coin: &CoinDef) {
+    let Some(runtime) = coin.runtime.0.clone() else {
+        return;
+    };
+    if DELIVERING.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let coin = coin.clone();
+    runtime.spawn(async move {
+        if let Err(e) = deliver_webhooks(&coin).await {
+            tracing::warn!("Webhooks: {}", e);
+        }
+        DELIVERING.store(false, Ordering::SeqCst);
+    });
+}
+
+struct QueuedEvent {
+    id: u32,
+    account: u32,
+    url: String,
+    body: Vec<u8>,
+    signature: String,
+    attempts: u32,
+}
+
+/// Post the queued events that are due. After a failure, the next
+/// events of the account wait for the next attempt
+pub async fn deliver_webhooks(coin: &CoinDef) -> Result<()> {
+    let now = unix_time()?;
+    let events = {
+        let connection = coin.connection()?;
+        let mut s = connection.prepare(
+            "SELECT id_event, account, url, body, signature, attempts, next_attempt
+            FROM webhook_queue ORDER BY id_event",
+        )?;
+        let rows = s.query_map([], |r| {
+            Ok((
+                QueuedEvent {
+                    id: r.get(0)?,
+                    account: r.get(1)?,
+                    url: r.get(2)?,
+                    body: r.get(3)?,
+                    signature: r.get(4)?,
+                    attempts: r.get(5)?,
+                },
+                r.get::<_, u64>(6)?,
+            ))
+        })?;
+        rows.collect::<Result<Vec<_>, _>>()?
+    };
+
+    let client = reqwest::Client::new();
+    let mut blocked = vec![];
+    for (event, next_attempt) in events {
+        if blocked.contains(&event.account) {
+            continue;
+        }
+        if next_attempt > now {
+            blocked.push(event.account);
+            continue;
+        }
+        let res = post_event(&client, &event).await;
+        let connection = coin.connection()?;
+        match res {
+            Ok(()) => {
+                connection.execute("DELETE FROM webhook_queue WHERE id_event = ?1", [event.id])?;
+            }
+            Err(e) => {
+                tracing::warn!("Webhook {} failed: {}", event.url, e);
+                let delay = (1u64 << event.attempts.min(12)).min(MAX_RETRY_DELAY);
+                connection.execute(
+                    "UPDATE webhook_queue SET attempts = attempts + 1, next_attempt = ?2
+                    WHERE id_event = ?1",
+                    params![event.id, unix_time()? + delay],
+                )?;
+                blocked.push(event.account);
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn post_event(client: &reqwest::Client, event: &QueuedEvent) -> Result<()> {
+    client
+        .post(&event.url)
+        .header("Content-Type", "application/json")
+        .header("X-Warp-Signature", &event.signature)
+        .body(event.body.clone())
+        .timeout(Duration::from_secs(10))
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
+
+fn unix_time() -> Result<u64> {
+    Ok(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs())
+}
+
+fn get_webhook_height(connection: &Connection, account: u32) -> Result<u32> {
+    let value = get_account_property(connection, account, WEBHOOK_HEIGHT_PROP)?;
+    let height = value
+        .try_into()
+        .map(u32::from_le_bytes)
+        .unwrap_or_default();
+    Ok(height)
+}
+
+fn set_webhook_height(connection: &Connection, account: u32, height: u32) -> Result<()> {
+    set_account_property(
+        connection,
+        account,
+        WEBHOOK_HEIGHT_PROP,
+        &height.to_le_bytes(),
+    )
+}
@@ -0,0 +1,429 @@
+use anyhow::Result;
+use rusqlite::Connection;
+
+use crate::{
+    coin::CoinDef,
+    data::fb::{
+        BalanceT, PaymentRequestT, RecipientT, TransactionRecipientT, TransactionSummaryT,
+        UserMemoT,
+    },
+    db::chain::SNAP_ACCOUNT_DEFAULT,
+};
+
+pub mod json;
+
+/*
+    Rust API
+
+    The FFI exchanges the flatbuffer object types (`XxxT`). They are
+    generated, every field is public and most of them are options,
+    and they are `non_exhaustive`: another crate cannot build them with
+    a struct literal. The types of this module are the same data
+    for Rust applications, with `From` conversions both ways,
+    and a few entry points that take and return them.
+
+    The conversions from the flatbuffer types never fail. Missing
+    strings and lists become empty ones. The types of `json` are
+    the stable JSON documents of the same data.
+*/
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct Balance {
+    pub transparent: u64,
+    pub sapling: u64,
+    pub orchard: u64,
+    /// UTXOs of the watch-only addresses, not in the total
+    pub watch_only: u64,
+}
+
+impl Balance {
+    pub fn total(&self) -> u64 {
+        self.transparent + self.sapling + self.orchard
+    }
+}
+
+impl From<BalanceT> for Balance {
+    fn from(b: BalanceT) -> Self {
+        Self {
+            transparent: b.transparent,
+            sapling: b.sapling,
+            orchard: b.orchard,
+            watch_only: b.watch_only,
+        }
+    }
+}
+
+impl From<Balance> for BalanceT {
+    fn from(b: Balance) -> Self {
+        BalanceT {
+            transparent: b.transparent,
+            sapling: b.sapling,
+            orchard: b.orchard,
+            watch_only: b.watch_only,
+            assets: None,
+        }
+    }
+}
+
+/// Memo of an output
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Memo {
+    /// A message, with our address as the reply-to if `reply_to`
+    Text {
+        subject: String,
+        body: String,
+        reply_to: bool,
+    },
+    /// Raw memo bytes (up to 512)
+    Bytes(Vec<u8>),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Recipient {
+    pub address: String,
+    pub amount: u64,
+    /// Receivers of a unified address that can be used, bitmask of
+    /// transparent = 1, sapling = 2, orchard = 4
+    pub pools: u8,
+    pub memo: Option<Memo>,
+    /// Pools of the notes that can pay this recipient (same bitmask),
+    /// 0 for every pool of the payment
+    pub src_pools: u8,
+}
+
+impl Recipient {
+    pub fn new(address: &str, amount: u64) -> Self {
+        Self {
+            address: address.to_string(),
+            amount,
+            pools: 7,
+            memo: None,
+            src_pools: 0,
+        }
+    }
+
+    pub fn with_memo(mut self, memo: Memo) -> Self {
+        self.memo = Some(memo);
+        self
+    }
+}
+
+impl From<RecipientT> for Recipient {
+    fn from(r: RecipientT) -> Self {
+        let memo = match (r.memo, r.memo_bytes) {
+            (Some(memo), _) => Some(Memo::Text {
+                subject: memo.subject.unwrap_or_default(),
+                body: memo.body.unwrap_or_default(),
+                reply_to: memo.reply_to,
+            }),
+            (None, Some(bytes)) => Some(Memo::Bytes(bytes)),
+            (None, None) => None,
+        };
+        Self {
+            address: r.address.unwrap_or_default(),
+            amount: r.amount,
+            pools: r.pools,
+            memo,
+            src_pools: r.src_pools,
+        }
+    }
+}
+
+impl From<Recipient> for RecipientT {
+    fn from(r: Recipient) -> Self {
+        let (memo, memo_bytes) = match r.memo {
+            Some(Memo::Text {
+                subject,
+                body,
+                reply_to,
+            }) => {
+                let memo = UserMemoT {
+                    reply_to,
+                    sender: None,
+                    recipient: Some(r.address.clone()),
+                    subject: Some(subject),
+                    body: Some(body),
+                };
+                (Some(Box::new(memo)), None)
+            }
+            Some(Memo::Bytes(bytes)) => (None, Some(bytes)),
+            None => (None, None),
+        };
+        RecipientT {
+            address: Some(r.address),
+            amount: r.amount,
+            pools: r.pools,
+            memo,
+            memo_bytes,
+            src_pools: r.src_pools,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Payment {
+    pub recipients: Vec<Recipient>,
+    /// Pools of the notes that can be spent (same bitmask as `Recipient::pools`)
+    pub src_pools: u8,
+    /// If false, the recipients pay the fee
+    pub sender_pay_fees: bool,
+    pub use_change: bool,
+    /// Height of the payment, it is snapped to a checkpoint
+    pub height: u32,
+    pub expiration: u32,
+    /// Skip the fee limits of the config
+    pub allow_high_fee: bool,
+    /// Pay a change address of the wallet
+    pub allow_change_address: bool,
+    /// Pay an address that looks like a contact or a recent counterparty
+    pub allow_lookalike_address: bool,
+}
+
+impl Payment {
+    /// Payment from every pool, the sender pays the fees
+    pub fn new(recipients: Vec<Recipient>, height: u32) -> Self {
+        Self {
+            recipients,
+            src_pools: 7,
+            sender_pay_fees: true,
+            use_change: true,
+            height,
+            expiration: 0,
+            allow_high_fee: false,
+            allow_change_address: false,
+            allow_lookalike_address: false,
+        }
+    }
+}
+
+impl From<PaymentRequestT> for Payment {
+    fn from(p: PaymentRequestT) -> Self {
+        Self {
+            recipients: p
+                .recipients
+                .unwrap_or_default()
+                .into_iter()
+                .map(Recipient::from)
+                .collect(),
+            src_pools: p.src_pools,
+            sender_pay_fees: p.sender_pay_fees,
+            use_change: p.use_change,
+            height: p.height,
+            expiration: p.expiration,
+            allow_high_fee: p.allow_high_fee,
+            allow_change_address: p.allow_change_address,
+            allow_lookalike_address: p.allow_lookalike_address,
+        }
+    }
+}
+
+impl From<Payment> for PaymentRequestT {
+    fn from(p: Payment) -> Self {
+        PaymentRequestT {
+            recipients: Some(p.recipients.into_iter().map(RecipientT::from).collect()),
+            src_pools: p.src_pools,
+            sender_pay_fees: p.sender_pay_fees,
+            use_change: p.use_change,
+            height: p.height,
+            expiration: p.expiration,
+            decoy_outputs: 0,
+            from_address: None,
+            allow_high_fee: p.allow_high_fee,
+            change_outputs: 0,
+            allow_change_address: p.allow_change_address,
+            allow_lookalike_address: p.allow_lookalike_address,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PrivacyLevel {
+    /// Transparent inputs and outputs
+    Transparent,
+    /// Transparent inputs or outputs
+    PartiallyTransparent,
+    /// The value that moves between the shielded pools is more than the fee
+    CrossPool,
+    FullyShielded,
+}
+
+impl From<u8> for PrivacyLevel {
+    fn from(level: u8) -> Self {
+        match level {
+            0 => PrivacyLevel::Transparent,
+            1 => PrivacyLevel::PartiallyTransparent,
+            2 => PrivacyLevel::CrossPool,
+            _ => PrivacyLevel::FullyShielded,
+        }
+    }
+}
+
+impl From<PrivacyLevel> for u8 {
+    fn from(level: PrivacyLevel) -> Self {
+        match level {
+            PrivacyLevel::Transparent => 0,
+            PrivacyLevel::PartiallyTransparent => 1,
+            PrivacyLevel::CrossPool => 2,
+            PrivacyLevel::FullyShielded => 3,
+        }
+    }
+}
+
+/// Output of a transaction summary
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TxRecipient {
+    pub address: String,
+    pub amount: u64,
+    pub change: bool,
+    /// Zero value output with a memo
+    pub memo_only: bool,
+}
+
+impl From<TransactionRecipientT> for TxRecipient {
+    fn from(r: TransactionRecipientT) -> Self {
+        Self {
+            address: r.address.unwrap_or_default(),
+            amount: r.amount,
+            change: r.change,
+            memo_only: r.memo_only,
+        }
+    }
+}
+
+impl From<TxRecipient> for TransactionRecipientT {
+    fn from(r: TxRecipient) -> Self {
+        TransactionRecipientT {
+            address: Some(r.address),
+            amount: r.amount,
+            change: r.change,
+            memo_only: r.memo_only,
+        }
+    }
+}
+
+/// Summary of a prepared transaction. It converts back to
+/// the flatbuffer summary for the signature: `data` is the
+/// unsigned transaction
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TxSummary {
+    pub anchor_height: u32,
+    pub requested_height: u32,
+    pub snap_policy: u8,
+    pub recipients: Vec<TxRecipient>,
+    pub transparent_ins: u64,
+    pub sapling_net: i64,
+    pub orchard_net: i64,
+    pub fee: u64,
+    pub decoy_fee: u64,
+    pub privacy_level: PrivacyLevel,
+    /// By pool: transparent, sapling, orchard
+    pub num_inputs: [u8; 3],
+    pub num_outputs: [u8; 3],
+    pub orchard_actions: u32,
+    pub tx_size: u32,
+    pub message: Option<String>,
+    pub request_status: u8,
+    pub requester: Option<String>,
+    pub data: Vec<u8>,
+}
+
+fn to_pools(v: Option<Vec<u8>>) -> [u8; 3] {
+    let mut pools = [0u8; 3];
+    for (p, n) in pools.iter_mut().zip(v.unwrap_or_default()) {
+        *p = n;
+    }
+    pools
+}
+
+impl From<TransactionSummaryT> for TxSummary {
+    fn from(s: TransactionSummaryT) -> Self {
+        Self {
+            anchor_height: s.height,
+            requested_height: s.requested_height,
+            snap_policy: s.snap_policy,
+            recipients: s
+                .recipients
+                .unwrap_or_default()
+                .into_iter()
+                .map(TxRecipient::from)
+                .collect(),
+            transparent_ins: s.transparent_ins,
+            sapling_net: s.sapling_net,
+            orchard_net: s.orchard_net,
+            fee: s.fee,
+            decoy_fee: s.decoy_fee,
+            privacy_level: PrivacyLevel::from(s.privacy_level),
+            num_inputs: to_pools(s.num_inputs),
+            num_outputs: to_pools(s.num_outputs),
+            orchard_actions: s.orchard_actions,
+            tx_size: s.tx_size,
+            message: s.message,
+            request_status: s.request_status,
+            requester: s.requester,
+            data: s.data.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<TxSummary> for TransactionSummaryT {
+    fn from(s: TxSummary) -> Self {
+        TransactionSummaryT {
+            height: s.anchor_height,
+            recipients: Some(
+                s.recipients
+                    .into_iter()
+                    .map(TransactionRecipientT::from)
+                    .collect(),
+            ),
+            transparent_ins: s.transparent_ins,
+            sapling_net: s.sapling_net,
+            orchard_net: s.orchard_net,
+            fee: s.fee,
+            privacy_level: s.privacy_level.into(),
+            num_inputs: Some(s.num_inputs.to_vec()),
+            num_outputs: Some(s.num_outputs.to_vec()),
+            data: Some(s.data),
+            message: s.message,
+            decoy_fee: s.decoy_fee,
+            orchard_actions: s.orchard_actions,
+            tx_size: s.tx_size,
+            request_status: s.request_status,
+            requester: s.requester,
+            requested_height: s.requested_height,
+            snap_policy: s.snap_policy,
+        }
+    }
+}
+
+/// Balance of the account at `height`, with its default
+/// number of confirmations. `session` is the API session of
+/// the caller (see `db::api_keys`), 0 if it has none
+pub fn get_balance(
+    connection: &Connection,
+    session: u32,
+    account: u32,
+    height: u32,
+) -> Result<Balance> {
+    let balance = crate::db::account::get_balance(connection, session, account, height, 0)?;
+    Ok(balance.into())
+}
+
+/// Prepare a payment with the snap policy of the account
+pub async fn prepare_payment(
+    coin: &CoinDef,
+    session: u32,
+    account: u32,
+    payment: Payment,
+) -> Result<TxSummary> {
+    let payment = PaymentRequestT::from(payment);
+    let summary = crate::utils::pay::prepare_payment(
+        coin,
+        session,
+        account,
+        &payment,
+        "",
+        SNAP_ACCOUNT_DEFAULT,
+    )
+    .await?;
+    Ok(summary.into())
+}
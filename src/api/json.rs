@@ -0,0 +1,321 @@
+use anyhow::Result;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::data::fb::{BackupT, ShieldedMessageT, TransactionRecipientT, TransactionSummaryT};
+
+/*
+    Stable JSON
+
+    The flatbuffer object types derive Serialize with the names of
+    their fields, so their JSON changes with the schema and with the
+    generator. The JSON output of the CLI and the documents that
+    scripts keep (summaries, messages, backups) use the types below:
+    - every field has an explicit name that stays the same when the
+    Rust field is renamed,
+    - a new field has a default, so that an older document still
+    reads,
+    - renaming or removing a field, or changing its meaning,
+    increments SCHEMA_VERSION,
+    - the top level object has the `schema_version` of its writer.
+    Binary values are hex strings and amounts are in zatoshis.
+*/
+
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// A document with the version of its schema
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Versioned<T> {
+    #[serde(rename = "schema_version")]
+    pub schema_version: u32,
+    #[serde(flatten)]
+    pub data: T,
+}
+
+impl<T> Versioned<T> {
+    pub fn new(data: T) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            data,
+        }
+    }
+}
+
+/// Parse a document. It fails if it comes from a newer schema
+pub fn from_json<T: DeserializeOwned>(json: &str) -> Result<T> {
+    let doc: Versioned<T> = serde_json::from_str(json)?;
+    if doc.schema_version > SCHEMA_VERSION {
+        anyhow::bail!(
+            "Schema version {} is newer than {}",
+            doc.schema_version,
+            SCHEMA_VERSION
+        );
+    }
+    Ok(doc.data)
+}
+
+pub fn to_json<T: Serialize>(data: T) -> Result<String> {
+    Ok(serde_json::to_string_pretty(&Versioned::new(data))?)
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct RecipientJson {
+    #[serde(rename = "address")]
+    pub address: String,
+    #[serde(rename = "amount")]
+    pub amount: u64,
+    #[serde(rename = "change")]
+    pub change: bool,
+    #[serde(rename = "memo_only", default)]
+    pub memo_only: bool,
+}
+
+impl From<&TransactionRecipientT> for RecipientJson {
+    fn from(r: &TransactionRecipientT) -> Self {
+        Self {
+            address: r.address.clone().unwrap_or_default(),
+            amount: r.amount,
+            change: r.change,
+            memo_only: r.memo_only,
+        }
+    }
+}
+
+impl From<RecipientJson> for TransactionRecipientT {
+    fn from(r: RecipientJson) -> Self {
+        TransactionRecipientT {
+            address: Some(r.address),
+            amount: r.amount,
+            change: r.change,
+            memo_only: r.memo_only,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct TransactionSummaryJson {
+    #[serde(rename = "anchor_height")]
+    pub anchor_height: u32,
+    #[serde(rename = "requested_height", default)]
+    pub requested_height: u32,
+    #[serde(rename = "snap_policy", default)]
+    pub snap_policy: u8,
+    #[serde(rename = "recipients")]
+    pub recipients: Vec<RecipientJson>,
+    #[serde(rename = "transparent_ins")]
+    pub transparent_ins: u64,
+    #[serde(rename = "sapling_net")]
+    pub sapling_net: i64,
+    #[serde(rename = "orchard_net")]
+    pub orchard_net: i64,
+    #[serde(rename = "fee")]
+    pub fee: u64,
+    #[serde(rename = "decoy_fee", default)]
+    pub decoy_fee: u64,
+    /// 0 = transparent to 3 = fully shielded
+    #[serde(rename = "privacy_level")]
+    pub privacy_level: u8,
+    /// By pool: transparent, sapling, orchard
+    #[serde(rename = "num_inputs")]
+    pub num_inputs: Vec<u8>,
+    #[serde(rename = "num_outputs")]
+    pub num_outputs: Vec<u8>,
+    #[serde(rename = "orchard_actions", default)]
+    pub orchard_actions: u32,
+    #[serde(rename = "tx_size", default)]
+    pub tx_size: u32,
+    #[serde(rename = "message", default)]
+    pub message: Option<String>,
+    #[serde(rename = "request_status", default)]
+    pub request_status: u8,
+    #[serde(rename = "requester", default)]
+    pub requester: Option<String>,
+    /// The unsigned transaction, none once it is signed
+    #[serde(rename = "data", default)]
+    pub data: Option<String>,
+}
+
+impl From<&TransactionSummaryT> for TransactionSummaryJson {
+    fn from(s: &TransactionSummaryT) -> Self {
+        Self {
+            anchor_height: s.height,
+            requested_height: s.requested_height,
+            snap_policy: s.snap_policy,
+            recipients: s
+                .recipients
+                .iter()
+                .flatten()
+                .map(RecipientJson::from)
+                .collect(),
+            transparent_ins: s.transparent_ins,
+            sapling_net: s.sapling_net,
+            orchard_net: s.orchard_net,
+            fee: s.fee,
+            decoy_fee: s.decoy_fee,
+            privacy_level: s.privacy_level,
+            num_inputs: s.num_inputs.clone().unwrap_or_default(),
+            num_outputs: s.num_outputs.clone().unwrap_or_default(),
+            orchard_actions: s.orchard_actions,
+            tx_size: s.tx_size,
+            message: s.message.clone(),
+            request_status: s.request_status,
+            requester: s.requester.clone(),
+            data: s.data.as_ref().map(hex::encode),
+        }
+    }
+}
+
+impl TryFrom<TransactionSummaryJson> for TransactionSummaryT {
+    type Error = anyhow::Error;
+
+    fn try_from(s: TransactionSummaryJson) -> Result<Self> {
+        Ok(TransactionSummaryT {
+            height: s.anchor_height,
+            recipients: Some(
+                s.recipients
+                    .into_iter()
+                    .map(TransactionRecipientT::from)
+                    .collect(),
+            ),
+            transparent_ins: s.transparent_ins,
+            sapling_net: s.sapling_net,
+            orchard_net: s.orchard_net,
+            fee: s.fee,
+            privacy_level: s.privacy_level,
+            num_inputs: Some(s.num_inputs),
+            num_outputs: Some(s.num_outputs),
+            data: s.data.map(hex::decode).transpose()?,
+            message: s.message,
+            decoy_fee: s.decoy_fee,
+            orchard_actions: s.orchard_actions,
+            tx_size: s.tx_size,
+            request_status: s.request_status,
+            requester: s.requester,
+            requested_height: s.requested_height,
+            snap_policy: s.snap_policy,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct MessageJson {
+    #[serde(rename = "id")]
+    pub id: u32,
+    #[serde(rename = "account")]
+    pub account: u32,
+    #[serde(rename = "id_tx")]
+    pub id_tx: u32,
+    #[serde(rename = "txid")]
+    pub txid: String,
+    #[serde(rename = "height")]
+    pub height: u32,
+    #[serde(rename = "timestamp")]
+    pub timestamp: u32,
+    #[serde(rename = "incoming")]
+    pub incoming: bool,
+    #[serde(rename = "contact", default)]
+    pub contact: Option<String>,
+    #[serde(rename = "output_index")]
+    pub output_index: u32,
+    #[serde(rename = "sender", default)]
+    pub sender: Option<String>,
+    #[serde(rename = "recipient", default)]
+    pub recipient: Option<String>,
+    #[serde(rename = "subject", default)]
+    pub subject: String,
+    #[serde(rename = "body", default)]
+    pub body: String,
+    #[serde(rename = "reply_to", default)]
+    pub reply_to: bool,
+    #[serde(rename = "read", default)]
+    pub read: bool,
+}
+
+impl From<&ShieldedMessageT> for MessageJson {
+    fn from(m: &ShieldedMessageT) -> Self {
+        let memo = m.memo.as_deref().cloned().unwrap_or_default();
+        Self {
+            id: m.id_msg,
+            account: m.account,
+            id_tx: m.id_tx,
+            txid: m.txid.as_ref().map(hex::encode).unwrap_or_default(),
+            height: m.height,
+            timestamp: m.timestamp,
+            incoming: m.incoming,
+            contact: m.contact.clone(),
+            output_index: m.nout,
+            sender: memo.sender,
+            recipient: memo.recipient,
+            subject: memo.subject.unwrap_or_default(),
+            body: memo.body.unwrap_or_default(),
+            reply_to: memo.reply_to,
+            read: m.read,
+        }
+    }
+}
+
+/// The keys of an account, null for the keys that it does not have
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct BackupJson {
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "seed", default)]
+    pub seed: Option<String>,
+    #[serde(rename = "index", default)]
+    pub index: u32,
+    #[serde(rename = "sapling_sk", default)]
+    pub sk: Option<String>,
+    #[serde(rename = "sapling_fvk", default)]
+    pub fvk: Option<String>,
+    #[serde(rename = "ufvk", default)]
+    pub uvk: Option<String>,
+    #[serde(rename = "transparent_sk", default)]
+    pub tsk: Option<String>,
+    #[serde(rename = "transparent_xsk", default)]
+    pub txsk: Option<String>,
+    #[serde(rename = "transparent_xvk", default)]
+    pub tvk: Option<String>,
+    #[serde(rename = "transparent_address", default)]
+    pub taddr: Option<String>,
+    #[serde(rename = "birth")]
+    pub birth: u32,
+    #[serde(rename = "saved", default)]
+    pub saved: bool,
+}
+
+impl From<&BackupT> for BackupJson {
+    fn from(b: &BackupT) -> Self {
+        Self {
+            name: b.name.clone().unwrap_or_default(),
+            seed: b.seed.clone(),
+            index: b.index,
+            sk: b.sk.clone(),
+            fvk: b.fvk.clone(),
+            uvk: b.uvk.clone(),
+            tsk: b.tsk.clone(),
+            txsk: b.txsk.clone(),
+            tvk: b.tvk.clone(),
+            taddr: b.taddr.clone(),
+            birth: b.birth,
+            saved: b.saved,
+        }
+    }
+}
+
+impl From<BackupJson> for BackupT {
+    fn from(b: BackupJson) -> Self {
+        BackupT {
+            name: Some(b.name),
+            seed: b.seed,
+            index: b.index,
+            sk: b.sk,
+            fvk: b.fvk,
+            uvk: b.uvk,
+            tsk: b.tsk,
+            txsk: b.txsk,
+            tvk: b.tvk,
+            taddr: b.taddr,
+            birth: b.birth,
+            saved: b.saved,
+        }
+    }
+}
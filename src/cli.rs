@@ -1,6 +1,7 @@
 use std::{
     str::FromStr,
-    time::{SystemTime, UNIX_EPOCH},
+    sync::Arc,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
 use crate::{
@@ -12,7 +13,9 @@ use crate::{
     },
     fb_unwrap,
     network::{Network, _regtest},
-    pay::sweep::scan_transparent_addresses,
+    pay::{
+        frost::clear_signing_sessions, render::render_summary, sweep::scan_transparent_addresses,
+    },
     types::PoolMask,
     utils::chain::reset_chain,
     warp::{
@@ -24,7 +27,7 @@ use crate::{
 };
 use anyhow::Result;
 use chrono::{DateTime, Utc};
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use clap_repl::{
     reedline::{DefaultPrompt, DefaultPromptSegment, FileBackedHistory},
     ClapEditor,
@@ -34,45 +37,97 @@ use figment::{
     providers::{Env, Format as _, Toml},
     Figment,
 };
+use parking_lot::Mutex;
 use rand::rngs::OsRng;
 use rusqlite::Connection;
+use serde::Serialize;
+use serde_json::json;
 use tokio::runtime::Handle;
 use zcash_protocol::consensus::{NetworkUpgrade, Parameters};
 
 use crate::{
     account::{
-        contacts::{add_contact, commit_unsaved_contacts},
-        txs::get_txs,
+        contacts::add_contact,
+        descriptor::export_transparent_descriptors,
+        discovery::discover_accounts,
+        lwd::{clear_account_lwd_url, set_account_lwd_url},
+        outbox::{
+            compose_message, compose_reply, list_outbox, queue_message, send_queued_messages,
+        },
+        txs::{get_group_txs, get_txs, get_txs_with_address, get_txs_with_contact},
+        watch_only::{add_watch_only_address, remove_watch_only_address},
     },
+    api::json::{BackupJson, MessageJson, TransactionSummaryJson, Versioned},
     coin::CoinDef,
-    data::fb::{ConfigT, PacketsT, PaymentRequestT, RecipientT, TransactionSummaryT},
+    data::fb::{ConfigT, PacketT, PacketsT, PaymentRequestT, RecipientT, TransactionSummaryT},
     db::{
-        account::{get_account_property, get_balance, list_accounts, set_account_property},
+        account::{
+            get_account_property, get_balance, list_accounts, list_accounts_in_group,
+            set_account_property, set_change_outputs, set_snap_policy,
+        },
+        account_groups::{
+            create_account_group, delete_account_group, edit_account_group_name, get_group_balance,
+            list_account_groups, set_account_group,
+        },
         account_manager::{
-            create_new_account, delete_account, edit_account_birth, edit_account_name,
-            get_min_birth, new_transparent_address,
+            create_accounts_bulk, create_new_account, delete_account, edit_account_birth,
+            edit_account_color, edit_account_emoji, edit_account_name, edit_account_notes,
+            get_min_birth, new_transparent_address, secure_delete_account,
+        },
+        api_keys::{
+            broadcast_operation, create_api_key, get_capabilities, list_api_keys, open_api_session,
+            payment_amount, revoke_api_key, Capabilities, Operation,
+        },
+        audit::{
+            get_audit_log_anchor, list_audit_log, set_audit_origin, verify_audit_log, AuditOrigin,
+        },
+        chain::{
+            get_block_info, get_rewind_plan, get_sync_height, list_checkpoints, rewind,
+            rewind_offline, snap_to_checkpoint, SNAP_ACCOUNT_DEFAULT,
         },
-        chain::{get_sync_height, list_checkpoints, rewind, snap_to_checkpoint},
         contacts::{
-            delete_contact, edit_contact_address, edit_contact_name, get_contact, list_contacts,
+            add_contact_to_group, create_contact_group, delete_contact, delete_contact_group,
+            edit_contact_address, edit_contact_group_name, edit_contact_name, get_contact,
+            get_contact_group, list_contact_groups, list_contacts, remove_contact_from_group,
         },
         create_schema,
+        events::get_events_since,
+        logs::get_recent_logs,
         messages::{get_message, list_messages, mark_all_read, mark_read},
-        notes::{exclude_note, get_unspent_notes, reverse_note_exclusion},
+        notes::{
+            apply_note_profile, delete_note_profile, exclude_note, get_unspent_notes,
+            list_note_profiles, reverse_note_exclusion, save_note_profile,
+        },
+        prices::{get_fiat_value, get_price_range, import_prices},
+        servers::list_servers,
         tx::{get_tx_details_account, get_txid, store_tx_details},
     },
-    keys::generate_random_mnemonic_phrase,
-    lwd::{broadcast, get_last_height, get_transaction, get_tree_state},
+    keys::{generate_random_mnemonic_phrase, mnemonic_language},
+    lwd::{broadcast, get_last_height, get_transaction, test_servers},
     txdetails::{analyze_raw_transaction, decode_tx_details, retrieve_tx_details},
     types::CheckpointHeight,
     utils::{
-        chain::{get_activation_date, get_height_by_time},
-        data_split::{merge, split},
+        attachment::{attach_file, save_attachments},
+        chain::{
+            export_checkpoint, get_activation_date, get_chain_status, get_height_by_time,
+            import_checkpoint, verify_chain,
+        },
+        data_split::{merge, merge_seed_phrase, split, split_seed_phrase},
         db::{create_backup, encrypt_db, get_address},
+        doctor::run_health_check,
+        ecc_import::import_ecc_wallet,
         messages::navigate_message,
-        pay::{prepare_payment, sign},
-        ua::decode_address,
-        uri::{make_payment_uri, parse_payment_uri},
+        pay::{pay_group, prepare_payment, save_contacts, sign, transfer},
+        qr::{make_payment_qr, QR_PNG, QR_SVG},
+        ua::diagnostic::diagnose_address,
+        uri::{
+            make_payment_uri, parse_payment_uri,
+            signature::{
+                sign_payment_uri, verify_payment_uri, REQUEST_INVALID_SIGNATURE, REQUEST_UNSIGNED,
+                REQUEST_VERIFIED,
+            },
+        },
+        witness::get_note_witness,
         zip_db::{
             decrypt_zip_database_files, encrypt_zip_database_files, generate_zip_database_keys,
         },
@@ -88,12 +143,44 @@ pub struct Account {
 
 #[derive(Subcommand, Clone, Debug)]
 pub enum AccountCommand {
-    List,
+    List {
+        /// Only the accounts of this group
+        #[arg(long)]
+        group: Option<u32>,
+    },
     Create {
         key: String,
         name: Option<String>,
         birth: Option<u32>,
         pools: u8,
+        #[arg(long)]
+        passphrase: Option<String>,
+    },
+    /// One key per line
+    CreateBulk {
+        filename: String,
+        birth: u32,
+        pools: u8,
+        #[arg(long, default_value = "")]
+        prefix: String,
+    },
+    /// Create the accounts of a seed that have some activity
+    Discover {
+        seed: String,
+        name: String,
+        birth: u32,
+        #[arg(long, default_value_t = 3)]
+        max_gap: u32,
+        #[arg(long)]
+        passphrase: Option<String>,
+    },
+    /// Import the accounts of a zcash_client_sqlite database
+    ImportEcc {
+        path: String,
+        #[arg(long)]
+        seed: Option<String>,
+        #[arg(long)]
+        passphrase: Option<String>,
     },
     EditName {
         account: u32,
@@ -103,8 +190,23 @@ pub enum AccountCommand {
         account: u32,
         birth: u32,
     },
+    EditColor {
+        account: u32,
+        color: u32,
+    },
+    EditEmoji {
+        account: u32,
+        emoji: String,
+    },
+    EditNotes {
+        account: u32,
+        notes: String,
+    },
     Delete {
         account: u32,
+        /// Overwrite the keys and vacuum the database
+        #[arg(long)]
+        secure: bool,
     },
     NewTransparentAddress {
         account: u32,
@@ -112,6 +214,15 @@ pub enum AccountCommand {
     ListTransparentAddresses {
         account: u32,
     },
+    /// Transparent address without keys, tracked but not spent
+    AddWatchOnly {
+        account: u32,
+        address: String,
+    },
+    RemoveWatchOnly {
+        account: u32,
+        address: String,
+    },
     Scan {
         account: u32,
         external: u32,
@@ -126,6 +237,43 @@ pub enum AccountCommand {
         account: u32,
         name: String,
     },
+    /// Server used for this account, the server of the wallet if none
+    SetLwdUrl {
+        account: u32,
+        url: Option<String>,
+    },
+    /// 1: nearest checkpoint, 2: exact height, 3: latest
+    SetSnapPolicy {
+        account: u32,
+        policy: u8,
+    },
+    /// Number of notes of the shielded change of the payments
+    SetChangeOutputs {
+        account: u32,
+        count: u8,
+    },
+    ListGroups,
+    CreateGroup {
+        name: String,
+    },
+    EditGroupName {
+        group: u32,
+        name: String,
+    },
+    DeleteGroup {
+        group: u32,
+    },
+    /// Move an account to a group, 0 to take it out of its group
+    SetGroup {
+        account: u32,
+        group: u32,
+    },
+    GroupBalance {
+        group: u32,
+    },
+    GroupTxs {
+        group: u32,
+    },
 }
 
 #[derive(Parser, Clone, Debug)]
@@ -159,6 +307,35 @@ pub enum ContactCommand {
     Save {
         account: u32,
     },
+    ListGroups {
+        account: u32,
+    },
+    CreateGroup {
+        account: u32,
+        name: String,
+    },
+    EditGroupName {
+        group: u32,
+        name: String,
+    },
+    DeleteGroup {
+        group: u32,
+    },
+    AddToGroup {
+        group: u32,
+        id: u32,
+    },
+    RemoveFromGroup {
+        group: u32,
+        id: u32,
+    },
+    /// Pay `amount` to every member, or share it between them with `split`
+    PayGroup {
+        account: u32,
+        group: u32,
+        amount: u64,
+        split: u8,
+    },
 }
 
 #[derive(Parser, Clone, Debug)]
@@ -173,6 +350,9 @@ pub enum ChainCommand {
     GetHeightFromTime { time: u32 },
     Download { filename: String },
     SyncFromFile { filename: String },
+    ExportCheckpoint { height: u32, filename: String },
+    ImportCheckpoint { filename: String, reset: u8 },
+    BlockInfo { height: u32 },
 }
 
 #[derive(Parser, Clone, Debug)]
@@ -190,6 +370,12 @@ pub enum MessageCommand {
     List { account: u32 },
     MarkAllRead { account: u32, reverse: u8 },
     MarkRead { id: u32, reverse: u8 },
+    Compose { account: u32, contact: u32, subject: String, body: String },
+    Reply { id: u32, body: String },
+    /// Queue a draft, or put it back in the drafts with `reverse`
+    Queue { id: u32, reverse: u8 },
+    Outbox { account: u32 },
+    SendQueued { account: u32 },
 }
 
 #[derive(Parser, Clone, Debug)]
@@ -200,10 +386,40 @@ pub struct Note {
 
 #[derive(Subcommand, Clone, Debug)]
 pub enum NoteCommand {
-    List { account: u32 },
-    Exclude { id: u32, reverse: u8 },
-    Reverse { account: u32 },
-    Utxo { account: u32 },
+    List {
+        account: u32,
+    },
+    Exclude {
+        id: u32,
+        reverse: u8,
+    },
+    Reverse {
+        account: u32,
+    },
+    Utxo {
+        account: u32,
+    },
+    SaveProfile {
+        account: u32,
+        name: String,
+    },
+    ListProfiles {
+        account: u32,
+    },
+    ApplyProfile {
+        account: u32,
+        id: u32,
+    },
+    DeleteProfile {
+        account: u32,
+        id: u32,
+    },
+    /// Merkle path of a note at the checkpoint at or before `height`
+    /// (the sync height by default)
+    Witness {
+        id: u32,
+        height: Option<u32>,
+    },
 }
 
 #[derive(Parser, Clone, Debug)]
@@ -232,6 +448,40 @@ pub enum DatabaseCommand {
     },
 }
 
+#[derive(Parser, Clone, Debug)]
+pub struct ApiKeys {
+    #[structopt(subcommand)]
+    command: ApiKeyCommand,
+}
+
+#[derive(Subcommand, Clone, Debug)]
+pub enum ApiKeyCommand {
+    List,
+    Create {
+        name: String,
+        read_only: u8,
+        max_amount: u64,
+        accounts: Option<String>,
+    },
+    Revoke {
+        id: u32,
+    },
+}
+
+#[derive(Parser, Clone, Debug)]
+pub struct Server {
+    #[structopt(subcommand)]
+    command: ServerCommand,
+}
+
+#[derive(Subcommand, Clone, Debug)]
+pub enum ServerCommand {
+    /// Scores of the servers of the config
+    List,
+    /// Test every server and update their scores
+    Test,
+}
+
 #[derive(Parser, Clone, Debug)]
 pub struct Checkpoint {
     #[structopt(subcommand)]
@@ -241,7 +491,16 @@ pub struct Checkpoint {
 #[derive(Subcommand, Clone, Debug)]
 pub enum CheckpointCommand {
     List,
-    Rewind { height: u32 },
+    Rewind {
+        height: u32,
+        /// Only to a checkpoint stored in the database, without the server
+        #[arg(long)]
+        offline: bool,
+        /// Show what the rewind would drop, without rewinding
+        #[arg(long)]
+        dry_run: bool,
+    },
+    Verify,
 }
 
 #[derive(Parser, Clone, Debug)]
@@ -264,12 +523,44 @@ pub struct QRData {
 
 #[derive(Subcommand, Clone, Debug)]
 pub enum QRDataCommand {
-    Split { data: String, threshold: u32 },
-    Merge { parts: String },
+    Split {
+        data: String,
+        threshold: u32,
+    },
+    Merge {
+        parts: String,
+    },
+    /// Split a seed phrase into `count` shares, `threshold` of them recover it
+    SplitSeed {
+        phrase: String,
+        threshold: u32,
+        count: u32,
+    },
+    /// Recover a seed phrase from its shares (hex, separated by spaces)
+    MergeSeed {
+        shares: String,
+    },
 }
 
-/// The enum of sub-commands supported by the CLI
+#[derive(ValueEnum, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+/// A line of the REPL: a command and the global options
 #[derive(Parser, Clone, Debug)]
+pub struct CommandLine {
+    /// Print the results as JSON for scripting
+    #[arg(long, global = true, value_enum, default_value_t)]
+    output: OutputFormat,
+    #[structopt(subcommand)]
+    command: Command,
+}
+
+/// The enum of sub-commands supported by the CLI
+#[derive(Subcommand, Clone, Debug)]
 pub enum Command {
     Account(Account),
     Contact(Contact),
@@ -280,11 +571,47 @@ pub enum Command {
     Keys(Keys),
     QRData(QRData),
     Checkpoint(Checkpoint),
+    ApiKey(ApiKeys),
+    Server(Server),
     CreateDatabase,
-    GenerateSeed,
+    GenerateSeed {
+        /// en, zh-hans, zh-hant, fr, it, ja, ko or es
+        #[arg(long, default_value = "")]
+        language: String,
+    },
     Backup {
         account: u32,
     },
+    ExportDescriptors {
+        account: u32,
+        #[arg(long, default_value = "")]
+        passphrase: String,
+        /// With the extended private key
+        #[arg(long)]
+        private_keys: bool,
+    },
+    AuditLog {
+        timestamp: Option<u32>,
+    },
+    /// Check the audit log, against the hex anchor if given
+    VerifyAuditLog {
+        anchor: Option<String>,
+    },
+    /// Anchor of the audit log, to keep outside of the database
+    AuditLogAnchor,
+    Logs {
+        n: u32,
+    },
+    /// Balance events after the sequence number `seq`
+    Events {
+        seq: u32,
+    },
+    Doctor,
+    ChainStatus,
+    ImportPrices {
+        path: String,
+        currency: String,
+    },
     LastHeight,
     SyncHeight,
     Reset {
@@ -319,27 +646,73 @@ pub enum Command {
         from_pools: u8,
         fee_paid_by_sender: u8,
         use_change: u8,
+        /// File sent in the memos of the payment
+        #[arg(long)]
+        attachment: Option<String>,
+        /// Pay even if the fee is above the limits of the config
+        #[arg(long)]
+        allow_high_fee: bool,
+        /// Pay even if the address is a change address of the wallet
+        #[arg(long)]
+        allow_change_address: bool,
+        /// Pay even if the address looks like a contact or a recent counterparty
+        #[arg(long)]
+        allow_lookalike_address: bool,
     },
     MultiPay {
         account: u32,
         payment: PaymentRequestT,
     },
+    Transfer {
+        src_account: u32,
+        dst_account: u32,
+        amount: u64,
+        pools: u8,
+    },
     GetTxDetails {
         id: u32,
     },
+    SaveAttachments {
+        id: u32,
+        directory: String,
+    },
     DecodeAddress {
         address: String,
     },
     ListTxs {
         account: u32,
+        /// Only the transactions with this counterparty
+        #[arg(long)]
+        address: Option<String>,
+        /// Only the transactions with this contact
+        #[arg(long)]
+        contact: Option<u32>,
+        /// Add the value of the transactions in this currency,
+        /// from the imported prices
+        #[arg(long)]
+        currency: Option<String>,
     },
     MakePaymentURI {
         payment: PaymentRequestT,
     },
+    /// Write the QR code of the payment URI to a PNG or SVG file
+    MakePaymentQR {
+        payment: PaymentRequestT,
+        path: String,
+        #[arg(long)]
+        svg: bool,
+        /// Error correction level: 0 (L) to 3 (H)
+        #[arg(long, default_value_t = 1)]
+        ecc: u8,
+    },
     PayPaymentUri {
         account: u32,
         uri: String,
     },
+    SignPaymentUri {
+        account: u32,
+        uri: String,
+    },
     BroadcastLatest {
         clear: Option<u8>,
     },
@@ -360,25 +733,48 @@ macro_rules! impl_fb_from_str {
 impl_fb_from_str!(PaymentRequestT);
 impl_fb_from_str!(ZipDbConfigT);
 
+/// Print `value` as JSON, or with `text` in text mode
+fn print_output<T: Serialize + ?Sized, F: FnOnce() -> String>(
+    output: OutputFormat,
+    value: &T,
+    text: F,
+) {
+    match output {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(value).unwrap()),
+        OutputFormat::Text => println!("{}", text()),
+    }
+}
+
+fn print_json<T: Serialize + ?Sized>(value: &T) {
+    println!("{}", serde_json::to_string_pretty(value).unwrap());
+}
+
 fn display_tx(
     network: &Network,
     connection: &Connection,
+    session: u32,
+    output: OutputFormat,
     mut summary: TransactionSummaryT,
 ) -> Result<TransactionBytesT> {
     let txb = sign(
         network,
         connection,
+        session,
         &summary,
         summary.height + EXPIRATION_HEIGHT_DELTA,
     )?;
+    let text = render_summary(&summary)?;
     summary.detach();
-    println!("{}", serde_json::to_string_pretty(&summary).unwrap());
+    let json = Versioned::new(TransactionSummaryJson::from(&summary));
+    print_output(output, &json, || text);
     Ok(txb)
 }
 
 async fn process_command(
     command: Command,
+    output: OutputFormat,
     zec: &mut CoinDef,
+    session: u32,
     txbytes: &mut TransactionBytesT,
 ) -> Result<()> {
     let network = &zec.network;
@@ -390,15 +786,19 @@ async fn process_command(
         Command::Account(account_cmd) => {
             let mut connection = zec.connection()?;
             match account_cmd.command {
-                AccountCommand::List => {
-                    let accounts = list_accounts(&zec, &connection)?;
-                    println!("{}", serde_json::to_string_pretty(&accounts)?);
+                AccountCommand::List { group } => {
+                    let accounts = match group {
+                        Some(group) => list_accounts_in_group(&zec, &connection, group)?,
+                        None => list_accounts(&zec, &connection)?,
+                    };
+                    print_json(&accounts);
                 }
                 AccountCommand::Create {
                     key,
                     name,
                     birth,
                     pools,
+                    passphrase,
                 } => {
                     let birth = match birth {
                         Some(b) => b,
@@ -413,31 +813,107 @@ async fn process_command(
                     create_new_account(
                         network,
                         &mut connection,
+                        session,
                         &name,
                         &key,
+                        &passphrase.unwrap_or_default(),
                         0,
                         birth,
                         pools,
                         false,
                     )?;
                 }
+                AccountCommand::CreateBulk {
+                    filename,
+                    birth,
+                    pools,
+                    prefix,
+                } => {
+                    let keys = std::fs::read_to_string(&filename)?
+                        .lines()
+                        .filter(|l| !l.trim().is_empty())
+                        .map(|l| l.to_string())
+                        .collect::<Vec<_>>();
+                    let accounts = create_accounts_bulk(
+                        network,
+                        &mut connection,
+                        &keys,
+                        &prefix,
+                        birth,
+                        pools,
+                        |current, total| {
+                            if current % 100 == 0 || current == total {
+                                tracing::info!("Created {current}/{total} accounts");
+                            }
+                        },
+                    )?;
+                    print_output(output, &accounts, || {
+                        format!(
+                            "{} accounts, {} duplicates",
+                            keys.len() as u32 - accounts.duplicates,
+                            accounts.duplicates
+                        )
+                    });
+                }
+                AccountCommand::Discover {
+                    seed,
+                    name,
+                    birth,
+                    max_gap,
+                    passphrase,
+                } => {
+                    let mut client = zec.connect_lwd()?;
+                    let accounts = discover_accounts(
+                        network,
+                        &mut connection,
+                        &mut client,
+                        &name,
+                        &seed,
+                        &passphrase.unwrap_or_default(),
+                        birth,
+                        max_gap,
+                    )
+                    .await?;
+                    print_json(&accounts);
+                }
+                AccountCommand::ImportEcc {
+                    path,
+                    seed,
+                    passphrase,
+                } => {
+                    let imported = import_ecc_wallet(
+                        network,
+                        &mut connection,
+                        session,
+                        &path,
+                        &seed.unwrap_or_default(),
+                        &passphrase.unwrap_or_default(),
+                    )?;
+                    let value = json!({ "imported": imported });
+                    print_output(output, &value, || format!("{imported} account(s) imported"));
+                }
                 AccountCommand::NewTransparentAddress { account } => {
                     new_transparent_address(network, &connection, account)?;
                 }
                 AccountCommand::ListTransparentAddresses { account } => {
                     let t_addresses = list_account_transparent_addresses(&connection, account)?;
-                    println!("{:?}", t_addresses);
+                    print_output(output, &t_addresses, || format!("{:?}", t_addresses));
+                }
+                AccountCommand::AddWatchOnly { account, address } => {
+                    add_watch_only_address(network, &connection, account, &address)?;
+                }
+                AccountCommand::RemoveWatchOnly { account, address } => {
+                    remove_watch_only_address(&mut connection, account, &address)?;
                 }
                 AccountCommand::Scan {
                     account,
                     external,
                     gap_limit,
                 } => {
-                    let mut client = zec.connect_lwd()?;
                     scan_transparent_addresses(
+                        &zec,
                         &network,
                         &mut connection,
-                        &mut client,
                         account,
                         external,
                         gap_limit,
@@ -450,8 +926,21 @@ async fn process_command(
                 AccountCommand::EditBirthHeight { account, birth } => {
                     edit_account_birth(&connection, account, birth)?;
                 }
-                AccountCommand::Delete { account } => {
-                    delete_account(&connection, account)?;
+                AccountCommand::EditColor { account, color } => {
+                    edit_account_color(&connection, account, color)?;
+                }
+                AccountCommand::EditEmoji { account, emoji } => {
+                    edit_account_emoji(&connection, account, &emoji)?;
+                }
+                AccountCommand::EditNotes { account, notes } => {
+                    edit_account_notes(&connection, account, &notes)?;
+                }
+                AccountCommand::Delete { account, secure } => {
+                    if secure {
+                        secure_delete_account(&mut connection, session, account)?;
+                    } else {
+                        delete_account(&connection, session, account)?;
+                    }
                 }
                 AccountCommand::SetProperty {
                     account,
@@ -462,7 +951,51 @@ async fn process_command(
                 }
                 AccountCommand::GetProperty { account, name } => {
                     let value = get_account_property(&connection, account, &name)?;
-                    println!("{}", hex::encode(&value));
+                    let value = hex::encode(&value);
+                    print_output(output, &value, || value.clone());
+                }
+                AccountCommand::SetLwdUrl { account, url } => match url {
+                    Some(url) => set_account_lwd_url(&connection, session, account, &url)?,
+                    None => clear_account_lwd_url(&connection, session, account)?,
+                },
+                AccountCommand::SetSnapPolicy { account, policy } => {
+                    set_snap_policy(&connection, account, policy)?;
+                }
+                AccountCommand::SetChangeOutputs { account, count } => {
+                    set_change_outputs(&connection, account, count)?;
+                }
+                AccountCommand::ListGroups => {
+                    let groups = list_account_groups(&connection)?;
+                    print_json(&groups);
+                }
+                AccountCommand::CreateGroup { name } => {
+                    let group = create_account_group(&connection, &name)?;
+                    let value = json!({ "group": group });
+                    print_output(output, &value, || format!("Group: {group}"));
+                }
+                AccountCommand::EditGroupName { group, name } => {
+                    edit_account_group_name(&connection, group, &name)?;
+                }
+                AccountCommand::DeleteGroup { group } => {
+                    delete_account_group(&connection, group)?;
+                }
+                AccountCommand::SetGroup { account, group } => {
+                    set_account_group(&connection, account, group)?;
+                }
+                AccountCommand::GroupBalance { group } => {
+                    let height = get_sync_height(&connection)?.height;
+                    let balance = get_group_balance(&connection, session, group, height)?;
+                    print_output(output, &balance, || format!("Balance: {:?}", balance));
+                }
+                AccountCommand::GroupTxs { group } => {
+                    let height = get_sync_height(&connection)?.height;
+                    let txs = get_group_txs(&connection, session, group, height)?;
+                    print_output(output, &txs, || {
+                        txs.iter()
+                            .map(|tx| serde_json::to_string_pretty(tx).unwrap())
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                    });
                 }
             }
         }
@@ -472,7 +1005,7 @@ async fn process_command(
                 ContactCommand::List => {
                     let contacts = list_contacts(network, &connection)?;
                     let cards = contacts.iter().map(|c| c.card.clone()).collect::<Vec<_>>();
-                    println!("{}", serde_json::to_string_pretty(&cards).unwrap());
+                    print_json(&cards);
                 }
                 ContactCommand::Create {
                     account,
@@ -483,7 +1016,7 @@ async fn process_command(
                 }
                 ContactCommand::Get { id } => {
                     let contact = get_contact(network, &connection, id)?;
-                    println!("{contact:?}");
+                    print_output(output, &contact.card, || format!("{contact:?}"));
                 }
                 ContactCommand::EditName { id, name } => {
                     edit_contact_name(&connection, id, &name)?;
@@ -497,21 +1030,40 @@ async fn process_command(
                 ContactCommand::Save { account } => {
                     let mut client = zec.connect_lwd()?;
                     let bc_height = get_last_height(&mut client).await?;
-                    let cp_height =
-                        snap_to_checkpoint(&connection, bc_height - zec.config.confirmations + 1)?;
-                    let (s_tree, o_tree) = get_tree_state(&mut client, cp_height).await?;
-                    let summary = commit_unsaved_contacts(
-                        network,
-                        &connection,
-                        account,
-                        7,
-                        cp_height,
-                        &s_tree,
-                        &o_tree,
-                        None,
-                    )?
-                    .to_summary()?;
-                    *txbytes = display_tx(network, &connection, summary)?;
+                    let height = bc_height - zec.config.confirmations + 1;
+                    let summary = save_contacts(&zec, session, account, height, "").await?;
+                    *txbytes = display_tx(network, &connection, session, output, summary)?;
+                }
+                ContactCommand::ListGroups { account } => {
+                    let groups = list_contact_groups(&connection, account)?;
+                    print_json(&groups);
+                }
+                ContactCommand::CreateGroup { account, name } => {
+                    let group = create_contact_group(&connection, account, &name)?;
+                    let value = json!({ "group": group });
+                    print_output(output, &value, || format!("Group: {group}"));
+                }
+                ContactCommand::EditGroupName { group, name } => {
+                    edit_contact_group_name(&connection, group, &name)?;
+                }
+                ContactCommand::DeleteGroup { group } => {
+                    delete_contact_group(&connection, group)?;
+                }
+                ContactCommand::AddToGroup { group, id } => {
+                    add_contact_to_group(&connection, group, id)?;
+                }
+                ContactCommand::RemoveFromGroup { group, id } => {
+                    remove_contact_from_group(&connection, group, id)?;
+                }
+                ContactCommand::PayGroup {
+                    account,
+                    group,
+                    amount,
+                    split,
+                } => {
+                    let summary =
+                        pay_group(&zec, session, account, group, amount, split != 0, "").await?;
+                    *txbytes = display_tx(network, &connection, session, output, summary)?;
                 }
             }
         }
@@ -522,11 +1074,13 @@ async fn process_command(
                     let timestamp = get_activation_date(network, &mut client).await?;
                     let datetime = DateTime::<Utc>::from_timestamp(timestamp as i64, 0).unwrap();
                     let timestamp_str = datetime.format("%Y-%m-%d").to_string();
-                    println!("{timestamp_str}");
+                    let value = json!({ "timestamp": timestamp, "date": timestamp_str });
+                    print_output(output, &value, || timestamp_str.clone());
                 }
                 ChainCommand::GetHeightFromTime { time } => {
                     let height = get_height_by_time(network, &mut client, time).await?;
-                    println!("height: {height}");
+                    let value = json!({ "height": height });
+                    print_output(output, &value, || format!("height: {height}"));
                 }
                 ChainCommand::Download { filename } => {
                     download_warp_blocks(
@@ -540,6 +1094,31 @@ async fn process_command(
                 ChainCommand::SyncFromFile { filename } => {
                     warp_synchronize_from_file(&zec, &filename).await?;
                 }
+                ChainCommand::ExportCheckpoint { height, filename } => {
+                    let connection = zec.read_connection()?;
+                    let tree_state = export_checkpoint(&connection, height)?;
+                    std::fs::write(&filename, tree_state)?;
+                }
+                ChainCommand::BlockInfo { height } => {
+                    let connection = zec.read_connection()?;
+                    let info = get_block_info(&connection, height)?;
+                    print_json(&info);
+                }
+                ChainCommand::ImportCheckpoint { filename, reset } => {
+                    let mut connection = zec.connection()?;
+                    let tree_state = std::fs::read_to_string(&filename)?;
+                    let height = import_checkpoint(
+                        network,
+                        &mut connection,
+                        session,
+                        &tree_state,
+                        reset != 0,
+                    )?;
+                    let value = json!({ "height": height });
+                    print_output(output, &value, || {
+                        format!("Checkpoint imported at {height}")
+                    });
+                }
             }
         }
         Command::Message(message_command) => {
@@ -564,8 +1143,9 @@ async fn process_command(
                     navigate_message(&connection, m.account, m.height, subject, false)
                 }
                 MessageCommand::List { account } => {
-                    let msgs = list_messages(&connection, account)?;
-                    println!("{}", serde_json::to_string_pretty(&msgs).unwrap());
+                    let msgs = list_messages(&connection, session, account)?;
+                    let msgs = msgs.iter().map(MessageJson::from).collect::<Vec<_>>();
+                    print_json(&Versioned::new(json!({ "messages": msgs })));
                     Ok(None)
                 }
                 MessageCommand::MarkRead { id, reverse } => {
@@ -576,15 +1156,51 @@ async fn process_command(
                     mark_all_read(&connection, account, reverse != 0)?;
                     Ok(None)
                 }
+                MessageCommand::Compose {
+                    account,
+                    contact,
+                    subject,
+                    body,
+                } => {
+                    let id = compose_message(&connection, account, contact, &subject, &body)?;
+                    let value = json!({ "draft": id });
+                    print_output(output, &value, || format!("Draft {id}"));
+                    Ok(None)
+                }
+                MessageCommand::Reply { id, body } => {
+                    let id = compose_reply(&connection, id, &body)?;
+                    let value = json!({ "draft": id });
+                    print_output(output, &value, || format!("Draft {id}"));
+                    Ok(None)
+                }
+                MessageCommand::Queue { id, reverse } => {
+                    queue_message(&connection, id, reverse == 0)?;
+                    Ok(None)
+                }
+                MessageCommand::Outbox { account } => {
+                    let msgs = list_outbox(&connection, account)?;
+                    let value = json!({ "messages": msgs });
+                    print_output(output, &value, || format!("{msgs:?}"));
+                    Ok(None)
+                }
+                MessageCommand::SendQueued { account } => {
+                    let txid = send_queued_messages(&zec, session, account).await?;
+                    let value = json!({ "txid": txid });
+                    print_output(output, &value, || txid.clone());
+                    Ok(None)
+                }
             }?;
-            println!("{message:?}");
+            let json = message
+                .as_ref()
+                .map(|m| Versioned::new(MessageJson::from(m)));
+            print_output(output, &json, || format!("{message:?}"));
         }
         Command::Note(note_command) => {
-            let connection = zec.connection()?;
+            let mut connection = zec.connection()?;
             match note_command.command {
                 NoteCommand::List { account } => {
-                    let notes = get_unspent_notes(&connection, account, u32::MAX)?;
-                    println!("{}", serde_json::to_string_pretty(&notes).unwrap());
+                    let notes = get_unspent_notes(&connection, session, account, u32::MAX)?;
+                    print_json(&notes);
                 }
                 NoteCommand::Exclude { id, reverse } => {
                     exclude_note(&connection, id, reverse != 0)?;
@@ -593,8 +1209,32 @@ async fn process_command(
                     reverse_note_exclusion(&connection, account)?;
                 }
                 NoteCommand::Utxo { account } => {
-                    let utxos = list_utxos(&connection, account, CheckpointHeight(u32::MAX))?;
-                    println!("{:?}", utxos);
+                    let utxos = list_utxos(&connection, account, CheckpointHeight(u32::MAX), 0)?;
+                    print_output(output, &utxos, || format!("{:?}", utxos));
+                }
+                NoteCommand::SaveProfile { account, name } => {
+                    let id = save_note_profile(&mut connection, account, &name)?;
+                    print_output(output, &id, || format!("Profile {id}"));
+                }
+                NoteCommand::ListProfiles { account } => {
+                    let profiles = list_note_profiles(&connection, account)?;
+                    print_json(&profiles);
+                }
+                NoteCommand::ApplyProfile { account, id } => {
+                    apply_note_profile(&mut connection, account, id)?;
+                }
+                NoteCommand::DeleteProfile { account, id } => {
+                    delete_note_profile(&mut connection, account, id)?;
+                }
+                NoteCommand::Witness { id, height } => {
+                    let height = match height {
+                        Some(height) => height,
+                        None => get_sync_height(&connection)?.height,
+                    };
+                    let mut client = zec.connect_lwd()?;
+                    let witness =
+                        get_note_witness(network, &connection, &mut client, id, height).await?;
+                    print_json(&witness);
                 }
             }
         }
@@ -604,7 +1244,7 @@ async fn process_command(
                 new_db_path,
             } => {
                 let connection = zec.connection()?;
-                encrypt_db(&connection, &password, &new_db_path)?;
+                encrypt_db(&connection, session, &password, &new_db_path)?;
             }
             DatabaseCommand::SetDbPassword { password } => {
                 zec.db_password = Some(password);
@@ -621,7 +1261,7 @@ async fn process_command(
             }
             DatabaseCommand::GenerateKeys => {
                 let keys = generate_zip_database_keys()?;
-                println!("{keys:?}");
+                print_output(output, &keys, || format!("{keys:?}"));
             }
         },
         Command::Keys(keys_command) => match keys_command.command {
@@ -631,7 +1271,8 @@ async fn process_command(
                 let ai = ai.select_pools(PoolMask(pools));
                 let uvk = ai.to_vk()?;
                 let uvk = uvk.encode(network);
-                println!("{uvk}");
+                let value = json!({ "viewing_key": uvk });
+                print_output(output, &value, || uvk.clone());
             }
             KeysCommand::GetDiversifiedAddress {
                 account,
@@ -641,16 +1282,25 @@ async fn process_command(
                 let connection = zec.connection()?;
                 let address =
                     get_diversified_address(network, &connection, account, index, PoolMask(pools))?;
-                println!("{address:?}");
+                print_output(output, &address, || format!("{address:?}"));
             }
         },
         Command::QRData(qr_command) => match qr_command.command {
             QRDataCommand::Split { data, threshold } => {
                 let data = hex::decode(&data)?;
                 let packets = split(&data, threshold)?;
-                for p in packets {
-                    println!("{} {}", data.len(), hex::encode(fb_unwrap!(p.data)));
-                }
+                let parts = packets
+                    .iter()
+                    .map(|p| hex::encode(fb_unwrap!(p.data)))
+                    .collect::<Vec<_>>();
+                let value = json!({ "len": data.len(), "parts": parts });
+                print_output(output, &value, || {
+                    parts
+                        .iter()
+                        .map(|p| format!("{} {}", data.len(), p))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                });
             }
             QRDataCommand::Merge { parts } => {
                 let mut packets = vec![];
@@ -663,40 +1313,253 @@ async fn process_command(
                 let packets = PacketsT {
                     packets: Some(packets),
                 };
-                let data = merge(&packets)?;
-                println!("{:?}", hex::encode(&data));
+                let data = hex::encode(merge(&packets)?);
+                print_output(output, &data, || format!("{:?}", data));
+            }
+            QRDataCommand::SplitSeed {
+                phrase,
+                threshold,
+                count,
+            } => {
+                let packets = split_seed_phrase(&phrase, threshold, count)?;
+                let shares = packets
+                    .iter()
+                    .map(|p| hex::encode(fb_unwrap!(p.data)))
+                    .collect::<Vec<_>>();
+                print_output(output, &shares, || shares.join("\n"));
+            }
+            QRDataCommand::MergeSeed { shares } => {
+                let packets = shares
+                    .split_whitespace()
+                    .map(|s| {
+                        Ok(PacketT {
+                            data: Some(hex::decode(s)?),
+                            len: 0,
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let phrase = merge_seed_phrase(&PacketsT {
+                    packets: Some(packets),
+                })?;
+                print_output(output, &phrase, || phrase.clone());
             }
         },
         Command::Checkpoint(checkpoint_command) => match checkpoint_command.command {
             CheckpointCommand::List => {
-                let connection = zec.connection()?;
+                let connection = zec.read_connection()?;
                 let checkpoints = list_checkpoints(&connection)?;
-                println!("{checkpoints:?}");
+                print_output(output, &checkpoints, || format!("{checkpoints:?}"));
             }
-            CheckpointCommand::Rewind { height } => {
+            CheckpointCommand::Rewind {
+                height,
+                offline,
+                dry_run,
+            } => {
                 let mut connection = zec.connection()?;
+                if dry_run {
+                    let plan = get_rewind_plan(&connection, height)?;
+                    print_output(output, &plan, || format!("{plan:?}"));
+                } else if offline {
+                    let height = rewind_offline(&mut connection, session, height)?;
+                    print_output(output, &height, || format!("Rewound to {height}"));
+                } else {
+                    let mut client = zec.connect_lwd()?;
+                    rewind(&network, &mut connection, &mut client, height).await?;
+                }
+            }
+            CheckpointCommand::Verify => {
+                let connection = zec.connection()?;
                 let mut client = zec.connect_lwd()?;
-                rewind(&network, &mut connection, &mut client, height).await?;
+                let anchor = verify_chain(network, &connection, &mut client).await?;
+                let value = json!({ "verified": true, "hard_checkpoint": anchor });
+                print_output(output, &value, || match anchor {
+                    0 => "Header chain verified (after the last hard checkpoint)".to_string(),
+                    h => format!("Header chain verified from the hard checkpoint {h}"),
+                });
             }
         },
-        Command::GenerateSeed => {
-            let seed = generate_random_mnemonic_phrase(&mut OsRng);
-            println!("{seed}");
+        Command::GenerateSeed { language } => {
+            let language = mnemonic_language(&language)?;
+            let seed = generate_random_mnemonic_phrase(&mut OsRng, language);
+            let value = json!({ "seed": seed });
+            print_output(output, &value, || seed.clone());
         }
         Command::Backup { account } => {
             let connection = zec.connection()?;
-            let backup = create_backup(network, &connection, account)?;
-            println!("{}", serde_json::to_string_pretty(&backup).unwrap());
+            let backup = create_backup(network, &connection, session, account)?;
+            print_json(&Versioned::new(BackupJson::from(&backup)));
+        }
+        Command::ExportDescriptors {
+            account,
+            passphrase,
+            private_keys,
+        } => {
+            let connection = zec.read_connection()?;
+            let descriptors = export_transparent_descriptors(
+                network,
+                &connection,
+                session,
+                account,
+                &passphrase,
+                private_keys,
+            )?;
+            print_json(&descriptors);
+        }
+        Command::ApiKey(api_key_command) => {
+            let connection = zec.connection()?;
+            match api_key_command.command {
+                ApiKeyCommand::List => {
+                    let keys = list_api_keys(&connection, session)?;
+                    print_json(&keys);
+                }
+                ApiKeyCommand::Create {
+                    name,
+                    read_only,
+                    max_amount,
+                    accounts,
+                } => {
+                    let accounts = accounts
+                        .iter()
+                        .flat_map(|a| a.split(','))
+                        .map(|a| a.trim().parse::<u32>())
+                        .collect::<std::result::Result<Vec<_>, _>>()?;
+                    let accounts = accounts
+                        .iter()
+                        .flat_map(|a| a.to_le_bytes())
+                        .collect::<Vec<_>>();
+                    let key = create_api_key(
+                        &connection,
+                        session,
+                        &name,
+                        read_only != 0,
+                        max_amount,
+                        &accounts,
+                    )?;
+                    let value = json!({ "key": key });
+                    print_output(output, &value, || key.clone());
+                }
+                ApiKeyCommand::Revoke { id } => {
+                    revoke_api_key(&connection, session, id)?;
+                }
+            }
+        }
+        Command::Server(server_command) => {
+            let connection = zec.connection()?;
+            let servers = match server_command.command {
+                ServerCommand::List => list_servers(&zec, &connection)?,
+                ServerCommand::Test => test_servers(&zec, &connection).await?,
+            };
+            print_json(&servers);
+        }
+        Command::AuditLog { timestamp } => {
+            let connection = zec.read_connection()?;
+            let events = list_audit_log(&connection, timestamp.unwrap_or_default())?;
+            print_json(&events);
+        }
+        Command::VerifyAuditLog { anchor } => {
+            let connection = zec.read_connection()?;
+            let anchor = hex::decode(anchor.unwrap_or_default())?;
+            let id = verify_audit_log(&connection, &anchor)?;
+            let value = json!({ "intact": id == 0, "broken_at": id });
+            print_output(output, &value, || match id {
+                0 => "Audit log is intact".to_string(),
+                id => format!("Audit log is broken at event {id}"),
+            });
+        }
+        Command::AuditLogAnchor => {
+            let connection = zec.read_connection()?;
+            let anchor = get_audit_log_anchor(&connection)?;
+            println!("{}", hex::encode(anchor));
+        }
+        Command::Logs { n } => {
+            let connection = zec.read_connection()?;
+            let logs = get_recent_logs(&connection, n)?;
+            print_json(&logs);
+        }
+        Command::Events { seq } => {
+            let connection = zec.read_connection()?;
+            let events = get_events_since(&connection, seq)?;
+            print_json(&events);
+        }
+        Command::Doctor => {
+            let report = run_health_check(zec).await?;
+            print_output(output, &report, || {
+                let mut text = format!(
+                    "zcash-warp {} - {}",
+                    report.version.as_deref().unwrap_or_default(),
+                    if report.ok { "healthy" } else { "problems found" }
+                );
+                for check in report.checks.iter().flatten() {
+                    text += &format!(
+                        "\n[{}] {}: {}",
+                        if check.ok { "ok" } else { "FAIL" },
+                        check.name.as_deref().unwrap_or_default(),
+                        check.details.as_deref().unwrap_or_default(),
+                    );
+                }
+                text
+            });
+        }
+        Command::ChainStatus => {
+            let connection = zec.connection()?;
+            let mut client = zec.connect_lwd()?;
+            let status = get_chain_status(&connection, &mut client).await?;
+            print_output(output, &status, || {
+                let server = if status.server_ok {
+                    status.server_height.to_string()
+                } else {
+                    status.server_error.clone().unwrap_or_default()
+                };
+                format!(
+                    "Sync height: {}\nServer height: {}\nEstimated height: {}\n\
+                    Behind: {} blocks ({} min)\nLast sync: {} blocks in {} ms",
+                    status.sync_height,
+                    server,
+                    status.estimated_height,
+                    status.lag,
+                    status.lag_minutes,
+                    status.last_sync_blocks,
+                    status.last_sync_millis,
+                )
+            });
+        }
+        Command::ImportPrices { path, currency } => {
+            let mut connection = zec.connection()?;
+            let days = import_prices(&mut connection, &path, &currency)?;
+            let range = get_price_range(&connection, &currency)?.map(|(start, end)| {
+                let start = DateTime::<Utc>::from_timestamp(start as i64, 0).unwrap();
+                let end = DateTime::<Utc>::from_timestamp(end as i64, 0).unwrap();
+                (start.date_naive().to_string(), end.date_naive().to_string())
+            });
+            let value = json!({
+                "imported": days,
+                "currency": currency.to_uppercase(),
+                "start": range.as_ref().map(|r| &r.0),
+                "end": range.as_ref().map(|r| &r.1),
+            });
+            print_output(output, &value, || {
+                let mut text = format!("Imported {days} prices");
+                if let Some((start, end)) = range.as_ref() {
+                    text += &format!(
+                        "\n{} prices from {} to {}",
+                        currency.to_uppercase(),
+                        start,
+                        end
+                    );
+                }
+                text
+            });
         }
         Command::LastHeight => {
             let mut client = zec.connect_lwd()?;
             let bc_height = get_last_height(&mut client).await?;
-            println!("{bc_height}");
+            let value = json!({ "height": bc_height });
+            print_output(output, &value, || bc_height.to_string());
         }
         Command::SyncHeight => {
-            let connection = zec.connection()?;
+            let connection = zec.read_connection()?;
             let height = get_sync_height(&connection)?;
-            println!("{height:?}");
+            print_output(output, &height, || format!("{height:?}"));
         }
         Command::Reset { height } => {
             let mut connection = zec.connection()?;
@@ -747,14 +1610,15 @@ async fn process_command(
         Command::Address { account, mask } => {
             let time = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as u32;
             let connection = zec.connection()?;
-            let address = get_address(network, &connection, account, time, mask)?;
-            println!("Address: {}", address);
+            let address = get_address(&zec, network, &connection, account, time, mask)?;
+            let value = json!({ "address": address });
+            print_output(output, &value, || format!("Address: {}", address));
         }
         Command::Balance { account } => {
-            let connection = zec.connection()?;
+            let connection = zec.read_connection()?;
             let height = get_sync_height(&connection)?.height;
-            let balance = get_balance(&connection, account, height)?;
-            println!("Balance: {:?}", balance);
+            let balance = get_balance(&connection, session, account, height, 0)?;
+            print_output(output, &balance, || format!("Balance: {:?}", balance));
         }
         Command::Pay {
             account,
@@ -764,6 +1628,10 @@ async fn process_command(
             from_pools,
             fee_paid_by_sender,
             use_change,
+            attachment,
+            allow_high_fee,
+            allow_change_address,
+            allow_lookalike_address,
         } => {
             let mut client = zec.connect_lwd()?;
             let bc_height = get_last_height(&mut client).await?;
@@ -774,6 +1642,7 @@ async fn process_command(
                 pools: to_pools,
                 memo: None,
                 memo_bytes: None,
+                src_pools: 0,
             };
             let payment = PaymentRequestT {
                 recipients: Some(vec![recipient]),
@@ -782,17 +1651,44 @@ async fn process_command(
                 use_change: use_change != 0,
                 height: bc_height,
                 expiration: bc_height + 100,
+                decoy_outputs: 0,
+                from_address: None,
+                allow_high_fee,
+                change_outputs: 0,
+                allow_change_address,
+                allow_lookalike_address,
+            };
+            let payment = match attachment {
+                Some(path) => {
+                    let data = std::fs::read(&path)?;
+                    let name = std::path::Path::new(&path)
+                        .file_name()
+                        .map(|n| n.to_string_lossy().to_string())
+                        .unwrap_or_default();
+                    attach_file(&payment, &address, &name, &data)?
+                }
+                None => payment,
             };
             tracing::info!("{}", serde_json::to_string(&payment)?);
             let summary =
-                prepare_payment(&zec, account, &payment, "").await?;
-            *txbytes = display_tx(network, &connection, summary)?;
+                prepare_payment(&zec, session, account, &payment, "", SNAP_ACCOUNT_DEFAULT).await?;
+            *txbytes = display_tx(network, &connection, session, output, summary)?;
         }
         Command::MultiPay { account, payment } => {
             let connection = zec.connection()?;
             let summary =
-                prepare_payment(&zec, account, &payment, "").await?;
-            *txbytes = display_tx(network, &connection, summary)?;
+                prepare_payment(&zec, session, account, &payment, "", SNAP_ACCOUNT_DEFAULT).await?;
+            *txbytes = display_tx(network, &connection, session, output, summary)?;
+        }
+        Command::Transfer {
+            src_account,
+            dst_account,
+            amount,
+            pools,
+        } => {
+            let connection = zec.connection()?;
+            let summary = transfer(&zec, session, src_account, dst_account, amount, pools).await?;
+            *txbytes = display_tx(network, &connection, session, output, summary)?;
         }
         Command::GetTx { account, id } => {
             let connection = zec.connection()?;
@@ -809,7 +1705,7 @@ async fn process_command(
                 tx,
             )?;
             let txb = serde_cbor::to_vec(&tx)?;
-            println!("{}", hex::encode(&txb));
+            print_output(output, &tx, || hex::encode(&txb));
             store_tx_details(&connection, id, account, height, &tx.txid, &txb)?;
         }
         Command::GetTxDetails { id } => {
@@ -817,26 +1713,95 @@ async fn process_command(
             let (account, tx) = get_tx_details_account(&connection, id)?;
             decode_tx_details(network, &connection, account, id, &tx)?;
             let etx = tx.to_transaction_info_ext(network);
-            println!("{}", serde_json::to_string_pretty(&etx).unwrap());
+            print_json(&etx);
+        }
+        Command::SaveAttachments { id, directory } => {
+            let connection = zec.connection()?;
+            let (account, tx) = get_tx_details_account(&connection, id)?;
+            let attachments = save_attachments(&connection, account, &tx.txid, &directory)?;
+            print_json(&attachments);
         }
         Command::DecodeAddress { address } => {
-            let receivers = decode_address(network, &address)?;
-            println!("{:?}", receivers);
+            let connection = zec.read_connection()?;
+            let diagnostic = diagnose_address(network, &connection, &address)?;
+            print_output(output, &diagnostic, || {
+                let mut text = format!(
+                    "Unified: {} (version {}), TEX: {}, Padding: {}, Owned: {}",
+                    diagnostic.unified,
+                    diagnostic.version,
+                    diagnostic.tex,
+                    if diagnostic.padding_valid { "valid" } else { "INVALID" },
+                    diagnostic.owned,
+                );
+                for r in diagnostic.receivers.iter().flatten() {
+                    text.push_str(&format!(
+                        "\n{:>8} ({:#04x}) {} account {}",
+                        r.name.as_deref().unwrap_or_default(),
+                        r.typecode,
+                        hex::encode(r.data.as_deref().unwrap_or_default()),
+                        r.account,
+                    ));
+                }
+                text
+            });
         }
-        Command::ListTxs { account } => {
+        Command::ListTxs {
+            account,
+            address,
+            contact,
+            currency,
+        } => {
             let mut client = zec.connect_lwd()?;
             let bc_height = get_last_height(&mut client).await?;
-            let connection = zec.connection()?;
-            let txs = get_txs(&connection, account, bc_height)?;
+            let connection = zec.read_connection()?;
+            let txs = match (address, contact) {
+                (Some(address), _) => get_txs_with_address(
+                    network,
+                    &connection,
+                    session,
+                    account,
+                    &address,
+                    bc_height,
+                )?,
+                (None, Some(contact)) => {
+                    get_txs_with_contact(&connection, session, account, contact, bc_height)?
+                }
+                (None, None) => get_txs(&connection, session, account, bc_height)?,
+            };
+            let txs = txs
+                .into_iter()
+                .map(|tx| {
+                    let mut value = serde_json::to_value(&tx)?;
+                    if let Some(currency) = currency.as_deref() {
+                        let fiat = get_fiat_value(&connection, currency, tx.timestamp, tx.amount)?;
+                        value["fiat"] = json!(fiat);
+                    }
+                    Ok(value)
+                })
+                .collect::<Result<Vec<_>>>()?;
 
-            for tx in txs.iter() {
-                println!("{}", serde_json::to_string_pretty(tx).unwrap());
-            }
+            print_output(output, &txs, || {
+                txs.iter()
+                    .map(|tx| serde_json::to_string_pretty(tx).unwrap())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            });
         }
         Command::MakePaymentURI { payment } => {
             tracing::info!("{}", serde_json::to_string(&payment)?);
             let payment_uri = make_payment_uri(network, &payment)?;
-            println!("{}", payment_uri);
+            let value = json!({ "uri": payment_uri });
+            print_output(output, &value, || payment_uri.clone());
+        }
+        Command::MakePaymentQR {
+            payment,
+            path,
+            svg,
+            ecc,
+        } => {
+            let format = if svg { QR_SVG } else { QR_PNG };
+            let image = make_payment_qr(network, &payment, format, ecc)?;
+            std::fs::write(&path, &image)?;
         }
         Command::PayPaymentUri { account, uri } => {
             let mut client = zec.connect_lwd()?;
@@ -844,10 +1809,35 @@ async fn process_command(
             let bc_height = get_last_height(&mut client).await?;
             let cp_height =
                 snap_to_checkpoint(&connection, bc_height - zec.config.confirmations + 1)?;
+            let request = verify_payment_uri(network, &connection, account, &uri)?;
+            match request.status {
+                REQUEST_UNSIGNED => {}
+                REQUEST_INVALID_SIGNATURE => {
+                    anyhow::bail!("Invalid signature of the payment request")
+                }
+                status => {
+                    let signer = request.requester.as_deref().unwrap_or_default();
+                    let status = if status == REQUEST_VERIFIED {
+                        "verified"
+                    } else {
+                        "unknown signer"
+                    };
+                    let value = json!({ "status": status, "signer": signer });
+                    print_output(output, &value, || {
+                        format!("Payment request signed by {signer} ({status})")
+                    });
+                }
+            }
             let payment = parse_payment_uri(&zec.network, &uri, cp_height.0, cp_height.0 + 50)?;
             let summary =
-                prepare_payment(&zec, account, &payment, "").await?;
-            *txbytes = display_tx(network, &connection, summary)?;
+                prepare_payment(&zec, session, account, &payment, "", SNAP_ACCOUNT_DEFAULT).await?;
+            *txbytes = display_tx(network, &connection, session, output, summary)?;
+        }
+        Command::SignPaymentUri { account, uri } => {
+            let connection = zec.connection()?;
+            let signed_uri = sign_payment_uri(network, &connection, account, &uri)?;
+            let value = json!({ "uri": signed_uri });
+            print_output(output, &value, || signed_uri.clone());
         }
         Command::BroadcastLatest { clear } => {
             let clear = clear.unwrap_or(1);
@@ -857,7 +1847,8 @@ async fn process_command(
                     let mut client = zec.connect_lwd()?;
                     let bc_height = get_last_height(&mut client).await?;
                     let r = broadcast(&mut client, bc_height, txbytes).await?;
-                    println!("{}", r);
+                    let value = json!({ "result": r });
+                    print_output(output, &value, || r.clone());
                 }
             }
         }
@@ -865,7 +1856,122 @@ async fn process_command(
     Ok(())
 }
 
-pub fn cli_main(config: &ConfigT) -> Result<()> {
+/// What the command needs from the capabilities of the API key
+fn command_operation(
+    zec: &CoinDef,
+    command: &Command,
+    txbytes: &TransactionBytesT,
+) -> Result<Operation> {
+    let operation = match command {
+        Command::LastHeight
+        | Command::SyncHeight
+        | Command::GenerateSeed { .. }
+        | Command::DecodeAddress { .. }
+        | Command::MakePaymentURI { .. }
+        | Command::MakePaymentQR { .. }
+        | Command::QRData(_) => Operation::Info,
+        // spends the notes of the last signed transaction
+        Command::BroadcastLatest { .. } => broadcast_operation(&zec.connection()?, txbytes)?,
+        Command::Balance { account }
+        | Command::ListTxs { account, .. }
+        | Command::GetTx { account, .. }
+        | Command::Address { account, .. }
+        | Command::Mempool { account } => Operation::Read { account: *account },
+        Command::Pay {
+            account, amount, ..
+        } => Operation::Pay {
+            account: *account,
+            amount: *amount,
+        },
+        Command::MultiPay { account, payment } => Operation::Pay {
+            account: *account,
+            amount: payment_amount(payment),
+        },
+        Command::Transfer {
+            src_account,
+            amount,
+            ..
+        } => Operation::Pay {
+            account: *src_account,
+            amount: *amount,
+        },
+        Command::Contact(Contact {
+            command:
+                ContactCommand::PayGroup {
+                    account,
+                    group,
+                    amount,
+                    split,
+                },
+        }) => {
+            let amount = if *split != 0 {
+                *amount
+            } else {
+                let connection = zec.connection()?;
+                let members = get_contact_group(&connection, *group)?
+                    .contacts
+                    .map(|c| c.len())
+                    .unwrap_or_default();
+                amount * members as u64
+            };
+            Operation::Pay {
+                account: *account,
+                amount,
+            }
+        }
+        Command::PayPaymentUri { account, uri } => {
+            let payment = parse_payment_uri(&zec.network, uri, 0, 0)?;
+            Operation::Pay {
+                account: *account,
+                amount: payment_amount(&payment),
+            }
+        }
+        _ => Operation::Admin,
+    };
+    Ok(operation)
+}
+
+fn authorize_command(
+    zec: &CoinDef,
+    capabilities: Option<&Capabilities>,
+    command: &Command,
+    txbytes: &TransactionBytesT,
+) -> Result<()> {
+    if let Some(capabilities) = capabilities {
+        let operation = command_operation(zec, command, txbytes)?;
+        capabilities.check(&operation)?;
+    }
+    Ok(())
+}
+
+/// Options of the program. Without any, it starts the REPL
+#[derive(Parser, Clone, Debug)]
+#[command(name = "zcash-warp")]
+pub struct Args {
+    /// Run the commands of a file, one per line (`-` for stdin), and exit
+    #[arg(long)]
+    pub script: Option<String>,
+    /// Run a command and exit. Can be repeated
+    #[arg(long)]
+    pub command: Vec<String>,
+}
+
+pub const EXIT_OK: i32 = 0;
+/// A command failed
+pub const EXIT_FAILED: i32 = 1;
+/// A line is not a valid command
+pub const EXIT_USAGE: i32 = 2;
+
+/// API session of the CLI, opened with the API key of the config
+#[derive(Default)]
+struct ApiSession {
+    /// 0 without a key
+    id: u32,
+    capabilities: Option<Capabilities>,
+}
+
+fn open_coin(config: &ConfigT) -> Result<(CoinDef, ApiSession)> {
+    set_audit_origin(AuditOrigin::Cli);
     let mut zec = CoinDef::from_network(
         0,
         if config.regtest {
@@ -877,12 +1983,90 @@ pub fn cli_main(config: &ConfigT) -> Result<()> {
     zec.set_config(config)?;
     zec.set_path_password(config.db_path.as_deref().unwrap(), "")?;
     zec.run_mempool()?;
+    // restrict the commands to the capabilities of the API key, if any.
+    // The functions that check their access get its session
+    let session = match config.api_key.as_ref() {
+        Some(key) => {
+            let connection = zec.connection()?;
+            ApiSession {
+                id: open_api_session(&connection, key)?,
+                capabilities: Some(get_capabilities(&connection, key)?),
+            }
+        }
+        None => ApiSession::default(),
+    };
+    Ok((zec, session))
+}
+
+fn run_command(
+    zec: &mut CoinDef,
+    session: &ApiSession,
+    command: Command,
+    output: OutputFormat,
+    txbytes: &mut TransactionBytesT,
+) -> Result<()> {
+    authorize_command(zec, session.capabilities.as_ref(), &command, txbytes)?;
+    tokio::task::block_in_place(|| {
+        Handle::current()
+            .block_on(async { process_command(command, output, zec, session.id, txbytes).await })
+    })
+}
+
+fn print_error(output: OutputFormat, e: &anyhow::Error) {
+    match output {
+        OutputFormat::Json => print_json(&json!({ "error": e.to_string() })),
+        OutputFormat::Text => println!("{} {}", style("Error:").red().bold(), e),
+    }
+}
+
+/*
+    Idle lock
+
+    With `cli_lock_timeout` (in minutes) in the config, the REPL locks
+    the wallet when no command has run for that long, to protect
+    an unattended terminal. It closes the db and forgets its password,
+    and drops the signed transaction and the FROST signing sessions.
+    Then every command is refused until `database set-db-password`
+    opens the db again. The mempool monitor keeps its own connection.
+    The scripting mode does not lock.
+*/
+const LOCK_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+struct ReplSession {
+    zec: CoinDef,
+    txbytes: TransactionBytesT,
+    last_command: Instant,
+    locked: bool,
+}
+
+impl ReplSession {
+    fn lock_wallet(&mut self) {
+        self.zec.close_db();
+        self.txbytes = TransactionBytesT::default();
+        clear_signing_sessions();
+        self.locked = true;
+    }
+
+    fn unlock_wallet(&mut self, db_path: &str, password: &str) -> Result<()> {
+        self.zec.set_path_password(db_path, password)?;
+        if let Err(e) = self.zec.connection() {
+            self.zec.close_db();
+            return Err(e);
+        }
+        self.locked = false;
+        Ok(())
+    }
+}
+
+pub fn cli_main(config: &ConfigT) -> Result<()> {
+    let (zec, api_session) = open_coin(config)?;
+    let db_path = config.db_path.clone().unwrap();
 
     let prompt = DefaultPrompt {
         left_prompt: DefaultPromptSegment::Basic("zcash-warp".to_owned()),
         ..DefaultPrompt::default()
     };
-    let rl = ClapEditor::<Command>::builder()
+    let rl = ClapEditor::<CommandLine>::builder()
         .with_prompt(Box::new(prompt))
         .with_editor_hook(|reed| {
             reed.with_history(Box::new(
@@ -891,14 +2075,45 @@ pub fn cli_main(config: &ConfigT) -> Result<()> {
         })
         .build();
 
-    let mut txbytes = TransactionBytesT::default();
-    rl.repl(|command| {
-        let e = tokio::task::block_in_place(|| {
-            Handle::current()
-                .block_on(async { process_command(command, &mut zec, &mut txbytes).await })
+    let session = Arc::new(Mutex::new(ReplSession {
+        zec,
+        txbytes: TransactionBytesT::default(),
+        last_command: Instant::now(),
+        locked: false,
+    }));
+    if config.cli_lock_timeout > 0 {
+        let minutes = config.cli_lock_timeout;
+        let timeout = Duration::from_secs(minutes as u64 * 60);
+        let session = session.clone();
+        std::thread::spawn(move || loop {
+            std::thread::sleep(LOCK_CHECK_INTERVAL);
+            let mut session = session.lock();
+            if !session.locked && session.last_command.elapsed() >= timeout {
+                session.lock_wallet();
+                tracing::info!("Wallet locked after {minutes} minutes of inactivity");
+            }
         });
-        if let Err(e) = e {
-            println!("{} {}", style("Error:").red().bold(), e);
+    }
+
+    rl.repl(|CommandLine { output, command }| {
+        let mut session = session.lock();
+        let res = if session.locked {
+            match command {
+                Command::Database(Database {
+                    command: DatabaseCommand::SetDbPassword { password },
+                }) => session.unlock_wallet(&db_path, &password),
+                _ => Err(anyhow::anyhow!(
+                    "The wallet is locked, open it with database set-db-password"
+                )),
+            }
+        } else {
+            let ReplSession { zec, txbytes, .. } = &mut *session;
+            run_command(zec, &api_session, command, output, txbytes)
+        };
+        // a long command (sync) is not idle time
+        session.last_command = Instant::now();
+        if let Err(e) = res {
+            print_error(output, &e);
         }
     });
 
@@ -907,6 +2122,59 @@ pub fn cli_main(config: &ConfigT) -> Result<()> {
     Ok(())
 }
 
+/*
+    Scripting mode
+
+    Runs the commands of `--script` and then the `--command` lines,
+    without the REPL. Every line is a REPL command, with the shell
+    quoting rules. Empty lines and lines that start with `#` are skipped.
+    The output is JSON, one value per command.
+    The run stops at the first error, reported as
+    `{"error": ..., "line": ...}` and the exit code tells what failed.
+*/
+pub fn cli_script(config: &ConfigT, args: &Args) -> Result<i32> {
+    let mut lines = vec![];
+    if let Some(script) = args.script.as_ref() {
+        let script = if script == "-" {
+            std::io::read_to_string(std::io::stdin())?
+        } else {
+            std::fs::read_to_string(script)?
+        };
+        lines.extend(script.lines().map(|l| l.to_string()));
+    }
+    lines.extend(args.command.iter().cloned());
+
+    let (mut zec, api_session) = open_coin(config)?;
+    let mut txbytes = TransactionBytesT::default();
+    let output = OutputFormat::Json;
+    for (i, line) in lines.iter().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let command = shlex::split(line)
+            .ok_or(anyhow::anyhow!("Invalid quoting"))
+            .and_then(|words| {
+                let command = CommandLine::try_parse_from(
+                    std::iter::once("zcash-warp".to_string()).chain(words),
+                )?;
+                Ok(command.command)
+            });
+        let command = match command {
+            Ok(command) => command,
+            Err(e) => {
+                print_json(&json!({ "error": e.to_string(), "line": i + 1 }));
+                return Ok(EXIT_USAGE);
+            }
+        };
+        if let Err(e) = run_command(&mut zec, &api_session, command, output, &mut txbytes) {
+            print_json(&json!({ "error": e.to_string(), "line": i + 1 }));
+            return Ok(EXIT_FAILED);
+        }
+    }
+    Ok(EXIT_OK)
+}
+
 pub fn init_config() -> ConfigT {
     let config: ConfigT = Figment::new()
         .merge(Toml::file("App.toml"))
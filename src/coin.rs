@@ -3,11 +3,12 @@ use lazy_static::lazy_static;
 use parking_lot::Mutex;
 use r2d2::Pool;
 use r2d2_sqlite::SqliteConnectionManager;
-use rusqlite::OptionalExtension;
+use rusqlite::{OpenFlags, OptionalExtension, TransactionBehavior};
 use std::future::Future;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
+use thiserror::Error;
 use tokio::runtime::Runtime;
 use tokio::sync::mpsc::Sender;
 use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint};
@@ -15,6 +16,7 @@ use tower::discover::Change;
 
 use crate::network::Network;
 
+use crate::db::servers::pick_best_server;
 use crate::warp::mempool::{Mempool, MempoolMsg};
 use crate::{
     data::fb::ConfigT, lwd::rpc::compact_tx_streamer_client::CompactTxStreamerClient, Client,
@@ -27,10 +29,13 @@ pub struct CoinDef {
     pub coin: u8,
     pub network: Network,
     pub pool: Option<Pool<SqliteConnectionManager>>,
+    pub read_pool: Option<Pool<SqliteConnectionManager>>,
     pub db_password: Option<String>,
     pub channel: Option<Channel>,
     pub config: ConfigT,
     pub mempool_tx: Option<Sender<MempoolMsg>>,
+    /// Opened with `open_read_only`
+    pub read_only: bool,
     pub runtime: TokioRuntime, // this runtime needs to live for the whole duration of the app
 }
 
@@ -44,10 +49,18 @@ impl Drop for CoinDef {
     }
 }
 
+#[derive(Error, Debug)]
+pub enum CoinError {
+    #[error("The wallet is open read only")]
+    ReadOnly,
+}
+
 #[derive(Clone, Debug)]
 pub struct TokioRuntime(pub Option<Arc<Runtime>>);
 
 const TIMEOUT_SEC: u64 = 5;
+const DEFAULT_READ_POOL_SIZE: u32 = 4;
+const DEFAULT_BUSY_TIMEOUT_MS: u32 = 60_000;
 
 impl CoinDef {
     pub fn from_network(coin: u8, network: Network) -> Self {
@@ -55,29 +68,24 @@ impl CoinDef {
             coin,
             network,
             pool: None,
+            read_pool: None,
             db_password: None,
             channel: None,
             config: ConfigT::default(),
             mempool_tx: None,
+            read_only: false,
             runtime: TokioRuntime(Some(Arc::new(Runtime::new().unwrap()))),
         }
     }
 
     pub fn set_config(&mut self, config: &ConfigT) -> Result<()> {
         self.config.merge(config);
+        crate::pay::prover::set_proof_threads(self.config.proof_threads)?;
         if let Some(servers) = self.config.servers.as_ref() {
-            let pem = include_bytes!("ca.pem");
-            let ca = Certificate::from_pem(pem);
-            let tls = ClientTlsConfig::new().ca_certificate(ca);
             let endpoints = servers
                 .iter()
-                .map(|s| {
-                    let ep = Endpoint::from_str(&s).unwrap();
-                    ep.tls_config(tls.clone())
-                        .unwrap()
-                        .connect_timeout(Duration::from_secs(TIMEOUT_SEC))
-                })
-                .collect::<Vec<_>>();
+                .map(|s| server_endpoint(s))
+                .collect::<Result<Vec<_>>>()?;
             let (channel, tx) = Channel::balance_channel_with_executor(16, self.runtime.clone());
             endpoints.into_iter().for_each(|endpoint| {
                 tx.try_send(Change::Insert(endpoint.uri().clone(), endpoint))
@@ -88,36 +96,147 @@ impl CoinDef {
         Ok(())
     }
 
+    /*
+        The db is in WAL mode so that readers do not block the writer
+        and vice versa. Writes go through `pool`, reads can use
+        the read only connections of `read_pool` and still succeed
+        while a sync is committing.
+    */
     pub fn set_path_password(&mut self, path: &str, password: &str) -> Result<()> {
         self.db_password = Some(password.to_string());
         tracing::info!("Setting pool");
-        let manager = r2d2_sqlite::SqliteConnectionManager::file(path);
+        let busy_timeout = match self.config.db_busy_timeout {
+            0 => DEFAULT_BUSY_TIMEOUT_MS,
+            t => t,
+        };
+        let busy_timeout = Duration::from_millis(busy_timeout as u64);
+        let read_pool_size = match self.config.db_read_pool_size {
+            0 => DEFAULT_READ_POOL_SIZE,
+            n => n,
+        };
+
+        let pwd = password.to_string();
+        let manager = SqliteConnectionManager::file(path).with_init(move |c| {
+            init_connection(c, &pwd, busy_timeout)?;
+            let _ = c.query_row("PRAGMA journal_mode = WAL", [], |_| Ok(()));
+            Ok(())
+        });
         let pool = r2d2::Pool::new(manager)?;
         self.pool = Some(pool);
+
+        let pwd = password.to_string();
+        let manager = SqliteConnectionManager::file(path)
+            .with_flags(
+                OpenFlags::SQLITE_OPEN_READ_ONLY
+                    | OpenFlags::SQLITE_OPEN_URI
+                    | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+            )
+            .with_init(move |c| init_connection(c, &pwd, busy_timeout));
+        // the db file may not exist yet, open connections on demand
+        let read_pool = r2d2::Pool::builder()
+            .max_size(read_pool_size)
+            .min_idle(Some(0))
+            .build_unchecked(manager);
+        self.read_pool = Some(read_pool);
+        self.read_only = false;
+        Ok(())
+    }
+
+    /*
+        A companion tool (reports, audits) can open the db of a wallet
+        that another process is using. Every connection is read only:
+        there is no write pool, no read pool, and the journal mode is
+        left as it is. The operations that write (sync, mempool, write
+        transactions) fail with CoinError::ReadOnly, and any other write
+        is refused by SQLite.
+    */
+    pub fn open_read_only(&mut self, path: &str, password: &str) -> Result<()> {
+        self.db_password = Some(password.to_string());
+        let busy_timeout = match self.config.db_busy_timeout {
+            0 => DEFAULT_BUSY_TIMEOUT_MS,
+            t => t,
+        };
+        let busy_timeout = Duration::from_millis(busy_timeout as u64);
+        let pwd = password.to_string();
+        let manager = SqliteConnectionManager::file(path)
+            .with_flags(
+                OpenFlags::SQLITE_OPEN_READ_ONLY
+                    | OpenFlags::SQLITE_OPEN_URI
+                    | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+            )
+            .with_init(move |c| init_connection(c, &pwd, busy_timeout));
+        let pool = r2d2::Pool::builder().max_size(1).build(manager)?;
+        self.pool = Some(pool);
+        self.read_pool = None;
+        self.read_only = true;
+        Ok(())
+    }
+
+    /// Close the db and forget its password until
+    /// `set_path_password` opens it again
+    pub fn close_db(&mut self) {
+        self.db_password = None;
+        self.pool = None;
+        self.read_pool = None;
+    }
+
+    pub fn check_writable(&self) -> Result<(), CoinError> {
+        if self.read_only {
+            return Err(CoinError::ReadOnly);
+        }
         Ok(())
     }
 
     pub fn connection(&self) -> Result<Connection> {
         let pool = self.pool.as_ref().expect("No db path set");
-        let connection = pool.get().unwrap();
-        if let Some(ref password) = self.db_password {
-            let _ = connection
-                .query_row(&format!("PRAGMA key = '{}'", password), [], |_| Ok(()))
-                .optional();
-        }
-        let _ = connection.busy_timeout(Duration::from_secs(60));
-        let c = connection
-            .query_row("SELECT COUNT(*) FROM sqlite_master", [], |row| {
-                row.get::<_, u32>(0)
-            })
-            .optional()?;
-        if c.is_none() {
-            anyhow::bail!("Could not open db (invalid password?)")
-        }
+        let connection = pool.get()?;
+        check_connection(&connection)?;
+        Ok(connection)
+    }
+
+    /// A read only connection. Falls back to the write pool
+    /// if the read pool is not available
+    pub fn read_connection(&self) -> Result<Connection> {
+        let Some(pool) = self.read_pool.as_ref() else {
+            return self.connection();
+        };
+        let connection = pool.get()?;
+        check_connection(&connection)?;
         Ok(connection)
     }
 
+    pub fn with_read_connection<T, F: FnOnce(&rusqlite::Connection) -> Result<T>>(
+        &self,
+        f: F,
+    ) -> Result<T> {
+        let connection = self.read_connection()?;
+        f(&connection)
+    }
+
+    /// Run `f` in a transaction that takes the write lock immediately,
+    /// so that it waits for other writers (up to the busy timeout)
+    /// instead of failing with SQLITE_BUSY when it first writes
+    pub fn with_write_connection<T, F: FnOnce(&rusqlite::Transaction) -> Result<T>>(
+        &self,
+        f: F,
+    ) -> Result<T> {
+        self.check_writable()?;
+        let mut connection = self.connection()?;
+        let db_tx = connection.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let res = f(&db_tx)?;
+        db_tx.commit()?;
+        Ok(res)
+    }
+
+    /// Client of the best server (see `db::servers`), or of
+    /// all the servers of the config if none is better
     pub fn connect_lwd(&self) -> Result<Client> {
+        if let Some(url) = self.best_server() {
+            let channel = server_endpoint(&url)?
+                .executor(self.runtime.clone())
+                .connect_lazy();
+            return Ok(CompactTxStreamerClient::new(channel));
+        }
         let channel = self
             .channel
             .as_ref()
@@ -126,7 +245,19 @@ impl CoinDef {
         Ok(client)
     }
 
+    fn best_server(&self) -> Option<String> {
+        let servers = self.config.servers.as_ref()?;
+        if servers.len() < 2 || self.pool.is_none() {
+            return None;
+        }
+        // the table does not exist before the schema is created
+        let connection = self.read_connection().ok()?;
+        let url = pick_best_server(self, &connection).ok()?;
+        (!url.is_empty()).then_some(url)
+    }
+
     pub fn run_mempool(&mut self) -> Result<()> {
+        self.check_writable()?;
         let runtime = self.runtime.0.as_ref().unwrap();
         let tx = Mempool::run(self.clone(), runtime.clone())?;
         self.mempool_tx = Some(tx);
@@ -144,6 +275,42 @@ where
     }
 }
 
+fn init_connection(
+    connection: &mut rusqlite::Connection,
+    password: &str,
+    busy_timeout: Duration,
+) -> rusqlite::Result<()> {
+    if !password.is_empty() {
+        let _ = connection
+            .query_row(&format!("PRAGMA key = '{}'", password), [], |_| Ok(()))
+            .optional();
+    }
+    connection.busy_timeout(busy_timeout)?;
+    Ok(())
+}
+
+fn check_connection(connection: &rusqlite::Connection) -> Result<()> {
+    let c = connection
+        .query_row("SELECT COUNT(*) FROM sqlite_master", [], |row| {
+            row.get::<_, u32>(0)
+        })
+        .optional()?;
+    if c.is_none() {
+        anyhow::bail!("Could not open db (invalid password?)")
+    }
+    Ok(())
+}
+
+fn server_endpoint(url: &str) -> Result<Endpoint> {
+    let pem = include_bytes!("ca.pem");
+    let ca = Certificate::from_pem(pem);
+    let tls = ClientTlsConfig::new().ca_certificate(ca);
+    let endpoint = Endpoint::from_str(url)?
+        .tls_config(tls)?
+        .connect_timeout(Duration::from_secs(TIMEOUT_SEC));
+    Ok(endpoint)
+}
+
 pub async fn connect_lwd(url: &str) -> Result<Client> {
     let mut channel = tonic::transport::Channel::from_shared(url.to_string())?;
     if url.starts_with("https") {
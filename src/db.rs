@@ -5,12 +5,20 @@ use warp_macros::c_export;
 use crate::utils::ContextExt;
 
 pub mod account;
+pub mod account_groups;
 pub mod account_manager;
+pub mod api_keys;
+pub mod audit;
 pub mod chain;
 pub mod contacts;
+pub mod draft;
+pub mod events;
+pub mod logs;
 pub mod mempool;
 pub mod messages;
 pub mod notes;
+pub mod prices;
+pub mod servers;
 pub mod swap;
 pub mod tx;
 pub mod witnesses;
@@ -41,10 +49,20 @@ pub fn create_schema(connection: &mut Connection, _version: &str) -> Result<()>
         icon BLOB,
         balance INTEGER NOT NULL,
         saved BOOL NOT NULL,
-        hidden BOOL NOT NULL)",
+        hidden BOOL NOT NULL,
+        color INTEGER,
+        emoji TEXT,
+        notes TEXT)",
             [],
         )
         .with_file_line(|| "accounts")?;
+    add_column(connection, "accounts", "color", "INTEGER")?;
+    add_column(connection, "accounts", "emoji", "TEXT")?;
+    add_column(connection, "accounts", "notes", "TEXT")?;
+    add_column(connection, "accounts", "deleted", "INTEGER")?;
+    add_column(connection, "accounts", "deleted_height", "INTEGER")?;
+    add_column(connection, "accounts", "passphrase", "BOOL NOT NULL DEFAULT FALSE")?;
+    add_column(connection, "accounts", "scan_start", "INTEGER")?;
 
     connection
         .execute(
@@ -104,6 +122,19 @@ pub fn create_schema(connection: &mut Connection, _version: &str) -> Result<()>
         )
         .with_file_line(|| "blcks")?;
 
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS frontiers(
+        height INTEGER PRIMARY KEY,
+        hash BLOB NOT NULL,
+        prev_hash BLOB NOT NULL,
+        timestamp INTEGER NOT NULL,
+        sapling BLOB NOT NULL,
+        orchard BLOB NOT NULL)",
+            [],
+        )
+        .with_file_line(|| "frontiers")?;
+
     connection
         .execute(
             "CREATE TABLE IF NOT EXISTS txs(
@@ -121,6 +152,8 @@ pub fn create_schema(connection: &mut Connection, _version: &str) -> Result<()>
             [],
         )
         .with_file_line(|| "txs")?;
+    add_column(connection, "txs", "contact", "INTEGER")?;
+    add_column(connection, "txs", "transfer", "INTEGER")?;
 
     connection
         .execute(
@@ -157,6 +190,50 @@ pub fn create_schema(connection: &mut Connection, _version: &str) -> Result<()>
             [],
         )
         .with_file_line(|| "notes")?;
+    add_column(connection, "notes", "coinbase", "BOOL NOT NULL DEFAULT FALSE")?;
+    add_column(connection, "notes", "change", "BOOL NOT NULL DEFAULT FALSE")?;
+    // asset base of a ZSA note, NULL for ZEC
+    add_column(connection, "notes", "asset", "BLOB")?;
+
+    // index of the nullifiers of the notes for the spend detection,
+    // kept in sync with the notes by the triggers
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS nullifiers(
+        nf BLOB NOT NULL,
+        account INTEGER NOT NULL,
+        id_note INTEGER NOT NULL UNIQUE,
+        orchard BOOL NOT NULL,
+        PRIMARY KEY (nf, account))",
+            [],
+        )
+        .with_file_line(|| "nullifiers")?;
+    connection
+        .execute(
+            "CREATE TRIGGER IF NOT EXISTS notes_nf_insert
+        AFTER INSERT ON notes
+        BEGIN INSERT OR REPLACE INTO nullifiers(nf, account, id_note, orchard)
+        VALUES (NEW.nf, NEW.account, NEW.id_note, NEW.orchard); END",
+            [],
+        )
+        .with_file_line(|| "notes_nf_insert")?;
+    connection
+        .execute(
+            "CREATE TRIGGER IF NOT EXISTS notes_nf_delete
+        AFTER DELETE ON notes
+        BEGIN DELETE FROM nullifiers WHERE id_note = OLD.id_note; END",
+            [],
+        )
+        .with_file_line(|| "notes_nf_delete")?;
+    // notes received before the index existed
+    connection
+        .execute(
+            "INSERT INTO nullifiers(nf, account, id_note, orchard)
+        SELECT nf, account, id_note, orchard FROM notes
+        WHERE NOT EXISTS (SELECT 1 FROM nullifiers)",
+            [],
+        )
+        .with_file_line(|| "nullifiers")?;
 
     connection
         .execute(
@@ -212,6 +289,56 @@ pub fn create_schema(connection: &mut Connection, _version: &str) -> Result<()>
         )
         .with_file_line(|| "utxo_spends")?;
 
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS t_scan_progress(
+        account INTEGER NOT NULL,
+        address TEXT NOT NULL,
+        height INTEGER NOT NULL,
+        PRIMARY KEY (account, address))",
+            [],
+        )
+        .with_file_line(|| "t_scan_progress")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS t_sweep_progress(
+        account INTEGER NOT NULL,
+        external INTEGER NOT NULL,
+        addr_index INTEGER NOT NULL,
+        gap INTEGER NOT NULL,
+        PRIMARY KEY (account, external))",
+            [],
+        )
+        .with_file_line(|| "t_sweep_progress")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS transparent_blocks(
+        height INTEGER PRIMARY KEY,
+        hash BLOB NOT NULL)",
+            [],
+        )
+        .with_file_line(|| "transparent_blocks")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS script_utxos(
+        id_script_utxo INTEGER PRIMARY KEY,
+        account INTEGER NOT NULL,
+        height INTEGER NOT NULL,
+        timestamp INTEGER NOT NULL,
+        txid BLOB NOT NULL,
+        vout INTEGER NOT NULL,
+        value INTEGER NOT NULL,
+        script_type INTEGER NOT NULL,
+        script BLOB NOT NULL,
+        UNIQUE (account, txid, vout))",
+            [],
+        )
+        .with_file_line(|| "script_utxos")?;
+    add_column(connection, "script_utxos", "spent", "INTEGER")?;
+
     connection
         .execute(
             "CREATE TABLE IF NOT EXISTS txdetails(
@@ -225,6 +352,34 @@ pub fn create_schema(connection: &mut Connection, _version: &str) -> Result<()>
         )
         .with_file_line(|| "txdetails")?;
 
+    // counterparties of the transactions, by receiver
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS tx_addresses(
+        account INTEGER NOT NULL,
+        receiver BLOB NOT NULL,
+        id_tx INTEGER NOT NULL,
+        height INTEGER NOT NULL,
+        address TEXT NOT NULL,
+        contact INTEGER,
+        PRIMARY KEY (account, receiver, id_tx))",
+            [],
+        )
+        .with_file_line(|| "tx_addresses")?;
+
+    // failed retrievals of the details, see `run_tx_details_worker`
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS tx_details_failures(
+        id_tx INTEGER PRIMARY KEY,
+        account INTEGER NOT NULL,
+        height INTEGER NOT NULL,
+        attempts INTEGER NOT NULL,
+        error TEXT NOT NULL)",
+            [],
+        )
+        .with_file_line(|| "tx_details_failures")?;
+
     connection
         .execute(
             "CREATE TABLE IF NOT EXISTS msgs(
@@ -245,6 +400,7 @@ pub fn create_schema(connection: &mut Connection, _version: &str) -> Result<()>
             [],
         )
         .with_file_line(|| "msgs")?;
+    add_column(connection, "msgs", "contact", "INTEGER")?;
 
     connection
         .execute(
@@ -254,10 +410,12 @@ pub fn create_schema(connection: &mut Connection, _version: &str) -> Result<()>
         name TEXT NOT NULL,
         address TEXT NOT NULL,
         saved BOOL NOT NULL,
+        ack INTEGER NOT NULL DEFAULT 0,
         UNIQUE (account, name))",
             [],
         )
         .with_file_line(|| "contacts")?;
+    add_column(connection, "contacts", "ack", "INTEGER NOT NULL DEFAULT 0")?;
 
     connection
         .execute(
@@ -272,6 +430,155 @@ pub fn create_schema(connection: &mut Connection, _version: &str) -> Result<()>
         )
         .with_file_line(|| "contact_receivers")?;
 
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS contact_groups(
+        id_group INTEGER PRIMARY KEY,
+        account INTEGER NOT NULL,
+        name TEXT NOT NULL,
+        UNIQUE (account, name))",
+            [],
+        )
+        .with_file_line(|| "contact_groups")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS contact_group_members(
+        id_group INTEGER NOT NULL,
+        contact INTEGER NOT NULL,
+        PRIMARY KEY (id_group, contact))",
+            [],
+        )
+        .with_file_line(|| "contact_group_members")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS trusted_addresses(
+        id_trusted INTEGER PRIMARY KEY,
+        account INTEGER NOT NULL,
+        address TEXT NOT NULL,
+        UNIQUE (account, address))",
+            [],
+        )
+        .with_file_line(|| "trusted_addresses")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS broadcast_txs(
+        id_broadcast INTEGER PRIMARY KEY,
+        account INTEGER NOT NULL,
+        txid BLOB NOT NULL,
+        height INTEGER NOT NULL,
+        expiration INTEGER NOT NULL,
+        expired_height INTEGER,
+        mined BOOL NOT NULL,
+        UNIQUE (account, txid))",
+            [],
+        )
+        .with_file_line(|| "broadcast_txs")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS backup_state(
+        tbl TEXT NOT NULL,
+        id INTEGER NOT NULL,
+        hash BLOB NOT NULL,
+        PRIMARY KEY (tbl, id))",
+            [],
+        )
+        .with_file_line(|| "backup_state")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS backup_snapshots(
+        sequence INTEGER PRIMARY KEY,
+        path TEXT NOT NULL,
+        timestamp INTEGER NOT NULL,
+        incremental BOOL NOT NULL)",
+            [],
+        )
+        .with_file_line(|| "backup_snapshots")?;
+    add_column(connection, "backup_snapshots", "key_id", "BLOB")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS acks(
+        id_ack INTEGER PRIMARY KEY,
+        account INTEGER NOT NULL,
+        msg INTEGER NOT NULL,
+        txid BLOB NOT NULL,
+        address TEXT NOT NULL,
+        height INTEGER NOT NULL,
+        status INTEGER NOT NULL,
+        UNIQUE (account, msg))",
+            [],
+        )
+        .with_file_line(|| "acks")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS tex_changes(
+        id_tex_change INTEGER PRIMARY KEY,
+        account INTEGER NOT NULL,
+        txid BLOB NOT NULL,
+        address TEXT NOT NULL,
+        status INTEGER NOT NULL,
+        sweep_txid TEXT,
+        UNIQUE (account, txid))",
+            [],
+        )
+        .with_file_line(|| "tex_changes")?;
+    add_column(connection, "tex_changes", "retries", "INTEGER NOT NULL DEFAULT 0")?;
+    add_column(connection, "tex_changes", "retry_height", "INTEGER NOT NULL DEFAULT 0")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS outbox(
+        id_outbox INTEGER PRIMARY KEY,
+        account INTEGER NOT NULL,
+        contact INTEGER,
+        recipient TEXT NOT NULL,
+        subject TEXT NOT NULL,
+        body TEXT NOT NULL,
+        status INTEGER NOT NULL,
+        timestamp INTEGER NOT NULL,
+        txid BLOB)",
+            [],
+        )
+        .with_file_line(|| "outbox")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS audit_log(
+        id_event INTEGER PRIMARY KEY,
+        timestamp INTEGER NOT NULL,
+        origin TEXT NOT NULL,
+        operation TEXT NOT NULL,
+        account INTEGER NOT NULL,
+        details TEXT NOT NULL,
+        hash BLOB NOT NULL)",
+            [],
+        )
+        .with_file_line(|| "audit_log")?;
+
+    // the audit log is append only
+    connection
+        .execute(
+            "CREATE TRIGGER IF NOT EXISTS audit_log_no_update
+        BEFORE UPDATE ON audit_log
+        BEGIN SELECT RAISE(ABORT, 'audit_log is append only'); END",
+            [],
+        )
+        .with_file_line(|| "audit_log_no_update")?;
+    connection
+        .execute(
+            "CREATE TRIGGER IF NOT EXISTS audit_log_no_delete
+        BEFORE DELETE ON audit_log
+        BEGIN SELECT RAISE(ABORT, 'audit_log is append only'); END",
+            [],
+        )
+        .with_file_line(|| "audit_log_no_delete")?;
+
     connection
         .execute(
             "CREATE TABLE IF NOT EXISTS blck_times(
@@ -281,6 +588,57 @@ pub fn create_schema(connection: &mut Connection, _version: &str) -> Result<()>
         )
         .with_file_line(|| "blck_times")?;
 
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS block_stats(
+        height INTEGER PRIMARY KEY,
+        hash BLOB NOT NULL,
+        timestamp INTEGER NOT NULL,
+        num_txs INTEGER NOT NULL,
+        num_spends INTEGER NOT NULL,
+        num_outputs INTEGER NOT NULL,
+        num_actions INTEGER NOT NULL,
+        size INTEGER NOT NULL)",
+            [],
+        )
+        .with_file_line(|| "block_stats")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS last_sync(
+        id INTEGER PRIMARY KEY CHECK (id = 0),
+        timestamp INTEGER NOT NULL,
+        height INTEGER NOT NULL,
+        blocks INTEGER NOT NULL,
+        millis INTEGER NOT NULL)",
+            [],
+        )
+        .with_file_line(|| "last_sync")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS subtree_roots(
+        orchard BOOL NOT NULL,
+        idx INTEGER NOT NULL,
+        root BLOB NOT NULL,
+        height INTEGER NOT NULL,
+        PRIMARY KEY (orchard, idx))",
+            [],
+        )
+        .with_file_line(|| "subtree_roots")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS blck_headers(
+        height INTEGER PRIMARY KEY,
+        hash BLOB NOT NULL,
+        prev_hash BLOB NOT NULL,
+        timestamp INTEGER NOT NULL,
+        anchor BOOL NOT NULL)",
+            [],
+        )
+        .with_file_line(|| "blck_headers")?;
+
     connection
         .execute(
             "CREATE TABLE IF NOT EXISTS swaps(
@@ -302,5 +660,163 @@ pub fn create_schema(connection: &mut Connection, _version: &str) -> Result<()>
         )
         .with_file_line(|| "swaps")?;
 
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS drafts(
+        id_draft INTEGER PRIMARY KEY,
+        account INTEGER NOT NULL,
+        name TEXT NOT NULL,
+        timestamp INTEGER NOT NULL,
+        payment TEXT NOT NULL,
+        UNIQUE (account, name))",
+            [],
+        )
+        .with_file_line(|| "drafts")?;
+
+    // coin control presets, by nullifier so that they survive a rescan
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS note_profiles(
+        id_profile INTEGER PRIMARY KEY,
+        account INTEGER NOT NULL,
+        name TEXT NOT NULL,
+        active BOOL NOT NULL,
+        UNIQUE (account, name))",
+            [],
+        )
+        .with_file_line(|| "note_profiles")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS note_profile_notes(
+        profile INTEGER NOT NULL,
+        nf BLOB NOT NULL,
+        PRIMARY KEY (profile, nf))",
+            [],
+        )
+        .with_file_line(|| "note_profile_notes")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS prices(
+        id_price INTEGER PRIMARY KEY,
+        currency TEXT NOT NULL,
+        timestamp INTEGER NOT NULL,
+        price REAL NOT NULL,
+        UNIQUE (currency, timestamp))",
+            [],
+        )
+        .with_file_line(|| "prices")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS api_keys(
+        id_key INTEGER PRIMARY KEY,
+        name TEXT NOT NULL,
+        key_hash BLOB NOT NULL UNIQUE,
+        read_only BOOL NOT NULL,
+        max_amount INTEGER NOT NULL,
+        created INTEGER NOT NULL,
+        revoked BOOL NOT NULL)",
+            [],
+        )
+        .with_file_line(|| "api_keys")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS api_key_accounts(
+        id_key INTEGER NOT NULL,
+        account INTEGER NOT NULL,
+        PRIMARY KEY (id_key, account))",
+            [],
+        )
+        .with_file_line(|| "api_key_accounts")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS logs(
+        id_log INTEGER PRIMARY KEY,
+        timestamp INTEGER NOT NULL,
+        level INTEGER NOT NULL,
+        target TEXT NOT NULL,
+        message TEXT NOT NULL)",
+            [],
+        )
+        .with_file_line(|| "logs")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS account_groups(
+        id_group INTEGER PRIMARY KEY,
+        name TEXT NOT NULL UNIQUE)",
+            [],
+        )
+        .with_file_line(|| "account_groups")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS account_group_members(
+        account INTEGER PRIMARY KEY,
+        id_group INTEGER NOT NULL)",
+            [],
+        )
+        .with_file_line(|| "account_group_members")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS servers(
+        url TEXT PRIMARY KEY,
+        latency INTEGER NOT NULL,
+        tests INTEGER NOT NULL,
+        failures INTEGER NOT NULL,
+        height INTEGER NOT NULL,
+        last_ok BOOL NOT NULL,
+        updated INTEGER NOT NULL)",
+            [],
+        )
+        .with_file_line(|| "servers")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS events(
+        seq INTEGER PRIMARY KEY AUTOINCREMENT,
+        account INTEGER NOT NULL,
+        kind INTEGER NOT NULL,
+        height INTEGER NOT NULL,
+        txid BLOB,
+        pool INTEGER NOT NULL,
+        id_ref INTEGER NOT NULL,
+        value INTEGER NOT NULL)",
+            [],
+        )
+        .with_file_line(|| "events")?;
+
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS webhook_queue(
+        id_event INTEGER PRIMARY KEY,
+        account INTEGER NOT NULL,
+        url TEXT NOT NULL,
+        body BLOB NOT NULL,
+        signature TEXT NOT NULL,
+        attempts INTEGER NOT NULL,
+        next_attempt INTEGER NOT NULL)",
+            [],
+        )
+        .with_file_line(|| "webhook_queue")?;
+
+    Ok(())
+}
+
+/// Add a column to a table created by an older version of the schema
+fn add_column(connection: &Connection, table: &str, column: &str, def: &str) -> Result<()> {
+    let exists = connection
+        .prepare(&format!("SELECT 1 FROM pragma_table_info('{table}') WHERE name = ?1"))?
+        .exists([column])?;
+    if !exists {
+        connection
+            .execute(&format!("ALTER TABLE {table} ADD COLUMN {column} {def}"), [])
+            .with_file_line(|| format!("{table}.{column}"))?;
+    }
     Ok(())
 }
@@ -1,4 +1,4 @@
-use std::u32;
+use std::{collections::HashMap, u32};
 
 use anyhow::Result;
 use orchard::keys::{FullViewingKey, Scope, SpendingKey};
@@ -13,12 +13,16 @@ use zcash_primitives::legacy::keys::{AccountPrivKey, AccountPubKey};
 use zcash_primitives::legacy::TransparentAddress;
 
 use crate::account::contacts::recipient_contains;
+use crate::account::watch_only::WATCH_ONLY_SCOPE;
 use crate::coin::CoinDef;
 use crate::data::fb::{
-    AccountNameListT, AccountNameT, AccountSigningCapabilitiesT, BalanceT, SpendableT, SpendingT,
-    TransparentAddressT,
+    AccountBalanceT, AccountNameListT, AccountNameT, AccountSigningCapabilitiesT, AssetBalanceT,
+    BalanceT, SpendableT, SpendingT, TransparentAddressT, WalletSummary, WalletSummaryT,
 };
+use crate::db::api_keys::{check_access, Operation};
+use crate::db::chain::{get_sync_height, SNAP_ACCOUNT_DEFAULT, SNAP_LATEST, SNAP_NEAREST};
 use crate::db::contacts::list_contacts;
+use crate::db::mempool::get_unconfirmed_balance_inner;
 use crate::keys::{export_sk_bip38, import_sk_bip38};
 use crate::network::Network;
 use crate::types::{AccountInfo, OrchardAccountInfo, SaplingAccountInfo, TransparentAccountInfo};
@@ -28,10 +32,31 @@ use warp_macros::c_export;
 
 #[c_export]
 pub fn list_accounts(coin: &CoinDef, connection: &Connection) -> Result<AccountNameListT> {
+    select_accounts(coin, connection, None)
+}
+
+/// Accounts of an account group (see `account_groups`)
+#[c_export]
+pub fn list_accounts_in_group(
+    coin: &CoinDef,
+    connection: &Connection,
+    group: u32,
+) -> Result<AccountNameListT> {
+    select_accounts(coin, connection, Some(group))
+}
+
+fn select_accounts(
+    coin: &CoinDef,
+    connection: &Connection,
+    group: Option<u32>,
+) -> Result<AccountNameListT> {
     let mut s = connection.prepare(
-        "SELECT id_account, name, birth, balance, icon, hidden FROM accounts ORDER BY position",
+        "SELECT a.id_account, a.name, a.birth, a.balance, a.icon, a.hidden,
+        a.color, a.emoji, a.notes, g.id_group FROM accounts a
+        LEFT JOIN account_group_members g ON g.account = a.id_account
+        WHERE a.deleted IS NULL AND (?1 IS NULL OR g.id_group = ?1) ORDER BY a.position",
     )?;
-    let rows = s.query_map([], |r| {
+    let rows = s.query_map([group], |r| {
         Ok((
             r.get::<_, u32>(0)?,
             r.get::<_, String>(1)?,
@@ -39,11 +64,15 @@ pub fn list_accounts(coin: &CoinDef, connection: &Connection) -> Result<AccountN
             r.get::<_, u64>(3)?,
             r.get::<_, Option<Vec<u8>>>(4)?,
             r.get::<_, bool>(5)?,
+            r.get::<_, Option<u32>>(6)?,
+            r.get::<_, Option<String>>(7)?,
+            r.get::<_, Option<String>>(8)?,
+            r.get::<_, Option<u32>>(9)?,
         ))
     })?;
     let mut accounts = vec![];
     for r in rows {
-        let (id, name, birth, balance, icon, hidden) = r?;
+        let (id, name, birth, balance, icon, hidden, color, emoji, notes, group) = r?;
         accounts.push(AccountNameT {
             coin: coin.coin,
             id,
@@ -52,6 +81,10 @@ pub fn list_accounts(coin: &CoinDef, connection: &Connection) -> Result<AccountN
             icon,
             balance,
             hidden,
+            color: color.unwrap_or_default(),
+            emoji,
+            notes,
+            group: group.unwrap_or_default(),
         });
     }
     let accounts = AccountNameListT {
@@ -108,7 +141,9 @@ pub fn list_transparent_addresses(
     connection: &Connection,
 ) -> Result<Vec<(TransparentDerPath, String)>> {
     let mut s = connection.prepare(
-        "SELECT account, external, addr_index, address FROM t_addresses ORDER BY addr_index",
+        "SELECT t.account, t.external, t.addr_index, t.address FROM t_addresses t
+        JOIN accounts a ON a.id_account = t.account
+        WHERE a.deleted IS NULL ORDER BY t.addr_index",
     )?;
     let rows = s.query_map([], |r| {
         Ok((
@@ -131,6 +166,17 @@ pub fn list_transparent_addresses(
     Ok(res)
 }
 
+/// Accounts that were created new, with the first height
+/// that can have their notes
+pub fn list_account_scan_starts(connection: &Connection) -> Result<HashMap<u32, u32>> {
+    let mut s = connection.prepare(
+        "SELECT id_account, scan_start FROM accounts
+        WHERE scan_start IS NOT NULL AND deleted IS NULL",
+    )?;
+    let rows = s.query_map([], |r| Ok((r.get::<_, u32>(0)?, r.get::<_, u32>(1)?)))?;
+    Ok(rows.collect::<Result<HashMap<_, _>, _>>()?)
+}
+
 pub fn get_account_info(
     network: &Network,
     connection: &Connection,
@@ -297,7 +343,8 @@ pub fn list_account_tsk(
     connection: &Connection,
     account: u32,
 ) -> Result<Vec<TransparentSK>> {
-    let mut s = connection.prepare("SELECT address, sk FROM t_addresses WHERE account = ?1")?;
+    let mut s = connection
+        .prepare("SELECT address, sk FROM t_addresses WHERE account = ?1 AND sk IS NOT NULL")?;
     let rows = s.query_map([account], |r| {
         Ok((r.get::<_, String>(0)?, r.get::<_, String>(1)?))
     })?;
@@ -312,16 +359,137 @@ pub fn list_account_tsk(
     Ok(tsks)
 }
 
+const MIN_CONFIRMATIONS_PROP: &str = "min_confirmations";
+
+/// Notes and UTXOs need this number of confirmations
+/// before they can be spent. 0 and 1 mean any mined note
+#[c_export]
+pub fn set_min_confirmations(connection: &Connection, account: u32, confirmations: u32) -> Result<()> {
+    set_account_property(
+        connection,
+        account,
+        MIN_CONFIRMATIONS_PROP,
+        &confirmations.to_le_bytes(),
+    )
+}
+
+#[c_export]
+pub fn get_min_confirmations(connection: &Connection, account: u32) -> Result<u32> {
+    let value = get_account_property(connection, account, MIN_CONFIRMATIONS_PROP)?;
+    let confirmations = value
+        .try_into()
+        .map(u32::from_le_bytes)
+        .unwrap_or_default();
+    Ok(confirmations)
+}
+
+const SNAP_POLICY_PROP: &str = "snap_policy";
+
+/// Default anchor snap policy of the payments of the account
+/// (SNAP_NEAREST, SNAP_EXACT or SNAP_LATEST)
+#[c_export]
+pub fn set_snap_policy(connection: &Connection, account: u32, policy: u8) -> Result<()> {
+    if !(SNAP_NEAREST..=SNAP_LATEST).contains(&policy) {
+        anyhow::bail!("Invalid snap policy {}", policy);
+    }
+    set_account_property(connection, account, SNAP_POLICY_PROP, &[policy])
+}
+
+#[c_export]
+pub fn get_snap_policy(connection: &Connection, account: u32) -> Result<u8> {
+    let value = get_account_property(connection, account, SNAP_POLICY_PROP)?;
+    let policy = value.first().copied().unwrap_or(SNAP_NEAREST);
+    Ok(policy)
+}
+
+/// Replace SNAP_ACCOUNT_DEFAULT by the policy of the account
+pub fn resolve_snap_policy(connection: &Connection, account: u32, policy: u8) -> Result<u8> {
+    match policy {
+        SNAP_ACCOUNT_DEFAULT => get_snap_policy(connection, account),
+        policy => Ok(policy),
+    }
+}
+
+const CHANGE_OUTPUTS_PROP: &str = "change_outputs";
+/// Most change outputs of a payment
+pub const MAX_CHANGE_OUTPUTS: u8 = 8;
+
+/// Default number of shielded change outputs of the payments of the account
+#[c_export]
+pub fn set_change_outputs(connection: &Connection, account: u32, count: u8) -> Result<()> {
+    if !(1..=MAX_CHANGE_OUTPUTS).contains(&count) {
+        anyhow::bail!(
+            "The number of change outputs must be between 1 and {}",
+            MAX_CHANGE_OUTPUTS
+        );
+    }
+    set_account_property(connection, account, CHANGE_OUTPUTS_PROP, &[count])
+}
+
+#[c_export]
+pub fn get_change_outputs(connection: &Connection, account: u32) -> Result<u8> {
+    let value = get_account_property(connection, account, CHANGE_OUTPUTS_PROP)?;
+    let count = value.first().copied().unwrap_or(1);
+    Ok(count)
+}
+
+/// Replace 0 by the number of change outputs of the account
+pub fn resolve_change_outputs(connection: &Connection, account: u32, count: u8) -> Result<u8> {
+    match count {
+        0 => get_change_outputs(connection, account),
+        count => Ok(count.min(MAX_CHANGE_OUTPUTS)),
+    }
+}
+
+/// Highest height of a note that has `confirmations` at `height`
+pub fn confirmed_height(height: u32, confirmations: u32) -> u32 {
+    height.saturating_sub(confirmations.saturating_sub(1))
+}
+
+/// `min_confirmations` is 0 for the default of the account
 #[c_export]
-pub fn get_balance(connection: &Connection, account: u32, height: u32) -> Result<BalanceT> {
+pub fn get_balance(
+    connection: &Connection,
+    session: u32,
+    account: u32,
+    height: u32,
+    min_confirmations: u32,
+) -> Result<BalanceT> {
+    check_access(connection, session, &Operation::Read { account })?;
+    get_balance_inner(connection, account, height, min_confirmations)
+}
+
+pub fn get_balance_inner(
+    connection: &Connection,
+    account: u32,
+    height: u32,
+    min_confirmations: u32,
+) -> Result<BalanceT> {
     // includes spent but not confirmed
     // for display on the balance page
-    let height = if height == 0 { u32::MAX } else { height };
+    let min_confirmations = match min_confirmations {
+        0 => get_min_confirmations(connection, account)?,
+        c => c,
+    };
+    let height = match height {
+        0 if min_confirmations > 1 => get_sync_height(connection)?.height,
+        0 => u32::MAX,
+        h => h,
+    };
+    let height = confirmed_height(height, min_confirmations);
     let transparent = connection
         .query_row(
             "SELECT SUM(value) FROM utxos
-        WHERE account = ?1 AND height <= ?2 AND spent IS NULL",
-            params![account, height],
+        WHERE account = ?1 AND height <= ?2 AND spent IS NULL AND external <> ?3",
+            params![account, height, WATCH_ONLY_SCOPE],
+            |r| r.get::<_, Option<u64>>(0),
+        )?
+        .unwrap_or_default();
+    let watch_only = connection
+        .query_row(
+            "SELECT SUM(value) FROM utxos
+        WHERE account = ?1 AND height <= ?2 AND spent IS NULL AND external = ?3",
+            params![account, height, WATCH_ONLY_SCOPE],
             |r| r.get::<_, Option<u64>>(0),
         )?
         .unwrap_or_default();
@@ -338,19 +506,82 @@ pub fn get_balance(connection: &Connection, account: u32, height: u32) -> Result
         .query_row(
             "SELECT SUM(value) FROM notes
         WHERE account = ?1 AND height <= ?2 AND orchard = 1
-        AND spent IS NULL",
+        AND spent IS NULL AND asset IS NULL",
             params![account, height],
             |r| r.get::<_, Option<u64>>(0),
         )?
         .unwrap_or_default();
+    let assets = get_asset_balances(connection, account, height)?;
     let b = BalanceT {
         transparent,
         sapling,
         orchard,
+        watch_only,
+        assets: Some(assets),
     };
     Ok(b)
 }
 
+/// Balances of the ZSA notes, by asset base. They are not in the
+/// ZEC balance
+fn get_asset_balances(
+    connection: &Connection,
+    account: u32,
+    height: u32,
+) -> Result<Vec<AssetBalanceT>> {
+    let mut s = connection.prepare(
+        "SELECT asset, SUM(value) FROM notes
+        WHERE account = ?1 AND height <= ?2 AND asset IS NOT NULL
+        AND spent IS NULL GROUP BY asset ORDER BY asset",
+    )?;
+    let rows = s.query_map(params![account, height], |r| {
+        Ok(AssetBalanceT {
+            asset: Some(r.get::<_, Vec<u8>>(0)?),
+            value: r.get(1)?,
+        })
+    })?;
+    let assets = rows.collect::<Result<Vec<_>, _>>()?;
+    Ok(assets)
+}
+
+/// Balances of every visible account and their totals
+#[c_export]
+pub fn get_wallet_summary(connection: &Connection, session: u32) -> Result<WalletSummaryT> {
+    let height = get_sync_height(connection)?.height;
+    let mut s = connection.prepare(
+        "SELECT id_account, name FROM accounts
+        WHERE hidden = 0 AND deleted IS NULL ORDER BY position",
+    )?;
+    let rows = s.query_map([], |r| Ok((r.get::<_, u32>(0)?, r.get::<_, String>(1)?)))?;
+    let mut total = BalanceT::default();
+    let mut unconfirmed = 0;
+    let mut accounts = vec![];
+    for r in rows {
+        let (account, name) = r?;
+        check_access(connection, session, &Operation::Read { account })?;
+        let balance = get_balance_inner(connection, account, 0, 0)?;
+        let account_unconfirmed = get_unconfirmed_balance_inner(connection, account)?;
+        total.transparent += balance.transparent;
+        total.sapling += balance.sapling;
+        total.orchard += balance.orchard;
+        total.watch_only += balance.watch_only;
+        unconfirmed += account_unconfirmed;
+        accounts.push(AccountBalanceT {
+            account,
+            name: Some(name),
+            balance: Some(Box::new(balance)),
+            unconfirmed: account_unconfirmed,
+        });
+    }
+    let summary = WalletSummaryT {
+        height,
+        total: Some(Box::new(total)),
+        unconfirmed,
+        accounts: Some(accounts),
+    };
+    Ok(summary)
+}
+
 #[c_export]
 pub fn get_account_signing_capabilities(
     network: &Network,
@@ -396,6 +627,20 @@ pub fn get_account_signing_capabilities(
     Ok(account_caps)
 }
 
+/// True if the wallet has transparent accounts and none
+/// of them has a sapling or orchard key
+#[c_export]
+pub fn is_transparent_only(connection: &Connection) -> Result<bool> {
+    let transparent_only = connection.query_row(
+        "SELECT EXISTS(SELECT 1 FROM t_accounts)
+        AND NOT EXISTS(SELECT 1 FROM s_accounts)
+        AND NOT EXISTS(SELECT 1 FROM o_accounts)",
+        [],
+        |r| r.get::<_, bool>(0),
+    )?;
+    Ok(transparent_only)
+}
+
 #[c_export]
 pub fn get_account_property(connection: &Connection, account: u32, name: &str) -> Result<Vec<u8>> {
     let value = connection
@@ -428,14 +673,16 @@ pub fn set_account_property(
 pub fn get_spendings(
     network: &Network,
     connection: &Connection,
+    session: u32,
     account: u32,
     timestamp: u32,
 ) -> Result<Vec<SpendingT>> {
+    check_access(connection, session, &Operation::Read { account })?;
     let contacts = list_contacts(network, connection)?;
     let mut s = connection.prepare(
         "SELECT -SUM(value) as v, t.address FROM txs t
         WHERE account = ?1 AND timestamp >= ?2 AND value < 0
-        AND t.address IS NOT NULL GROUP BY t.address ORDER BY v ASC LIMIT 5",
+        AND t.address IS NOT NULL AND t.transfer IS NULL GROUP BY t.address ORDER BY v ASC LIMIT 5",
     )?;
     let rows = s.query_map(params![account, timestamp], |r| {
         Ok((r.get::<_, u64>(0)?, r.get::<_, Option<String>>(1)?))
@@ -475,20 +722,28 @@ pub fn get_unspent_before(connection: &Connection, account: u32, height: u32) ->
     let spent = connection.query_row(
         "WITH n(value, account, height, spent, expiration) AS (
 	SELECT value, account, height, spent, expiration FROM notes UNION ALL
-	SELECT value, account, height, spent, expiration FROM utxos )
+	SELECT value, account, height, spent, expiration FROM utxos WHERE external <> ?3 )
     SELECT SUM(value) FROM n WHERE account = ?1 AND height <= ?2
     AND expiration IS NULL AND spent IS NULL",
-        [account, height],
+        [account, height, WATCH_ONLY_SCOPE],
         |r| r.get::<_, Option<u64>>(0),
     )?;
     Ok(spent.unwrap_or_default())
 }
 
 #[c_export]
-pub fn get_spendable(connection: &Connection, account: u32, height: u32) -> Result<SpendableT> {
+pub fn get_spendable(
+    connection: &Connection,
+    session: u32,
+    account: u32,
+    height: u32,
+) -> Result<SpendableT> {
+    check_access(connection, session, &Operation::Read { account })?;
     let unconfirmed = get_unconfirmed_spent(connection, account)?;
     let total = get_unspent_before(connection, account, u32::MAX)?;
-    let spendable = get_unspent_before(connection, account, height)?;
+    let min_confirmations = get_min_confirmations(connection, account)?;
+    let spendable =
+        get_unspent_before(connection, account, confirmed_height(height, min_confirmations))?;
     let immature = total - spendable;
     let sp = SpendableT {
         total,
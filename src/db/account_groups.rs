@@ -0,0 +1,141 @@
+use anyhow::Result;
+use rusqlite::{params, Connection, OptionalExtension as _};
+
+use crate::{
+    data::fb::{AccountGroupT, BalanceT},
+    db::{
+        account::get_balance_inner,
+        api_keys::{check_access, Operation},
+    },
+};
+use warp_macros::c_export;
+
+/*
+    Account groups
+
+    Accounts can be put in named groups (personal, business...)
+    to keep portfolios apart. An account is in at most one group.
+    The balance of a group is the sum of the balances of its
+    accounts, and its history is the list of the transactions of
+    its accounts (see `get_group_txs`). A transfer between two
+    accounts of a group is in its history twice, once per account.
+
+    Groups are local: they are not in the backups of the accounts.
+*/
+
+#[c_export]
+pub fn create_account_group(connection: &Connection, name: &str) -> Result<u32> {
+    let id = connection.query_row(
+        "INSERT INTO account_groups(name) VALUES (?1)
+        RETURNING id_group",
+        [name],
+        |r| r.get::<_, u32>(0),
+    )?;
+    Ok(id)
+}
+
+#[c_export]
+pub fn list_account_groups(connection: &Connection) -> Result<Vec<AccountGroupT>> {
+    let mut s = connection.prepare("SELECT id_group, name FROM account_groups ORDER BY name")?;
+    let rows = s.query_map([], |r| Ok((r.get::<_, u32>(0)?, r.get::<_, String>(1)?)))?;
+    let mut groups = vec![];
+    for r in rows {
+        let (id, name) = r?;
+        let group = AccountGroupT {
+            id,
+            name: Some(name),
+            accounts: Some(list_group_accounts(connection, id)?),
+        };
+        groups.push(group);
+    }
+    Ok(groups)
+}
+
+#[c_export]
+pub fn get_account_group(connection: &Connection, id: u32) -> Result<AccountGroupT> {
+    let name = connection
+        .query_row(
+            "SELECT name FROM account_groups WHERE id_group = ?1",
+            [id],
+            |r| r.get::<_, String>(0),
+        )
+        .optional()?
+        .ok_or(anyhow::anyhow!("No account group {id}"))?;
+    let group = AccountGroupT {
+        id,
+        name: Some(name),
+        accounts: Some(list_group_accounts(connection, id)?),
+    };
+    Ok(group)
+}
+
+#[c_export]
+pub fn edit_account_group_name(connection: &Connection, id: u32, name: &str) -> Result<()> {
+    connection.execute(
+        "UPDATE account_groups SET name = ?2 WHERE id_group = ?1",
+        params![id, name],
+    )?;
+    Ok(())
+}
+
+/// Delete a group. Its accounts are no longer in a group
+#[c_export]
+pub fn delete_account_group(connection: &Connection, id: u32) -> Result<()> {
+    connection.execute(
+        "DELETE FROM account_group_members WHERE id_group = ?1",
+        [id],
+    )?;
+    connection.execute("DELETE FROM account_groups WHERE id_group = ?1", [id])?;
+    Ok(())
+}
+
+/// Move an account to a group, 0 to take it out of its group
+#[c_export]
+pub fn set_account_group(connection: &Connection, account: u32, id: u32) -> Result<()> {
+    if id == 0 {
+        connection.execute(
+            "DELETE FROM account_group_members WHERE account = ?1",
+            [account],
+        )?;
+        return Ok(());
+    }
+    get_account_group(connection, id)?;
+    connection.execute(
+        "INSERT INTO account_group_members(account, id_group)
+        VALUES (?1, ?2) ON CONFLICT (account) DO UPDATE SET id_group = excluded.id_group",
+        params![account, id],
+    )?;
+    Ok(())
+}
+
+/// Sum of the balances of the accounts of a group at `height`,
+/// with their default number of confirmations
+#[c_export]
+pub fn get_group_balance(
+    connection: &Connection,
+    session: u32,
+    id: u32,
+    height: u32,
+) -> Result<BalanceT> {
+    let mut balance = BalanceT::default();
+    for account in list_group_accounts(connection, id)? {
+        check_access(connection, session, &Operation::Read { account })?;
+        let b = get_balance_inner(connection, account, height, 0)?;
+        balance.transparent += b.transparent;
+        balance.sapling += b.sapling;
+        balance.orchard += b.orchard;
+        balance.watch_only += b.watch_only;
+    }
+    Ok(balance)
+}
+
+/// Accounts of a group, in the order of the account list
+pub fn list_group_accounts(connection: &Connection, id: u32) -> Result<Vec<u32>> {
+    let mut s = connection.prepare(
+        "SELECT m.account FROM account_group_members m
+        JOIN accounts a ON a.id_account = m.account
+        WHERE m.id_group = ?1 AND a.deleted IS NULL ORDER BY a.position",
+    )?;
+    let rows = s.query_map([id], |r| r.get::<_, u32>(0))?;
+    Ok(rows.collect::<Result<Vec<_>, _>>()?)
+}
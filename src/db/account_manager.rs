@@ -1,6 +1,13 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs::File,
+    io::Read,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
 use anyhow::Result;
-use bip39::{Mnemonic, Seed};
-use rusqlite::{params, Connection, OptionalExtension};
+use bip39::Seed;
+use rusqlite::{params, types::ValueRef, Connection, OptionalExtension};
 use zcash_client_backend::{
     encoding::{
         decode_extended_full_viewing_key, decode_extended_spending_key,
@@ -18,11 +25,20 @@ use zcash_primitives::{
 };
 
 use crate::{
-    data::fb::{AccountSigningCapabilities, AccountSigningCapabilitiesT},
-    db::account::change_account_dindex,
+    account::descriptor::parse_transparent_descriptor,
+    data::fb::{
+        AccountSigningCapabilities, AccountSigningCapabilitiesT, BulkAccounts, BulkAccountsT,
+        ViewingKeys, ViewingKeysT,
+    },
+    db::{
+        account::change_account_dindex,
+        api_keys::{check_access, Operation},
+        chain::{get_sync_height, rewind_offline},
+    },
+    fb_unwrap,
     keys::{
         decode_extended_private_key, decode_extended_public_key, export_sk_bip38, import_sk_bip38,
-        to_extended_full_viewing_key, AccountKeys,
+        parse_mnemonic, to_extended_full_viewing_key, AccountKeys,
     },
     network::Network,
     types::{OrchardAccountInfo, SaplingAccountInfo, TransparentAccountInfo},
@@ -32,27 +48,37 @@ use crate::{
 use warp_macros::c_export;
 
 use super::account::get_account_info;
+use super::audit::{audit, ACCOUNT_DELETE};
 
-pub fn parse_seed_phrase(phrase: &str) -> Result<Seed> {
+/// The BIP-39 passphrase (aka the 25th word) is not part of the phrase
+pub fn parse_seed_phrase(phrase: &str, passphrase: &str) -> Result<Seed> {
+    let (mnemonic, _) = parse_mnemonic(phrase)?;
+    let seed = Seed::new(&mnemonic, passphrase);
+    Ok(seed)
+}
+
+/// Accounts created before the passphrase was a separate parameter
+/// have it as an extra word at the end of their stored seed phrase
+pub fn split_stored_seed_phrase(phrase: &str) -> (String, Option<String>) {
     let words = phrase.split_whitespace().collect::<Vec<_>>();
     let len = words.len();
-    let (phrase, password) = if len % 3 == 1 {
-        // extra word
+    if len % 3 == 1 {
         let phrase = words[0..len - 1].join(" ");
-        let password = words[len - 1].to_string();
-        (phrase, Some(password))
+        let passphrase = words[len - 1].to_string();
+        (phrase, Some(passphrase))
     } else {
         (phrase.to_string(), None)
-    };
-
-    let mnemonic = Mnemonic::from_phrase(&phrase, bip39::Language::English)?;
-    let seed = Seed::new(&mnemonic, &password.unwrap_or_default());
-    Ok(seed)
+    }
 }
 
-pub fn detect_key(network: &Network, key: &str, acc_index: u32) -> Result<AccountKeys> {
-    let ak = if let Ok(_) = parse_seed_phrase(key) {
-        AccountKeys::from_seed(network, key, acc_index)?
+pub fn detect_key(
+    network: &Network,
+    key: &str,
+    passphrase: &str,
+    acc_index: u32,
+) -> Result<AccountKeys> {
+    let ak = if let Ok(_) = parse_seed_phrase(key, passphrase) {
+        AccountKeys::from_seed(network, key, passphrase, acc_index)?
     } else if let Ok(ssk) =
         decode_extended_spending_key(network.hrp_sapling_extended_spending_key(), key)
     {
@@ -165,6 +191,8 @@ pub fn detect_key(network: &Network, key: &str, acc_index: u32) -> Result<Accoun
             osk: None,
             ovk: None,
         }
+    } else if let Ok(xkey) = parse_transparent_descriptor(network, key) {
+        detect_key(network, &xkey, "", 0)?
     } else if let Ok(taddr) = TransparentAddress::decode(network, key) {
         AccountKeys {
             seed: None,
@@ -188,28 +216,61 @@ pub fn detect_key(network: &Network, key: &str, acc_index: u32) -> Result<Accoun
 
 #[c_export]
 pub fn is_valid_key(network: &Network, key: &str) -> Result<bool> {
-    let valid = detect_key(network, key, 0).is_ok();
+    let valid = detect_key(network, key, "", 0).is_ok();
     Ok(valid)
 }
 
+/// `passphrase` is only used with a seed phrase. It is not stored,
+/// only the fact that the account has one
 #[c_export]
 pub fn create_new_account(
     network: &Network,
     connection: &mut Connection,
+    session: u32,
     name: &str,
     key: &str,
+    passphrase: &str,
     acc_index: u32,
     birth: u32,
     pools: u8,
     is_new: bool,
 ) -> Result<u32> {
-    let ak = detect_key(network, &key, acc_index)?;
-    let dindex = ak.dindex;
+    check_access(connection, session, &Operation::Admin)?;
+    let ak = detect_key(network, &key, passphrase, acc_index)?;
+    let has_passphrase = ak.seed.is_some() && !passphrase.is_empty();
     let db_tx = connection.transaction()?;
-    let account = create_account(
+    let account = store_account_keys(
+        network,
         &db_tx,
         name,
+        &ak,
+        has_passphrase,
+        acc_index,
+        birth,
+        pools,
+        is_new,
+    )?;
+    db_tx.commit()?;
+    Ok(account)
+}
+
+pub(crate) fn store_account_keys(
+    network: &Network,
+    db_tx: &Connection,
+    name: &str,
+    ak: &AccountKeys,
+    has_passphrase: bool,
+    acc_index: u32,
+    birth: u32,
+    pools: u8,
+    is_new: bool,
+) -> Result<u32> {
+    let dindex = ak.dindex;
+    let account = create_account(
+        db_tx,
+        name,
         ak.seed.as_deref(),
+        has_passphrase,
         acc_index,
         dindex,
         birth,
@@ -217,36 +278,218 @@ pub fn create_new_account(
     )?;
     if pools & 1 != 0 {
         if let Some(ti) = ak.to_transparent() {
-            create_transparent_account(network, &db_tx, account, &ti)?;
+            create_transparent_account(network, db_tx, account, &ti)?;
             // this is not merged in the 'if' below to keep the addresses
             // in this order in the db (it looks nicer)
             if ti.vk.is_some() && dindex != 0 {
-                create_transparent_address(network, &db_tx, account, 0, 0, &ti)?;
+                create_transparent_address(network, db_tx, account, 0, 0, &ti)?;
             }
-            create_transparent_address(network, &db_tx, account, 0, dindex, &ti)?;
+            create_transparent_address(network, db_tx, account, 0, dindex, &ti)?;
             if ti.vk.is_some() {
-                create_transparent_address(network, &db_tx, account, 1, 0, &ti)?; // change
+                create_transparent_address(network, db_tx, account, 1, 0, &ti)?; // change
             }
         } 
     }
     if pools & 2 != 0 {
         if let Some(si) = ak.to_sapling() {
-            create_sapling_account(network, &db_tx, account, &si)?;
+            create_sapling_account(network, db_tx, account, &si)?;
         }
     }
     if pools & 4 != 0 {
         if let Some(oi) = ak.to_orchard() {
-            create_orchard_account(network, &db_tx, account, &oi)?;
+            create_orchard_account(network, db_tx, account, &oi)?;
         }
     }
-    db_tx.commit()?;
     Ok(account)
 }
 
+/// Create accounts from a list of keys (typically the UFVKs of the
+/// deposit addresses of a custodian) in a single db transaction.
+/// The accounts share the same birth height and are named
+/// `prefix` followed by their index in the list.
+/// A key that is already in the wallet (or earlier in the list)
+/// does not create a new account: its existing account is reported instead.
+/// `progress` is called with the number of keys processed and the total
+pub fn create_accounts_bulk<F: FnMut(u32, u32)>(
+    network: &Network,
+    connection: &mut Connection,
+    keys: &[String],
+    prefix: &str,
+    birth: u32,
+    pools: u8,
+    mut progress: F,
+) -> Result<BulkAccountsT> {
+    let total = keys.len() as u32;
+    let db_tx = connection.transaction()?;
+    let mut known = list_account_fingerprints(&db_tx)?;
+    let mut accounts = vec![];
+    let mut duplicates = 0;
+    for (i, key) in keys.iter().enumerate() {
+        let ak = detect_key(network, key.trim(), "", 0)
+            .with_file_line(|| format!("Invalid key at index {i}"))?;
+        let fingerprints = account_fingerprints(network, &ak, pools)?;
+        let existing = fingerprints.iter().find_map(|fp| known.get(fp)).copied();
+        let account = match existing {
+            Some(account) => {
+                duplicates += 1;
+                account
+            }
+            None => {
+                let name = format!("{prefix}{i}");
+                let account =
+                    store_account_keys(network, &db_tx, &name, &ak, false, 0, birth, pools, false)?;
+                for fp in fingerprints {
+                    known.insert(fp, account);
+                }
+                account
+            }
+        };
+        accounts.push(account);
+        progress(i as u32 + 1, total);
+    }
+    db_tx.commit()?;
+    Ok(BulkAccountsT {
+        accounts: Some(accounts),
+        duplicates,
+    })
+}
+
+/// See `create_accounts_bulk`. Use a JOB_CREATE_ACCOUNTS job
+/// to follow the progress
+#[c_export]
+pub fn import_viewing_keys(
+    network: &Network,
+    connection: &mut Connection,
+    session: u32,
+    keys: &ViewingKeysT,
+) -> Result<BulkAccountsT> {
+    check_access(connection, session, &Operation::Admin)?;
+    create_accounts_bulk(
+        network,
+        connection,
+        fb_unwrap!(keys.keys),
+        keys.prefix.as_deref().unwrap_or_default(),
+        keys.birth,
+        keys.pools,
+        |_, _| {},
+    )
+}
+
+/// Identify an account by its viewing keys, in the form they
+/// are stored in the db: (pool, key)
+pub(crate) fn account_fingerprints(
+    network: &Network,
+    ak: &AccountKeys,
+    pools: u8,
+) -> Result<Vec<(u8, Vec<u8>)>> {
+    let mut fingerprints = vec![];
+    if pools & 1 != 0 {
+        if let Some(taddr) = ak.taddr.as_ref() {
+            fingerprints.push((0, taddr.encode(network).into_bytes()));
+        }
+    }
+    if pools & 2 != 0 {
+        if let Some(svk) = ak.svk.as_ref() {
+            let efvk = to_extended_full_viewing_key(svk)?;
+            let vk = encode_extended_full_viewing_key(
+                network.hrp_sapling_extended_full_viewing_key(),
+                &efvk,
+            );
+            fingerprints.push((1, vk.into_bytes()));
+        }
+    }
+    if pools & 4 != 0 {
+        if let Some(ovk) = ak.ovk.as_ref() {
+            fingerprints.push((2, ovk.to_bytes().to_vec()));
+        }
+    }
+    Ok(fingerprints)
+}
+
+pub(crate) fn list_account_fingerprints(
+    connection: &Connection,
+) -> Result<HashMap<(u8, Vec<u8>), u32>> {
+    let mut fingerprints = HashMap::new();
+    for (pool, sql) in [
+        (0u8, "SELECT account, address FROM t_accounts"),
+        (1, "SELECT account, vk FROM s_accounts"),
+        (2, "SELECT account, vk FROM o_accounts"),
+    ] {
+        let mut s = connection.prepare(sql)?;
+        let rows = s.query_map([], |r| {
+            let account = r.get::<_, u32>(0)?;
+            let key = match r.get_ref(1)? {
+                ValueRef::Text(t) | ValueRef::Blob(t) => t.to_vec(),
+                _ => vec![],
+            };
+            Ok((account, key))
+        })?;
+        for r in rows {
+            let (account, key) = r?;
+            fingerprints.insert((pool, key), account);
+        }
+    }
+    Ok(fingerprints)
+}
+
+#[c_export]
+pub fn has_account_passphrase(connection: &Connection, account: u32) -> Result<bool> {
+    let passphrase = connection
+        .query_row(
+            "SELECT passphrase FROM accounts WHERE id_account = ?1",
+            [account],
+            |r| r.get::<_, bool>(0),
+        )
+        .with_file_line(|| format!("No account {account}"))?;
+    Ok(passphrase)
+}
+
+/// Check that the seed phrase of the account with this passphrase
+/// derives the keys of the account, for example after a restore
+#[c_export]
+pub fn check_account_passphrase(
+    network: &Network,
+    connection: &Connection,
+    account: u32,
+    passphrase: &str,
+) -> Result<bool> {
+    let ai = get_account_info(network, connection, account)?;
+    let seed = ai.seed.as_ref().ok_or(anyhow::anyhow!("No Seed"))?;
+    let (phrase, extra) = split_stored_seed_phrase(seed);
+    let passphrase = extra.as_deref().unwrap_or(passphrase);
+    let ak = AccountKeys::from_seed(network, &phrase, passphrase, ai.aindex)?;
+    let matches = if let Some(si) = ai.sapling.as_ref() {
+        ak.svk.map(|vk| vk.to_bytes()) == Some(si.vk.to_bytes())
+    } else if let Some(oi) = ai.orchard.as_ref() {
+        ak.ovk.map(|vk| vk.to_bytes()) == Some(oi.vk.to_bytes())
+    } else if let Some(tvk) = ai.transparent.as_ref().and_then(|ti| ti.vk.as_ref()) {
+        ak.tvk.map(|vk| vk.serialize()) == Some(tvk.serialize())
+    } else {
+        false
+    };
+    Ok(matches)
+}
+
+/// Seed phrase and passphrase of the account, after checking the passphrase
+pub fn get_seed_phrase(
+    network: &Network,
+    connection: &Connection,
+    account: u32,
+    passphrase: &str,
+) -> Result<(String, String)> {
+    if !check_account_passphrase(network, connection, account, passphrase)? {
+        anyhow::bail!("Invalid passphrase");
+    }
+    let ai = get_account_info(network, connection, account)?;
+    let (phrase, extra) = split_stored_seed_phrase(&ai.seed.unwrap());
+    Ok((phrase, extra.unwrap_or(passphrase.to_string())))
+}
+
 pub fn create_account(
     connection: &Connection,
     name: &str,
     seed: Option<&str>,
+    passphrase: bool,
     acc_index: u32,
     addr_index: u32,
     birth: u32,
@@ -254,11 +497,23 @@ pub fn create_account(
 ) -> Result<u32> {
     let position =
         connection.query_row("SELECT COUNT(*) FROM accounts", [], |r| r.get::<_, u32>(0))?;
+    // a new account cannot receive anything before the current
+    // sync height, the trial decryption skips the earlier blocks
+    let scan_start = if is_new {
+        connection.query_row("SELECT MAX(height) + 1 FROM blcks", [], |r| {
+            r.get::<_, Option<u32>>(0)
+        })?
+    } else {
+        None
+    };
     connection.execute(
         "INSERT INTO accounts
-        (name, position, seed, aindex, dindex, birth, balance, saved, hidden)
-        VALUES (?1, ?2, ?3, ?4, ?5, ?6, 0, ?7, 0)",
-        params![name, position, seed, acc_index, addr_index, birth, !is_new],
+        (name, position, seed, passphrase, aindex, dindex, birth, balance, saved, hidden,
+        scan_start)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, 0, ?8, 0, ?9)",
+        params![
+            name, position, seed, passphrase, acc_index, addr_index, birth, !is_new, scan_start
+        ],
     )?;
     let account = connection.last_insert_rowid();
     Ok(account as u32)
@@ -420,6 +675,33 @@ pub fn edit_account_icon(connection: &Connection, account: u32, image: &[u8]) ->
     Ok(())
 }
 
+#[c_export]
+pub fn edit_account_color(connection: &Connection, account: u32, color: u32) -> Result<()> {
+    connection.execute(
+        "UPDATE accounts SET color = ?2 WHERE id_account = ?1",
+        params![account, color],
+    )?;
+    Ok(())
+}
+
+#[c_export]
+pub fn edit_account_emoji(connection: &Connection, account: u32, emoji: &str) -> Result<()> {
+    connection.execute(
+        "UPDATE accounts SET emoji = ?2 WHERE id_account = ?1",
+        params![account, emoji],
+    )?;
+    Ok(())
+}
+
+#[c_export]
+pub fn edit_account_notes(connection: &Connection, account: u32, notes: &str) -> Result<()> {
+    connection.execute(
+        "UPDATE accounts SET notes = ?2 WHERE id_account = ?1",
+        params![account, notes],
+    )?;
+    Ok(())
+}
+
 pub fn get_account_by_name(connection: &Connection, name: &str) -> Result<Option<u32>> {
     let account = connection
         .query_row(
@@ -431,7 +713,11 @@ pub fn get_account_by_name(connection: &Connection, name: &str) -> Result<Option
     Ok(account)
 }
 
-pub fn get_account_seed(connection: &Connection, account: u32) -> Result<(Seed, u32)> {
+pub fn get_account_seed(
+    connection: &Connection,
+    account: u32,
+    passphrase: &str,
+) -> Result<(Seed, u32)> {
     let (phrase, aindex) = connection
         .query_row(
             "SELECT seed, aindex FROM accounts WHERE id_account = ?1",
@@ -440,7 +726,8 @@ pub fn get_account_seed(connection: &Connection, account: u32) -> Result<(Seed,
         )
         .with_file_line(|| format!("{account}"))?;
     let phrase = phrase.ok_or(anyhow::anyhow!("No seed"))?;
-    let seed = parse_seed_phrase(&phrase)?;
+    let (phrase, extra) = split_stored_seed_phrase(&phrase);
+    let seed = parse_seed_phrase(&phrase, extra.as_deref().unwrap_or(passphrase))?;
     Ok((seed, aindex))
 }
 
@@ -561,7 +848,13 @@ pub fn edit_account_birth(connection: &Connection, account: u32, birth: u32) ->
 }
 
 #[c_export]
-pub fn delete_account(connection: &Connection, account: u32) -> Result<()> {
+pub fn delete_account(connection: &Connection, session: u32, account: u32) -> Result<()> {
+    check_access(connection, session, &Operation::Admin)?;
+    drop_account(connection, account)
+}
+
+fn drop_account(connection: &Connection, account: u32) -> Result<()> {
+    audit(connection, ACCOUNT_DELETE, account, "")?;
     connection.execute("DELETE FROM notes WHERE account = ?1", params![account])?;
     connection.execute("DELETE FROM utxos WHERE account = ?1", params![account])?;
     connection.execute(
@@ -572,9 +865,45 @@ pub fn delete_account(connection: &Connection, account: u32) -> Result<()> {
         "DELETE FROM utxo_spends WHERE account = ?1",
         params![account],
     )?;
+    connection.execute(
+        "DELETE FROM t_scan_progress WHERE account = ?1",
+        params![account],
+    )?;
+    connection.execute(
+        "DELETE FROM t_sweep_progress WHERE account = ?1",
+        params![account],
+    )?;
+    connection.execute(
+        "DELETE FROM script_utxos WHERE account = ?1",
+        params![account],
+    )?;
     connection.execute("DELETE FROM witnesses WHERE account = ?1", params![account])?;
     connection.execute("DELETE FROM txs WHERE account = ?1", params![account])?;
+    connection.execute(
+        "UPDATE txs SET transfer = NULL WHERE transfer = ?1",
+        params![account],
+    )?;
     connection.execute("DELETE FROM txdetails WHERE account = ?1", params![account])?;
+    connection.execute(
+        "DELETE FROM tx_addresses WHERE account = ?1",
+        params![account],
+    )?;
+    connection.execute(
+        "DELETE FROM tx_details_failures WHERE account = ?1",
+        params![account],
+    )?;
+    connection.execute(
+        "DELETE FROM broadcast_txs WHERE account = ?1",
+        params![account],
+    )?;
+    connection.execute("DELETE FROM acks WHERE account = ?1", params![account])?;
+    connection.execute("DELETE FROM tex_changes WHERE account = ?1", params![account])?;
+    connection.execute("DELETE FROM outbox WHERE account = ?1", params![account])?;
+    connection.execute("DELETE FROM events WHERE account = ?1", params![account])?;
+    connection.execute(
+        "DELETE FROM webhook_queue WHERE account = ?1",
+        params![account],
+    )?;
     connection.execute(
         "DELETE FROM accounts WHERE id_account = ?1",
         params![account],
@@ -596,11 +925,274 @@ pub fn delete_account(connection: &Connection, account: u32) -> Result<()> {
         params![account],
     )?;
     connection.execute("DELETE FROM msgs WHERE account = ?1", params![account])?;
+    connection.execute(
+        "DELETE FROM contact_group_members WHERE id_group IN
+        (SELECT id_group FROM contact_groups WHERE account = ?1)",
+        params![account],
+    )?;
+    connection.execute(
+        "DELETE FROM contact_groups WHERE account = ?1",
+        params![account],
+    )?;
     connection.execute("DELETE FROM contacts WHERE account = ?1", params![account])?;
     connection.execute("DELETE FROM props WHERE account = ?1", params![account])?;
+    connection.execute("DELETE FROM drafts WHERE account = ?1", params![account])?;
+    connection.execute(
+        "DELETE FROM account_group_members WHERE account = ?1",
+        params![account],
+    )?;
+    connection.execute(
+        "DELETE FROM note_profile_notes WHERE profile IN
+        (SELECT id_profile FROM note_profiles WHERE account = ?1)",
+        params![account],
+    )?;
+    connection.execute(
+        "DELETE FROM note_profiles WHERE account = ?1",
+        params![account],
+    )?;
+    connection.execute(
+        "DELETE FROM trusted_addresses WHERE account = ?1",
+        params![account],
+    )?;
+    Ok(())
+}
+
+/*
+    Secure deletion
+
+    SQLite does not erase deleted rows: their content stays in the
+    free pages of the database and in the frames of the WAL until
+    they are reused. `secure_delete_account`:
+    - overwrites the columns with key material (SECRET_COLUMNS)
+    with zeros before the rows are deleted, with `secure_delete` on
+    so that the freed space is zeroed too,
+    - checkpoints and truncates the WAL and vacuums the database,
+    - scans the database and WAL files for the key material of the
+    account, and fails if any of it is still there.
+
+    Key material that another account shares (e.g. the seed of
+    an account with another index) is kept and not scanned for.
+    The scan is meaningless on an encrypted database and the file
+    system may still have copies of older blocks.
+*/
+
+/// (table, column, account column) of the key material
+const SECRET_COLUMNS: &[(&str, &str, &str)] = &[
+    ("accounts", "seed", "id_account"),
+    ("t_accounts", "xsk", "account"),
+    ("t_accounts", "sk", "account"),
+    ("t_addresses", "sk", "account"),
+    ("s_accounts", "sk", "account"),
+    ("o_accounts", "sk", "account"),
+];
+
+#[c_export]
+pub fn secure_delete_account(
+    connection: &mut Connection,
+    session: u32,
+    account: u32,
+) -> Result<()> {
+    check_access(connection, session, &Operation::Admin)?;
+    let secrets = account_secrets(connection, account)?;
+    connection.query_row("PRAGMA secure_delete = ON", [], |_| Ok(()))?;
+    let db_tx = connection.transaction()?;
+    for (table, column, key) in SECRET_COLUMNS.iter() {
+        db_tx.execute(
+            &format!(
+                "UPDATE {table} SET {column} = zeroblob(length({column}))
+                WHERE {key} = ?1 AND {column} IS NOT NULL"
+            ),
+            [account],
+        )?;
+    }
+    drop_account(&db_tx, account)?;
+    db_tx.commit()?;
+
+    connection.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))?;
+    connection.execute("VACUUM", [])?;
+    connection.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))?;
+
+    let found = scan_database_files(connection, &secrets)?;
+    if found > 0 {
+        anyhow::bail!(
+            "{} key(s) of account {} are still in the database file",
+            found,
+            account
+        );
+    }
+    Ok(())
+}
+
+/// Key material of the account that no other account has
+fn account_secrets(connection: &Connection, account: u32) -> Result<Vec<Vec<u8>>> {
+    let mut secrets = vec![];
+    for (table, column, key) in SECRET_COLUMNS.iter() {
+        let mut s = connection.prepare(&format!(
+            "SELECT {key} = ?1, {column} FROM {table} WHERE {column} IS NOT NULL"
+        ))?;
+        let mut rows = s.query([account])?;
+        let mut mine = vec![];
+        let mut others = HashSet::new();
+        while let Some(r) = rows.next()? {
+            let is_mine = r.get::<_, bool>(0)?;
+            let value = match r.get_ref(1)? {
+                ValueRef::Text(v) | ValueRef::Blob(v) => v.to_vec(),
+                _ => continue,
+            };
+            if is_mine {
+                mine.push(value);
+            } else {
+                others.insert(value);
+            }
+        }
+        secrets.extend(
+            mine.into_iter()
+                .filter(|v| !v.is_empty() && !others.contains(v)),
+        );
+    }
+    secrets.sort();
+    secrets.dedup();
+    Ok(secrets)
+}
+
+/// Number of `secrets` found in the database file and its WAL
+fn scan_database_files(connection: &Connection, secrets: &[Vec<u8>]) -> Result<usize> {
+    let path = connection.query_row(
+        "SELECT file FROM pragma_database_list WHERE name = 'main'",
+        [],
+        |r| r.get::<_, String>(0),
+    )?;
+    // in-memory database
+    if path.is_empty() {
+        return Ok(0);
+    }
+    let mut found = HashSet::new();
+    for file in [path.clone(), format!("{path}-wal")] {
+        let Ok(f) = File::open(&file) else {
+            continue;
+        };
+        for i in scan_file(f, secrets)? {
+            tracing::warn!("Key material found in {}", file);
+            found.insert(i);
+        }
+    }
+    Ok(found.len())
+}
+
+const SCAN_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// Indices of the `secrets` found in the file. The file is read by chunks
+/// and the end of each chunk is kept for the next one, so that a
+/// secret that straddles two chunks is still found
+fn scan_file<R: Read>(mut r: R, secrets: &[Vec<u8>]) -> Result<HashSet<usize>> {
+    let overlap = secrets
+        .iter()
+        .map(|s| s.len())
+        .max()
+        .unwrap_or_default()
+        .saturating_sub(1);
+    let mut found = HashSet::new();
+    let mut buffer = vec![0u8; overlap + SCAN_CHUNK_SIZE];
+    let mut len = 0;
+    loop {
+        let n = r.read(&mut buffer[len..])?;
+        if n == 0 {
+            break;
+        }
+        len += n;
+        if len < buffer.len() {
+            continue;
+        }
+        scan_chunk(&buffer, secrets, &mut found);
+        buffer.copy_within(len - overlap.., 0);
+        len = overlap;
+    }
+    scan_chunk(&buffer[..len], secrets, &mut found);
+    Ok(found)
+}
+
+fn scan_chunk(data: &[u8], secrets: &[Vec<u8>], found: &mut HashSet<usize>) {
+    for (i, secret) in secrets.iter().enumerate() {
+        if !secret.is_empty() && data.windows(secret.len()).any(|w| w == &secret[..]) {
+            found.insert(i);
+        }
+    }
+}
+
+/*
+    Soft deletion
+
+    A deleted account keeps its data for a grace period during which
+    it can be restored with `undelete_account`. In the meantime, it is
+    not listed and not synchronized: notes received during that time
+    are only found by a rescan. Therefore, the restore rewinds the
+    wallet to the last checkpoint before the deletion, and it is
+    refused if the database no longer has one.
+    After the grace period, the account is purged by the sync.
+*/
+
+/// Used when the config does not have `account_grace_period`
+pub const DEFAULT_ACCOUNT_GRACE_PERIOD: u32 = 7 * 24 * 3600;
+
+#[c_export]
+pub fn soft_delete_account(connection: &Connection, session: u32, account: u32) -> Result<()> {
+    check_access(connection, session, &Operation::Admin)?;
+    audit(connection, ACCOUNT_DELETE, account, "soft")?;
+    let height = get_sync_height(connection)?.height;
+    connection.execute(
+        "UPDATE accounts SET deleted = ?2, deleted_height = ?3 WHERE id_account = ?1",
+        params![account, now()?, height],
+    )?;
     Ok(())
 }
 
+/// Restore a soft deleted account. The sync skipped it since its
+/// deletion, so the wallet is rewound to the last checkpoint before it
+#[c_export]
+pub fn undelete_account(connection: &mut Connection, session: u32, account: u32) -> Result<()> {
+    check_access(connection, session, &Operation::Admin)?;
+    let deleted_height = connection
+        .query_row(
+            "SELECT deleted_height FROM accounts
+            WHERE id_account = ?1 AND deleted IS NOT NULL",
+            [account],
+            |r| r.get::<_, Option<u32>>(0),
+        )
+        .optional()?;
+    let Some(deleted_height) = deleted_height else {
+        anyhow::bail!("Account {} is not deleted", account);
+    };
+    let deleted_height = deleted_height.unwrap_or_default();
+    if get_sync_height(connection)?.height > deleted_height {
+        rewind_offline(connection, session, deleted_height)?;
+    }
+    connection.execute(
+        "UPDATE accounts SET deleted = NULL, deleted_height = NULL
+        WHERE id_account = ?1",
+        [account],
+    )?;
+    Ok(())
+}
+
+/// Delete the accounts that were soft deleted more than
+/// `grace_period` seconds ago. Returns the number of accounts purged
+pub fn purge_deleted_accounts(connection: &Connection, grace_period: u32) -> Result<u32> {
+    let mut s = connection.prepare(
+        "SELECT id_account FROM accounts
+        WHERE deleted IS NOT NULL AND deleted + ?1 <= ?2",
+    )?;
+    let rows = s.query_map(params![grace_period, now()?], |r| r.get::<_, u32>(0))?;
+    let accounts = rows.collect::<Result<Vec<_>, _>>()?;
+    for account in accounts.iter() {
+        drop_account(connection, *account)?;
+    }
+    Ok(accounts.len() as u32)
+}
+
+pub(crate) fn now() -> Result<u32> {
+    Ok(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as u32)
+}
+
 #[c_export]
 pub fn set_backup_reminder(connection: &Connection, account: u32, saved: bool) -> Result<()> {
     connection.execute(
@@ -613,9 +1205,11 @@ pub fn set_backup_reminder(connection: &Connection, account: u32, saved: bool) -
 #[c_export]
 pub fn downgrade_account(
     connection: &Connection,
+    session: u32,
     account: u32,
     capabilities: &AccountSigningCapabilitiesT,
 ) -> Result<()> {
+    check_access(connection, session, &Operation::Admin)?;
     if capabilities.transparent == 0 && capabilities.sapling == 0 && capabilities.orchard == 0 {
         anyhow::bail!("Account needs at least one key");
     }
@@ -673,3 +1267,77 @@ pub fn get_min_birth(connection: &Connection) -> Result<Option<u32>> {
     })?;
     Ok(birth)
 }
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::Connection;
+
+    use super::{scan_file, soft_delete_account, undelete_account, SCAN_CHUNK_SIZE};
+    use crate::{
+        db::{
+            chain::{get_sync_height, store_block, store_frontiers},
+            create_schema,
+        },
+        warp::{legacy::CommitmentTreeFrontier, BlockHeader},
+    };
+
+    fn sync_to(connection: &mut Connection, height: u32) {
+        let bh = BlockHeader {
+            height,
+            ..BlockHeader::default()
+        };
+        let empty = CommitmentTreeFrontier::default();
+        let db_tx = connection.transaction().unwrap();
+        store_block(&db_tx, &bh).unwrap();
+        store_frontiers(&db_tx, &bh, &empty, &empty).unwrap();
+        db_tx.commit().unwrap();
+    }
+
+    #[test]
+    fn undelete_rescans_after_deletion() {
+        let mut connection = Connection::open_in_memory().unwrap();
+        create_schema(&mut connection, "").unwrap();
+        connection
+            .execute(
+                "INSERT INTO accounts(id_account, name, position, aindex, dindex,
+                birth, balance, saved, hidden)
+                VALUES (1, 'test', 0, 0, 0, 0, 0, TRUE, FALSE)",
+                [],
+            )
+            .unwrap();
+        sync_to(&mut connection, 100);
+        soft_delete_account(&connection, 0, 1).unwrap();
+
+        // the account receives funds at 150 but the sync skips it
+        sync_to(&mut connection, 200);
+        undelete_account(&mut connection, 0, 1).unwrap();
+        // the next sync scans 150 again for the account
+        assert_eq!(get_sync_height(&connection).unwrap().height, 100);
+        let deleted = connection
+            .query_row(
+                "SELECT deleted FROM accounts WHERE id_account = 1",
+                [],
+                |r| r.get::<_, Option<u32>>(0),
+            )
+            .unwrap();
+        assert_eq!(deleted, None);
+
+        // too late once the checkpoints before the deletion are gone
+        soft_delete_account(&connection, 0, 1).unwrap();
+        sync_to(&mut connection, 300);
+        connection
+            .execute("DELETE FROM frontiers WHERE height < 300", [])
+            .unwrap();
+        assert!(undelete_account(&mut connection, 0, 1).is_err());
+    }
+
+    #[test]
+    fn scan_finds_secret_across_chunks() {
+        let secret = b"0123456789abcdef".to_vec();
+        let mut data = vec![0u8; 2 * SCAN_CHUNK_SIZE + 100];
+        let offset = SCAN_CHUNK_SIZE - 5;
+        data[offset..offset + secret.len()].copy_from_slice(&secret);
+        let found = scan_file(&data[..], &[b"missing".to_vec(), secret]).unwrap();
+        assert_eq!(found.into_iter().collect::<Vec<_>>(), vec![1]);
+    }
+}
@@ -0,0 +1,362 @@
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicU32, Ordering},
+};
+
+use anyhow::Result;
+use blake2b_simd::Params;
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use rand::{rngs::OsRng, RngCore as _};
+use rusqlite::{params, Connection, OptionalExtension as _};
+
+use crate::{
+    data::fb::{ApiKey, ApiKeyT, PaymentRequestT, TransactionBytesT},
+    db::{
+        account_manager::now,
+        audit::{audit, API_KEY},
+        tx::get_id_note_account,
+    },
+    network::Network,
+    pay::UnsignedTransaction,
+};
+use warp_macros::c_export;
+
+/*
+    API keys for the embedding servers
+
+    A key grants a set of capabilities:
+    - read only, or allowed to make payments up to `max_amount`
+    (0 for no limit) per transaction,
+    - restricted to a subset of accounts, or all of them.
+    Only a key without any restriction can perform the administrative
+    commands (accounts, keys, database, sync...).
+
+    The secret is shown once when the key is created. The db only keeps
+    its hash, so a leaked db file does not leak the keys.
+
+    A caller opens a session with its key and gets a session id,
+    that it passes to every call as its `session` parameter (0 if
+    it has none). Several callers with different keys can therefore
+    use the wallet at the same time.
+    Once the wallet has a key that is not revoked, the exported
+    functions that read the balance, transactions, notes or messages
+    of an account, pay, sign, broadcast, reveal secret keys or change
+    the wallet check the session with `check_access` before they run,
+    and fail without a session that allows them. Creating and revoking
+    keys needs an admin session, except for the first key, which must
+    be unrestricted so that the wallet can still be administered.
+    A background job (`submit_job`) checks the session of its command.
+    Sessions live in memory and end with the process.
+*/
+
+const API_KEY_PERSONALIZATION: &[u8; 16] = b"Warp_ApiKey_____";
+const API_KEY_PREFIX: &str = "wk_";
+
+pub const OP_READ: u8 = 0;
+pub const OP_PAY: u8 = 1;
+pub const OP_ADMIN: u8 = 2;
+
+/// What a command needs from the capabilities of the session
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Operation {
+    Info,
+    Read { account: u32 },
+    Pay { account: u32, amount: u64 },
+    Admin,
+}
+
+#[derive(Clone, Debug)]
+pub struct Capabilities {
+    pub id_key: u32,
+    pub read_only: bool,
+    pub max_amount: u64,
+    pub accounts: Option<Vec<u32>>,
+}
+
+impl Capabilities {
+    fn unrestricted(&self) -> bool {
+        !self.read_only && self.max_amount == 0 && self.accounts.is_none()
+    }
+
+    fn has_account(&self, account: u32) -> bool {
+        self.accounts
+            .as_ref()
+            .map(|accounts| accounts.contains(&account))
+            .unwrap_or(true)
+    }
+
+    pub fn check(&self, operation: &Operation) -> Result<()> {
+        match *operation {
+            Operation::Info => {}
+            Operation::Read { account } => {
+                if !self.has_account(account) {
+                    anyhow::bail!("API key {} has no access to account {}", self.id_key, account);
+                }
+            }
+            Operation::Pay { account, amount } => {
+                if self.read_only {
+                    anyhow::bail!("API key {} is read only", self.id_key);
+                }
+                if !self.has_account(account) {
+                    anyhow::bail!("API key {} has no access to account {}", self.id_key, account);
+                }
+                if self.max_amount != 0 && amount > self.max_amount {
+                    anyhow::bail!(
+                        "API key {} cannot pay more than {}",
+                        self.id_key,
+                        self.max_amount
+                    );
+                }
+            }
+            Operation::Admin => {
+                if !self.unrestricted() {
+                    anyhow::bail!("API key {} is not an admin key", self.id_key);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+lazy_static! {
+    static ref SESSIONS: Mutex<HashMap<u32, Capabilities>> = Mutex::new(HashMap::new());
+}
+
+static NEXT_SESSION: AtomicU32 = AtomicU32::new(1);
+
+/// Create an API key. `accounts` is empty for all the accounts.
+/// Returns the secret key, which is not stored
+#[c_export]
+pub fn create_api_key(
+    connection: &Connection,
+    session: u32,
+    name: &str,
+    read_only: bool,
+    max_amount: u64,
+    accounts: &[u8],
+) -> Result<String> {
+    let accounts = accounts
+        .chunks_exact(4)
+        .map(|a| u32::from_le_bytes(a.try_into().unwrap()))
+        .collect::<Vec<_>>();
+    if has_api_keys(connection)? {
+        check_access(connection, session, &Operation::Admin)?;
+    } else if read_only || max_amount != 0 || !accounts.is_empty() {
+        anyhow::bail!("The first API key must be unrestricted");
+    }
+    let mut secret = [0u8; 32];
+    OsRng.fill_bytes(&mut secret);
+    let key = format!("{}{}", API_KEY_PREFIX, hex::encode(secret));
+    connection.execute(
+        "INSERT INTO api_keys(name, key_hash, read_only, max_amount, created, revoked)
+        VALUES (?1, ?2, ?3, ?4, ?5, FALSE)",
+        params![name, key_hash(&key), read_only, max_amount, now()?],
+    )?;
+    let id_key = connection.last_insert_rowid() as u32;
+    let mut s = connection
+        .prepare("INSERT INTO api_key_accounts(id_key, account) VALUES (?1, ?2)")?;
+    for account in accounts.iter() {
+        s.execute(params![id_key, account])?;
+    }
+    audit(connection, API_KEY, 0, &format!("create {} {}", id_key, name))?;
+    Ok(key)
+}
+
+#[c_export]
+pub fn list_api_keys(connection: &Connection, session: u32) -> Result<Vec<ApiKeyT>> {
+    check_access(connection, session, &Operation::Admin)?;
+    let mut s = connection.prepare(
+        "SELECT id_key, name, read_only, max_amount, created, revoked
+        FROM api_keys ORDER BY id_key",
+    )?;
+    let rows = s.query_map([], |r| {
+        Ok(ApiKeyT {
+            id: r.get(0)?,
+            name: Some(r.get::<_, String>(1)?),
+            read_only: r.get(2)?,
+            max_amount: r.get(3)?,
+            accounts: None,
+            created: r.get(4)?,
+            revoked: r.get(5)?,
+        })
+    })?;
+    let mut keys = rows.collect::<Result<Vec<_>, _>>()?;
+    for k in keys.iter_mut() {
+        k.accounts = get_key_accounts(connection, k.id)?;
+    }
+    Ok(keys)
+}
+
+/// Revoke a key. The sessions opened with it are closed
+#[c_export]
+pub fn revoke_api_key(connection: &Connection, session: u32, id: u32) -> Result<()> {
+    check_access(connection, session, &Operation::Admin)?;
+    connection.execute("UPDATE api_keys SET revoked = TRUE WHERE id_key = ?1", [id])?;
+    SESSIONS.lock().retain(|_, c| c.id_key != id);
+    audit(connection, API_KEY, 0, &format!("revoke {}", id))?;
+    Ok(())
+}
+
+/// Open a session bound to the capabilities of the key.
+/// Returns the session id
+#[c_export]
+pub fn open_api_session(connection: &Connection, key: &str) -> Result<u32> {
+    let capabilities = get_capabilities(connection, key)?;
+    let session = NEXT_SESSION.fetch_add(1, Ordering::Relaxed);
+    SESSIONS.lock().insert(session, capabilities);
+    Ok(session)
+}
+
+#[c_export]
+pub fn close_api_session(session: u32) -> Result<()> {
+    SESSIONS.lock().remove(&session);
+    Ok(())
+}
+
+/// Check that the session of the caller can run an operation.
+/// A wallet without any key does not need a session
+pub fn check_access(connection: &Connection, session: u32, operation: &Operation) -> Result<()> {
+    if !has_api_keys(connection)? {
+        return Ok(());
+    }
+    authorize_session(session, operation)
+}
+
+/// Check that the session can run an operation (OP_READ, OP_PAY or OP_ADMIN)
+/// on the account, paying `amount` for OP_PAY
+#[c_export]
+pub fn authorize(session: u32, operation: u8, account: u32, amount: u64) -> Result<()> {
+    let operation = match operation {
+        OP_READ => Operation::Read { account },
+        OP_PAY => Operation::Pay { account, amount },
+        OP_ADMIN => Operation::Admin,
+        _ => anyhow::bail!("Invalid operation {}", operation),
+    };
+    authorize_session(session, &operation)
+}
+
+pub fn authorize_session(session: u32, operation: &Operation) -> Result<()> {
+    let sessions = SESSIONS.lock();
+    let capabilities = sessions
+        .get(&session)
+        .ok_or(anyhow::anyhow!("Invalid or expired session {}", session))?;
+    capabilities.check(operation)
+}
+
+/// True if the wallet has a key that is not revoked
+pub fn has_api_keys(connection: &Connection) -> Result<bool> {
+    let has_keys = connection.query_row(
+        "SELECT EXISTS(SELECT 1 FROM api_keys WHERE NOT revoked)",
+        [],
+        |r| r.get::<_, bool>(0),
+    )?;
+    Ok(has_keys)
+}
+
+/// Total amount paid to the recipients of a payment
+pub fn payment_amount(payment: &PaymentRequestT) -> u64 {
+    payment
+        .recipients
+        .iter()
+        .flatten()
+        .map(|r| r.amount)
+        .sum::<u64>()
+}
+
+/// What signing a transaction needs: a payment from the first account
+/// that signs it of the amount that leaves the signing accounts, and
+/// a payment from the sponsor. They do not rely on the `account` and
+/// `is_change` fields, which come from the caller with the summary
+pub fn sign_operations(
+    network: &Network,
+    connection: &Connection,
+    unsigned_tx: &UnsignedTransaction,
+) -> Result<Vec<Operation>> {
+    let accounts = unsigned_tx.signing_accounts(connection)?;
+    let amount = unsigned_tx.amount_sent(network, connection, &accounts)?;
+    let operations = accounts
+        .iter()
+        .enumerate()
+        .map(|(i, &account)| Operation::Pay {
+            account,
+            amount: if i == 0 { amount } else { 0 },
+        })
+        .collect();
+    Ok(operations)
+}
+
+/// `check_access` for the signature of a transaction
+pub fn check_sign_access(
+    network: &Network,
+    connection: &Connection,
+    session: u32,
+    unsigned_tx: &UnsignedTransaction,
+) -> Result<()> {
+    if !has_api_keys(connection)? {
+        return Ok(());
+    }
+    for operation in sign_operations(network, connection, unsigned_tx)?.iter() {
+        authorize_session(session, operation)?;
+    }
+    Ok(())
+}
+
+/// Broadcasting a transaction spends the notes of its account.
+/// A transaction without its notes needs an admin key
+pub fn broadcast_operation(
+    connection: &Connection,
+    txbytes: &TransactionBytesT,
+) -> Result<Operation> {
+    let operation = match txbytes.notes.as_deref() {
+        Some([note, ..]) => Operation::Pay {
+            account: get_id_note_account(connection, note)?,
+            amount: 0,
+        },
+        _ => Operation::Admin,
+    };
+    Ok(operation)
+}
+
+/// Capabilities of a valid (known and not revoked) key
+pub fn get_capabilities(connection: &Connection, key: &str) -> Result<Capabilities> {
+    let capabilities = connection
+        .query_row(
+            "SELECT id_key, read_only, max_amount FROM api_keys
+            WHERE key_hash = ?1 AND NOT revoked",
+            [key_hash(key.trim())],
+            |r| {
+                Ok(Capabilities {
+                    id_key: r.get(0)?,
+                    read_only: r.get(1)?,
+                    max_amount: r.get(2)?,
+                    accounts: None,
+                })
+            },
+        )
+        .optional()?;
+    let mut capabilities = capabilities.ok_or(anyhow::anyhow!("Invalid API key"))?;
+    capabilities.accounts = get_key_accounts(connection, capabilities.id_key)?;
+    Ok(capabilities)
+}
+
+fn get_key_accounts(connection: &Connection, id_key: u32) -> Result<Option<Vec<u32>>> {
+    let mut s = connection
+        .prepare("SELECT account FROM api_key_accounts WHERE id_key = ?1 ORDER BY account")?;
+    let rows = s.query_map([id_key], |r| r.get::<_, u32>(0))?;
+    let accounts = rows.collect::<Result<Vec<_>, _>>()?;
+    Ok(if accounts.is_empty() {
+        None
+    } else {
+        Some(accounts)
+    })
+}
+
+fn key_hash(key: &str) -> Vec<u8> {
+    Params::new()
+        .hash_length(32)
+        .personal(API_KEY_PERSONALIZATION)
+        .hash(key.as_bytes())
+        .as_bytes()
+        .to_vec()
+}
@@ -0,0 +1,208 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+use blake2b_simd::Params;
+use rusqlite::{params, Connection, OptionalExtension as _};
+
+use crate::data::fb::{AuditEvent, AuditEventT};
+use warp_macros::c_export;
+
+/*
+    Audit log of the sensitive operations
+
+    Every event is chained to the previous one by its hash:
+    hash = H(prev_hash | id | timestamp | origin | operation | account | details).
+    The table rejects updates and deletes (see the triggers in `create_schema`),
+    and a modification of the file outside of the library breaks the chain,
+    which `verify_audit_log` detects.
+
+    The chain is not keyed: someone who can write the file can also truncate
+    the log or recompute every hash. The anchor returned by
+    `get_audit_log_anchor` (id and hash of the last event) must therefore be
+    kept by the app outside of the database, e.g. in the secure storage of
+    the platform, and passed back to `verify_audit_log`.
+*/
+
+const AUDIT_PERSONALIZATION: &[u8; 16] = b"Warp_AuditLog___";
+
+pub const KEY_EXPORT: &str = "key_export";
+pub const SEED_DISPLAY: &str = "seed_display";
+pub const ACCOUNT_DELETE: &str = "account_delete";
+pub const DB_DECRYPT: &str = "db_decrypt";
+pub const BROADCAST: &str = "broadcast";
+pub const API_KEY: &str = "api_key";
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum AuditOrigin {
+    Ffi = 0,
+    Cli = 1,
+}
+
+impl AuditOrigin {
+    fn name(&self) -> &'static str {
+        match self {
+            AuditOrigin::Ffi => "FFI",
+            AuditOrigin::Cli => "CLI",
+        }
+    }
+}
+
+static AUDIT_ORIGIN: AtomicU8 = AtomicU8::new(AuditOrigin::Ffi as u8);
+
+/// Origin of the requests made by this process, FFI by default
+pub fn set_audit_origin(origin: AuditOrigin) {
+    AUDIT_ORIGIN.store(origin as u8, Ordering::Relaxed);
+}
+
+fn audit_origin() -> AuditOrigin {
+    match AUDIT_ORIGIN.load(Ordering::Relaxed) {
+        1 => AuditOrigin::Cli,
+        _ => AuditOrigin::Ffi,
+    }
+}
+
+/// Append an event to the audit log
+pub fn audit(connection: &Connection, operation: &str, account: u32, details: &str) -> Result<()> {
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as u32;
+    let origin = audit_origin().name();
+    let prev = connection
+        .query_row(
+            "SELECT id_event, hash FROM audit_log ORDER BY id_event DESC LIMIT 1",
+            [],
+            |r| Ok((r.get::<_, u32>(0)?, r.get::<_, Vec<u8>>(1)?)),
+        )
+        .optional()?;
+    let (id, prev_hash) = match prev {
+        Some((id, hash)) => (id + 1, hash),
+        None => (1, vec![0u8; 32]),
+    };
+    let hash = event_hash(&prev_hash, id, timestamp, origin, operation, account, details);
+    connection.execute(
+        "INSERT INTO audit_log
+        (id_event, timestamp, origin, operation, account, details, hash)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![id, timestamp, origin, operation, account, details, hash],
+    )?;
+    tracing::info!("Audit {} {} {} {}", origin, operation, account, details);
+    Ok(())
+}
+
+/// Events since `timestamp` (0 for all), oldest first
+#[c_export]
+pub fn list_audit_log(connection: &Connection, timestamp: u32) -> Result<Vec<AuditEventT>> {
+    let mut s = connection.prepare(
+        "SELECT id_event, timestamp, origin, operation, account, details, hash
+        FROM audit_log WHERE timestamp >= ?1 ORDER BY id_event",
+    )?;
+    let rows = s.query_map([timestamp], |r| {
+        Ok(AuditEventT {
+            id: r.get(0)?,
+            timestamp: r.get(1)?,
+            origin: Some(r.get::<_, String>(2)?),
+            operation: Some(r.get::<_, String>(3)?),
+            account: r.get(4)?,
+            details: Some(r.get::<_, String>(5)?),
+            hash: Some(r.get::<_, Vec<u8>>(6)?),
+        })
+    })?;
+    let events = rows.collect::<Result<Vec<_>, _>>()?;
+    Ok(events)
+}
+
+/// Anchor of the audit log: id (u32 LE) and hash of its last event,
+/// empty if the log is empty. Store it outside of the database
+#[c_export]
+pub fn get_audit_log_anchor(connection: &Connection) -> Result<Vec<u8>> {
+    let last = connection
+        .query_row(
+            "SELECT id_event, hash FROM audit_log ORDER BY id_event DESC LIMIT 1",
+            [],
+            |r| Ok((r.get::<_, u32>(0)?, r.get::<_, Vec<u8>>(1)?)),
+        )
+        .optional()?;
+    let anchor = match last {
+        Some((id, hash)) => [&id.to_le_bytes()[..], &hash].concat(),
+        None => vec![],
+    };
+    Ok(anchor)
+}
+
+/// Check the hash chain of the audit log against an anchor
+/// from `get_audit_log_anchor` (empty to check the chain only).
+/// Returns the id of the first event that does not match
+/// (or where an event is missing), 0 if the log is intact
+#[c_export]
+pub fn verify_audit_log(connection: &Connection, anchor: &[u8]) -> Result<u32> {
+    let anchor = match anchor.len() {
+        0 => None,
+        36 => {
+            let id = u32::from_le_bytes(anchor[0..4].try_into().unwrap());
+            Some((id, &anchor[4..]))
+        }
+        _ => anyhow::bail!("Invalid audit log anchor"),
+    };
+    let events = list_audit_log(connection, 0)?;
+    let mut prev_hash = vec![0u8; 32];
+    let mut expected_id = 1;
+    for e in events {
+        if e.id != expected_id {
+            return Ok(expected_id);
+        }
+        let hash = event_hash(
+            &prev_hash,
+            e.id,
+            e.timestamp,
+            e.origin.as_deref().unwrap(),
+            e.operation.as_deref().unwrap(),
+            e.account,
+            e.details.as_deref().unwrap(),
+        );
+        let stored = e.hash.unwrap();
+        if hash != stored {
+            return Ok(e.id);
+        }
+        if let Some((id, anchor_hash)) = anchor {
+            if e.id == id && stored != anchor_hash {
+                return Ok(e.id);
+            }
+        }
+        prev_hash = stored;
+        expected_id += 1;
+    }
+    // the log was truncated before the anchor
+    if let Some((id, _)) = anchor {
+        if id >= expected_id {
+            return Ok(expected_id);
+        }
+    }
+    Ok(0)
+}
+
+fn event_hash(
+    prev_hash: &[u8],
+    id: u32,
+    timestamp: u32,
+    origin: &str,
+    operation: &str,
+    account: u32,
+    details: &str,
+) -> Vec<u8> {
+    let mut state = Params::new()
+        .hash_length(32)
+        .personal(AUDIT_PERSONALIZATION)
+        .to_state();
+    state.update(prev_hash);
+    state.update(&id.to_le_bytes());
+    state.update(&timestamp.to_le_bytes());
+    // strings are length prefixed so that fields cannot be shifted
+    for s in [origin, operation] {
+        state.update(&(s.len() as u32).to_le_bytes());
+        state.update(s.as_bytes());
+    }
+    state.update(&account.to_le_bytes());
+    state.update(&(details.len() as u32).to_le_bytes());
+    state.update(details.as_bytes());
+    state.finalize().as_bytes().to_vec()
+}
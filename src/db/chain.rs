@@ -1,13 +1,24 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
 use anyhow::Result;
 use rusqlite::{params, Connection, OptionalExtension as _, Transaction};
 use zcash_protocol::consensus::{NetworkUpgrade, Parameters as _};
 
+use crate::account::webhook::rewind_webhooks;
+use crate::db::api_keys::{check_access, Operation};
+use crate::db::events::record_rollback;
 use crate::db::notes::update_account_balances;
+use crate::db::witnesses::derive_witnesses;
 use crate::network::Network;
 use crate::types::CheckpointHeight;
 use crate::utils::chain::reset_chain;
 use crate::utils::ContextExt;
-use crate::{data::fb::CheckpointT, warp::BlockHeader};
+use crate::warp::sync::revalidate_transparent_blocks;
+use crate::{
+    data::fb::{BlockInfo, BlockInfoT, ChainStatusT, CheckpointT, RewindPlanT},
+    lwd::rpc::SubtreeRoot,
+    warp::{legacy::CommitmentTreeFrontier, BlockHeader},
+};
 use crate::{Client, Hash};
 
 use warp_macros::c_export;
@@ -22,6 +33,53 @@ pub fn snap_to_checkpoint(connection: &Connection, height: u32) -> Result<Checkp
     Ok(CheckpointHeight(height))
 }
 
+/*
+    Anchor snap policy
+
+    A payment spends the notes at a checkpoint, the anchor of its
+    spends. The checkpoint is:
+    - SNAP_NEAREST: the last checkpoint at or before the height of
+    the payment (which is usually the height minus the confirmations),
+    - SNAP_EXACT: the height of the payment, or it fails,
+    - SNAP_LATEST: the last checkpoint of the sync, i.e. every
+    mined note is spendable.
+    SNAP_ACCOUNT_DEFAULT is the policy of the account (see
+    `set_snap_policy`), SNAP_NEAREST if it has none.
+    The summary of the transaction has the anchor height, notes
+    received after it cannot be spent by this transaction.
+*/
+
+pub const SNAP_ACCOUNT_DEFAULT: u8 = 0;
+pub const SNAP_NEAREST: u8 = 1;
+pub const SNAP_EXACT: u8 = 2;
+pub const SNAP_LATEST: u8 = 3;
+
+/// `policy` is resolved, i.e. not SNAP_ACCOUNT_DEFAULT
+pub fn snap_with_policy(
+    connection: &Connection,
+    height: u32,
+    policy: u8,
+) -> Result<CheckpointHeight> {
+    match policy {
+        SNAP_EXACT => {
+            let cp_height = snap_to_checkpoint(connection, height)?;
+            if cp_height.0 != height {
+                anyhow::bail!(
+                    "No checkpoint at {}, the nearest is {}",
+                    height,
+                    cp_height.0
+                );
+            }
+            Ok(cp_height)
+        }
+        SNAP_LATEST => {
+            let height = get_sync_height(connection)?.height;
+            snap_to_checkpoint(connection, height)
+        }
+        _ => snap_to_checkpoint(connection, height),
+    }
+}
+
 pub fn get_block_header(connection: &Connection, height: u32) -> Result<BlockHeader> {
     let (hash, prev_hash, timestamp) = connection
         .query_row(
@@ -53,6 +111,221 @@ pub fn store_block(connection: &Transaction, bh: &BlockHeader) -> Result<()> {
     Ok(())
 }
 
+/*
+    Block stats
+
+    With the `store_block_stats` option, the sync keeps the number of
+    transactions, spends, outputs and actions of every block that it
+    scans, for a local explorer of the synced range. They come from
+    the compact blocks, which have no transparent data. The size is an
+    estimate from the shielded components only, and the counts include
+    the outputs of the transactions that a pruned block range skipped.
+*/
+
+pub fn store_block_stats(connection: &Transaction, stats: &[BlockInfoT]) -> Result<()> {
+    let mut s = connection.prepare_cached(
+        "INSERT OR REPLACE INTO block_stats
+        (height, hash, timestamp, num_txs, num_spends, num_outputs, num_actions, size)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+    )?;
+    for b in stats.iter() {
+        s.execute(params![
+            b.height,
+            b.hash,
+            b.timestamp,
+            b.num_txs,
+            b.num_spends,
+            b.num_outputs,
+            b.num_actions,
+            b.size
+        ])?;
+    }
+    Ok(())
+}
+
+/// Record the last sync run that committed blocks, for `get_chain_status`
+pub fn store_last_sync(
+    connection: &Connection,
+    height: u32,
+    blocks: u32,
+    duration: Duration,
+) -> Result<()> {
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as u32;
+    connection.execute(
+        "INSERT OR REPLACE INTO last_sync(id, timestamp, height, blocks, millis)
+        VALUES (0, ?1, ?2, ?3, ?4)",
+        params![timestamp, height, blocks, duration.as_millis() as u32],
+    )?;
+    Ok(())
+}
+
+/// The sync height and the last sync run, without the server data
+pub fn get_local_chain_status(connection: &Connection) -> Result<ChainStatusT> {
+    let cp = get_sync_height(connection)?;
+    let mut status = ChainStatusT {
+        sync_height: cp.height,
+        sync_timestamp: cp.timestamp,
+        ..ChainStatusT::default()
+    };
+    let last_sync = connection
+        .query_row(
+            "SELECT timestamp, blocks, millis FROM last_sync WHERE id = 0",
+            [],
+            |r| {
+                Ok((
+                    r.get::<_, u32>(0)?,
+                    r.get::<_, u32>(1)?,
+                    r.get::<_, u32>(2)?,
+                ))
+            },
+        )
+        .optional()?;
+    if let Some((timestamp, blocks, millis)) = last_sync {
+        status.last_sync_timestamp = timestamp;
+        status.last_sync_blocks = blocks;
+        status.last_sync_millis = millis;
+        status.blocks_per_minute = (blocks as u64 * 60_000 / millis.max(1) as u64) as u32;
+    }
+    Ok(status)
+}
+
+#[c_export]
+pub fn get_block_info(connection: &Connection, height: u32) -> Result<BlockInfoT> {
+    let info = connection
+        .query_row(
+            "SELECT hash, timestamp, num_txs, num_spends, num_outputs, num_actions, size
+            FROM block_stats WHERE height = ?1",
+            [height],
+            |r| {
+                Ok(BlockInfoT {
+                    height,
+                    hash: r.get(0)?,
+                    timestamp: r.get(1)?,
+                    num_txs: r.get(2)?,
+                    num_spends: r.get(3)?,
+                    num_outputs: r.get(4)?,
+                    num_actions: r.get(5)?,
+                    size: r.get(6)?,
+                })
+            },
+        )
+        .optional()?
+        .ok_or(anyhow::anyhow!("No block info for height {}", height))?;
+    Ok(info)
+}
+
+/// Subtree roots of the server, from `start_index`
+/// (see `warp::sync::subtrees`)
+pub fn store_subtree_roots(
+    connection: &Connection,
+    orchard: bool,
+    start_index: u32,
+    roots: &[SubtreeRoot],
+) -> Result<()> {
+    let mut s = connection.prepare_cached(
+        "INSERT OR REPLACE INTO subtree_roots(orchard, idx, root, height)
+        VALUES (?1, ?2, ?3, ?4)",
+    )?;
+    for (i, r) in roots.iter().enumerate() {
+        s.execute(params![
+            orchard,
+            start_index + i as u32,
+            r.root_hash,
+            r.completing_block_height as u32
+        ])?;
+    }
+    Ok(())
+}
+
+pub fn get_subtree_root(
+    connection: &Connection,
+    orchard: bool,
+    index: u32,
+) -> Result<Option<Hash>> {
+    let root = connection
+        .query_row(
+            "SELECT root FROM subtree_roots WHERE orchard = ?1 AND idx = ?2",
+            params![orchard, index],
+            |r| r.get::<_, Vec<u8>>(0),
+        )
+        .optional()?;
+    let root = root
+        .map(Hash::try_from)
+        .transpose()
+        .map_err(|_| anyhow::anyhow!("Invalid subtree root {}", index))?;
+    Ok(root)
+}
+
+/*
+    Header chain
+
+    The sync checks that every block links to the previous one.
+    It keeps the last HEADER_CHAIN_LENGTH headers and the anchors:
+    the first header after a reset and the hard checkpoints
+    of the network (see `Network::hard_checkpoint`) it went through.
+    Since the chain is continuous between them, a chain that has
+    a hard checkpoint anchor is the chain of the hard checkpoint.
+    `verify_chain` checks them against the server.
+*/
+
+pub const HEADER_CHAIN_LENGTH: u32 = 1_000;
+
+/// Store the headers received by the sync, then drop the old ones
+/// that are not anchors
+pub fn store_headers(
+    connection: &Connection,
+    headers: &[BlockHeader],
+    anchors: &[BlockHeader],
+) -> Result<()> {
+    let first = connection.query_row("SELECT COUNT(*) FROM blck_headers", [], |r| {
+        r.get::<_, u32>(0)
+    })? == 0;
+    let mut s = connection.prepare_cached(
+        "INSERT INTO blck_headers(height, hash, prev_hash, timestamp, anchor)
+        VALUES (?1, ?2, ?3, ?4, ?5) ON CONFLICT DO UPDATE
+        SET anchor = anchor OR excluded.anchor",
+    )?;
+    for (i, bh) in headers.iter().enumerate() {
+        let anchor = first && i == 0;
+        s.execute(params![bh.height, bh.hash, bh.prev_hash, bh.timestamp, anchor])?;
+    }
+    for bh in anchors.iter() {
+        s.execute(params![bh.height, bh.hash, bh.prev_hash, bh.timestamp, true])?;
+    }
+    connection.execute(
+        "DELETE FROM blck_headers WHERE NOT anchor
+        AND height <= (SELECT MAX(height) FROM blck_headers) - ?1",
+        [HEADER_CHAIN_LENGTH],
+    )?;
+    Ok(())
+}
+
+/// Stored headers, by height. The boolean is true for the anchors
+pub fn list_headers(connection: &Connection) -> Result<Vec<(BlockHeader, bool)>> {
+    let mut s = connection.prepare(
+        "SELECT height, hash, prev_hash, timestamp, anchor
+        FROM blck_headers ORDER BY height",
+    )?;
+    let rows = s.query_map([], |r| {
+        Ok((
+            BlockHeader {
+                height: r.get(0)?,
+                hash: r.get(1)?,
+                prev_hash: r.get(2)?,
+                timestamp: r.get(3)?,
+            },
+            r.get::<_, bool>(4)?,
+        ))
+    })?;
+    Ok(rows.collect::<Result<Vec<_>, _>>()?)
+}
+
+/// Drop the header chain. The sync starts a new one
+pub fn clear_headers(connection: &Connection) -> Result<()> {
+    connection.execute("DELETE FROM blck_headers", [])?;
+    Ok(())
+}
+
 #[c_export]
 pub fn get_sync_height(connection: &Connection) -> Result<CheckpointT> {
     let height = connection
@@ -72,18 +345,94 @@ pub fn get_sync_height(connection: &Connection) -> Result<CheckpointT> {
     Ok(height.unwrap_or_default())
 }
 
+/// Cache the commitment tree frontiers at a checkpoint,
+/// together with its block header
+pub fn store_frontiers(
+    connection: &Connection,
+    bh: &BlockHeader,
+    sapling: &CommitmentTreeFrontier,
+    orchard: &CommitmentTreeFrontier,
+) -> Result<()> {
+    let mut s = vec![];
+    sapling.write(&mut s)?;
+    let mut o = vec![];
+    orchard.write(&mut o)?;
+    connection.execute(
+        "INSERT INTO frontiers(height, hash, prev_hash, timestamp, sapling, orchard)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6) ON CONFLICT DO UPDATE SET
+        hash = excluded.hash, prev_hash = excluded.prev_hash,
+        timestamp = excluded.timestamp,
+        sapling = excluded.sapling, orchard = excluded.orchard",
+        params![bh.height, bh.hash, bh.prev_hash, bh.timestamp, s, o],
+    )?;
+    Ok(())
+}
+
+/// Frontiers at the given height if we have them in cache
+pub fn get_frontier_at(
+    connection: &Connection,
+    height: u32,
+) -> Result<Option<(CommitmentTreeFrontier, CommitmentTreeFrontier)>> {
+    let frontiers = connection
+        .query_row(
+            "SELECT sapling, orchard FROM frontiers WHERE height = ?1",
+            [height],
+            |r| Ok((r.get::<_, Vec<u8>>(0)?, r.get::<_, Vec<u8>>(1)?)),
+        )
+        .optional()?;
+    let frontiers = frontiers
+        .map(|(s, o)| {
+            Ok::<_, anyhow::Error>((
+                CommitmentTreeFrontier::read(&*s)?,
+                CommitmentTreeFrontier::read(&*o)?,
+            ))
+        })
+        .transpose()?;
+    Ok(frontiers)
+}
+
+/// Latest cached checkpoint at or before the given height
+pub fn get_cached_checkpoint(connection: &Connection, height: u32) -> Result<Option<BlockHeader>> {
+    let bh = connection
+        .query_row(
+            "SELECT height, hash, prev_hash, timestamp FROM frontiers
+            WHERE height <= ?1 ORDER BY height DESC LIMIT 1",
+            [height],
+            |r| {
+                Ok(BlockHeader {
+                    height: r.get(0)?,
+                    hash: r.get(1)?,
+                    prev_hash: r.get(2)?,
+                    timestamp: r.get(3)?,
+                })
+            },
+        )
+        .optional()?;
+    Ok(bh)
+}
+
 pub fn truncate_scan(connection: &Connection) -> Result<()> {
     connection.execute("DELETE FROM blcks", [])?;
     connection.execute("DELETE FROM blck_times", [])?;
+    connection.execute("DELETE FROM blck_headers", [])?;
+    connection.execute("DELETE FROM block_stats", [])?;
+    connection.execute("DELETE FROM subtree_roots", [])?;
     connection.execute("DELETE FROM txs", [])?;
     connection.execute("DELETE FROM txdetails", [])?;
+    connection.execute("DELETE FROM tx_addresses", [])?;
+    connection.execute("DELETE FROM tx_details_failures", [])?;
     connection.execute("DELETE FROM notes", [])?;
     connection.execute("DELETE FROM note_spends", [])?;
     connection.execute("DELETE FROM witnesses", [])?;
     connection.execute("DELETE FROM utxos", [])?;
     connection.execute("DELETE FROM utxo_spends", [])?;
+    connection.execute("DELETE FROM t_scan_progress", [])?;
+    connection.execute("DELETE FROM t_sweep_progress", [])?;
+    connection.execute("DELETE FROM transparent_blocks", [])?;
+    connection.execute("DELETE FROM script_utxos", [])?;
     connection.execute("DELETE FROM contacts", [])?;
     connection.execute("DELETE FROM msgs", [])?;
+    rewind_webhooks(connection, 0)?;
 
     Ok(())
 }
@@ -100,21 +449,46 @@ pub fn reset_scan(
     let height = height.unwrap_or(activation + 1) - 1;
 
     let db_tx = connection.transaction()?;
+    let checkpoint = db_tx.query_row(
+        "SELECT MAX(height) FROM blcks WHERE height < ?1",
+        [height],
+        |r| r.get::<_, Option<u32>>(0),
+    )?;
+    if let Some(checkpoint) = checkpoint {
+        derive_checkpoint_witnesses(&db_tx, checkpoint)?;
+    }
     db_tx.execute("DELETE FROM blcks WHERE height >= ?1", [height])?;
     db_tx.execute("DELETE FROM blck_times WHERE height >= ?1", [height])?;
+    db_tx.execute("DELETE FROM block_stats WHERE height >= ?1", [height])?;
+    db_tx.execute("DELETE FROM subtree_roots WHERE height >= ?1", [height])?;
+    db_tx.execute("DELETE FROM blck_headers WHERE height >= ?1", [height])?;
+    db_tx.execute("DELETE FROM frontiers WHERE height >= ?1", [height])?;
     db_tx.execute("DELETE FROM txs WHERE height >= ?1", [height])?;
     db_tx.execute("DELETE FROM txdetails", [])?;
+    db_tx.execute("DELETE FROM tx_addresses", [])?;
+    db_tx.execute("DELETE FROM tx_details_failures", [])?;
     db_tx.execute("DELETE FROM notes WHERE height >= ?1", [height])?;
     db_tx.execute("DELETE FROM note_spends WHERE height >= ?1", [height])?;
     db_tx.execute("DELETE FROM witnesses WHERE height >= ?1", [height])?;
     db_tx.execute("DELETE FROM utxos WHERE height >= ?1", [height])?;
     db_tx.execute("DELETE FROM utxo_spends WHERE height >= ?1", [height])?;
+    db_tx.execute("DELETE FROM t_scan_progress WHERE height >= ?1", [height])?;
+    db_tx.execute(
+        "DELETE FROM transparent_blocks WHERE height >= ?1",
+        [height],
+    )?;
+    db_tx.execute("DELETE FROM script_utxos WHERE height >= ?1", [height])?;
+    db_tx.execute(
+        "UPDATE script_utxos SET spent = NULL WHERE spent >= ?1",
+        [height],
+    )?;
     db_tx.execute("DELETE FROM msgs", [])?;
     db_tx.execute("UPDATE notes SET spent = NULL WHERE spent >= ?1", [height])?;
     db_tx.execute("UPDATE notes SET expiration = NULL", [])?;
     db_tx.execute("UPDATE utxos SET spent = NULL WHERE spent >= ?1", [height])?;
     db_tx.execute("UPDATE utxos SET expiration = NULL", [])?;
     update_account_balances(&db_tx)?;
+    rewind_webhooks(&db_tx, height.saturating_sub(1))?;
     db_tx.commit()?;
 
     Ok(height)
@@ -147,24 +521,8 @@ pub async fn rewind(
         )
         .optional()?;
     if let Some(height) = height {
-        let db_tx = connection.transaction()?;
-        tracing::info!("Dropping sync data after @{height}");
-        db_tx.execute("DELETE FROM blcks WHERE height > ?1", [height])?;
-        db_tx.execute("DELETE FROM blck_times WHERE height > ?1", [height])?;
-        db_tx.execute("DELETE FROM txs WHERE height > ?1", [height])?;
-        db_tx.execute("DELETE FROM notes WHERE height > ?1", [height])?;
-        db_tx.execute("DELETE FROM note_spends WHERE height > ?1", [height])?;
-        db_tx.execute("DELETE FROM witnesses WHERE height > ?1", [height])?;
-        db_tx.execute("DELETE FROM utxos WHERE height > ?1", [height])?;
-        db_tx.execute("DELETE FROM utxo_spends WHERE height > ?1", [height])?;
-        db_tx.execute("DELETE FROM txdetails WHERE height > ?1", [height])?;
-        db_tx.execute("DELETE FROM msgs WHERE height > ?1", [height])?;
-        db_tx.execute("UPDATE notes SET spent = NULL WHERE spent > ?1", [height])?;
-        db_tx.execute("UPDATE notes SET expiration = NULL", [])?;
-        db_tx.execute("UPDATE utxos SET spent = NULL WHERE spent > ?1", [height])?;
-        db_tx.execute("UPDATE utxos SET expiration = NULL", [])?;
-        update_account_balances(&db_tx)?;
-        db_tx.commit()?;
+        drop_sync_data_after(connection, height)?;
+        revalidate_transparent_blocks(network, connection, client, height).await?;
     } else {
         reset_chain(network, connection, client, 0).await?;
     }
@@ -172,6 +530,157 @@ pub async fn rewind(
     Ok(())
 }
 
+/// Rewind to the last checkpoint at or before `height` that has
+/// its tree state in the database, without the server.
+/// Returns the height of the checkpoint
+#[c_export]
+pub fn rewind_offline(connection: &mut Connection, session: u32, height: u32) -> Result<u32> {
+    check_access(connection, session, &Operation::Admin)?;
+    let checkpoint = connection
+        .query_row(
+            "SELECT b.height FROM blcks b JOIN frontiers f ON f.height = b.height
+            WHERE b.height <= ?1 ORDER BY b.height DESC LIMIT 1",
+            [height],
+            |r| r.get::<_, u32>(0),
+        )
+        .optional()?;
+    let Some(checkpoint) = checkpoint else {
+        let earliest = connection.query_row(
+            "SELECT MIN(b.height) FROM blcks b JOIN frontiers f ON f.height = b.height",
+            [],
+            |r| r.get::<_, Option<u32>>(0),
+        )?;
+        match earliest {
+            Some(earliest) => anyhow::bail!(
+                "No stored checkpoint at or before {}, the earliest is {}",
+                height,
+                earliest
+            ),
+            None => anyhow::bail!("No stored checkpoint"),
+        }
+    };
+    drop_sync_data_after(connection, checkpoint)?;
+    Ok(checkpoint)
+}
+
+/*
+    Rewind plan
+
+    A rewind goes back to the last checkpoint at or before the
+    requested height and drops everything that was synchronized
+    after it. If there is no such checkpoint, the whole chain is
+    reset and the accounts are rescanned from their birth height.
+
+    The plan is the checkpoint of a rewind and what it invalidates:
+    the transactions, notes, UTXOs and messages after the checkpoint
+    and the notes and UTXOs that become unspent again. `offline`
+    tells if the checkpoint has its tree state in the database
+    (see `rewind_offline`). The apps should show it before they
+    rewind, and `rewind_with_plan` with `dry_run` only makes the plan.
+*/
+
+#[c_export]
+pub fn get_rewind_plan(connection: &Connection, height: u32) -> Result<RewindPlanT> {
+    let checkpoint = connection
+        .query_row(
+            "SELECT b.height, f.height IS NOT NULL FROM blcks b
+            LEFT JOIN frontiers f ON f.height = b.height
+            WHERE b.height <= ?1 ORDER BY b.height DESC LIMIT 1",
+            [height],
+            |r| Ok((r.get::<_, u32>(0)?, r.get::<_, bool>(1)?)),
+        )
+        .optional()?;
+    let reset = checkpoint.is_none();
+    let (checkpoint, offline) = checkpoint.unwrap_or_default();
+    let count = |sql: &str| connection.query_row(sql, [checkpoint], |r| r.get::<_, u32>(0));
+    let plan = RewindPlanT {
+        requested_height: height,
+        height: checkpoint,
+        reset,
+        offline,
+        txs: count("SELECT COUNT(*) FROM txs WHERE height > ?1")?,
+        notes: count("SELECT COUNT(*) FROM notes WHERE height > ?1")?,
+        utxos: count("SELECT COUNT(*) FROM utxos WHERE height > ?1")?,
+        unspent: count(
+            "SELECT (SELECT COUNT(*) FROM notes WHERE height <= ?1 AND spent > ?1)
+            + (SELECT COUNT(*) FROM utxos WHERE height <= ?1 AND spent > ?1)",
+        )?,
+        messages: count("SELECT COUNT(*) FROM msgs WHERE height > ?1")?,
+    };
+    Ok(plan)
+}
+
+/// Rewind like `rewind` and return its plan. With `dry_run`,
+/// the database is not changed
+#[c_export]
+pub async fn rewind_with_plan(
+    network: &Network,
+    connection: &mut Connection,
+    client: &mut Client,
+    session: u32,
+    height: u32,
+    dry_run: bool,
+) -> Result<RewindPlanT> {
+    if !dry_run {
+        check_access(connection, session, &Operation::Admin)?;
+    }
+    let plan = get_rewind_plan(connection, height)?;
+    if !dry_run {
+        rewind(network, connection, client, height).await?;
+    }
+    Ok(plan)
+}
+
+fn drop_sync_data_after(connection: &mut Connection, height: u32) -> Result<()> {
+    let db_tx = connection.transaction()?;
+    tracing::info!("Dropping sync data after @{height}");
+    derive_checkpoint_witnesses(&db_tx, height)?;
+    db_tx.execute("DELETE FROM blcks WHERE height > ?1", [height])?;
+    db_tx.execute("DELETE FROM blck_times WHERE height > ?1", [height])?;
+    db_tx.execute("DELETE FROM block_stats WHERE height > ?1", [height])?;
+    db_tx.execute("DELETE FROM subtree_roots WHERE height > ?1", [height])?;
+    db_tx.execute("DELETE FROM blck_headers WHERE height > ?1", [height])?;
+    db_tx.execute("DELETE FROM frontiers WHERE height > ?1", [height])?;
+    db_tx.execute("DELETE FROM txs WHERE height > ?1", [height])?;
+    db_tx.execute("DELETE FROM notes WHERE height > ?1", [height])?;
+    db_tx.execute("DELETE FROM note_spends WHERE height > ?1", [height])?;
+    db_tx.execute("DELETE FROM witnesses WHERE height > ?1", [height])?;
+    db_tx.execute("DELETE FROM utxos WHERE height > ?1", [height])?;
+    db_tx.execute("DELETE FROM utxo_spends WHERE height > ?1", [height])?;
+    db_tx.execute("DELETE FROM transparent_blocks WHERE height > ?1", [height])?;
+    db_tx.execute("DELETE FROM script_utxos WHERE height > ?1", [height])?;
+    db_tx.execute(
+        "UPDATE script_utxos SET spent = NULL WHERE spent > ?1",
+        [height],
+    )?;
+    db_tx.execute("DELETE FROM txdetails WHERE height > ?1", [height])?;
+    db_tx.execute("DELETE FROM tx_addresses WHERE height > ?1", [height])?;
+    db_tx.execute(
+        "DELETE FROM tx_details_failures WHERE height > ?1",
+        [height],
+    )?;
+    db_tx.execute("DELETE FROM msgs WHERE height > ?1", [height])?;
+    db_tx.execute("UPDATE notes SET spent = NULL WHERE spent > ?1", [height])?;
+    db_tx.execute("UPDATE notes SET expiration = NULL", [])?;
+    db_tx.execute("UPDATE utxos SET spent = NULL WHERE spent > ?1", [height])?;
+    db_tx.execute("UPDATE utxos SET expiration = NULL", [])?;
+    update_account_balances(&db_tx)?;
+    rewind_webhooks(&db_tx, height)?;
+    record_rollback(&db_tx, height)?;
+    db_tx.commit()?;
+
+    Ok(())
+}
+
+/// Derive the witnesses at the checkpoint before the later ones are
+/// dropped, in the lazy witnesses mode they are the only ones
+fn derive_checkpoint_witnesses(connection: &Connection, height: u32) -> Result<()> {
+    if let Some((s, o)) = get_frontier_at(connection, height)? {
+        derive_witnesses(connection, height, s.size() as u32, o.size() as u32)?;
+    }
+    Ok(())
+}
+
 #[c_export]
 pub fn list_checkpoints(connection: &Connection) -> Result<Vec<CheckpointT>> {
     let mut s = connection.prepare("SELECT height, hash, timestamp FROM blcks ORDER BY height")?;
@@ -194,14 +703,24 @@ pub fn delete_checkpoint(connection: &mut Connection, height: u32) -> Result<()>
     let db_tx = connection.transaction()?;
     {
         db_tx.execute("DELETE FROM blcks WHERE height = ?1", [height])?;
-        db_tx.execute("DELETE FROM witnesses WHERE height = ?1", [height])?;
+        db_tx.execute("DELETE FROM frontiers WHERE height = ?1", [height])?;
+        // keep the last witness of the unspent notes, it may be
+        // their only one in the lazy witnesses mode
+        db_tx.execute(
+            "DELETE FROM witnesses WHERE height = ?1 AND (
+            EXISTS (SELECT 1 FROM witnesses w2 WHERE w2.account = witnesses.account
+                AND w2.note = witnesses.note AND w2.height > ?1)
+            OR note IN (SELECT id_note FROM notes WHERE spent IS NOT NULL))",
+            [height],
+        )?;
     }
     db_tx.commit()?;
     Ok(())
 }
 
 #[c_export]
-pub fn purge_checkpoints(connection: &mut Connection, min_height: u32) -> Result<()> {
+pub fn purge_checkpoints(connection: &mut Connection, session: u32, min_height: u32) -> Result<()> {
+    check_access(connection, session, &Operation::Admin)?;
     let heights = {
         let mut s = connection.prepare(
         "WITH checkpoints(height, day) AS (SELECT MIN(height) AS height, timestamp/86400 AS day FROM blcks GROUP BY day ORDER BY day)
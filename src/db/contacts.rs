@@ -2,11 +2,11 @@ use crate::network::Network;
 use crate::utils::ua::split_address;
 use crate::{fb_unwrap, utils::ContextExt};
 use anyhow::Result;
-use rusqlite::{params, Connection};
+use rusqlite::{params, Connection, OptionalExtension as _};
 use zcash_keys::address::Address as RecipientAddress;
 
 use crate::{
-    data::fb::{ContactCard, ContactCardT},
+    data::fb::{ContactCard, ContactCardT, ContactGroup, ContactGroupT},
     types::Contact,
 };
 use warp_macros::c_export;
@@ -151,6 +151,17 @@ pub fn edit_contact_address(
     Ok(())
 }
 
+/// Acknowledgment of the payments from this contact:
+/// 0 = ask, 1 = always, 2 = never
+#[c_export]
+pub fn edit_contact_ack(connection: &Connection, id: u32, ack: u8) -> Result<()> {
+    connection.execute(
+        "UPDATE contacts SET ack = ?2 WHERE id_contact = ?1",
+        params![id, ack],
+    )?;
+    Ok(())
+}
+
 pub fn upsert_contact_receivers(
     network: &Network,
     connection: &Connection,
@@ -195,9 +206,37 @@ pub fn upsert_contact_receivers(
     Ok(())
 }
 
+/// Contact of the account that has a receiver of this address
+pub fn find_contact_by_address(
+    network: &Network,
+    connection: &Connection,
+    account: u32,
+    address: &str,
+) -> Result<Option<u32>> {
+    let Ok(receiver) = address_to_bytes(network, address) else {
+        return Ok(None);
+    };
+    let contact = connection
+        .query_row(
+            "SELECT contact FROM contact_receivers
+            WHERE account = ?1 AND address = ?2 LIMIT 1",
+            params![account, receiver],
+            |r| r.get::<_, u32>(0),
+        )
+        .optional()?;
+    Ok(contact)
+}
+
 #[c_export]
 pub fn delete_contact(connection: &Connection, id: u32) -> Result<()> {
     connection.execute("DELETE FROM contacts WHERE id_contact = ?1", [id])?;
+    connection.execute("DELETE FROM contact_group_members WHERE contact = ?1", [id])?;
+    connection.execute("UPDATE txs SET contact = NULL WHERE contact = ?1", [id])?;
+    connection.execute("UPDATE msgs SET contact = NULL WHERE contact = ?1", [id])?;
+    connection.execute(
+        "UPDATE tx_addresses SET contact = NULL WHERE contact = ?1",
+        [id],
+    )?;
     Ok(())
 }
 
@@ -236,3 +275,122 @@ pub fn on_contacts_saved(connection: &Connection, account: u32) -> Result<()> {
     )?;
     Ok(())
 }
+
+/*
+    Contact groups
+
+    A group is a named set of contacts of an account, for example
+    the members of a team that get paid together. A contact can be in
+    several groups. Groups are local: they are not saved on chain
+    with the contacts.
+*/
+
+#[c_export]
+pub fn create_contact_group(connection: &Connection, account: u32, name: &str) -> Result<u32> {
+    let id = connection.query_row(
+        "INSERT INTO contact_groups(account, name) VALUES (?1, ?2)
+        RETURNING id_group",
+        params![account, name],
+        |r| r.get::<_, u32>(0),
+    )?;
+    Ok(id)
+}
+
+#[c_export]
+pub fn list_contact_groups(connection: &Connection, account: u32) -> Result<Vec<ContactGroupT>> {
+    let mut s = connection
+        .prepare("SELECT id_group, name FROM contact_groups WHERE account = ?1 ORDER BY name")?;
+    let rows = s.query_map([account], |r| {
+        Ok((r.get::<_, u32>(0)?, r.get::<_, String>(1)?))
+    })?;
+    let mut groups = vec![];
+    for r in rows {
+        let (id, name) = r?;
+        let group = ContactGroupT {
+            id,
+            account,
+            name: Some(name),
+            contacts: Some(list_group_members(connection, id)?),
+        };
+        groups.push(group);
+    }
+    Ok(groups)
+}
+
+#[c_export]
+pub fn get_contact_group(connection: &Connection, id: u32) -> Result<ContactGroupT> {
+    let (account, name) = connection
+        .query_row(
+            "SELECT account, name FROM contact_groups WHERE id_group = ?1",
+            [id],
+            |r| Ok((r.get::<_, u32>(0)?, r.get::<_, String>(1)?)),
+        )
+        .optional()?
+        .ok_or(anyhow::anyhow!("No contact group {id}"))?;
+    let group = ContactGroupT {
+        id,
+        account,
+        name: Some(name),
+        contacts: Some(list_group_members(connection, id)?),
+    };
+    Ok(group)
+}
+
+#[c_export]
+pub fn edit_contact_group_name(connection: &Connection, id: u32, name: &str) -> Result<()> {
+    connection.execute(
+        "UPDATE contact_groups SET name = ?2 WHERE id_group = ?1",
+        params![id, name],
+    )?;
+    Ok(())
+}
+
+#[c_export]
+pub fn delete_contact_group(connection: &Connection, id: u32) -> Result<()> {
+    connection.execute("DELETE FROM contact_group_members WHERE id_group = ?1", [id])?;
+    connection.execute("DELETE FROM contact_groups WHERE id_group = ?1", [id])?;
+    Ok(())
+}
+
+/// Add a contact to a group. They must belong to the same account
+#[c_export]
+pub fn add_contact_to_group(connection: &Connection, id: u32, contact: u32) -> Result<()> {
+    let group = get_contact_group(connection, id)?;
+    let card = get_contact_card(connection, contact)?;
+    if card.account != group.account {
+        anyhow::bail!("Contact {} is not from the account of group {}", contact, id);
+    }
+    connection.execute(
+        "INSERT INTO contact_group_members(id_group, contact)
+        VALUES (?1, ?2) ON CONFLICT DO NOTHING",
+        params![id, contact],
+    )?;
+    Ok(())
+}
+
+#[c_export]
+pub fn remove_contact_from_group(connection: &Connection, id: u32, contact: u32) -> Result<()> {
+    connection.execute(
+        "DELETE FROM contact_group_members WHERE id_group = ?1 AND contact = ?2",
+        params![id, contact],
+    )?;
+    Ok(())
+}
+
+/// Contact cards of the members of a group
+pub fn list_group_contacts(connection: &Connection, id: u32) -> Result<Vec<ContactCardT>> {
+    list_group_members(connection, id)?
+        .into_iter()
+        .map(|contact| get_contact_card(connection, contact))
+        .collect()
+}
+
+fn list_group_members(connection: &Connection, id: u32) -> Result<Vec<u32>> {
+    let mut s = connection.prepare(
+        "SELECT m.contact FROM contact_group_members m
+        JOIN contacts c ON c.id_contact = m.contact
+        WHERE m.id_group = ?1 ORDER BY c.name",
+    )?;
+    let rows = s.query_map([id], |r| r.get::<_, u32>(0))?;
+    Ok(rows.collect::<Result<Vec<_>, _>>()?)
+}
@@ -0,0 +1,119 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+use rusqlite::{params, Connection};
+
+use crate::{
+    data::fb::{Draft, DraftT, PaymentRequestT},
+    utils::ContextExt,
+};
+use warp_macros::c_export;
+
+fn now() -> Result<u32> {
+    Ok(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as u32)
+}
+
+#[c_export]
+pub fn store_draft(
+    connection: &Connection,
+    account: u32,
+    name: &str,
+    payment: &PaymentRequestT,
+) -> Result<u32> {
+    let payment = serde_json::to_string(payment)?;
+    let id = connection.query_row(
+        "INSERT INTO drafts(account, name, timestamp, payment)
+        VALUES (?1, ?2, ?3, ?4) ON CONFLICT DO UPDATE
+        SET timestamp = excluded.timestamp, payment = excluded.payment
+        RETURNING id_draft",
+        params![account, name, now()?, payment],
+        |r| r.get::<_, u32>(0),
+    )?;
+    Ok(id)
+}
+
+#[c_export]
+pub fn list_drafts(connection: &Connection, account: u32) -> Result<Vec<DraftT>> {
+    let mut s = connection.prepare(
+        "SELECT id_draft, name, timestamp, payment FROM drafts
+        WHERE account = ?1 ORDER BY name",
+    )?;
+    let rows = s.query_map([account], |r| {
+        Ok((
+            r.get::<_, u32>(0)?,
+            r.get::<_, String>(1)?,
+            r.get::<_, u32>(2)?,
+            r.get::<_, String>(3)?,
+        ))
+    })?;
+    let mut drafts = vec![];
+    for r in rows {
+        let (id, name, timestamp, payment) = r?;
+        let payment = serde_json::from_str::<PaymentRequestT>(&payment)?;
+        let draft = DraftT {
+            id,
+            account,
+            name: Some(name),
+            timestamp,
+            payment: Some(Box::new(payment)),
+        };
+        drafts.push(draft);
+    }
+    Ok(drafts)
+}
+
+#[c_export]
+pub fn get_draft(connection: &Connection, id: u32) -> Result<DraftT> {
+    let (account, name, timestamp, payment) = connection
+        .query_row(
+            "SELECT account, name, timestamp, payment FROM drafts
+            WHERE id_draft = ?1",
+            [id],
+            |r| {
+                Ok((
+                    r.get::<_, u32>(0)?,
+                    r.get::<_, String>(1)?,
+                    r.get::<_, u32>(2)?,
+                    r.get::<_, String>(3)?,
+                ))
+            },
+        )
+        .with_file_line(|| format!("No draft {id}"))?;
+    let payment = serde_json::from_str::<PaymentRequestT>(&payment)?;
+    let draft = DraftT {
+        id,
+        account,
+        name: Some(name),
+        timestamp,
+        payment: Some(Box::new(payment)),
+    };
+    Ok(draft)
+}
+
+#[c_export]
+pub fn edit_draft(
+    connection: &Connection,
+    id: u32,
+    name: &str,
+    payment: &PaymentRequestT,
+) -> Result<()> {
+    let payment = serde_json::to_string(payment)?;
+    let updated = connection.execute(
+        "UPDATE drafts SET name = ?2, timestamp = ?3, payment = ?4
+        WHERE id_draft = ?1",
+        params![id, name, now()?, payment],
+    )?;
+    if updated != 1 {
+        anyhow::bail!("No draft {id}");
+    }
+    Ok(())
+}
+
+#[c_export]
+pub fn delete_draft(connection: &Connection, id: u32) -> Result<()> {
+    let deleted = connection.execute("DELETE FROM drafts WHERE id_draft = ?1", [id])?;
+    if deleted != 1 {
+        anyhow::bail!("No draft {id}");
+    }
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use anyhow::Result;
+use rusqlite::{params, Connection};
+
+use crate::{data::fb::BalanceEventT, Hash};
+use warp_macros::c_export;
+
+/*
+    Balance events
+
+    Every change of the balance of an account is appended to the
+    journal `events`, with a sequence number that only increases:
+    - EVENT_RECEIVED: a note or a UTXO is received,
+    - EVENT_SPENT: a note or a UTXO is spent,
+    - EVENT_FEE: the account paid the fee of a transaction (recorded
+    with the details of the transaction, when every input is known),
+    - EVENT_EXPIRED_SPEND: a spend was not mined before its expiration
+    and the note is spendable again,
+    - EVENT_ROLLBACK: the sync data after `height` was dropped (reorg
+    or rewind). The previous events with a higher height are void and
+    come again, with new sequence numbers, when the blocks are synced
+    again.
+
+    `pool` is 0 for transparent, 1 for sapling and 2 for orchard, and
+    `id_ref` is the id of the note or UTXO. An accounting system keeps
+    the last sequence number that it processed and calls
+    `get_events_since` with it until there are no more events. An event
+    is not recorded twice, unless there was a rollback in between,
+    so reading the same range again gives the same events.
+*/
+
+pub const EVENT_RECEIVED: u8 = 0;
+pub const EVENT_SPENT: u8 = 1;
+pub const EVENT_FEE: u8 = 2;
+pub const EVENT_EXPIRED_SPEND: u8 = 3;
+pub const EVENT_ROLLBACK: u8 = 4;
+
+const MAX_EVENTS_BATCH: u32 = 1000;
+
+/// Record an event of the note `id_note`
+pub fn record_note_event(
+    connection: &Connection,
+    kind: u8,
+    id_note: u32,
+    height: u32,
+    txid: &Hash,
+) -> Result<()> {
+    let mut s = connection.prepare_cached(
+        "INSERT INTO events(account, kind, height, txid, pool, id_ref, value)
+        SELECT n.account, ?2, ?3, ?4, CASE WHEN n.orchard THEN 2 ELSE 1 END, n.id_note, n.value
+        FROM notes n WHERE n.id_note = ?1
+        AND NOT EXISTS (SELECT 1 FROM events e
+        WHERE e.kind = ?2 AND e.pool <> 0 AND e.id_ref = ?1
+        AND e.seq > (SELECT COALESCE(MAX(seq), 0) FROM events WHERE kind = ?5))",
+    )?;
+    s.execute(params![id_note, kind, height, txid, EVENT_ROLLBACK])?;
+    Ok(())
+}
+
+/// Record an event of the UTXO `id_utxo`
+pub fn record_utxo_event(
+    connection: &Connection,
+    kind: u8,
+    id_utxo: u32,
+    height: u32,
+    txid: &Hash,
+) -> Result<()> {
+    let mut s = connection.prepare_cached(
+        "INSERT INTO events(account, kind, height, txid, pool, id_ref, value)
+        SELECT u.account, ?2, ?3, ?4, 0, u.id_utxo, u.value
+        FROM utxos u WHERE u.id_utxo = ?1
+        AND NOT EXISTS (SELECT 1 FROM events e
+        WHERE e.kind = ?2 AND e.pool = 0 AND e.id_ref = ?1
+        AND e.seq > (SELECT COALESCE(MAX(seq), 0) FROM events WHERE kind = ?5))",
+    )?;
+    s.execute(params![id_utxo, kind, height, txid, EVENT_ROLLBACK])?;
+    Ok(())
+}
+
+/// Record the fee that the account paid for the transaction `txid`
+pub fn record_fee(
+    connection: &Connection,
+    account: u32,
+    height: u32,
+    txid: &Hash,
+    fee: u64,
+) -> Result<()> {
+    let mut s = connection.prepare_cached(
+        "INSERT INTO events(account, kind, height, txid, pool, id_ref, value)
+        SELECT ?1, ?2, ?3, ?4, 0, 0, ?5
+        WHERE NOT EXISTS (SELECT 1 FROM events e
+        WHERE e.kind = ?2 AND e.account = ?1 AND e.txid = ?4
+        AND e.seq > (SELECT COALESCE(MAX(seq), 0) FROM events WHERE kind = ?6))",
+    )?;
+    s.execute(params![
+        account,
+        EVENT_FEE,
+        height,
+        txid,
+        fee,
+        EVENT_ROLLBACK
+    ])?;
+    Ok(())
+}
+
+/// Record the notes and UTXOs whose spend expired before `height`
+pub fn record_expired_spends(connection: &Connection, height: u32) -> Result<()> {
+    connection.execute(
+        "INSERT INTO events(account, kind, height, txid, pool, id_ref, value)
+        SELECT account, ?2, ?1, NULL, CASE WHEN orchard THEN 2 ELSE 1 END, id_note, value
+        FROM notes WHERE expiration < ?1 ORDER BY id_note",
+        params![height, EVENT_EXPIRED_SPEND],
+    )?;
+    connection.execute(
+        "INSERT INTO events(account, kind, height, txid, pool, id_ref, value)
+        SELECT account, ?2, ?1, NULL, 0, id_utxo, value
+        FROM utxos WHERE expiration < ?1 ORDER BY id_utxo",
+        params![height, EVENT_EXPIRED_SPEND],
+    )?;
+    Ok(())
+}
+
+/// Record that the sync data after `height` was dropped
+pub fn record_rollback(connection: &Connection, height: u32) -> Result<()> {
+    connection.execute(
+        "INSERT INTO events(account, kind, height, txid, pool, id_ref, value)
+        VALUES (0, ?1, ?2, NULL, 0, 0, 0)",
+        params![EVENT_ROLLBACK, height],
+    )?;
+    Ok(())
+}
+
+/// Events after the sequence number `seq`, in order and at most
+/// MAX_EVENTS_BATCH of them
+#[c_export]
+pub fn get_events_since(connection: &Connection, seq: u32) -> Result<Vec<BalanceEventT>> {
+    let mut s = connection.prepare(
+        "SELECT seq, account, kind, height, txid, pool, id_ref, value
+        FROM events WHERE seq > ?1 ORDER BY seq LIMIT ?2",
+    )?;
+    let rows = s.query_map(params![seq, MAX_EVENTS_BATCH], |r| {
+        Ok(BalanceEventT {
+            seq: r.get(0)?,
+            account: r.get(1)?,
+            kind: r.get(2)?,
+            height: r.get(3)?,
+            txid: r.get::<_, Option<Vec<u8>>>(4)?,
+            pool: r.get(5)?,
+            id_ref: r.get(6)?,
+            value: r.get(7)?,
+        })
+    })?;
+    Ok(rows.collect::<Result<Vec<_>, _>>()?)
+}
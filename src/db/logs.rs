@@ -0,0 +1,183 @@
+use std::{
+    fmt::{Debug, Write as _},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Result;
+use lazy_static::lazy_static;
+use parking_lot::{Mutex, RwLock};
+use rusqlite::{params, Connection};
+use tracing::{
+    field::{Field, Visit},
+    Event, Level, Subscriber,
+};
+use tracing_subscriber::{layer::Context, Layer};
+
+use crate::data::fb::{LogEntry, LogEntryT};
+use warp_macros::c_export;
+
+/*
+    Log ring buffer
+
+    Mobile users cannot easily get the device logs. The warnings
+    and errors (and optionally the sync summaries) are also kept
+    in the `logs` table, so that they can be attached to a bug report.
+
+    The tracing layer does not touch the database: an event may be
+    emitted while a connection is in use. It queues the records in
+    memory and they are written by `flush_logs`, after every sync and
+    before reading the logs. Only the last `capacity` records are kept.
+*/
+
+/// Target of the events that summarize a sync
+pub const SYNC_TARGET: &str = "sync";
+
+pub const LOG_ERROR: u8 = 1;
+pub const LOG_WARN: u8 = 2;
+pub const LOG_INFO: u8 = 3;
+pub const LOG_DEBUG: u8 = 4;
+pub const LOG_TRACE: u8 = 5;
+
+const DEFAULT_LOG_CAPACITY: u32 = 1000;
+
+struct LogConfig {
+    level: u8,
+    sync_summaries: bool,
+    capacity: u32,
+}
+
+lazy_static! {
+    static ref LOG_CONFIG: RwLock<LogConfig> = RwLock::new(LogConfig {
+        level: LOG_WARN,
+        sync_summaries: false,
+        capacity: DEFAULT_LOG_CAPACITY,
+    });
+    static ref PENDING_LOGS: Mutex<Vec<LogEntryT>> = Mutex::new(vec![]);
+}
+
+/// Select the events kept in the logs table: up to `level`
+/// (0 for none, 1 for errors, 2 for warnings...), plus the sync
+/// summaries if `sync_summaries`. `capacity` is the number of
+/// records kept (0 for the default)
+#[c_export]
+pub fn configure_logs(level: u8, sync_summaries: bool, capacity: u32) -> Result<()> {
+    if level > LOG_TRACE {
+        anyhow::bail!("Invalid log level {}", level);
+    }
+    let mut config = LOG_CONFIG.write();
+    config.level = level;
+    config.sync_summaries = sync_summaries;
+    config.capacity = if capacity == 0 {
+        DEFAULT_LOG_CAPACITY
+    } else {
+        capacity
+    };
+    Ok(())
+}
+
+/// Last `n` log records, newest first
+#[c_export]
+pub fn get_recent_logs(connection: &Connection, n: u32) -> Result<Vec<LogEntryT>> {
+    flush_logs(connection)?;
+    let mut s = connection.prepare(
+        "SELECT id_log, timestamp, level, target, message
+        FROM logs ORDER BY id_log DESC LIMIT ?1",
+    )?;
+    let rows = s.query_map([n], |r| {
+        Ok(LogEntryT {
+            id: r.get(0)?,
+            timestamp: r.get(1)?,
+            level: r.get(2)?,
+            target: Some(r.get::<_, String>(3)?),
+            message: Some(r.get::<_, String>(4)?),
+        })
+    })?;
+    let logs = rows.collect::<Result<Vec<_>, _>>()?;
+    Ok(logs)
+}
+
+#[c_export]
+pub fn clear_logs(connection: &Connection) -> Result<()> {
+    PENDING_LOGS.lock().clear();
+    connection.execute("DELETE FROM logs", [])?;
+    Ok(())
+}
+
+/// Write the queued records and drop the oldest ones
+/// above the capacity
+pub fn flush_logs(connection: &Connection) -> Result<()> {
+    let logs = std::mem::take(&mut *PENDING_LOGS.lock());
+    let capacity = LOG_CONFIG.read().capacity;
+    for l in logs.iter() {
+        connection.execute(
+            "INSERT INTO logs(timestamp, level, target, message)
+            VALUES (?1, ?2, ?3, ?4)",
+            params![l.timestamp, l.level, l.target, l.message],
+        )?;
+    }
+    connection.execute(
+        "DELETE FROM logs WHERE id_log <= (SELECT MAX(id_log) FROM logs) - ?1",
+        [capacity],
+    )?;
+    Ok(())
+}
+
+/// Tracing layer that queues the records for the logs table
+pub struct DbLogLayer;
+
+impl<S: Subscriber> Layer<S> for DbLogLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let level = match *metadata.level() {
+            Level::ERROR => LOG_ERROR,
+            Level::WARN => LOG_WARN,
+            Level::INFO => LOG_INFO,
+            Level::DEBUG => LOG_DEBUG,
+            Level::TRACE => LOG_TRACE,
+        };
+        let capacity = {
+            let config = LOG_CONFIG.read();
+            let sync_summary = config.sync_summaries && metadata.target() == SYNC_TARGET;
+            if level > config.level && !sync_summary {
+                return;
+            }
+            config.capacity as usize
+        };
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as u32)
+            .unwrap_or_default();
+        let mut pending = PENDING_LOGS.lock();
+        // nobody flushes, keep the last records only
+        if pending.len() >= capacity {
+            pending.remove(0);
+        }
+        pending.push(LogEntryT {
+            id: 0,
+            timestamp,
+            level,
+            target: Some(metadata.target().to_string()),
+            message: Some(visitor.message),
+        });
+    }
+}
+
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if !self.message.is_empty() {
+            self.message.push(' ');
+        }
+        if field.name() == "message" {
+            let _ = write!(self.message, "{:?}", value);
+        } else {
+            let _ = write!(self.message, "{}={:?}", field.name(), value);
+        }
+    }
+}
@@ -3,10 +3,19 @@ use rusqlite::{params, Connection};
 
 use warp_macros::c_export;
 
-use crate::{data::fb::UnconfirmedTxT, warp::sync::ReceivedTx};
+use crate::{
+    data::fb::UnconfirmedTxT,
+    db::api_keys::{check_access, Operation},
+    warp::sync::ReceivedTx,
+};
 
 #[c_export]
-pub fn list_unconfirmed_txs(connection: &Connection, account: u32) -> Result<Vec<UnconfirmedTxT>> {
+pub fn list_unconfirmed_txs(
+    connection: &Connection,
+    session: u32,
+    account: u32,
+) -> Result<Vec<UnconfirmedTxT>> {
+    check_access(connection, session, &Operation::Read { account })?;
     let mut s = connection.prepare("SELECT txid, value FROM mempool_txs WHERE account = ?1")?;
     let rows = s.query_map([account], |r| {
         let txid = r.get::<_, Vec<u8>>(0)?;
@@ -22,7 +31,12 @@ pub fn list_unconfirmed_txs(connection: &Connection, account: u32) -> Result<Vec
 }
 
 #[c_export]
-pub fn get_unconfirmed_balance(connection: &Connection, account: u32) -> Result<i64> {
+pub fn get_unconfirmed_balance(connection: &Connection, session: u32, account: u32) -> Result<i64> {
+    check_access(connection, session, &Operation::Read { account })?;
+    get_unconfirmed_balance_inner(connection, account)
+}
+
+pub fn get_unconfirmed_balance_inner(connection: &Connection, account: u32) -> Result<i64> {
     let balance = connection.query_row(
         "SELECT SUM(value) FROM mempool_txs
         WHERE account = ?1",
@@ -3,6 +3,7 @@ use rusqlite::{params, Connection, OptionalExtension as _, Row};
 
 use crate::{
     data::fb::{ShieldedMessageT, UserMemoT},
+    db::api_keys::{check_access, Operation},
     fb_unwrap,
     network::Network,
     txdetails::TransactionDetails,
@@ -11,7 +12,7 @@ use crate::{
 
 use warp_macros::c_export;
 
-use super::contacts::address_to_bytes;
+use super::contacts::{address_to_bytes, find_contact_by_address};
 
 pub fn navigate_message_by_height(
     connection: &Connection,
@@ -85,8 +86,11 @@ pub fn navigate_message_by_subject(
 pub fn get_message(connection: &Connection, id: u32) -> Result<ShieldedMessageT> {
     let r = connection.query_row(
         "SELECT m.id_msg, m.account, m.height, m.timestamp, m.txid, m.nout, m.incoming, m.sender, 
-        m.recipient, m.subject, m.body, m.read, t.id_tx FROM msgs m JOIN txs t
-        ON m.txid = t.txid WHERE m.id_msg = ?1",
+        m.recipient, m.subject, m.body, m.read, t.id_tx, c.name FROM msgs m
+        JOIN txs t ON m.txid = t.txid AND m.account = t.account
+        LEFT JOIN contact_receivers r ON r.account = m.account AND r.address = m.receiver
+        LEFT JOIN contacts c ON c.id_contact = COALESCE(m.contact, r.contact)
+        WHERE m.id_msg = ?1",
         [id],
         select_message,
     )
@@ -132,13 +136,18 @@ pub fn get_message(connection: &Connection, id: u32) -> Result<ShieldedMessageT>
 }
 
 #[c_export]
-pub fn list_messages(connection: &Connection, account: u32) -> Result<Vec<ShieldedMessageT>> {
+pub fn list_messages(
+    connection: &Connection,
+    session: u32,
+    account: u32,
+) -> Result<Vec<ShieldedMessageT>> {
+    check_access(connection, session, &Operation::Read { account })?;
     let mut s = connection.prepare(
         "SELECT m.id_msg, m.account, m.height, m.timestamp, m.txid, m.nout, m.incoming, m.sender, 
         m.recipient, m.subject, m.body, m.read, t.id_tx, c.name FROM msgs m 
         JOIN txs t ON m.txid = t.txid AND m.account = t.account
         LEFT JOIN contact_receivers r ON r.account = m.account AND r.address = m.receiver
-        LEFT JOIN contacts c ON c.id_contact = r.contact
+        LEFT JOIN contacts c ON c.id_contact = COALESCE(m.contact, r.contact)
         WHERE m.account = ?1 ORDER BY m.height DESC",
     )?;
     let rows = s.query_map([account], select_message)?;
@@ -234,8 +243,8 @@ pub fn store_message(
     let mut s = connection.prepare_cached(
         "INSERT INTO msgs
         (account, height, timestamp, txid, nout, incoming,
-        sender, recipient, receiver, subject, body, read)
-        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, false)
+        sender, recipient, receiver, subject, body, read, contact)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, false, ?12)
         ON CONFLICT DO NOTHING",
     )?;
     let memo = fb_unwrap!(message.memo);
@@ -244,6 +253,11 @@ pub fn store_message(
     } else {
         memo.recipient.clone()
     };
+    let contact = r
+        .as_ref()
+        .map(|r| find_contact_by_address(network, connection, account, r))
+        .transpose()?
+        .flatten();
     let r = r.map(|r| address_to_bytes(network, &r).unwrap());
     s.execute(params![
         account,
@@ -256,7 +270,8 @@ pub fn store_message(
         memo.recipient,
         r,
         memo.subject,
-        memo.body
+        memo.body,
+        contact
     ])?;
     Ok(())
 }
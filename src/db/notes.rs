@@ -1,10 +1,15 @@
+use std::collections::HashMap;
+
 use crate::{
-    data::fb::{IdNoteT, InputTransparentT, ShieldedNoteT},
+    account::watch_only::WATCH_ONLY_SCOPE,
+    data::fb::{IdNoteT, InputTransparentT, NoteProfile, NoteProfileT, ScriptUtxoT, ShieldedNoteT},
+    db::api_keys::{check_access, Operation},
+    network::Network,
     types::CheckpointHeight,
     utils::ContextExt,
     warp::{
         sync::{IdSpent, PlainNote, ReceivedNote, ReceivedTx, TxValueUpdate},
-        BlockHeader, OutPoint, Witness, STXO, UTXO,
+        BlockHeader, OutPoint, ScriptTxOut, Witness, STXO, UTXO,
     },
     Hash,
 };
@@ -13,6 +18,10 @@ use rusqlite::{params, Connection, OptionalExtension, Row, Transaction};
 
 use warp_macros::c_export;
 
+use super::account::{confirmed_height, get_account_info};
+use super::events::{
+    record_expired_spends, record_note_event, record_utxo_event, EVENT_RECEIVED, EVENT_SPENT,
+};
 use super::tx::{add_tx_value, store_tx};
 
 pub fn get_note_by_nf(
@@ -65,6 +74,7 @@ fn select_note(row: &Row) -> Result<ReceivedNote, rusqlite::Error> {
         timestamp,
         tx_value,
         witness,
+        coinbase,
     ) = (
         row.get::<_, u32>(0)?,
         row.get::<_, u32>(1)?,
@@ -81,6 +91,7 @@ fn select_note(row: &Row) -> Result<ReceivedNote, rusqlite::Error> {
         row.get::<_, u32>(12)?,
         row.get::<_, i64>(13)?,
         row.get::<_, Vec<u8>>(14)?,
+        row.get::<_, bool>(15)?,
     );
     let note = ReceivedNote {
         is_new: false,
@@ -105,6 +116,7 @@ fn select_note(row: &Row) -> Result<ReceivedNote, rusqlite::Error> {
         },
         spent,
         witness: bincode::deserialize_from(&*witness).unwrap(),
+        coinbase,
     };
     Ok(note)
 }
@@ -119,11 +131,14 @@ pub fn list_all_received_notes(
     let height: u32 = height.into();
     let mut s = connection.prepare(
         "SELECT n.id_note, n.account, n.position, n.height, n.output_index, n.address,
-        n.value, n.rcm, n.nf, n.rho, n.spent, t.txid, t.timestamp, t.value, w.witness
+        n.value, n.rcm, n.nf, n.rho, n.spent, t.txid, t.timestamp, t.value, w.witness,
+        n.coinbase
         FROM notes n, txs t, witnesses w WHERE
         n.tx = t.id_tx AND n.account = t.account
-        AND w.account = n.account AND w.note = n.id_note AND w.height = ?1
-        AND orchard = ?2 AND spent IS NULL
+        AND w.account = n.account AND w.note = n.id_note
+        AND w.height = (SELECT MAX(height) FROM witnesses
+            WHERE account = n.account AND note = n.id_note AND height <= ?1)
+        AND orchard = ?2 AND spent IS NULL AND asset IS NULL
         ORDER BY n.value DESC",
     )?;
     let rows = s.query_map(params![height, orchard], select_note)?;
@@ -131,24 +146,108 @@ pub fn list_all_received_notes(
     Ok(notes)
 }
 
+/// Only the notes that have `min_confirmations` at the checkpoint
 pub fn list_received_notes(
     connection: &Connection,
     account: u32,
     height: CheckpointHeight,
     orchard: bool,
+    min_confirmations: u32,
 ) -> Result<Vec<ReceivedNote>> {
     let height: u32 = height.into();
+    let confirmed = confirmed_height(height, min_confirmations);
     let mut s = connection.prepare(
         "SELECT n.id_note, n.account, n.position, n.height, n.output_index, n.address,
-        n.value, n.rcm, n.nf, n.rho, n.spent, t.txid, t.timestamp, t.value, w.witness
+        n.value, n.rcm, n.nf, n.rho, n.spent, t.txid, t.timestamp, t.value, w.witness,
+        n.coinbase
         FROM notes n, txs t, witnesses w
         WHERE n.tx = t.id_tx AND n.account = t.account
-        AND w.note = n.id_note AND w.account = n.account AND w.height = ?1
+        AND w.note = n.id_note AND w.account = n.account
+        AND w.height = (SELECT MAX(height) FROM witnesses
+            WHERE account = n.account AND note = n.id_note AND height <= ?1)
         AND orchard = ?2 AND spent IS NULL AND n.account = ?3 AND NOT excluded
-        AND n.height <= ?1 AND n.expiration IS NULL
+        AND asset IS NULL
+        AND n.height <= ?4 AND n.expiration IS NULL
         ORDER BY n.value DESC",
     )?;
-    let rows = s.query_map(params![height, orchard, account], select_note)?;
+    let rows = s.query_map(params![height, orchard, account, confirmed], select_note)?;
+    let notes = rows.collect::<Result<Vec<_>, _>>()?;
+    Ok(notes)
+}
+
+/// Unspent notes at the checkpoint `height` that only have witnesses
+/// after it, with their earliest one
+pub fn list_later_witnesses(
+    connection: &Connection,
+    height: u32,
+    orchard: bool,
+) -> Result<Vec<(u32, u32, Witness)>> {
+    let mut s = connection.prepare(
+        "SELECT n.account, n.id_note, w.witness
+        FROM notes n, witnesses w
+        WHERE w.note = n.id_note AND w.account = n.account
+        AND w.height = (SELECT MIN(height) FROM witnesses
+            WHERE account = n.account AND note = n.id_note AND height >= ?1)
+        AND w.height > ?1 AND n.height <= ?1 AND n.orchard = ?2
+        AND (n.spent IS NULL OR n.spent > ?1)",
+    )?;
+    let rows = s.query_map(params![height, orchard], |r| {
+        let witness = r.get::<_, Vec<u8>>(2)?;
+        Ok((r.get::<_, u32>(0)?, r.get::<_, u32>(1)?, witness))
+    })?;
+    let mut witnesses = vec![];
+    for r in rows {
+        let (account, id_note, witness) = r?;
+        let witness = bincode::deserialize::<Witness>(&witness)?;
+        witnesses.push((account, id_note, witness));
+    }
+    Ok(witnesses)
+}
+
+/// Note `id` with its latest witness at or before `height`,
+/// the height of the witness and true if it is an orchard note
+pub fn get_received_note(
+    connection: &Connection,
+    id: u32,
+    height: CheckpointHeight,
+) -> Result<Option<(ReceivedNote, u32, bool)>> {
+    let height: u32 = height.into();
+    let note = connection
+        .query_row(
+            "SELECT n.id_note, n.account, n.position, n.height, n.output_index, n.address,
+            n.value, n.rcm, n.nf, n.rho, n.spent, t.txid, t.timestamp, t.value, w.witness,
+            n.coinbase, w.height, n.orchard
+            FROM notes n, txs t, witnesses w
+            WHERE n.tx = t.id_tx AND n.account = t.account
+            AND w.note = n.id_note AND w.account = n.account
+            AND w.height = (SELECT MAX(height) FROM witnesses
+                WHERE account = n.account AND note = n.id_note AND height <= ?2)
+            AND n.id_note = ?1",
+            params![id, height],
+            |r| Ok((select_note(r)?, r.get::<_, u32>(16)?, r.get::<_, bool>(17)?)),
+        )
+        .optional()?;
+    Ok(note)
+}
+
+/// Unspent notes (id, account, value) that have this nullifier
+pub fn find_notes_by_nf(
+    connection: &Connection,
+    nf: &Hash,
+    orchard: bool,
+) -> Result<Vec<(u32, u32, u64)>> {
+    let mut s = connection.prepare_cached(
+        "SELECT n.id_note, n.account, n.value FROM nullifiers f
+        JOIN notes n ON n.id_note = f.id_note
+        WHERE f.nf = ?1 AND f.orchard = ?2 AND n.spent IS NULL",
+    )?;
+    let rows = s.query_map(params![nf, orchard], |r| {
+        Ok((
+            r.get::<_, u32>(0)?,
+            r.get::<_, u32>(1)?,
+            r.get::<_, u64>(2)?,
+        ))
+    })?;
     let notes = rows.collect::<Result<Vec<_>, _>>()?;
     Ok(notes)
 }
@@ -176,6 +275,13 @@ pub fn mark_shielded_spent(connection: &Transaction, id_spent: &IdSpent<Hash>) -
     let mut s = connection
         .prepare_cached("UPDATE notes SET spent = ?2, expiration = NULL WHERE id_note = ?1")?;
     s.execute(params![id_note, id_spent.height])?;
+    record_note_event(
+        connection,
+        EVENT_SPENT,
+        id_note,
+        id_spent.height,
+        &id_spent.txid,
+    )?;
     Ok(())
 }
 
@@ -205,6 +311,13 @@ pub fn mark_transparent_spent(
     let mut s = connection
         .prepare_cached("UPDATE utxos SET spent = ?2, expiration = NULL WHERE id_utxo = ?1")?;
     s.execute(params![id_utxo, id_spent.height])?;
+    record_utxo_event(
+        connection,
+        EVENT_SPENT,
+        id_utxo,
+        id_spent.height,
+        &id_spent.txid,
+    )?;
     Ok(())
 }
 
@@ -232,6 +345,7 @@ pub fn mark_notes_unconfirmed_spent(
 }
 
 pub fn recover_expired_spends(connection: &Connection, height: u32) -> Result<()> {
+    record_expired_spends(connection, height)?;
     connection.execute(
         "UPDATE notes SET expiration = NULL WHERE expiration < ?1",
         [height],
@@ -248,15 +362,73 @@ pub fn recover_expired_spends(connection: &Connection, height: u32) -> Result<()
     Ok(())
 }
 
+/*
+    Change notes
+
+    The change of a payment comes back to the account as a note
+    that the sync decrypts like any other. A note is change when
+    the transaction that created it spends funds of the same account
+    (so that we are the sender and can recover the output with our
+    OVK), and it is sent to the change address of its pool: the
+    default Sapling or Orchard address, see `to_change_address`.
+
+    Change notes are still spent like the others, but they are
+    not received funds: they don't generate incoming messages
+    and are flagged as `change` in the note lists.
+*/
+
+/// Flag the change notes created after `height`
+pub fn tag_change_notes(network: &Network, connection: &Connection, height: u32) -> Result<()> {
+    let mut s = connection.prepare(
+        "SELECT n.id_note, n.account, n.orchard, n.address FROM notes n
+        WHERE n.height > ?1 AND NOT n.change
+        AND (EXISTS(SELECT 1 FROM note_spends s WHERE s.id_tx = n.tx AND s.account = n.account)
+        OR EXISTS(SELECT 1 FROM utxo_spends s WHERE s.id_tx = n.tx AND s.account = n.account))",
+    )?;
+    let rows = s.query_map([height], |r| {
+        Ok((
+            r.get::<_, u32>(0)?,
+            r.get::<_, u32>(1)?,
+            r.get::<_, bool>(2)?,
+            r.get::<_, Vec<u8>>(3)?,
+        ))
+    })?;
+    let mut change_addresses = HashMap::new();
+    let mut s_change =
+        connection.prepare_cached("UPDATE notes SET change = TRUE WHERE id_note = ?1")?;
+    for r in rows {
+        let (id_note, account, orchard, address) = r?;
+        if !change_addresses.contains_key(&account) {
+            let ai = get_account_info(network, connection, account)?;
+            let sapling_change = ai.sapling.as_ref().map(|si| si.addr.to_bytes());
+            let orchard_change = ai.orchard.as_ref().map(|oi| oi.addr.to_raw_address_bytes());
+            change_addresses.insert(account, (sapling_change, orchard_change));
+        }
+        let (sapling_change, orchard_change) = &change_addresses[&account];
+        let change = if orchard {
+            orchard_change.as_ref()
+        } else {
+            sapling_change.as_ref()
+        };
+        if change.map(|c| &c[..]) == Some(&address[..]) {
+            s_change.execute([id_note])?;
+        }
+    }
+    Ok(())
+}
+
+/// Store the new notes and the witnesses at `height`. With `lazy_witnesses`,
+/// only the witnesses of the new notes are stored
 pub fn store_received_note(
     connection: &Transaction,
     height: u32,
     notes: &[ReceivedNote],
+    lazy_witnesses: bool,
 ) -> Result<()> {
     let mut s_note = connection.prepare_cached(
         "INSERT INTO notes
-    (account, position, height, tx, output_index, address, value, rcm, nf, rho, spent, orchard, excluded)
-    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, FALSE)",
+    (account, position, height, tx, output_index, address, value, rcm, nf, rho, spent, orchard, excluded, coinbase)
+    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, FALSE, ?13)",
     )?;
     for n in notes {
         let orchard = n.rho.is_some();
@@ -275,8 +447,10 @@ pub fn store_received_note(
             )?;
             s_note.execute(params![
                 n.account, n.position, n.height, id_tx, n.vout, n.address, n.value, n.rcm, n.nf,
-                n.rho, n.spent, orchard,
+                n.rho, n.spent, orchard, n.coinbase,
             ])?;
+            let id_note = connection.last_insert_rowid() as u32;
+            record_note_event(connection, EVENT_RECEIVED, id_note, n.height, &n.tx.txid)?;
         }
         let id_note = connection.query_row(
             "SELECT id_note FROM notes
@@ -285,13 +459,20 @@ pub fn store_received_note(
             |r| r.get::<_, u32>(0),
         )?;
         store_witness(connection, n.account, id_note, height, &n.witness)?;
+        if lazy_witnesses {
+            // the previous witnesses can be derived from this one
+            connection.execute(
+                "DELETE FROM witnesses WHERE account = ?1 AND note = ?2 AND height < ?3",
+                params![n.account, id_note, height],
+            )?;
+        }
     }
 
     Ok(())
 }
 
 pub fn store_witness(
-    connection: &Transaction,
+    connection: &Connection,
     account: u32,
     id_note: u32,
     height: u32,
@@ -389,13 +570,16 @@ pub fn list_pending_stxos(connection: &Connection, account: u32) -> Result<Vec<S
     Ok(stxos)
 }
 
+/// Only the UTXOs that have `min_confirmations` at `height`
 pub fn list_utxos(
     connection: &Connection,
     account: u32,
     height: CheckpointHeight,
+    min_confirmations: u32,
 ) -> Result<Vec<UTXO>> {
     let height: u32 = height.into();
-    // exclude unconfirmed spents
+    let confirmed = confirmed_height(height, min_confirmations);
+    // exclude unconfirmed spents and the watch-only addresses
     let mut s = connection.prepare(
         &("SELECT u.id_utxo, u.account, u.external, u.addr_index, u.height, u.external, u.txid, u.vout, s.address,
         u.value FROM utxos u
@@ -403,11 +587,14 @@ pub fn list_utxos(
         JOIN t_addresses s ON t.account = s.account
             AND u.external = s.external
             AND u.addr_index = s.addr_index
-        WHERE u.height <= ?1 AND (u.spent IS NULL OR u.spent > ?1)
-        AND u.expiration IS NULL
+        WHERE u.height <= ?3 AND (u.spent IS NULL OR u.spent > ?1)
+        AND u.expiration IS NULL AND u.external <> ?4
         AND u.account = ?2 ORDER BY u.height DESC"),
     )?;
-    let rows = s.query_map(params![height, account], select_utxo)?;
+    let rows = s.query_map(
+        params![height, account, confirmed, WATCH_ONLY_SCOPE],
+        select_utxo,
+    )?;
     let utxos = rows.collect::<Result<Vec<_>, _>>()?;
 
     Ok(utxos)
@@ -421,7 +608,7 @@ pub fn store_utxo(connection: &Transaction, utxo: &UTXO) -> Result<()> {
             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
             ON CONFLICT DO NOTHING",
         )?;
-        s.execute(params![
+        let inserted = s.execute(params![
             utxo.account,
             utxo.height,
             utxo.timestamp,
@@ -432,6 +619,10 @@ pub fn store_utxo(connection: &Transaction, utxo: &UTXO) -> Result<()> {
             utxo.value,
             None::<u32>
         ])?;
+        if inserted == 1 {
+            let id_utxo = connection.last_insert_rowid() as u32;
+            record_utxo_event(connection, EVENT_RECEIVED, id_utxo, utxo.height, &utxo.txid)?;
+        }
         let tx_value = TxValueUpdate {
             id_tx: 0,
             account: utxo.account,
@@ -462,13 +653,13 @@ pub fn update_account_balances(connection: &Transaction) -> Result<()> {
     connection.execute(
         "UPDATE accounts SET balance = balances.balance FROM
         (WITH 
-            coins AS (SELECT account, value, spent FROM notes UNION ALL
-                SELECT account, value, spent FROM utxos),
+            coins AS (SELECT account, value, spent FROM notes WHERE asset IS NULL UNION ALL
+                SELECT account, value, spent FROM utxos WHERE external <> ?1),
             unspent AS (SELECT account, SUM(value) AS balance , spent FROM coins WHERE spent IS NULL GROUP BY account)
 		SELECT id_account, COALESCE(u.balance, 0) AS balance FROM accounts a
 		LEFT JOIN unspent u ON a.id_account = u.account) AS balances
         WHERE balances.id_account = accounts.id_account",
-        [],
+        [WATCH_ONLY_SCOPE],
     )?;
     Ok(())
 }
@@ -476,12 +667,14 @@ pub fn update_account_balances(connection: &Transaction) -> Result<()> {
 #[c_export]
 pub fn get_unspent_notes(
     connection: &Connection,
+    session: u32,
     account: u32,
     bc_height: u32,
 ) -> Result<Vec<ShieldedNoteT>> {
+    check_access(connection, session, &Operation::Read { account })?;
     let mut s = connection.prepare(
-        "SELECT n.id_note, n.height, t.timestamp, n.value, n.orchard, n.excluded
-        FROM notes n JOIN txs t ON n.tx = t.id_tx
+        "SELECT n.id_note, n.height, t.timestamp, n.value, n.orchard, n.excluded, n.coinbase,
+        n.change, n.asset FROM notes n JOIN txs t ON n.tx = t.id_tx
         WHERE n.account = ?1 AND (spent IS NULL OR spent > ?2) AND n.expiration IS NULL
         ORDER BY n.height DESC",
     )?;
@@ -493,11 +686,14 @@ pub fn get_unspent_notes(
             r.get::<_, u64>(3)?,
             r.get::<_, bool>(4)?,
             r.get::<_, bool>(5)?,
+            r.get::<_, bool>(6)?,
+            r.get::<_, bool>(7)?,
+            r.get::<_, Option<Vec<u8>>>(8)?,
         ))
     })?;
     let mut notes = vec![];
     for r in rows {
-        let (id, height, timestamp, value, orchard, excluded) = r?;
+        let (id, height, timestamp, value, orchard, excluded, coinbase, change, asset) = r?;
         let note = ShieldedNoteT {
             id_note: id,
             height,
@@ -506,19 +702,101 @@ pub fn get_unspent_notes(
             value,
             orchard,
             excluded,
+            coinbase,
+            change,
+            asset,
         };
         notes.push(note);
     }
     Ok(notes)
 }
 
+/// A P2PK or multisig output received by an account
+pub struct ReceivedScriptUtxo {
+    pub account: u32,
+    pub height: u32,
+    pub timestamp: u32,
+    pub txid: Hash,
+    pub out: ScriptTxOut,
+}
+
+pub fn store_script_utxo(connection: &Transaction, utxo: &ReceivedScriptUtxo) -> Result<()> {
+    let mut s = connection.prepare_cached(
+        "INSERT INTO script_utxos
+        (account, height, timestamp, txid, vout, value, script_type, script)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+        ON CONFLICT DO NOTHING",
+    )?;
+    s.execute(params![
+        utxo.account,
+        utxo.height,
+        utxo.timestamp,
+        utxo.txid,
+        utxo.out.vout,
+        utxo.out.value,
+        utxo.out.script_type,
+        utxo.out.script,
+    ])?;
+    Ok(())
+}
+
+/// Outpoints of the P2PK and multisig outputs that are not spent
+pub fn list_unspent_script_utxos(connection: &Connection) -> Result<Vec<OutPoint>> {
+    let mut s = connection.prepare("SELECT txid, vout FROM script_utxos WHERE spent IS NULL")?;
+    let rows = s.query_map([], |r| {
+        Ok(OutPoint {
+            txid: r.get(0)?,
+            vout: r.get(1)?,
+        })
+    })?;
+    let outpoints = rows.collect::<Result<Vec<_>, _>>()?;
+    Ok(outpoints)
+}
+
+pub fn mark_script_utxo_spent(
+    connection: &Transaction,
+    outpoint: &OutPoint,
+    height: u32,
+) -> Result<()> {
+    let mut s = connection
+        .prepare_cached("UPDATE script_utxos SET spent = ?3 WHERE txid = ?1 AND vout = ?2")?;
+    s.execute(params![outpoint.txid, outpoint.vout, height])?;
+    Ok(())
+}
+
+/// Unspent P2PK and multisig outputs that pay a key of the account.
+/// They are not in the balance, the wallet cannot spend them yet
+#[c_export]
+pub fn list_script_utxos(connection: &Connection, account: u32) -> Result<Vec<ScriptUtxoT>> {
+    let mut s = connection.prepare(
+        "SELECT id_script_utxo, height, timestamp, txid, vout, value, script_type, script
+        FROM script_utxos WHERE account = ?1 AND spent IS NULL ORDER BY height DESC",
+    )?;
+    let rows = s.query_map([account], |r| {
+        Ok(ScriptUtxoT {
+            id: r.get(0)?,
+            height: r.get(1)?,
+            timestamp: r.get(2)?,
+            txid: Some(r.get::<_, Vec<u8>>(3)?),
+            vout: r.get(4)?,
+            value: r.get(5)?,
+            script_type: r.get(6)?,
+            script: Some(r.get::<_, Vec<u8>>(7)?),
+        })
+    })?;
+    let utxos = rows.collect::<Result<Vec<_>, _>>()?;
+    Ok(utxos)
+}
+
 #[c_export]
 pub fn get_unspent_utxos(
     connection: &Connection,
+    session: u32,
     account: u32,
     bc_height: u32,
 ) -> Result<Vec<InputTransparentT>> {
-    let utxos = list_utxos(connection, account, CheckpointHeight(bc_height))?;
+    check_access(connection, session, &Operation::Read { account })?;
+    let utxos = list_utxos(connection, account, CheckpointHeight(bc_height), 0)?;
     let utxos = utxos
         .into_iter()
         .map(|u| InputTransparentT {
@@ -548,3 +826,119 @@ pub fn reverse_note_exclusion(connection: &Connection, account: u32) -> Result<(
     )?;
     Ok(())
 }
+
+/*
+    Note exclusion profiles
+
+    A profile is a named set of excluded notes of an account, for
+    example the notes that come from an exchange or the notes
+    that are kept for the long term. The `excluded` flags of the
+    notes are the working set that `list_received_notes` filters
+    on, and a profile is a saved copy of them:
+    - `save_note_profile` saves the excluded unspent notes under
+    a name, and replaces the profile if it exists,
+    - `apply_note_profile` sets the flags of every note of the
+    account to the profile in one transaction, so that a payment
+    never sees a mix of two profiles. Profile 0 clears the flags.
+    The last saved or applied profile is the active one. Changing
+    the flags with `exclude_note` afterwards does not update it.
+
+    The profiles keep the nullifiers of the notes, not their ids,
+    so they still apply after a rescan.
+*/
+
+/// Returns the id of the profile
+#[c_export]
+pub fn save_note_profile(connection: &mut Connection, account: u32, name: &str) -> Result<u32> {
+    let db_tx = connection.transaction()?;
+    let id = db_tx.query_row(
+        "INSERT INTO note_profiles(account, name, active)
+        VALUES (?1, ?2, TRUE) ON CONFLICT DO UPDATE
+        SET active = TRUE RETURNING id_profile",
+        params![account, name],
+        |r| r.get::<_, u32>(0),
+    )?;
+    db_tx.execute(
+        "UPDATE note_profiles SET active = FALSE
+        WHERE account = ?1 AND id_profile != ?2",
+        params![account, id],
+    )?;
+    db_tx.execute("DELETE FROM note_profile_notes WHERE profile = ?1", [id])?;
+    db_tx.execute(
+        "INSERT INTO note_profile_notes(profile, nf)
+        SELECT ?2, nf FROM notes
+        WHERE account = ?1 AND excluded AND spent IS NULL",
+        params![account, id],
+    )?;
+    db_tx.commit()?;
+    Ok(id)
+}
+
+/// The profiles of the account, with the number and the
+/// value of their unspent notes
+#[c_export]
+pub fn list_note_profiles(connection: &Connection, account: u32) -> Result<Vec<NoteProfileT>> {
+    let mut s = connection.prepare(
+        "SELECT p.id_profile, p.name, p.active, COUNT(n.id_note), COALESCE(SUM(n.value), 0)
+        FROM note_profiles p
+        LEFT JOIN note_profile_notes pn ON pn.profile = p.id_profile
+        LEFT JOIN notes n ON n.nf = pn.nf AND n.account = p.account AND n.spent IS NULL
+        WHERE p.account = ?1 GROUP BY p.id_profile ORDER BY p.name",
+    )?;
+    let rows = s.query_map([account], |r| {
+        Ok(NoteProfileT {
+            id: r.get(0)?,
+            name: Some(r.get::<_, String>(1)?),
+            active: r.get(2)?,
+            notes: r.get(3)?,
+            value: r.get(4)?,
+        })
+    })?;
+    let profiles = rows.collect::<Result<Vec<_>, _>>()?;
+    Ok(profiles)
+}
+
+#[c_export]
+pub fn apply_note_profile(connection: &mut Connection, account: u32, id: u32) -> Result<()> {
+    let db_tx = connection.transaction()?;
+    if id != 0 {
+        let exists = db_tx.query_row(
+            "SELECT EXISTS(SELECT 1 FROM note_profiles
+            WHERE id_profile = ?1 AND account = ?2)",
+            params![id, account],
+            |r| r.get::<_, bool>(0),
+        )?;
+        if !exists {
+            anyhow::bail!("Profile {} not found in account {}", id, account);
+        }
+    }
+    db_tx.execute(
+        "UPDATE notes SET excluded =
+        nf IN (SELECT nf FROM note_profile_notes WHERE profile = ?2)
+        WHERE account = ?1",
+        params![account, id],
+    )?;
+    db_tx.execute(
+        "UPDATE note_profiles SET active = (id_profile = ?2) WHERE account = ?1",
+        params![account, id],
+    )?;
+    db_tx.commit()?;
+    Ok(())
+}
+
+/// The flags of the notes stay as they are
+#[c_export]
+pub fn delete_note_profile(connection: &mut Connection, account: u32, id: u32) -> Result<()> {
+    let db_tx = connection.transaction()?;
+    db_tx.execute(
+        "DELETE FROM note_profile_notes WHERE profile =
+        (SELECT id_profile FROM note_profiles WHERE id_profile = ?1 AND account = ?2)",
+        params![id, account],
+    )?;
+    db_tx.execute(
+        "DELETE FROM note_profiles WHERE id_profile = ?1 AND account = ?2",
+        params![id, account],
+    )?;
+    db_tx.commit()?;
+    Ok(())
+}
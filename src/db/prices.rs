@@ -0,0 +1,115 @@
+use std::{
+    collections::HashSet,
+    fs::File,
+    io::{BufRead as _, BufReader},
+};
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use rusqlite::{params, Connection, OptionalExtension as _};
+
+use warp_macros::c_export;
+
+/*
+    Historical prices, one per day, for the fiat valuation
+    of the transactions without network access.
+
+    The CSV file has a `date,price` row per day, with the date
+    in YYYY-MM-DD format. An optional header row is skipped.
+    The file is read line by line and imported in a single db transaction:
+    it is rejected as a whole if any row is invalid.
+    Prices already in the db for the same currency and day are replaced,
+    the others are kept, so that ranges from several files merge.
+*/
+
+/// Import the prices of `currency` from a CSV file.
+/// Returns the number of days imported
+#[c_export]
+pub fn import_prices(connection: &mut Connection, path: &str, currency: &str) -> Result<u32> {
+    let currency = currency.trim().to_uppercase();
+    if currency.is_empty() {
+        anyhow::bail!("Missing currency");
+    }
+    let reader = BufReader::new(File::open(path)?);
+    let db_tx = connection.transaction()?;
+    let mut days = HashSet::new();
+    {
+        let mut s = db_tx.prepare(
+            "INSERT INTO prices(currency, timestamp, price) VALUES (?1, ?2, ?3)
+            ON CONFLICT DO UPDATE SET price = excluded.price",
+        )?;
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let row = parse_row(line);
+            let (timestamp, price) = match row {
+                Ok(row) => row,
+                Err(_) if i == 0 => continue, // header
+                Err(e) => anyhow::bail!("Line {}: {}", i + 1, e),
+            };
+            if !days.insert(timestamp) {
+                anyhow::bail!("Line {}: duplicate date", i + 1);
+            }
+            s.execute(params![currency, timestamp, price])?;
+        }
+    }
+    db_tx.commit()?;
+    Ok(days.len() as u32)
+}
+
+/// Price of `currency` on the day of `timestamp`,
+/// or the last known price before it
+pub fn get_price(connection: &Connection, currency: &str, timestamp: u32) -> Result<Option<f64>> {
+    let price = connection
+        .query_row(
+            "SELECT price FROM prices WHERE currency = ?1 AND timestamp <= ?2
+            ORDER BY timestamp DESC LIMIT 1",
+            params![currency.to_uppercase(), timestamp],
+            |r| r.get::<_, f64>(0),
+        )
+        .optional()?;
+    Ok(price)
+}
+
+/// Value of `amount` zats in `currency` on the day of `timestamp`
+pub fn get_fiat_value(
+    connection: &Connection,
+    currency: &str,
+    timestamp: u32,
+    amount: i64,
+) -> Result<Option<f64>> {
+    let price = get_price(connection, currency, timestamp)?;
+    Ok(price.map(|p| amount as f64 * p / 100_000_000.0))
+}
+
+/// First and last day with a price for `currency`
+pub fn get_price_range(connection: &Connection, currency: &str) -> Result<Option<(u32, u32)>> {
+    let range = connection.query_row(
+        "SELECT MIN(timestamp), MAX(timestamp) FROM prices WHERE currency = ?1",
+        [currency.to_uppercase()],
+        |r| Ok((r.get::<_, Option<u32>>(0)?, r.get::<_, Option<u32>>(1)?)),
+    )?;
+    Ok(range.0.zip(range.1))
+}
+
+fn parse_row(line: &str) -> Result<(u32, f64)> {
+    let mut fields = line.split(',').map(|f| f.trim().trim_matches('"'));
+    let (Some(date), Some(price), None) = (fields.next(), fields.next(), fields.next()) else {
+        anyhow::bail!("expected date,price");
+    };
+    let date = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|_| anyhow::anyhow!("invalid date {}", date))?;
+    let timestamp = date.and_hms_opt(0, 0, 0).unwrap().and_utc().timestamp();
+    let timestamp =
+        u32::try_from(timestamp).map_err(|_| anyhow::anyhow!("date {} out of range", date))?;
+    let price = price
+        .parse::<f64>()
+        .map_err(|_| anyhow::anyhow!("invalid price {}", price))?;
+    if !price.is_finite() || price <= 0.0 {
+        anyhow::bail!("invalid price {}", price);
+    }
+    Ok((timestamp, price))
+}
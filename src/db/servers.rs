@@ -0,0 +1,146 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+use rusqlite::{params, Connection, OptionalExtension as _};
+
+use crate::{coin::CoinDef, data::fb::ServerT};
+use warp_macros::c_export;
+
+/*
+    Server scores
+
+    `test_servers` measures the latency and the height of every
+    server of the config and keeps the results in `servers`:
+    - the latency is a moving average (3/4 of the previous value and
+    1/4 of the new one),
+    - the number of tests and failures are halved every MAX_TESTS tests,
+    so that the failure rate follows the recent results.
+
+    A server is healthy if its last test succeeded, it fails less than
+    one test out of two, and it is less than MAX_HEIGHT_LAG blocks
+    behind the highest server. The best server is the healthy server
+    with the lowest latency, weighted by its failure rate. The client
+    of the wallet uses it when there is more than one server and some
+    are healthy. Otherwise, the requests are balanced between all the
+    servers.
+*/
+
+const MAX_TESTS: u32 = 100;
+const MAX_HEIGHT_LAG: u32 = 10;
+
+/// Record the result of a test of `url`: latency (ms) and height,
+/// or None if it failed
+pub fn record_server_test(
+    connection: &Connection,
+    url: &str,
+    result: Option<(u32, u32)>,
+) -> Result<()> {
+    let updated = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as u32;
+    let previous = connection
+        .query_row(
+            "SELECT latency, tests, failures, height FROM servers WHERE url = ?1",
+            [url],
+            |r| {
+                Ok((
+                    r.get::<_, u32>(0)?,
+                    r.get::<_, u32>(1)?,
+                    r.get::<_, u32>(2)?,
+                    r.get::<_, u32>(3)?,
+                ))
+            },
+        )
+        .optional()?;
+    let (mut latency, mut tests, mut failures, mut height) = previous.unwrap_or_default();
+    if tests >= MAX_TESTS {
+        tests /= 2;
+        failures /= 2;
+    }
+    tests += 1;
+    match result {
+        Some((l, h)) => {
+            latency = if tests == 1 || latency == 0 {
+                l
+            } else {
+                (3 * latency + l) / 4
+            };
+            height = h;
+        }
+        None => failures += 1,
+    }
+    connection.execute(
+        "INSERT INTO servers(url, latency, tests, failures, height, last_ok, updated)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7) ON CONFLICT (url) DO UPDATE SET
+        latency = excluded.latency, tests = excluded.tests, failures = excluded.failures,
+        height = excluded.height, last_ok = excluded.last_ok, updated = excluded.updated",
+        params![
+            url,
+            latency,
+            tests,
+            failures,
+            height,
+            result.is_some(),
+            updated
+        ],
+    )?;
+    Ok(())
+}
+
+/// Scores of the servers of the config. The servers that were
+/// never tested have no latency and are not healthy
+#[c_export]
+pub fn list_servers(coin: &CoinDef, connection: &Connection) -> Result<Vec<ServerT>> {
+    let urls = coin.config.servers.clone().unwrap_or_default();
+    let mut servers = vec![];
+    let mut last_ok = vec![];
+    for url in urls {
+        let s = connection
+            .query_row(
+                "SELECT latency, tests, failures, height, last_ok, updated
+                FROM servers WHERE url = ?1",
+                [&url],
+                |r| {
+                    Ok((
+                        ServerT {
+                            url: Some(url.clone()),
+                            latency: r.get(0)?,
+                            tests: r.get(1)?,
+                            failures: r.get(2)?,
+                            height: r.get(3)?,
+                            updated: r.get(5)?,
+                            healthy: false,
+                        },
+                        r.get::<_, bool>(4)?,
+                    ))
+                },
+            )
+            .optional()?;
+        let (server, ok) = s.unwrap_or_else(|| {
+            let server = ServerT {
+                url: Some(url.clone()),
+                ..ServerT::default()
+            };
+            (server, false)
+        });
+        servers.push(server);
+        last_ok.push(ok);
+    }
+    let max_height = servers.iter().map(|s| s.height).max().unwrap_or_default();
+    for (s, ok) in servers.iter_mut().zip(last_ok) {
+        s.healthy = ok && s.failures * 2 < s.tests && s.height + MAX_HEIGHT_LAG >= max_height;
+    }
+    Ok(servers)
+}
+
+/// Url of the healthy server with the best score, or an empty
+/// string if no server is healthy
+#[c_export]
+pub fn pick_best_server(coin: &CoinDef, connection: &Connection) -> Result<String> {
+    let servers = list_servers(coin, connection)?;
+    let best = servers
+        .into_iter()
+        .filter(|s| s.healthy)
+        .min_by_key(|s| s.latency as u64 * (s.tests + 4 * s.failures) as u64 / s.tests as u64)
+        .and_then(|s| s.url)
+        .unwrap_or_default();
+    Ok(best)
+}
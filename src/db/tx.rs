@@ -1,5 +1,6 @@
 use crate::{
-    data::fb::TransactionInfoExtendedT,
+    data::fb::{ExpiredTx, ExpiredTxT, IdNoteT, TransactionInfoExtendedT},
+    db::api_keys::{check_access, Operation},
     network::Network,
     txdetails::TransactionDetails,
     utils::ContextExt,
@@ -7,7 +8,7 @@ use crate::{
     Hash,
 };
 use anyhow::Result;
-use rusqlite::{params, Connection, Transaction};
+use rusqlite::{params, Connection, OptionalExtension as _, ToSql, Transaction};
 
 use warp_macros::c_export;
 
@@ -35,14 +36,104 @@ pub fn list_new_txids(connection: &Connection) -> Result<Vec<(u32, u32, u32, Has
     Ok(res)
 }
 
-pub fn list_txs(connection: &Connection, account: u32) -> Result<Vec<ExtendedReceivedTx>> {
+/// Transactions without details, the most recent first and the
+/// ones that failed less than `max_attempts` times after the others
+pub fn list_tx_details_queue(
+    connection: &Connection,
+    max_attempts: u32,
+) -> Result<Vec<(u32, u32, u32, Hash)>> {
     let mut s = connection.prepare(
-        "SELECT t.id_tx, t.txid, t.height, t.timestamp, t.value, t.address, c.name, t.memo FROM txs t
-        LEFT JOIN contact_receivers r ON r.address = t.receiver AND r.account = t.account
-        LEFT JOIN contacts c ON c.id_contact = r.contact
-        WHERE t.account = ?1 ORDER BY t.height DESC",
+        "SELECT t.id_tx, t.account, t.timestamp, t.txid FROM txs t
+        LEFT JOIN txdetails d ON t.id_tx = d.id_tx
+        LEFT JOIN tx_details_failures f ON t.id_tx = f.id_tx
+        WHERE d.id_tx IS NULL AND COALESCE(f.attempts, 0) < ?1
+        ORDER BY COALESCE(f.attempts, 0), t.height DESC",
     )?;
-    let rows = s.query_map([account], |r| {
+    let rows = s.query_map([max_attempts], |r| {
+        Ok((
+            r.get::<_, u32>(0)?,
+            r.get::<_, u32>(1)?,
+            r.get::<_, u32>(2)?,
+            r.get::<_, Vec<u8>>(3)?,
+        ))
+    })?;
+    let mut res = vec![];
+    for r in rows {
+        let (id_tx, account, timestamp, txid) = r?;
+        let txid: Hash = txid.try_into().unwrap();
+        res.push((id_tx, account, timestamp, txid));
+    }
+    Ok(res)
+}
+
+pub fn store_tx_details_failure(connection: &Connection, id_tx: u32, error: &str) -> Result<()> {
+    connection.execute(
+        "INSERT INTO tx_details_failures(id_tx, account, height, attempts, error)
+        SELECT id_tx, account, height, 1, ?2 FROM txs WHERE id_tx = ?1
+        ON CONFLICT DO UPDATE SET attempts = attempts + 1, error = excluded.error",
+        params![id_tx, error],
+    )?;
+    Ok(())
+}
+
+pub fn clear_tx_details_failure(connection: &Connection, id_tx: u32) -> Result<()> {
+    connection.execute("DELETE FROM tx_details_failures WHERE id_tx = ?1", [id_tx])?;
+    Ok(())
+}
+
+pub fn list_txs(connection: &Connection, account: u32) -> Result<Vec<ExtendedReceivedTx>> {
+    select_txs(connection, account, "", None)
+}
+
+/// Transactions of the account with a counterparty that has
+/// this receiver (see `address_to_bytes`)
+pub fn list_txs_with_receiver(
+    connection: &Connection,
+    account: u32,
+    receiver: &[u8],
+) -> Result<Vec<ExtendedReceivedTx>> {
+    select_txs(
+        connection,
+        account,
+        "AND t.id_tx IN (SELECT id_tx FROM tx_addresses
+        WHERE account = ?1 AND receiver = ?2)",
+        Some(&receiver),
+    )
+}
+
+/// Transactions of the account with a contact, by any
+/// of its receivers
+pub fn list_txs_with_contact(
+    connection: &Connection,
+    account: u32,
+    contact: u32,
+) -> Result<Vec<ExtendedReceivedTx>> {
+    select_txs(
+        connection,
+        account,
+        "AND t.id_tx IN (SELECT a.id_tx FROM tx_addresses a
+        LEFT JOIN contact_receivers r ON r.address = a.receiver AND r.account = a.account
+        WHERE a.account = ?1 AND COALESCE(a.contact, r.contact) = ?2)",
+        Some(&contact),
+    )
+}
+
+fn select_txs(
+    connection: &Connection,
+    account: u32,
+    filter: &str,
+    arg: Option<&dyn ToSql>,
+) -> Result<Vec<ExtendedReceivedTx>> {
+    let mut s = connection.prepare(&format!(
+        "SELECT t.id_tx, t.txid, t.height, t.timestamp, t.value, t.address, c.name, t.memo,
+        t.transfer FROM txs t
+        LEFT JOIN contact_receivers r ON r.address = t.receiver AND r.account = t.account
+        LEFT JOIN contacts c ON c.id_contact = COALESCE(t.contact, r.contact)
+        WHERE t.account = ?1 {filter} ORDER BY t.height DESC"
+    ))?;
+    let mut args: Vec<&dyn ToSql> = vec![&account];
+    args.extend(arg);
+    let rows = s.query_map(args.as_slice(), |r| {
         Ok((
             r.get::<_, u32>(0)?,
             r.get::<_, Vec<u8>>(1)?,
@@ -52,11 +143,12 @@ pub fn list_txs(connection: &Connection, account: u32) -> Result<Vec<ExtendedRec
             r.get::<_, Option<String>>(5)?,
             r.get::<_, Option<String>>(6)?,
             r.get::<_, Option<String>>(7)?,
+            r.get::<_, Option<u32>>(8)?,
         ))
     })?;
     let mut txs = vec![];
     for r in rows {
-        let (id_tx, txid, height, timestamp, value, address, contact, memo) = r?;
+        let (id_tx, txid, height, timestamp, value, address, contact, memo, transfer) = r?;
         let rtx = ReceivedTx {
             id: id_tx,
             account,
@@ -71,6 +163,7 @@ pub fn list_txs(connection: &Connection, account: u32) -> Result<Vec<ExtendedRec
             address,
             contact,
             memo,
+            transfer,
         };
         txs.push(ertx);
     }
@@ -123,13 +216,21 @@ pub fn get_tx_details_account(
     Ok((account, tx))
 }
 
+pub fn has_tx_details(connection: &Connection, id_tx: u32) -> Result<bool> {
+    let mut s = connection.prepare("SELECT 1 FROM txdetails WHERE id_tx = ?1")?;
+    let exists = s.exists([id_tx])?;
+    Ok(exists)
+}
+
 #[c_export]
 pub fn get_tx_details(
     network: &Network,
     connection: &Connection,
+    session: u32,
     account: u32,
     txid: &[u8],
 ) -> Result<TransactionInfoExtendedT> {
+    check_access(connection, session, &Operation::Read { account })?;
     let tx_bin = connection
         .query_row(
             "SELECT data FROM txdetails WHERE account = ?1 AND txid = ?2",
@@ -142,6 +243,25 @@ pub fn get_tx_details(
     Ok(etx)
 }
 
+/// Memos of the shielded outputs that the account can decrypt
+pub fn list_tx_memos(connection: &Connection, account: u32, txid: &[u8]) -> Result<Vec<Vec<u8>>> {
+    let tx_bin = connection
+        .query_row(
+            "SELECT data FROM txdetails WHERE account = ?1 AND txid = ?2",
+            params![account, txid],
+            |r| r.get::<_, Vec<u8>>(0),
+        )
+        .with_file_line(|| format!("No txdetails {}", hex::encode(txid)))?;
+    let tx: TransactionDetails = bincode::deserialize_from(&*tx_bin)?;
+    let memos = tx
+        .souts
+        .into_iter()
+        .chain(tx.oouts)
+        .filter_map(|o| o.note.map(|n| n.memo.0))
+        .collect();
+    Ok(memos)
+}
+
 pub fn store_tx(connection: &Transaction, tx: &ReceivedTx) -> Result<u32> {
     // Reset value if tx is confirmed
     let mut s_tx = connection.prepare_cached(
@@ -201,6 +321,15 @@ pub fn update_tx_primary_address_memo(
     Ok(())
 }
 
+/// Label the tx with the contact of its counterparty
+pub fn update_tx_contact(connection: &Connection, id_tx: u32, contact: Option<u32>) -> Result<()> {
+    connection.execute(
+        "UPDATE txs SET contact = ?2 WHERE id_tx = ?1",
+        params![id_tx, contact],
+    )?;
+    Ok(())
+}
+
 pub fn store_tx_details(
     connection: &Connection,
     id: u32,
@@ -217,9 +346,202 @@ pub fn store_tx_details(
     Ok(())
 }
 
+/// Index the counterparties of a transaction by receiver.
+/// `contact` is the contact found by the decoding, if any
+pub fn store_tx_addresses(
+    network: &Network,
+    connection: &Connection,
+    account: u32,
+    id_tx: u32,
+    height: u32,
+    addresses: &[String],
+    contact: Option<u32>,
+) -> Result<()> {
+    let mut s = connection.prepare_cached(
+        "INSERT INTO tx_addresses(account, receiver, id_tx, height, address, contact)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6) ON CONFLICT DO NOTHING",
+    )?;
+    for address in addresses.iter() {
+        let Ok(receiver) = address_to_bytes(network, address) else {
+            continue;
+        };
+        s.execute(params![account, receiver, id_tx, height, address, contact])?;
+    }
+    Ok(())
+}
+
 pub fn drop_transparent_data(connection: &Connection, account: u32) -> Result<()> {
     connection.execute("DELETE FROM utxos WHERE account = ?1", [account])?;
     connection.execute("DELETE FROM utxo_spends WHERE account = ?1", [account])?;
+    connection.execute("DELETE FROM script_utxos WHERE account = ?1", [account])?;
+    Ok(())
+}
+
+/// Remember the hash of a block with transparent txs of the wallet
+pub fn store_transparent_block(connection: &Connection, height: u32, hash: &Hash) -> Result<()> {
+    connection.execute(
+        "INSERT INTO transparent_blocks(height, hash)
+        VALUES (?1, ?2) ON CONFLICT DO UPDATE SET hash = excluded.hash",
+        params![height, hash],
+    )?;
+    Ok(())
+}
+
+/// Blocks with transparent txs between `start` and `end` (inclusive)
+pub fn list_transparent_blocks(
+    connection: &Connection,
+    start: u32,
+    end: u32,
+) -> Result<Vec<(u32, Hash)>> {
+    let mut s = connection.prepare(
+        "SELECT height, hash FROM transparent_blocks
+        WHERE height >= ?1 AND height <= ?2 ORDER BY height",
+    )?;
+    let rows = s.query_map([start, end], |r| {
+        Ok((r.get::<_, u32>(0)?, r.get::<_, Vec<u8>>(1)?))
+    })?;
+    let mut blocks = vec![];
+    for r in rows {
+        let (height, hash) = r?;
+        blocks.push((height, hash.try_into().unwrap()));
+    }
+    Ok(blocks)
+}
+
+/// Heights of the UTXOs and their spends that do not have
+/// the hash of their block
+pub fn list_unknown_transparent_blocks(connection: &Connection) -> Result<Vec<u32>> {
+    let mut s = connection.prepare(
+        "WITH h AS (SELECT height FROM utxos UNION SELECT height FROM utxo_spends
+        UNION SELECT height FROM script_utxos)
+        SELECT h.height FROM h LEFT JOIN transparent_blocks b ON h.height = b.height
+        WHERE b.height IS NULL ORDER BY h.height",
+    )?;
+    let rows = s.query_map([], |r| r.get::<_, u32>(0))?;
+    let heights = rows.collect::<Result<Vec<_>, _>>()?;
+    Ok(heights)
+}
+
+/// Drop the transparent data of a block that is no longer
+/// on the chain. The UTXOs it spent are unspent again and
+/// the txs without shielded notes are removed
+pub fn drop_transparent_block(connection: &Connection, height: u32) -> Result<()> {
+    connection.execute(
+        "UPDATE utxos SET spent = NULL WHERE id_utxo IN
+        (SELECT id_utxo FROM utxo_spends WHERE height = ?1)",
+        [height],
+    )?;
+    connection.execute("DELETE FROM utxo_spends WHERE height = ?1", [height])?;
+    connection.execute(
+        "DELETE FROM utxo_spends WHERE id_utxo IN
+        (SELECT id_utxo FROM utxos WHERE height = ?1)",
+        [height],
+    )?;
+    connection.execute("DELETE FROM utxos WHERE height = ?1", [height])?;
+    connection.execute("DELETE FROM script_utxos WHERE height = ?1", [height])?;
+    connection.execute(
+        "UPDATE script_utxos SET spent = NULL WHERE spent = ?1",
+        [height],
+    )?;
+    connection.execute(
+        "DELETE FROM txs WHERE height = ?1
+        AND id_tx NOT IN (SELECT tx FROM notes WHERE tx IS NOT NULL)
+        AND id_tx NOT IN (SELECT id_tx FROM note_spends)",
+        [height],
+    )?;
+    connection.execute("DELETE FROM transparent_blocks WHERE height = ?1", [height])?;
+    Ok(())
+}
+
+/// Last height of the transparent history of the address
+/// saved by an unfinished transparent scan
+pub fn get_transparent_scan_height(
+    connection: &Connection,
+    account: u32,
+    address: &str,
+) -> Result<Option<u32>> {
+    let height = connection
+        .query_row(
+            "SELECT height FROM t_scan_progress WHERE account = ?1 AND address = ?2",
+            params![account, address],
+            |r| r.get::<_, u32>(0),
+        )
+        .optional()?;
+    Ok(height)
+}
+
+pub fn store_transparent_scan_height(
+    connection: &Connection,
+    account: u32,
+    address: &str,
+    height: u32,
+) -> Result<()> {
+    connection.execute(
+        "INSERT INTO t_scan_progress(account, address, height)
+        VALUES (?1, ?2, ?3) ON CONFLICT DO UPDATE
+        SET height = excluded.height",
+        params![account, address, height],
+    )?;
+    Ok(())
+}
+
+pub fn has_transparent_scan_progress(connection: &Connection, account: u32) -> Result<bool> {
+    let exists = connection.query_row(
+        "SELECT EXISTS(SELECT 1 FROM t_scan_progress WHERE account = ?1)",
+        [account],
+        |r| r.get::<_, bool>(0),
+    )?;
+    Ok(exists)
+}
+
+pub fn clear_transparent_scan_progress(connection: &Connection, account: u32) -> Result<()> {
+    connection.execute("DELETE FROM t_scan_progress WHERE account = ?1", [account])?;
+    Ok(())
+}
+
+/// Next address index and current gap saved by an unfinished
+/// scan of the transparent addresses
+pub fn get_transparent_sweep_progress(
+    connection: &Connection,
+    account: u32,
+    external: u32,
+) -> Result<Option<(u32, u32)>> {
+    let progress = connection
+        .query_row(
+            "SELECT addr_index, gap FROM t_sweep_progress
+            WHERE account = ?1 AND external = ?2",
+            params![account, external],
+            |r| Ok((r.get::<_, u32>(0)?, r.get::<_, u32>(1)?)),
+        )
+        .optional()?;
+    Ok(progress)
+}
+
+pub fn store_transparent_sweep_progress(
+    connection: &Connection,
+    account: u32,
+    external: u32,
+    addr_index: u32,
+    gap: u32,
+) -> Result<()> {
+    connection.execute(
+        "INSERT INTO t_sweep_progress(account, external, addr_index, gap)
+        VALUES (?1, ?2, ?3, ?4) ON CONFLICT DO UPDATE
+        SET addr_index = excluded.addr_index, gap = excluded.gap",
+        params![account, external, addr_index, gap],
+    )?;
+    Ok(())
+}
+
+pub fn clear_transparent_sweep_progress(
+    connection: &Connection,
+    account: u32,
+    external: u32,
+) -> Result<()> {
+    connection.execute(
+        "DELETE FROM t_sweep_progress WHERE account = ?1 AND external = ?2",
+        params![account, external],
+    )?;
     Ok(())
 }
 
@@ -260,6 +582,19 @@ pub fn store_block_time(connection: &Connection, height: u32, timestamp: u32) ->
     Ok(())
 }
 
+/// Tag the txs between two accounts of the wallet with the other account.
+/// Both sides have the same txid
+pub fn tag_transfers(connection: &Connection) -> Result<()> {
+    connection.execute(
+        "UPDATE txs SET transfer = (SELECT t.account FROM txs t
+        WHERE t.txid = txs.txid AND t.account <> txs.account)
+        WHERE transfer IS NULL AND EXISTS(SELECT 1 FROM txs t
+        WHERE t.txid = txs.txid AND t.account <> txs.account)",
+        [],
+    )?;
+    Ok(())
+}
+
 pub fn copy_block_times_from_tx(connection: &Connection) -> Result<()> {
     connection.execute(
         "INSERT INTO blck_times(height, timestamp)
@@ -288,3 +623,100 @@ pub fn list_unknown_height_timestamps(connection: &Connection) -> Result<Vec<u32
     let heights = rows.collect::<Result<Vec<_>, _>>()?;
     Ok(heights)
 }
+
+/// Account of a spent note or UTXO
+pub fn get_id_note_account(connection: &Connection, note: &IdNoteT) -> Result<u32> {
+    let sql = match note.pool {
+        0 => "SELECT account FROM utxos WHERE id_utxo = ?1",
+        _ => "SELECT account FROM notes WHERE id_note = ?1",
+    };
+    let account = connection
+        .prepare_cached(sql)?
+        .query_row([note.id], |r| r.get::<_, u32>(0))?;
+    Ok(account)
+}
+
+/// Remember a broadcast transaction until it is mined or expires
+pub fn store_broadcast_tx(
+    connection: &Connection,
+    txid: &Hash,
+    id_notes: &[IdNoteT],
+    height: u32,
+    expiration: u32,
+) -> Result<()> {
+    let mut accounts = vec![];
+    for note in id_notes {
+        let account = get_id_note_account(connection, note)?;
+        if !accounts.contains(&account) {
+            accounts.push(account);
+        }
+    }
+    for account in accounts {
+        connection.execute(
+            "INSERT INTO broadcast_txs(account, txid, height, expiration, mined)
+            VALUES (?1, ?2, ?3, ?4, FALSE) ON CONFLICT DO UPDATE SET
+            height = excluded.height, expiration = excluded.expiration,
+            expired_height = NULL",
+            params![account, txid, height, expiration],
+        )?;
+    }
+    Ok(())
+}
+
+/*
+    Called during sync, before the spends of expired transactions
+    are recovered. Flag the broadcast transactions that were mined
+    and returns the ones that expired at this height.
+*/
+pub fn update_broadcast_txs(connection: &Connection, height: u32) -> Result<Vec<(u32, Hash)>> {
+    connection.execute(
+        "UPDATE broadcast_txs SET mined = TRUE
+        WHERE NOT mined AND EXISTS (SELECT 1 FROM txs t
+        WHERE t.account = broadcast_txs.account AND t.txid = broadcast_txs.txid)",
+        [],
+    )?;
+    let mut s = connection.prepare(
+        "UPDATE broadcast_txs SET expired_height = ?1
+        WHERE NOT mined AND expired_height IS NULL AND expiration < ?1
+        RETURNING account, txid",
+    )?;
+    let rows = s.query_map([height], |r| {
+        Ok((r.get::<_, u32>(0)?, r.get::<_, Vec<u8>>(1)?))
+    })?;
+    let mut expired = vec![];
+    for r in rows {
+        let (account, txid) = r?;
+        expired.push((account, txid.try_into().unwrap()));
+    }
+    Ok(expired)
+}
+
+/// Transactions that expired without being mined.
+/// Their inputs are spendable again
+#[c_export]
+pub fn list_expired_txs(connection: &Connection, account: u32) -> Result<Vec<ExpiredTxT>> {
+    let mut s = connection.prepare(
+        "SELECT id_broadcast, txid, height, expiration, expired_height
+        FROM broadcast_txs WHERE account = ?1 AND expired_height IS NOT NULL
+        ORDER BY expired_height DESC",
+    )?;
+    let rows = s.query_map([account], |r| {
+        Ok(ExpiredTxT {
+            id: r.get(0)?,
+            account,
+            txid: Some(r.get::<_, Vec<u8>>(1)?),
+            height: r.get(2)?,
+            expiration: r.get(3)?,
+            expired_height: r.get(4)?,
+        })
+    })?;
+    let txs = rows.collect::<Result<Vec<_>, _>>()?;
+    Ok(txs)
+}
+
+/// Remove an expired transaction once the user has retried or dismissed it
+#[c_export]
+pub fn dismiss_expired_tx(connection: &Connection, id: u32) -> Result<()> {
+    connection.execute("DELETE FROM broadcast_txs WHERE id_broadcast = ?1", [id])?;
+    Ok(())
+}
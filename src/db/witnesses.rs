@@ -4,7 +4,11 @@
 // use sapling_crypto::Node;
 // use tracing::info;
 
+use anyhow::Result;
+use rusqlite::Connection;
+
 use crate::{
+    db::notes::{list_later_witnesses, store_witness},
     warp::{AuthPath, Hasher, Witness, MERKLE_DEPTH},
     Hash,
 };
@@ -62,7 +66,57 @@ use crate::{
 //     Ok(ws)
 // }
 
+/*
+    Derived witnesses
+
+    The ommers of a witness are the roots of the complete subtrees
+    next to the path of the note. The left ones do not change, and
+    a right one is known once its subtree is full. Therefore the
+    witness at an earlier checkpoint is a later witness without the
+    right ommers whose subtree was not full yet, and that only needs
+    the size of the tree at the checkpoint.
+
+    In the lazy witnesses mode, the sync only keeps the latest witness
+    of the notes and the witnesses at the previous checkpoints are
+    derived when they are needed, for the anchor of a transaction or
+    before a rewind.
+*/
+
+/// Store the witnesses at the checkpoint `height` of the unspent
+/// notes that only have later ones, given the size of the sapling and
+/// orchard trees at `height`.
+/// Returns the number of witnesses derived
+pub fn derive_witnesses(
+    connection: &Connection,
+    height: u32,
+    sapling_size: u32,
+    orchard_size: u32,
+) -> Result<u32> {
+    let mut count = 0;
+    for (orchard, size) in [(false, sapling_size), (true, orchard_size)] {
+        for (account, id_note, witness) in list_later_witnesses(connection, height, orchard)? {
+            store_witness(connection, account, id_note, height, &witness.rewind(size))?;
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
 impl Witness {
+    /// Witness when the tree had `size` leaves
+    pub fn rewind(&self, size: u32) -> Witness {
+        let mut w = self.clone();
+        let p = self.position as u64;
+        for i in 0..MERKLE_DEPTH as usize {
+            // the right sibling at depth i covers the leaves before `end`
+            let end = ((p >> i) + 2) << i;
+            if (p >> i) & 1 == 0 && end > size as u64 {
+                w.ommers.0[i] = None;
+            }
+        }
+        w
+    }
+
     pub fn build_auth_path(&self, edge: &AuthPath, empty_roots: &AuthPath) -> AuthPath {
         let mut path = AuthPath::default();
         let mut p = self.position;
@@ -631,6 +631,8 @@ pub mod fb {
         pub const VT_ADDRESS: flatbuffers::VOffsetT = 16;
         pub const VT_CONTACT: flatbuffers::VOffsetT = 18;
         pub const VT_MEMO: flatbuffers::VOffsetT = 20;
+        pub const VT_TRANSFER: flatbuffers::VOffsetT = 22;
+        pub const VT_ACCOUNT: flatbuffers::VOffsetT = 24;
 
         #[inline]
         pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -648,6 +650,8 @@ pub mod fb {
         ) -> flatbuffers::WIPOffset<TransactionInfo<'bldr>> {
             let mut builder = TransactionInfoBuilder::new(_fbb);
             builder.add_amount(args.amount);
+            builder.add_account(args.account);
+            builder.add_transfer(args.transfer);
             if let Some(x) = args.memo {
                 builder.add_memo(x);
             }
@@ -677,6 +681,8 @@ pub mod fb {
             let address = self.address().map(|x| x.to_string());
             let contact = self.contact().map(|x| x.to_string());
             let memo = self.memo().map(|x| x.to_string());
+            let transfer = self.transfer();
+            let account = self.account();
             TransactionInfoT {
                 id,
                 txid,
@@ -687,6 +693,8 @@ pub mod fb {
                 address,
                 contact,
                 memo,
+                transfer,
+                account,
             }
         }
 
@@ -788,6 +796,28 @@ pub mod fb {
                     .get::<flatbuffers::ForwardsUOffset<&str>>(TransactionInfo::VT_MEMO, None)
             }
         }
+        #[inline]
+        pub fn transfer(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(TransactionInfo::VT_TRANSFER, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn account(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(TransactionInfo::VT_ACCOUNT, Some(0))
+                    .unwrap()
+            }
+        }
     }
 
     impl flatbuffers::Verifiable for TransactionInfo<'_> {
@@ -819,6 +849,8 @@ pub mod fb {
                     false,
                 )?
                 .visit_field::<flatbuffers::ForwardsUOffset<&str>>("memo", Self::VT_MEMO, false)?
+                .visit_field::<u32>("transfer", Self::VT_TRANSFER, false)?
+                .visit_field::<u32>("account", Self::VT_ACCOUNT, false)?
                 .finish();
             Ok(())
         }
@@ -833,6 +865,8 @@ pub mod fb {
         pub address: Option<flatbuffers::WIPOffset<&'a str>>,
         pub contact: Option<flatbuffers::WIPOffset<&'a str>>,
         pub memo: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub transfer: u32,
+        pub account: u32,
     }
     impl<'a> Default for TransactionInfoArgs<'a> {
         #[inline]
@@ -847,6 +881,8 @@ pub mod fb {
                 address: None,
                 contact: None,
                 memo: None,
+                transfer: 0,
+                account: 0,
             }
         }
     }
@@ -905,6 +941,16 @@ pub mod fb {
                 .push_slot_always::<flatbuffers::WIPOffset<_>>(TransactionInfo::VT_MEMO, memo);
         }
         #[inline]
+        pub fn add_transfer(&mut self, transfer: u32) {
+            self.fbb_
+                .push_slot::<u32>(TransactionInfo::VT_TRANSFER, transfer, 0);
+        }
+        #[inline]
+        pub fn add_account(&mut self, account: u32) {
+            self.fbb_
+                .push_slot::<u32>(TransactionInfo::VT_ACCOUNT, account, 0);
+        }
+        #[inline]
         pub fn new(
             _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
         ) -> TransactionInfoBuilder<'a, 'b, A> {
@@ -933,6 +979,8 @@ pub mod fb {
             ds.field("address", &self.address());
             ds.field("contact", &self.contact());
             ds.field("memo", &self.memo());
+            ds.field("transfer", &self.transfer());
+            ds.field("account", &self.account());
             ds.finish()
         }
     }
@@ -948,6 +996,8 @@ pub mod fb {
         pub address: Option<String>,
         pub contact: Option<String>,
         pub memo: Option<String>,
+        pub transfer: u32,
+        pub account: u32,
     }
     impl Default for TransactionInfoT {
         fn default() -> Self {
@@ -961,6 +1011,8 @@ pub mod fb {
                 address: None,
                 contact: None,
                 memo: None,
+                transfer: 0,
+                account: 0,
             }
         }
     }
@@ -978,6 +1030,8 @@ pub mod fb {
             let address = self.address.as_ref().map(|x| _fbb.create_string(x));
             let contact = self.contact.as_ref().map(|x| _fbb.create_string(x));
             let memo = self.memo.as_ref().map(|x| _fbb.create_string(x));
+            let transfer = self.transfer;
+            let account = self.account;
             TransactionInfo::create(
                 _fbb,
                 &TransactionInfoArgs {
@@ -990,6 +1044,8 @@ pub mod fb {
                     address,
                     contact,
                     memo,
+                    transfer,
+                    account,
                 },
             )
         }
@@ -2619,6 +2675,9 @@ pub mod fb {
         pub const VT_VALUE: flatbuffers::VOffsetT = 12;
         pub const VT_ORCHARD: flatbuffers::VOffsetT = 14;
         pub const VT_EXCLUDED: flatbuffers::VOffsetT = 16;
+        pub const VT_COINBASE: flatbuffers::VOffsetT = 18;
+        pub const VT_CHANGE: flatbuffers::VOffsetT = 20;
+        pub const VT_ASSET: flatbuffers::VOffsetT = 22;
 
         #[inline]
         pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -2632,14 +2691,19 @@ pub mod fb {
             A: flatbuffers::Allocator + 'bldr,
         >(
             _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-            args: &'args ShieldedNoteArgs,
+            args: &'args ShieldedNoteArgs<'args>,
         ) -> flatbuffers::WIPOffset<ShieldedNote<'bldr>> {
             let mut builder = ShieldedNoteBuilder::new(_fbb);
             builder.add_value(args.value);
+            if let Some(x) = args.asset {
+                builder.add_asset(x);
+            }
             builder.add_timestamp(args.timestamp);
             builder.add_confirmations(args.confirmations);
             builder.add_height(args.height);
             builder.add_id_note(args.id_note);
+            builder.add_change(args.change);
+            builder.add_coinbase(args.coinbase);
             builder.add_excluded(args.excluded);
             builder.add_orchard(args.orchard);
             builder.finish()
@@ -2653,6 +2717,9 @@ pub mod fb {
             let value = self.value();
             let orchard = self.orchard();
             let excluded = self.excluded();
+            let coinbase = self.coinbase();
+            let change = self.change();
+            let asset = self.asset().map(|x| x.into_iter().collect());
             ShieldedNoteT {
                 id_note,
                 height,
@@ -2661,6 +2728,9 @@ pub mod fb {
                 value,
                 orchard,
                 excluded,
+                coinbase,
+                change,
+                asset,
             }
         }
 
@@ -2741,6 +2811,41 @@ pub mod fb {
                     .unwrap()
             }
         }
+        #[inline]
+        pub fn coinbase(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(ShieldedNote::VT_COINBASE, Some(false))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn change(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(ShieldedNote::VT_CHANGE, Some(false))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn asset(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        ShieldedNote::VT_ASSET,
+                        None,
+                    )
+            }
+        }
     }
 
     impl flatbuffers::Verifiable for ShieldedNote<'_> {
@@ -2758,11 +2863,18 @@ pub mod fb {
                 .visit_field::<u64>("value", Self::VT_VALUE, false)?
                 .visit_field::<bool>("orchard", Self::VT_ORCHARD, false)?
                 .visit_field::<bool>("excluded", Self::VT_EXCLUDED, false)?
+                .visit_field::<bool>("coinbase", Self::VT_COINBASE, false)?
+                .visit_field::<bool>("change", Self::VT_CHANGE, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "asset",
+                    Self::VT_ASSET,
+                    false,
+                )?
                 .finish();
             Ok(())
         }
     }
-    pub struct ShieldedNoteArgs {
+    pub struct ShieldedNoteArgs<'a> {
         pub id_note: u32,
         pub height: u32,
         pub confirmations: u32,
@@ -2770,8 +2882,11 @@ pub mod fb {
         pub value: u64,
         pub orchard: bool,
         pub excluded: bool,
+        pub coinbase: bool,
+        pub change: bool,
+        pub asset: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
     }
-    impl<'a> Default for ShieldedNoteArgs {
+    impl<'a> Default for ShieldedNoteArgs<'a> {
         #[inline]
         fn default() -> Self {
             ShieldedNoteArgs {
@@ -2782,6 +2897,9 @@ pub mod fb {
                 value: 0,
                 orchard: false,
                 excluded: false,
+                coinbase: false,
+                change: false,
+                asset: None,
             }
         }
     }
@@ -2826,6 +2944,21 @@ pub mod fb {
                 .push_slot::<bool>(ShieldedNote::VT_EXCLUDED, excluded, false);
         }
         #[inline]
+        pub fn add_coinbase(&mut self, coinbase: bool) {
+            self.fbb_
+                .push_slot::<bool>(ShieldedNote::VT_COINBASE, coinbase, false);
+        }
+        #[inline]
+        pub fn add_change(&mut self, change: bool) {
+            self.fbb_
+                .push_slot::<bool>(ShieldedNote::VT_CHANGE, change, false);
+        }
+        #[inline]
+        pub fn add_asset(&mut self, asset: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(ShieldedNote::VT_ASSET, asset);
+        }
+        #[inline]
         pub fn new(
             _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
         ) -> ShieldedNoteBuilder<'a, 'b, A> {
@@ -2852,6 +2985,9 @@ pub mod fb {
             ds.field("value", &self.value());
             ds.field("orchard", &self.orchard());
             ds.field("excluded", &self.excluded());
+            ds.field("coinbase", &self.coinbase());
+            ds.field("change", &self.change());
+            ds.field("asset", &self.asset());
             ds.finish()
         }
     }
@@ -2865,6 +3001,9 @@ pub mod fb {
         pub value: u64,
         pub orchard: bool,
         pub excluded: bool,
+        pub coinbase: bool,
+        pub change: bool,
+        pub asset: Option<Vec<u8>>,
     }
     impl Default for ShieldedNoteT {
         fn default() -> Self {
@@ -2876,6 +3015,9 @@ pub mod fb {
                 value: 0,
                 orchard: false,
                 excluded: false,
+                coinbase: false,
+                change: false,
+                asset: None,
             }
         }
     }
@@ -2891,6 +3033,9 @@ pub mod fb {
             let value = self.value;
             let orchard = self.orchard;
             let excluded = self.excluded;
+            let coinbase = self.coinbase;
+            let change = self.change;
+            let asset = self.asset.as_ref().map(|x| _fbb.create_vector(x));
             ShieldedNote::create(
                 _fbb,
                 &ShieldedNoteArgs {
@@ -2901,6 +3046,9 @@ pub mod fb {
                     value,
                     orchard,
                     excluded,
+                    coinbase,
+                    change,
+                    asset,
                 },
             )
         }
@@ -3374,6 +3522,8 @@ pub mod fb {
         pub const VT_TRANSPARENT: flatbuffers::VOffsetT = 6;
         pub const VT_SAPLING: flatbuffers::VOffsetT = 8;
         pub const VT_ORCHARD: flatbuffers::VOffsetT = 10;
+        pub const VT_EXPIRY_HEIGHT: flatbuffers::VOffsetT = 12;
+        pub const VT_EXPIRY_TIME: flatbuffers::VOffsetT = 14;
 
         #[inline]
         pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -3390,6 +3540,8 @@ pub mod fb {
             args: &'args UAReceiversArgs<'args>,
         ) -> flatbuffers::WIPOffset<UAReceivers<'bldr>> {
             let mut builder = UAReceiversBuilder::new(_fbb);
+            builder.add_expiry_time(args.expiry_time);
+            builder.add_expiry_height(args.expiry_height);
             if let Some(x) = args.orchard {
                 builder.add_orchard(x);
             }
@@ -3408,11 +3560,15 @@ pub mod fb {
             let transparent = self.transparent().map(|x| x.to_string());
             let sapling = self.sapling().map(|x| x.to_string());
             let orchard = self.orchard().map(|x| x.to_string());
+            let expiry_height = self.expiry_height();
+            let expiry_time = self.expiry_time();
             UAReceiversT {
                 tex,
                 transparent,
                 sapling,
                 orchard,
+                expiry_height,
+                expiry_time,
             }
         }
 
@@ -3457,6 +3613,28 @@ pub mod fb {
                     .get::<flatbuffers::ForwardsUOffset<&str>>(UAReceivers::VT_ORCHARD, None)
             }
         }
+        #[inline]
+        pub fn expiry_height(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(UAReceivers::VT_EXPIRY_HEIGHT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn expiry_time(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(UAReceivers::VT_EXPIRY_TIME, Some(0))
+                    .unwrap()
+            }
+        }
     }
 
     impl flatbuffers::Verifiable for UAReceivers<'_> {
@@ -3483,6 +3661,8 @@ pub mod fb {
                     Self::VT_ORCHARD,
                     false,
                 )?
+                .visit_field::<u32>("expiry_height", Self::VT_EXPIRY_HEIGHT, false)?
+                .visit_field::<u64>("expiry_time", Self::VT_EXPIRY_TIME, false)?
                 .finish();
             Ok(())
         }
@@ -3492,6 +3672,8 @@ pub mod fb {
         pub transparent: Option<flatbuffers::WIPOffset<&'a str>>,
         pub sapling: Option<flatbuffers::WIPOffset<&'a str>>,
         pub orchard: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub expiry_height: u32,
+        pub expiry_time: u64,
     }
     impl<'a> Default for UAReceiversArgs<'a> {
         #[inline]
@@ -3501,6 +3683,8 @@ pub mod fb {
                 transparent: None,
                 sapling: None,
                 orchard: None,
+                expiry_height: 0,
+                expiry_time: 0,
             }
         }
     }
@@ -3532,6 +3716,16 @@ pub mod fb {
                 .push_slot_always::<flatbuffers::WIPOffset<_>>(UAReceivers::VT_ORCHARD, orchard);
         }
         #[inline]
+        pub fn add_expiry_height(&mut self, expiry_height: u32) {
+            self.fbb_
+                .push_slot::<u32>(UAReceivers::VT_EXPIRY_HEIGHT, expiry_height, 0);
+        }
+        #[inline]
+        pub fn add_expiry_time(&mut self, expiry_time: u64) {
+            self.fbb_
+                .push_slot::<u64>(UAReceivers::VT_EXPIRY_TIME, expiry_time, 0);
+        }
+        #[inline]
         pub fn new(
             _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
         ) -> UAReceiversBuilder<'a, 'b, A> {
@@ -3555,6 +3749,8 @@ pub mod fb {
             ds.field("transparent", &self.transparent());
             ds.field("sapling", &self.sapling());
             ds.field("orchard", &self.orchard());
+            ds.field("expiry_height", &self.expiry_height());
+            ds.field("expiry_time", &self.expiry_time());
             ds.finish()
         }
     }
@@ -3565,6 +3761,8 @@ pub mod fb {
         pub transparent: Option<String>,
         pub sapling: Option<String>,
         pub orchard: Option<String>,
+        pub expiry_height: u32,
+        pub expiry_time: u64,
     }
     impl Default for UAReceiversT {
         fn default() -> Self {
@@ -3573,6 +3771,8 @@ pub mod fb {
                 transparent: None,
                 sapling: None,
                 orchard: None,
+                expiry_height: 0,
+                expiry_time: 0,
             }
         }
     }
@@ -3585,6 +3785,8 @@ pub mod fb {
             let transparent = self.transparent.as_ref().map(|x| _fbb.create_string(x));
             let sapling = self.sapling.as_ref().map(|x| _fbb.create_string(x));
             let orchard = self.orchard.as_ref().map(|x| _fbb.create_string(x));
+            let expiry_height = self.expiry_height;
+            let expiry_time = self.expiry_time;
             UAReceivers::create(
                 _fbb,
                 &UAReceiversArgs {
@@ -3592,6 +3794,8 @@ pub mod fb {
                     transparent,
                     sapling,
                     orchard,
+                    expiry_height,
+                    expiry_time,
                 },
             )
         }
@@ -3619,6 +3823,7 @@ pub mod fb {
         pub const VT_POOLS: flatbuffers::VOffsetT = 8;
         pub const VT_MEMO: flatbuffers::VOffsetT = 10;
         pub const VT_MEMO_BYTES: flatbuffers::VOffsetT = 12;
+        pub const VT_SRC_POOLS: flatbuffers::VOffsetT = 14;
 
         #[inline]
         pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -3645,6 +3850,7 @@ pub mod fb {
             if let Some(x) = args.address {
                 builder.add_address(x);
             }
+            builder.add_src_pools(args.src_pools);
             builder.add_pools(args.pools);
             builder.finish()
         }
@@ -3655,12 +3861,14 @@ pub mod fb {
             let pools = self.pools();
             let memo = self.memo().map(|x| Box::new(x.unpack()));
             let memo_bytes = self.memo_bytes().map(|x| x.into_iter().collect());
+            let src_pools = self.src_pools();
             RecipientT {
                 address,
                 amount,
                 pools,
                 memo,
                 memo_bytes,
+                src_pools,
             }
         }
 
@@ -3711,6 +3919,17 @@ pub mod fb {
                     )
             }
         }
+        #[inline]
+        pub fn src_pools(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(Recipient::VT_SRC_POOLS, Some(0))
+                    .unwrap()
+            }
+        }
     }
 
     impl flatbuffers::Verifiable for Recipient<'_> {
@@ -3738,6 +3957,7 @@ pub mod fb {
                     Self::VT_MEMO_BYTES,
                     false,
                 )?
+                .visit_field::<u8>("src_pools", Self::VT_SRC_POOLS, false)?
                 .finish();
             Ok(())
         }
@@ -3748,6 +3968,7 @@ pub mod fb {
         pub pools: u8,
         pub memo: Option<flatbuffers::WIPOffset<UserMemo<'a>>>,
         pub memo_bytes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub src_pools: u8,
     }
     impl<'a> Default for RecipientArgs<'a> {
         #[inline]
@@ -3758,6 +3979,7 @@ pub mod fb {
                 pools: 0,
                 memo: None,
                 memo_bytes: None,
+                src_pools: 0,
             }
         }
     }
@@ -3796,6 +4018,11 @@ pub mod fb {
             );
         }
         #[inline]
+        pub fn add_src_pools(&mut self, src_pools: u8) {
+            self.fbb_
+                .push_slot::<u8>(Recipient::VT_SRC_POOLS, src_pools, 0);
+        }
+        #[inline]
         pub fn new(
             _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
         ) -> RecipientBuilder<'a, 'b, A> {
@@ -3820,6 +4047,7 @@ pub mod fb {
             ds.field("pools", &self.pools());
             ds.field("memo", &self.memo());
             ds.field("memo_bytes", &self.memo_bytes());
+            ds.field("src_pools", &self.src_pools());
             ds.finish()
         }
     }
@@ -3831,6 +4059,7 @@ pub mod fb {
         pub pools: u8,
         pub memo: Option<Box<UserMemoT>>,
         pub memo_bytes: Option<Vec<u8>>,
+        pub src_pools: u8,
     }
     impl Default for RecipientT {
         fn default() -> Self {
@@ -3840,6 +4069,7 @@ pub mod fb {
                 pools: 0,
                 memo: None,
                 memo_bytes: None,
+                src_pools: 0,
             }
         }
     }
@@ -3853,6 +4083,7 @@ pub mod fb {
             let pools = self.pools;
             let memo = self.memo.as_ref().map(|x| x.pack(_fbb));
             let memo_bytes = self.memo_bytes.as_ref().map(|x| _fbb.create_vector(x));
+            let src_pools = self.src_pools;
             Recipient::create(
                 _fbb,
                 &RecipientArgs {
@@ -3861,6 +4092,7 @@ pub mod fb {
                     pools,
                     memo,
                     memo_bytes,
+                    src_pools,
                 },
             )
         }
@@ -3889,6 +4121,12 @@ pub mod fb {
         pub const VT_USE_CHANGE: flatbuffers::VOffsetT = 10;
         pub const VT_HEIGHT: flatbuffers::VOffsetT = 12;
         pub const VT_EXPIRATION: flatbuffers::VOffsetT = 14;
+        pub const VT_DECOY_OUTPUTS: flatbuffers::VOffsetT = 16;
+        pub const VT_FROM_ADDRESS: flatbuffers::VOffsetT = 18;
+        pub const VT_ALLOW_HIGH_FEE: flatbuffers::VOffsetT = 20;
+        pub const VT_CHANGE_OUTPUTS: flatbuffers::VOffsetT = 22;
+        pub const VT_ALLOW_CHANGE_ADDRESS: flatbuffers::VOffsetT = 24;
+        pub const VT_ALLOW_LOOKALIKE_ADDRESS: flatbuffers::VOffsetT = 26;
 
         #[inline]
         pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -3905,11 +4143,19 @@ pub mod fb {
             args: &'args PaymentRequestArgs<'args>,
         ) -> flatbuffers::WIPOffset<PaymentRequest<'bldr>> {
             let mut builder = PaymentRequestBuilder::new(_fbb);
+            if let Some(x) = args.from_address {
+                builder.add_from_address(x);
+            }
             builder.add_expiration(args.expiration);
             builder.add_height(args.height);
             if let Some(x) = args.recipients {
                 builder.add_recipients(x);
             }
+            builder.add_allow_lookalike_address(args.allow_lookalike_address);
+            builder.add_allow_change_address(args.allow_change_address);
+            builder.add_change_outputs(args.change_outputs);
+            builder.add_allow_high_fee(args.allow_high_fee);
+            builder.add_decoy_outputs(args.decoy_outputs);
             builder.add_use_change(args.use_change);
             builder.add_sender_pay_fees(args.sender_pay_fees);
             builder.add_src_pools(args.src_pools);
@@ -3925,6 +4171,12 @@ pub mod fb {
             let use_change = self.use_change();
             let height = self.height();
             let expiration = self.expiration();
+            let decoy_outputs = self.decoy_outputs();
+            let from_address = self.from_address().map(|x| x.to_string());
+            let allow_high_fee = self.allow_high_fee();
+            let change_outputs = self.change_outputs();
+            let allow_change_address = self.allow_change_address();
+            let allow_lookalike_address = self.allow_lookalike_address();
             PaymentRequestT {
                 recipients,
                 src_pools,
@@ -3932,6 +4184,12 @@ pub mod fb {
                 use_change,
                 height,
                 expiration,
+                decoy_outputs,
+                from_address,
+                allow_high_fee,
+                change_outputs,
+                allow_change_address,
+                allow_lookalike_address,
             }
         }
 
@@ -4003,6 +4261,73 @@ pub mod fb {
                     .unwrap()
             }
         }
+        #[inline]
+        pub fn decoy_outputs(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(PaymentRequest::VT_DECOY_OUTPUTS, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn from_address(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(
+                    PaymentRequest::VT_FROM_ADDRESS,
+                    None,
+                )
+            }
+        }
+        #[inline]
+        pub fn allow_high_fee(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(PaymentRequest::VT_ALLOW_HIGH_FEE, Some(false))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn change_outputs(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(PaymentRequest::VT_CHANGE_OUTPUTS, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn allow_change_address(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(PaymentRequest::VT_ALLOW_CHANGE_ADDRESS, Some(false))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn allow_lookalike_address(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(PaymentRequest::VT_ALLOW_LOOKALIKE_ADDRESS, Some(false))
+                    .unwrap()
+            }
+        }
     }
 
     impl flatbuffers::Verifiable for PaymentRequest<'_> {
@@ -4021,6 +4346,20 @@ pub mod fb {
                 .visit_field::<bool>("use_change", Self::VT_USE_CHANGE, false)?
                 .visit_field::<u32>("height", Self::VT_HEIGHT, false)?
                 .visit_field::<u32>("expiration", Self::VT_EXPIRATION, false)?
+                .visit_field::<u8>("decoy_outputs", Self::VT_DECOY_OUTPUTS, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "from_address",
+                    Self::VT_FROM_ADDRESS,
+                    false,
+                )?
+                .visit_field::<bool>("allow_high_fee", Self::VT_ALLOW_HIGH_FEE, false)?
+                .visit_field::<u8>("change_outputs", Self::VT_CHANGE_OUTPUTS, false)?
+                .visit_field::<bool>("allow_change_address", Self::VT_ALLOW_CHANGE_ADDRESS, false)?
+                .visit_field::<bool>(
+                    "allow_lookalike_address",
+                    Self::VT_ALLOW_LOOKALIKE_ADDRESS,
+                    false,
+                )?
                 .finish();
             Ok(())
         }
@@ -4036,6 +4375,12 @@ pub mod fb {
         pub use_change: bool,
         pub height: u32,
         pub expiration: u32,
+        pub decoy_outputs: u8,
+        pub from_address: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub allow_high_fee: bool,
+        pub change_outputs: u8,
+        pub allow_change_address: bool,
+        pub allow_lookalike_address: bool,
     }
     impl<'a> Default for PaymentRequestArgs<'a> {
         #[inline]
@@ -4047,6 +4392,12 @@ pub mod fb {
                 use_change: false,
                 height: 0,
                 expiration: 0,
+                decoy_outputs: 0,
+                from_address: None,
+                allow_high_fee: false,
+                change_outputs: 0,
+                allow_change_address: false,
+                allow_lookalike_address: false,
             }
         }
     }
@@ -4094,6 +4445,44 @@ pub mod fb {
                 .push_slot::<u32>(PaymentRequest::VT_EXPIRATION, expiration, 0);
         }
         #[inline]
+        pub fn add_decoy_outputs(&mut self, decoy_outputs: u8) {
+            self.fbb_
+                .push_slot::<u8>(PaymentRequest::VT_DECOY_OUTPUTS, decoy_outputs, 0);
+        }
+        #[inline]
+        pub fn add_from_address(&mut self, from_address: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                PaymentRequest::VT_FROM_ADDRESS,
+                from_address,
+            );
+        }
+        #[inline]
+        pub fn add_allow_high_fee(&mut self, allow_high_fee: bool) {
+            self.fbb_
+                .push_slot::<bool>(PaymentRequest::VT_ALLOW_HIGH_FEE, allow_high_fee, false);
+        }
+        #[inline]
+        pub fn add_change_outputs(&mut self, change_outputs: u8) {
+            self.fbb_
+                .push_slot::<u8>(PaymentRequest::VT_CHANGE_OUTPUTS, change_outputs, 0);
+        }
+        #[inline]
+        pub fn add_allow_change_address(&mut self, allow_change_address: bool) {
+            self.fbb_.push_slot::<bool>(
+                PaymentRequest::VT_ALLOW_CHANGE_ADDRESS,
+                allow_change_address,
+                false,
+            );
+        }
+        #[inline]
+        pub fn add_allow_lookalike_address(&mut self, allow_lookalike_address: bool) {
+            self.fbb_.push_slot::<bool>(
+                PaymentRequest::VT_ALLOW_LOOKALIKE_ADDRESS,
+                allow_lookalike_address,
+                false,
+            );
+        }
+        #[inline]
         pub fn new(
             _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
         ) -> PaymentRequestBuilder<'a, 'b, A> {
@@ -4119,6 +4508,12 @@ pub mod fb {
             ds.field("use_change", &self.use_change());
             ds.field("height", &self.height());
             ds.field("expiration", &self.expiration());
+            ds.field("decoy_outputs", &self.decoy_outputs());
+            ds.field("from_address", &self.from_address());
+            ds.field("allow_high_fee", &self.allow_high_fee());
+            ds.field("change_outputs", &self.change_outputs());
+            ds.field("allow_change_address", &self.allow_change_address());
+            ds.field("allow_lookalike_address", &self.allow_lookalike_address());
             ds.finish()
         }
     }
@@ -4131,6 +4526,12 @@ pub mod fb {
         pub use_change: bool,
         pub height: u32,
         pub expiration: u32,
+        pub decoy_outputs: u8,
+        pub from_address: Option<String>,
+        pub allow_high_fee: bool,
+        pub change_outputs: u8,
+        pub allow_change_address: bool,
+        pub allow_lookalike_address: bool,
     }
     impl Default for PaymentRequestT {
         fn default() -> Self {
@@ -4141,6 +4542,12 @@ pub mod fb {
                 use_change: false,
                 height: 0,
                 expiration: 0,
+                decoy_outputs: 0,
+                from_address: None,
+                allow_high_fee: false,
+                change_outputs: 0,
+                allow_change_address: false,
+                allow_lookalike_address: false,
             }
         }
     }
@@ -4158,6 +4565,12 @@ pub mod fb {
             let use_change = self.use_change;
             let height = self.height;
             let expiration = self.expiration;
+            let decoy_outputs = self.decoy_outputs;
+            let from_address = self.from_address.as_ref().map(|x| _fbb.create_string(x));
+            let allow_high_fee = self.allow_high_fee;
+            let change_outputs = self.change_outputs;
+            let allow_change_address = self.allow_change_address;
+            let allow_lookalike_address = self.allow_lookalike_address;
             PaymentRequest::create(
                 _fbb,
                 &PaymentRequestArgs {
@@ -4167,6 +4580,12 @@ pub mod fb {
                     use_change,
                     height,
                     expiration,
+                    decoy_outputs,
+                    from_address,
+                    allow_high_fee,
+                    change_outputs,
+                    allow_change_address,
+                    allow_lookalike_address,
                 },
             )
         }
@@ -4196,6 +4615,10 @@ pub mod fb {
         pub const VT_ICON: flatbuffers::VOffsetT = 12;
         pub const VT_BALANCE: flatbuffers::VOffsetT = 14;
         pub const VT_HIDDEN: flatbuffers::VOffsetT = 16;
+        pub const VT_COLOR: flatbuffers::VOffsetT = 18;
+        pub const VT_EMOJI: flatbuffers::VOffsetT = 20;
+        pub const VT_NOTES: flatbuffers::VOffsetT = 22;
+        pub const VT_GROUP: flatbuffers::VOffsetT = 24;
 
         #[inline]
         pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -4213,6 +4636,14 @@ pub mod fb {
         ) -> flatbuffers::WIPOffset<AccountName<'bldr>> {
             let mut builder = AccountNameBuilder::new(_fbb);
             builder.add_balance(args.balance);
+            builder.add_group(args.group);
+            if let Some(x) = args.notes {
+                builder.add_notes(x);
+            }
+            if let Some(x) = args.emoji {
+                builder.add_emoji(x);
+            }
+            builder.add_color(args.color);
             if let Some(x) = args.icon {
                 builder.add_icon(x);
             }
@@ -4234,6 +4665,10 @@ pub mod fb {
             let icon = self.icon().map(|x| x.into_iter().collect());
             let balance = self.balance();
             let hidden = self.hidden();
+            let color = self.color();
+            let emoji = self.emoji().map(|x| x.to_string());
+            let notes = self.notes().map(|x| x.to_string());
+            let group = self.group();
             AccountNameT {
                 coin,
                 id,
@@ -4242,6 +4677,10 @@ pub mod fb {
                 icon,
                 balance,
                 hidden,
+                color,
+                emoji,
+                notes,
+                group,
             }
         }
 
@@ -4315,27 +4754,73 @@ pub mod fb {
                     .unwrap()
             }
         }
-    }
-
-    impl flatbuffers::Verifiable for AccountName<'_> {
         #[inline]
-        fn run_verifier(
-            v: &mut flatbuffers::Verifier,
-            pos: usize,
-        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
-            use self::flatbuffers::Verifiable;
-            v.visit_table(pos)?
-                .visit_field::<u8>("coin", Self::VT_COIN, false)?
-                .visit_field::<u32>("id", Self::VT_ID, false)?
-                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
-                .visit_field::<u32>("birth", Self::VT_BIRTH, false)?
-                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+        pub fn color(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(AccountName::VT_COLOR, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn emoji(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(AccountName::VT_EMOJI, None)
+            }
+        }
+        #[inline]
+        pub fn notes(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(AccountName::VT_NOTES, None)
+            }
+        }
+        #[inline]
+        pub fn group(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(AccountName::VT_GROUP, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for AccountName<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u8>("coin", Self::VT_COIN, false)?
+                .visit_field::<u32>("id", Self::VT_ID, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+                .visit_field::<u32>("birth", Self::VT_BIRTH, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
                     "icon",
                     Self::VT_ICON,
                     false,
                 )?
                 .visit_field::<u64>("balance", Self::VT_BALANCE, false)?
                 .visit_field::<bool>("hidden", Self::VT_HIDDEN, false)?
+                .visit_field::<u32>("color", Self::VT_COLOR, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("emoji", Self::VT_EMOJI, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("notes", Self::VT_NOTES, false)?
+                .visit_field::<u32>("group", Self::VT_GROUP, false)?
                 .finish();
             Ok(())
         }
@@ -4348,6 +4833,10 @@ pub mod fb {
         pub icon: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
         pub balance: u64,
         pub hidden: bool,
+        pub color: u32,
+        pub emoji: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub notes: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub group: u32,
     }
     impl<'a> Default for AccountNameArgs<'a> {
         #[inline]
@@ -4360,6 +4849,10 @@ pub mod fb {
                 icon: None,
                 balance: 0,
                 hidden: false,
+                color: 0,
+                emoji: None,
+                notes: None,
+                group: 0,
             }
         }
     }
@@ -4402,6 +4895,24 @@ pub mod fb {
                 .push_slot::<bool>(AccountName::VT_HIDDEN, hidden, false);
         }
         #[inline]
+        pub fn add_color(&mut self, color: u32) {
+            self.fbb_.push_slot::<u32>(AccountName::VT_COLOR, color, 0);
+        }
+        #[inline]
+        pub fn add_emoji(&mut self, emoji: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(AccountName::VT_EMOJI, emoji);
+        }
+        #[inline]
+        pub fn add_notes(&mut self, notes: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(AccountName::VT_NOTES, notes);
+        }
+        #[inline]
+        pub fn add_group(&mut self, group: u32) {
+            self.fbb_.push_slot::<u32>(AccountName::VT_GROUP, group, 0);
+        }
+        #[inline]
         pub fn new(
             _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
         ) -> AccountNameBuilder<'a, 'b, A> {
@@ -4428,6 +4939,10 @@ pub mod fb {
             ds.field("icon", &self.icon());
             ds.field("balance", &self.balance());
             ds.field("hidden", &self.hidden());
+            ds.field("color", &self.color());
+            ds.field("emoji", &self.emoji());
+            ds.field("notes", &self.notes());
+            ds.field("group", &self.group());
             ds.finish()
         }
     }
@@ -4441,6 +4956,10 @@ pub mod fb {
         pub icon: Option<Vec<u8>>,
         pub balance: u64,
         pub hidden: bool,
+        pub color: u32,
+        pub emoji: Option<String>,
+        pub notes: Option<String>,
+        pub group: u32,
     }
     impl Default for AccountNameT {
         fn default() -> Self {
@@ -4452,6 +4971,10 @@ pub mod fb {
                 icon: None,
                 balance: 0,
                 hidden: false,
+                color: 0,
+                emoji: None,
+                notes: None,
+                group: 0,
             }
         }
     }
@@ -4467,6 +4990,10 @@ pub mod fb {
             let icon = self.icon.as_ref().map(|x| _fbb.create_vector(x));
             let balance = self.balance;
             let hidden = self.hidden;
+            let color = self.color;
+            let emoji = self.emoji.as_ref().map(|x| _fbb.create_string(x));
+            let notes = self.notes.as_ref().map(|x| _fbb.create_string(x));
+            let group = self.group;
             AccountName::create(
                 _fbb,
                 &AccountNameArgs {
@@ -4477,6 +5004,10 @@ pub mod fb {
                     icon,
                     balance,
                     hidden,
+                    color,
+                    emoji,
+                    notes,
+                    group,
                 },
             )
         }
@@ -4911,6 +5442,7 @@ pub mod fb {
         pub const VT_ADDRESS: flatbuffers::VOffsetT = 4;
         pub const VT_AMOUNT: flatbuffers::VOffsetT = 6;
         pub const VT_CHANGE: flatbuffers::VOffsetT = 8;
+        pub const VT_MEMO_ONLY: flatbuffers::VOffsetT = 10;
 
         #[inline]
         pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -4931,6 +5463,7 @@ pub mod fb {
             if let Some(x) = args.address {
                 builder.add_address(x);
             }
+            builder.add_memo_only(args.memo_only);
             builder.add_change(args.change);
             builder.finish()
         }
@@ -4939,10 +5472,12 @@ pub mod fb {
             let address = self.address().map(|x| x.to_string());
             let amount = self.amount();
             let change = self.change();
+            let memo_only = self.memo_only();
             TransactionRecipientT {
                 address,
                 amount,
                 change,
+                memo_only,
             }
         }
 
@@ -4980,6 +5515,17 @@ pub mod fb {
                     .unwrap()
             }
         }
+        #[inline]
+        pub fn memo_only(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(TransactionRecipient::VT_MEMO_ONLY, Some(false))
+                    .unwrap()
+            }
+        }
     }
 
     impl flatbuffers::Verifiable for TransactionRecipient<'_> {
@@ -4997,6 +5543,7 @@ pub mod fb {
                 )?
                 .visit_field::<u64>("amount", Self::VT_AMOUNT, false)?
                 .visit_field::<bool>("change", Self::VT_CHANGE, false)?
+                .visit_field::<bool>("memo_only", Self::VT_MEMO_ONLY, false)?
                 .finish();
             Ok(())
         }
@@ -5005,6 +5552,7 @@ pub mod fb {
         pub address: Option<flatbuffers::WIPOffset<&'a str>>,
         pub amount: u64,
         pub change: bool,
+        pub memo_only: bool,
     }
     impl<'a> Default for TransactionRecipientArgs<'a> {
         #[inline]
@@ -5013,6 +5561,7 @@ pub mod fb {
                 address: None,
                 amount: 0,
                 change: false,
+                memo_only: false,
             }
         }
     }
@@ -5040,6 +5589,11 @@ pub mod fb {
                 .push_slot::<bool>(TransactionRecipient::VT_CHANGE, change, false);
         }
         #[inline]
+        pub fn add_memo_only(&mut self, memo_only: bool) {
+            self.fbb_
+                .push_slot::<bool>(TransactionRecipient::VT_MEMO_ONLY, memo_only, false);
+        }
+        #[inline]
         pub fn new(
             _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
         ) -> TransactionRecipientBuilder<'a, 'b, A> {
@@ -5062,6 +5616,7 @@ pub mod fb {
             ds.field("address", &self.address());
             ds.field("amount", &self.amount());
             ds.field("change", &self.change());
+            ds.field("memo_only", &self.memo_only());
             ds.finish()
         }
     }
@@ -5071,6 +5626,7 @@ pub mod fb {
         pub address: Option<String>,
         pub amount: u64,
         pub change: bool,
+        pub memo_only: bool,
     }
     impl Default for TransactionRecipientT {
         fn default() -> Self {
@@ -5078,6 +5634,7 @@ pub mod fb {
                 address: None,
                 amount: 0,
                 change: false,
+                memo_only: false,
             }
         }
     }
@@ -5089,12 +5646,14 @@ pub mod fb {
             let address = self.address.as_ref().map(|x| _fbb.create_string(x));
             let amount = self.amount;
             let change = self.change;
+            let memo_only = self.memo_only;
             TransactionRecipient::create(
                 _fbb,
                 &TransactionRecipientArgs {
                     address,
                     amount,
                     change,
+                    memo_only,
                 },
             )
         }
@@ -5128,6 +5687,13 @@ pub mod fb {
         pub const VT_NUM_OUTPUTS: flatbuffers::VOffsetT = 20;
         pub const VT_DATA: flatbuffers::VOffsetT = 22;
         pub const VT_MESSAGE: flatbuffers::VOffsetT = 24;
+        pub const VT_DECOY_FEE: flatbuffers::VOffsetT = 26;
+        pub const VT_ORCHARD_ACTIONS: flatbuffers::VOffsetT = 28;
+        pub const VT_TX_SIZE: flatbuffers::VOffsetT = 30;
+        pub const VT_REQUEST_STATUS: flatbuffers::VOffsetT = 32;
+        pub const VT_REQUESTER: flatbuffers::VOffsetT = 34;
+        pub const VT_REQUESTED_HEIGHT: flatbuffers::VOffsetT = 36;
+        pub const VT_SNAP_POLICY: flatbuffers::VOffsetT = 38;
 
         #[inline]
         pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -5144,10 +5710,17 @@ pub mod fb {
             args: &'args TransactionSummaryArgs<'args>,
         ) -> flatbuffers::WIPOffset<TransactionSummary<'bldr>> {
             let mut builder = TransactionSummaryBuilder::new(_fbb);
+            builder.add_decoy_fee(args.decoy_fee);
             builder.add_fee(args.fee);
             builder.add_orchard_net(args.orchard_net);
             builder.add_sapling_net(args.sapling_net);
             builder.add_transparent_ins(args.transparent_ins);
+            builder.add_requested_height(args.requested_height);
+            if let Some(x) = args.requester {
+                builder.add_requester(x);
+            }
+            builder.add_tx_size(args.tx_size);
+            builder.add_orchard_actions(args.orchard_actions);
             if let Some(x) = args.message {
                 builder.add_message(x);
             }
@@ -5164,6 +5737,8 @@ pub mod fb {
                 builder.add_recipients(x);
             }
             builder.add_height(args.height);
+            builder.add_snap_policy(args.snap_policy);
+            builder.add_request_status(args.request_status);
             builder.add_privacy_level(args.privacy_level);
             builder.finish()
         }
@@ -5182,6 +5757,13 @@ pub mod fb {
             let num_outputs = self.num_outputs().map(|x| x.into_iter().collect());
             let data = self.data().map(|x| x.into_iter().collect());
             let message = self.message().map(|x| x.to_string());
+            let decoy_fee = self.decoy_fee();
+            let orchard_actions = self.orchard_actions();
+            let tx_size = self.tx_size();
+            let request_status = self.request_status();
+            let requester = self.requester().map(|x| x.to_string());
+            let requested_height = self.requested_height();
+            let snap_policy = self.snap_policy();
             TransactionSummaryT {
                 height,
                 recipients,
@@ -5194,6 +5776,13 @@ pub mod fb {
                 num_outputs,
                 data,
                 message,
+                decoy_fee,
+                orchard_actions,
+                tx_size,
+                request_status,
+                requester,
+                requested_height,
+                snap_policy,
             }
         }
 
@@ -5326,6 +5915,84 @@ pub mod fb {
                     .get::<flatbuffers::ForwardsUOffset<&str>>(TransactionSummary::VT_MESSAGE, None)
             }
         }
+        #[inline]
+        pub fn decoy_fee(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(TransactionSummary::VT_DECOY_FEE, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn orchard_actions(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(TransactionSummary::VT_ORCHARD_ACTIONS, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn tx_size(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(TransactionSummary::VT_TX_SIZE, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn request_status(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(TransactionSummary::VT_REQUEST_STATUS, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn requester(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(
+                    TransactionSummary::VT_REQUESTER,
+                    None,
+                )
+            }
+        }
+        #[inline]
+        pub fn requested_height(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(TransactionSummary::VT_REQUESTED_HEIGHT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn snap_policy(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(TransactionSummary::VT_SNAP_POLICY, Some(0))
+                    .unwrap()
+            }
+        }
     }
 
     impl flatbuffers::Verifiable for TransactionSummary<'_> {
@@ -5365,6 +6032,17 @@ pub mod fb {
                     Self::VT_MESSAGE,
                     false,
                 )?
+                .visit_field::<u64>("decoy_fee", Self::VT_DECOY_FEE, false)?
+                .visit_field::<u32>("orchard_actions", Self::VT_ORCHARD_ACTIONS, false)?
+                .visit_field::<u32>("tx_size", Self::VT_TX_SIZE, false)?
+                .visit_field::<u8>("request_status", Self::VT_REQUEST_STATUS, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "requester",
+                    Self::VT_REQUESTER,
+                    false,
+                )?
+                .visit_field::<u32>("requested_height", Self::VT_REQUESTED_HEIGHT, false)?
+                .visit_field::<u8>("snap_policy", Self::VT_SNAP_POLICY, false)?
                 .finish();
             Ok(())
         }
@@ -5385,6 +6063,13 @@ pub mod fb {
         pub num_outputs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
         pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
         pub message: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub decoy_fee: u64,
+        pub orchard_actions: u32,
+        pub tx_size: u32,
+        pub request_status: u8,
+        pub requester: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub requested_height: u32,
+        pub snap_policy: u8,
     }
     impl<'a> Default for TransactionSummaryArgs<'a> {
         #[inline]
@@ -5401,6 +6086,13 @@ pub mod fb {
                 num_outputs: None,
                 data: None,
                 message: None,
+                decoy_fee: 0,
+                orchard_actions: 0,
+                tx_size: 0,
+                request_status: 0,
+                requester: None,
+                requested_height: 0,
+                snap_policy: 0,
             }
         }
     }
@@ -5485,6 +6177,46 @@ pub mod fb {
             );
         }
         #[inline]
+        pub fn add_decoy_fee(&mut self, decoy_fee: u64) {
+            self.fbb_
+                .push_slot::<u64>(TransactionSummary::VT_DECOY_FEE, decoy_fee, 0);
+        }
+        #[inline]
+        pub fn add_orchard_actions(&mut self, orchard_actions: u32) {
+            self.fbb_
+                .push_slot::<u32>(TransactionSummary::VT_ORCHARD_ACTIONS, orchard_actions, 0);
+        }
+        #[inline]
+        pub fn add_tx_size(&mut self, tx_size: u32) {
+            self.fbb_
+                .push_slot::<u32>(TransactionSummary::VT_TX_SIZE, tx_size, 0);
+        }
+        #[inline]
+        pub fn add_request_status(&mut self, request_status: u8) {
+            self.fbb_
+                .push_slot::<u8>(TransactionSummary::VT_REQUEST_STATUS, request_status, 0);
+        }
+        #[inline]
+        pub fn add_requester(&mut self, requester: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                TransactionSummary::VT_REQUESTER,
+                requester,
+            );
+        }
+        #[inline]
+        pub fn add_requested_height(&mut self, requested_height: u32) {
+            self.fbb_.push_slot::<u32>(
+                TransactionSummary::VT_REQUESTED_HEIGHT,
+                requested_height,
+                0,
+            );
+        }
+        #[inline]
+        pub fn add_snap_policy(&mut self, snap_policy: u8) {
+            self.fbb_
+                .push_slot::<u8>(TransactionSummary::VT_SNAP_POLICY, snap_policy, 0);
+        }
+        #[inline]
         pub fn new(
             _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
         ) -> TransactionSummaryBuilder<'a, 'b, A> {
@@ -5515,6 +6247,13 @@ pub mod fb {
             ds.field("num_outputs", &self.num_outputs());
             ds.field("data", &self.data());
             ds.field("message", &self.message());
+            ds.field("decoy_fee", &self.decoy_fee());
+            ds.field("orchard_actions", &self.orchard_actions());
+            ds.field("tx_size", &self.tx_size());
+            ds.field("request_status", &self.request_status());
+            ds.field("requester", &self.requester());
+            ds.field("requested_height", &self.requested_height());
+            ds.field("snap_policy", &self.snap_policy());
             ds.finish()
         }
     }
@@ -5532,6 +6271,13 @@ pub mod fb {
         pub num_outputs: Option<Vec<u8>>,
         pub data: Option<Vec<u8>>,
         pub message: Option<String>,
+        pub decoy_fee: u64,
+        pub orchard_actions: u32,
+        pub tx_size: u32,
+        pub request_status: u8,
+        pub requester: Option<String>,
+        pub requested_height: u32,
+        pub snap_policy: u8,
     }
     impl Default for TransactionSummaryT {
         fn default() -> Self {
@@ -5547,6 +6293,13 @@ pub mod fb {
                 num_outputs: None,
                 data: None,
                 message: None,
+                decoy_fee: 0,
+                orchard_actions: 0,
+                tx_size: 0,
+                request_status: 0,
+                requester: None,
+                requested_height: 0,
+                snap_policy: 0,
             }
         }
     }
@@ -5569,6 +6322,13 @@ pub mod fb {
             let num_outputs = self.num_outputs.as_ref().map(|x| _fbb.create_vector(x));
             let data = self.data.as_ref().map(|x| _fbb.create_vector(x));
             let message = self.message.as_ref().map(|x| _fbb.create_string(x));
+            let decoy_fee = self.decoy_fee;
+            let orchard_actions = self.orchard_actions;
+            let tx_size = self.tx_size;
+            let request_status = self.request_status;
+            let requester = self.requester.as_ref().map(|x| _fbb.create_string(x));
+            let requested_height = self.requested_height;
+            let snap_policy = self.snap_policy;
             TransactionSummary::create(
                 _fbb,
                 &TransactionSummaryArgs {
@@ -5583,6 +6343,13 @@ pub mod fb {
                     num_outputs,
                     data,
                     message,
+                    decoy_fee,
+                    orchard_actions,
+                    tx_size,
+                    request_status,
+                    requester,
+                    requested_height,
+                    snap_policy,
                 },
             )
         }
@@ -5790,6 +6557,8 @@ pub mod fb {
         pub const VT_TRANSPARENT: flatbuffers::VOffsetT = 4;
         pub const VT_SAPLING: flatbuffers::VOffsetT = 6;
         pub const VT_ORCHARD: flatbuffers::VOffsetT = 8;
+        pub const VT_WATCH_ONLY: flatbuffers::VOffsetT = 10;
+        pub const VT_ASSETS: flatbuffers::VOffsetT = 12;
 
         #[inline]
         pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -5803,12 +6572,16 @@ pub mod fb {
             A: flatbuffers::Allocator + 'bldr,
         >(
             _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-            args: &'args BalanceArgs,
+            args: &'args BalanceArgs<'args>,
         ) -> flatbuffers::WIPOffset<Balance<'bldr>> {
             let mut builder = BalanceBuilder::new(_fbb);
+            builder.add_watch_only(args.watch_only);
             builder.add_orchard(args.orchard);
             builder.add_sapling(args.sapling);
             builder.add_transparent(args.transparent);
+            if let Some(x) = args.assets {
+                builder.add_assets(x);
+            }
             builder.finish()
         }
 
@@ -5816,10 +6589,16 @@ pub mod fb {
             let transparent = self.transparent();
             let sapling = self.sapling();
             let orchard = self.orchard();
+            let watch_only = self.watch_only();
+            let assets = self
+                .assets()
+                .map(|x| x.iter().map(|t| t.unpack()).collect());
             BalanceT {
                 transparent,
                 sapling,
                 orchard,
+                watch_only,
+                assets,
             }
         }
 
@@ -5848,6 +6627,31 @@ pub mod fb {
             // which contains a valid value in this slot
             unsafe { self._tab.get::<u64>(Balance::VT_ORCHARD, Some(0)).unwrap() }
         }
+        #[inline]
+        pub fn watch_only(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(Balance::VT_WATCH_ONLY, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn assets(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AssetBalance<'a>>>>
+        {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AssetBalance>>,
+                >>(Balance::VT_ASSETS, None)
+            }
+        }
     }
 
     impl flatbuffers::Verifiable for Balance<'_> {
@@ -5861,22 +6665,34 @@ pub mod fb {
                 .visit_field::<u64>("transparent", Self::VT_TRANSPARENT, false)?
                 .visit_field::<u64>("sapling", Self::VT_SAPLING, false)?
                 .visit_field::<u64>("orchard", Self::VT_ORCHARD, false)?
+                .visit_field::<u64>("watch_only", Self::VT_WATCH_ONLY, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<AssetBalance>>,
+                >>("assets", Self::VT_ASSETS, false)?
                 .finish();
             Ok(())
         }
     }
-    pub struct BalanceArgs {
+    pub struct BalanceArgs<'a> {
         pub transparent: u64,
         pub sapling: u64,
         pub orchard: u64,
+        pub watch_only: u64,
+        pub assets: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AssetBalance<'a>>>,
+            >,
+        >,
     }
-    impl<'a> Default for BalanceArgs {
+    impl<'a> Default for BalanceArgs<'a> {
         #[inline]
         fn default() -> Self {
             BalanceArgs {
                 transparent: 0,
                 sapling: 0,
                 orchard: 0,
+                watch_only: 0,
+                assets: None,
             }
         }
     }
@@ -5900,6 +6716,21 @@ pub mod fb {
             self.fbb_.push_slot::<u64>(Balance::VT_ORCHARD, orchard, 0);
         }
         #[inline]
+        pub fn add_watch_only(&mut self, watch_only: u64) {
+            self.fbb_
+                .push_slot::<u64>(Balance::VT_WATCH_ONLY, watch_only, 0);
+        }
+        #[inline]
+        pub fn add_assets(
+            &mut self,
+            assets: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<AssetBalance<'b>>>,
+            >,
+        ) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(Balance::VT_ASSETS, assets);
+        }
+        #[inline]
         pub fn new(
             _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
         ) -> BalanceBuilder<'a, 'b, A> {
@@ -5922,6 +6753,8 @@ pub mod fb {
             ds.field("transparent", &self.transparent());
             ds.field("sapling", &self.sapling());
             ds.field("orchard", &self.orchard());
+            ds.field("watch_only", &self.watch_only());
+            ds.field("assets", &self.assets());
             ds.finish()
         }
     }
@@ -5931,6 +6764,8 @@ pub mod fb {
         pub transparent: u64,
         pub sapling: u64,
         pub orchard: u64,
+        pub watch_only: u64,
+        pub assets: Option<Vec<AssetBalanceT>>,
     }
     impl Default for BalanceT {
         fn default() -> Self {
@@ -5938,6 +6773,8 @@ pub mod fb {
                 transparent: 0,
                 sapling: 0,
                 orchard: 0,
+                watch_only: 0,
+                assets: None,
             }
         }
     }
@@ -5949,25 +6786,32 @@ pub mod fb {
             let transparent = self.transparent;
             let sapling = self.sapling;
             let orchard = self.orchard;
+            let watch_only = self.watch_only;
+            let assets = self.assets.as_ref().map(|x| {
+                let w: Vec<_> = x.iter().map(|t| t.pack(_fbb)).collect();
+                _fbb.create_vector(&w)
+            });
             Balance::create(
                 _fbb,
                 &BalanceArgs {
                     transparent,
                     sapling,
                     orchard,
+                    watch_only,
+                    assets,
                 },
             )
         }
     }
-    pub enum PacketOffset {}
+    pub enum AssetBalanceOffset {}
     #[derive(Copy, Clone, PartialEq)]
 
-    pub struct Packet<'a> {
+    pub struct AssetBalance<'a> {
         pub _tab: flatbuffers::Table<'a>,
     }
 
-    impl<'a> flatbuffers::Follow<'a> for Packet<'a> {
-        type Inner = Packet<'a>;
+    impl<'a> flatbuffers::Follow<'a> for AssetBalance<'a> {
+        type Inner = AssetBalance<'a>;
         #[inline]
         unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
             Self {
@@ -5976,13 +6820,13 @@ pub mod fb {
         }
     }
 
-    impl<'a> Packet<'a> {
-        pub const VT_LEN: flatbuffers::VOffsetT = 4;
-        pub const VT_DATA: flatbuffers::VOffsetT = 6;
+    impl<'a> AssetBalance<'a> {
+        pub const VT_ASSET: flatbuffers::VOffsetT = 4;
+        pub const VT_VALUE: flatbuffers::VOffsetT = 6;
 
         #[inline]
         pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-            Packet { _tab: table }
+            AssetBalance { _tab: table }
         }
         #[allow(unused_mut)]
         pub fn create<
@@ -5992,45 +6836,49 @@ pub mod fb {
             A: flatbuffers::Allocator + 'bldr,
         >(
             _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-            args: &'args PacketArgs<'args>,
-        ) -> flatbuffers::WIPOffset<Packet<'bldr>> {
-            let mut builder = PacketBuilder::new(_fbb);
-            if let Some(x) = args.data {
-                builder.add_data(x);
+            args: &'args AssetBalanceArgs<'args>,
+        ) -> flatbuffers::WIPOffset<AssetBalance<'bldr>> {
+            let mut builder = AssetBalanceBuilder::new(_fbb);
+            builder.add_value(args.value);
+            if let Some(x) = args.asset {
+                builder.add_asset(x);
             }
-            builder.add_len(args.len);
             builder.finish()
         }
 
-        pub fn unpack(&self) -> PacketT {
-            let len = self.len();
-            let data = self.data().map(|x| x.into_iter().collect());
-            PacketT { len, data }
+        pub fn unpack(&self) -> AssetBalanceT {
+            let asset = self.asset().map(|x| x.into_iter().collect());
+            let value = self.value();
+            AssetBalanceT { asset, value }
         }
 
         #[inline]
-        pub fn len(&self) -> u32 {
+        pub fn asset(&self) -> Option<flatbuffers::Vector<'a, u8>> {
             // Safety:
             // Created from valid Table for this object
             // which contains a valid value in this slot
-            unsafe { self._tab.get::<u32>(Packet::VT_LEN, Some(0)).unwrap() }
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        AssetBalance::VT_ASSET,
+                        None,
+                    )
+            }
         }
         #[inline]
-        pub fn data(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+        pub fn value(&self) -> u64 {
             // Safety:
             // Created from valid Table for this object
             // which contains a valid value in this slot
             unsafe {
                 self._tab
-                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
-                        Packet::VT_DATA,
-                        None,
-                    )
+                    .get::<u64>(AssetBalance::VT_VALUE, Some(0))
+                    .unwrap()
             }
         }
     }
 
-    impl flatbuffers::Verifiable for Packet<'_> {
+    impl flatbuffers::Verifiable for AssetBalance<'_> {
         #[inline]
         fn run_verifier(
             v: &mut flatbuffers::Verifier,
@@ -6038,38 +6886,204 @@ pub mod fb {
         ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
             use self::flatbuffers::Verifiable;
             v.visit_table(pos)?
-                .visit_field::<u32>("len", Self::VT_LEN, false)?
                 .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
-                    "data",
-                    Self::VT_DATA,
+                    "asset",
+                    Self::VT_ASSET,
                     false,
                 )?
+                .visit_field::<u64>("value", Self::VT_VALUE, false)?
                 .finish();
             Ok(())
         }
     }
-    pub struct PacketArgs<'a> {
-        pub len: u32,
-        pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub struct AssetBalanceArgs<'a> {
+        pub asset: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub value: u64,
     }
-    impl<'a> Default for PacketArgs<'a> {
+    impl<'a> Default for AssetBalanceArgs<'a> {
         #[inline]
         fn default() -> Self {
-            PacketArgs { len: 0, data: None }
+            AssetBalanceArgs {
+                asset: None,
+                value: 0,
+            }
         }
     }
 
-    pub struct PacketBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    pub struct AssetBalanceBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
         fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
         start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
     }
-    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PacketBuilder<'a, 'b, A> {
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AssetBalanceBuilder<'a, 'b, A> {
         #[inline]
-        pub fn add_len(&mut self, len: u32) {
-            self.fbb_.push_slot::<u32>(Packet::VT_LEN, len, 0);
+        pub fn add_asset(&mut self, asset: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(AssetBalance::VT_ASSET, asset);
         }
         #[inline]
-        pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+        pub fn add_value(&mut self, value: u64) {
+            self.fbb_.push_slot::<u64>(AssetBalance::VT_VALUE, value, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> AssetBalanceBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            AssetBalanceBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<AssetBalance<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for AssetBalance<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("AssetBalance");
+            ds.field("asset", &self.asset());
+            ds.field("value", &self.value());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct AssetBalanceT {
+        pub asset: Option<Vec<u8>>,
+        pub value: u64,
+    }
+    impl Default for AssetBalanceT {
+        fn default() -> Self {
+            Self {
+                asset: None,
+                value: 0,
+            }
+        }
+    }
+    impl AssetBalanceT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<AssetBalance<'b>> {
+            let asset = self.asset.as_ref().map(|x| _fbb.create_vector(x));
+            let value = self.value;
+            AssetBalance::create(_fbb, &AssetBalanceArgs { asset, value })
+        }
+    }
+    pub enum PacketOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct Packet<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for Packet<'a> {
+        type Inner = Packet<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> Packet<'a> {
+        pub const VT_LEN: flatbuffers::VOffsetT = 4;
+        pub const VT_DATA: flatbuffers::VOffsetT = 6;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            Packet { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args PacketArgs<'args>,
+        ) -> flatbuffers::WIPOffset<Packet<'bldr>> {
+            let mut builder = PacketBuilder::new(_fbb);
+            if let Some(x) = args.data {
+                builder.add_data(x);
+            }
+            builder.add_len(args.len);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> PacketT {
+            let len = self.len();
+            let data = self.data().map(|x| x.into_iter().collect());
+            PacketT { len, data }
+        }
+
+        #[inline]
+        pub fn len(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(Packet::VT_LEN, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn data(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        Packet::VT_DATA,
+                        None,
+                    )
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for Packet<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("len", Self::VT_LEN, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "data",
+                    Self::VT_DATA,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct PacketArgs<'a> {
+        pub len: u32,
+        pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    }
+    impl<'a> Default for PacketArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            PacketArgs { len: 0, data: None }
+        }
+    }
+
+    pub struct PacketBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PacketBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_len(&mut self, len: u32) {
+            self.fbb_.push_slot::<u32>(Packet::VT_LEN, len, 0);
+        }
+        #[inline]
+        pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
             self.fbb_
                 .push_slot_always::<flatbuffers::WIPOffset<_>>(Packet::VT_DATA, data);
         }
@@ -6479,15 +7493,15 @@ pub mod fb {
             )
         }
     }
-    pub enum SpendingOffset {}
+    pub enum RewindPlanOffset {}
     #[derive(Copy, Clone, PartialEq)]
 
-    pub struct Spending<'a> {
+    pub struct RewindPlan<'a> {
         pub _tab: flatbuffers::Table<'a>,
     }
 
-    impl<'a> flatbuffers::Follow<'a> for Spending<'a> {
-        type Inner = Spending<'a>;
+    impl<'a> flatbuffers::Follow<'a> for RewindPlan<'a> {
+        type Inner = RewindPlan<'a>;
         #[inline]
         unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
             Self {
@@ -6496,13 +7510,20 @@ pub mod fb {
         }
     }
 
-    impl<'a> Spending<'a> {
-        pub const VT_RECIPIENT: flatbuffers::VOffsetT = 4;
-        pub const VT_AMOUNT: flatbuffers::VOffsetT = 6;
+    impl<'a> RewindPlan<'a> {
+        pub const VT_REQUESTED_HEIGHT: flatbuffers::VOffsetT = 4;
+        pub const VT_HEIGHT: flatbuffers::VOffsetT = 6;
+        pub const VT_RESET: flatbuffers::VOffsetT = 8;
+        pub const VT_OFFLINE: flatbuffers::VOffsetT = 10;
+        pub const VT_TXS: flatbuffers::VOffsetT = 12;
+        pub const VT_NOTES: flatbuffers::VOffsetT = 14;
+        pub const VT_UTXOS: flatbuffers::VOffsetT = 16;
+        pub const VT_UNSPENT: flatbuffers::VOffsetT = 18;
+        pub const VT_MESSAGES: flatbuffers::VOffsetT = 20;
 
         #[inline]
         pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-            Spending { _tab: table }
+            RewindPlan { _tab: table }
         }
         #[allow(unused_mut)]
         pub fn create<
@@ -6512,42 +7533,134 @@ pub mod fb {
             A: flatbuffers::Allocator + 'bldr,
         >(
             _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-            args: &'args SpendingArgs<'args>,
-        ) -> flatbuffers::WIPOffset<Spending<'bldr>> {
-            let mut builder = SpendingBuilder::new(_fbb);
-            builder.add_amount(args.amount);
-            if let Some(x) = args.recipient {
-                builder.add_recipient(x);
-            }
+            args: &'args RewindPlanArgs,
+        ) -> flatbuffers::WIPOffset<RewindPlan<'bldr>> {
+            let mut builder = RewindPlanBuilder::new(_fbb);
+            builder.add_messages(args.messages);
+            builder.add_unspent(args.unspent);
+            builder.add_utxos(args.utxos);
+            builder.add_notes(args.notes);
+            builder.add_txs(args.txs);
+            builder.add_height(args.height);
+            builder.add_requested_height(args.requested_height);
+            builder.add_offline(args.offline);
+            builder.add_reset(args.reset);
             builder.finish()
         }
 
-        pub fn unpack(&self) -> SpendingT {
-            let recipient = self.recipient().map(|x| x.to_string());
-            let amount = self.amount();
-            SpendingT { recipient, amount }
+        pub fn unpack(&self) -> RewindPlanT {
+            let requested_height = self.requested_height();
+            let height = self.height();
+            let reset = self.reset();
+            let offline = self.offline();
+            let txs = self.txs();
+            let notes = self.notes();
+            let utxos = self.utxos();
+            let unspent = self.unspent();
+            let messages = self.messages();
+            RewindPlanT {
+                requested_height,
+                height,
+                reset,
+                offline,
+                txs,
+                notes,
+                utxos,
+                unspent,
+                messages,
+            }
         }
 
         #[inline]
-        pub fn recipient(&self) -> Option<&'a str> {
+        pub fn requested_height(&self) -> u32 {
             // Safety:
             // Created from valid Table for this object
             // which contains a valid value in this slot
             unsafe {
                 self._tab
-                    .get::<flatbuffers::ForwardsUOffset<&str>>(Spending::VT_RECIPIENT, None)
+                    .get::<u32>(RewindPlan::VT_REQUESTED_HEIGHT, Some(0))
+                    .unwrap()
             }
         }
         #[inline]
-        pub fn amount(&self) -> u64 {
+        pub fn height(&self) -> u32 {
             // Safety:
             // Created from valid Table for this object
             // which contains a valid value in this slot
-            unsafe { self._tab.get::<u64>(Spending::VT_AMOUNT, Some(0)).unwrap() }
+            unsafe {
+                self._tab
+                    .get::<u32>(RewindPlan::VT_HEIGHT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn reset(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(RewindPlan::VT_RESET, Some(false))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn offline(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(RewindPlan::VT_OFFLINE, Some(false))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn txs(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(RewindPlan::VT_TXS, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn notes(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(RewindPlan::VT_NOTES, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn utxos(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(RewindPlan::VT_UTXOS, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn unspent(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(RewindPlan::VT_UNSPENT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn messages(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(RewindPlan::VT_MESSAGES, Some(0))
+                    .unwrap()
+            }
         }
     }
 
-    impl flatbuffers::Verifiable for Spending<'_> {
+    impl flatbuffers::Verifiable for RewindPlan<'_> {
         #[inline]
         fn run_verifier(
             v: &mut flatbuffers::Verifier,
@@ -6555,42 +7668,295 @@ pub mod fb {
         ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
             use self::flatbuffers::Verifiable;
             v.visit_table(pos)?
-                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
-                    "recipient",
-                    Self::VT_RECIPIENT,
-                    false,
-                )?
-                .visit_field::<u64>("amount", Self::VT_AMOUNT, false)?
+                .visit_field::<u32>("requested_height", Self::VT_REQUESTED_HEIGHT, false)?
+                .visit_field::<u32>("height", Self::VT_HEIGHT, false)?
+                .visit_field::<bool>("reset", Self::VT_RESET, false)?
+                .visit_field::<bool>("offline", Self::VT_OFFLINE, false)?
+                .visit_field::<u32>("txs", Self::VT_TXS, false)?
+                .visit_field::<u32>("notes", Self::VT_NOTES, false)?
+                .visit_field::<u32>("utxos", Self::VT_UTXOS, false)?
+                .visit_field::<u32>("unspent", Self::VT_UNSPENT, false)?
+                .visit_field::<u32>("messages", Self::VT_MESSAGES, false)?
                 .finish();
             Ok(())
         }
     }
-    pub struct SpendingArgs<'a> {
-        pub recipient: Option<flatbuffers::WIPOffset<&'a str>>,
-        pub amount: u64,
+    pub struct RewindPlanArgs {
+        pub requested_height: u32,
+        pub height: u32,
+        pub reset: bool,
+        pub offline: bool,
+        pub txs: u32,
+        pub notes: u32,
+        pub utxos: u32,
+        pub unspent: u32,
+        pub messages: u32,
     }
-    impl<'a> Default for SpendingArgs<'a> {
+    impl<'a> Default for RewindPlanArgs {
         #[inline]
         fn default() -> Self {
-            SpendingArgs {
-                recipient: None,
-                amount: 0,
+            RewindPlanArgs {
+                requested_height: 0,
+                height: 0,
+                reset: false,
+                offline: false,
+                txs: 0,
+                notes: 0,
+                utxos: 0,
+                unspent: 0,
+                messages: 0,
             }
         }
     }
 
-    pub struct SpendingBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    pub struct RewindPlanBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
         fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
         start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
     }
-    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SpendingBuilder<'a, 'b, A> {
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RewindPlanBuilder<'a, 'b, A> {
         #[inline]
-        pub fn add_recipient(&mut self, recipient: flatbuffers::WIPOffset<&'b str>) {
+        pub fn add_requested_height(&mut self, requested_height: u32) {
             self.fbb_
-                .push_slot_always::<flatbuffers::WIPOffset<_>>(Spending::VT_RECIPIENT, recipient);
+                .push_slot::<u32>(RewindPlan::VT_REQUESTED_HEIGHT, requested_height, 0);
         }
         #[inline]
-        pub fn add_amount(&mut self, amount: u64) {
+        pub fn add_height(&mut self, height: u32) {
+            self.fbb_.push_slot::<u32>(RewindPlan::VT_HEIGHT, height, 0);
+        }
+        #[inline]
+        pub fn add_reset(&mut self, reset: bool) {
+            self.fbb_
+                .push_slot::<bool>(RewindPlan::VT_RESET, reset, false);
+        }
+        #[inline]
+        pub fn add_offline(&mut self, offline: bool) {
+            self.fbb_
+                .push_slot::<bool>(RewindPlan::VT_OFFLINE, offline, false);
+        }
+        #[inline]
+        pub fn add_txs(&mut self, txs: u32) {
+            self.fbb_.push_slot::<u32>(RewindPlan::VT_TXS, txs, 0);
+        }
+        #[inline]
+        pub fn add_notes(&mut self, notes: u32) {
+            self.fbb_.push_slot::<u32>(RewindPlan::VT_NOTES, notes, 0);
+        }
+        #[inline]
+        pub fn add_utxos(&mut self, utxos: u32) {
+            self.fbb_.push_slot::<u32>(RewindPlan::VT_UTXOS, utxos, 0);
+        }
+        #[inline]
+        pub fn add_unspent(&mut self, unspent: u32) {
+            self.fbb_
+                .push_slot::<u32>(RewindPlan::VT_UNSPENT, unspent, 0);
+        }
+        #[inline]
+        pub fn add_messages(&mut self, messages: u32) {
+            self.fbb_
+                .push_slot::<u32>(RewindPlan::VT_MESSAGES, messages, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> RewindPlanBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            RewindPlanBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<RewindPlan<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for RewindPlan<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("RewindPlan");
+            ds.field("requested_height", &self.requested_height());
+            ds.field("height", &self.height());
+            ds.field("reset", &self.reset());
+            ds.field("offline", &self.offline());
+            ds.field("txs", &self.txs());
+            ds.field("notes", &self.notes());
+            ds.field("utxos", &self.utxos());
+            ds.field("unspent", &self.unspent());
+            ds.field("messages", &self.messages());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct RewindPlanT {
+        pub requested_height: u32,
+        pub height: u32,
+        pub reset: bool,
+        pub offline: bool,
+        pub txs: u32,
+        pub notes: u32,
+        pub utxos: u32,
+        pub unspent: u32,
+        pub messages: u32,
+    }
+    impl Default for RewindPlanT {
+        fn default() -> Self {
+            Self {
+                requested_height: 0,
+                height: 0,
+                reset: false,
+                offline: false,
+                txs: 0,
+                notes: 0,
+                utxos: 0,
+                unspent: 0,
+                messages: 0,
+            }
+        }
+    }
+    impl RewindPlanT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<RewindPlan<'b>> {
+            let requested_height = self.requested_height;
+            let height = self.height;
+            let reset = self.reset;
+            let offline = self.offline;
+            let txs = self.txs;
+            let notes = self.notes;
+            let utxos = self.utxos;
+            let unspent = self.unspent;
+            let messages = self.messages;
+            RewindPlan::create(
+                _fbb,
+                &RewindPlanArgs {
+                    requested_height,
+                    height,
+                    reset,
+                    offline,
+                    txs,
+                    notes,
+                    utxos,
+                    unspent,
+                    messages,
+                },
+            )
+        }
+    }
+    pub enum SpendingOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct Spending<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for Spending<'a> {
+        type Inner = Spending<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> Spending<'a> {
+        pub const VT_RECIPIENT: flatbuffers::VOffsetT = 4;
+        pub const VT_AMOUNT: flatbuffers::VOffsetT = 6;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            Spending { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args SpendingArgs<'args>,
+        ) -> flatbuffers::WIPOffset<Spending<'bldr>> {
+            let mut builder = SpendingBuilder::new(_fbb);
+            builder.add_amount(args.amount);
+            if let Some(x) = args.recipient {
+                builder.add_recipient(x);
+            }
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> SpendingT {
+            let recipient = self.recipient().map(|x| x.to_string());
+            let amount = self.amount();
+            SpendingT { recipient, amount }
+        }
+
+        #[inline]
+        pub fn recipient(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(Spending::VT_RECIPIENT, None)
+            }
+        }
+        #[inline]
+        pub fn amount(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u64>(Spending::VT_AMOUNT, Some(0)).unwrap() }
+        }
+    }
+
+    impl flatbuffers::Verifiable for Spending<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "recipient",
+                    Self::VT_RECIPIENT,
+                    false,
+                )?
+                .visit_field::<u64>("amount", Self::VT_AMOUNT, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct SpendingArgs<'a> {
+        pub recipient: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub amount: u64,
+    }
+    impl<'a> Default for SpendingArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            SpendingArgs {
+                recipient: None,
+                amount: 0,
+            }
+        }
+    }
+
+    pub struct SpendingBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SpendingBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_recipient(&mut self, recipient: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(Spending::VT_RECIPIENT, recipient);
+        }
+        #[inline]
+        pub fn add_amount(&mut self, amount: u64) {
             self.fbb_.push_slot::<u64>(Spending::VT_AMOUNT, amount, 0);
         }
         #[inline]
@@ -7452,6 +8818,23 @@ pub mod fb {
         pub const VT_WARP_END_HEIGHT: flatbuffers::VOffsetT = 10;
         pub const VT_CONFIRMATIONS: flatbuffers::VOffsetT = 12;
         pub const VT_REGTEST: flatbuffers::VOffsetT = 14;
+        pub const VT_COMMIT_INTERVAL: flatbuffers::VOffsetT = 16;
+        pub const VT_PROOF_THREADS: flatbuffers::VOffsetT = 18;
+        pub const VT_LAZY_TX_DETAILS: flatbuffers::VOffsetT = 20;
+        pub const VT_DB_READ_POOL_SIZE: flatbuffers::VOffsetT = 22;
+        pub const VT_DB_BUSY_TIMEOUT: flatbuffers::VOffsetT = 24;
+        pub const VT_ROTATE_USED_ADDRESS: flatbuffers::VOffsetT = 26;
+        pub const VT_ACCOUNT_GRACE_PERIOD: flatbuffers::VOffsetT = 28;
+        pub const VT_MAX_DECOY_FEE: flatbuffers::VOffsetT = 30;
+        pub const VT_API_KEY: flatbuffers::VOffsetT = 32;
+        pub const VT_LAZY_WITNESSES: flatbuffers::VOffsetT = 34;
+        pub const VT_PREFILTER_OUTPUTS: flatbuffers::VOffsetT = 36;
+        pub const VT_STORE_BLOCK_STATS: flatbuffers::VOffsetT = 38;
+        pub const VT_SWEEP_TEX_CHANGE: flatbuffers::VOffsetT = 40;
+        pub const VT_MAX_FEE_PERCENT: flatbuffers::VOffsetT = 42;
+        pub const VT_MAX_FEE: flatbuffers::VOffsetT = 44;
+        pub const VT_CLI_LOCK_TIMEOUT: flatbuffers::VOffsetT = 46;
+        pub const VT_MAX_CHANGE_FEE: flatbuffers::VOffsetT = 48;
 
         #[inline]
         pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -7468,6 +8851,19 @@ pub mod fb {
             args: &'args ConfigArgs<'args>,
         ) -> flatbuffers::WIPOffset<Config<'bldr>> {
             let mut builder = ConfigBuilder::new(_fbb);
+            builder.add_max_change_fee(args.max_change_fee);
+            builder.add_max_fee(args.max_fee);
+            builder.add_max_decoy_fee(args.max_decoy_fee);
+            builder.add_cli_lock_timeout(args.cli_lock_timeout);
+            builder.add_max_fee_percent(args.max_fee_percent);
+            if let Some(x) = args.api_key {
+                builder.add_api_key(x);
+            }
+            builder.add_account_grace_period(args.account_grace_period);
+            builder.add_db_busy_timeout(args.db_busy_timeout);
+            builder.add_db_read_pool_size(args.db_read_pool_size);
+            builder.add_proof_threads(args.proof_threads);
+            builder.add_commit_interval(args.commit_interval);
             builder.add_confirmations(args.confirmations);
             builder.add_warp_end_height(args.warp_end_height);
             if let Some(x) = args.warp_url {
@@ -7479,6 +8875,12 @@ pub mod fb {
             if let Some(x) = args.db_path {
                 builder.add_db_path(x);
             }
+            builder.add_sweep_tex_change(args.sweep_tex_change);
+            builder.add_store_block_stats(args.store_block_stats);
+            builder.add_prefilter_outputs(args.prefilter_outputs);
+            builder.add_lazy_witnesses(args.lazy_witnesses);
+            builder.add_rotate_used_address(args.rotate_used_address);
+            builder.add_lazy_tx_details(args.lazy_tx_details);
             builder.add_regtest(args.regtest);
             builder.finish()
         }
@@ -7492,6 +8894,23 @@ pub mod fb {
             let warp_end_height = self.warp_end_height();
             let confirmations = self.confirmations();
             let regtest = self.regtest();
+            let commit_interval = self.commit_interval();
+            let proof_threads = self.proof_threads();
+            let lazy_tx_details = self.lazy_tx_details();
+            let db_read_pool_size = self.db_read_pool_size();
+            let db_busy_timeout = self.db_busy_timeout();
+            let rotate_used_address = self.rotate_used_address();
+            let account_grace_period = self.account_grace_period();
+            let max_decoy_fee = self.max_decoy_fee();
+            let api_key = self.api_key().map(|x| x.to_string());
+            let lazy_witnesses = self.lazy_witnesses();
+            let prefilter_outputs = self.prefilter_outputs();
+            let store_block_stats = self.store_block_stats();
+            let sweep_tex_change = self.sweep_tex_change();
+            let max_fee_percent = self.max_fee_percent();
+            let max_fee = self.max_fee();
+            let cli_lock_timeout = self.cli_lock_timeout();
+            let max_change_fee = self.max_change_fee();
             ConfigT {
                 db_path,
                 servers,
@@ -7499,6 +8918,23 @@ pub mod fb {
                 warp_end_height,
                 confirmations,
                 regtest,
+                commit_interval,
+                proof_threads,
+                lazy_tx_details,
+                db_read_pool_size,
+                db_busy_timeout,
+                rotate_used_address,
+                account_grace_period,
+                max_decoy_fee,
+                api_key,
+                lazy_witnesses,
+                prefilter_outputs,
+                store_block_stats,
+                sweep_tex_change,
+                max_fee_percent,
+                max_fee,
+                cli_lock_timeout,
+                max_change_fee,
             }
         }
 
@@ -7568,78 +9004,315 @@ pub mod fb {
                     .unwrap()
             }
         }
-    }
-
-    impl flatbuffers::Verifiable for Config<'_> {
         #[inline]
-        fn run_verifier(
-            v: &mut flatbuffers::Verifier,
-            pos: usize,
-        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
-            use self::flatbuffers::Verifiable;
-            v.visit_table(pos)?
-                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
-                    "db_path",
-                    Self::VT_DB_PATH,
-                    false,
-                )?
-                .visit_field::<flatbuffers::ForwardsUOffset<
-                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
-                >>("servers", Self::VT_SERVERS, false)?
-                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
-                    "warp_url",
-                    Self::VT_WARP_URL,
-                    false,
-                )?
-                .visit_field::<u32>("warp_end_height", Self::VT_WARP_END_HEIGHT, false)?
-                .visit_field::<u32>("confirmations", Self::VT_CONFIRMATIONS, false)?
-                .visit_field::<bool>("regtest", Self::VT_REGTEST, false)?
-                .finish();
-            Ok(())
+        pub fn commit_interval(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(Config::VT_COMMIT_INTERVAL, Some(0))
+                    .unwrap()
+            }
         }
-    }
-    pub struct ConfigArgs<'a> {
-        pub db_path: Option<flatbuffers::WIPOffset<&'a str>>,
-        pub servers: Option<
-            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
-        >,
-        pub warp_url: Option<flatbuffers::WIPOffset<&'a str>>,
-        pub warp_end_height: u32,
-        pub confirmations: u32,
-        pub regtest: bool,
-    }
-    impl<'a> Default for ConfigArgs<'a> {
         #[inline]
-        fn default() -> Self {
-            ConfigArgs {
-                db_path: None,
-                servers: None,
-                warp_url: None,
-                warp_end_height: 0,
-                confirmations: 0,
-                regtest: false,
+        pub fn proof_threads(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(Config::VT_PROOF_THREADS, Some(0))
+                    .unwrap()
             }
         }
-    }
-
-    pub struct ConfigBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
-        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
-    }
-    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ConfigBuilder<'a, 'b, A> {
         #[inline]
-        pub fn add_db_path(&mut self, db_path: flatbuffers::WIPOffset<&'b str>) {
-            self.fbb_
-                .push_slot_always::<flatbuffers::WIPOffset<_>>(Config::VT_DB_PATH, db_path);
+        pub fn lazy_tx_details(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(Config::VT_LAZY_TX_DETAILS, Some(false))
+                    .unwrap()
+            }
         }
         #[inline]
-        pub fn add_servers(
-            &mut self,
-            servers: flatbuffers::WIPOffset<
-                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
-            >,
-        ) {
-            self.fbb_
+        pub fn db_read_pool_size(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(Config::VT_DB_READ_POOL_SIZE, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn db_busy_timeout(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(Config::VT_DB_BUSY_TIMEOUT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn rotate_used_address(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(Config::VT_ROTATE_USED_ADDRESS, Some(false))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn account_grace_period(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(Config::VT_ACCOUNT_GRACE_PERIOD, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn max_decoy_fee(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(Config::VT_MAX_DECOY_FEE, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn api_key(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(Config::VT_API_KEY, None)
+            }
+        }
+        #[inline]
+        pub fn lazy_witnesses(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(Config::VT_LAZY_WITNESSES, Some(false))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn prefilter_outputs(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(Config::VT_PREFILTER_OUTPUTS, Some(false))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn store_block_stats(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(Config::VT_STORE_BLOCK_STATS, Some(false))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn sweep_tex_change(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(Config::VT_SWEEP_TEX_CHANGE, Some(false))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn max_fee_percent(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(Config::VT_MAX_FEE_PERCENT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn max_fee(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u64>(Config::VT_MAX_FEE, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn cli_lock_timeout(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(Config::VT_CLI_LOCK_TIMEOUT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn max_change_fee(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(Config::VT_MAX_CHANGE_FEE, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for Config<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "db_path",
+                    Self::VT_DB_PATH,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+                >>("servers", Self::VT_SERVERS, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "warp_url",
+                    Self::VT_WARP_URL,
+                    false,
+                )?
+                .visit_field::<u32>("warp_end_height", Self::VT_WARP_END_HEIGHT, false)?
+                .visit_field::<u32>("confirmations", Self::VT_CONFIRMATIONS, false)?
+                .visit_field::<bool>("regtest", Self::VT_REGTEST, false)?
+                .visit_field::<u32>("commit_interval", Self::VT_COMMIT_INTERVAL, false)?
+                .visit_field::<u32>("proof_threads", Self::VT_PROOF_THREADS, false)?
+                .visit_field::<bool>("lazy_tx_details", Self::VT_LAZY_TX_DETAILS, false)?
+                .visit_field::<u32>("db_read_pool_size", Self::VT_DB_READ_POOL_SIZE, false)?
+                .visit_field::<u32>("db_busy_timeout", Self::VT_DB_BUSY_TIMEOUT, false)?
+                .visit_field::<bool>("rotate_used_address", Self::VT_ROTATE_USED_ADDRESS, false)?
+                .visit_field::<u32>("account_grace_period", Self::VT_ACCOUNT_GRACE_PERIOD, false)?
+                .visit_field::<u64>("max_decoy_fee", Self::VT_MAX_DECOY_FEE, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "api_key",
+                    Self::VT_API_KEY,
+                    false,
+                )?
+                .visit_field::<bool>("lazy_witnesses", Self::VT_LAZY_WITNESSES, false)?
+                .visit_field::<bool>("prefilter_outputs", Self::VT_PREFILTER_OUTPUTS, false)?
+                .visit_field::<bool>("store_block_stats", Self::VT_STORE_BLOCK_STATS, false)?
+                .visit_field::<bool>("sweep_tex_change", Self::VT_SWEEP_TEX_CHANGE, false)?
+                .visit_field::<u32>("max_fee_percent", Self::VT_MAX_FEE_PERCENT, false)?
+                .visit_field::<u64>("max_fee", Self::VT_MAX_FEE, false)?
+                .visit_field::<u32>("cli_lock_timeout", Self::VT_CLI_LOCK_TIMEOUT, false)?
+                .visit_field::<u64>("max_change_fee", Self::VT_MAX_CHANGE_FEE, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct ConfigArgs<'a> {
+        pub db_path: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub servers: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+        >,
+        pub warp_url: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub warp_end_height: u32,
+        pub confirmations: u32,
+        pub regtest: bool,
+        pub commit_interval: u32,
+        pub proof_threads: u32,
+        pub lazy_tx_details: bool,
+        pub db_read_pool_size: u32,
+        pub db_busy_timeout: u32,
+        pub rotate_used_address: bool,
+        pub account_grace_period: u32,
+        pub max_decoy_fee: u64,
+        pub api_key: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub lazy_witnesses: bool,
+        pub prefilter_outputs: bool,
+        pub store_block_stats: bool,
+        pub sweep_tex_change: bool,
+        pub max_fee_percent: u32,
+        pub max_fee: u64,
+        pub cli_lock_timeout: u32,
+        pub max_change_fee: u64,
+    }
+    impl<'a> Default for ConfigArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            ConfigArgs {
+                db_path: None,
+                servers: None,
+                warp_url: None,
+                warp_end_height: 0,
+                confirmations: 0,
+                regtest: false,
+                commit_interval: 0,
+                proof_threads: 0,
+                lazy_tx_details: false,
+                db_read_pool_size: 0,
+                db_busy_timeout: 0,
+                rotate_used_address: false,
+                account_grace_period: 0,
+                max_decoy_fee: 0,
+                api_key: None,
+                lazy_witnesses: false,
+                prefilter_outputs: false,
+                store_block_stats: false,
+                sweep_tex_change: false,
+                max_fee_percent: 0,
+                max_fee: 0,
+                cli_lock_timeout: 0,
+                max_change_fee: 0,
+            }
+        }
+    }
+
+    pub struct ConfigBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ConfigBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_db_path(&mut self, db_path: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(Config::VT_DB_PATH, db_path);
+        }
+        #[inline]
+        pub fn add_servers(
+            &mut self,
+            servers: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+            >,
+        ) {
+            self.fbb_
                 .push_slot_always::<flatbuffers::WIPOffset<_>>(Config::VT_SERVERS, servers);
         }
         #[inline]
@@ -7663,6 +9336,90 @@ pub mod fb {
                 .push_slot::<bool>(Config::VT_REGTEST, regtest, false);
         }
         #[inline]
+        pub fn add_commit_interval(&mut self, commit_interval: u32) {
+            self.fbb_
+                .push_slot::<u32>(Config::VT_COMMIT_INTERVAL, commit_interval, 0);
+        }
+        #[inline]
+        pub fn add_proof_threads(&mut self, proof_threads: u32) {
+            self.fbb_
+                .push_slot::<u32>(Config::VT_PROOF_THREADS, proof_threads, 0);
+        }
+        #[inline]
+        pub fn add_lazy_tx_details(&mut self, lazy_tx_details: bool) {
+            self.fbb_
+                .push_slot::<bool>(Config::VT_LAZY_TX_DETAILS, lazy_tx_details, false);
+        }
+        #[inline]
+        pub fn add_db_read_pool_size(&mut self, db_read_pool_size: u32) {
+            self.fbb_
+                .push_slot::<u32>(Config::VT_DB_READ_POOL_SIZE, db_read_pool_size, 0);
+        }
+        #[inline]
+        pub fn add_db_busy_timeout(&mut self, db_busy_timeout: u32) {
+            self.fbb_
+                .push_slot::<u32>(Config::VT_DB_BUSY_TIMEOUT, db_busy_timeout, 0);
+        }
+        #[inline]
+        pub fn add_rotate_used_address(&mut self, rotate_used_address: bool) {
+            self.fbb_
+                .push_slot::<bool>(Config::VT_ROTATE_USED_ADDRESS, rotate_used_address, false);
+        }
+        #[inline]
+        pub fn add_account_grace_period(&mut self, account_grace_period: u32) {
+            self.fbb_
+                .push_slot::<u32>(Config::VT_ACCOUNT_GRACE_PERIOD, account_grace_period, 0);
+        }
+        #[inline]
+        pub fn add_max_decoy_fee(&mut self, max_decoy_fee: u64) {
+            self.fbb_
+                .push_slot::<u64>(Config::VT_MAX_DECOY_FEE, max_decoy_fee, 0);
+        }
+        #[inline]
+        pub fn add_api_key(&mut self, api_key: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(Config::VT_API_KEY, api_key);
+        }
+        #[inline]
+        pub fn add_lazy_witnesses(&mut self, lazy_witnesses: bool) {
+            self.fbb_
+                .push_slot::<bool>(Config::VT_LAZY_WITNESSES, lazy_witnesses, false);
+        }
+        #[inline]
+        pub fn add_prefilter_outputs(&mut self, prefilter_outputs: bool) {
+            self.fbb_
+                .push_slot::<bool>(Config::VT_PREFILTER_OUTPUTS, prefilter_outputs, false);
+        }
+        #[inline]
+        pub fn add_store_block_stats(&mut self, store_block_stats: bool) {
+            self.fbb_
+                .push_slot::<bool>(Config::VT_STORE_BLOCK_STATS, store_block_stats, false);
+        }
+        #[inline]
+        pub fn add_sweep_tex_change(&mut self, sweep_tex_change: bool) {
+            self.fbb_
+                .push_slot::<bool>(Config::VT_SWEEP_TEX_CHANGE, sweep_tex_change, false);
+        }
+        #[inline]
+        pub fn add_max_fee_percent(&mut self, max_fee_percent: u32) {
+            self.fbb_
+                .push_slot::<u32>(Config::VT_MAX_FEE_PERCENT, max_fee_percent, 0);
+        }
+        #[inline]
+        pub fn add_max_fee(&mut self, max_fee: u64) {
+            self.fbb_.push_slot::<u64>(Config::VT_MAX_FEE, max_fee, 0);
+        }
+        #[inline]
+        pub fn add_cli_lock_timeout(&mut self, cli_lock_timeout: u32) {
+            self.fbb_
+                .push_slot::<u32>(Config::VT_CLI_LOCK_TIMEOUT, cli_lock_timeout, 0);
+        }
+        #[inline]
+        pub fn add_max_change_fee(&mut self, max_change_fee: u64) {
+            self.fbb_
+                .push_slot::<u64>(Config::VT_MAX_CHANGE_FEE, max_change_fee, 0);
+        }
+        #[inline]
         pub fn new(
             _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
         ) -> ConfigBuilder<'a, 'b, A> {
@@ -7688,6 +9445,23 @@ pub mod fb {
             ds.field("warp_end_height", &self.warp_end_height());
             ds.field("confirmations", &self.confirmations());
             ds.field("regtest", &self.regtest());
+            ds.field("commit_interval", &self.commit_interval());
+            ds.field("proof_threads", &self.proof_threads());
+            ds.field("lazy_tx_details", &self.lazy_tx_details());
+            ds.field("db_read_pool_size", &self.db_read_pool_size());
+            ds.field("db_busy_timeout", &self.db_busy_timeout());
+            ds.field("rotate_used_address", &self.rotate_used_address());
+            ds.field("account_grace_period", &self.account_grace_period());
+            ds.field("max_decoy_fee", &self.max_decoy_fee());
+            ds.field("api_key", &self.api_key());
+            ds.field("lazy_witnesses", &self.lazy_witnesses());
+            ds.field("prefilter_outputs", &self.prefilter_outputs());
+            ds.field("store_block_stats", &self.store_block_stats());
+            ds.field("sweep_tex_change", &self.sweep_tex_change());
+            ds.field("max_fee_percent", &self.max_fee_percent());
+            ds.field("max_fee", &self.max_fee());
+            ds.field("cli_lock_timeout", &self.cli_lock_timeout());
+            ds.field("max_change_fee", &self.max_change_fee());
             ds.finish()
         }
     }
@@ -7700,6 +9474,23 @@ pub mod fb {
         pub warp_end_height: u32,
         pub confirmations: u32,
         pub regtest: bool,
+        pub commit_interval: u32,
+        pub proof_threads: u32,
+        pub lazy_tx_details: bool,
+        pub db_read_pool_size: u32,
+        pub db_busy_timeout: u32,
+        pub rotate_used_address: bool,
+        pub account_grace_period: u32,
+        pub max_decoy_fee: u64,
+        pub api_key: Option<String>,
+        pub lazy_witnesses: bool,
+        pub prefilter_outputs: bool,
+        pub store_block_stats: bool,
+        pub sweep_tex_change: bool,
+        pub max_fee_percent: u32,
+        pub max_fee: u64,
+        pub cli_lock_timeout: u32,
+        pub max_change_fee: u64,
     }
     impl Default for ConfigT {
         fn default() -> Self {
@@ -7710,6 +9501,23 @@ pub mod fb {
                 warp_end_height: 0,
                 confirmations: 0,
                 regtest: false,
+                commit_interval: 0,
+                proof_threads: 0,
+                lazy_tx_details: false,
+                db_read_pool_size: 0,
+                db_busy_timeout: 0,
+                rotate_used_address: false,
+                account_grace_period: 0,
+                max_decoy_fee: 0,
+                api_key: None,
+                lazy_witnesses: false,
+                prefilter_outputs: false,
+                store_block_stats: false,
+                sweep_tex_change: false,
+                max_fee_percent: 0,
+                max_fee: 0,
+                cli_lock_timeout: 0,
+                max_change_fee: 0,
             }
         }
     }
@@ -7727,6 +9535,23 @@ pub mod fb {
             let warp_end_height = self.warp_end_height;
             let confirmations = self.confirmations;
             let regtest = self.regtest;
+            let commit_interval = self.commit_interval;
+            let proof_threads = self.proof_threads;
+            let lazy_tx_details = self.lazy_tx_details;
+            let db_read_pool_size = self.db_read_pool_size;
+            let db_busy_timeout = self.db_busy_timeout;
+            let rotate_used_address = self.rotate_used_address;
+            let account_grace_period = self.account_grace_period;
+            let max_decoy_fee = self.max_decoy_fee;
+            let api_key = self.api_key.as_ref().map(|x| _fbb.create_string(x));
+            let lazy_witnesses = self.lazy_witnesses;
+            let prefilter_outputs = self.prefilter_outputs;
+            let store_block_stats = self.store_block_stats;
+            let sweep_tex_change = self.sweep_tex_change;
+            let max_fee_percent = self.max_fee_percent;
+            let max_fee = self.max_fee;
+            let cli_lock_timeout = self.cli_lock_timeout;
+            let max_change_fee = self.max_change_fee;
             Config::create(
                 _fbb,
                 &ConfigArgs {
@@ -7736,6 +9561,23 @@ pub mod fb {
                     warp_end_height,
                     confirmations,
                     regtest,
+                    commit_interval,
+                    proof_threads,
+                    lazy_tx_details,
+                    db_read_pool_size,
+                    db_busy_timeout,
+                    rotate_used_address,
+                    account_grace_period,
+                    max_decoy_fee,
+                    api_key,
+                    lazy_witnesses,
+                    prefilter_outputs,
+                    store_block_stats,
+                    sweep_tex_change,
+                    max_fee_percent,
+                    max_fee,
+                    cli_lock_timeout,
+                    max_change_fee,
                 },
             )
         }
@@ -8145,6 +9987,7 @@ pub mod fb {
         pub const VT_FILE_LIST: flatbuffers::VOffsetT = 6;
         pub const VT_TARGET_PATH: flatbuffers::VOffsetT = 8;
         pub const VT_PUBLIC_KEY: flatbuffers::VOffsetT = 10;
+        pub const VT_CHUNK_SIZE: flatbuffers::VOffsetT = 12;
 
         #[inline]
         pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -8161,6 +10004,7 @@ pub mod fb {
             args: &'args ZipDbConfigArgs<'args>,
         ) -> flatbuffers::WIPOffset<ZipDbConfig<'bldr>> {
             let mut builder = ZipDbConfigBuilder::new(_fbb);
+            builder.add_chunk_size(args.chunk_size);
             if let Some(x) = args.public_key {
                 builder.add_public_key(x);
             }
@@ -8183,11 +10027,13 @@ pub mod fb {
                 .map(|x| x.iter().map(|s| s.to_string()).collect());
             let target_path = self.target_path().map(|x| x.to_string());
             let public_key = self.public_key().map(|x| x.to_string());
+            let chunk_size = self.chunk_size();
             ZipDbConfigT {
                 directory,
                 file_list,
                 target_path,
                 public_key,
+                chunk_size,
             }
         }
 
@@ -8234,6 +10080,17 @@ pub mod fb {
                     .get::<flatbuffers::ForwardsUOffset<&str>>(ZipDbConfig::VT_PUBLIC_KEY, None)
             }
         }
+        #[inline]
+        pub fn chunk_size(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(ZipDbConfig::VT_CHUNK_SIZE, Some(0))
+                    .unwrap()
+            }
+        }
     }
 
     impl flatbuffers::Verifiable for ZipDbConfig<'_> {
@@ -8262,6 +10119,7 @@ pub mod fb {
                     Self::VT_PUBLIC_KEY,
                     false,
                 )?
+                .visit_field::<u32>("chunk_size", Self::VT_CHUNK_SIZE, false)?
                 .finish();
             Ok(())
         }
@@ -8273,6 +10131,7 @@ pub mod fb {
         >,
         pub target_path: Option<flatbuffers::WIPOffset<&'a str>>,
         pub public_key: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub chunk_size: u32,
     }
     impl<'a> Default for ZipDbConfigArgs<'a> {
         #[inline]
@@ -8282,6 +10141,7 @@ pub mod fb {
                 file_list: None,
                 target_path: None,
                 public_key: None,
+                chunk_size: 0,
             }
         }
     }
@@ -8325,8 +10185,13 @@ pub mod fb {
             );
         }
         #[inline]
-        pub fn new(
-            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        pub fn add_chunk_size(&mut self, chunk_size: u32) {
+            self.fbb_
+                .push_slot::<u32>(ZipDbConfig::VT_CHUNK_SIZE, chunk_size, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
         ) -> ZipDbConfigBuilder<'a, 'b, A> {
             let start = _fbb.start_table();
             ZipDbConfigBuilder {
@@ -8348,6 +10213,7 @@ pub mod fb {
             ds.field("file_list", &self.file_list());
             ds.field("target_path", &self.target_path());
             ds.field("public_key", &self.public_key());
+            ds.field("chunk_size", &self.chunk_size());
             ds.finish()
         }
     }
@@ -8358,6 +10224,7 @@ pub mod fb {
         pub file_list: Option<Vec<String>>,
         pub target_path: Option<String>,
         pub public_key: Option<String>,
+        pub chunk_size: u32,
     }
     impl Default for ZipDbConfigT {
         fn default() -> Self {
@@ -8366,6 +10233,7 @@ pub mod fb {
                 file_list: None,
                 target_path: None,
                 public_key: None,
+                chunk_size: 0,
             }
         }
     }
@@ -8381,6 +10249,7 @@ pub mod fb {
             });
             let target_path = self.target_path.as_ref().map(|x| _fbb.create_string(x));
             let public_key = self.public_key.as_ref().map(|x| _fbb.create_string(x));
+            let chunk_size = self.chunk_size;
             ZipDbConfig::create(
                 _fbb,
                 &ZipDbConfigArgs {
@@ -8388,6 +10257,7 @@ pub mod fb {
                     file_list,
                     target_path,
                     public_key,
+                    chunk_size,
                 },
             )
         }
@@ -8676,6 +10546,7 @@ pub mod fb {
         pub const VT_NOTES: flatbuffers::VOffsetT = 4;
         pub const VT_DATA: flatbuffers::VOffsetT = 6;
         pub const VT_MESSAGE: flatbuffers::VOffsetT = 8;
+        pub const VT_TEX_CHANGE: flatbuffers::VOffsetT = 10;
 
         #[inline]
         pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -8692,6 +10563,9 @@ pub mod fb {
             args: &'args TransactionBytesArgs<'args>,
         ) -> flatbuffers::WIPOffset<TransactionBytes<'bldr>> {
             let mut builder = TransactionBytesBuilder::new(_fbb);
+            if let Some(x) = args.tex_change {
+                builder.add_tex_change(x);
+            }
             if let Some(x) = args.message {
                 builder.add_message(x);
             }
@@ -8708,10 +10582,12 @@ pub mod fb {
             let notes = self.notes().map(|x| x.iter().map(|t| t.unpack()).collect());
             let data = self.data().map(|x| x.into_iter().collect());
             let message = self.message().map(|x| x.to_string());
+            let tex_change = self.tex_change().map(|x| x.to_string());
             TransactionBytesT {
                 notes,
                 data,
                 message,
+                tex_change,
             }
         }
 
@@ -8751,6 +10627,18 @@ pub mod fb {
                     .get::<flatbuffers::ForwardsUOffset<&str>>(TransactionBytes::VT_MESSAGE, None)
             }
         }
+        #[inline]
+        pub fn tex_change(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(
+                    TransactionBytes::VT_TEX_CHANGE,
+                    None,
+                )
+            }
+        }
     }
 
     impl flatbuffers::Verifiable for TransactionBytes<'_> {
@@ -8776,6 +10664,11 @@ pub mod fb {
                     Self::VT_MESSAGE,
                     false,
                 )?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "tex_change",
+                    Self::VT_TEX_CHANGE,
+                    false,
+                )?
                 .finish();
             Ok(())
         }
@@ -8784,6 +10677,7 @@ pub mod fb {
         pub notes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, IdNote>>>,
         pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
         pub message: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub tex_change: Option<flatbuffers::WIPOffset<&'a str>>,
     }
     impl<'a> Default for TransactionBytesArgs<'a> {
         #[inline]
@@ -8792,6 +10686,7 @@ pub mod fb {
                 notes: None,
                 data: None,
                 message: None,
+                tex_change: None,
             }
         }
     }
@@ -8822,6 +10717,13 @@ pub mod fb {
             );
         }
         #[inline]
+        pub fn add_tex_change(&mut self, tex_change: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                TransactionBytes::VT_TEX_CHANGE,
+                tex_change,
+            );
+        }
+        #[inline]
         pub fn new(
             _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
         ) -> TransactionBytesBuilder<'a, 'b, A> {
@@ -8844,6 +10746,7 @@ pub mod fb {
             ds.field("notes", &self.notes());
             ds.field("data", &self.data());
             ds.field("message", &self.message());
+            ds.field("tex_change", &self.tex_change());
             ds.finish()
         }
     }
@@ -8853,6 +10756,7 @@ pub mod fb {
         pub notes: Option<Vec<IdNoteT>>,
         pub data: Option<Vec<u8>>,
         pub message: Option<String>,
+        pub tex_change: Option<String>,
     }
     impl Default for TransactionBytesT {
         fn default() -> Self {
@@ -8860,6 +10764,7 @@ pub mod fb {
                 notes: None,
                 data: None,
                 message: None,
+                tex_change: None,
             }
         }
     }
@@ -8874,12 +10779,14 @@ pub mod fb {
             });
             let data = self.data.as_ref().map(|x| _fbb.create_vector(x));
             let message = self.message.as_ref().map(|x| _fbb.create_string(x));
+            let tex_change = self.tex_change.as_ref().map(|x| _fbb.create_string(x));
             TransactionBytes::create(
                 _fbb,
                 &TransactionBytesArgs {
                     notes,
                     data,
                     message,
+                    tex_change,
                 },
             )
         }
@@ -9905,4 +11812,11906 @@ pub mod fb {
             )
         }
     }
+    pub enum TransactionMismatchOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct TransactionMismatch<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for TransactionMismatch<'a> {
+        type Inner = TransactionMismatch<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> TransactionMismatch<'a> {
+        pub const VT_KIND: flatbuffers::VOffsetT = 4;
+        pub const VT_ADDRESS: flatbuffers::VOffsetT = 6;
+        pub const VT_EXPECTED: flatbuffers::VOffsetT = 8;
+        pub const VT_ACTUAL: flatbuffers::VOffsetT = 10;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            TransactionMismatch { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args TransactionMismatchArgs<'args>,
+        ) -> flatbuffers::WIPOffset<TransactionMismatch<'bldr>> {
+            let mut builder = TransactionMismatchBuilder::new(_fbb);
+            if let Some(x) = args.actual {
+                builder.add_actual(x);
+            }
+            if let Some(x) = args.expected {
+                builder.add_expected(x);
+            }
+            if let Some(x) = args.address {
+                builder.add_address(x);
+            }
+            if let Some(x) = args.kind {
+                builder.add_kind(x);
+            }
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> TransactionMismatchT {
+            let kind = self.kind().map(|x| x.to_string());
+            let address = self.address().map(|x| x.to_string());
+            let expected = self.expected().map(|x| x.to_string());
+            let actual = self.actual().map(|x| x.to_string());
+            TransactionMismatchT {
+                kind,
+                address,
+                expected,
+                actual,
+            }
+        }
+
+        #[inline]
+        pub fn kind(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(TransactionMismatch::VT_KIND, None)
+            }
+        }
+        #[inline]
+        pub fn address(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(
+                    TransactionMismatch::VT_ADDRESS,
+                    None,
+                )
+            }
+        }
+        #[inline]
+        pub fn expected(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(
+                    TransactionMismatch::VT_EXPECTED,
+                    None,
+                )
+            }
+        }
+        #[inline]
+        pub fn actual(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(TransactionMismatch::VT_ACTUAL, None)
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for TransactionMismatch<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("kind", Self::VT_KIND, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "address",
+                    Self::VT_ADDRESS,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "expected",
+                    Self::VT_EXPECTED,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "actual",
+                    Self::VT_ACTUAL,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct TransactionMismatchArgs<'a> {
+        pub kind: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub address: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub expected: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub actual: Option<flatbuffers::WIPOffset<&'a str>>,
+    }
+    impl<'a> Default for TransactionMismatchArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            TransactionMismatchArgs {
+                kind: None,
+                address: None,
+                expected: None,
+                actual: None,
+            }
+        }
+    }
+
+    pub struct TransactionMismatchBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TransactionMismatchBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_kind(&mut self, kind: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TransactionMismatch::VT_KIND, kind);
+        }
+        #[inline]
+        pub fn add_address(&mut self, address: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                TransactionMismatch::VT_ADDRESS,
+                address,
+            );
+        }
+        #[inline]
+        pub fn add_expected(&mut self, expected: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                TransactionMismatch::VT_EXPECTED,
+                expected,
+            );
+        }
+        #[inline]
+        pub fn add_actual(&mut self, actual: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                TransactionMismatch::VT_ACTUAL,
+                actual,
+            );
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> TransactionMismatchBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            TransactionMismatchBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<TransactionMismatch<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for TransactionMismatch<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("TransactionMismatch");
+            ds.field("kind", &self.kind());
+            ds.field("address", &self.address());
+            ds.field("expected", &self.expected());
+            ds.field("actual", &self.actual());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct TransactionMismatchT {
+        pub kind: Option<String>,
+        pub address: Option<String>,
+        pub expected: Option<String>,
+        pub actual: Option<String>,
+    }
+    impl Default for TransactionMismatchT {
+        fn default() -> Self {
+            Self {
+                kind: None,
+                address: None,
+                expected: None,
+                actual: None,
+            }
+        }
+    }
+    impl TransactionMismatchT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<TransactionMismatch<'b>> {
+            let kind = self.kind.as_ref().map(|x| _fbb.create_string(x));
+            let address = self.address.as_ref().map(|x| _fbb.create_string(x));
+            let expected = self.expected.as_ref().map(|x| _fbb.create_string(x));
+            let actual = self.actual.as_ref().map(|x| _fbb.create_string(x));
+            TransactionMismatch::create(
+                _fbb,
+                &TransactionMismatchArgs {
+                    kind,
+                    address,
+                    expected,
+                    actual,
+                },
+            )
+        }
+    }
+    pub enum TransactionReviewOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct TransactionReview<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for TransactionReview<'a> {
+        type Inner = TransactionReview<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> TransactionReview<'a> {
+        pub const VT_TXID: flatbuffers::VOffsetT = 4;
+        pub const VT_FEE: flatbuffers::VOffsetT = 6;
+        pub const VT_MISMATCHES: flatbuffers::VOffsetT = 8;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            TransactionReview { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args TransactionReviewArgs<'args>,
+        ) -> flatbuffers::WIPOffset<TransactionReview<'bldr>> {
+            let mut builder = TransactionReviewBuilder::new(_fbb);
+            builder.add_fee(args.fee);
+            if let Some(x) = args.mismatches {
+                builder.add_mismatches(x);
+            }
+            if let Some(x) = args.txid {
+                builder.add_txid(x);
+            }
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> TransactionReviewT {
+            let txid = self.txid().map(|x| x.into_iter().collect());
+            let fee = self.fee();
+            let mismatches = self
+                .mismatches()
+                .map(|x| x.iter().map(|t| t.unpack()).collect());
+            TransactionReviewT {
+                txid,
+                fee,
+                mismatches,
+            }
+        }
+
+        #[inline]
+        pub fn txid(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        TransactionReview::VT_TXID,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn fee(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(TransactionReview::VT_FEE, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn mismatches(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TransactionMismatch<'a>>>>
+        {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TransactionMismatch>>,
+                >>(TransactionReview::VT_MISMATCHES, None)
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for TransactionReview<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "txid",
+                    Self::VT_TXID,
+                    false,
+                )?
+                .visit_field::<u64>("fee", Self::VT_FEE, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<TransactionMismatch>>,
+                >>("mismatches", Self::VT_MISMATCHES, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct TransactionReviewArgs<'a> {
+        pub txid: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub fee: u64,
+        pub mismatches: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TransactionMismatch<'a>>>,
+            >,
+        >,
+    }
+    impl<'a> Default for TransactionReviewArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            TransactionReviewArgs {
+                txid: None,
+                fee: 0,
+                mismatches: None,
+            }
+        }
+    }
+
+    pub struct TransactionReviewBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TransactionReviewBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_txid(&mut self, txid: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TransactionReview::VT_TXID, txid);
+        }
+        #[inline]
+        pub fn add_fee(&mut self, fee: u64) {
+            self.fbb_
+                .push_slot::<u64>(TransactionReview::VT_FEE, fee, 0);
+        }
+        #[inline]
+        pub fn add_mismatches(
+            &mut self,
+            mismatches: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<TransactionMismatch<'b>>>,
+            >,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                TransactionReview::VT_MISMATCHES,
+                mismatches,
+            );
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> TransactionReviewBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            TransactionReviewBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<TransactionReview<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for TransactionReview<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("TransactionReview");
+            ds.field("txid", &self.txid());
+            ds.field("fee", &self.fee());
+            ds.field("mismatches", &self.mismatches());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct TransactionReviewT {
+        pub txid: Option<Vec<u8>>,
+        pub fee: u64,
+        pub mismatches: Option<Vec<TransactionMismatchT>>,
+    }
+    impl Default for TransactionReviewT {
+        fn default() -> Self {
+            Self {
+                txid: None,
+                fee: 0,
+                mismatches: None,
+            }
+        }
+    }
+    impl TransactionReviewT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<TransactionReview<'b>> {
+            let txid = self.txid.as_ref().map(|x| _fbb.create_vector(x));
+            let fee = self.fee;
+            let mismatches = self.mismatches.as_ref().map(|x| {
+                let w: Vec<_> = x.iter().map(|t| t.pack(_fbb)).collect();
+                _fbb.create_vector(&w)
+            });
+            TransactionReview::create(
+                _fbb,
+                &TransactionReviewArgs {
+                    txid,
+                    fee,
+                    mismatches,
+                },
+            )
+        }
+    }
+    pub enum DraftOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct Draft<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for Draft<'a> {
+        type Inner = Draft<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> Draft<'a> {
+        pub const VT_ID: flatbuffers::VOffsetT = 4;
+        pub const VT_ACCOUNT: flatbuffers::VOffsetT = 6;
+        pub const VT_NAME: flatbuffers::VOffsetT = 8;
+        pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 10;
+        pub const VT_PAYMENT: flatbuffers::VOffsetT = 12;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            Draft { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args DraftArgs<'args>,
+        ) -> flatbuffers::WIPOffset<Draft<'bldr>> {
+            let mut builder = DraftBuilder::new(_fbb);
+            if let Some(x) = args.payment {
+                builder.add_payment(x);
+            }
+            builder.add_timestamp(args.timestamp);
+            if let Some(x) = args.name {
+                builder.add_name(x);
+            }
+            builder.add_account(args.account);
+            builder.add_id(args.id);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> DraftT {
+            let id = self.id();
+            let account = self.account();
+            let name = self.name().map(|x| x.to_string());
+            let timestamp = self.timestamp();
+            let payment = self.payment().map(|x| Box::new(x.unpack()));
+            DraftT {
+                id,
+                account,
+                name,
+                timestamp,
+                payment,
+            }
+        }
+
+        #[inline]
+        pub fn id(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(Draft::VT_ID, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn account(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(Draft::VT_ACCOUNT, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn name(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(Draft::VT_NAME, None)
+            }
+        }
+        #[inline]
+        pub fn timestamp(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(Draft::VT_TIMESTAMP, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn payment(&self) -> Option<PaymentRequest<'a>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<PaymentRequest>>(Draft::VT_PAYMENT, None)
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for Draft<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("id", Self::VT_ID, false)?
+                .visit_field::<u32>("account", Self::VT_ACCOUNT, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+                .visit_field::<u32>("timestamp", Self::VT_TIMESTAMP, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<PaymentRequest>>(
+                    "payment",
+                    Self::VT_PAYMENT,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct DraftArgs<'a> {
+        pub id: u32,
+        pub account: u32,
+        pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub timestamp: u32,
+        pub payment: Option<flatbuffers::WIPOffset<PaymentRequest<'a>>>,
+    }
+    impl<'a> Default for DraftArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            DraftArgs {
+                id: 0,
+                account: 0,
+                name: None,
+                timestamp: 0,
+                payment: None,
+            }
+        }
+    }
+
+    pub struct DraftBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DraftBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_id(&mut self, id: u32) {
+            self.fbb_.push_slot::<u32>(Draft::VT_ID, id, 0);
+        }
+        #[inline]
+        pub fn add_account(&mut self, account: u32) {
+            self.fbb_.push_slot::<u32>(Draft::VT_ACCOUNT, account, 0);
+        }
+        #[inline]
+        pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(Draft::VT_NAME, name);
+        }
+        #[inline]
+        pub fn add_timestamp(&mut self, timestamp: u32) {
+            self.fbb_
+                .push_slot::<u32>(Draft::VT_TIMESTAMP, timestamp, 0);
+        }
+        #[inline]
+        pub fn add_payment(&mut self, payment: flatbuffers::WIPOffset<PaymentRequest<'b>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<PaymentRequest>>(
+                    Draft::VT_PAYMENT,
+                    payment,
+                );
+        }
+        #[inline]
+        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DraftBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            DraftBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<Draft<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for Draft<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("Draft");
+            ds.field("id", &self.id());
+            ds.field("account", &self.account());
+            ds.field("name", &self.name());
+            ds.field("timestamp", &self.timestamp());
+            ds.field("payment", &self.payment());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct DraftT {
+        pub id: u32,
+        pub account: u32,
+        pub name: Option<String>,
+        pub timestamp: u32,
+        pub payment: Option<Box<PaymentRequestT>>,
+    }
+    impl Default for DraftT {
+        fn default() -> Self {
+            Self {
+                id: 0,
+                account: 0,
+                name: None,
+                timestamp: 0,
+                payment: None,
+            }
+        }
+    }
+    impl DraftT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<Draft<'b>> {
+            let id = self.id;
+            let account = self.account;
+            let name = self.name.as_ref().map(|x| _fbb.create_string(x));
+            let timestamp = self.timestamp;
+            let payment = self.payment.as_ref().map(|x| x.pack(_fbb));
+            Draft::create(
+                _fbb,
+                &DraftArgs {
+                    id,
+                    account,
+                    name,
+                    timestamp,
+                    payment,
+                },
+            )
+        }
+    }
+    pub enum WebhookOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct Webhook<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for Webhook<'a> {
+        type Inner = Webhook<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> Webhook<'a> {
+        pub const VT_URL: flatbuffers::VOffsetT = 4;
+        pub const VT_SECRET: flatbuffers::VOffsetT = 6;
+        pub const VT_CONFIRMATIONS: flatbuffers::VOffsetT = 8;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            Webhook { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args WebhookArgs<'args>,
+        ) -> flatbuffers::WIPOffset<Webhook<'bldr>> {
+            let mut builder = WebhookBuilder::new(_fbb);
+            builder.add_confirmations(args.confirmations);
+            if let Some(x) = args.secret {
+                builder.add_secret(x);
+            }
+            if let Some(x) = args.url {
+                builder.add_url(x);
+            }
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> WebhookT {
+            let url = self.url().map(|x| x.to_string());
+            let secret = self.secret().map(|x| x.to_string());
+            let confirmations = self.confirmations();
+            WebhookT {
+                url,
+                secret,
+                confirmations,
+            }
+        }
+
+        #[inline]
+        pub fn url(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(Webhook::VT_URL, None)
+            }
+        }
+        #[inline]
+        pub fn secret(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(Webhook::VT_SECRET, None)
+            }
+        }
+        #[inline]
+        pub fn confirmations(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(Webhook::VT_CONFIRMATIONS, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for Webhook<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("url", Self::VT_URL, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "secret",
+                    Self::VT_SECRET,
+                    false,
+                )?
+                .visit_field::<u32>("confirmations", Self::VT_CONFIRMATIONS, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct WebhookArgs<'a> {
+        pub url: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub secret: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub confirmations: u32,
+    }
+    impl<'a> Default for WebhookArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            WebhookArgs {
+                url: None,
+                secret: None,
+                confirmations: 0,
+            }
+        }
+    }
+
+    pub struct WebhookBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> WebhookBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_url(&mut self, url: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(Webhook::VT_URL, url);
+        }
+        #[inline]
+        pub fn add_secret(&mut self, secret: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(Webhook::VT_SECRET, secret);
+        }
+        #[inline]
+        pub fn add_confirmations(&mut self, confirmations: u32) {
+            self.fbb_
+                .push_slot::<u32>(Webhook::VT_CONFIRMATIONS, confirmations, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> WebhookBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            WebhookBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<Webhook<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for Webhook<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("Webhook");
+            ds.field("url", &self.url());
+            ds.field("secret", &self.secret());
+            ds.field("confirmations", &self.confirmations());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct WebhookT {
+        pub url: Option<String>,
+        pub secret: Option<String>,
+        pub confirmations: u32,
+    }
+    impl Default for WebhookT {
+        fn default() -> Self {
+            Self {
+                url: None,
+                secret: None,
+                confirmations: 0,
+            }
+        }
+    }
+    impl WebhookT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<Webhook<'b>> {
+            let url = self.url.as_ref().map(|x| _fbb.create_string(x));
+            let secret = self.secret.as_ref().map(|x| _fbb.create_string(x));
+            let confirmations = self.confirmations;
+            Webhook::create(
+                _fbb,
+                &WebhookArgs {
+                    url,
+                    secret,
+                    confirmations,
+                },
+            )
+        }
+    }
+    pub enum AddressWarningOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct AddressWarning<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for AddressWarning<'a> {
+        type Inner = AddressWarning<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> AddressWarning<'a> {
+        pub const VT_ADDRESS: flatbuffers::VOffsetT = 4;
+        pub const VT_SIMILAR: flatbuffers::VOffsetT = 6;
+        pub const VT_SOURCE: flatbuffers::VOffsetT = 8;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            AddressWarning { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args AddressWarningArgs<'args>,
+        ) -> flatbuffers::WIPOffset<AddressWarning<'bldr>> {
+            let mut builder = AddressWarningBuilder::new(_fbb);
+            if let Some(x) = args.source {
+                builder.add_source(x);
+            }
+            if let Some(x) = args.similar {
+                builder.add_similar(x);
+            }
+            if let Some(x) = args.address {
+                builder.add_address(x);
+            }
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> AddressWarningT {
+            let address = self.address().map(|x| x.to_string());
+            let similar = self.similar().map(|x| x.to_string());
+            let source = self.source().map(|x| x.to_string());
+            AddressWarningT {
+                address,
+                similar,
+                source,
+            }
+        }
+
+        #[inline]
+        pub fn address(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(AddressWarning::VT_ADDRESS, None)
+            }
+        }
+        #[inline]
+        pub fn similar(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(AddressWarning::VT_SIMILAR, None)
+            }
+        }
+        #[inline]
+        pub fn source(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(AddressWarning::VT_SOURCE, None)
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for AddressWarning<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "address",
+                    Self::VT_ADDRESS,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "similar",
+                    Self::VT_SIMILAR,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "source",
+                    Self::VT_SOURCE,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct AddressWarningArgs<'a> {
+        pub address: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub similar: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub source: Option<flatbuffers::WIPOffset<&'a str>>,
+    }
+    impl<'a> Default for AddressWarningArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            AddressWarningArgs {
+                address: None,
+                similar: None,
+                source: None,
+            }
+        }
+    }
+
+    pub struct AddressWarningBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AddressWarningBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_address(&mut self, address: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(AddressWarning::VT_ADDRESS, address);
+        }
+        #[inline]
+        pub fn add_similar(&mut self, similar: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(AddressWarning::VT_SIMILAR, similar);
+        }
+        #[inline]
+        pub fn add_source(&mut self, source: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(AddressWarning::VT_SOURCE, source);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> AddressWarningBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            AddressWarningBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<AddressWarning<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for AddressWarning<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("AddressWarning");
+            ds.field("address", &self.address());
+            ds.field("similar", &self.similar());
+            ds.field("source", &self.source());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct AddressWarningT {
+        pub address: Option<String>,
+        pub similar: Option<String>,
+        pub source: Option<String>,
+    }
+    impl Default for AddressWarningT {
+        fn default() -> Self {
+            Self {
+                address: None,
+                similar: None,
+                source: None,
+            }
+        }
+    }
+    impl AddressWarningT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<AddressWarning<'b>> {
+            let address = self.address.as_ref().map(|x| _fbb.create_string(x));
+            let similar = self.similar.as_ref().map(|x| _fbb.create_string(x));
+            let source = self.source.as_ref().map(|x| _fbb.create_string(x));
+            AddressWarning::create(
+                _fbb,
+                &AddressWarningArgs {
+                    address,
+                    similar,
+                    source,
+                },
+            )
+        }
+    }
+    pub enum AccountBalanceOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct AccountBalance<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for AccountBalance<'a> {
+        type Inner = AccountBalance<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> AccountBalance<'a> {
+        pub const VT_ACCOUNT: flatbuffers::VOffsetT = 4;
+        pub const VT_NAME: flatbuffers::VOffsetT = 6;
+        pub const VT_BALANCE: flatbuffers::VOffsetT = 8;
+        pub const VT_UNCONFIRMED: flatbuffers::VOffsetT = 10;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            AccountBalance { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args AccountBalanceArgs<'args>,
+        ) -> flatbuffers::WIPOffset<AccountBalance<'bldr>> {
+            let mut builder = AccountBalanceBuilder::new(_fbb);
+            builder.add_unconfirmed(args.unconfirmed);
+            if let Some(x) = args.balance {
+                builder.add_balance(x);
+            }
+            if let Some(x) = args.name {
+                builder.add_name(x);
+            }
+            builder.add_account(args.account);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> AccountBalanceT {
+            let account = self.account();
+            let name = self.name().map(|x| x.to_string());
+            let balance = self.balance().map(|x| Box::new(x.unpack()));
+            let unconfirmed = self.unconfirmed();
+            AccountBalanceT {
+                account,
+                name,
+                balance,
+                unconfirmed,
+            }
+        }
+
+        #[inline]
+        pub fn account(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(AccountBalance::VT_ACCOUNT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn name(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(AccountBalance::VT_NAME, None)
+            }
+        }
+        #[inline]
+        pub fn balance(&self) -> Option<Balance<'a>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<Balance>>(AccountBalance::VT_BALANCE, None)
+            }
+        }
+        #[inline]
+        pub fn unconfirmed(&self) -> i64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<i64>(AccountBalance::VT_UNCONFIRMED, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for AccountBalance<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("account", Self::VT_ACCOUNT, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<Balance>>(
+                    "balance",
+                    Self::VT_BALANCE,
+                    false,
+                )?
+                .visit_field::<i64>("unconfirmed", Self::VT_UNCONFIRMED, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct AccountBalanceArgs<'a> {
+        pub account: u32,
+        pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub balance: Option<flatbuffers::WIPOffset<Balance<'a>>>,
+        pub unconfirmed: i64,
+    }
+    impl<'a> Default for AccountBalanceArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            AccountBalanceArgs {
+                account: 0,
+                name: None,
+                balance: None,
+                unconfirmed: 0,
+            }
+        }
+    }
+
+    pub struct AccountBalanceBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AccountBalanceBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_account(&mut self, account: u32) {
+            self.fbb_
+                .push_slot::<u32>(AccountBalance::VT_ACCOUNT, account, 0);
+        }
+        #[inline]
+        pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(AccountBalance::VT_NAME, name);
+        }
+        #[inline]
+        pub fn add_balance(&mut self, balance: flatbuffers::WIPOffset<Balance<'b>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<Balance>>(
+                    AccountBalance::VT_BALANCE,
+                    balance,
+                );
+        }
+        #[inline]
+        pub fn add_unconfirmed(&mut self, unconfirmed: i64) {
+            self.fbb_
+                .push_slot::<i64>(AccountBalance::VT_UNCONFIRMED, unconfirmed, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> AccountBalanceBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            AccountBalanceBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<AccountBalance<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for AccountBalance<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("AccountBalance");
+            ds.field("account", &self.account());
+            ds.field("name", &self.name());
+            ds.field("balance", &self.balance());
+            ds.field("unconfirmed", &self.unconfirmed());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct AccountBalanceT {
+        pub account: u32,
+        pub name: Option<String>,
+        pub balance: Option<Box<BalanceT>>,
+        pub unconfirmed: i64,
+    }
+    impl Default for AccountBalanceT {
+        fn default() -> Self {
+            Self {
+                account: 0,
+                name: None,
+                balance: None,
+                unconfirmed: 0,
+            }
+        }
+    }
+    impl AccountBalanceT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<AccountBalance<'b>> {
+            let account = self.account;
+            let name = self.name.as_ref().map(|x| _fbb.create_string(x));
+            let balance = self.balance.as_ref().map(|x| x.pack(_fbb));
+            let unconfirmed = self.unconfirmed;
+            AccountBalance::create(
+                _fbb,
+                &AccountBalanceArgs {
+                    account,
+                    name,
+                    balance,
+                    unconfirmed,
+                },
+            )
+        }
+    }
+    pub enum WalletSummaryOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct WalletSummary<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for WalletSummary<'a> {
+        type Inner = WalletSummary<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> WalletSummary<'a> {
+        pub const VT_HEIGHT: flatbuffers::VOffsetT = 4;
+        pub const VT_TOTAL: flatbuffers::VOffsetT = 6;
+        pub const VT_UNCONFIRMED: flatbuffers::VOffsetT = 8;
+        pub const VT_ACCOUNTS: flatbuffers::VOffsetT = 10;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            WalletSummary { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args WalletSummaryArgs<'args>,
+        ) -> flatbuffers::WIPOffset<WalletSummary<'bldr>> {
+            let mut builder = WalletSummaryBuilder::new(_fbb);
+            builder.add_unconfirmed(args.unconfirmed);
+            if let Some(x) = args.accounts {
+                builder.add_accounts(x);
+            }
+            if let Some(x) = args.total {
+                builder.add_total(x);
+            }
+            builder.add_height(args.height);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> WalletSummaryT {
+            let height = self.height();
+            let total = self.total().map(|x| Box::new(x.unpack()));
+            let unconfirmed = self.unconfirmed();
+            let accounts = self
+                .accounts()
+                .map(|x| x.iter().map(|t| t.unpack()).collect());
+            WalletSummaryT {
+                height,
+                total,
+                unconfirmed,
+                accounts,
+            }
+        }
+
+        #[inline]
+        pub fn height(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(WalletSummary::VT_HEIGHT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn total(&self) -> Option<Balance<'a>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<Balance>>(WalletSummary::VT_TOTAL, None)
+            }
+        }
+        #[inline]
+        pub fn unconfirmed(&self) -> i64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<i64>(WalletSummary::VT_UNCONFIRMED, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn accounts(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AccountBalance<'a>>>>
+        {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AccountBalance>>,
+                >>(WalletSummary::VT_ACCOUNTS, None)
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for WalletSummary<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("height", Self::VT_HEIGHT, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<Balance>>(
+                    "total",
+                    Self::VT_TOTAL,
+                    false,
+                )?
+                .visit_field::<i64>("unconfirmed", Self::VT_UNCONFIRMED, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<AccountBalance>>,
+                >>("accounts", Self::VT_ACCOUNTS, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct WalletSummaryArgs<'a> {
+        pub height: u32,
+        pub total: Option<flatbuffers::WIPOffset<Balance<'a>>>,
+        pub unconfirmed: i64,
+        pub accounts: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AccountBalance<'a>>>,
+            >,
+        >,
+    }
+    impl<'a> Default for WalletSummaryArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            WalletSummaryArgs {
+                height: 0,
+                total: None,
+                unconfirmed: 0,
+                accounts: None,
+            }
+        }
+    }
+
+    pub struct WalletSummaryBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> WalletSummaryBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_height(&mut self, height: u32) {
+            self.fbb_
+                .push_slot::<u32>(WalletSummary::VT_HEIGHT, height, 0);
+        }
+        #[inline]
+        pub fn add_total(&mut self, total: flatbuffers::WIPOffset<Balance<'b>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<Balance>>(
+                    WalletSummary::VT_TOTAL,
+                    total,
+                );
+        }
+        #[inline]
+        pub fn add_unconfirmed(&mut self, unconfirmed: i64) {
+            self.fbb_
+                .push_slot::<i64>(WalletSummary::VT_UNCONFIRMED, unconfirmed, 0);
+        }
+        #[inline]
+        pub fn add_accounts(
+            &mut self,
+            accounts: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<AccountBalance<'b>>>,
+            >,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                WalletSummary::VT_ACCOUNTS,
+                accounts,
+            );
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> WalletSummaryBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            WalletSummaryBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<WalletSummary<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for WalletSummary<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("WalletSummary");
+            ds.field("height", &self.height());
+            ds.field("total", &self.total());
+            ds.field("unconfirmed", &self.unconfirmed());
+            ds.field("accounts", &self.accounts());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct WalletSummaryT {
+        pub height: u32,
+        pub total: Option<Box<BalanceT>>,
+        pub unconfirmed: i64,
+        pub accounts: Option<Vec<AccountBalanceT>>,
+    }
+    impl Default for WalletSummaryT {
+        fn default() -> Self {
+            Self {
+                height: 0,
+                total: None,
+                unconfirmed: 0,
+                accounts: None,
+            }
+        }
+    }
+    impl WalletSummaryT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<WalletSummary<'b>> {
+            let height = self.height;
+            let total = self.total.as_ref().map(|x| x.pack(_fbb));
+            let unconfirmed = self.unconfirmed;
+            let accounts = self.accounts.as_ref().map(|x| {
+                let w: Vec<_> = x.iter().map(|t| t.pack(_fbb)).collect();
+                _fbb.create_vector(&w)
+            });
+            WalletSummary::create(
+                _fbb,
+                &WalletSummaryArgs {
+                    height,
+                    total,
+                    unconfirmed,
+                    accounts,
+                },
+            )
+        }
+    }
+    pub enum ExpiredTxOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct ExpiredTx<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for ExpiredTx<'a> {
+        type Inner = ExpiredTx<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> ExpiredTx<'a> {
+        pub const VT_ID: flatbuffers::VOffsetT = 4;
+        pub const VT_ACCOUNT: flatbuffers::VOffsetT = 6;
+        pub const VT_TXID: flatbuffers::VOffsetT = 8;
+        pub const VT_HEIGHT: flatbuffers::VOffsetT = 10;
+        pub const VT_EXPIRATION: flatbuffers::VOffsetT = 12;
+        pub const VT_EXPIRED_HEIGHT: flatbuffers::VOffsetT = 14;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            ExpiredTx { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args ExpiredTxArgs<'args>,
+        ) -> flatbuffers::WIPOffset<ExpiredTx<'bldr>> {
+            let mut builder = ExpiredTxBuilder::new(_fbb);
+            builder.add_expired_height(args.expired_height);
+            builder.add_expiration(args.expiration);
+            builder.add_height(args.height);
+            if let Some(x) = args.txid {
+                builder.add_txid(x);
+            }
+            builder.add_account(args.account);
+            builder.add_id(args.id);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> ExpiredTxT {
+            let id = self.id();
+            let account = self.account();
+            let txid = self.txid().map(|x| x.into_iter().collect());
+            let height = self.height();
+            let expiration = self.expiration();
+            let expired_height = self.expired_height();
+            ExpiredTxT {
+                id,
+                account,
+                txid,
+                height,
+                expiration,
+                expired_height,
+            }
+        }
+
+        #[inline]
+        pub fn id(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(ExpiredTx::VT_ID, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn account(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(ExpiredTx::VT_ACCOUNT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn txid(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        ExpiredTx::VT_TXID,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn height(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(ExpiredTx::VT_HEIGHT, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn expiration(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(ExpiredTx::VT_EXPIRATION, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn expired_height(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(ExpiredTx::VT_EXPIRED_HEIGHT, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for ExpiredTx<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("id", Self::VT_ID, false)?
+                .visit_field::<u32>("account", Self::VT_ACCOUNT, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "txid",
+                    Self::VT_TXID,
+                    false,
+                )?
+                .visit_field::<u32>("height", Self::VT_HEIGHT, false)?
+                .visit_field::<u32>("expiration", Self::VT_EXPIRATION, false)?
+                .visit_field::<u32>("expired_height", Self::VT_EXPIRED_HEIGHT, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct ExpiredTxArgs<'a> {
+        pub id: u32,
+        pub account: u32,
+        pub txid: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub height: u32,
+        pub expiration: u32,
+        pub expired_height: u32,
+    }
+    impl<'a> Default for ExpiredTxArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            ExpiredTxArgs {
+                id: 0,
+                account: 0,
+                txid: None,
+                height: 0,
+                expiration: 0,
+                expired_height: 0,
+            }
+        }
+    }
+
+    pub struct ExpiredTxBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ExpiredTxBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_id(&mut self, id: u32) {
+            self.fbb_.push_slot::<u32>(ExpiredTx::VT_ID, id, 0);
+        }
+        #[inline]
+        pub fn add_account(&mut self, account: u32) {
+            self.fbb_
+                .push_slot::<u32>(ExpiredTx::VT_ACCOUNT, account, 0);
+        }
+        #[inline]
+        pub fn add_txid(&mut self, txid: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(ExpiredTx::VT_TXID, txid);
+        }
+        #[inline]
+        pub fn add_height(&mut self, height: u32) {
+            self.fbb_.push_slot::<u32>(ExpiredTx::VT_HEIGHT, height, 0);
+        }
+        #[inline]
+        pub fn add_expiration(&mut self, expiration: u32) {
+            self.fbb_
+                .push_slot::<u32>(ExpiredTx::VT_EXPIRATION, expiration, 0);
+        }
+        #[inline]
+        pub fn add_expired_height(&mut self, expired_height: u32) {
+            self.fbb_
+                .push_slot::<u32>(ExpiredTx::VT_EXPIRED_HEIGHT, expired_height, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> ExpiredTxBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            ExpiredTxBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<ExpiredTx<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for ExpiredTx<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("ExpiredTx");
+            ds.field("id", &self.id());
+            ds.field("account", &self.account());
+            ds.field("txid", &self.txid());
+            ds.field("height", &self.height());
+            ds.field("expiration", &self.expiration());
+            ds.field("expired_height", &self.expired_height());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct ExpiredTxT {
+        pub id: u32,
+        pub account: u32,
+        pub txid: Option<Vec<u8>>,
+        pub height: u32,
+        pub expiration: u32,
+        pub expired_height: u32,
+    }
+    impl Default for ExpiredTxT {
+        fn default() -> Self {
+            Self {
+                id: 0,
+                account: 0,
+                txid: None,
+                height: 0,
+                expiration: 0,
+                expired_height: 0,
+            }
+        }
+    }
+    impl ExpiredTxT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<ExpiredTx<'b>> {
+            let id = self.id;
+            let account = self.account;
+            let txid = self.txid.as_ref().map(|x| _fbb.create_vector(x));
+            let height = self.height;
+            let expiration = self.expiration;
+            let expired_height = self.expired_height;
+            ExpiredTx::create(
+                _fbb,
+                &ExpiredTxArgs {
+                    id,
+                    account,
+                    txid,
+                    height,
+                    expiration,
+                    expired_height,
+                },
+            )
+        }
+    }
+    pub enum SpendAuthRequestOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct SpendAuthRequest<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for SpendAuthRequest<'a> {
+        type Inner = SpendAuthRequest<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> SpendAuthRequest<'a> {
+        pub const VT_INDEX: flatbuffers::VOffsetT = 4;
+        pub const VT_RK: flatbuffers::VOffsetT = 6;
+        pub const VT_ALPHA: flatbuffers::VOffsetT = 8;
+        pub const VT_SIGHASH: flatbuffers::VOffsetT = 10;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            SpendAuthRequest { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args SpendAuthRequestArgs<'args>,
+        ) -> flatbuffers::WIPOffset<SpendAuthRequest<'bldr>> {
+            let mut builder = SpendAuthRequestBuilder::new(_fbb);
+            if let Some(x) = args.sighash {
+                builder.add_sighash(x);
+            }
+            if let Some(x) = args.alpha {
+                builder.add_alpha(x);
+            }
+            if let Some(x) = args.rk {
+                builder.add_rk(x);
+            }
+            builder.add_index(args.index);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> SpendAuthRequestT {
+            let index = self.index();
+            let rk = self.rk().map(|x| x.into_iter().collect());
+            let alpha = self.alpha().map(|x| x.into_iter().collect());
+            let sighash = self.sighash().map(|x| x.into_iter().collect());
+            SpendAuthRequestT {
+                index,
+                rk,
+                alpha,
+                sighash,
+            }
+        }
+
+        #[inline]
+        pub fn index(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(SpendAuthRequest::VT_INDEX, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn rk(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        SpendAuthRequest::VT_RK,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn alpha(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        SpendAuthRequest::VT_ALPHA,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn sighash(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        SpendAuthRequest::VT_SIGHASH,
+                        None,
+                    )
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for SpendAuthRequest<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("index", Self::VT_INDEX, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "rk",
+                    Self::VT_RK,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "alpha",
+                    Self::VT_ALPHA,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "sighash",
+                    Self::VT_SIGHASH,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct SpendAuthRequestArgs<'a> {
+        pub index: u32,
+        pub rk: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub alpha: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub sighash: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    }
+    impl<'a> Default for SpendAuthRequestArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            SpendAuthRequestArgs {
+                index: 0,
+                rk: None,
+                alpha: None,
+                sighash: None,
+            }
+        }
+    }
+
+    pub struct SpendAuthRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SpendAuthRequestBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_index(&mut self, index: u32) {
+            self.fbb_
+                .push_slot::<u32>(SpendAuthRequest::VT_INDEX, index, 0);
+        }
+        #[inline]
+        pub fn add_rk(&mut self, rk: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(SpendAuthRequest::VT_RK, rk);
+        }
+        #[inline]
+        pub fn add_alpha(&mut self, alpha: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(SpendAuthRequest::VT_ALPHA, alpha);
+        }
+        #[inline]
+        pub fn add_sighash(
+            &mut self,
+            sighash: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                SpendAuthRequest::VT_SIGHASH,
+                sighash,
+            );
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> SpendAuthRequestBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            SpendAuthRequestBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<SpendAuthRequest<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for SpendAuthRequest<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("SpendAuthRequest");
+            ds.field("index", &self.index());
+            ds.field("rk", &self.rk());
+            ds.field("alpha", &self.alpha());
+            ds.field("sighash", &self.sighash());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct SpendAuthRequestT {
+        pub index: u32,
+        pub rk: Option<Vec<u8>>,
+        pub alpha: Option<Vec<u8>>,
+        pub sighash: Option<Vec<u8>>,
+    }
+    impl Default for SpendAuthRequestT {
+        fn default() -> Self {
+            Self {
+                index: 0,
+                rk: None,
+                alpha: None,
+                sighash: None,
+            }
+        }
+    }
+    impl SpendAuthRequestT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<SpendAuthRequest<'b>> {
+            let index = self.index;
+            let rk = self.rk.as_ref().map(|x| _fbb.create_vector(x));
+            let alpha = self.alpha.as_ref().map(|x| _fbb.create_vector(x));
+            let sighash = self.sighash.as_ref().map(|x| _fbb.create_vector(x));
+            SpendAuthRequest::create(
+                _fbb,
+                &SpendAuthRequestArgs {
+                    index,
+                    rk,
+                    alpha,
+                    sighash,
+                },
+            )
+        }
+    }
+    pub enum ExternalSigningRequestOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct ExternalSigningRequest<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for ExternalSigningRequest<'a> {
+        type Inner = ExternalSigningRequest<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> ExternalSigningRequest<'a> {
+        pub const VT_SESSION: flatbuffers::VOffsetT = 4;
+        pub const VT_SIGHASH: flatbuffers::VOffsetT = 6;
+        pub const VT_SPENDS: flatbuffers::VOffsetT = 8;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            ExternalSigningRequest { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args ExternalSigningRequestArgs<'args>,
+        ) -> flatbuffers::WIPOffset<ExternalSigningRequest<'bldr>> {
+            let mut builder = ExternalSigningRequestBuilder::new(_fbb);
+            if let Some(x) = args.spends {
+                builder.add_spends(x);
+            }
+            if let Some(x) = args.sighash {
+                builder.add_sighash(x);
+            }
+            builder.add_session(args.session);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> ExternalSigningRequestT {
+            let session = self.session();
+            let sighash = self.sighash().map(|x| x.into_iter().collect());
+            let spends = self
+                .spends()
+                .map(|x| x.iter().map(|t| t.unpack()).collect());
+            ExternalSigningRequestT {
+                session,
+                sighash,
+                spends,
+            }
+        }
+
+        #[inline]
+        pub fn session(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(ExternalSigningRequest::VT_SESSION, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn sighash(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        ExternalSigningRequest::VT_SIGHASH,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn spends(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SpendAuthRequest<'a>>>>
+        {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SpendAuthRequest>>,
+                >>(ExternalSigningRequest::VT_SPENDS, None)
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for ExternalSigningRequest<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("session", Self::VT_SESSION, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "sighash",
+                    Self::VT_SIGHASH,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<SpendAuthRequest>>,
+                >>("spends", Self::VT_SPENDS, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct ExternalSigningRequestArgs<'a> {
+        pub session: u32,
+        pub sighash: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub spends: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SpendAuthRequest<'a>>>,
+            >,
+        >,
+    }
+    impl<'a> Default for ExternalSigningRequestArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            ExternalSigningRequestArgs {
+                session: 0,
+                sighash: None,
+                spends: None,
+            }
+        }
+    }
+
+    pub struct ExternalSigningRequestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ExternalSigningRequestBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_session(&mut self, session: u32) {
+            self.fbb_
+                .push_slot::<u32>(ExternalSigningRequest::VT_SESSION, session, 0);
+        }
+        #[inline]
+        pub fn add_sighash(
+            &mut self,
+            sighash: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                ExternalSigningRequest::VT_SIGHASH,
+                sighash,
+            );
+        }
+        #[inline]
+        pub fn add_spends(
+            &mut self,
+            spends: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<SpendAuthRequest<'b>>>,
+            >,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                ExternalSigningRequest::VT_SPENDS,
+                spends,
+            );
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> ExternalSigningRequestBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            ExternalSigningRequestBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<ExternalSigningRequest<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for ExternalSigningRequest<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("ExternalSigningRequest");
+            ds.field("session", &self.session());
+            ds.field("sighash", &self.sighash());
+            ds.field("spends", &self.spends());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct ExternalSigningRequestT {
+        pub session: u32,
+        pub sighash: Option<Vec<u8>>,
+        pub spends: Option<Vec<SpendAuthRequestT>>,
+    }
+    impl Default for ExternalSigningRequestT {
+        fn default() -> Self {
+            Self {
+                session: 0,
+                sighash: None,
+                spends: None,
+            }
+        }
+    }
+    impl ExternalSigningRequestT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<ExternalSigningRequest<'b>> {
+            let session = self.session;
+            let sighash = self.sighash.as_ref().map(|x| _fbb.create_vector(x));
+            let spends = self.spends.as_ref().map(|x| {
+                let w: Vec<_> = x.iter().map(|t| t.pack(_fbb)).collect();
+                _fbb.create_vector(&w)
+            });
+            ExternalSigningRequest::create(
+                _fbb,
+                &ExternalSigningRequestArgs {
+                    session,
+                    sighash,
+                    spends,
+                },
+            )
+        }
+    }
+    pub enum UpgradeStatusOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct UpgradeStatus<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for UpgradeStatus<'a> {
+        type Inner = UpgradeStatus<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> UpgradeStatus<'a> {
+        pub const VT_HEIGHT: flatbuffers::VOffsetT = 4;
+        pub const VT_BRANCH_ID: flatbuffers::VOffsetT = 6;
+        pub const VT_SERVER_BRANCH_ID: flatbuffers::VOffsetT = 8;
+        pub const VT_NEXT_UPGRADE: flatbuffers::VOffsetT = 10;
+        pub const VT_ACTIVATION_HEIGHT: flatbuffers::VOffsetT = 12;
+        pub const VT_BLOCKS_REMAINING: flatbuffers::VOffsetT = 14;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            UpgradeStatus { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args UpgradeStatusArgs<'args>,
+        ) -> flatbuffers::WIPOffset<UpgradeStatus<'bldr>> {
+            let mut builder = UpgradeStatusBuilder::new(_fbb);
+            builder.add_blocks_remaining(args.blocks_remaining);
+            builder.add_activation_height(args.activation_height);
+            if let Some(x) = args.next_upgrade {
+                builder.add_next_upgrade(x);
+            }
+            builder.add_server_branch_id(args.server_branch_id);
+            builder.add_branch_id(args.branch_id);
+            builder.add_height(args.height);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> UpgradeStatusT {
+            let height = self.height();
+            let branch_id = self.branch_id();
+            let server_branch_id = self.server_branch_id();
+            let next_upgrade = self.next_upgrade().map(|x| x.to_string());
+            let activation_height = self.activation_height();
+            let blocks_remaining = self.blocks_remaining();
+            UpgradeStatusT {
+                height,
+                branch_id,
+                server_branch_id,
+                next_upgrade,
+                activation_height,
+                blocks_remaining,
+            }
+        }
+
+        #[inline]
+        pub fn height(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(UpgradeStatus::VT_HEIGHT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn branch_id(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(UpgradeStatus::VT_BRANCH_ID, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn server_branch_id(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(UpgradeStatus::VT_SERVER_BRANCH_ID, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn next_upgrade(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(UpgradeStatus::VT_NEXT_UPGRADE, None)
+            }
+        }
+        #[inline]
+        pub fn activation_height(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(UpgradeStatus::VT_ACTIVATION_HEIGHT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn blocks_remaining(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(UpgradeStatus::VT_BLOCKS_REMAINING, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for UpgradeStatus<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("height", Self::VT_HEIGHT, false)?
+                .visit_field::<u32>("branch_id", Self::VT_BRANCH_ID, false)?
+                .visit_field::<u32>("server_branch_id", Self::VT_SERVER_BRANCH_ID, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "next_upgrade",
+                    Self::VT_NEXT_UPGRADE,
+                    false,
+                )?
+                .visit_field::<u32>("activation_height", Self::VT_ACTIVATION_HEIGHT, false)?
+                .visit_field::<u32>("blocks_remaining", Self::VT_BLOCKS_REMAINING, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct UpgradeStatusArgs<'a> {
+        pub height: u32,
+        pub branch_id: u32,
+        pub server_branch_id: u32,
+        pub next_upgrade: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub activation_height: u32,
+        pub blocks_remaining: u32,
+    }
+    impl<'a> Default for UpgradeStatusArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            UpgradeStatusArgs {
+                height: 0,
+                branch_id: 0,
+                server_branch_id: 0,
+                next_upgrade: None,
+                activation_height: 0,
+                blocks_remaining: 0,
+            }
+        }
+    }
+
+    pub struct UpgradeStatusBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> UpgradeStatusBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_height(&mut self, height: u32) {
+            self.fbb_
+                .push_slot::<u32>(UpgradeStatus::VT_HEIGHT, height, 0);
+        }
+        #[inline]
+        pub fn add_branch_id(&mut self, branch_id: u32) {
+            self.fbb_
+                .push_slot::<u32>(UpgradeStatus::VT_BRANCH_ID, branch_id, 0);
+        }
+        #[inline]
+        pub fn add_server_branch_id(&mut self, server_branch_id: u32) {
+            self.fbb_
+                .push_slot::<u32>(UpgradeStatus::VT_SERVER_BRANCH_ID, server_branch_id, 0);
+        }
+        #[inline]
+        pub fn add_next_upgrade(&mut self, next_upgrade: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                UpgradeStatus::VT_NEXT_UPGRADE,
+                next_upgrade,
+            );
+        }
+        #[inline]
+        pub fn add_activation_height(&mut self, activation_height: u32) {
+            self.fbb_
+                .push_slot::<u32>(UpgradeStatus::VT_ACTIVATION_HEIGHT, activation_height, 0);
+        }
+        #[inline]
+        pub fn add_blocks_remaining(&mut self, blocks_remaining: u32) {
+            self.fbb_
+                .push_slot::<u32>(UpgradeStatus::VT_BLOCKS_REMAINING, blocks_remaining, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> UpgradeStatusBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            UpgradeStatusBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<UpgradeStatus<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for UpgradeStatus<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("UpgradeStatus");
+            ds.field("height", &self.height());
+            ds.field("branch_id", &self.branch_id());
+            ds.field("server_branch_id", &self.server_branch_id());
+            ds.field("next_upgrade", &self.next_upgrade());
+            ds.field("activation_height", &self.activation_height());
+            ds.field("blocks_remaining", &self.blocks_remaining());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct UpgradeStatusT {
+        pub height: u32,
+        pub branch_id: u32,
+        pub server_branch_id: u32,
+        pub next_upgrade: Option<String>,
+        pub activation_height: u32,
+        pub blocks_remaining: u32,
+    }
+    impl Default for UpgradeStatusT {
+        fn default() -> Self {
+            Self {
+                height: 0,
+                branch_id: 0,
+                server_branch_id: 0,
+                next_upgrade: None,
+                activation_height: 0,
+                blocks_remaining: 0,
+            }
+        }
+    }
+    impl UpgradeStatusT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<UpgradeStatus<'b>> {
+            let height = self.height;
+            let branch_id = self.branch_id;
+            let server_branch_id = self.server_branch_id;
+            let next_upgrade = self.next_upgrade.as_ref().map(|x| _fbb.create_string(x));
+            let activation_height = self.activation_height;
+            let blocks_remaining = self.blocks_remaining;
+            UpgradeStatus::create(
+                _fbb,
+                &UpgradeStatusArgs {
+                    height,
+                    branch_id,
+                    server_branch_id,
+                    next_upgrade,
+                    activation_height,
+                    blocks_remaining,
+                },
+            )
+        }
+    }
+    pub enum AcknowledgmentOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct Acknowledgment<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for Acknowledgment<'a> {
+        type Inner = Acknowledgment<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> Acknowledgment<'a> {
+        pub const VT_ID: flatbuffers::VOffsetT = 4;
+        pub const VT_ACCOUNT: flatbuffers::VOffsetT = 6;
+        pub const VT_ID_MSG: flatbuffers::VOffsetT = 8;
+        pub const VT_TXID: flatbuffers::VOffsetT = 10;
+        pub const VT_ADDRESS: flatbuffers::VOffsetT = 12;
+        pub const VT_HEIGHT: flatbuffers::VOffsetT = 14;
+        pub const VT_STATUS: flatbuffers::VOffsetT = 16;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            Acknowledgment { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args AcknowledgmentArgs<'args>,
+        ) -> flatbuffers::WIPOffset<Acknowledgment<'bldr>> {
+            let mut builder = AcknowledgmentBuilder::new(_fbb);
+            builder.add_height(args.height);
+            if let Some(x) = args.address {
+                builder.add_address(x);
+            }
+            if let Some(x) = args.txid {
+                builder.add_txid(x);
+            }
+            builder.add_id_msg(args.id_msg);
+            builder.add_account(args.account);
+            builder.add_id(args.id);
+            builder.add_status(args.status);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> AcknowledgmentT {
+            let id = self.id();
+            let account = self.account();
+            let id_msg = self.id_msg();
+            let txid = self.txid().map(|x| x.into_iter().collect());
+            let address = self.address().map(|x| x.to_string());
+            let height = self.height();
+            let status = self.status();
+            AcknowledgmentT {
+                id,
+                account,
+                id_msg,
+                txid,
+                address,
+                height,
+                status,
+            }
+        }
+
+        #[inline]
+        pub fn id(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(Acknowledgment::VT_ID, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn account(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(Acknowledgment::VT_ACCOUNT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn id_msg(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(Acknowledgment::VT_ID_MSG, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn txid(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        Acknowledgment::VT_TXID,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn address(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(Acknowledgment::VT_ADDRESS, None)
+            }
+        }
+        #[inline]
+        pub fn height(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(Acknowledgment::VT_HEIGHT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn status(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(Acknowledgment::VT_STATUS, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for Acknowledgment<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("id", Self::VT_ID, false)?
+                .visit_field::<u32>("account", Self::VT_ACCOUNT, false)?
+                .visit_field::<u32>("id_msg", Self::VT_ID_MSG, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "txid",
+                    Self::VT_TXID,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "address",
+                    Self::VT_ADDRESS,
+                    false,
+                )?
+                .visit_field::<u32>("height", Self::VT_HEIGHT, false)?
+                .visit_field::<u8>("status", Self::VT_STATUS, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct AcknowledgmentArgs<'a> {
+        pub id: u32,
+        pub account: u32,
+        pub id_msg: u32,
+        pub txid: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub address: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub height: u32,
+        pub status: u8,
+    }
+    impl<'a> Default for AcknowledgmentArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            AcknowledgmentArgs {
+                id: 0,
+                account: 0,
+                id_msg: 0,
+                txid: None,
+                address: None,
+                height: 0,
+                status: 0,
+            }
+        }
+    }
+
+    pub struct AcknowledgmentBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AcknowledgmentBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_id(&mut self, id: u32) {
+            self.fbb_.push_slot::<u32>(Acknowledgment::VT_ID, id, 0);
+        }
+        #[inline]
+        pub fn add_account(&mut self, account: u32) {
+            self.fbb_
+                .push_slot::<u32>(Acknowledgment::VT_ACCOUNT, account, 0);
+        }
+        #[inline]
+        pub fn add_id_msg(&mut self, id_msg: u32) {
+            self.fbb_
+                .push_slot::<u32>(Acknowledgment::VT_ID_MSG, id_msg, 0);
+        }
+        #[inline]
+        pub fn add_txid(&mut self, txid: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(Acknowledgment::VT_TXID, txid);
+        }
+        #[inline]
+        pub fn add_address(&mut self, address: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(Acknowledgment::VT_ADDRESS, address);
+        }
+        #[inline]
+        pub fn add_height(&mut self, height: u32) {
+            self.fbb_
+                .push_slot::<u32>(Acknowledgment::VT_HEIGHT, height, 0);
+        }
+        #[inline]
+        pub fn add_status(&mut self, status: u8) {
+            self.fbb_
+                .push_slot::<u8>(Acknowledgment::VT_STATUS, status, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> AcknowledgmentBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            AcknowledgmentBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<Acknowledgment<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for Acknowledgment<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("Acknowledgment");
+            ds.field("id", &self.id());
+            ds.field("account", &self.account());
+            ds.field("id_msg", &self.id_msg());
+            ds.field("txid", &self.txid());
+            ds.field("address", &self.address());
+            ds.field("height", &self.height());
+            ds.field("status", &self.status());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct AcknowledgmentT {
+        pub id: u32,
+        pub account: u32,
+        pub id_msg: u32,
+        pub txid: Option<Vec<u8>>,
+        pub address: Option<String>,
+        pub height: u32,
+        pub status: u8,
+    }
+    impl Default for AcknowledgmentT {
+        fn default() -> Self {
+            Self {
+                id: 0,
+                account: 0,
+                id_msg: 0,
+                txid: None,
+                address: None,
+                height: 0,
+                status: 0,
+            }
+        }
+    }
+    impl AcknowledgmentT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<Acknowledgment<'b>> {
+            let id = self.id;
+            let account = self.account;
+            let id_msg = self.id_msg;
+            let txid = self.txid.as_ref().map(|x| _fbb.create_vector(x));
+            let address = self.address.as_ref().map(|x| _fbb.create_string(x));
+            let height = self.height;
+            let status = self.status;
+            Acknowledgment::create(
+                _fbb,
+                &AcknowledgmentArgs {
+                    id,
+                    account,
+                    id_msg,
+                    txid,
+                    address,
+                    height,
+                    status,
+                },
+            )
+        }
+    }
+    pub enum AuditEventOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct AuditEvent<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for AuditEvent<'a> {
+        type Inner = AuditEvent<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> AuditEvent<'a> {
+        pub const VT_ID: flatbuffers::VOffsetT = 4;
+        pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 6;
+        pub const VT_ORIGIN: flatbuffers::VOffsetT = 8;
+        pub const VT_OPERATION: flatbuffers::VOffsetT = 10;
+        pub const VT_ACCOUNT: flatbuffers::VOffsetT = 12;
+        pub const VT_DETAILS: flatbuffers::VOffsetT = 14;
+        pub const VT_HASH: flatbuffers::VOffsetT = 16;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            AuditEvent { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args AuditEventArgs<'args>,
+        ) -> flatbuffers::WIPOffset<AuditEvent<'bldr>> {
+            let mut builder = AuditEventBuilder::new(_fbb);
+            if let Some(x) = args.hash {
+                builder.add_hash(x);
+            }
+            if let Some(x) = args.details {
+                builder.add_details(x);
+            }
+            builder.add_account(args.account);
+            if let Some(x) = args.operation {
+                builder.add_operation(x);
+            }
+            if let Some(x) = args.origin {
+                builder.add_origin(x);
+            }
+            builder.add_timestamp(args.timestamp);
+            builder.add_id(args.id);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> AuditEventT {
+            let id = self.id();
+            let timestamp = self.timestamp();
+            let origin = self.origin().map(|x| x.to_string());
+            let operation = self.operation().map(|x| x.to_string());
+            let account = self.account();
+            let details = self.details().map(|x| x.to_string());
+            let hash = self.hash().map(|x| x.into_iter().collect());
+            AuditEventT {
+                id,
+                timestamp,
+                origin,
+                operation,
+                account,
+                details,
+                hash,
+            }
+        }
+
+        #[inline]
+        pub fn id(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(AuditEvent::VT_ID, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn timestamp(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(AuditEvent::VT_TIMESTAMP, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn origin(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(AuditEvent::VT_ORIGIN, None)
+            }
+        }
+        #[inline]
+        pub fn operation(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(AuditEvent::VT_OPERATION, None)
+            }
+        }
+        #[inline]
+        pub fn account(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(AuditEvent::VT_ACCOUNT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn details(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(AuditEvent::VT_DETAILS, None)
+            }
+        }
+        #[inline]
+        pub fn hash(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        AuditEvent::VT_HASH,
+                        None,
+                    )
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for AuditEvent<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("id", Self::VT_ID, false)?
+                .visit_field::<u32>("timestamp", Self::VT_TIMESTAMP, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "origin",
+                    Self::VT_ORIGIN,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "operation",
+                    Self::VT_OPERATION,
+                    false,
+                )?
+                .visit_field::<u32>("account", Self::VT_ACCOUNT, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "details",
+                    Self::VT_DETAILS,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "hash",
+                    Self::VT_HASH,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct AuditEventArgs<'a> {
+        pub id: u32,
+        pub timestamp: u32,
+        pub origin: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub operation: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub account: u32,
+        pub details: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub hash: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    }
+    impl<'a> Default for AuditEventArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            AuditEventArgs {
+                id: 0,
+                timestamp: 0,
+                origin: None,
+                operation: None,
+                account: 0,
+                details: None,
+                hash: None,
+            }
+        }
+    }
+
+    pub struct AuditEventBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AuditEventBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_id(&mut self, id: u32) {
+            self.fbb_.push_slot::<u32>(AuditEvent::VT_ID, id, 0);
+        }
+        #[inline]
+        pub fn add_timestamp(&mut self, timestamp: u32) {
+            self.fbb_
+                .push_slot::<u32>(AuditEvent::VT_TIMESTAMP, timestamp, 0);
+        }
+        #[inline]
+        pub fn add_origin(&mut self, origin: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(AuditEvent::VT_ORIGIN, origin);
+        }
+        #[inline]
+        pub fn add_operation(&mut self, operation: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(AuditEvent::VT_OPERATION, operation);
+        }
+        #[inline]
+        pub fn add_account(&mut self, account: u32) {
+            self.fbb_
+                .push_slot::<u32>(AuditEvent::VT_ACCOUNT, account, 0);
+        }
+        #[inline]
+        pub fn add_details(&mut self, details: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(AuditEvent::VT_DETAILS, details);
+        }
+        #[inline]
+        pub fn add_hash(&mut self, hash: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(AuditEvent::VT_HASH, hash);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> AuditEventBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            AuditEventBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<AuditEvent<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for AuditEvent<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("AuditEvent");
+            ds.field("id", &self.id());
+            ds.field("timestamp", &self.timestamp());
+            ds.field("origin", &self.origin());
+            ds.field("operation", &self.operation());
+            ds.field("account", &self.account());
+            ds.field("details", &self.details());
+            ds.field("hash", &self.hash());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct AuditEventT {
+        pub id: u32,
+        pub timestamp: u32,
+        pub origin: Option<String>,
+        pub operation: Option<String>,
+        pub account: u32,
+        pub details: Option<String>,
+        pub hash: Option<Vec<u8>>,
+    }
+    impl Default for AuditEventT {
+        fn default() -> Self {
+            Self {
+                id: 0,
+                timestamp: 0,
+                origin: None,
+                operation: None,
+                account: 0,
+                details: None,
+                hash: None,
+            }
+        }
+    }
+    impl AuditEventT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<AuditEvent<'b>> {
+            let id = self.id;
+            let timestamp = self.timestamp;
+            let origin = self.origin.as_ref().map(|x| _fbb.create_string(x));
+            let operation = self.operation.as_ref().map(|x| _fbb.create_string(x));
+            let account = self.account;
+            let details = self.details.as_ref().map(|x| _fbb.create_string(x));
+            let hash = self.hash.as_ref().map(|x| _fbb.create_vector(x));
+            AuditEvent::create(
+                _fbb,
+                &AuditEventArgs {
+                    id,
+                    timestamp,
+                    origin,
+                    operation,
+                    account,
+                    details,
+                    hash,
+                },
+            )
+        }
+    }
+    pub enum SweepTransactionOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct SweepTransaction<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for SweepTransaction<'a> {
+        type Inner = SweepTransaction<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> SweepTransaction<'a> {
+        pub const VT_ACCOUNT: flatbuffers::VOffsetT = 4;
+        pub const VT_AMOUNT: flatbuffers::VOffsetT = 6;
+        pub const VT_SUMMARY: flatbuffers::VOffsetT = 8;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            SweepTransaction { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args SweepTransactionArgs<'args>,
+        ) -> flatbuffers::WIPOffset<SweepTransaction<'bldr>> {
+            let mut builder = SweepTransactionBuilder::new(_fbb);
+            builder.add_amount(args.amount);
+            if let Some(x) = args.summary {
+                builder.add_summary(x);
+            }
+            builder.add_account(args.account);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> SweepTransactionT {
+            let account = self.account();
+            let amount = self.amount();
+            let summary = self.summary().map(|x| Box::new(x.unpack()));
+            SweepTransactionT {
+                account,
+                amount,
+                summary,
+            }
+        }
+
+        #[inline]
+        pub fn account(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(SweepTransaction::VT_ACCOUNT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn amount(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(SweepTransaction::VT_AMOUNT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn summary(&self) -> Option<TransactionSummary<'a>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<TransactionSummary>>(
+                        SweepTransaction::VT_SUMMARY,
+                        None,
+                    )
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for SweepTransaction<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("account", Self::VT_ACCOUNT, false)?
+                .visit_field::<u64>("amount", Self::VT_AMOUNT, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<TransactionSummary>>(
+                    "summary",
+                    Self::VT_SUMMARY,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct SweepTransactionArgs<'a> {
+        pub account: u32,
+        pub amount: u64,
+        pub summary: Option<flatbuffers::WIPOffset<TransactionSummary<'a>>>,
+    }
+    impl<'a> Default for SweepTransactionArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            SweepTransactionArgs {
+                account: 0,
+                amount: 0,
+                summary: None,
+            }
+        }
+    }
+
+    pub struct SweepTransactionBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SweepTransactionBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_account(&mut self, account: u32) {
+            self.fbb_
+                .push_slot::<u32>(SweepTransaction::VT_ACCOUNT, account, 0);
+        }
+        #[inline]
+        pub fn add_amount(&mut self, amount: u64) {
+            self.fbb_
+                .push_slot::<u64>(SweepTransaction::VT_AMOUNT, amount, 0);
+        }
+        #[inline]
+        pub fn add_summary(&mut self, summary: flatbuffers::WIPOffset<TransactionSummary<'b>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<TransactionSummary>>(
+                    SweepTransaction::VT_SUMMARY,
+                    summary,
+                );
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> SweepTransactionBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            SweepTransactionBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<SweepTransaction<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for SweepTransaction<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("SweepTransaction");
+            ds.field("account", &self.account());
+            ds.field("amount", &self.amount());
+            ds.field("summary", &self.summary());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct SweepTransactionT {
+        pub account: u32,
+        pub amount: u64,
+        pub summary: Option<Box<TransactionSummaryT>>,
+    }
+    impl Default for SweepTransactionT {
+        fn default() -> Self {
+            Self {
+                account: 0,
+                amount: 0,
+                summary: None,
+            }
+        }
+    }
+    impl SweepTransactionT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<SweepTransaction<'b>> {
+            let account = self.account;
+            let amount = self.amount;
+            let summary = self.summary.as_ref().map(|x| x.pack(_fbb));
+            SweepTransaction::create(
+                _fbb,
+                &SweepTransactionArgs {
+                    account,
+                    amount,
+                    summary,
+                },
+            )
+        }
+    }
+    pub enum SweepBundleOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct SweepBundle<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for SweepBundle<'a> {
+        type Inner = SweepBundle<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> SweepBundle<'a> {
+        pub const VT_HEIGHT: flatbuffers::VOffsetT = 4;
+        pub const VT_ADDRESS: flatbuffers::VOffsetT = 6;
+        pub const VT_TXS: flatbuffers::VOffsetT = 8;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            SweepBundle { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args SweepBundleArgs<'args>,
+        ) -> flatbuffers::WIPOffset<SweepBundle<'bldr>> {
+            let mut builder = SweepBundleBuilder::new(_fbb);
+            if let Some(x) = args.txs {
+                builder.add_txs(x);
+            }
+            if let Some(x) = args.address {
+                builder.add_address(x);
+            }
+            builder.add_height(args.height);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> SweepBundleT {
+            let height = self.height();
+            let address = self.address().map(|x| x.to_string());
+            let txs = self.txs().map(|x| x.iter().map(|t| t.unpack()).collect());
+            SweepBundleT {
+                height,
+                address,
+                txs,
+            }
+        }
+
+        #[inline]
+        pub fn height(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(SweepBundle::VT_HEIGHT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn address(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(SweepBundle::VT_ADDRESS, None)
+            }
+        }
+        #[inline]
+        pub fn txs(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SweepTransaction<'a>>>>
+        {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SweepTransaction>>,
+                >>(SweepBundle::VT_TXS, None)
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for SweepBundle<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("height", Self::VT_HEIGHT, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "address",
+                    Self::VT_ADDRESS,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<SweepTransaction>>,
+                >>("txs", Self::VT_TXS, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct SweepBundleArgs<'a> {
+        pub height: u32,
+        pub address: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub txs: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SweepTransaction<'a>>>,
+            >,
+        >,
+    }
+    impl<'a> Default for SweepBundleArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            SweepBundleArgs {
+                height: 0,
+                address: None,
+                txs: None,
+            }
+        }
+    }
+
+    pub struct SweepBundleBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SweepBundleBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_height(&mut self, height: u32) {
+            self.fbb_
+                .push_slot::<u32>(SweepBundle::VT_HEIGHT, height, 0);
+        }
+        #[inline]
+        pub fn add_address(&mut self, address: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(SweepBundle::VT_ADDRESS, address);
+        }
+        #[inline]
+        pub fn add_txs(
+            &mut self,
+            txs: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<SweepTransaction<'b>>>,
+            >,
+        ) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(SweepBundle::VT_TXS, txs);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> SweepBundleBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            SweepBundleBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<SweepBundle<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for SweepBundle<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("SweepBundle");
+            ds.field("height", &self.height());
+            ds.field("address", &self.address());
+            ds.field("txs", &self.txs());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct SweepBundleT {
+        pub height: u32,
+        pub address: Option<String>,
+        pub txs: Option<Vec<SweepTransactionT>>,
+    }
+    impl Default for SweepBundleT {
+        fn default() -> Self {
+            Self {
+                height: 0,
+                address: None,
+                txs: None,
+            }
+        }
+    }
+    impl SweepBundleT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<SweepBundle<'b>> {
+            let height = self.height;
+            let address = self.address.as_ref().map(|x| _fbb.create_string(x));
+            let txs = self.txs.as_ref().map(|x| {
+                let w: Vec<_> = x.iter().map(|t| t.pack(_fbb)).collect();
+                _fbb.create_vector(&w)
+            });
+            SweepBundle::create(
+                _fbb,
+                &SweepBundleArgs {
+                    height,
+                    address,
+                    txs,
+                },
+            )
+        }
+    }
+    pub enum NoteProofOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct NoteProof<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for NoteProof<'a> {
+        type Inner = NoteProof<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> NoteProof<'a> {
+        pub const VT_POOL: flatbuffers::VOffsetT = 4;
+        pub const VT_POSITION: flatbuffers::VOffsetT = 6;
+        pub const VT_VALUE: flatbuffers::VOffsetT = 8;
+        pub const VT_ADDRESS: flatbuffers::VOffsetT = 10;
+        pub const VT_RSEED: flatbuffers::VOffsetT = 12;
+        pub const VT_RHO: flatbuffers::VOffsetT = 14;
+        pub const VT_CMX: flatbuffers::VOffsetT = 16;
+        pub const VT_PATH: flatbuffers::VOffsetT = 18;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            NoteProof { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args NoteProofArgs<'args>,
+        ) -> flatbuffers::WIPOffset<NoteProof<'bldr>> {
+            let mut builder = NoteProofBuilder::new(_fbb);
+            builder.add_value(args.value);
+            if let Some(x) = args.path {
+                builder.add_path(x);
+            }
+            if let Some(x) = args.cmx {
+                builder.add_cmx(x);
+            }
+            if let Some(x) = args.rho {
+                builder.add_rho(x);
+            }
+            if let Some(x) = args.rseed {
+                builder.add_rseed(x);
+            }
+            if let Some(x) = args.address {
+                builder.add_address(x);
+            }
+            builder.add_position(args.position);
+            builder.add_pool(args.pool);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> NoteProofT {
+            let pool = self.pool();
+            let position = self.position();
+            let value = self.value();
+            let address = self.address().map(|x| x.into_iter().collect());
+            let rseed = self.rseed().map(|x| x.into_iter().collect());
+            let rho = self.rho().map(|x| x.into_iter().collect());
+            let cmx = self.cmx().map(|x| x.into_iter().collect());
+            let path = self.path().map(|x| x.into_iter().collect());
+            NoteProofT {
+                pool,
+                position,
+                value,
+                address,
+                rseed,
+                rho,
+                cmx,
+                path,
+            }
+        }
+
+        #[inline]
+        pub fn pool(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u8>(NoteProof::VT_POOL, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn position(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(NoteProof::VT_POSITION, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn value(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u64>(NoteProof::VT_VALUE, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn address(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        NoteProof::VT_ADDRESS,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn rseed(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        NoteProof::VT_RSEED,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn rho(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        NoteProof::VT_RHO,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn cmx(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        NoteProof::VT_CMX,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn path(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        NoteProof::VT_PATH,
+                        None,
+                    )
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for NoteProof<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u8>("pool", Self::VT_POOL, false)?
+                .visit_field::<u32>("position", Self::VT_POSITION, false)?
+                .visit_field::<u64>("value", Self::VT_VALUE, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "address",
+                    Self::VT_ADDRESS,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "rseed",
+                    Self::VT_RSEED,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "rho",
+                    Self::VT_RHO,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "cmx",
+                    Self::VT_CMX,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "path",
+                    Self::VT_PATH,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct NoteProofArgs<'a> {
+        pub pool: u8,
+        pub position: u32,
+        pub value: u64,
+        pub address: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub rseed: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub rho: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub cmx: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub path: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    }
+    impl<'a> Default for NoteProofArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            NoteProofArgs {
+                pool: 0,
+                position: 0,
+                value: 0,
+                address: None,
+                rseed: None,
+                rho: None,
+                cmx: None,
+                path: None,
+            }
+        }
+    }
+
+    pub struct NoteProofBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> NoteProofBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_pool(&mut self, pool: u8) {
+            self.fbb_.push_slot::<u8>(NoteProof::VT_POOL, pool, 0);
+        }
+        #[inline]
+        pub fn add_position(&mut self, position: u32) {
+            self.fbb_
+                .push_slot::<u32>(NoteProof::VT_POSITION, position, 0);
+        }
+        #[inline]
+        pub fn add_value(&mut self, value: u64) {
+            self.fbb_.push_slot::<u64>(NoteProof::VT_VALUE, value, 0);
+        }
+        #[inline]
+        pub fn add_address(
+            &mut self,
+            address: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>,
+        ) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(NoteProof::VT_ADDRESS, address);
+        }
+        #[inline]
+        pub fn add_rseed(&mut self, rseed: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(NoteProof::VT_RSEED, rseed);
+        }
+        #[inline]
+        pub fn add_rho(&mut self, rho: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(NoteProof::VT_RHO, rho);
+        }
+        #[inline]
+        pub fn add_cmx(&mut self, cmx: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(NoteProof::VT_CMX, cmx);
+        }
+        #[inline]
+        pub fn add_path(&mut self, path: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(NoteProof::VT_PATH, path);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> NoteProofBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            NoteProofBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<NoteProof<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for NoteProof<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("NoteProof");
+            ds.field("pool", &self.pool());
+            ds.field("position", &self.position());
+            ds.field("value", &self.value());
+            ds.field("address", &self.address());
+            ds.field("rseed", &self.rseed());
+            ds.field("rho", &self.rho());
+            ds.field("cmx", &self.cmx());
+            ds.field("path", &self.path());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct NoteProofT {
+        pub pool: u8,
+        pub position: u32,
+        pub value: u64,
+        pub address: Option<Vec<u8>>,
+        pub rseed: Option<Vec<u8>>,
+        pub rho: Option<Vec<u8>>,
+        pub cmx: Option<Vec<u8>>,
+        pub path: Option<Vec<u8>>,
+    }
+    impl Default for NoteProofT {
+        fn default() -> Self {
+            Self {
+                pool: 0,
+                position: 0,
+                value: 0,
+                address: None,
+                rseed: None,
+                rho: None,
+                cmx: None,
+                path: None,
+            }
+        }
+    }
+    impl NoteProofT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<NoteProof<'b>> {
+            let pool = self.pool;
+            let position = self.position;
+            let value = self.value;
+            let address = self.address.as_ref().map(|x| _fbb.create_vector(x));
+            let rseed = self.rseed.as_ref().map(|x| _fbb.create_vector(x));
+            let rho = self.rho.as_ref().map(|x| _fbb.create_vector(x));
+            let cmx = self.cmx.as_ref().map(|x| _fbb.create_vector(x));
+            let path = self.path.as_ref().map(|x| _fbb.create_vector(x));
+            NoteProof::create(
+                _fbb,
+                &NoteProofArgs {
+                    pool,
+                    position,
+                    value,
+                    address,
+                    rseed,
+                    rho,
+                    cmx,
+                    path,
+                },
+            )
+        }
+    }
+    pub enum BalanceProofOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct BalanceProof<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for BalanceProof<'a> {
+        type Inner = BalanceProof<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> BalanceProof<'a> {
+        pub const VT_ACCOUNT: flatbuffers::VOffsetT = 4;
+        pub const VT_HEIGHT: flatbuffers::VOffsetT = 6;
+        pub const VT_SAPLING_ROOT: flatbuffers::VOffsetT = 8;
+        pub const VT_ORCHARD_ROOT: flatbuffers::VOffsetT = 10;
+        pub const VT_TOTAL: flatbuffers::VOffsetT = 12;
+        pub const VT_NOTES: flatbuffers::VOffsetT = 14;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            BalanceProof { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args BalanceProofArgs<'args>,
+        ) -> flatbuffers::WIPOffset<BalanceProof<'bldr>> {
+            let mut builder = BalanceProofBuilder::new(_fbb);
+            builder.add_total(args.total);
+            if let Some(x) = args.notes {
+                builder.add_notes(x);
+            }
+            if let Some(x) = args.orchard_root {
+                builder.add_orchard_root(x);
+            }
+            if let Some(x) = args.sapling_root {
+                builder.add_sapling_root(x);
+            }
+            builder.add_height(args.height);
+            builder.add_account(args.account);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> BalanceProofT {
+            let account = self.account();
+            let height = self.height();
+            let sapling_root = self.sapling_root().map(|x| x.into_iter().collect());
+            let orchard_root = self.orchard_root().map(|x| x.into_iter().collect());
+            let total = self.total();
+            let notes = self.notes().map(|x| x.iter().map(|t| t.unpack()).collect());
+            BalanceProofT {
+                account,
+                height,
+                sapling_root,
+                orchard_root,
+                total,
+                notes,
+            }
+        }
+
+        #[inline]
+        pub fn account(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(BalanceProof::VT_ACCOUNT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn height(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(BalanceProof::VT_HEIGHT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn sapling_root(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        BalanceProof::VT_SAPLING_ROOT,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn orchard_root(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        BalanceProof::VT_ORCHARD_ROOT,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn total(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(BalanceProof::VT_TOTAL, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn notes(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<NoteProof<'a>>>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<NoteProof>>,
+                >>(BalanceProof::VT_NOTES, None)
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for BalanceProof<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("account", Self::VT_ACCOUNT, false)?
+                .visit_field::<u32>("height", Self::VT_HEIGHT, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "sapling_root",
+                    Self::VT_SAPLING_ROOT,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "orchard_root",
+                    Self::VT_ORCHARD_ROOT,
+                    false,
+                )?
+                .visit_field::<u64>("total", Self::VT_TOTAL, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<NoteProof>>,
+                >>("notes", Self::VT_NOTES, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct BalanceProofArgs<'a> {
+        pub account: u32,
+        pub height: u32,
+        pub sapling_root: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub orchard_root: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub total: u64,
+        pub notes: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<NoteProof<'a>>>,
+            >,
+        >,
+    }
+    impl<'a> Default for BalanceProofArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            BalanceProofArgs {
+                account: 0,
+                height: 0,
+                sapling_root: None,
+                orchard_root: None,
+                total: 0,
+                notes: None,
+            }
+        }
+    }
+
+    pub struct BalanceProofBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> BalanceProofBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_account(&mut self, account: u32) {
+            self.fbb_
+                .push_slot::<u32>(BalanceProof::VT_ACCOUNT, account, 0);
+        }
+        #[inline]
+        pub fn add_height(&mut self, height: u32) {
+            self.fbb_
+                .push_slot::<u32>(BalanceProof::VT_HEIGHT, height, 0);
+        }
+        #[inline]
+        pub fn add_sapling_root(
+            &mut self,
+            sapling_root: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                BalanceProof::VT_SAPLING_ROOT,
+                sapling_root,
+            );
+        }
+        #[inline]
+        pub fn add_orchard_root(
+            &mut self,
+            orchard_root: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                BalanceProof::VT_ORCHARD_ROOT,
+                orchard_root,
+            );
+        }
+        #[inline]
+        pub fn add_total(&mut self, total: u64) {
+            self.fbb_.push_slot::<u64>(BalanceProof::VT_TOTAL, total, 0);
+        }
+        #[inline]
+        pub fn add_notes(
+            &mut self,
+            notes: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<NoteProof<'b>>>,
+            >,
+        ) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(BalanceProof::VT_NOTES, notes);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> BalanceProofBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            BalanceProofBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<BalanceProof<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for BalanceProof<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("BalanceProof");
+            ds.field("account", &self.account());
+            ds.field("height", &self.height());
+            ds.field("sapling_root", &self.sapling_root());
+            ds.field("orchard_root", &self.orchard_root());
+            ds.field("total", &self.total());
+            ds.field("notes", &self.notes());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct BalanceProofT {
+        pub account: u32,
+        pub height: u32,
+        pub sapling_root: Option<Vec<u8>>,
+        pub orchard_root: Option<Vec<u8>>,
+        pub total: u64,
+        pub notes: Option<Vec<NoteProofT>>,
+    }
+    impl Default for BalanceProofT {
+        fn default() -> Self {
+            Self {
+                account: 0,
+                height: 0,
+                sapling_root: None,
+                orchard_root: None,
+                total: 0,
+                notes: None,
+            }
+        }
+    }
+    impl BalanceProofT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<BalanceProof<'b>> {
+            let account = self.account;
+            let height = self.height;
+            let sapling_root = self.sapling_root.as_ref().map(|x| _fbb.create_vector(x));
+            let orchard_root = self.orchard_root.as_ref().map(|x| _fbb.create_vector(x));
+            let total = self.total;
+            let notes = self.notes.as_ref().map(|x| {
+                let w: Vec<_> = x.iter().map(|t| t.pack(_fbb)).collect();
+                _fbb.create_vector(&w)
+            });
+            BalanceProof::create(
+                _fbb,
+                &BalanceProofArgs {
+                    account,
+                    height,
+                    sapling_root,
+                    orchard_root,
+                    total,
+                    notes,
+                },
+            )
+        }
+    }
+    pub enum AddressUsageOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct AddressUsage<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for AddressUsage<'a> {
+        type Inner = AddressUsage<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> AddressUsage<'a> {
+        pub const VT_POOL: flatbuffers::VOffsetT = 4;
+        pub const VT_ADDRESS: flatbuffers::VOffsetT = 6;
+        pub const VT_ADDR_INDEX: flatbuffers::VOffsetT = 8;
+        pub const VT_COUNT: flatbuffers::VOffsetT = 10;
+        pub const VT_LAST_HEIGHT: flatbuffers::VOffsetT = 12;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            AddressUsage { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args AddressUsageArgs<'args>,
+        ) -> flatbuffers::WIPOffset<AddressUsage<'bldr>> {
+            let mut builder = AddressUsageBuilder::new(_fbb);
+            builder.add_last_height(args.last_height);
+            builder.add_count(args.count);
+            builder.add_addr_index(args.addr_index);
+            if let Some(x) = args.address {
+                builder.add_address(x);
+            }
+            builder.add_pool(args.pool);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> AddressUsageT {
+            let pool = self.pool();
+            let address = self.address().map(|x| x.to_string());
+            let addr_index = self.addr_index();
+            let count = self.count();
+            let last_height = self.last_height();
+            AddressUsageT {
+                pool,
+                address,
+                addr_index,
+                count,
+                last_height,
+            }
+        }
+
+        #[inline]
+        pub fn pool(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u8>(AddressUsage::VT_POOL, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn address(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(AddressUsage::VT_ADDRESS, None)
+            }
+        }
+        #[inline]
+        pub fn addr_index(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(AddressUsage::VT_ADDR_INDEX, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn count(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(AddressUsage::VT_COUNT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn last_height(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(AddressUsage::VT_LAST_HEIGHT, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for AddressUsage<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u8>("pool", Self::VT_POOL, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "address",
+                    Self::VT_ADDRESS,
+                    false,
+                )?
+                .visit_field::<u32>("addr_index", Self::VT_ADDR_INDEX, false)?
+                .visit_field::<u32>("count", Self::VT_COUNT, false)?
+                .visit_field::<u32>("last_height", Self::VT_LAST_HEIGHT, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct AddressUsageArgs<'a> {
+        pub pool: u8,
+        pub address: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub addr_index: u32,
+        pub count: u32,
+        pub last_height: u32,
+    }
+    impl<'a> Default for AddressUsageArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            AddressUsageArgs {
+                pool: 0,
+                address: None,
+                addr_index: 0,
+                count: 0,
+                last_height: 0,
+            }
+        }
+    }
+
+    pub struct AddressUsageBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AddressUsageBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_pool(&mut self, pool: u8) {
+            self.fbb_.push_slot::<u8>(AddressUsage::VT_POOL, pool, 0);
+        }
+        #[inline]
+        pub fn add_address(&mut self, address: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(AddressUsage::VT_ADDRESS, address);
+        }
+        #[inline]
+        pub fn add_addr_index(&mut self, addr_index: u32) {
+            self.fbb_
+                .push_slot::<u32>(AddressUsage::VT_ADDR_INDEX, addr_index, 0);
+        }
+        #[inline]
+        pub fn add_count(&mut self, count: u32) {
+            self.fbb_.push_slot::<u32>(AddressUsage::VT_COUNT, count, 0);
+        }
+        #[inline]
+        pub fn add_last_height(&mut self, last_height: u32) {
+            self.fbb_
+                .push_slot::<u32>(AddressUsage::VT_LAST_HEIGHT, last_height, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> AddressUsageBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            AddressUsageBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<AddressUsage<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for AddressUsage<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("AddressUsage");
+            ds.field("pool", &self.pool());
+            ds.field("address", &self.address());
+            ds.field("addr_index", &self.addr_index());
+            ds.field("count", &self.count());
+            ds.field("last_height", &self.last_height());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct AddressUsageT {
+        pub pool: u8,
+        pub address: Option<String>,
+        pub addr_index: u32,
+        pub count: u32,
+        pub last_height: u32,
+    }
+    impl Default for AddressUsageT {
+        fn default() -> Self {
+            Self {
+                pool: 0,
+                address: None,
+                addr_index: 0,
+                count: 0,
+                last_height: 0,
+            }
+        }
+    }
+    impl AddressUsageT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<AddressUsage<'b>> {
+            let pool = self.pool;
+            let address = self.address.as_ref().map(|x| _fbb.create_string(x));
+            let addr_index = self.addr_index;
+            let count = self.count;
+            let last_height = self.last_height;
+            AddressUsage::create(
+                _fbb,
+                &AddressUsageArgs {
+                    pool,
+                    address,
+                    addr_index,
+                    count,
+                    last_height,
+                },
+            )
+        }
+    }
+    pub enum ApiKeyOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct ApiKey<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for ApiKey<'a> {
+        type Inner = ApiKey<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> ApiKey<'a> {
+        pub const VT_ID: flatbuffers::VOffsetT = 4;
+        pub const VT_NAME: flatbuffers::VOffsetT = 6;
+        pub const VT_READ_ONLY: flatbuffers::VOffsetT = 8;
+        pub const VT_MAX_AMOUNT: flatbuffers::VOffsetT = 10;
+        pub const VT_ACCOUNTS: flatbuffers::VOffsetT = 12;
+        pub const VT_CREATED: flatbuffers::VOffsetT = 14;
+        pub const VT_REVOKED: flatbuffers::VOffsetT = 16;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            ApiKey { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args ApiKeyArgs<'args>,
+        ) -> flatbuffers::WIPOffset<ApiKey<'bldr>> {
+            let mut builder = ApiKeyBuilder::new(_fbb);
+            builder.add_max_amount(args.max_amount);
+            builder.add_created(args.created);
+            if let Some(x) = args.accounts {
+                builder.add_accounts(x);
+            }
+            if let Some(x) = args.name {
+                builder.add_name(x);
+            }
+            builder.add_id(args.id);
+            builder.add_revoked(args.revoked);
+            builder.add_read_only(args.read_only);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> ApiKeyT {
+            let id = self.id();
+            let name = self.name().map(|x| x.to_string());
+            let read_only = self.read_only();
+            let max_amount = self.max_amount();
+            let accounts = self.accounts().map(|x| x.into_iter().collect());
+            let created = self.created();
+            let revoked = self.revoked();
+            ApiKeyT {
+                id,
+                name,
+                read_only,
+                max_amount,
+                accounts,
+                created,
+                revoked,
+            }
+        }
+
+        #[inline]
+        pub fn id(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(ApiKey::VT_ID, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn name(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(ApiKey::VT_NAME, None)
+            }
+        }
+        #[inline]
+        pub fn read_only(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(ApiKey::VT_READ_ONLY, Some(false))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn max_amount(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(ApiKey::VT_MAX_AMOUNT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn accounts(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                        ApiKey::VT_ACCOUNTS,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn created(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(ApiKey::VT_CREATED, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn revoked(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(ApiKey::VT_REVOKED, Some(false))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for ApiKey<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("id", Self::VT_ID, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+                .visit_field::<bool>("read_only", Self::VT_READ_ONLY, false)?
+                .visit_field::<u64>("max_amount", Self::VT_MAX_AMOUNT, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                    "accounts",
+                    Self::VT_ACCOUNTS,
+                    false,
+                )?
+                .visit_field::<u32>("created", Self::VT_CREATED, false)?
+                .visit_field::<bool>("revoked", Self::VT_REVOKED, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct ApiKeyArgs<'a> {
+        pub id: u32,
+        pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub read_only: bool,
+        pub max_amount: u64,
+        pub accounts: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+        pub created: u32,
+        pub revoked: bool,
+    }
+    impl<'a> Default for ApiKeyArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            ApiKeyArgs {
+                id: 0,
+                name: None,
+                read_only: false,
+                max_amount: 0,
+                accounts: None,
+                created: 0,
+                revoked: false,
+            }
+        }
+    }
+
+    pub struct ApiKeyBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ApiKeyBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_id(&mut self, id: u32) {
+            self.fbb_.push_slot::<u32>(ApiKey::VT_ID, id, 0);
+        }
+        #[inline]
+        pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(ApiKey::VT_NAME, name);
+        }
+        #[inline]
+        pub fn add_read_only(&mut self, read_only: bool) {
+            self.fbb_
+                .push_slot::<bool>(ApiKey::VT_READ_ONLY, read_only, false);
+        }
+        #[inline]
+        pub fn add_max_amount(&mut self, max_amount: u64) {
+            self.fbb_
+                .push_slot::<u64>(ApiKey::VT_MAX_AMOUNT, max_amount, 0);
+        }
+        #[inline]
+        pub fn add_accounts(
+            &mut self,
+            accounts: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+        ) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(ApiKey::VT_ACCOUNTS, accounts);
+        }
+        #[inline]
+        pub fn add_created(&mut self, created: u32) {
+            self.fbb_.push_slot::<u32>(ApiKey::VT_CREATED, created, 0);
+        }
+        #[inline]
+        pub fn add_revoked(&mut self, revoked: bool) {
+            self.fbb_
+                .push_slot::<bool>(ApiKey::VT_REVOKED, revoked, false);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> ApiKeyBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            ApiKeyBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<ApiKey<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for ApiKey<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("ApiKey");
+            ds.field("id", &self.id());
+            ds.field("name", &self.name());
+            ds.field("read_only", &self.read_only());
+            ds.field("max_amount", &self.max_amount());
+            ds.field("accounts", &self.accounts());
+            ds.field("created", &self.created());
+            ds.field("revoked", &self.revoked());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct ApiKeyT {
+        pub id: u32,
+        pub name: Option<String>,
+        pub read_only: bool,
+        pub max_amount: u64,
+        pub accounts: Option<Vec<u32>>,
+        pub created: u32,
+        pub revoked: bool,
+    }
+    impl Default for ApiKeyT {
+        fn default() -> Self {
+            Self {
+                id: 0,
+                name: None,
+                read_only: false,
+                max_amount: 0,
+                accounts: None,
+                created: 0,
+                revoked: false,
+            }
+        }
+    }
+    impl ApiKeyT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<ApiKey<'b>> {
+            let id = self.id;
+            let name = self.name.as_ref().map(|x| _fbb.create_string(x));
+            let read_only = self.read_only;
+            let max_amount = self.max_amount;
+            let accounts = self.accounts.as_ref().map(|x| _fbb.create_vector(x));
+            let created = self.created;
+            let revoked = self.revoked;
+            ApiKey::create(
+                _fbb,
+                &ApiKeyArgs {
+                    id,
+                    name,
+                    read_only,
+                    max_amount,
+                    accounts,
+                    created,
+                    revoked,
+                },
+            )
+        }
+    }
+    pub enum ContactGroupOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct ContactGroup<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for ContactGroup<'a> {
+        type Inner = ContactGroup<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> ContactGroup<'a> {
+        pub const VT_ID: flatbuffers::VOffsetT = 4;
+        pub const VT_ACCOUNT: flatbuffers::VOffsetT = 6;
+        pub const VT_NAME: flatbuffers::VOffsetT = 8;
+        pub const VT_CONTACTS: flatbuffers::VOffsetT = 10;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            ContactGroup { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args ContactGroupArgs<'args>,
+        ) -> flatbuffers::WIPOffset<ContactGroup<'bldr>> {
+            let mut builder = ContactGroupBuilder::new(_fbb);
+            if let Some(x) = args.contacts {
+                builder.add_contacts(x);
+            }
+            if let Some(x) = args.name {
+                builder.add_name(x);
+            }
+            builder.add_account(args.account);
+            builder.add_id(args.id);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> ContactGroupT {
+            let id = self.id();
+            let account = self.account();
+            let name = self.name().map(|x| x.to_string());
+            let contacts = self.contacts().map(|x| x.into_iter().collect());
+            ContactGroupT {
+                id,
+                account,
+                name,
+                contacts,
+            }
+        }
+
+        #[inline]
+        pub fn id(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(ContactGroup::VT_ID, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn account(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(ContactGroup::VT_ACCOUNT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn name(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(ContactGroup::VT_NAME, None)
+            }
+        }
+        #[inline]
+        pub fn contacts(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                        ContactGroup::VT_CONTACTS,
+                        None,
+                    )
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for ContactGroup<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("id", Self::VT_ID, false)?
+                .visit_field::<u32>("account", Self::VT_ACCOUNT, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                    "contacts",
+                    Self::VT_CONTACTS,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct ContactGroupArgs<'a> {
+        pub id: u32,
+        pub account: u32,
+        pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub contacts: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    }
+    impl<'a> Default for ContactGroupArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            ContactGroupArgs {
+                id: 0,
+                account: 0,
+                name: None,
+                contacts: None,
+            }
+        }
+    }
+
+    pub struct ContactGroupBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ContactGroupBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_id(&mut self, id: u32) {
+            self.fbb_.push_slot::<u32>(ContactGroup::VT_ID, id, 0);
+        }
+        #[inline]
+        pub fn add_account(&mut self, account: u32) {
+            self.fbb_
+                .push_slot::<u32>(ContactGroup::VT_ACCOUNT, account, 0);
+        }
+        #[inline]
+        pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(ContactGroup::VT_NAME, name);
+        }
+        #[inline]
+        pub fn add_contacts(
+            &mut self,
+            contacts: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+        ) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(ContactGroup::VT_CONTACTS, contacts);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> ContactGroupBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            ContactGroupBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<ContactGroup<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for ContactGroup<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("ContactGroup");
+            ds.field("id", &self.id());
+            ds.field("account", &self.account());
+            ds.field("name", &self.name());
+            ds.field("contacts", &self.contacts());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct ContactGroupT {
+        pub id: u32,
+        pub account: u32,
+        pub name: Option<String>,
+        pub contacts: Option<Vec<u32>>,
+    }
+    impl Default for ContactGroupT {
+        fn default() -> Self {
+            Self {
+                id: 0,
+                account: 0,
+                name: None,
+                contacts: None,
+            }
+        }
+    }
+    impl ContactGroupT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<ContactGroup<'b>> {
+            let id = self.id;
+            let account = self.account;
+            let name = self.name.as_ref().map(|x| _fbb.create_string(x));
+            let contacts = self.contacts.as_ref().map(|x| _fbb.create_vector(x));
+            ContactGroup::create(
+                _fbb,
+                &ContactGroupArgs {
+                    id,
+                    account,
+                    name,
+                    contacts,
+                },
+            )
+        }
+    }
+    pub enum SeedChallengeOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct SeedChallenge<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for SeedChallenge<'a> {
+        type Inner = SeedChallenge<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> SeedChallenge<'a> {
+        pub const VT_ACCOUNT: flatbuffers::VOffsetT = 4;
+        pub const VT_POSITIONS: flatbuffers::VOffsetT = 6;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            SeedChallenge { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args SeedChallengeArgs<'args>,
+        ) -> flatbuffers::WIPOffset<SeedChallenge<'bldr>> {
+            let mut builder = SeedChallengeBuilder::new(_fbb);
+            if let Some(x) = args.positions {
+                builder.add_positions(x);
+            }
+            builder.add_account(args.account);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> SeedChallengeT {
+            let account = self.account();
+            let positions = self.positions().map(|x| x.into_iter().collect());
+            SeedChallengeT { account, positions }
+        }
+
+        #[inline]
+        pub fn account(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(SeedChallenge::VT_ACCOUNT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn positions(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                        SeedChallenge::VT_POSITIONS,
+                        None,
+                    )
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for SeedChallenge<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("account", Self::VT_ACCOUNT, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                    "positions",
+                    Self::VT_POSITIONS,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct SeedChallengeArgs<'a> {
+        pub account: u32,
+        pub positions: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    }
+    impl<'a> Default for SeedChallengeArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            SeedChallengeArgs {
+                account: 0,
+                positions: None,
+            }
+        }
+    }
+
+    pub struct SeedChallengeBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SeedChallengeBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_account(&mut self, account: u32) {
+            self.fbb_
+                .push_slot::<u32>(SeedChallenge::VT_ACCOUNT, account, 0);
+        }
+        #[inline]
+        pub fn add_positions(
+            &mut self,
+            positions: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                SeedChallenge::VT_POSITIONS,
+                positions,
+            );
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> SeedChallengeBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            SeedChallengeBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<SeedChallenge<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for SeedChallenge<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("SeedChallenge");
+            ds.field("account", &self.account());
+            ds.field("positions", &self.positions());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct SeedChallengeT {
+        pub account: u32,
+        pub positions: Option<Vec<u32>>,
+    }
+    impl Default for SeedChallengeT {
+        fn default() -> Self {
+            Self {
+                account: 0,
+                positions: None,
+            }
+        }
+    }
+    impl SeedChallengeT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<SeedChallenge<'b>> {
+            let account = self.account;
+            let positions = self.positions.as_ref().map(|x| _fbb.create_vector(x));
+            SeedChallenge::create(_fbb, &SeedChallengeArgs { account, positions })
+        }
+    }
+    pub enum JobCommandOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct JobCommand<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for JobCommand<'a> {
+        type Inner = JobCommand<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> JobCommand<'a> {
+        pub const VT_KIND: flatbuffers::VOffsetT = 4;
+        pub const VT_ACCOUNT: flatbuffers::VOffsetT = 6;
+        pub const VT_HEIGHT: flatbuffers::VOffsetT = 8;
+        pub const VT_PAYMENT: flatbuffers::VOffsetT = 10;
+        pub const VT_SUMMARY: flatbuffers::VOffsetT = 12;
+        pub const VT_REDIRECT: flatbuffers::VOffsetT = 14;
+        pub const VT_KEYS: flatbuffers::VOffsetT = 16;
+        pub const VT_SNAP_POLICY: flatbuffers::VOffsetT = 18;
+        pub const VT_BANDWIDTH: flatbuffers::VOffsetT = 20;
+        pub const VT_SESSION: flatbuffers::VOffsetT = 22;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            JobCommand { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args JobCommandArgs<'args>,
+        ) -> flatbuffers::WIPOffset<JobCommand<'bldr>> {
+            let mut builder = JobCommandBuilder::new(_fbb);
+            builder.add_session(args.session);
+            builder.add_bandwidth(args.bandwidth);
+            if let Some(x) = args.keys {
+                builder.add_keys(x);
+            }
+            if let Some(x) = args.redirect {
+                builder.add_redirect(x);
+            }
+            if let Some(x) = args.summary {
+                builder.add_summary(x);
+            }
+            if let Some(x) = args.payment {
+                builder.add_payment(x);
+            }
+            builder.add_height(args.height);
+            builder.add_account(args.account);
+            builder.add_snap_policy(args.snap_policy);
+            builder.add_kind(args.kind);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> JobCommandT {
+            let kind = self.kind();
+            let account = self.account();
+            let height = self.height();
+            let payment = self.payment().map(|x| Box::new(x.unpack()));
+            let summary = self.summary().map(|x| Box::new(x.unpack()));
+            let redirect = self.redirect().map(|x| x.to_string());
+            let keys = self.keys().map(|x| Box::new(x.unpack()));
+            let snap_policy = self.snap_policy();
+            let bandwidth = self.bandwidth();
+            let session = self.session();
+            JobCommandT {
+                kind,
+                account,
+                height,
+                payment,
+                summary,
+                redirect,
+                keys,
+                snap_policy,
+                bandwidth,
+                session,
+            }
+        }
+
+        #[inline]
+        pub fn kind(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u8>(JobCommand::VT_KIND, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn account(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(JobCommand::VT_ACCOUNT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn height(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(JobCommand::VT_HEIGHT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn payment(&self) -> Option<PaymentRequest<'a>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<PaymentRequest>>(
+                        JobCommand::VT_PAYMENT,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn summary(&self) -> Option<TransactionSummary<'a>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<TransactionSummary>>(
+                        JobCommand::VT_SUMMARY,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn redirect(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(JobCommand::VT_REDIRECT, None)
+            }
+        }
+        #[inline]
+        pub fn keys(&self) -> Option<ViewingKeys<'a>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<ViewingKeys>>(JobCommand::VT_KEYS, None)
+            }
+        }
+        #[inline]
+        pub fn snap_policy(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(JobCommand::VT_SNAP_POLICY, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn bandwidth(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(JobCommand::VT_BANDWIDTH, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn session(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(JobCommand::VT_SESSION, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for JobCommand<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u8>("kind", Self::VT_KIND, false)?
+                .visit_field::<u32>("account", Self::VT_ACCOUNT, false)?
+                .visit_field::<u32>("height", Self::VT_HEIGHT, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<PaymentRequest>>(
+                    "payment",
+                    Self::VT_PAYMENT,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<TransactionSummary>>(
+                    "summary",
+                    Self::VT_SUMMARY,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "redirect",
+                    Self::VT_REDIRECT,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<ViewingKeys>>(
+                    "keys",
+                    Self::VT_KEYS,
+                    false,
+                )?
+                .visit_field::<u8>("snap_policy", Self::VT_SNAP_POLICY, false)?
+                .visit_field::<u32>("bandwidth", Self::VT_BANDWIDTH, false)?
+                .visit_field::<u32>("session", Self::VT_SESSION, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct JobCommandArgs<'a> {
+        pub kind: u8,
+        pub account: u32,
+        pub height: u32,
+        pub payment: Option<flatbuffers::WIPOffset<PaymentRequest<'a>>>,
+        pub summary: Option<flatbuffers::WIPOffset<TransactionSummary<'a>>>,
+        pub redirect: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub keys: Option<flatbuffers::WIPOffset<ViewingKeys<'a>>>,
+        pub snap_policy: u8,
+        pub bandwidth: u32,
+        pub session: u32,
+    }
+    impl<'a> Default for JobCommandArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            JobCommandArgs {
+                kind: 0,
+                account: 0,
+                height: 0,
+                payment: None,
+                summary: None,
+                redirect: None,
+                keys: None,
+                snap_policy: 0,
+                bandwidth: 0,
+                session: 0,
+            }
+        }
+    }
+
+    pub struct JobCommandBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> JobCommandBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_kind(&mut self, kind: u8) {
+            self.fbb_.push_slot::<u8>(JobCommand::VT_KIND, kind, 0);
+        }
+        #[inline]
+        pub fn add_account(&mut self, account: u32) {
+            self.fbb_
+                .push_slot::<u32>(JobCommand::VT_ACCOUNT, account, 0);
+        }
+        #[inline]
+        pub fn add_height(&mut self, height: u32) {
+            self.fbb_.push_slot::<u32>(JobCommand::VT_HEIGHT, height, 0);
+        }
+        #[inline]
+        pub fn add_payment(&mut self, payment: flatbuffers::WIPOffset<PaymentRequest<'b>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<PaymentRequest>>(
+                    JobCommand::VT_PAYMENT,
+                    payment,
+                );
+        }
+        #[inline]
+        pub fn add_summary(&mut self, summary: flatbuffers::WIPOffset<TransactionSummary<'b>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<TransactionSummary>>(
+                    JobCommand::VT_SUMMARY,
+                    summary,
+                );
+        }
+        #[inline]
+        pub fn add_redirect(&mut self, redirect: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(JobCommand::VT_REDIRECT, redirect);
+        }
+        #[inline]
+        pub fn add_keys(&mut self, keys: flatbuffers::WIPOffset<ViewingKeys<'b>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<ViewingKeys>>(JobCommand::VT_KEYS, keys);
+        }
+        #[inline]
+        pub fn add_snap_policy(&mut self, snap_policy: u8) {
+            self.fbb_
+                .push_slot::<u8>(JobCommand::VT_SNAP_POLICY, snap_policy, 0);
+        }
+        #[inline]
+        pub fn add_bandwidth(&mut self, bandwidth: u32) {
+            self.fbb_
+                .push_slot::<u32>(JobCommand::VT_BANDWIDTH, bandwidth, 0);
+        }
+        #[inline]
+        pub fn add_session(&mut self, session: u32) {
+            self.fbb_
+                .push_slot::<u32>(JobCommand::VT_SESSION, session, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> JobCommandBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            JobCommandBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<JobCommand<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for JobCommand<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("JobCommand");
+            ds.field("kind", &self.kind());
+            ds.field("account", &self.account());
+            ds.field("height", &self.height());
+            ds.field("payment", &self.payment());
+            ds.field("summary", &self.summary());
+            ds.field("redirect", &self.redirect());
+            ds.field("keys", &self.keys());
+            ds.field("snap_policy", &self.snap_policy());
+            ds.field("bandwidth", &self.bandwidth());
+            ds.field("session", &self.session());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct JobCommandT {
+        pub kind: u8,
+        pub account: u32,
+        pub height: u32,
+        pub payment: Option<Box<PaymentRequestT>>,
+        pub summary: Option<Box<TransactionSummaryT>>,
+        pub redirect: Option<String>,
+        pub keys: Option<Box<ViewingKeysT>>,
+        pub snap_policy: u8,
+        pub bandwidth: u32,
+        pub session: u32,
+    }
+    impl Default for JobCommandT {
+        fn default() -> Self {
+            Self {
+                kind: 0,
+                account: 0,
+                height: 0,
+                payment: None,
+                summary: None,
+                redirect: None,
+                keys: None,
+                snap_policy: 0,
+                bandwidth: 0,
+                session: 0,
+            }
+        }
+    }
+    impl JobCommandT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<JobCommand<'b>> {
+            let kind = self.kind;
+            let account = self.account;
+            let height = self.height;
+            let payment = self.payment.as_ref().map(|x| x.pack(_fbb));
+            let summary = self.summary.as_ref().map(|x| x.pack(_fbb));
+            let redirect = self.redirect.as_ref().map(|x| _fbb.create_string(x));
+            let keys = self.keys.as_ref().map(|x| x.pack(_fbb));
+            let snap_policy = self.snap_policy;
+            let bandwidth = self.bandwidth;
+            let session = self.session;
+            JobCommand::create(
+                _fbb,
+                &JobCommandArgs {
+                    kind,
+                    account,
+                    height,
+                    payment,
+                    summary,
+                    redirect,
+                    keys,
+                    snap_policy,
+                    bandwidth,
+                    session,
+                },
+            )
+        }
+    }
+    pub enum JobStatusOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct JobStatus<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for JobStatus<'a> {
+        type Inner = JobStatus<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> JobStatus<'a> {
+        pub const VT_ID: flatbuffers::VOffsetT = 4;
+        pub const VT_KIND: flatbuffers::VOffsetT = 6;
+        pub const VT_STATE: flatbuffers::VOffsetT = 8;
+        pub const VT_CURRENT: flatbuffers::VOffsetT = 10;
+        pub const VT_TOTAL: flatbuffers::VOffsetT = 12;
+        pub const VT_ERROR: flatbuffers::VOffsetT = 14;
+        pub const VT_RESULT: flatbuffers::VOffsetT = 16;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            JobStatus { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args JobStatusArgs<'args>,
+        ) -> flatbuffers::WIPOffset<JobStatus<'bldr>> {
+            let mut builder = JobStatusBuilder::new(_fbb);
+            if let Some(x) = args.result {
+                builder.add_result(x);
+            }
+            if let Some(x) = args.error {
+                builder.add_error(x);
+            }
+            builder.add_total(args.total);
+            builder.add_current(args.current);
+            builder.add_id(args.id);
+            builder.add_state(args.state);
+            builder.add_kind(args.kind);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> JobStatusT {
+            let id = self.id();
+            let kind = self.kind();
+            let state = self.state();
+            let current = self.current();
+            let total = self.total();
+            let error = self.error().map(|x| x.to_string());
+            let result = self.result().map(|x| x.into_iter().collect());
+            JobStatusT {
+                id,
+                kind,
+                state,
+                current,
+                total,
+                error,
+                result,
+            }
+        }
+
+        #[inline]
+        pub fn id(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(JobStatus::VT_ID, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn kind(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u8>(JobStatus::VT_KIND, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn state(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u8>(JobStatus::VT_STATE, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn current(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(JobStatus::VT_CURRENT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn total(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(JobStatus::VT_TOTAL, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn error(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(JobStatus::VT_ERROR, None)
+            }
+        }
+        #[inline]
+        pub fn result(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        JobStatus::VT_RESULT,
+                        None,
+                    )
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for JobStatus<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("id", Self::VT_ID, false)?
+                .visit_field::<u8>("kind", Self::VT_KIND, false)?
+                .visit_field::<u8>("state", Self::VT_STATE, false)?
+                .visit_field::<u32>("current", Self::VT_CURRENT, false)?
+                .visit_field::<u32>("total", Self::VT_TOTAL, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("error", Self::VT_ERROR, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "result",
+                    Self::VT_RESULT,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct JobStatusArgs<'a> {
+        pub id: u32,
+        pub kind: u8,
+        pub state: u8,
+        pub current: u32,
+        pub total: u32,
+        pub error: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub result: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    }
+    impl<'a> Default for JobStatusArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            JobStatusArgs {
+                id: 0,
+                kind: 0,
+                state: 0,
+                current: 0,
+                total: 0,
+                error: None,
+                result: None,
+            }
+        }
+    }
+
+    pub struct JobStatusBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> JobStatusBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_id(&mut self, id: u32) {
+            self.fbb_.push_slot::<u32>(JobStatus::VT_ID, id, 0);
+        }
+        #[inline]
+        pub fn add_kind(&mut self, kind: u8) {
+            self.fbb_.push_slot::<u8>(JobStatus::VT_KIND, kind, 0);
+        }
+        #[inline]
+        pub fn add_state(&mut self, state: u8) {
+            self.fbb_.push_slot::<u8>(JobStatus::VT_STATE, state, 0);
+        }
+        #[inline]
+        pub fn add_current(&mut self, current: u32) {
+            self.fbb_
+                .push_slot::<u32>(JobStatus::VT_CURRENT, current, 0);
+        }
+        #[inline]
+        pub fn add_total(&mut self, total: u32) {
+            self.fbb_.push_slot::<u32>(JobStatus::VT_TOTAL, total, 0);
+        }
+        #[inline]
+        pub fn add_error(&mut self, error: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(JobStatus::VT_ERROR, error);
+        }
+        #[inline]
+        pub fn add_result(&mut self, result: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(JobStatus::VT_RESULT, result);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> JobStatusBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            JobStatusBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<JobStatus<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for JobStatus<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("JobStatus");
+            ds.field("id", &self.id());
+            ds.field("kind", &self.kind());
+            ds.field("state", &self.state());
+            ds.field("current", &self.current());
+            ds.field("total", &self.total());
+            ds.field("error", &self.error());
+            ds.field("result", &self.result());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct JobStatusT {
+        pub id: u32,
+        pub kind: u8,
+        pub state: u8,
+        pub current: u32,
+        pub total: u32,
+        pub error: Option<String>,
+        pub result: Option<Vec<u8>>,
+    }
+    impl Default for JobStatusT {
+        fn default() -> Self {
+            Self {
+                id: 0,
+                kind: 0,
+                state: 0,
+                current: 0,
+                total: 0,
+                error: None,
+                result: None,
+            }
+        }
+    }
+    impl JobStatusT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<JobStatus<'b>> {
+            let id = self.id;
+            let kind = self.kind;
+            let state = self.state;
+            let current = self.current;
+            let total = self.total;
+            let error = self.error.as_ref().map(|x| _fbb.create_string(x));
+            let result = self.result.as_ref().map(|x| _fbb.create_vector(x));
+            JobStatus::create(
+                _fbb,
+                &JobStatusArgs {
+                    id,
+                    kind,
+                    state,
+                    current,
+                    total,
+                    error,
+                    result,
+                },
+            )
+        }
+    }
+    pub enum AddressReceiverOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct AddressReceiver<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for AddressReceiver<'a> {
+        type Inner = AddressReceiver<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> AddressReceiver<'a> {
+        pub const VT_TYPECODE: flatbuffers::VOffsetT = 4;
+        pub const VT_NAME: flatbuffers::VOffsetT = 6;
+        pub const VT_DATA: flatbuffers::VOffsetT = 8;
+        pub const VT_ACCOUNT: flatbuffers::VOffsetT = 10;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            AddressReceiver { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args AddressReceiverArgs<'args>,
+        ) -> flatbuffers::WIPOffset<AddressReceiver<'bldr>> {
+            let mut builder = AddressReceiverBuilder::new(_fbb);
+            builder.add_account(args.account);
+            if let Some(x) = args.data {
+                builder.add_data(x);
+            }
+            if let Some(x) = args.name {
+                builder.add_name(x);
+            }
+            builder.add_typecode(args.typecode);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> AddressReceiverT {
+            let typecode = self.typecode();
+            let name = self.name().map(|x| x.to_string());
+            let data = self.data().map(|x| x.into_iter().collect());
+            let account = self.account();
+            AddressReceiverT {
+                typecode,
+                name,
+                data,
+                account,
+            }
+        }
+
+        #[inline]
+        pub fn typecode(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(AddressReceiver::VT_TYPECODE, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn name(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(AddressReceiver::VT_NAME, None)
+            }
+        }
+        #[inline]
+        pub fn data(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        AddressReceiver::VT_DATA,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn account(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(AddressReceiver::VT_ACCOUNT, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for AddressReceiver<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("typecode", Self::VT_TYPECODE, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "data",
+                    Self::VT_DATA,
+                    false,
+                )?
+                .visit_field::<u32>("account", Self::VT_ACCOUNT, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct AddressReceiverArgs<'a> {
+        pub typecode: u32,
+        pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub account: u32,
+    }
+    impl<'a> Default for AddressReceiverArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            AddressReceiverArgs {
+                typecode: 0,
+                name: None,
+                data: None,
+                account: 0,
+            }
+        }
+    }
+
+    pub struct AddressReceiverBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AddressReceiverBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_typecode(&mut self, typecode: u32) {
+            self.fbb_
+                .push_slot::<u32>(AddressReceiver::VT_TYPECODE, typecode, 0);
+        }
+        #[inline]
+        pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(AddressReceiver::VT_NAME, name);
+        }
+        #[inline]
+        pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(AddressReceiver::VT_DATA, data);
+        }
+        #[inline]
+        pub fn add_account(&mut self, account: u32) {
+            self.fbb_
+                .push_slot::<u32>(AddressReceiver::VT_ACCOUNT, account, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> AddressReceiverBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            AddressReceiverBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<AddressReceiver<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for AddressReceiver<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("AddressReceiver");
+            ds.field("typecode", &self.typecode());
+            ds.field("name", &self.name());
+            ds.field("data", &self.data());
+            ds.field("account", &self.account());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct AddressReceiverT {
+        pub typecode: u32,
+        pub name: Option<String>,
+        pub data: Option<Vec<u8>>,
+        pub account: u32,
+    }
+    impl Default for AddressReceiverT {
+        fn default() -> Self {
+            Self {
+                typecode: 0,
+                name: None,
+                data: None,
+                account: 0,
+            }
+        }
+    }
+    impl AddressReceiverT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<AddressReceiver<'b>> {
+            let typecode = self.typecode;
+            let name = self.name.as_ref().map(|x| _fbb.create_string(x));
+            let data = self.data.as_ref().map(|x| _fbb.create_vector(x));
+            let account = self.account;
+            AddressReceiver::create(
+                _fbb,
+                &AddressReceiverArgs {
+                    typecode,
+                    name,
+                    data,
+                    account,
+                },
+            )
+        }
+    }
+    pub enum AddressDiagnosticOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct AddressDiagnostic<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for AddressDiagnostic<'a> {
+        type Inner = AddressDiagnostic<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> AddressDiagnostic<'a> {
+        pub const VT_ADDRESS: flatbuffers::VOffsetT = 4;
+        pub const VT_HRP: flatbuffers::VOffsetT = 6;
+        pub const VT_UNIFIED: flatbuffers::VOffsetT = 8;
+        pub const VT_TEX: flatbuffers::VOffsetT = 10;
+        pub const VT_VERSION: flatbuffers::VOffsetT = 12;
+        pub const VT_PADDING_VALID: flatbuffers::VOffsetT = 14;
+        pub const VT_RECEIVERS: flatbuffers::VOffsetT = 16;
+        pub const VT_OWNED: flatbuffers::VOffsetT = 18;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            AddressDiagnostic { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args AddressDiagnosticArgs<'args>,
+        ) -> flatbuffers::WIPOffset<AddressDiagnostic<'bldr>> {
+            let mut builder = AddressDiagnosticBuilder::new(_fbb);
+            if let Some(x) = args.receivers {
+                builder.add_receivers(x);
+            }
+            if let Some(x) = args.hrp {
+                builder.add_hrp(x);
+            }
+            if let Some(x) = args.address {
+                builder.add_address(x);
+            }
+            builder.add_owned(args.owned);
+            builder.add_padding_valid(args.padding_valid);
+            builder.add_version(args.version);
+            builder.add_tex(args.tex);
+            builder.add_unified(args.unified);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> AddressDiagnosticT {
+            let address = self.address().map(|x| x.to_string());
+            let hrp = self.hrp().map(|x| x.to_string());
+            let unified = self.unified();
+            let tex = self.tex();
+            let version = self.version();
+            let padding_valid = self.padding_valid();
+            let receivers = self
+                .receivers()
+                .map(|x| x.iter().map(|t| t.unpack()).collect());
+            let owned = self.owned();
+            AddressDiagnosticT {
+                address,
+                hrp,
+                unified,
+                tex,
+                version,
+                padding_valid,
+                receivers,
+                owned,
+            }
+        }
+
+        #[inline]
+        pub fn address(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(AddressDiagnostic::VT_ADDRESS, None)
+            }
+        }
+        #[inline]
+        pub fn hrp(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(AddressDiagnostic::VT_HRP, None)
+            }
+        }
+        #[inline]
+        pub fn unified(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(AddressDiagnostic::VT_UNIFIED, Some(false))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn tex(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(AddressDiagnostic::VT_TEX, Some(false))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn version(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(AddressDiagnostic::VT_VERSION, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn padding_valid(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(AddressDiagnostic::VT_PADDING_VALID, Some(false))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn receivers(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AddressReceiver<'a>>>>
+        {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AddressReceiver>>,
+                >>(AddressDiagnostic::VT_RECEIVERS, None)
+            }
+        }
+        #[inline]
+        pub fn owned(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(AddressDiagnostic::VT_OWNED, Some(false))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for AddressDiagnostic<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "address",
+                    Self::VT_ADDRESS,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("hrp", Self::VT_HRP, false)?
+                .visit_field::<bool>("unified", Self::VT_UNIFIED, false)?
+                .visit_field::<bool>("tex", Self::VT_TEX, false)?
+                .visit_field::<u8>("version", Self::VT_VERSION, false)?
+                .visit_field::<bool>("padding_valid", Self::VT_PADDING_VALID, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<AddressReceiver>>,
+                >>("receivers", Self::VT_RECEIVERS, false)?
+                .visit_field::<bool>("owned", Self::VT_OWNED, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct AddressDiagnosticArgs<'a> {
+        pub address: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub hrp: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub unified: bool,
+        pub tex: bool,
+        pub version: u8,
+        pub padding_valid: bool,
+        pub receivers: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AddressReceiver<'a>>>,
+            >,
+        >,
+        pub owned: bool,
+    }
+    impl<'a> Default for AddressDiagnosticArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            AddressDiagnosticArgs {
+                address: None,
+                hrp: None,
+                unified: false,
+                tex: false,
+                version: 0,
+                padding_valid: false,
+                receivers: None,
+                owned: false,
+            }
+        }
+    }
+
+    pub struct AddressDiagnosticBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AddressDiagnosticBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_address(&mut self, address: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                AddressDiagnostic::VT_ADDRESS,
+                address,
+            );
+        }
+        #[inline]
+        pub fn add_hrp(&mut self, hrp: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(AddressDiagnostic::VT_HRP, hrp);
+        }
+        #[inline]
+        pub fn add_unified(&mut self, unified: bool) {
+            self.fbb_
+                .push_slot::<bool>(AddressDiagnostic::VT_UNIFIED, unified, false);
+        }
+        #[inline]
+        pub fn add_tex(&mut self, tex: bool) {
+            self.fbb_
+                .push_slot::<bool>(AddressDiagnostic::VT_TEX, tex, false);
+        }
+        #[inline]
+        pub fn add_version(&mut self, version: u8) {
+            self.fbb_
+                .push_slot::<u8>(AddressDiagnostic::VT_VERSION, version, 0);
+        }
+        #[inline]
+        pub fn add_padding_valid(&mut self, padding_valid: bool) {
+            self.fbb_
+                .push_slot::<bool>(AddressDiagnostic::VT_PADDING_VALID, padding_valid, false);
+        }
+        #[inline]
+        pub fn add_receivers(
+            &mut self,
+            receivers: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<AddressReceiver<'b>>>,
+            >,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                AddressDiagnostic::VT_RECEIVERS,
+                receivers,
+            );
+        }
+        #[inline]
+        pub fn add_owned(&mut self, owned: bool) {
+            self.fbb_
+                .push_slot::<bool>(AddressDiagnostic::VT_OWNED, owned, false);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> AddressDiagnosticBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            AddressDiagnosticBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<AddressDiagnostic<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for AddressDiagnostic<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("AddressDiagnostic");
+            ds.field("address", &self.address());
+            ds.field("hrp", &self.hrp());
+            ds.field("unified", &self.unified());
+            ds.field("tex", &self.tex());
+            ds.field("version", &self.version());
+            ds.field("padding_valid", &self.padding_valid());
+            ds.field("receivers", &self.receivers());
+            ds.field("owned", &self.owned());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct AddressDiagnosticT {
+        pub address: Option<String>,
+        pub hrp: Option<String>,
+        pub unified: bool,
+        pub tex: bool,
+        pub version: u8,
+        pub padding_valid: bool,
+        pub receivers: Option<Vec<AddressReceiverT>>,
+        pub owned: bool,
+    }
+    impl Default for AddressDiagnosticT {
+        fn default() -> Self {
+            Self {
+                address: None,
+                hrp: None,
+                unified: false,
+                tex: false,
+                version: 0,
+                padding_valid: false,
+                receivers: None,
+                owned: false,
+            }
+        }
+    }
+    impl AddressDiagnosticT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<AddressDiagnostic<'b>> {
+            let address = self.address.as_ref().map(|x| _fbb.create_string(x));
+            let hrp = self.hrp.as_ref().map(|x| _fbb.create_string(x));
+            let unified = self.unified;
+            let tex = self.tex;
+            let version = self.version;
+            let padding_valid = self.padding_valid;
+            let receivers = self.receivers.as_ref().map(|x| {
+                let w: Vec<_> = x.iter().map(|t| t.pack(_fbb)).collect();
+                _fbb.create_vector(&w)
+            });
+            let owned = self.owned;
+            AddressDiagnostic::create(
+                _fbb,
+                &AddressDiagnosticArgs {
+                    address,
+                    hrp,
+                    unified,
+                    tex,
+                    version,
+                    padding_valid,
+                    receivers,
+                    owned,
+                },
+            )
+        }
+    }
+    pub enum ScriptUtxoOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct ScriptUtxo<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for ScriptUtxo<'a> {
+        type Inner = ScriptUtxo<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> ScriptUtxo<'a> {
+        pub const VT_ID: flatbuffers::VOffsetT = 4;
+        pub const VT_HEIGHT: flatbuffers::VOffsetT = 6;
+        pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 8;
+        pub const VT_TXID: flatbuffers::VOffsetT = 10;
+        pub const VT_VOUT: flatbuffers::VOffsetT = 12;
+        pub const VT_VALUE: flatbuffers::VOffsetT = 14;
+        pub const VT_SCRIPT_TYPE: flatbuffers::VOffsetT = 16;
+        pub const VT_SCRIPT: flatbuffers::VOffsetT = 18;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            ScriptUtxo { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args ScriptUtxoArgs<'args>,
+        ) -> flatbuffers::WIPOffset<ScriptUtxo<'bldr>> {
+            let mut builder = ScriptUtxoBuilder::new(_fbb);
+            builder.add_value(args.value);
+            if let Some(x) = args.script {
+                builder.add_script(x);
+            }
+            builder.add_vout(args.vout);
+            if let Some(x) = args.txid {
+                builder.add_txid(x);
+            }
+            builder.add_timestamp(args.timestamp);
+            builder.add_height(args.height);
+            builder.add_id(args.id);
+            builder.add_script_type(args.script_type);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> ScriptUtxoT {
+            let id = self.id();
+            let height = self.height();
+            let timestamp = self.timestamp();
+            let txid = self.txid().map(|x| x.into_iter().collect());
+            let vout = self.vout();
+            let value = self.value();
+            let script_type = self.script_type();
+            let script = self.script().map(|x| x.into_iter().collect());
+            ScriptUtxoT {
+                id,
+                height,
+                timestamp,
+                txid,
+                vout,
+                value,
+                script_type,
+                script,
+            }
+        }
+
+        #[inline]
+        pub fn id(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(ScriptUtxo::VT_ID, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn height(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(ScriptUtxo::VT_HEIGHT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn timestamp(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(ScriptUtxo::VT_TIMESTAMP, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn txid(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        ScriptUtxo::VT_TXID,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn vout(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(ScriptUtxo::VT_VOUT, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn value(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u64>(ScriptUtxo::VT_VALUE, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn script_type(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(ScriptUtxo::VT_SCRIPT_TYPE, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn script(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        ScriptUtxo::VT_SCRIPT,
+                        None,
+                    )
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for ScriptUtxo<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("id", Self::VT_ID, false)?
+                .visit_field::<u32>("height", Self::VT_HEIGHT, false)?
+                .visit_field::<u32>("timestamp", Self::VT_TIMESTAMP, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "txid",
+                    Self::VT_TXID,
+                    false,
+                )?
+                .visit_field::<u32>("vout", Self::VT_VOUT, false)?
+                .visit_field::<u64>("value", Self::VT_VALUE, false)?
+                .visit_field::<u8>("script_type", Self::VT_SCRIPT_TYPE, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "script",
+                    Self::VT_SCRIPT,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct ScriptUtxoArgs<'a> {
+        pub id: u32,
+        pub height: u32,
+        pub timestamp: u32,
+        pub txid: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub vout: u32,
+        pub value: u64,
+        pub script_type: u8,
+        pub script: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    }
+    impl<'a> Default for ScriptUtxoArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            ScriptUtxoArgs {
+                id: 0,
+                height: 0,
+                timestamp: 0,
+                txid: None,
+                vout: 0,
+                value: 0,
+                script_type: 0,
+                script: None,
+            }
+        }
+    }
+
+    pub struct ScriptUtxoBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ScriptUtxoBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_id(&mut self, id: u32) {
+            self.fbb_.push_slot::<u32>(ScriptUtxo::VT_ID, id, 0);
+        }
+        #[inline]
+        pub fn add_height(&mut self, height: u32) {
+            self.fbb_.push_slot::<u32>(ScriptUtxo::VT_HEIGHT, height, 0);
+        }
+        #[inline]
+        pub fn add_timestamp(&mut self, timestamp: u32) {
+            self.fbb_
+                .push_slot::<u32>(ScriptUtxo::VT_TIMESTAMP, timestamp, 0);
+        }
+        #[inline]
+        pub fn add_txid(&mut self, txid: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(ScriptUtxo::VT_TXID, txid);
+        }
+        #[inline]
+        pub fn add_vout(&mut self, vout: u32) {
+            self.fbb_.push_slot::<u32>(ScriptUtxo::VT_VOUT, vout, 0);
+        }
+        #[inline]
+        pub fn add_value(&mut self, value: u64) {
+            self.fbb_.push_slot::<u64>(ScriptUtxo::VT_VALUE, value, 0);
+        }
+        #[inline]
+        pub fn add_script_type(&mut self, script_type: u8) {
+            self.fbb_
+                .push_slot::<u8>(ScriptUtxo::VT_SCRIPT_TYPE, script_type, 0);
+        }
+        #[inline]
+        pub fn add_script(&mut self, script: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(ScriptUtxo::VT_SCRIPT, script);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> ScriptUtxoBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            ScriptUtxoBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<ScriptUtxo<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for ScriptUtxo<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("ScriptUtxo");
+            ds.field("id", &self.id());
+            ds.field("height", &self.height());
+            ds.field("timestamp", &self.timestamp());
+            ds.field("txid", &self.txid());
+            ds.field("vout", &self.vout());
+            ds.field("value", &self.value());
+            ds.field("script_type", &self.script_type());
+            ds.field("script", &self.script());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct ScriptUtxoT {
+        pub id: u32,
+        pub height: u32,
+        pub timestamp: u32,
+        pub txid: Option<Vec<u8>>,
+        pub vout: u32,
+        pub value: u64,
+        pub script_type: u8,
+        pub script: Option<Vec<u8>>,
+    }
+    impl Default for ScriptUtxoT {
+        fn default() -> Self {
+            Self {
+                id: 0,
+                height: 0,
+                timestamp: 0,
+                txid: None,
+                vout: 0,
+                value: 0,
+                script_type: 0,
+                script: None,
+            }
+        }
+    }
+    impl ScriptUtxoT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<ScriptUtxo<'b>> {
+            let id = self.id;
+            let height = self.height;
+            let timestamp = self.timestamp;
+            let txid = self.txid.as_ref().map(|x| _fbb.create_vector(x));
+            let vout = self.vout;
+            let value = self.value;
+            let script_type = self.script_type;
+            let script = self.script.as_ref().map(|x| _fbb.create_vector(x));
+            ScriptUtxo::create(
+                _fbb,
+                &ScriptUtxoArgs {
+                    id,
+                    height,
+                    timestamp,
+                    txid,
+                    vout,
+                    value,
+                    script_type,
+                    script,
+                },
+            )
+        }
+    }
+    pub enum LogEntryOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct LogEntry<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for LogEntry<'a> {
+        type Inner = LogEntry<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> LogEntry<'a> {
+        pub const VT_ID: flatbuffers::VOffsetT = 4;
+        pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 6;
+        pub const VT_LEVEL: flatbuffers::VOffsetT = 8;
+        pub const VT_TARGET: flatbuffers::VOffsetT = 10;
+        pub const VT_MESSAGE: flatbuffers::VOffsetT = 12;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            LogEntry { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args LogEntryArgs<'args>,
+        ) -> flatbuffers::WIPOffset<LogEntry<'bldr>> {
+            let mut builder = LogEntryBuilder::new(_fbb);
+            if let Some(x) = args.message {
+                builder.add_message(x);
+            }
+            if let Some(x) = args.target {
+                builder.add_target(x);
+            }
+            builder.add_timestamp(args.timestamp);
+            builder.add_id(args.id);
+            builder.add_level(args.level);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> LogEntryT {
+            let id = self.id();
+            let timestamp = self.timestamp();
+            let level = self.level();
+            let target = self.target().map(|x| x.to_string());
+            let message = self.message().map(|x| x.to_string());
+            LogEntryT {
+                id,
+                timestamp,
+                level,
+                target,
+                message,
+            }
+        }
+
+        #[inline]
+        pub fn id(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(LogEntry::VT_ID, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn timestamp(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(LogEntry::VT_TIMESTAMP, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn level(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u8>(LogEntry::VT_LEVEL, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn target(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(LogEntry::VT_TARGET, None)
+            }
+        }
+        #[inline]
+        pub fn message(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(LogEntry::VT_MESSAGE, None)
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for LogEntry<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("id", Self::VT_ID, false)?
+                .visit_field::<u32>("timestamp", Self::VT_TIMESTAMP, false)?
+                .visit_field::<u8>("level", Self::VT_LEVEL, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "target",
+                    Self::VT_TARGET,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "message",
+                    Self::VT_MESSAGE,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct LogEntryArgs<'a> {
+        pub id: u32,
+        pub timestamp: u32,
+        pub level: u8,
+        pub target: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub message: Option<flatbuffers::WIPOffset<&'a str>>,
+    }
+    impl<'a> Default for LogEntryArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            LogEntryArgs {
+                id: 0,
+                timestamp: 0,
+                level: 0,
+                target: None,
+                message: None,
+            }
+        }
+    }
+
+    pub struct LogEntryBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LogEntryBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_id(&mut self, id: u32) {
+            self.fbb_.push_slot::<u32>(LogEntry::VT_ID, id, 0);
+        }
+        #[inline]
+        pub fn add_timestamp(&mut self, timestamp: u32) {
+            self.fbb_
+                .push_slot::<u32>(LogEntry::VT_TIMESTAMP, timestamp, 0);
+        }
+        #[inline]
+        pub fn add_level(&mut self, level: u8) {
+            self.fbb_.push_slot::<u8>(LogEntry::VT_LEVEL, level, 0);
+        }
+        #[inline]
+        pub fn add_target(&mut self, target: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(LogEntry::VT_TARGET, target);
+        }
+        #[inline]
+        pub fn add_message(&mut self, message: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(LogEntry::VT_MESSAGE, message);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> LogEntryBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            LogEntryBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<LogEntry<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for LogEntry<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("LogEntry");
+            ds.field("id", &self.id());
+            ds.field("timestamp", &self.timestamp());
+            ds.field("level", &self.level());
+            ds.field("target", &self.target());
+            ds.field("message", &self.message());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct LogEntryT {
+        pub id: u32,
+        pub timestamp: u32,
+        pub level: u8,
+        pub target: Option<String>,
+        pub message: Option<String>,
+    }
+    impl Default for LogEntryT {
+        fn default() -> Self {
+            Self {
+                id: 0,
+                timestamp: 0,
+                level: 0,
+                target: None,
+                message: None,
+            }
+        }
+    }
+    impl LogEntryT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<LogEntry<'b>> {
+            let id = self.id;
+            let timestamp = self.timestamp;
+            let level = self.level;
+            let target = self.target.as_ref().map(|x| _fbb.create_string(x));
+            let message = self.message.as_ref().map(|x| _fbb.create_string(x));
+            LogEntry::create(
+                _fbb,
+                &LogEntryArgs {
+                    id,
+                    timestamp,
+                    level,
+                    target,
+                    message,
+                },
+            )
+        }
+    }
+    pub enum ViewingKeysOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct ViewingKeys<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for ViewingKeys<'a> {
+        type Inner = ViewingKeys<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> ViewingKeys<'a> {
+        pub const VT_KEYS: flatbuffers::VOffsetT = 4;
+        pub const VT_PREFIX: flatbuffers::VOffsetT = 6;
+        pub const VT_BIRTH: flatbuffers::VOffsetT = 8;
+        pub const VT_POOLS: flatbuffers::VOffsetT = 10;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            ViewingKeys { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args ViewingKeysArgs<'args>,
+        ) -> flatbuffers::WIPOffset<ViewingKeys<'bldr>> {
+            let mut builder = ViewingKeysBuilder::new(_fbb);
+            builder.add_birth(args.birth);
+            if let Some(x) = args.prefix {
+                builder.add_prefix(x);
+            }
+            if let Some(x) = args.keys {
+                builder.add_keys(x);
+            }
+            builder.add_pools(args.pools);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> ViewingKeysT {
+            let keys = self
+                .keys()
+                .map(|x| x.iter().map(|s| s.to_string()).collect());
+            let prefix = self.prefix().map(|x| x.to_string());
+            let birth = self.birth();
+            let pools = self.pools();
+            ViewingKeysT {
+                keys,
+                prefix,
+                birth,
+                pools,
+            }
+        }
+
+        #[inline]
+        pub fn keys(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+                >>(ViewingKeys::VT_KEYS, None)
+            }
+        }
+        #[inline]
+        pub fn prefix(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(ViewingKeys::VT_PREFIX, None)
+            }
+        }
+        #[inline]
+        pub fn birth(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(ViewingKeys::VT_BIRTH, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn pools(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u8>(ViewingKeys::VT_POOLS, Some(0)).unwrap() }
+        }
+    }
+
+    impl flatbuffers::Verifiable for ViewingKeys<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+                >>("keys", Self::VT_KEYS, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "prefix",
+                    Self::VT_PREFIX,
+                    false,
+                )?
+                .visit_field::<u32>("birth", Self::VT_BIRTH, false)?
+                .visit_field::<u8>("pools", Self::VT_POOLS, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct ViewingKeysArgs<'a> {
+        pub keys: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+        >,
+        pub prefix: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub birth: u32,
+        pub pools: u8,
+    }
+    impl<'a> Default for ViewingKeysArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            ViewingKeysArgs {
+                keys: None,
+                prefix: None,
+                birth: 0,
+                pools: 0,
+            }
+        }
+    }
+
+    pub struct ViewingKeysBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ViewingKeysBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_keys(
+            &mut self,
+            keys: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+            >,
+        ) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(ViewingKeys::VT_KEYS, keys);
+        }
+        #[inline]
+        pub fn add_prefix(&mut self, prefix: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(ViewingKeys::VT_PREFIX, prefix);
+        }
+        #[inline]
+        pub fn add_birth(&mut self, birth: u32) {
+            self.fbb_.push_slot::<u32>(ViewingKeys::VT_BIRTH, birth, 0);
+        }
+        #[inline]
+        pub fn add_pools(&mut self, pools: u8) {
+            self.fbb_.push_slot::<u8>(ViewingKeys::VT_POOLS, pools, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> ViewingKeysBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            ViewingKeysBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<ViewingKeys<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for ViewingKeys<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("ViewingKeys");
+            ds.field("keys", &self.keys());
+            ds.field("prefix", &self.prefix());
+            ds.field("birth", &self.birth());
+            ds.field("pools", &self.pools());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct ViewingKeysT {
+        pub keys: Option<Vec<String>>,
+        pub prefix: Option<String>,
+        pub birth: u32,
+        pub pools: u8,
+    }
+    impl Default for ViewingKeysT {
+        fn default() -> Self {
+            Self {
+                keys: None,
+                prefix: None,
+                birth: 0,
+                pools: 0,
+            }
+        }
+    }
+    impl ViewingKeysT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<ViewingKeys<'b>> {
+            let keys = self.keys.as_ref().map(|x| {
+                let w: Vec<_> = x.iter().map(|s| _fbb.create_string(s)).collect();
+                _fbb.create_vector(&w)
+            });
+            let prefix = self.prefix.as_ref().map(|x| _fbb.create_string(x));
+            let birth = self.birth;
+            let pools = self.pools;
+            ViewingKeys::create(
+                _fbb,
+                &ViewingKeysArgs {
+                    keys,
+                    prefix,
+                    birth,
+                    pools,
+                },
+            )
+        }
+    }
+    pub enum BulkAccountsOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct BulkAccounts<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for BulkAccounts<'a> {
+        type Inner = BulkAccounts<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> BulkAccounts<'a> {
+        pub const VT_ACCOUNTS: flatbuffers::VOffsetT = 4;
+        pub const VT_DUPLICATES: flatbuffers::VOffsetT = 6;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            BulkAccounts { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args BulkAccountsArgs<'args>,
+        ) -> flatbuffers::WIPOffset<BulkAccounts<'bldr>> {
+            let mut builder = BulkAccountsBuilder::new(_fbb);
+            builder.add_duplicates(args.duplicates);
+            if let Some(x) = args.accounts {
+                builder.add_accounts(x);
+            }
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> BulkAccountsT {
+            let accounts = self.accounts().map(|x| x.into_iter().collect());
+            let duplicates = self.duplicates();
+            BulkAccountsT {
+                accounts,
+                duplicates,
+            }
+        }
+
+        #[inline]
+        pub fn accounts(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                        BulkAccounts::VT_ACCOUNTS,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn duplicates(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(BulkAccounts::VT_DUPLICATES, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for BulkAccounts<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                    "accounts",
+                    Self::VT_ACCOUNTS,
+                    false,
+                )?
+                .visit_field::<u32>("duplicates", Self::VT_DUPLICATES, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct BulkAccountsArgs<'a> {
+        pub accounts: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+        pub duplicates: u32,
+    }
+    impl<'a> Default for BulkAccountsArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            BulkAccountsArgs {
+                accounts: None,
+                duplicates: 0,
+            }
+        }
+    }
+
+    pub struct BulkAccountsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> BulkAccountsBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_accounts(
+            &mut self,
+            accounts: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+        ) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(BulkAccounts::VT_ACCOUNTS, accounts);
+        }
+        #[inline]
+        pub fn add_duplicates(&mut self, duplicates: u32) {
+            self.fbb_
+                .push_slot::<u32>(BulkAccounts::VT_DUPLICATES, duplicates, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> BulkAccountsBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            BulkAccountsBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<BulkAccounts<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for BulkAccounts<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("BulkAccounts");
+            ds.field("accounts", &self.accounts());
+            ds.field("duplicates", &self.duplicates());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct BulkAccountsT {
+        pub accounts: Option<Vec<u32>>,
+        pub duplicates: u32,
+    }
+    impl Default for BulkAccountsT {
+        fn default() -> Self {
+            Self {
+                accounts: None,
+                duplicates: 0,
+            }
+        }
+    }
+    impl BulkAccountsT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<BulkAccounts<'b>> {
+            let accounts = self.accounts.as_ref().map(|x| _fbb.create_vector(x));
+            let duplicates = self.duplicates;
+            BulkAccounts::create(
+                _fbb,
+                &BulkAccountsArgs {
+                    accounts,
+                    duplicates,
+                },
+            )
+        }
+    }
+    pub enum DiscoveredAccountOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct DiscoveredAccount<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for DiscoveredAccount<'a> {
+        type Inner = DiscoveredAccount<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> DiscoveredAccount<'a> {
+        pub const VT_ACCOUNT: flatbuffers::VOffsetT = 4;
+        pub const VT_AINDEX: flatbuffers::VOffsetT = 6;
+        pub const VT_CREATED: flatbuffers::VOffsetT = 8;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            DiscoveredAccount { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args DiscoveredAccountArgs,
+        ) -> flatbuffers::WIPOffset<DiscoveredAccount<'bldr>> {
+            let mut builder = DiscoveredAccountBuilder::new(_fbb);
+            builder.add_aindex(args.aindex);
+            builder.add_account(args.account);
+            builder.add_created(args.created);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> DiscoveredAccountT {
+            let account = self.account();
+            let aindex = self.aindex();
+            let created = self.created();
+            DiscoveredAccountT {
+                account,
+                aindex,
+                created,
+            }
+        }
+
+        #[inline]
+        pub fn account(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(DiscoveredAccount::VT_ACCOUNT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn aindex(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(DiscoveredAccount::VT_AINDEX, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn created(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(DiscoveredAccount::VT_CREATED, Some(false))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for DiscoveredAccount<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("account", Self::VT_ACCOUNT, false)?
+                .visit_field::<u32>("aindex", Self::VT_AINDEX, false)?
+                .visit_field::<bool>("created", Self::VT_CREATED, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct DiscoveredAccountArgs {
+        pub account: u32,
+        pub aindex: u32,
+        pub created: bool,
+    }
+    impl<'a> Default for DiscoveredAccountArgs {
+        #[inline]
+        fn default() -> Self {
+            DiscoveredAccountArgs {
+                account: 0,
+                aindex: 0,
+                created: false,
+            }
+        }
+    }
+
+    pub struct DiscoveredAccountBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DiscoveredAccountBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_account(&mut self, account: u32) {
+            self.fbb_
+                .push_slot::<u32>(DiscoveredAccount::VT_ACCOUNT, account, 0);
+        }
+        #[inline]
+        pub fn add_aindex(&mut self, aindex: u32) {
+            self.fbb_
+                .push_slot::<u32>(DiscoveredAccount::VT_AINDEX, aindex, 0);
+        }
+        #[inline]
+        pub fn add_created(&mut self, created: bool) {
+            self.fbb_
+                .push_slot::<bool>(DiscoveredAccount::VT_CREATED, created, false);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> DiscoveredAccountBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            DiscoveredAccountBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<DiscoveredAccount<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for DiscoveredAccount<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("DiscoveredAccount");
+            ds.field("account", &self.account());
+            ds.field("aindex", &self.aindex());
+            ds.field("created", &self.created());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct DiscoveredAccountT {
+        pub account: u32,
+        pub aindex: u32,
+        pub created: bool,
+    }
+    impl Default for DiscoveredAccountT {
+        fn default() -> Self {
+            Self {
+                account: 0,
+                aindex: 0,
+                created: false,
+            }
+        }
+    }
+    impl DiscoveredAccountT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<DiscoveredAccount<'b>> {
+            let account = self.account;
+            let aindex = self.aindex;
+            let created = self.created;
+            DiscoveredAccount::create(
+                _fbb,
+                &DiscoveredAccountArgs {
+                    account,
+                    aindex,
+                    created,
+                },
+            )
+        }
+    }
+    pub enum PaymentRequestStatusOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct PaymentRequestStatus<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for PaymentRequestStatus<'a> {
+        type Inner = PaymentRequestStatus<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> PaymentRequestStatus<'a> {
+        pub const VT_STATUS: flatbuffers::VOffsetT = 4;
+        pub const VT_SIGNER_KEY: flatbuffers::VOffsetT = 6;
+        pub const VT_REQUESTER: flatbuffers::VOffsetT = 8;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            PaymentRequestStatus { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args PaymentRequestStatusArgs<'args>,
+        ) -> flatbuffers::WIPOffset<PaymentRequestStatus<'bldr>> {
+            let mut builder = PaymentRequestStatusBuilder::new(_fbb);
+            if let Some(x) = args.requester {
+                builder.add_requester(x);
+            }
+            if let Some(x) = args.signer_key {
+                builder.add_signer_key(x);
+            }
+            builder.add_status(args.status);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> PaymentRequestStatusT {
+            let status = self.status();
+            let signer_key = self.signer_key().map(|x| x.to_string());
+            let requester = self.requester().map(|x| x.to_string());
+            PaymentRequestStatusT {
+                status,
+                signer_key,
+                requester,
+            }
+        }
+
+        #[inline]
+        pub fn status(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(PaymentRequestStatus::VT_STATUS, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn signer_key(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(
+                    PaymentRequestStatus::VT_SIGNER_KEY,
+                    None,
+                )
+            }
+        }
+        #[inline]
+        pub fn requester(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(
+                    PaymentRequestStatus::VT_REQUESTER,
+                    None,
+                )
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for PaymentRequestStatus<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u8>("status", Self::VT_STATUS, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "signer_key",
+                    Self::VT_SIGNER_KEY,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "requester",
+                    Self::VT_REQUESTER,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct PaymentRequestStatusArgs<'a> {
+        pub status: u8,
+        pub signer_key: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub requester: Option<flatbuffers::WIPOffset<&'a str>>,
+    }
+    impl<'a> Default for PaymentRequestStatusArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            PaymentRequestStatusArgs {
+                status: 0,
+                signer_key: None,
+                requester: None,
+            }
+        }
+    }
+
+    pub struct PaymentRequestStatusBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PaymentRequestStatusBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_status(&mut self, status: u8) {
+            self.fbb_
+                .push_slot::<u8>(PaymentRequestStatus::VT_STATUS, status, 0);
+        }
+        #[inline]
+        pub fn add_signer_key(&mut self, signer_key: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                PaymentRequestStatus::VT_SIGNER_KEY,
+                signer_key,
+            );
+        }
+        #[inline]
+        pub fn add_requester(&mut self, requester: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                PaymentRequestStatus::VT_REQUESTER,
+                requester,
+            );
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> PaymentRequestStatusBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            PaymentRequestStatusBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<PaymentRequestStatus<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for PaymentRequestStatus<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("PaymentRequestStatus");
+            ds.field("status", &self.status());
+            ds.field("signer_key", &self.signer_key());
+            ds.field("requester", &self.requester());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct PaymentRequestStatusT {
+        pub status: u8,
+        pub signer_key: Option<String>,
+        pub requester: Option<String>,
+    }
+    impl Default for PaymentRequestStatusT {
+        fn default() -> Self {
+            Self {
+                status: 0,
+                signer_key: None,
+                requester: None,
+            }
+        }
+    }
+    impl PaymentRequestStatusT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<PaymentRequestStatus<'b>> {
+            let status = self.status;
+            let signer_key = self.signer_key.as_ref().map(|x| _fbb.create_string(x));
+            let requester = self.requester.as_ref().map(|x| _fbb.create_string(x));
+            PaymentRequestStatus::create(
+                _fbb,
+                &PaymentRequestStatusArgs {
+                    status,
+                    signer_key,
+                    requester,
+                },
+            )
+        }
+    }
+    pub enum OutgoingMessageOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct OutgoingMessage<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for OutgoingMessage<'a> {
+        type Inner = OutgoingMessage<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> OutgoingMessage<'a> {
+        pub const VT_ID: flatbuffers::VOffsetT = 4;
+        pub const VT_ACCOUNT: flatbuffers::VOffsetT = 6;
+        pub const VT_CONTACT: flatbuffers::VOffsetT = 8;
+        pub const VT_RECIPIENT: flatbuffers::VOffsetT = 10;
+        pub const VT_SUBJECT: flatbuffers::VOffsetT = 12;
+        pub const VT_BODY: flatbuffers::VOffsetT = 14;
+        pub const VT_STATUS: flatbuffers::VOffsetT = 16;
+        pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 18;
+        pub const VT_TXID: flatbuffers::VOffsetT = 20;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            OutgoingMessage { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args OutgoingMessageArgs<'args>,
+        ) -> flatbuffers::WIPOffset<OutgoingMessage<'bldr>> {
+            let mut builder = OutgoingMessageBuilder::new(_fbb);
+            if let Some(x) = args.txid {
+                builder.add_txid(x);
+            }
+            builder.add_timestamp(args.timestamp);
+            if let Some(x) = args.body {
+                builder.add_body(x);
+            }
+            if let Some(x) = args.subject {
+                builder.add_subject(x);
+            }
+            if let Some(x) = args.recipient {
+                builder.add_recipient(x);
+            }
+            builder.add_contact(args.contact);
+            builder.add_account(args.account);
+            builder.add_id(args.id);
+            builder.add_status(args.status);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> OutgoingMessageT {
+            let id = self.id();
+            let account = self.account();
+            let contact = self.contact();
+            let recipient = self.recipient().map(|x| x.to_string());
+            let subject = self.subject().map(|x| x.to_string());
+            let body = self.body().map(|x| x.to_string());
+            let status = self.status();
+            let timestamp = self.timestamp();
+            let txid = self.txid().map(|x| x.into_iter().collect());
+            OutgoingMessageT {
+                id,
+                account,
+                contact,
+                recipient,
+                subject,
+                body,
+                status,
+                timestamp,
+                txid,
+            }
+        }
+
+        #[inline]
+        pub fn id(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(OutgoingMessage::VT_ID, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn account(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(OutgoingMessage::VT_ACCOUNT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn contact(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(OutgoingMessage::VT_CONTACT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn recipient(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(OutgoingMessage::VT_RECIPIENT, None)
+            }
+        }
+        #[inline]
+        pub fn subject(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(OutgoingMessage::VT_SUBJECT, None)
+            }
+        }
+        #[inline]
+        pub fn body(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(OutgoingMessage::VT_BODY, None)
+            }
+        }
+        #[inline]
+        pub fn status(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(OutgoingMessage::VT_STATUS, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn timestamp(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(OutgoingMessage::VT_TIMESTAMP, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn txid(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        OutgoingMessage::VT_TXID,
+                        None,
+                    )
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for OutgoingMessage<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("id", Self::VT_ID, false)?
+                .visit_field::<u32>("account", Self::VT_ACCOUNT, false)?
+                .visit_field::<u32>("contact", Self::VT_CONTACT, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "recipient",
+                    Self::VT_RECIPIENT,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "subject",
+                    Self::VT_SUBJECT,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("body", Self::VT_BODY, false)?
+                .visit_field::<u8>("status", Self::VT_STATUS, false)?
+                .visit_field::<u32>("timestamp", Self::VT_TIMESTAMP, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "txid",
+                    Self::VT_TXID,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct OutgoingMessageArgs<'a> {
+        pub id: u32,
+        pub account: u32,
+        pub contact: u32,
+        pub recipient: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub subject: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub body: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub status: u8,
+        pub timestamp: u32,
+        pub txid: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    }
+    impl<'a> Default for OutgoingMessageArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            OutgoingMessageArgs {
+                id: 0,
+                account: 0,
+                contact: 0,
+                recipient: None,
+                subject: None,
+                body: None,
+                status: 0,
+                timestamp: 0,
+                txid: None,
+            }
+        }
+    }
+
+    pub struct OutgoingMessageBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> OutgoingMessageBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_id(&mut self, id: u32) {
+            self.fbb_.push_slot::<u32>(OutgoingMessage::VT_ID, id, 0);
+        }
+        #[inline]
+        pub fn add_account(&mut self, account: u32) {
+            self.fbb_
+                .push_slot::<u32>(OutgoingMessage::VT_ACCOUNT, account, 0);
+        }
+        #[inline]
+        pub fn add_contact(&mut self, contact: u32) {
+            self.fbb_
+                .push_slot::<u32>(OutgoingMessage::VT_CONTACT, contact, 0);
+        }
+        #[inline]
+        pub fn add_recipient(&mut self, recipient: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                OutgoingMessage::VT_RECIPIENT,
+                recipient,
+            );
+        }
+        #[inline]
+        pub fn add_subject(&mut self, subject: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                OutgoingMessage::VT_SUBJECT,
+                subject,
+            );
+        }
+        #[inline]
+        pub fn add_body(&mut self, body: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(OutgoingMessage::VT_BODY, body);
+        }
+        #[inline]
+        pub fn add_status(&mut self, status: u8) {
+            self.fbb_
+                .push_slot::<u8>(OutgoingMessage::VT_STATUS, status, 0);
+        }
+        #[inline]
+        pub fn add_timestamp(&mut self, timestamp: u32) {
+            self.fbb_
+                .push_slot::<u32>(OutgoingMessage::VT_TIMESTAMP, timestamp, 0);
+        }
+        #[inline]
+        pub fn add_txid(&mut self, txid: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(OutgoingMessage::VT_TXID, txid);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> OutgoingMessageBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            OutgoingMessageBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<OutgoingMessage<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for OutgoingMessage<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("OutgoingMessage");
+            ds.field("id", &self.id());
+            ds.field("account", &self.account());
+            ds.field("contact", &self.contact());
+            ds.field("recipient", &self.recipient());
+            ds.field("subject", &self.subject());
+            ds.field("body", &self.body());
+            ds.field("status", &self.status());
+            ds.field("timestamp", &self.timestamp());
+            ds.field("txid", &self.txid());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct OutgoingMessageT {
+        pub id: u32,
+        pub account: u32,
+        pub contact: u32,
+        pub recipient: Option<String>,
+        pub subject: Option<String>,
+        pub body: Option<String>,
+        pub status: u8,
+        pub timestamp: u32,
+        pub txid: Option<Vec<u8>>,
+    }
+    impl Default for OutgoingMessageT {
+        fn default() -> Self {
+            Self {
+                id: 0,
+                account: 0,
+                contact: 0,
+                recipient: None,
+                subject: None,
+                body: None,
+                status: 0,
+                timestamp: 0,
+                txid: None,
+            }
+        }
+    }
+    impl OutgoingMessageT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<OutgoingMessage<'b>> {
+            let id = self.id;
+            let account = self.account;
+            let contact = self.contact;
+            let recipient = self.recipient.as_ref().map(|x| _fbb.create_string(x));
+            let subject = self.subject.as_ref().map(|x| _fbb.create_string(x));
+            let body = self.body.as_ref().map(|x| _fbb.create_string(x));
+            let status = self.status;
+            let timestamp = self.timestamp;
+            let txid = self.txid.as_ref().map(|x| _fbb.create_vector(x));
+            OutgoingMessage::create(
+                _fbb,
+                &OutgoingMessageArgs {
+                    id,
+                    account,
+                    contact,
+                    recipient,
+                    subject,
+                    body,
+                    status,
+                    timestamp,
+                    txid,
+                },
+            )
+        }
+    }
+    pub enum BlockInfoOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct BlockInfo<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for BlockInfo<'a> {
+        type Inner = BlockInfo<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> BlockInfo<'a> {
+        pub const VT_HEIGHT: flatbuffers::VOffsetT = 4;
+        pub const VT_HASH: flatbuffers::VOffsetT = 6;
+        pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 8;
+        pub const VT_NUM_TXS: flatbuffers::VOffsetT = 10;
+        pub const VT_NUM_SPENDS: flatbuffers::VOffsetT = 12;
+        pub const VT_NUM_OUTPUTS: flatbuffers::VOffsetT = 14;
+        pub const VT_NUM_ACTIONS: flatbuffers::VOffsetT = 16;
+        pub const VT_SIZE: flatbuffers::VOffsetT = 18;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            BlockInfo { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args BlockInfoArgs<'args>,
+        ) -> flatbuffers::WIPOffset<BlockInfo<'bldr>> {
+            let mut builder = BlockInfoBuilder::new(_fbb);
+            builder.add_size(args.size);
+            builder.add_num_actions(args.num_actions);
+            builder.add_num_outputs(args.num_outputs);
+            builder.add_num_spends(args.num_spends);
+            builder.add_num_txs(args.num_txs);
+            builder.add_timestamp(args.timestamp);
+            if let Some(x) = args.hash {
+                builder.add_hash(x);
+            }
+            builder.add_height(args.height);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> BlockInfoT {
+            let height = self.height();
+            let hash = self.hash().map(|x| x.into_iter().collect());
+            let timestamp = self.timestamp();
+            let num_txs = self.num_txs();
+            let num_spends = self.num_spends();
+            let num_outputs = self.num_outputs();
+            let num_actions = self.num_actions();
+            let size = self.size();
+            BlockInfoT {
+                height,
+                hash,
+                timestamp,
+                num_txs,
+                num_spends,
+                num_outputs,
+                num_actions,
+                size,
+            }
+        }
+
+        #[inline]
+        pub fn height(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(BlockInfo::VT_HEIGHT, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn hash(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        BlockInfo::VT_HASH,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn timestamp(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(BlockInfo::VT_TIMESTAMP, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn num_txs(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(BlockInfo::VT_NUM_TXS, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn num_spends(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(BlockInfo::VT_NUM_SPENDS, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn num_outputs(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(BlockInfo::VT_NUM_OUTPUTS, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn num_actions(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(BlockInfo::VT_NUM_ACTIONS, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn size(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(BlockInfo::VT_SIZE, Some(0)).unwrap() }
+        }
+    }
+
+    impl flatbuffers::Verifiable for BlockInfo<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("height", Self::VT_HEIGHT, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "hash",
+                    Self::VT_HASH,
+                    false,
+                )?
+                .visit_field::<u32>("timestamp", Self::VT_TIMESTAMP, false)?
+                .visit_field::<u32>("num_txs", Self::VT_NUM_TXS, false)?
+                .visit_field::<u32>("num_spends", Self::VT_NUM_SPENDS, false)?
+                .visit_field::<u32>("num_outputs", Self::VT_NUM_OUTPUTS, false)?
+                .visit_field::<u32>("num_actions", Self::VT_NUM_ACTIONS, false)?
+                .visit_field::<u32>("size", Self::VT_SIZE, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct BlockInfoArgs<'a> {
+        pub height: u32,
+        pub hash: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub timestamp: u32,
+        pub num_txs: u32,
+        pub num_spends: u32,
+        pub num_outputs: u32,
+        pub num_actions: u32,
+        pub size: u32,
+    }
+    impl<'a> Default for BlockInfoArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            BlockInfoArgs {
+                height: 0,
+                hash: None,
+                timestamp: 0,
+                num_txs: 0,
+                num_spends: 0,
+                num_outputs: 0,
+                num_actions: 0,
+                size: 0,
+            }
+        }
+    }
+
+    pub struct BlockInfoBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> BlockInfoBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_height(&mut self, height: u32) {
+            self.fbb_.push_slot::<u32>(BlockInfo::VT_HEIGHT, height, 0);
+        }
+        #[inline]
+        pub fn add_hash(&mut self, hash: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(BlockInfo::VT_HASH, hash);
+        }
+        #[inline]
+        pub fn add_timestamp(&mut self, timestamp: u32) {
+            self.fbb_
+                .push_slot::<u32>(BlockInfo::VT_TIMESTAMP, timestamp, 0);
+        }
+        #[inline]
+        pub fn add_num_txs(&mut self, num_txs: u32) {
+            self.fbb_
+                .push_slot::<u32>(BlockInfo::VT_NUM_TXS, num_txs, 0);
+        }
+        #[inline]
+        pub fn add_num_spends(&mut self, num_spends: u32) {
+            self.fbb_
+                .push_slot::<u32>(BlockInfo::VT_NUM_SPENDS, num_spends, 0);
+        }
+        #[inline]
+        pub fn add_num_outputs(&mut self, num_outputs: u32) {
+            self.fbb_
+                .push_slot::<u32>(BlockInfo::VT_NUM_OUTPUTS, num_outputs, 0);
+        }
+        #[inline]
+        pub fn add_num_actions(&mut self, num_actions: u32) {
+            self.fbb_
+                .push_slot::<u32>(BlockInfo::VT_NUM_ACTIONS, num_actions, 0);
+        }
+        #[inline]
+        pub fn add_size(&mut self, size: u32) {
+            self.fbb_.push_slot::<u32>(BlockInfo::VT_SIZE, size, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> BlockInfoBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            BlockInfoBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<BlockInfo<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for BlockInfo<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("BlockInfo");
+            ds.field("height", &self.height());
+            ds.field("hash", &self.hash());
+            ds.field("timestamp", &self.timestamp());
+            ds.field("num_txs", &self.num_txs());
+            ds.field("num_spends", &self.num_spends());
+            ds.field("num_outputs", &self.num_outputs());
+            ds.field("num_actions", &self.num_actions());
+            ds.field("size", &self.size());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct BlockInfoT {
+        pub height: u32,
+        pub hash: Option<Vec<u8>>,
+        pub timestamp: u32,
+        pub num_txs: u32,
+        pub num_spends: u32,
+        pub num_outputs: u32,
+        pub num_actions: u32,
+        pub size: u32,
+    }
+    impl Default for BlockInfoT {
+        fn default() -> Self {
+            Self {
+                height: 0,
+                hash: None,
+                timestamp: 0,
+                num_txs: 0,
+                num_spends: 0,
+                num_outputs: 0,
+                num_actions: 0,
+                size: 0,
+            }
+        }
+    }
+    impl BlockInfoT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<BlockInfo<'b>> {
+            let height = self.height;
+            let hash = self.hash.as_ref().map(|x| _fbb.create_vector(x));
+            let timestamp = self.timestamp;
+            let num_txs = self.num_txs;
+            let num_spends = self.num_spends;
+            let num_outputs = self.num_outputs;
+            let num_actions = self.num_actions;
+            let size = self.size;
+            BlockInfo::create(
+                _fbb,
+                &BlockInfoArgs {
+                    height,
+                    hash,
+                    timestamp,
+                    num_txs,
+                    num_spends,
+                    num_outputs,
+                    num_actions,
+                    size,
+                },
+            )
+        }
+    }
+    pub enum HealthCheckOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct HealthCheck<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for HealthCheck<'a> {
+        type Inner = HealthCheck<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> HealthCheck<'a> {
+        pub const VT_NAME: flatbuffers::VOffsetT = 4;
+        pub const VT_OK: flatbuffers::VOffsetT = 6;
+        pub const VT_DETAILS: flatbuffers::VOffsetT = 8;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            HealthCheck { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args HealthCheckArgs<'args>,
+        ) -> flatbuffers::WIPOffset<HealthCheck<'bldr>> {
+            let mut builder = HealthCheckBuilder::new(_fbb);
+            if let Some(x) = args.details {
+                builder.add_details(x);
+            }
+            if let Some(x) = args.name {
+                builder.add_name(x);
+            }
+            builder.add_ok(args.ok);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> HealthCheckT {
+            let name = self.name().map(|x| x.to_string());
+            let ok = self.ok();
+            let details = self.details().map(|x| x.to_string());
+            HealthCheckT { name, ok, details }
+        }
+
+        #[inline]
+        pub fn name(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(HealthCheck::VT_NAME, None)
+            }
+        }
+        #[inline]
+        pub fn ok(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(HealthCheck::VT_OK, Some(false))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn details(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(HealthCheck::VT_DETAILS, None)
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for HealthCheck<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+                .visit_field::<bool>("ok", Self::VT_OK, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "details",
+                    Self::VT_DETAILS,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct HealthCheckArgs<'a> {
+        pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub ok: bool,
+        pub details: Option<flatbuffers::WIPOffset<&'a str>>,
+    }
+    impl<'a> Default for HealthCheckArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            HealthCheckArgs {
+                name: None,
+                ok: false,
+                details: None,
+            }
+        }
+    }
+
+    pub struct HealthCheckBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> HealthCheckBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(HealthCheck::VT_NAME, name);
+        }
+        #[inline]
+        pub fn add_ok(&mut self, ok: bool) {
+            self.fbb_.push_slot::<bool>(HealthCheck::VT_OK, ok, false);
+        }
+        #[inline]
+        pub fn add_details(&mut self, details: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(HealthCheck::VT_DETAILS, details);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> HealthCheckBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            HealthCheckBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<HealthCheck<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for HealthCheck<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("HealthCheck");
+            ds.field("name", &self.name());
+            ds.field("ok", &self.ok());
+            ds.field("details", &self.details());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct HealthCheckT {
+        pub name: Option<String>,
+        pub ok: bool,
+        pub details: Option<String>,
+    }
+    impl Default for HealthCheckT {
+        fn default() -> Self {
+            Self {
+                name: None,
+                ok: false,
+                details: None,
+            }
+        }
+    }
+    impl HealthCheckT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<HealthCheck<'b>> {
+            let name = self.name.as_ref().map(|x| _fbb.create_string(x));
+            let ok = self.ok;
+            let details = self.details.as_ref().map(|x| _fbb.create_string(x));
+            HealthCheck::create(_fbb, &HealthCheckArgs { name, ok, details })
+        }
+    }
+    pub enum HealthReportOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct HealthReport<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for HealthReport<'a> {
+        type Inner = HealthReport<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> HealthReport<'a> {
+        pub const VT_VERSION: flatbuffers::VOffsetT = 4;
+        pub const VT_COIN: flatbuffers::VOffsetT = 6;
+        pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 8;
+        pub const VT_OK: flatbuffers::VOffsetT = 10;
+        pub const VT_CHECKS: flatbuffers::VOffsetT = 12;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            HealthReport { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args HealthReportArgs<'args>,
+        ) -> flatbuffers::WIPOffset<HealthReport<'bldr>> {
+            let mut builder = HealthReportBuilder::new(_fbb);
+            if let Some(x) = args.checks {
+                builder.add_checks(x);
+            }
+            builder.add_timestamp(args.timestamp);
+            if let Some(x) = args.version {
+                builder.add_version(x);
+            }
+            builder.add_ok(args.ok);
+            builder.add_coin(args.coin);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> HealthReportT {
+            let version = self.version().map(|x| x.to_string());
+            let coin = self.coin();
+            let timestamp = self.timestamp();
+            let ok = self.ok();
+            let checks = self
+                .checks()
+                .map(|x| x.iter().map(|t| t.unpack()).collect());
+            HealthReportT {
+                version,
+                coin,
+                timestamp,
+                ok,
+                checks,
+            }
+        }
+
+        #[inline]
+        pub fn version(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(HealthReport::VT_VERSION, None)
+            }
+        }
+        #[inline]
+        pub fn coin(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u8>(HealthReport::VT_COIN, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn timestamp(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(HealthReport::VT_TIMESTAMP, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn ok(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(HealthReport::VT_OK, Some(false))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn checks(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<HealthCheck<'a>>>>
+        {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<HealthCheck>>,
+                >>(HealthReport::VT_CHECKS, None)
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for HealthReport<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "version",
+                    Self::VT_VERSION,
+                    false,
+                )?
+                .visit_field::<u8>("coin", Self::VT_COIN, false)?
+                .visit_field::<u32>("timestamp", Self::VT_TIMESTAMP, false)?
+                .visit_field::<bool>("ok", Self::VT_OK, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<HealthCheck>>,
+                >>("checks", Self::VT_CHECKS, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct HealthReportArgs<'a> {
+        pub version: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub coin: u8,
+        pub timestamp: u32,
+        pub ok: bool,
+        pub checks: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<HealthCheck<'a>>>,
+            >,
+        >,
+    }
+    impl<'a> Default for HealthReportArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            HealthReportArgs {
+                version: None,
+                coin: 0,
+                timestamp: 0,
+                ok: false,
+                checks: None,
+            }
+        }
+    }
+
+    pub struct HealthReportBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> HealthReportBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_version(&mut self, version: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(HealthReport::VT_VERSION, version);
+        }
+        #[inline]
+        pub fn add_coin(&mut self, coin: u8) {
+            self.fbb_.push_slot::<u8>(HealthReport::VT_COIN, coin, 0);
+        }
+        #[inline]
+        pub fn add_timestamp(&mut self, timestamp: u32) {
+            self.fbb_
+                .push_slot::<u32>(HealthReport::VT_TIMESTAMP, timestamp, 0);
+        }
+        #[inline]
+        pub fn add_ok(&mut self, ok: bool) {
+            self.fbb_.push_slot::<bool>(HealthReport::VT_OK, ok, false);
+        }
+        #[inline]
+        pub fn add_checks(
+            &mut self,
+            checks: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<HealthCheck<'b>>>,
+            >,
+        ) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(HealthReport::VT_CHECKS, checks);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> HealthReportBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            HealthReportBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<HealthReport<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for HealthReport<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("HealthReport");
+            ds.field("version", &self.version());
+            ds.field("coin", &self.coin());
+            ds.field("timestamp", &self.timestamp());
+            ds.field("ok", &self.ok());
+            ds.field("checks", &self.checks());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct HealthReportT {
+        pub version: Option<String>,
+        pub coin: u8,
+        pub timestamp: u32,
+        pub ok: bool,
+        pub checks: Option<Vec<HealthCheckT>>,
+    }
+    impl Default for HealthReportT {
+        fn default() -> Self {
+            Self {
+                version: None,
+                coin: 0,
+                timestamp: 0,
+                ok: false,
+                checks: None,
+            }
+        }
+    }
+    impl HealthReportT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<HealthReport<'b>> {
+            let version = self.version.as_ref().map(|x| _fbb.create_string(x));
+            let coin = self.coin;
+            let timestamp = self.timestamp;
+            let ok = self.ok;
+            let checks = self.checks.as_ref().map(|x| {
+                let w: Vec<_> = x.iter().map(|t| t.pack(_fbb)).collect();
+                _fbb.create_vector(&w)
+            });
+            HealthReport::create(
+                _fbb,
+                &HealthReportArgs {
+                    version,
+                    coin,
+                    timestamp,
+                    ok,
+                    checks,
+                },
+            )
+        }
+    }
+    pub enum ChainStatusOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct ChainStatus<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for ChainStatus<'a> {
+        type Inner = ChainStatus<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> ChainStatus<'a> {
+        pub const VT_SYNC_HEIGHT: flatbuffers::VOffsetT = 4;
+        pub const VT_SYNC_TIMESTAMP: flatbuffers::VOffsetT = 6;
+        pub const VT_SERVER_HEIGHT: flatbuffers::VOffsetT = 8;
+        pub const VT_ESTIMATED_HEIGHT: flatbuffers::VOffsetT = 10;
+        pub const VT_LAG: flatbuffers::VOffsetT = 12;
+        pub const VT_LAG_MINUTES: flatbuffers::VOffsetT = 14;
+        pub const VT_SERVER_OK: flatbuffers::VOffsetT = 16;
+        pub const VT_SERVER_ERROR: flatbuffers::VOffsetT = 18;
+        pub const VT_SERVER_LAG: flatbuffers::VOffsetT = 20;
+        pub const VT_LAST_SYNC_TIMESTAMP: flatbuffers::VOffsetT = 22;
+        pub const VT_LAST_SYNC_BLOCKS: flatbuffers::VOffsetT = 24;
+        pub const VT_LAST_SYNC_MILLIS: flatbuffers::VOffsetT = 26;
+        pub const VT_BLOCKS_PER_MINUTE: flatbuffers::VOffsetT = 28;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            ChainStatus { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args ChainStatusArgs<'args>,
+        ) -> flatbuffers::WIPOffset<ChainStatus<'bldr>> {
+            let mut builder = ChainStatusBuilder::new(_fbb);
+            builder.add_blocks_per_minute(args.blocks_per_minute);
+            builder.add_last_sync_millis(args.last_sync_millis);
+            builder.add_last_sync_blocks(args.last_sync_blocks);
+            builder.add_last_sync_timestamp(args.last_sync_timestamp);
+            builder.add_server_lag(args.server_lag);
+            if let Some(x) = args.server_error {
+                builder.add_server_error(x);
+            }
+            builder.add_lag_minutes(args.lag_minutes);
+            builder.add_lag(args.lag);
+            builder.add_estimated_height(args.estimated_height);
+            builder.add_server_height(args.server_height);
+            builder.add_sync_timestamp(args.sync_timestamp);
+            builder.add_sync_height(args.sync_height);
+            builder.add_server_ok(args.server_ok);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> ChainStatusT {
+            let sync_height = self.sync_height();
+            let sync_timestamp = self.sync_timestamp();
+            let server_height = self.server_height();
+            let estimated_height = self.estimated_height();
+            let lag = self.lag();
+            let lag_minutes = self.lag_minutes();
+            let server_ok = self.server_ok();
+            let server_error = self.server_error().map(|x| x.to_string());
+            let server_lag = self.server_lag();
+            let last_sync_timestamp = self.last_sync_timestamp();
+            let last_sync_blocks = self.last_sync_blocks();
+            let last_sync_millis = self.last_sync_millis();
+            let blocks_per_minute = self.blocks_per_minute();
+            ChainStatusT {
+                sync_height,
+                sync_timestamp,
+                server_height,
+                estimated_height,
+                lag,
+                lag_minutes,
+                server_ok,
+                server_error,
+                server_lag,
+                last_sync_timestamp,
+                last_sync_blocks,
+                last_sync_millis,
+                blocks_per_minute,
+            }
+        }
+
+        #[inline]
+        pub fn sync_height(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(ChainStatus::VT_SYNC_HEIGHT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn sync_timestamp(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(ChainStatus::VT_SYNC_TIMESTAMP, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn server_height(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(ChainStatus::VT_SERVER_HEIGHT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn estimated_height(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(ChainStatus::VT_ESTIMATED_HEIGHT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn lag(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(ChainStatus::VT_LAG, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn lag_minutes(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(ChainStatus::VT_LAG_MINUTES, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn server_ok(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(ChainStatus::VT_SERVER_OK, Some(false))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn server_error(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(ChainStatus::VT_SERVER_ERROR, None)
+            }
+        }
+        #[inline]
+        pub fn server_lag(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(ChainStatus::VT_SERVER_LAG, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn last_sync_timestamp(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(ChainStatus::VT_LAST_SYNC_TIMESTAMP, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn last_sync_blocks(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(ChainStatus::VT_LAST_SYNC_BLOCKS, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn last_sync_millis(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(ChainStatus::VT_LAST_SYNC_MILLIS, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn blocks_per_minute(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(ChainStatus::VT_BLOCKS_PER_MINUTE, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for ChainStatus<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("sync_height", Self::VT_SYNC_HEIGHT, false)?
+                .visit_field::<u32>("sync_timestamp", Self::VT_SYNC_TIMESTAMP, false)?
+                .visit_field::<u32>("server_height", Self::VT_SERVER_HEIGHT, false)?
+                .visit_field::<u32>("estimated_height", Self::VT_ESTIMATED_HEIGHT, false)?
+                .visit_field::<u32>("lag", Self::VT_LAG, false)?
+                .visit_field::<u32>("lag_minutes", Self::VT_LAG_MINUTES, false)?
+                .visit_field::<bool>("server_ok", Self::VT_SERVER_OK, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "server_error",
+                    Self::VT_SERVER_ERROR,
+                    false,
+                )?
+                .visit_field::<u32>("server_lag", Self::VT_SERVER_LAG, false)?
+                .visit_field::<u32>("last_sync_timestamp", Self::VT_LAST_SYNC_TIMESTAMP, false)?
+                .visit_field::<u32>("last_sync_blocks", Self::VT_LAST_SYNC_BLOCKS, false)?
+                .visit_field::<u32>("last_sync_millis", Self::VT_LAST_SYNC_MILLIS, false)?
+                .visit_field::<u32>("blocks_per_minute", Self::VT_BLOCKS_PER_MINUTE, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct ChainStatusArgs<'a> {
+        pub sync_height: u32,
+        pub sync_timestamp: u32,
+        pub server_height: u32,
+        pub estimated_height: u32,
+        pub lag: u32,
+        pub lag_minutes: u32,
+        pub server_ok: bool,
+        pub server_error: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub server_lag: u32,
+        pub last_sync_timestamp: u32,
+        pub last_sync_blocks: u32,
+        pub last_sync_millis: u32,
+        pub blocks_per_minute: u32,
+    }
+    impl<'a> Default for ChainStatusArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            ChainStatusArgs {
+                sync_height: 0,
+                sync_timestamp: 0,
+                server_height: 0,
+                estimated_height: 0,
+                lag: 0,
+                lag_minutes: 0,
+                server_ok: false,
+                server_error: None,
+                server_lag: 0,
+                last_sync_timestamp: 0,
+                last_sync_blocks: 0,
+                last_sync_millis: 0,
+                blocks_per_minute: 0,
+            }
+        }
+    }
+
+    pub struct ChainStatusBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ChainStatusBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_sync_height(&mut self, sync_height: u32) {
+            self.fbb_
+                .push_slot::<u32>(ChainStatus::VT_SYNC_HEIGHT, sync_height, 0);
+        }
+        #[inline]
+        pub fn add_sync_timestamp(&mut self, sync_timestamp: u32) {
+            self.fbb_
+                .push_slot::<u32>(ChainStatus::VT_SYNC_TIMESTAMP, sync_timestamp, 0);
+        }
+        #[inline]
+        pub fn add_server_height(&mut self, server_height: u32) {
+            self.fbb_
+                .push_slot::<u32>(ChainStatus::VT_SERVER_HEIGHT, server_height, 0);
+        }
+        #[inline]
+        pub fn add_estimated_height(&mut self, estimated_height: u32) {
+            self.fbb_
+                .push_slot::<u32>(ChainStatus::VT_ESTIMATED_HEIGHT, estimated_height, 0);
+        }
+        #[inline]
+        pub fn add_lag(&mut self, lag: u32) {
+            self.fbb_.push_slot::<u32>(ChainStatus::VT_LAG, lag, 0);
+        }
+        #[inline]
+        pub fn add_lag_minutes(&mut self, lag_minutes: u32) {
+            self.fbb_
+                .push_slot::<u32>(ChainStatus::VT_LAG_MINUTES, lag_minutes, 0);
+        }
+        #[inline]
+        pub fn add_server_ok(&mut self, server_ok: bool) {
+            self.fbb_
+                .push_slot::<bool>(ChainStatus::VT_SERVER_OK, server_ok, false);
+        }
+        #[inline]
+        pub fn add_server_error(&mut self, server_error: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                ChainStatus::VT_SERVER_ERROR,
+                server_error,
+            );
+        }
+        #[inline]
+        pub fn add_server_lag(&mut self, server_lag: u32) {
+            self.fbb_
+                .push_slot::<u32>(ChainStatus::VT_SERVER_LAG, server_lag, 0);
+        }
+        #[inline]
+        pub fn add_last_sync_timestamp(&mut self, last_sync_timestamp: u32) {
+            self.fbb_
+                .push_slot::<u32>(ChainStatus::VT_LAST_SYNC_TIMESTAMP, last_sync_timestamp, 0);
+        }
+        #[inline]
+        pub fn add_last_sync_blocks(&mut self, last_sync_blocks: u32) {
+            self.fbb_
+                .push_slot::<u32>(ChainStatus::VT_LAST_SYNC_BLOCKS, last_sync_blocks, 0);
+        }
+        #[inline]
+        pub fn add_last_sync_millis(&mut self, last_sync_millis: u32) {
+            self.fbb_
+                .push_slot::<u32>(ChainStatus::VT_LAST_SYNC_MILLIS, last_sync_millis, 0);
+        }
+        #[inline]
+        pub fn add_blocks_per_minute(&mut self, blocks_per_minute: u32) {
+            self.fbb_
+                .push_slot::<u32>(ChainStatus::VT_BLOCKS_PER_MINUTE, blocks_per_minute, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> ChainStatusBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            ChainStatusBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<ChainStatus<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for ChainStatus<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("ChainStatus");
+            ds.field("sync_height", &self.sync_height());
+            ds.field("sync_timestamp", &self.sync_timestamp());
+            ds.field("server_height", &self.server_height());
+            ds.field("estimated_height", &self.estimated_height());
+            ds.field("lag", &self.lag());
+            ds.field("lag_minutes", &self.lag_minutes());
+            ds.field("server_ok", &self.server_ok());
+            ds.field("server_error", &self.server_error());
+            ds.field("server_lag", &self.server_lag());
+            ds.field("last_sync_timestamp", &self.last_sync_timestamp());
+            ds.field("last_sync_blocks", &self.last_sync_blocks());
+            ds.field("last_sync_millis", &self.last_sync_millis());
+            ds.field("blocks_per_minute", &self.blocks_per_minute());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct ChainStatusT {
+        pub sync_height: u32,
+        pub sync_timestamp: u32,
+        pub server_height: u32,
+        pub estimated_height: u32,
+        pub lag: u32,
+        pub lag_minutes: u32,
+        pub server_ok: bool,
+        pub server_error: Option<String>,
+        pub server_lag: u32,
+        pub last_sync_timestamp: u32,
+        pub last_sync_blocks: u32,
+        pub last_sync_millis: u32,
+        pub blocks_per_minute: u32,
+    }
+    impl Default for ChainStatusT {
+        fn default() -> Self {
+            Self {
+                sync_height: 0,
+                sync_timestamp: 0,
+                server_height: 0,
+                estimated_height: 0,
+                lag: 0,
+                lag_minutes: 0,
+                server_ok: false,
+                server_error: None,
+                server_lag: 0,
+                last_sync_timestamp: 0,
+                last_sync_blocks: 0,
+                last_sync_millis: 0,
+                blocks_per_minute: 0,
+            }
+        }
+    }
+    impl ChainStatusT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<ChainStatus<'b>> {
+            let sync_height = self.sync_height;
+            let sync_timestamp = self.sync_timestamp;
+            let server_height = self.server_height;
+            let estimated_height = self.estimated_height;
+            let lag = self.lag;
+            let lag_minutes = self.lag_minutes;
+            let server_ok = self.server_ok;
+            let server_error = self.server_error.as_ref().map(|x| _fbb.create_string(x));
+            let server_lag = self.server_lag;
+            let last_sync_timestamp = self.last_sync_timestamp;
+            let last_sync_blocks = self.last_sync_blocks;
+            let last_sync_millis = self.last_sync_millis;
+            let blocks_per_minute = self.blocks_per_minute;
+            ChainStatus::create(
+                _fbb,
+                &ChainStatusArgs {
+                    sync_height,
+                    sync_timestamp,
+                    server_height,
+                    estimated_height,
+                    lag,
+                    lag_minutes,
+                    server_ok,
+                    server_error,
+                    server_lag,
+                    last_sync_timestamp,
+                    last_sync_blocks,
+                    last_sync_millis,
+                    blocks_per_minute,
+                },
+            )
+        }
+    }
+    pub enum NoteProfileOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct NoteProfile<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for NoteProfile<'a> {
+        type Inner = NoteProfile<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> NoteProfile<'a> {
+        pub const VT_ID: flatbuffers::VOffsetT = 4;
+        pub const VT_NAME: flatbuffers::VOffsetT = 6;
+        pub const VT_ACTIVE: flatbuffers::VOffsetT = 8;
+        pub const VT_NOTES: flatbuffers::VOffsetT = 10;
+        pub const VT_VALUE: flatbuffers::VOffsetT = 12;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            NoteProfile { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args NoteProfileArgs<'args>,
+        ) -> flatbuffers::WIPOffset<NoteProfile<'bldr>> {
+            let mut builder = NoteProfileBuilder::new(_fbb);
+            builder.add_value(args.value);
+            builder.add_notes(args.notes);
+            if let Some(x) = args.name {
+                builder.add_name(x);
+            }
+            builder.add_id(args.id);
+            builder.add_active(args.active);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> NoteProfileT {
+            let id = self.id();
+            let name = self.name().map(|x| x.to_string());
+            let active = self.active();
+            let notes = self.notes();
+            let value = self.value();
+            NoteProfileT {
+                id,
+                name,
+                active,
+                notes,
+                value,
+            }
+        }
+
+        #[inline]
+        pub fn id(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(NoteProfile::VT_ID, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn name(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(NoteProfile::VT_NAME, None)
+            }
+        }
+        #[inline]
+        pub fn active(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(NoteProfile::VT_ACTIVE, Some(false))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn notes(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(NoteProfile::VT_NOTES, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn value(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(NoteProfile::VT_VALUE, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for NoteProfile<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("id", Self::VT_ID, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+                .visit_field::<bool>("active", Self::VT_ACTIVE, false)?
+                .visit_field::<u32>("notes", Self::VT_NOTES, false)?
+                .visit_field::<u64>("value", Self::VT_VALUE, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct NoteProfileArgs<'a> {
+        pub id: u32,
+        pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub active: bool,
+        pub notes: u32,
+        pub value: u64,
+    }
+    impl<'a> Default for NoteProfileArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            NoteProfileArgs {
+                id: 0,
+                name: None,
+                active: false,
+                notes: 0,
+                value: 0,
+            }
+        }
+    }
+
+    pub struct NoteProfileBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> NoteProfileBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_id(&mut self, id: u32) {
+            self.fbb_.push_slot::<u32>(NoteProfile::VT_ID, id, 0);
+        }
+        #[inline]
+        pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(NoteProfile::VT_NAME, name);
+        }
+        #[inline]
+        pub fn add_active(&mut self, active: bool) {
+            self.fbb_
+                .push_slot::<bool>(NoteProfile::VT_ACTIVE, active, false);
+        }
+        #[inline]
+        pub fn add_notes(&mut self, notes: u32) {
+            self.fbb_.push_slot::<u32>(NoteProfile::VT_NOTES, notes, 0);
+        }
+        #[inline]
+        pub fn add_value(&mut self, value: u64) {
+            self.fbb_.push_slot::<u64>(NoteProfile::VT_VALUE, value, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> NoteProfileBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            NoteProfileBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<NoteProfile<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for NoteProfile<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("NoteProfile");
+            ds.field("id", &self.id());
+            ds.field("name", &self.name());
+            ds.field("active", &self.active());
+            ds.field("notes", &self.notes());
+            ds.field("value", &self.value());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct NoteProfileT {
+        pub id: u32,
+        pub name: Option<String>,
+        pub active: bool,
+        pub notes: u32,
+        pub value: u64,
+    }
+    impl Default for NoteProfileT {
+        fn default() -> Self {
+            Self {
+                id: 0,
+                name: None,
+                active: false,
+                notes: 0,
+                value: 0,
+            }
+        }
+    }
+    impl NoteProfileT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<NoteProfile<'b>> {
+            let id = self.id;
+            let name = self.name.as_ref().map(|x| _fbb.create_string(x));
+            let active = self.active;
+            let notes = self.notes;
+            let value = self.value;
+            NoteProfile::create(
+                _fbb,
+                &NoteProfileArgs {
+                    id,
+                    name,
+                    active,
+                    notes,
+                    value,
+                },
+            )
+        }
+    }
+    pub enum TransparentDescriptorsOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct TransparentDescriptors<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for TransparentDescriptors<'a> {
+        type Inner = TransparentDescriptors<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> TransparentDescriptors<'a> {
+        pub const VT_RECEIVE: flatbuffers::VOffsetT = 4;
+        pub const VT_CHANGE: flatbuffers::VOffsetT = 6;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            TransparentDescriptors { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args TransparentDescriptorsArgs<'args>,
+        ) -> flatbuffers::WIPOffset<TransparentDescriptors<'bldr>> {
+            let mut builder = TransparentDescriptorsBuilder::new(_fbb);
+            if let Some(x) = args.change {
+                builder.add_change(x);
+            }
+            if let Some(x) = args.receive {
+                builder.add_receive(x);
+            }
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> TransparentDescriptorsT {
+            let receive = self.receive().map(|x| x.to_string());
+            let change = self.change().map(|x| x.to_string());
+            TransparentDescriptorsT { receive, change }
+        }
+
+        #[inline]
+        pub fn receive(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(
+                    TransparentDescriptors::VT_RECEIVE,
+                    None,
+                )
+            }
+        }
+        #[inline]
+        pub fn change(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(
+                    TransparentDescriptors::VT_CHANGE,
+                    None,
+                )
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for TransparentDescriptors<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "receive",
+                    Self::VT_RECEIVE,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "change",
+                    Self::VT_CHANGE,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct TransparentDescriptorsArgs<'a> {
+        pub receive: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub change: Option<flatbuffers::WIPOffset<&'a str>>,
+    }
+    impl<'a> Default for TransparentDescriptorsArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            TransparentDescriptorsArgs {
+                receive: None,
+                change: None,
+            }
+        }
+    }
+
+    pub struct TransparentDescriptorsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TransparentDescriptorsBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_receive(&mut self, receive: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                TransparentDescriptors::VT_RECEIVE,
+                receive,
+            );
+        }
+        #[inline]
+        pub fn add_change(&mut self, change: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                TransparentDescriptors::VT_CHANGE,
+                change,
+            );
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> TransparentDescriptorsBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            TransparentDescriptorsBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<TransparentDescriptors<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for TransparentDescriptors<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("TransparentDescriptors");
+            ds.field("receive", &self.receive());
+            ds.field("change", &self.change());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct TransparentDescriptorsT {
+        pub receive: Option<String>,
+        pub change: Option<String>,
+    }
+    impl Default for TransparentDescriptorsT {
+        fn default() -> Self {
+            Self {
+                receive: None,
+                change: None,
+            }
+        }
+    }
+    impl TransparentDescriptorsT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<TransparentDescriptors<'b>> {
+            let receive = self.receive.as_ref().map(|x| _fbb.create_string(x));
+            let change = self.change.as_ref().map(|x| _fbb.create_string(x));
+            TransparentDescriptors::create(_fbb, &TransparentDescriptorsArgs { receive, change })
+        }
+    }
+    pub enum AttachmentOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct Attachment<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for Attachment<'a> {
+        type Inner = Attachment<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> Attachment<'a> {
+        pub const VT_NAME: flatbuffers::VOffsetT = 4;
+        pub const VT_SIZE: flatbuffers::VOffsetT = 6;
+        pub const VT_PATH: flatbuffers::VOffsetT = 8;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            Attachment { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args AttachmentArgs<'args>,
+        ) -> flatbuffers::WIPOffset<Attachment<'bldr>> {
+            let mut builder = AttachmentBuilder::new(_fbb);
+            if let Some(x) = args.path {
+                builder.add_path(x);
+            }
+            builder.add_size(args.size);
+            if let Some(x) = args.name {
+                builder.add_name(x);
+            }
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> AttachmentT {
+            let name = self.name().map(|x| x.to_string());
+            let size = self.size();
+            let path = self.path().map(|x| x.to_string());
+            AttachmentT { name, size, path }
+        }
+
+        #[inline]
+        pub fn name(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(Attachment::VT_NAME, None)
+            }
+        }
+        #[inline]
+        pub fn size(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(Attachment::VT_SIZE, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn path(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(Attachment::VT_PATH, None)
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for Attachment<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+                .visit_field::<u32>("size", Self::VT_SIZE, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct AttachmentArgs<'a> {
+        pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub size: u32,
+        pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    }
+    impl<'a> Default for AttachmentArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            AttachmentArgs {
+                name: None,
+                size: 0,
+                path: None,
+            }
+        }
+    }
+
+    pub struct AttachmentBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AttachmentBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(Attachment::VT_NAME, name);
+        }
+        #[inline]
+        pub fn add_size(&mut self, size: u32) {
+            self.fbb_.push_slot::<u32>(Attachment::VT_SIZE, size, 0);
+        }
+        #[inline]
+        pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(Attachment::VT_PATH, path);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> AttachmentBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            AttachmentBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<Attachment<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for Attachment<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("Attachment");
+            ds.field("name", &self.name());
+            ds.field("size", &self.size());
+            ds.field("path", &self.path());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct AttachmentT {
+        pub name: Option<String>,
+        pub size: u32,
+        pub path: Option<String>,
+    }
+    impl Default for AttachmentT {
+        fn default() -> Self {
+            Self {
+                name: None,
+                size: 0,
+                path: None,
+            }
+        }
+    }
+    impl AttachmentT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<Attachment<'b>> {
+            let name = self.name.as_ref().map(|x| _fbb.create_string(x));
+            let size = self.size;
+            let path = self.path.as_ref().map(|x| _fbb.create_string(x));
+            Attachment::create(_fbb, &AttachmentArgs { name, size, path })
+        }
+    }
+    pub enum AccountGroupOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct AccountGroup<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for AccountGroup<'a> {
+        type Inner = AccountGroup<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> AccountGroup<'a> {
+        pub const VT_ID: flatbuffers::VOffsetT = 4;
+        pub const VT_NAME: flatbuffers::VOffsetT = 6;
+        pub const VT_ACCOUNTS: flatbuffers::VOffsetT = 8;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            AccountGroup { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args AccountGroupArgs<'args>,
+        ) -> flatbuffers::WIPOffset<AccountGroup<'bldr>> {
+            let mut builder = AccountGroupBuilder::new(_fbb);
+            if let Some(x) = args.accounts {
+                builder.add_accounts(x);
+            }
+            if let Some(x) = args.name {
+                builder.add_name(x);
+            }
+            builder.add_id(args.id);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> AccountGroupT {
+            let id = self.id();
+            let name = self.name().map(|x| x.to_string());
+            let accounts = self.accounts().map(|x| x.into_iter().collect());
+            AccountGroupT { id, name, accounts }
+        }
+
+        #[inline]
+        pub fn id(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(AccountGroup::VT_ID, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn name(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(AccountGroup::VT_NAME, None)
+            }
+        }
+        #[inline]
+        pub fn accounts(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                        AccountGroup::VT_ACCOUNTS,
+                        None,
+                    )
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for AccountGroup<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("id", Self::VT_ID, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                    "accounts",
+                    Self::VT_ACCOUNTS,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct AccountGroupArgs<'a> {
+        pub id: u32,
+        pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub accounts: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    }
+    impl<'a> Default for AccountGroupArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            AccountGroupArgs {
+                id: 0,
+                name: None,
+                accounts: None,
+            }
+        }
+    }
+
+    pub struct AccountGroupBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AccountGroupBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_id(&mut self, id: u32) {
+            self.fbb_.push_slot::<u32>(AccountGroup::VT_ID, id, 0);
+        }
+        #[inline]
+        pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(AccountGroup::VT_NAME, name);
+        }
+        #[inline]
+        pub fn add_accounts(
+            &mut self,
+            accounts: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+        ) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(AccountGroup::VT_ACCOUNTS, accounts);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> AccountGroupBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            AccountGroupBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<AccountGroup<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for AccountGroup<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("AccountGroup");
+            ds.field("id", &self.id());
+            ds.field("name", &self.name());
+            ds.field("accounts", &self.accounts());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct AccountGroupT {
+        pub id: u32,
+        pub name: Option<String>,
+        pub accounts: Option<Vec<u32>>,
+    }
+    impl Default for AccountGroupT {
+        fn default() -> Self {
+            Self {
+                id: 0,
+                name: None,
+                accounts: None,
+            }
+        }
+    }
+    impl AccountGroupT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<AccountGroup<'b>> {
+            let id = self.id;
+            let name = self.name.as_ref().map(|x| _fbb.create_string(x));
+            let accounts = self.accounts.as_ref().map(|x| _fbb.create_vector(x));
+            AccountGroup::create(_fbb, &AccountGroupArgs { id, name, accounts })
+        }
+    }
+    pub enum ServerOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct Server<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for Server<'a> {
+        type Inner = Server<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> Server<'a> {
+        pub const VT_URL: flatbuffers::VOffsetT = 4;
+        pub const VT_LATENCY: flatbuffers::VOffsetT = 6;
+        pub const VT_TESTS: flatbuffers::VOffsetT = 8;
+        pub const VT_FAILURES: flatbuffers::VOffsetT = 10;
+        pub const VT_HEIGHT: flatbuffers::VOffsetT = 12;
+        pub const VT_UPDATED: flatbuffers::VOffsetT = 14;
+        pub const VT_HEALTHY: flatbuffers::VOffsetT = 16;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            Server { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args ServerArgs<'args>,
+        ) -> flatbuffers::WIPOffset<Server<'bldr>> {
+            let mut builder = ServerBuilder::new(_fbb);
+            builder.add_updated(args.updated);
+            builder.add_height(args.height);
+            builder.add_failures(args.failures);
+            builder.add_tests(args.tests);
+            builder.add_latency(args.latency);
+            if let Some(x) = args.url {
+                builder.add_url(x);
+            }
+            builder.add_healthy(args.healthy);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> ServerT {
+            let url = self.url().map(|x| x.to_string());
+            let latency = self.latency();
+            let tests = self.tests();
+            let failures = self.failures();
+            let height = self.height();
+            let updated = self.updated();
+            let healthy = self.healthy();
+            ServerT {
+                url,
+                latency,
+                tests,
+                failures,
+                height,
+                updated,
+                healthy,
+            }
+        }
+
+        #[inline]
+        pub fn url(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(Server::VT_URL, None)
+            }
+        }
+        #[inline]
+        pub fn latency(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(Server::VT_LATENCY, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn tests(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(Server::VT_TESTS, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn failures(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(Server::VT_FAILURES, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn height(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(Server::VT_HEIGHT, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn updated(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(Server::VT_UPDATED, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn healthy(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(Server::VT_HEALTHY, Some(false))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for Server<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("url", Self::VT_URL, false)?
+                .visit_field::<u32>("latency", Self::VT_LATENCY, false)?
+                .visit_field::<u32>("tests", Self::VT_TESTS, false)?
+                .visit_field::<u32>("failures", Self::VT_FAILURES, false)?
+                .visit_field::<u32>("height", Self::VT_HEIGHT, false)?
+                .visit_field::<u32>("updated", Self::VT_UPDATED, false)?
+                .visit_field::<bool>("healthy", Self::VT_HEALTHY, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct ServerArgs<'a> {
+        pub url: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub latency: u32,
+        pub tests: u32,
+        pub failures: u32,
+        pub height: u32,
+        pub updated: u32,
+        pub healthy: bool,
+    }
+    impl<'a> Default for ServerArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            ServerArgs {
+                url: None,
+                latency: 0,
+                tests: 0,
+                failures: 0,
+                height: 0,
+                updated: 0,
+                healthy: false,
+            }
+        }
+    }
+
+    pub struct ServerBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ServerBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_url(&mut self, url: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(Server::VT_URL, url);
+        }
+        #[inline]
+        pub fn add_latency(&mut self, latency: u32) {
+            self.fbb_.push_slot::<u32>(Server::VT_LATENCY, latency, 0);
+        }
+        #[inline]
+        pub fn add_tests(&mut self, tests: u32) {
+            self.fbb_.push_slot::<u32>(Server::VT_TESTS, tests, 0);
+        }
+        #[inline]
+        pub fn add_failures(&mut self, failures: u32) {
+            self.fbb_.push_slot::<u32>(Server::VT_FAILURES, failures, 0);
+        }
+        #[inline]
+        pub fn add_height(&mut self, height: u32) {
+            self.fbb_.push_slot::<u32>(Server::VT_HEIGHT, height, 0);
+        }
+        #[inline]
+        pub fn add_updated(&mut self, updated: u32) {
+            self.fbb_.push_slot::<u32>(Server::VT_UPDATED, updated, 0);
+        }
+        #[inline]
+        pub fn add_healthy(&mut self, healthy: bool) {
+            self.fbb_
+                .push_slot::<bool>(Server::VT_HEALTHY, healthy, false);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> ServerBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            ServerBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<Server<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for Server<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("Server");
+            ds.field("url", &self.url());
+            ds.field("latency", &self.latency());
+            ds.field("tests", &self.tests());
+            ds.field("failures", &self.failures());
+            ds.field("height", &self.height());
+            ds.field("updated", &self.updated());
+            ds.field("healthy", &self.healthy());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct ServerT {
+        pub url: Option<String>,
+        pub latency: u32,
+        pub tests: u32,
+        pub failures: u32,
+        pub height: u32,
+        pub updated: u32,
+        pub healthy: bool,
+    }
+    impl Default for ServerT {
+        fn default() -> Self {
+            Self {
+                url: None,
+                latency: 0,
+                tests: 0,
+                failures: 0,
+                height: 0,
+                updated: 0,
+                healthy: false,
+            }
+        }
+    }
+    impl ServerT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<Server<'b>> {
+            let url = self.url.as_ref().map(|x| _fbb.create_string(x));
+            let latency = self.latency;
+            let tests = self.tests;
+            let failures = self.failures;
+            let height = self.height;
+            let updated = self.updated;
+            let healthy = self.healthy;
+            Server::create(
+                _fbb,
+                &ServerArgs {
+                    url,
+                    latency,
+                    tests,
+                    failures,
+                    height,
+                    updated,
+                    healthy,
+                },
+            )
+        }
+    }
+    pub enum NoteWitnessOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct NoteWitness<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for NoteWitness<'a> {
+        type Inner = NoteWitness<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> NoteWitness<'a> {
+        pub const VT_ID_NOTE: flatbuffers::VOffsetT = 4;
+        pub const VT_POOL: flatbuffers::VOffsetT = 6;
+        pub const VT_HEIGHT: flatbuffers::VOffsetT = 8;
+        pub const VT_POSITION: flatbuffers::VOffsetT = 10;
+        pub const VT_CMX: flatbuffers::VOffsetT = 12;
+        pub const VT_PATH: flatbuffers::VOffsetT = 14;
+        pub const VT_ROOT: flatbuffers::VOffsetT = 16;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            NoteWitness { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args NoteWitnessArgs<'args>,
+        ) -> flatbuffers::WIPOffset<NoteWitness<'bldr>> {
+            let mut builder = NoteWitnessBuilder::new(_fbb);
+            if let Some(x) = args.root {
+                builder.add_root(x);
+            }
+            if let Some(x) = args.path {
+                builder.add_path(x);
+            }
+            if let Some(x) = args.cmx {
+                builder.add_cmx(x);
+            }
+            builder.add_position(args.position);
+            builder.add_height(args.height);
+            builder.add_id_note(args.id_note);
+            builder.add_pool(args.pool);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> NoteWitnessT {
+            let id_note = self.id_note();
+            let pool = self.pool();
+            let height = self.height();
+            let position = self.position();
+            let cmx = self.cmx().map(|x| x.into_iter().collect());
+            let path = self.path().map(|x| x.into_iter().collect());
+            let root = self.root().map(|x| x.into_iter().collect());
+            NoteWitnessT {
+                id_note,
+                pool,
+                height,
+                position,
+                cmx,
+                path,
+                root,
+            }
+        }
+
+        #[inline]
+        pub fn id_note(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(NoteWitness::VT_ID_NOTE, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn pool(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u8>(NoteWitness::VT_POOL, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn height(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(NoteWitness::VT_HEIGHT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn position(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(NoteWitness::VT_POSITION, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn cmx(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        NoteWitness::VT_CMX,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn path(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        NoteWitness::VT_PATH,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn root(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        NoteWitness::VT_ROOT,
+                        None,
+                    )
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for NoteWitness<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("id_note", Self::VT_ID_NOTE, false)?
+                .visit_field::<u8>("pool", Self::VT_POOL, false)?
+                .visit_field::<u32>("height", Self::VT_HEIGHT, false)?
+                .visit_field::<u32>("position", Self::VT_POSITION, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "cmx",
+                    Self::VT_CMX,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "path",
+                    Self::VT_PATH,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "root",
+                    Self::VT_ROOT,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct NoteWitnessArgs<'a> {
+        pub id_note: u32,
+        pub pool: u8,
+        pub height: u32,
+        pub position: u32,
+        pub cmx: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub path: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub root: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    }
+    impl<'a> Default for NoteWitnessArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            NoteWitnessArgs {
+                id_note: 0,
+                pool: 0,
+                height: 0,
+                position: 0,
+                cmx: None,
+                path: None,
+                root: None,
+            }
+        }
+    }
+
+    pub struct NoteWitnessBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> NoteWitnessBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_id_note(&mut self, id_note: u32) {
+            self.fbb_
+                .push_slot::<u32>(NoteWitness::VT_ID_NOTE, id_note, 0);
+        }
+        #[inline]
+        pub fn add_pool(&mut self, pool: u8) {
+            self.fbb_.push_slot::<u8>(NoteWitness::VT_POOL, pool, 0);
+        }
+        #[inline]
+        pub fn add_height(&mut self, height: u32) {
+            self.fbb_
+                .push_slot::<u32>(NoteWitness::VT_HEIGHT, height, 0);
+        }
+        #[inline]
+        pub fn add_position(&mut self, position: u32) {
+            self.fbb_
+                .push_slot::<u32>(NoteWitness::VT_POSITION, position, 0);
+        }
+        #[inline]
+        pub fn add_cmx(&mut self, cmx: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(NoteWitness::VT_CMX, cmx);
+        }
+        #[inline]
+        pub fn add_path(&mut self, path: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(NoteWitness::VT_PATH, path);
+        }
+        #[inline]
+        pub fn add_root(&mut self, root: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(NoteWitness::VT_ROOT, root);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> NoteWitnessBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            NoteWitnessBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<NoteWitness<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for NoteWitness<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("NoteWitness");
+            ds.field("id_note", &self.id_note());
+            ds.field("pool", &self.pool());
+            ds.field("height", &self.height());
+            ds.field("position", &self.position());
+            ds.field("cmx", &self.cmx());
+            ds.field("path", &self.path());
+            ds.field("root", &self.root());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct NoteWitnessT {
+        pub id_note: u32,
+        pub pool: u8,
+        pub height: u32,
+        pub position: u32,
+        pub cmx: Option<Vec<u8>>,
+        pub path: Option<Vec<u8>>,
+        pub root: Option<Vec<u8>>,
+    }
+    impl Default for NoteWitnessT {
+        fn default() -> Self {
+            Self {
+                id_note: 0,
+                pool: 0,
+                height: 0,
+                position: 0,
+                cmx: None,
+                path: None,
+                root: None,
+            }
+        }
+    }
+    impl NoteWitnessT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<NoteWitness<'b>> {
+            let id_note = self.id_note;
+            let pool = self.pool;
+            let height = self.height;
+            let position = self.position;
+            let cmx = self.cmx.as_ref().map(|x| _fbb.create_vector(x));
+            let path = self.path.as_ref().map(|x| _fbb.create_vector(x));
+            let root = self.root.as_ref().map(|x| _fbb.create_vector(x));
+            NoteWitness::create(
+                _fbb,
+                &NoteWitnessArgs {
+                    id_note,
+                    pool,
+                    height,
+                    position,
+                    cmx,
+                    path,
+                    root,
+                },
+            )
+        }
+    }
+    pub enum BalanceEventOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct BalanceEvent<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for BalanceEvent<'a> {
+        type Inner = BalanceEvent<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> BalanceEvent<'a> {
+        pub const VT_SEQ: flatbuffers::VOffsetT = 4;
+        pub const VT_ACCOUNT: flatbuffers::VOffsetT = 6;
+        pub const VT_KIND: flatbuffers::VOffsetT = 8;
+        pub const VT_HEIGHT: flatbuffers::VOffsetT = 10;
+        pub const VT_TXID: flatbuffers::VOffsetT = 12;
+        pub const VT_POOL: flatbuffers::VOffsetT = 14;
+        pub const VT_ID_REF: flatbuffers::VOffsetT = 16;
+        pub const VT_VALUE: flatbuffers::VOffsetT = 18;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            BalanceEvent { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args BalanceEventArgs<'args>,
+        ) -> flatbuffers::WIPOffset<BalanceEvent<'bldr>> {
+            let mut builder = BalanceEventBuilder::new(_fbb);
+            builder.add_value(args.value);
+            builder.add_id_ref(args.id_ref);
+            if let Some(x) = args.txid {
+                builder.add_txid(x);
+            }
+            builder.add_height(args.height);
+            builder.add_account(args.account);
+            builder.add_seq(args.seq);
+            builder.add_pool(args.pool);
+            builder.add_kind(args.kind);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> BalanceEventT {
+            let seq = self.seq();
+            let account = self.account();
+            let kind = self.kind();
+            let height = self.height();
+            let txid = self.txid().map(|x| x.into_iter().collect());
+            let pool = self.pool();
+            let id_ref = self.id_ref();
+            let value = self.value();
+            BalanceEventT {
+                seq,
+                account,
+                kind,
+                height,
+                txid,
+                pool,
+                id_ref,
+                value,
+            }
+        }
+
+        #[inline]
+        pub fn seq(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(BalanceEvent::VT_SEQ, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn account(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(BalanceEvent::VT_ACCOUNT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn kind(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u8>(BalanceEvent::VT_KIND, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn height(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(BalanceEvent::VT_HEIGHT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn txid(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        BalanceEvent::VT_TXID,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn pool(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u8>(BalanceEvent::VT_POOL, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn id_ref(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(BalanceEvent::VT_ID_REF, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn value(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(BalanceEvent::VT_VALUE, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for BalanceEvent<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("seq", Self::VT_SEQ, false)?
+                .visit_field::<u32>("account", Self::VT_ACCOUNT, false)?
+                .visit_field::<u8>("kind", Self::VT_KIND, false)?
+                .visit_field::<u32>("height", Self::VT_HEIGHT, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "txid",
+                    Self::VT_TXID,
+                    false,
+                )?
+                .visit_field::<u8>("pool", Self::VT_POOL, false)?
+                .visit_field::<u32>("id_ref", Self::VT_ID_REF, false)?
+                .visit_field::<u64>("value", Self::VT_VALUE, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct BalanceEventArgs<'a> {
+        pub seq: u32,
+        pub account: u32,
+        pub kind: u8,
+        pub height: u32,
+        pub txid: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub pool: u8,
+        pub id_ref: u32,
+        pub value: u64,
+    }
+    impl<'a> Default for BalanceEventArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            BalanceEventArgs {
+                seq: 0,
+                account: 0,
+                kind: 0,
+                height: 0,
+                txid: None,
+                pool: 0,
+                id_ref: 0,
+                value: 0,
+            }
+        }
+    }
+
+    pub struct BalanceEventBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> BalanceEventBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_seq(&mut self, seq: u32) {
+            self.fbb_.push_slot::<u32>(BalanceEvent::VT_SEQ, seq, 0);
+        }
+        #[inline]
+        pub fn add_account(&mut self, account: u32) {
+            self.fbb_
+                .push_slot::<u32>(BalanceEvent::VT_ACCOUNT, account, 0);
+        }
+        #[inline]
+        pub fn add_kind(&mut self, kind: u8) {
+            self.fbb_.push_slot::<u8>(BalanceEvent::VT_KIND, kind, 0);
+        }
+        #[inline]
+        pub fn add_height(&mut self, height: u32) {
+            self.fbb_
+                .push_slot::<u32>(BalanceEvent::VT_HEIGHT, height, 0);
+        }
+        #[inline]
+        pub fn add_txid(&mut self, txid: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(BalanceEvent::VT_TXID, txid);
+        }
+        #[inline]
+        pub fn add_pool(&mut self, pool: u8) {
+            self.fbb_.push_slot::<u8>(BalanceEvent::VT_POOL, pool, 0);
+        }
+        #[inline]
+        pub fn add_id_ref(&mut self, id_ref: u32) {
+            self.fbb_
+                .push_slot::<u32>(BalanceEvent::VT_ID_REF, id_ref, 0);
+        }
+        #[inline]
+        pub fn add_value(&mut self, value: u64) {
+            self.fbb_.push_slot::<u64>(BalanceEvent::VT_VALUE, value, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> BalanceEventBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            BalanceEventBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<BalanceEvent<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for BalanceEvent<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("BalanceEvent");
+            ds.field("seq", &self.seq());
+            ds.field("account", &self.account());
+            ds.field("kind", &self.kind());
+            ds.field("height", &self.height());
+            ds.field("txid", &self.txid());
+            ds.field("pool", &self.pool());
+            ds.field("id_ref", &self.id_ref());
+            ds.field("value", &self.value());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct BalanceEventT {
+        pub seq: u32,
+        pub account: u32,
+        pub kind: u8,
+        pub height: u32,
+        pub txid: Option<Vec<u8>>,
+        pub pool: u8,
+        pub id_ref: u32,
+        pub value: u64,
+    }
+    impl Default for BalanceEventT {
+        fn default() -> Self {
+            Self {
+                seq: 0,
+                account: 0,
+                kind: 0,
+                height: 0,
+                txid: None,
+                pool: 0,
+                id_ref: 0,
+                value: 0,
+            }
+        }
+    }
+    impl BalanceEventT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<BalanceEvent<'b>> {
+            let seq = self.seq;
+            let account = self.account;
+            let kind = self.kind;
+            let height = self.height;
+            let txid = self.txid.as_ref().map(|x| _fbb.create_vector(x));
+            let pool = self.pool;
+            let id_ref = self.id_ref;
+            let value = self.value;
+            BalanceEvent::create(
+                _fbb,
+                &BalanceEventArgs {
+                    seq,
+                    account,
+                    kind,
+                    height,
+                    txid,
+                    pool,
+                    id_ref,
+                    value,
+                },
+            )
+        }
+    }
+    pub enum ProofProgressOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct ProofProgress<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for ProofProgress<'a> {
+        type Inner = ProofProgress<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> ProofProgress<'a> {
+        pub const VT_POOL: flatbuffers::VOffsetT = 4;
+        pub const VT_CURRENT: flatbuffers::VOffsetT = 6;
+        pub const VT_TOTAL: flatbuffers::VOffsetT = 8;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            ProofProgress { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args ProofProgressArgs,
+        ) -> flatbuffers::WIPOffset<ProofProgress<'bldr>> {
+            let mut builder = ProofProgressBuilder::new(_fbb);
+            builder.add_total(args.total);
+            builder.add_current(args.current);
+            builder.add_pool(args.pool);
+            builder.finish()
+        }
+
+        pub fn unpack(&self) -> ProofProgressT {
+            let pool = self.pool();
+            let current = self.current();
+            let total = self.total();
+            ProofProgressT {
+                pool,
+                current,
+                total,
+            }
+        }
+
+        #[inline]
+        pub fn pool(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(ProofProgress::VT_POOL, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn current(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(ProofProgress::VT_CURRENT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn total(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(ProofProgress::VT_TOTAL, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for ProofProgress<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u8>("pool", Self::VT_POOL, false)?
+                .visit_field::<u32>("current", Self::VT_CURRENT, false)?
+                .visit_field::<u32>("total", Self::VT_TOTAL, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct ProofProgressArgs {
+        pub pool: u8,
+        pub current: u32,
+        pub total: u32,
+    }
+    impl<'a> Default for ProofProgressArgs {
+        #[inline]
+        fn default() -> Self {
+            ProofProgressArgs {
+                pool: 0,
+                current: 0,
+                total: 0,
+            }
+        }
+    }
+
+    pub struct ProofProgressBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ProofProgressBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_pool(&mut self, pool: u8) {
+            self.fbb_.push_slot::<u8>(ProofProgress::VT_POOL, pool, 0);
+        }
+        #[inline]
+        pub fn add_current(&mut self, current: u32) {
+            self.fbb_
+                .push_slot::<u32>(ProofProgress::VT_CURRENT, current, 0);
+        }
+        #[inline]
+        pub fn add_total(&mut self, total: u32) {
+            self.fbb_
+                .push_slot::<u32>(ProofProgress::VT_TOTAL, total, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> ProofProgressBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            ProofProgressBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<ProofProgress<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for ProofProgress<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("ProofProgress");
+            ds.field("pool", &self.pool());
+            ds.field("current", &self.current());
+            ds.field("total", &self.total());
+            ds.finish()
+        }
+    }
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub struct ProofProgressT {
+        pub pool: u8,
+        pub current: u32,
+        pub total: u32,
+    }
+    impl Default for ProofProgressT {
+        fn default() -> Self {
+            Self {
+                pool: 0,
+                current: 0,
+                total: 0,
+            }
+        }
+    }
+    impl ProofProgressT {
+        pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+            &self,
+            _fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+        ) -> flatbuffers::WIPOffset<ProofProgress<'b>> {
+            let pool = self.pool;
+            let current = self.current;
+            let total = self.total;
+            ProofProgress::create(
+                _fbb,
+                &ProofProgressArgs {
+                    pool,
+                    current,
+                    total,
+                },
+            )
+        }
+    }
 } // pub mod fb
@@ -0,0 +1,297 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use flatbuffers::FlatBufferBuilder;
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use rand::rngs::OsRng;
+use tokio::task::AbortHandle;
+
+use crate::{
+    coin::CoinDef,
+    data::fb::{JobCommand, JobCommandT, JobStatus, JobStatusT},
+    db::{
+        account_manager::create_accounts_bulk,
+        api_keys::{authorize_session, has_api_keys, payment_amount, sign_operations, Operation},
+        chain::get_sync_height,
+    },
+    fb_to_bytes, fb_unwrap,
+    lwd::get_last_height,
+    pay::UnsignedTransaction,
+    txdetails::run_tx_details_worker,
+    utils::pay::{prepare_payment_inner, PaymentOptions},
+    warp::sync::warp_synchronize,
+};
+use warp_macros::c_export;
+
+/*
+    Background jobs
+
+    Syncing and building a transaction take a long time, and the FFI
+    calls block the caller thread until they finish.
+    Instead, the caller can submit them as jobs that run on the runtime
+    of the coin, and poll their status from time to time.
+
+    For JOB_SYNC, `height` is the target height (0 for the tip of
+    the server). For JOB_SIGN, it is the expiration height.
+    JOB_TX_DETAILS retrieves the missing transaction details at
+    `bandwidth` bytes per second at most (0 for no limit).
+
+    Once the wallet has an API key, a job needs the id of a session
+    (see `db::api_keys`) whose key allows it: JOB_PREPARE_PAYMENT is a payment from the account,
+    JOB_SIGN a payment from the accounts whose keys sign it (see
+    `sign_operations`), the other jobs need an admin key.
+
+    The status has the progress of the job (blocks for a sync,
+    proofs for a signature, transactions for the details) and,
+    when it is done, the result as a flatbuffer: TransactionSummary
+    for JOB_PREPARE_PAYMENT, TransactionBytes for JOB_SIGN,
+    BulkAccounts for JOB_CREATE_ACCOUNTS, nothing for JOB_SYNC and
+    JOB_TX_DETAILS.
+    A finished job is removed after its status has been polled.
+
+    Jobs live in memory. Cancelling a job stops it at its next
+    await point: a db transaction in progress is rolled back.
+    The proofs of a signature are not interrupted, but their
+    result is discarded.
+*/
+
+pub const JOB_SYNC: u8 = 0;
+pub const JOB_PREPARE_PAYMENT: u8 = 1;
+pub const JOB_SIGN: u8 = 2;
+pub const JOB_CREATE_ACCOUNTS: u8 = 3;
+pub const JOB_TX_DETAILS: u8 = 4;
+
+pub const JOB_RUNNING: u8 = 0;
+pub const JOB_DONE: u8 = 1;
+pub const JOB_FAILED: u8 = 2;
+pub const JOB_CANCELLED: u8 = 3;
+
+lazy_static! {
+    static ref JOBS: Mutex<Jobs> = Mutex::new(Jobs::default());
+}
+
+#[derive(Default)]
+struct Jobs {
+    next_id: u32,
+    jobs: HashMap<u32, Job>,
+}
+
+struct Job {
+    status: JobStatusT,
+    handle: Option<AbortHandle>,
+}
+
+/// Run a command in the background. Returns the job id
+#[c_export]
+pub fn submit_job(coin: &CoinDef, command: &JobCommandT) -> Result<u32> {
+    if command.kind > JOB_TX_DETAILS {
+        anyhow::bail!("Invalid job {}", command.kind);
+    }
+    authorize_job(coin, command)?;
+    let id = {
+        let mut jobs = JOBS.lock();
+        jobs.next_id += 1;
+        let id = jobs.next_id;
+        let status = JobStatusT {
+            id,
+            kind: command.kind,
+            state: JOB_RUNNING,
+            ..JobStatusT::default()
+        };
+        jobs.jobs.insert(id, Job { status, handle: None });
+        id
+    };
+
+    let coin = coin.clone();
+    let command = command.clone();
+    let runtime = coin.runtime.0.clone().unwrap();
+    let handle = runtime.spawn(async move {
+        let res = run_job(&coin, id, &command).await;
+        update_status(id, |status| match res {
+            Ok(result) => {
+                status.state = JOB_DONE;
+                status.result = Some(result);
+            }
+            Err(e) => {
+                status.state = JOB_FAILED;
+                status.error = Some(e.to_string());
+            }
+        });
+    });
+    if let Some(job) = JOBS.lock().jobs.get_mut(&id) {
+        job.handle = Some(handle.abort_handle());
+    }
+    Ok(id)
+}
+
+fn authorize_job(coin: &CoinDef, command: &JobCommandT) -> Result<()> {
+    let connection = coin.connection()?;
+    if !has_api_keys(&connection)? {
+        return Ok(());
+    }
+    let operations = match command.kind {
+        JOB_PREPARE_PAYMENT => vec![Operation::Pay {
+            account: command.account,
+            amount: payment_amount(fb_unwrap!(command.payment)),
+        }],
+        JOB_SIGN => {
+            let data = fb_unwrap!(fb_unwrap!(command.summary).data);
+            let unsigned_tx = bincode::deserialize_from::<_, UnsignedTransaction>(&data[..])?;
+            sign_operations(&coin.network, &connection, &unsigned_tx)?
+        }
+        _ => vec![Operation::Admin],
+    };
+    for operation in operations.iter() {
+        authorize_session(command.session, operation)?;
+    }
+    Ok(())
+}
+
+/// Status of a job. It is forgotten once it has finished
+#[c_export]
+pub fn poll_job(id: u32) -> Result<JobStatusT> {
+    let mut jobs = JOBS.lock();
+    let job = jobs
+        .jobs
+        .get(&id)
+        .ok_or(anyhow::anyhow!("Unknown job {}", id))?;
+    let status = job.status.clone();
+    if status.state != JOB_RUNNING {
+        jobs.jobs.remove(&id);
+    }
+    Ok(status)
+}
+
+#[c_export]
+pub fn cancel_job(id: u32) -> Result<()> {
+    let mut jobs = JOBS.lock();
+    if let Some(job) = jobs.jobs.get_mut(&id) {
+        if job.status.state == JOB_RUNNING {
+            if let Some(handle) = job.handle.take() {
+                handle.abort();
+            }
+            job.status.state = JOB_CANCELLED;
+        }
+    }
+    Ok(())
+}
+
+async fn run_job(coin: &CoinDef, id: u32, command: &JobCommandT) -> Result<Vec<u8>> {
+    match command.kind {
+        JOB_SYNC => {
+            // warp_synchronize syncs a batch of blocks at most
+            let mut client = coin.connect_lwd()?;
+            let end = match command.height {
+                0 => get_last_height(&mut client).await?,
+                height => height,
+            };
+            let mut height = get_sync_height(&coin.connection()?)?.height;
+            while height < end {
+                update_status(id, |status| {
+                    status.current = height;
+                    status.total = end;
+                });
+                warp_synchronize(coin, end).await?;
+                let new_height = get_sync_height(&coin.connection()?)?.height;
+                if new_height == height {
+                    anyhow::bail!("Sync is stuck at {} (already running?)", height);
+                }
+                height = new_height;
+            }
+            update_status(id, |status| {
+                status.current = end;
+                status.total = end;
+            });
+            Ok(vec![])
+        }
+        JOB_PREPARE_PAYMENT => {
+            // authorized by `submit_job` with the session of the job
+            let mut client = coin.connect_lwd()?;
+            let summary = prepare_payment_inner(
+                &coin.network,
+                coin.connection()?,
+                &mut client,
+                command.account,
+                fb_unwrap!(command.payment),
+                &PaymentOptions::new(
+                    coin,
+                    command.snap_policy,
+                    command.redirect.as_deref().unwrap_or_default(),
+                ),
+            )
+            .await?;
+            Ok(fb_to_bytes!(summary))
+        }
+        JOB_SIGN => {
+            let connection = coin.connection()?;
+            let data = fb_unwrap!(fb_unwrap!(command.summary).data);
+            let unsigned_tx = bincode::deserialize_from::<_, UnsignedTransaction>(&data[..])?;
+            let txb = unsigned_tx
+                .build_async(&coin.network, &connection, command.height, OsRng, |p| {
+                    update_status(id, |status| {
+                        status.current = p.current;
+                        status.total = p.total;
+                    })
+                })
+                .await?;
+            Ok(fb_to_bytes!(txb))
+        }
+        JOB_CREATE_ACCOUNTS => {
+            let mut connection = coin.connection()?;
+            let keys = fb_unwrap!(command.keys);
+            let accounts = create_accounts_bulk(
+                &coin.network,
+                &mut connection,
+                fb_unwrap!(keys.keys),
+                keys.prefix.as_deref().unwrap_or_default(),
+                keys.birth,
+                keys.pools,
+                |current, total| {
+                    update_status(id, |status| {
+                        status.current = current;
+                        status.total = total;
+                    })
+                },
+            )?;
+            Ok(fb_to_bytes!(accounts))
+        }
+        JOB_TX_DETAILS => {
+            // the worker keeps a connection across its requests, its
+            // future cannot move between threads. It stops at the next
+            // transaction when the job is cancelled
+            let coin = coin.clone();
+            let bandwidth = command.bandwidth;
+            let runtime = tokio::runtime::Handle::current();
+            tokio::task::spawn_blocking(move || {
+                runtime.block_on(run_tx_details_worker(&coin, bandwidth, |current, total| {
+                    update_status(id, |status| {
+                        status.current = current;
+                        status.total = total;
+                    });
+                    is_running(id)
+                }))
+            })
+            .await??;
+            Ok(vec![])
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn is_running(id: u32) -> bool {
+    JOBS.lock()
+        .jobs
+        .get(&id)
+        .map(|job| job.status.state == JOB_RUNNING)
+        .unwrap_or_default()
+}
+
+/// Update the status of a running job
+fn update_status<F: FnOnce(&mut JobStatusT)>(id: u32, f: F) {
+    if let Some(job) = JOBS.lock().jobs.get_mut(&id) {
+        if job.status.state == JOB_RUNNING {
+            f(&mut job.status);
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use anyhow::Result;
 use base58check::{FromBase58Check, ToBase58Check};
 use bip32::{ExtendedPrivateKey, ExtendedPublicKey};
-use bip39::Mnemonic;
+use bip39::{Language, Mnemonic};
 use orchard::keys::{FullViewingKey, Scope, SpendingKey};
 use prost::bytes::BufMut as _;
 use rand::{rngs::OsRng, CryptoRng, RngCore};
@@ -43,8 +43,13 @@ pub struct AccountKeys {
 }
 
 impl AccountKeys {
-    pub fn from_seed(network: &Network, phrase: &str, acc_index: u32) -> Result<Self> {
-        let seed = parse_seed_phrase(phrase)?;
+    pub fn from_seed(
+        network: &Network,
+        phrase: &str,
+        passphrase: &str,
+        acc_index: u32,
+    ) -> Result<Self> {
+        let seed = parse_seed_phrase(phrase, passphrase)?;
         let usk = UnifiedSpendingKey::from_seed(
             network,
             seed.as_bytes(),
@@ -134,17 +139,77 @@ impl AccountKeys {
     }
 }
 
-pub fn generate_random_mnemonic_phrase<R: RngCore + CryptoRng>(mut rng: R) -> String {
+/*
+    Mnemonic languages
+
+    Seed phrases can use any of the BIP-39 word lists. The language
+    is not stored with the phrase: on import, it is the first language
+    of MNEMONIC_LANGUAGES whose word list has every word of the phrase
+    and gives a valid checksum. The seed only depends on the phrase,
+    so a phrase keeps its language when it is exported.
+
+    Languages are selected by their code: en, zh-hans, zh-hant,
+    fr, it, ja, ko, es. The empty code is English.
+*/
+
+pub const MNEMONIC_LANGUAGES: [(Language, &str); 8] = [
+    (Language::English, "en"),
+    (Language::ChineseSimplified, "zh-hans"),
+    (Language::ChineseTraditional, "zh-hant"),
+    (Language::French, "fr"),
+    (Language::Italian, "it"),
+    (Language::Japanese, "ja"),
+    (Language::Korean, "ko"),
+    (Language::Spanish, "es"),
+];
+
+pub fn mnemonic_language(code: &str) -> Result<Language> {
+    if code.is_empty() {
+        return Ok(Language::English);
+    }
+    let (language, _) = MNEMONIC_LANGUAGES
+        .iter()
+        .find(|(_, c)| *c == code)
+        .ok_or(anyhow::anyhow!("Unknown mnemonic language {}", code))?;
+    Ok(*language)
+}
+
+/// Parse a phrase in any of the BIP-39 languages.
+/// Returns the mnemonic and the code of its language
+pub fn parse_mnemonic(phrase: &str) -> Result<(Mnemonic, &'static str)> {
+    MNEMONIC_LANGUAGES
+        .iter()
+        .find_map(|(language, code)| {
+            Mnemonic::from_phrase(phrase, *language)
+                .ok()
+                .map(|m| (m, *code))
+        })
+        .ok_or(anyhow::anyhow!("Invalid seed phrase"))
+}
+
+pub fn generate_random_mnemonic_phrase<R: RngCore + CryptoRng>(
+    mut rng: R,
+    language: Language,
+) -> String {
     let mut entropy = [0u8; 32];
     rng.fill_bytes(&mut entropy);
-    Mnemonic::from_entropy(&entropy, bip39::Language::English)
+    Mnemonic::from_entropy(&entropy, language)
         .unwrap()
         .into_phrase()
 }
 
+/// `language` is a language code, see MNEMONIC_LANGUAGES
+#[c_export]
+pub fn generate_random_mnemonic_phrase_os_rng(language: &str) -> Result<String> {
+    let language = mnemonic_language(language)?;
+    Ok(generate_random_mnemonic_phrase(OsRng, language))
+}
+
+/// Code of the language of a seed phrase
 #[c_export]
-pub fn generate_random_mnemonic_phrase_os_rng() -> Result<String> {
-    Ok(generate_random_mnemonic_phrase(OsRng))
+pub fn get_mnemonic_language(phrase: &str) -> Result<String> {
+    let (_, code) = parse_mnemonic(phrase)?;
+    Ok(code.to_string())
 }
 
 pub fn export_sk_bip38(sk: &SecretKey) -> String {
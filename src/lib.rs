@@ -7,14 +7,19 @@ use tonic::transport::Channel;
 pub mod data;
 
 pub mod account;
+pub mod api;
 pub mod cli;
 pub mod coin;
 pub mod db;
 pub mod ffi;
+pub mod jobs;
 mod keys;
 pub mod lwd;
+pub mod metrics;
 pub mod network;
 pub mod pay;
+#[cfg(feature = "testkit")]
+pub mod testkit;
 pub mod txdetails;
 pub mod types;
 pub mod utils;
@@ -26,8 +31,10 @@ pub type Hash = [u8; 32];
 pub type VecBytes = Vec<u8>;
 
 pub const EXPIRATION_HEIGHT_DELTA: u32 = 50;
+/// Confirmations before the outputs of a coinbase transaction can be spent
+pub const COINBASE_MATURITY: u32 = 100;
 
 // pub use coin::{CoinDef, COINS};
 // pub use keys::{generate_random_mnemonic_phrase, TSKStore};
-pub use cli::cli_main;
+pub use cli::{cli_main, cli_script};
 pub use zcash_proofs::download_sapling_parameters;
@@ -1,12 +1,16 @@
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
 use rpc::{
-    BlockId, BlockRange, CompactBlock, Empty, GetAddressUtxosArg, RawTransaction,
+    BlockId, BlockRange, CompactBlock, CompactTx, Empty, Exclude, GetAddressUtxosArg,
+    GetSubtreeRootsArg, RawTransaction, ShieldedProtocol, SubtreeRoot,
     TransparentAddressBlockFilter, TreeState, TxFilter,
 };
+use rusqlite::Connection;
+use thiserror::Error;
 use tokio::runtime::Handle;
-use tonic::{Request, Streaming};
+use tonic::{Code, Request, Status, Streaming};
+use tracing::Instrument as _;
 use zcash_client_backend::encoding::AddressCodec as _;
 use zcash_primitives::{
     consensus::{BlockHeight, BranchId},
@@ -16,11 +20,14 @@ use zcash_primitives::{
 
 use crate::{
     coin::{connect_lwd, CoinDef},
-    data::fb::TransactionBytesT,
+    data::fb::{ServerT, TransactionBytesT},
+    db::servers::{list_servers, record_server_test},
     network::Network,
     types::CheckpointHeight,
-    utils::ContextExt as _,
-    warp::{legacy::CommitmentTreeFrontier, OutPoint, TransparentTx, TxOut2, UTXO},
+    warp::{
+        legacy::CommitmentTreeFrontier, script::ScriptType, OutPoint, ScriptTxOut, TransparentTx,
+        TxOut2, UTXO,
+    },
     Client,
 };
 
@@ -29,27 +36,113 @@ use warp_macros::c_export;
 #[path = "./generated/cash.z.wallet.sdk.rpc.rs"]
 pub mod rpc;
 
+#[derive(Error, Debug)]
+pub enum LwdError {
+    #[error("Cannot connect to server: {0}")]
+    Connection(#[from] tonic::transport::Error),
+    #[error("Server error: {0}")]
+    Status(Status),
+    #[error("Invalid server data: {0}")]
+    Decode(String),
+    #[error("Not found: {0}")]
+    NotFound(String),
+}
+
+pub type LwdResult<T> = std::result::Result<T, LwdError>;
+
+impl From<Status> for LwdError {
+    fn from(status: Status) -> Self {
+        match status.code() {
+            Code::NotFound => LwdError::NotFound(status.message().to_string()),
+            _ => LwdError::Status(status),
+        }
+    }
+}
+
+impl From<std::io::Error> for LwdError {
+    fn from(e: std::io::Error) -> Self {
+        LwdError::Decode(e.to_string())
+    }
+}
+
+impl LwdError {
+    /// True if the call may succeed when retried later,
+    /// i.e. the server is down or overloaded
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            LwdError::Connection(_) => true,
+            LwdError::Status(status) => matches!(
+                status.code(),
+                Code::Unavailable
+                    | Code::DeadlineExceeded
+                    | Code::ResourceExhausted
+                    | Code::Aborted
+                    | Code::Cancelled
+            ),
+            _ => false,
+        }
+    }
+
+    /// True if the server does not implement the call
+    pub fn is_unimplemented(&self) -> bool {
+        matches!(self, LwdError::Status(status) if status.code() == Code::Unimplemented)
+    }
+}
+
+/// Run a LWD call inside a tracing span and record its latency
+async fn timed<T, F>(name: &'static str, f: F) -> LwdResult<T>
+where
+    F: std::future::Future<Output = LwdResult<T>>,
+{
+    let span = tracing::debug_span!("lwd", call = name);
+    async move {
+        let start = Instant::now();
+        let res = f.await;
+        let elapsed = start.elapsed().as_millis() as u64;
+        match &res {
+            Ok(_) => tracing::debug!(elapsed, "ok"),
+            Err(e) => tracing::debug!(elapsed, retryable = e.is_retryable(), "{}", e),
+        }
+        res
+    }
+    .instrument(span)
+    .await
+}
+
 #[c_export]
 pub async fn get_last_height(client: &mut Client) -> Result<u32> {
-    let r = client
-        .get_lightd_info(Request::new(Empty {}))
-        .await?
-        .into_inner();
+    let r = timed("get_lightd_info", async {
+        Ok(client.get_lightd_info(Request::new(Empty {})).await?.into_inner())
+    })
+    .await?;
     Ok(r.block_height as u32)
 }
 
+/// Height of the server and consensus branch id of the next block
+pub async fn get_server_branch_id(client: &mut Client) -> Result<(u32, u32)> {
+    let r = timed("get_lightd_info", async {
+        Ok(client.get_lightd_info(Request::new(Empty {})).await?.into_inner())
+    })
+    .await?;
+    let branch_id = u32::from_str_radix(&r.consensus_branch_id, 16)?;
+    Ok((r.block_height as u32, branch_id))
+}
+
 pub async fn get_tree_state(
     client: &mut Client,
     height: CheckpointHeight,
-) -> Result<(CommitmentTreeFrontier, CommitmentTreeFrontier)> {
+) -> LwdResult<(CommitmentTreeFrontier, CommitmentTreeFrontier)> {
     let height: u32 = height.into();
-    let tree_state = client
-        .get_tree_state(Request::new(BlockId {
-            height: height as u64,
-            hash: vec![],
-        }))
-        .await?
-        .into_inner();
+    let tree_state = timed("get_tree_state", async {
+        Ok(client
+            .get_tree_state(Request::new(BlockId {
+                height: height as u64,
+                hash: vec![],
+            }))
+            .await?
+            .into_inner())
+    })
+    .await?;
 
     let TreeState {
         sapling_tree,
@@ -57,17 +150,17 @@ pub async fn get_tree_state(
         ..
     } = tree_state;
 
-    fn decode_tree_state(s: &str) -> CommitmentTreeFrontier {
+    fn decode_tree_state(s: &str) -> LwdResult<CommitmentTreeFrontier> {
         if s.is_empty() {
-            CommitmentTreeFrontier::default()
+            Ok(CommitmentTreeFrontier::default())
         } else {
-            let tree = hex::decode(s).unwrap();
-            CommitmentTreeFrontier::read(&*tree).unwrap()
+            let tree = hex::decode(s).map_err(|e| LwdError::Decode(e.to_string()))?;
+            Ok(CommitmentTreeFrontier::read(&*tree)?)
         }
     }
 
-    let sapling = decode_tree_state(&sapling_tree);
-    let orchard = decode_tree_state(&orchard_tree);
+    let sapling = decode_tree_state(&sapling_tree)?;
+    let orchard = decode_tree_state(&orchard_tree)?;
 
     #[cfg(test)]
     {
@@ -88,32 +181,62 @@ pub async fn get_tree_state(
     Ok((sapling, orchard))
 }
 
-pub async fn get_compact_block(client: &mut Client, height: u32) -> Result<CompactBlock> {
-    let mut blocks = client
-        .get_block_range(Request::new(BlockRange {
+pub async fn get_compact_block(client: &mut Client, height: u32) -> LwdResult<CompactBlock> {
+    timed("get_compact_block", async {
+        let mut blocks = client
+            .get_block_range(Request::new(BlockRange {
+                start: Some(BlockId {
+                    height: height as u64,
+                    hash: vec![],
+                }),
+                end: Some(BlockId {
+                    height: height as u64,
+                    hash: vec![],
+                }),
+                spam_filter_threshold: 0,
+            }))
+            .await?
+            .into_inner();
+        while let Some(block) = blocks.message().await? {
+            return Ok(block);
+        }
+        Err(LwdError::NotFound(format!("block {height}")))
+    })
+    .await
+}
+
+pub async fn get_compact_block_range(
+    client: &mut Client,
+    start: u32,
+    end: u32,
+) -> LwdResult<Streaming<CompactBlock>> {
+    let req = || {
+        Request::new(BlockRange {
             start: Some(BlockId {
-                height: height as u64,
+                height: start as u64,
                 hash: vec![],
             }),
             end: Some(BlockId {
-                height: height as u64,
+                height: end as u64,
                 hash: vec![],
             }),
             spam_filter_threshold: 0,
-        }))
-        .await?
-        .into_inner();
-    while let Some(block) = blocks.message().await? {
-        return Ok(block);
-    }
-    Err(anyhow::anyhow!("No block found"))
+        })
+    };
+    timed("get_block_range", async {
+        Ok(client.get_block_range(req()).await?.into_inner())
+    })
+    .await
 }
 
-pub async fn get_compact_block_range(
+/// Same as `get_compact_block_range` but the blocks only have
+/// the data needed for trial decryption and the tree updates.
+/// Only warp servers implement it
+pub async fn get_pruned_block_range(
     client: &mut Client,
     start: u32,
     end: u32,
-) -> Result<Streaming<CompactBlock>> {
+) -> LwdResult<Streaming<CompactBlock>> {
     let req = || {
         Request::new(BlockRange {
             start: Some(BlockId {
@@ -127,8 +250,52 @@ pub async fn get_compact_block_range(
             spam_filter_threshold: 0,
         })
     };
-    let blocks = client.get_block_range(req()).await?.into_inner();
-    Ok(blocks)
+    timed("get_pruned_block_range", async {
+        Ok(client.get_pruned_block_range(req()).await?.into_inner())
+    })
+    .await
+}
+
+/// Check if the server implements `GetPrunedBlockRange`
+/// by requesting a single block
+pub async fn has_pruned_block_range(client: &mut Client, height: u32) -> LwdResult<bool> {
+    match get_pruned_block_range(client, height, height).await {
+        Ok(mut blocks) => match blocks.message().await {
+            Ok(_) => Ok(true),
+            Err(status) => {
+                let e = LwdError::from(status);
+                if e.is_unimplemented() {
+                    Ok(false)
+                } else {
+                    Err(e)
+                }
+            }
+        },
+        Err(e) if e.is_unimplemented() => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+pub const MAX_RETRIES: u32 = 3;
+
+/// Await a call to the server, and call it again when it fails because
+/// the server is down or overloaded, at most MAX_RETRIES times and
+/// with a backoff that doubles
+#[macro_export]
+macro_rules! lwd_retry {
+    ($call: expr) => {{
+        let mut attempt = 0;
+        loop {
+            match $call.await {
+                Err(e) if e.is_retryable() && attempt < $crate::lwd::MAX_RETRIES => {
+                    attempt += 1;
+                    tracing::info!("{} attempt {} failed: {}", stringify!($call), attempt, e);
+                    tokio::time::sleep(std::time::Duration::from_secs(1 << attempt)).await;
+                }
+                res => break res,
+            }
+        }
+    }};
 }
 
 pub async fn get_transparent(
@@ -140,80 +307,108 @@ pub async fn get_transparent(
     taddr: TransparentAddress,
     start: u32,
     end: u32,
-) -> Result<Vec<TransparentTx>> {
+) -> LwdResult<Vec<TransparentTx>> {
     let taddr_string = taddr.encode(network);
     tracing::info!("get_transparent {taddr_string}");
-    let mut txs = client
-        .get_taddress_txids(Request::new(TransparentAddressBlockFilter {
-            address: taddr_string,
-            range: Some(BlockRange {
-                start: Some(BlockId {
-                    height: start as u64,
-                    hash: vec![],
-                }),
-                end: Some(BlockId {
-                    height: end as u64,
-                    hash: vec![],
+    timed("get_taddress_txids", async {
+        let mut txs = client
+            .get_taddress_txids(Request::new(TransparentAddressBlockFilter {
+                address: taddr_string,
+                range: Some(BlockRange {
+                    start: Some(BlockId {
+                        height: start as u64,
+                        hash: vec![],
+                    }),
+                    end: Some(BlockId {
+                        height: end as u64,
+                        hash: vec![],
+                    }),
+                    spam_filter_threshold: 0,
                 }),
-                spam_filter_threshold: 0,
-            }),
-        }))
-        .await?
-        .into_inner();
-    let mut ttxs = vec![];
-    while let Some(raw_tx) = txs.message().await? {
-        let height = raw_tx.height as u32;
-        let raw_tx = raw_tx.data;
-        let branch_id = BranchId::for_height(network, BlockHeight::from_u32(height));
-        let tx = Transaction::read(&*raw_tx, branch_id)?;
-        let transparent_bundle = tx.transparent_bundle().unwrap();
-        let mut vins = vec![];
-        for vin in transparent_bundle.vin.iter() {
-            let prev_out = crate::warp::OutPoint {
-                txid: vin.prevout.hash().clone(),
-                vout: vin.prevout.n(),
-            };
-            vins.push(prev_out);
-        }
-        let mut vouts = vec![];
-        for (vout, txout) in transparent_bundle.vout.iter().enumerate() {
-            if let Some(address) = txout.recipient_address() {
-                if address == taddr {
-                    let out = crate::warp::TxOut {
-                        address: txout.recipient_address(),
-                        value: txout.value.into(),
-                        vout: vout as u32,
-                    };
-                    vouts.push(out);
+            }))
+            .await?
+            .into_inner();
+        let mut ttxs = vec![];
+        while let Some(raw_tx) = txs.message().await? {
+            let height = raw_tx.height as u32;
+            let raw_tx = raw_tx.data;
+            let branch_id = BranchId::for_height(network, BlockHeight::from_u32(height));
+            let tx = Transaction::read(&*raw_tx, branch_id)?;
+            let transparent_bundle = tx
+                .transparent_bundle()
+                .ok_or(LwdError::Decode("No transparent bundle".to_string()))?;
+            let mut vins = vec![];
+            for vin in transparent_bundle.vin.iter() {
+                let prev_out = crate::warp::OutPoint {
+                    txid: vin.prevout.hash().clone(),
+                    vout: vin.prevout.n(),
+                };
+                vins.push(prev_out);
+            }
+            let mut vouts = vec![];
+            let mut script_vouts = vec![];
+            for (vout, txout) in transparent_bundle.vout.iter().enumerate() {
+                if let Some(address) = txout.recipient_address() {
+                    if address == taddr {
+                        let out = crate::warp::TxOut {
+                            address: txout.recipient_address(),
+                            value: txout.value.into(),
+                            vout: vout as u32,
+                        };
+                        vouts.push(out);
+                    }
+                } else {
+                    // matched with the keys of the wallet by the sync
+                    let script_type = ScriptType::classify(&txout.script_pubkey.0);
+                    if matches!(
+                        script_type,
+                        ScriptType::PubKey(_) | ScriptType::Multisig { .. }
+                    ) {
+                        script_vouts.push(ScriptTxOut {
+                            vout: vout as u32,
+                            value: txout.value.into(),
+                            script_type: script_type.code(),
+                            script: txout.script_pubkey.0.clone(),
+                        });
+                    }
                 }
             }
+            let ttx = TransparentTx {
+                account,
+                height,
+                external,
+                addr_index,
+                address: taddr.clone(),
+                timestamp: 0,
+                txid: *tx.txid().as_ref(),
+                vins,
+                vouts,
+                script_vouts,
+            };
+            ttxs.push(ttx);
         }
-        let ttx = TransparentTx {
-            account,
-            height,
-            external,
-            addr_index,
-            address: taddr.clone(),
-            timestamp: 0,
-            txid: tx.txid().as_ref().clone().try_into().unwrap(),
-            vins,
-            vouts,
-        };
-        ttxs.push(ttx);
-    }
 
-    Ok(ttxs)
+        Ok(ttxs)
+    })
+    .await
 }
 
-pub async fn broadcast(client: &mut Client, height: u32, tx: &TransactionBytesT) -> Result<String> {
+pub async fn broadcast(
+    client: &mut Client,
+    height: u32,
+    tx: &TransactionBytesT,
+) -> LwdResult<String> {
     let bb = tx.data.as_ref();
-    let res = client
-        .send_transaction(Request::new(RawTransaction {
-            data: bb.cloned().unwrap_or_default(),
-            height: height as u64,
-        }))
-        .await?
-        .into_inner();
+    let res = timed("send_transaction", async {
+        Ok(client
+            .send_transaction(Request::new(RawTransaction {
+                data: bb.cloned().unwrap_or_default(),
+                height: height as u64,
+            }))
+            .await?
+            .into_inner())
+    })
+    .await?;
     Ok(res.error_message)
 }
 
@@ -223,22 +418,18 @@ pub fn get_txin_coins(coin: &CoinDef, network: Network, ops: Vec<OutPoint>) -> R
             let mut client = coin.connect_lwd()?;
             let mut txouts = vec![];
             for op in ops {
-                let tx = client
-                    .get_transaction(Request::new(TxFilter {
-                        block: None,
-                        index: 0,
-                        hash: op.txid.to_vec(),
-                    }))
-                    .await
-                    .with_file_line(|| "get_transaction")?
-                    .into_inner();
-                let data = &*tx.data;
-                let tx = Transaction::read(data, BranchId::Nu5)?;
-                let tx_data = tx.into_data();
-                let b = tx_data
+                let (_, tx) = get_transaction(&network, &mut client, &op.txid).await?;
+                let b = tx
                     .transparent_bundle()
-                    .ok_or(anyhow::anyhow!("No T bundle"))?;
-                let txout = &b.vout[op.vout as usize];
+                    .ok_or(LwdError::Decode("No transparent bundle".to_string()))?;
+                let txout = b
+                    .vout
+                    .get(op.vout as usize)
+                    .ok_or(LwdError::NotFound(format!(
+                        "txout {}:{}",
+                        hex::encode(op.txid),
+                        op.vout
+                    )))?;
                 let txout = TxOut2 {
                     address: txout.recipient_address().map(|o| o.encode(&network)),
                     value: txout.value.into(),
@@ -251,20 +442,77 @@ pub fn get_txin_coins(coin: &CoinDef, network: Network, ops: Vec<OutPoint>) -> R
     })
 }
 
+/// Compact transactions of the mempool, except the ones that match
+/// one of the `exclude` txid suffixes (see `warp::mempool::KnownTxids`)
+pub async fn get_mempool_txs(
+    client: &mut Client,
+    exclude: Vec<Vec<u8>>,
+) -> LwdResult<Vec<CompactTx>> {
+    timed("get_mempool_tx", async {
+        let mut txs = client
+            .get_mempool_tx(Request::new(Exclude { txid: exclude }))
+            .await?
+            .into_inner();
+        let mut res = vec![];
+        while let Some(tx) = txs.message().await? {
+            res.push(tx);
+        }
+        Ok(res)
+    })
+    .await
+}
+
+/// Roots of the completed subtrees (2^16 leaves) of the note
+/// commitment tree, from `start_index`. `max_entries` 0 is unlimited
+pub async fn get_subtree_roots(
+    client: &mut Client,
+    orchard: bool,
+    start_index: u32,
+    max_entries: u32,
+) -> LwdResult<Vec<SubtreeRoot>> {
+    let protocol = if orchard {
+        ShieldedProtocol::Orchard
+    } else {
+        ShieldedProtocol::Sapling
+    };
+    timed("get_subtree_roots", async {
+        let mut roots = client
+            .get_subtree_roots(Request::new(GetSubtreeRootsArg {
+                start_index,
+                shielded_protocol: protocol as i32,
+                max_entries,
+            }))
+            .await?
+            .into_inner();
+        let mut res = vec![];
+        while let Some(root) = roots.message().await? {
+            res.push(root);
+        }
+        Ok(res)
+    })
+    .await
+}
+
 pub async fn get_transaction(
     network: &Network,
     client: &mut Client,
     txid: &[u8],
-) -> Result<(u32, Transaction)> {
-    let tx = client
-        .get_transaction(Request::new(TxFilter {
-            block: None,
-            index: 0,
-            hash: txid.to_vec(),
-        }))
-        .await
-        .with_file_line(|| format!("txid {}", hex::encode(txid)))?
-        .into_inner();
+) -> LwdResult<(u32, Transaction)> {
+    let tx = timed("get_transaction", async {
+        Ok(client
+            .get_transaction(Request::new(TxFilter {
+                block: None,
+                index: 0,
+                hash: txid.to_vec(),
+            }))
+            .await
+            .map_err(|status| match status.code() {
+                Code::NotFound => LwdError::NotFound(format!("txid {}", hex::encode(txid))),
+                _ => LwdError::from(status),
+            })?
+            .into_inner())
+    })
+    .await?;
     let height = tx.height as u32;
     let tx = Transaction::read(
         &*tx.data,
@@ -279,33 +527,51 @@ pub async fn get_utxos(
     external: u32,
     addr_index: u32,
     address: &str,
-) -> Result<Vec<UTXO>> {
-    let mut utxos = vec![];
-    let mut utxo_reps = client
-        .get_address_utxos_stream(Request::new(GetAddressUtxosArg {
-            addresses: vec![address.to_string()],
-            start_height: 1,
-            max_entries: u32::MAX,
-        }))
-        .await?
-        .into_inner();
-    while let Some(utxo) = utxo_reps.message().await? {
-        let utxo = UTXO {
-            is_new: true,
-            id: 0,
-            account,
-            external,
-            addr_index,
-            height: utxo.height as u32,
-            timestamp: 0, // no need to retrieve block timestamp for a sweep
-            txid: utxo.txid.try_into().unwrap(),
-            vout: utxo.index as u32,
-            address: utxo.address,
-            value: utxo.value_zat as u64,
-        };
-        utxos.push(utxo);
-    }
-    Ok(utxos)
+) -> LwdResult<Vec<UTXO>> {
+    timed("get_address_utxos", async {
+        let mut utxos = vec![];
+        let mut utxo_reps = client
+            .get_address_utxos_stream(Request::new(GetAddressUtxosArg {
+                addresses: vec![address.to_string()],
+                start_height: 1,
+                max_entries: u32::MAX,
+            }))
+            .await?
+            .into_inner();
+        while let Some(utxo) = utxo_reps.message().await? {
+            // the keys of the account only sign P2PKH inputs
+            let script_type = ScriptType::classify(&utxo.script);
+            if !matches!(script_type, ScriptType::PubKeyHash(_)) {
+                tracing::warn!(
+                    "Refusing UTXO {}:{} of {}, its script is {}",
+                    hex::encode(&utxo.txid),
+                    utxo.index,
+                    utxo.address,
+                    script_type.name()
+                );
+                continue;
+            }
+            let utxo = UTXO {
+                is_new: true,
+                id: 0,
+                account,
+                external,
+                addr_index,
+                height: utxo.height as u32,
+                timestamp: 0, // no need to retrieve block timestamp for a sweep
+                txid: utxo
+                    .txid
+                    .try_into()
+                    .map_err(|_| LwdError::Decode("Invalid txid".to_string()))?,
+                vout: utxo.index as u32,
+                address: utxo.address,
+                value: utxo.value_zat as u64,
+            };
+            utxos.push(utxo);
+        }
+        Ok(utxos)
+    })
+    .await
 }
 
 #[c_export]
@@ -316,3 +582,33 @@ pub async fn ping(#[allow(unused_variables)] network: &Network, lwd_url: &str) -
     let elapsed = Instant::now().duration_since(start);
     Ok(elapsed.as_millis() as u64)
 }
+
+const SERVER_TEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Test every server of the config and update their scores
+/// (see `db::servers`)
+#[c_export]
+pub async fn test_servers(coin: &CoinDef, connection: &Connection) -> Result<Vec<ServerT>> {
+    let urls = coin.config.servers.clone().unwrap_or_default();
+    for url in urls.iter() {
+        let start = Instant::now();
+        let res = tokio::time::timeout(SERVER_TEST_TIMEOUT, async {
+            let mut client = connect_lwd(url).await?;
+            get_last_height(&mut client).await
+        })
+        .await;
+        let result = match res {
+            Ok(Ok(height)) => Some((start.elapsed().as_millis() as u32, height)),
+            Ok(Err(e)) => {
+                tracing::info!("{url}: {e}");
+                None
+            }
+            Err(_) => {
+                tracing::info!("{url}: timeout");
+                None
+            }
+        };
+        record_server_test(connection, url, result)?;
+    }
+    list_servers(coin, connection)
+}
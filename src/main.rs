@@ -1,9 +1,19 @@
-use zcash_warp::{cli::init_config, cli_main, utils::init_tracing};
+use clap::Parser as _;
+use zcash_warp::{
+    cli::{init_config, Args},
+    cli_main, cli_script,
+    utils::init_tracing,
+};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     init_tracing();
+    let args = Args::parse();
     let config = init_config();
+    if args.script.is_some() || !args.command.is_empty() {
+        let code = cli_script(&config, &args)?;
+        std::process::exit(code);
+    }
     cli_main(&config)?;
     Ok(())
 }
@@ -0,0 +1,68 @@
+use std::{sync::Arc, time::Duration};
+
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+
+#[cfg(feature = "prometheus")]
+pub mod prometheus;
+
+/*
+    Telemetry hooks
+
+    The embedder can install a `Metrics` implementation to feed its
+    own telemetry. It is opt-in: the default does nothing.
+
+    The hooks only report aggregate, privacy-preserving values:
+    durations, block counts and error categories. They never get
+    an address, an amount, a txid or an account id, so a telemetry
+    backend learns nothing about the wallet contents.
+
+    With the `prometheus` feature, `prometheus::PrometheusMetrics`
+    keeps the counters and serves them in the Prometheus text format
+    for server deployments.
+*/
+
+/// Category of a sync error
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ErrorKind {
+    Reorg,
+    Lwd,
+    Other,
+}
+
+impl ErrorKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorKind::Reorg => "reorg",
+            ErrorKind::Lwd => "lwd",
+            ErrorKind::Other => "other",
+        }
+    }
+}
+
+pub trait Metrics: Send + Sync {
+    /// A sync run committed `blocks` blocks in `duration`
+    fn on_sync(&self, _blocks: u32, _duration: Duration) {}
+    /// A batch of `blocks` blocks went through trial decryption
+    fn on_blocks(&self, _blocks: u32) {}
+    /// A sync run failed
+    fn on_error(&self, _kind: ErrorKind) {}
+}
+
+/// The default, which does nothing
+pub struct NoMetrics;
+
+impl Metrics for NoMetrics {}
+
+lazy_static! {
+    static ref METRICS: RwLock<Arc<dyn Metrics>> = RwLock::new(Arc::new(NoMetrics));
+}
+
+/// Install the metrics of the embedder
+pub fn set_metrics(metrics: Arc<dyn Metrics>) {
+    *METRICS.write() = metrics;
+}
+
+pub fn metrics() -> Arc<dyn Metrics> {
+    METRICS.read().clone()
+}
@@ -0,0 +1,148 @@
+use std::{
+    fmt::Write as _,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use anyhow::Result;
+use lazy_static::lazy_static;
+use tokio::{
+    io::{AsyncReadExt as _, AsyncWriteExt as _},
+    net::TcpListener,
+};
+
+use crate::coin::CoinDef;
+
+use super::{set_metrics, ErrorKind, Metrics};
+use warp_macros::c_export;
+
+/// Counters in the Prometheus text exposition format
+#[derive(Default)]
+pub struct PrometheusMetrics {
+    syncs: AtomicU64,
+    sync_millis: AtomicU64,
+    synced_blocks: AtomicU64,
+    processed_blocks: AtomicU64,
+    reorg_errors: AtomicU64,
+    lwd_errors: AtomicU64,
+    other_errors: AtomicU64,
+}
+
+impl Metrics for PrometheusMetrics {
+    fn on_sync(&self, blocks: u32, duration: Duration) {
+        self.syncs.fetch_add(1, Ordering::Relaxed);
+        self.sync_millis.fetch_add(duration.as_millis() as u64, Ordering::Relaxed);
+        self.synced_blocks.fetch_add(blocks as u64, Ordering::Relaxed);
+    }
+
+    fn on_blocks(&self, blocks: u32) {
+        self.processed_blocks.fetch_add(blocks as u64, Ordering::Relaxed);
+    }
+
+    fn on_error(&self, kind: ErrorKind) {
+        let counter = match kind {
+            ErrorKind::Reorg => &self.reorg_errors,
+            ErrorKind::Lwd => &self.lwd_errors,
+            ErrorKind::Other => &self.other_errors,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+impl PrometheusMetrics {
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let counters = [
+            ("warp_syncs_total", "Completed sync runs", &self.syncs),
+            (
+                "warp_sync_duration_milliseconds_total",
+                "Time spent in completed sync runs",
+                &self.sync_millis,
+            ),
+            (
+                "warp_synced_blocks_total",
+                "Blocks committed by completed sync runs",
+                &self.synced_blocks,
+            ),
+            (
+                "warp_processed_blocks_total",
+                "Blocks processed by trial decryption",
+                &self.processed_blocks,
+            ),
+        ];
+        for (name, help, counter) in counters {
+            let _ = writeln!(out, "# HELP {name} {help}");
+            let _ = writeln!(out, "# TYPE {name} counter");
+            let _ = writeln!(out, "{name} {}", counter.load(Ordering::Relaxed));
+        }
+        let _ = writeln!(out, "# HELP warp_sync_errors_total Failed sync runs");
+        let _ = writeln!(out, "# TYPE warp_sync_errors_total counter");
+        for (kind, counter) in [
+            (ErrorKind::Reorg, &self.reorg_errors),
+            (ErrorKind::Lwd, &self.lwd_errors),
+            (ErrorKind::Other, &self.other_errors),
+        ] {
+            let _ = writeln!(
+                out,
+                "warp_sync_errors_total{{kind=\"{}\"}} {}",
+                kind.as_str(),
+                counter.load(Ordering::Relaxed)
+            );
+        }
+        out
+    }
+}
+
+lazy_static! {
+    static ref PROMETHEUS: Arc<PrometheusMetrics> = Arc::new(PrometheusMetrics::default());
+}
+
+/// Install the Prometheus metrics and, if `port` is not 0,
+/// serve them over HTTP on that port
+#[c_export]
+pub fn start_prometheus_metrics(coin: &CoinDef, port: u32) -> Result<()> {
+    set_metrics(PROMETHEUS.clone());
+    if port != 0 {
+        let port = u16::try_from(port).map_err(|_| anyhow::anyhow!("Invalid port {}", port))?;
+        let runtime = coin.runtime.0.as_ref().unwrap();
+        runtime.spawn(async move {
+            if let Err(e) = serve(port).await {
+                tracing::warn!("Metrics server: {}", e);
+            }
+        });
+    }
+    Ok(())
+}
+
+/// Current metrics in the Prometheus text format
+#[c_export]
+pub fn get_prometheus_metrics() -> Result<String> {
+    Ok(PROMETHEUS.render())
+}
+
+async fn serve(port: u16) -> Result<()> {
+    let listener = TcpListener::bind(("0.0.0.0", port)).await?;
+    tracing::info!("Serving metrics on port {}", port);
+    loop {
+        let (mut socket, _) = listener.accept().await?;
+        tokio::spawn(async move {
+            // every request gets the metrics, whatever its path
+            let mut buf = [0u8; 1024];
+            let _ = socket.read(&mut buf).await?;
+            let body = PROMETHEUS.render();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\n\
+                Content-Type: text/plain; version=0.0.4\r\n\
+                Content-Length: {}\r\n\
+                Connection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            socket.write_all(response.as_bytes()).await?;
+            Ok::<_, std::io::Error>(())
+        });
+    }
+}
@@ -4,6 +4,8 @@ use zcash_protocol::{
     local_consensus::LocalNetwork,
 };
 
+use crate::Hash;
+
 lazy_static! {
     pub static ref REGTEST: Network = Network::Regtest(_regtest());
 }
@@ -33,6 +35,46 @@ impl Parameters for Network {
     }
 }
 
+/// Block hashes hard-coded in the wallet, in display (reversed) order.
+/// The header chain must go through them
+const MAIN_HARD_CHECKPOINTS: &[(u32, &str)] = &[
+    // Sapling activation
+    (
+        419_200,
+        "00000000025a57200d898ac7f21e26bf29028bbe96ec46e05b2c17cc9db9e4f3",
+    ),
+    // NU5 activation
+    (
+        1_687_104,
+        "0000000000d723156d9b65ffcf4984da7a19675ed7e2f06d9e5d5188af087bf8",
+    ),
+];
+
+impl Network {
+    /// Hash of the hard checkpoint at `height`, if there is one
+    pub fn hard_checkpoint(&self, height: u32) -> Option<Hash> {
+        self.hard_checkpoints()
+            .into_iter()
+            .find(|(h, _)| *h == height)
+            .map(|(_, hash)| hash)
+    }
+
+    pub fn hard_checkpoints(&self) -> Vec<(u32, Hash)> {
+        let checkpoints = match self {
+            Network::Main => MAIN_HARD_CHECKPOINTS,
+            Network::Regtest(_) => &[],
+        };
+        checkpoints
+            .iter()
+            .map(|(height, hash)| {
+                let mut hash: Hash = hex::decode(hash).unwrap().try_into().unwrap();
+                hash.reverse();
+                (*height, hash)
+            })
+            .collect()
+    }
+}
+
 pub fn _regtest() -> LocalNetwork {
     LocalNetwork {
         overwinter: Some(BlockHeight::from_u32(1)),
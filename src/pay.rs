@@ -1,20 +1,28 @@
 use fee::FeeManager;
 use fpdec::Decimal;
-use orchard::circuit::ProvingKey;
+use orchard::{circuit::ProvingKey, keys::Scope};
 use parking_lot::Mutex;
-use rusqlite::Connection;
+use rusqlite::{params, Connection};
+use sapling_crypto::PaymentAddress;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use zcash_client_backend::encoding::AddressCodec as _;
 use zcash_keys::address::Address as RecipientAddress;
-use zcash_primitives::memo::MemoBytes;
+use zcash_primitives::{legacy::TransparentAddress, memo::MemoBytes};
 use zcash_proofs::prover::LocalTxProver;
 
 use self::conv::MemoBytesProxy;
 use crate::{
+    account::address::change_address_account,
     data::fb::{PaymentRequestT, RecipientT, TransactionRecipientT, TransactionSummaryT},
+    db::{
+        account::{get_account_info, resolve_change_outputs},
+        account_manager::get_account_by_name,
+    },
     fb_unwrap,
     network::Network,
     types::{AccountInfo, CheckpointHeight, PoolMask},
+    utils::pay::PaymentOptions,
     warp::{legacy::CommitmentTreeFrontier, AuthPath, Edge, Witness, UTXO},
     Hash,
 };
@@ -24,8 +32,13 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub mod builder;
 pub mod conv;
 mod fee;
+pub mod frost;
 pub mod prepare;
+pub mod prover;
+pub mod render;
+pub mod review;
 pub mod sweep;
+pub mod tex;
 
 #[derive(Error, Debug)]
 pub enum Error {
@@ -39,6 +52,28 @@ pub enum Error {
     NoChangeOutput,
     #[error("No Funds available. Some funds may not have enough confirmations yet.")]
     NoFunds,
+    #[error("Not Enough Funds at {0}, {1} needed, {2} available")]
+    NotEnoughFundsAtAddress(String, Decimal, Decimal),
+    #[error("Transaction too large, {0} bytes, max {1}. Split it into {2} payments")]
+    TxTooLarge(u32, u32, u32),
+    #[error("Too many orchard actions, {0}, max {1}")]
+    TooManyActions(u32, u32),
+    #[error("Fees paid by the recipient cannot be sponsored")]
+    SponsoredRecipientFees,
+    #[error("Address {0} cannot receive a memo without value, it has no shielded receiver")]
+    MemoOnlyTransparent(String),
+    #[error("Address {0} looks like {1}. It may be an address poisoning attempt")]
+    LookalikeAddress(String, String),
+    #[error("Address {0} expires at height {1}, before the transaction")]
+    AddressExpiredHeight(String, u32),
+    #[error("Address {0} expired at time {1}")]
+    AddressExpiredTime(String, u64),
+    #[error("Address {0} is a change address of account {1}")]
+    ChangeAddress(String, u32),
+    #[error("Fee {0} is more than the maximum fee {1}")]
+    FeeAboveMaximum(u64, u64),
+    #[error("Fee {0} is more than {1}% of the amount sent {2}")]
+    FeeAbovePercent(u64, u32, u64),
     #[error(transparent)]
     Other(#[from] anyhow::Error),
 }
@@ -50,6 +85,13 @@ pub struct ExtendedRecipient {
     pub remaining: u64,
     pub pool_mask: PoolMask,
     pub is_change: bool,
+    /// Zero value output to self that hides the number of real outputs
+    pub decoy: bool,
+    /// Zero value output that only carries a memo. It pays
+    /// for its share of the fee from the inputs
+    pub memo_only: bool,
+    /// Pools of the inputs that can fund this output
+    pub src_pools: PoolMask,
 }
 
 impl ExtendedRecipient {
@@ -73,12 +115,27 @@ impl ExtendedRecipient {
         };
         let pools = pools & recipient.pools;
         let pools = if pools != 1 { pools & 6 } else { pools }; // remove T
+        let src_pools = match recipient.src_pools {
+            0 => PoolMask(7),
+            p => PoolMask(p & 7),
+        };
+        // a memo needs a shielded output, which can have a zero value
+        let memo_only =
+            recipient.amount == 0 && (recipient.memo.is_some() || recipient.memo_bytes.is_some());
+        if memo_only && pools & 6 == 0 {
+            return Err(Error::MemoOnlyTransparent(
+                recipient.address.clone().unwrap_or_default(),
+            ));
+        }
         Ok(ExtendedRecipient {
             amount: recipient.amount,
             remaining: recipient.amount,
             recipient,
             pool_mask: PoolMask(pools),
             is_change: false,
+            decoy: false,
+            memo_only,
+            src_pools,
         })
     }
 }
@@ -143,6 +200,9 @@ pub struct TxOutput {
     pub amount: u64,
     pub note: OutputNote,
     pub is_change: bool,
+    /// Decoys are built even when they have no value
+    #[serde(default)]
+    pub decoy: bool,
 }
 
 pub struct PaymentBuilder {
@@ -154,6 +214,8 @@ pub struct PaymentBuilder {
     pub outputs: Vec<ExtendedRecipient>,
     pub account_pools: PoolMask,
     pub src_pools: PoolMask,
+    pub from_address: Option<String>,
+    pub sponsor: Option<u32>,
 
     pub fee_manager: FeeManager,
     pub fee: u64,
@@ -162,6 +224,12 @@ pub struct PaymentBuilder {
     pub used: [bool; 3],
     pub use_change: bool,
     pub use_unique_change: bool,
+    pub decoy_outputs: u8,
+    pub max_decoy_fee: u64,
+    pub decoy_fee: u64,
+    pub change_outputs: u8,
+    pub max_change_fee: u64,
+    pub extra_change_outputs: usize,
 
     pub s_edge: Edge,
     pub o_edge: Edge,
@@ -186,7 +254,9 @@ pub struct UnsignedTransaction {
     pub roots: [Hash; 2],
     pub edges: [AuthPath; 2],
     pub fees: FeeManager,
+    pub decoy_fee: u64,
     pub message: Option<String>,
+    pub sponsor: Option<u32>,
 }
 
 impl UnsignedTransaction {
@@ -198,7 +268,8 @@ impl UnsignedTransaction {
                 Some(TransactionRecipientT {
                     address: Some(o.address_string.clone()),
                     amount: o.amount,
-                    change: o.is_change,
+                    change: o.is_change || o.decoy,
+                    memo_only: !o.is_change && !o.decoy && o.amount == 0,
                 })
             })
             .collect::<Vec<_>>();
@@ -245,10 +316,102 @@ impl UnsignedTransaction {
             privacy_level,
             data: Some(data),
             message: self.message.clone(),
+            decoy_fee: self.decoy_fee,
+            orchard_actions: self.fees.orchard_actions(),
+            tx_size: self.fees.tx_size(),
+            request_status: 0,
+            requester: None,
+            requested_height: self.height,
+            snap_policy: 0,
         })
     }
 }
 
+impl UnsignedTransaction {
+    /// Accounts whose keys sign the transaction, as `prepare_signing`
+    /// finds them: the account of `account_name`, then the sponsor
+    pub fn signing_accounts(&self, connection: &Connection) -> Result<Vec<u32>> {
+        let account = get_account_by_name(connection, &self.account_name)?
+            .ok_or(anyhow::anyhow!("Account not in wallet"))?;
+        let mut accounts = vec![account];
+        accounts.extend(self.sponsor.filter(|&s| s != account));
+        Ok(accounts)
+    }
+
+    /// Amount of the outputs that do not go to one of `accounts`,
+    /// whatever their `is_change` flag says
+    pub fn amount_sent(
+        &self,
+        network: &Network,
+        connection: &Connection,
+        accounts: &[u32],
+    ) -> Result<u64> {
+        let ais = accounts
+            .iter()
+            .map(|&a| get_account_info(network, connection, a))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let mut amount = 0;
+        for o in self.tx_outputs.iter() {
+            let mut own = false;
+            for ai in ais.iter() {
+                if is_account_output(network, connection, ai, &o.note)? {
+                    own = true;
+                    break;
+                }
+            }
+            if !own {
+                amount += o.amount;
+            }
+        }
+        Ok(amount)
+    }
+}
+
+/// True if the output goes to an address of the account,
+/// external or change
+fn is_account_output(
+    network: &Network,
+    connection: &Connection,
+    ai: &AccountInfo,
+    note: &OutputNote,
+) -> Result<bool> {
+    let own = match note {
+        OutputNote::Transparent { pkh, address } => {
+            let taddr = if *pkh {
+                TransparentAddress::PublicKeyHash(*address)
+            } else {
+                TransparentAddress::ScriptHash(*address)
+            };
+            if ai.transparent.as_ref().is_some_and(|ti| ti.addr == taddr) {
+                return Ok(true);
+            }
+            let taddr = taddr.encode(network);
+            let known = connection
+                .prepare("SELECT 1 FROM t_addresses WHERE account = ?1 AND address = ?2")
+                .and_then(|mut s| s.exists(params![ai.account, &taddr]))
+                .map_err(anyhow::Error::from)?;
+            known || change_address_account(network, connection, &taddr)? == Some(ai.account)
+        }
+        OutputNote::Sapling { address, .. } => {
+            let pa = PaymentAddress::from_bytes(address);
+            match (ai.sapling.as_ref(), pa) {
+                (Some(si), Some(pa)) => si.vk.decrypt_diversifier(&pa).is_some(),
+                _ => false,
+            }
+        }
+        OutputNote::Orchard { address, .. } => {
+            let addr = orchard::Address::from_raw_address_bytes(address);
+            match (ai.orchard.as_ref(), Option::<orchard::Address>::from(addr)) {
+                (Some(oi), Some(addr)) => [Scope::External, Scope::Internal]
+                    .iter()
+                    .any(|&scope| oi.vk.to_ivk(scope).diversifier_index(&addr).is_some()),
+                _ => false,
+            }
+        }
+    };
+    Ok(own)
+}
+
 impl TransactionSummaryT {
     pub fn detach(&mut self) -> Vec<u8> {
         let data = self.data.take();
@@ -293,7 +456,7 @@ pub fn make_payment(
     payment: &PaymentRequestT,
     s_tree: &CommitmentTreeFrontier,
     o_tree: &CommitmentTreeFrontier,
-    redirect: Option<String>,
+    options: &PaymentOptions,
 ) -> Result<UnsignedTransaction> {
     let mut pb = PaymentBuilder::new(
         network,
@@ -305,13 +468,103 @@ pub fn make_payment(
         s_tree,
         o_tree,
     )?;
+    pb.set_from_address(payment.from_address.clone())?;
     pb.add_account_funds(&connection)?;
     pb.set_use_change(payment.use_change)?;
+    pb.set_decoy_outputs(payment.decoy_outputs, options.max_decoy_fee)?;
+    let change_outputs = resolve_change_outputs(connection, account, payment.change_outputs)?;
+    pb.set_change_outputs(change_outputs, options.max_change_fee)?;
     let mut utx = pb.prepare()?;
     if !payment.sender_pay_fees {
-        let fee = pb.fee_manager.fee();
+        // the sender still pays for its decoys
+        let fee = pb.fee_manager.fee() - pb.decoy_fee;
         utx.add_to_change(fee as i64)?;
     }
-    let utx = pb.finalize(utx, redirect)?;
+    let utx = pb.finalize(utx, options.redirect.clone())?;
     Ok(utx)
 }
+
+/// Payment from `account` whose fee is paid by `sponsor`.
+/// A single transaction if a UTXO of the sponsor covers the fee,
+/// otherwise the payment and a reimbursement of its fee by the sponsor
+#[allow(clippy::too_many_arguments)]
+pub fn make_sponsored_payment(
+    network: &Network,
+    connection: &Connection,
+    account: u32,
+    sponsor: u32,
+    payment: &PaymentRequestT,
+    s_tree: &CommitmentTreeFrontier,
+    o_tree: &CommitmentTreeFrontier,
+    options: &PaymentOptions,
+) -> Result<Vec<UnsignedTransaction>> {
+    if sponsor == account {
+        return Err(anyhow::anyhow!("The sponsor must be another account").into());
+    }
+    if !payment.sender_pay_fees {
+        return Err(Error::SponsoredRecipientFees);
+    }
+    let mut pb = PaymentBuilder::new(
+        network,
+        connection,
+        account,
+        CheckpointHeight(payment.height),
+        fb_unwrap!(payment.recipients),
+        PoolMask(payment.src_pools),
+        s_tree,
+        o_tree,
+    )?;
+    pb.set_from_address(payment.from_address.clone())?;
+    pb.add_account_funds(connection)?;
+    pb.set_use_change(true)?;
+    pb.set_decoy_outputs(payment.decoy_outputs, options.max_decoy_fee)?;
+    let change_outputs = resolve_change_outputs(connection, account, payment.change_outputs)?;
+    pb.set_change_outputs(change_outputs, options.max_change_fee)?;
+    let mut utx = pb.prepare()?;
+    if pb.sponsor_fee(connection, sponsor, &mut utx)? {
+        let utx = pb.finalize(utx, options.redirect.clone())?;
+        return Ok(vec![utx]);
+    }
+
+    let fee = pb.fee_manager.fee();
+    let utx = pb.finalize(utx, options.redirect.clone())?;
+    let ai = get_account_info(network, connection, account)?;
+    let address = ai
+        .to_address(network, ai.pools())
+        .ok_or(anyhow::anyhow!("Account {} has no address", account))?;
+    let reimbursement = PaymentRequestT {
+        recipients: Some(vec![RecipientT {
+            address: Some(address),
+            amount: fee,
+            pools: 7,
+            memo: None,
+            memo_bytes: None,
+            src_pools: 0,
+        }]),
+        src_pools: 7,
+        sender_pay_fees: true,
+        use_change: true,
+        height: payment.height,
+        expiration: payment.expiration,
+        decoy_outputs: 0,
+        from_address: None,
+        allow_high_fee: false,
+        change_outputs: 0,
+        allow_change_address: false,
+        allow_lookalike_address: false,
+    };
+    let reimbursement_utx = make_payment(
+        network,
+        connection,
+        sponsor,
+        &reimbursement,
+        s_tree,
+        o_tree,
+        // the reimbursement goes back to the account
+        &PaymentOptions {
+            redirect: None,
+            ..options.clone()
+        },
+    )?;
+    Ok(vec![utx, reimbursement_utx])
+}
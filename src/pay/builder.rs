@@ -1,7 +1,7 @@
 use std::{collections::HashMap, path::Path};
 
 use crate::{
-    data::fb::{IdNoteT, TransactionBytesT},
+    data::fb::{IdNoteT, ProofProgressT, TransactionBytesT},
     db::{account::get_account_info, account_manager::get_account_by_name},
     keys::sk_to_address,
     types::TransparentAccountInfo,
@@ -14,15 +14,23 @@ use anyhow::Result;
 use sapling_crypto::{note_encryption::Zip212Enforcement, PaymentAddress};
 use secp256k1::SecretKey;
 use zcash_client_backend::encoding::AddressCodec as _;
-use zcash_protocol::value::Zatoshis;
+use zcash_protocol::value::{ZatBalance, Zatoshis};
 
-use super::{InputNote, OutputNote, UnsignedTransaction, ORCHARD_PROVER, PROVER};
+use super::{
+    prover::{
+        create_sapling_proofs, get_sapling_proofs, report_progress, sapling_proof_key,
+        store_sapling_proofs, with_proof_pool,
+    },
+    InputNote, OutputNote, TxOutput, UnsignedTransaction, ORCHARD_PROVER, PROVER,
+};
+use group::ff::PrimeField as _;
 use jubjub::Fr;
 use orchard::{
-    builder::{Builder as OrchardBuilder, BundleType},
+    builder::{Builder as OrchardBuilder, BundleType, InProgress, MaybeSigned, PartiallyAuthorized},
     bundle::Flags,
     keys::{Scope, SpendAuthorizingKey},
     note::Rho,
+    primitives::redpallas::{self, SpendAuth},
     tree::MerkleHashOrchard,
     Address,
 };
@@ -32,7 +40,10 @@ use zcash_primitives::{
     consensus::{BlockHeight, BranchId},
     legacy::TransparentAddress,
     transaction::{
-        components::{transparent::builder::TransparentBuilder, OutPoint, TxOut},
+        components::{
+            transparent::{self, builder::TransparentBuilder},
+            OutPoint, TxOut,
+        },
         sighash::{signature_hash, SignableInput},
         txid::TxIdDigester,
         TransactionData, TxVersion,
@@ -43,23 +54,101 @@ use zcash_proofs::prover::LocalTxProver;
 use crate::network::Network;
 use warp_macros::c_export;
 
-const DUST: u64 = 54;
+pub(crate) const DUST: u64 = 54;
 
 impl UnsignedTransaction {
-    pub fn build<R: RngCore + CryptoRng>(
+    /// Outputs that go into the transaction. A change below the dust
+    /// limit is left to the fee, but decoys are kept whatever their value
+    pub fn built_outputs(&self) -> impl Iterator<Item = &TxOutput> {
+        self.tx_outputs
+            .iter()
+            .filter(|o| o.decoy || !(o.is_change && o.amount < DUST))
+    }
+
+    pub fn build<R: RngCore + CryptoRng + Send>(
+        &self,
+        network: &Network,
+        connection: &Connection,
+        expiration_height: u32,
+        rng: R,
+    ) -> Result<TransactionBytesT> {
+        self.build_with_progress(network, connection, expiration_height, rng, |_| {})
+    }
+
+    /// Build the transaction off the async executor, calling `progress`
+    /// after each proof
+    pub async fn build_async<R: RngCore + CryptoRng + Send, F: FnMut(ProofProgressT) + Send>(
+        &self,
+        network: &Network,
+        connection: &Connection,
+        expiration_height: u32,
+        rng: R,
+        progress: F,
+    ) -> Result<TransactionBytesT> {
+        tokio::task::block_in_place(|| {
+            self.build_with_progress(network, connection, expiration_height, rng, progress)
+        })
+    }
+
+    pub fn build_with_progress<
+        R: RngCore + CryptoRng + Send,
+        F: FnMut(ProofProgressT) + Send,
+    >(
         &self,
         network: &Network,
         connection: &Connection,
         expiration_height: u32,
         mut rng: R,
+        progress: F,
     ) -> Result<TransactionBytesT> {
+        let mut ptx =
+            self.prepare_signing(network, connection, expiration_height, &mut rng, progress)?;
+        let ai = get_account_info(network, connection, ptx.account)?;
+        if let Some(sk) = ai.orchard.as_ref().and_then(|oi| oi.sk) {
+            ptx.sign_orchard(&mut rng, &SpendAuthorizingKey::from(&sk));
+        }
+        ptx.finalize()
+    }
+
+    /// Create the proofs and sign everything but the Orchard spends
+    pub fn prepare_signing<R: RngCore + CryptoRng + Send, F: FnMut(ProofProgressT) + Send>(
+        &self,
+        network: &Network,
+        connection: &Connection,
+        expiration_height: u32,
+        mut rng: R,
+        mut progress: F,
+    ) -> Result<PreparedTransaction> {
         let account = get_account_by_name(connection, &self.account_name)?;
         let account = account.ok_or(anyhow::anyhow!("Account not in wallet"))?;
 
+        // A transaction is only valid for the consensus branch it was built for
+        let branch_id = BranchId::for_height(network, BlockHeight::from_u32(self.height));
+        let expiry_branch_id =
+            BranchId::for_height(network, BlockHeight::from_u32(expiration_height));
+        if branch_id != expiry_branch_id {
+            anyhow::bail!(
+                "Transaction would straddle a network upgrade ({:08x} at height {}, {:08x} at expiry {}). \
+                Prepare it again after the upgrade activates",
+                u32::from(branch_id),
+                self.height,
+                u32::from(expiry_branch_id),
+                expiration_height
+            );
+        }
+
         let ai = get_account_info(network, connection, account)?;
+        // the UTXO of a sponsor pays the fee
+        let sponsor_ai = match self.sponsor {
+            Some(sponsor) => Some(get_account_info(network, connection, sponsor)?),
+            None => None,
+        };
 
         let mut tsk_store: HashMap<String, SecretKey> = HashMap::new();
-        if let Some(ti) = ai.transparent.as_ref() {
+        let tis = std::iter::once(&ai)
+            .chain(sponsor_ai.iter())
+            .filter_map(|ai| ai.transparent.as_ref());
+        for ti in tis {
             for txin in self.tx_notes.iter() {
                 match &txin.note {
                     // derive the transparent keys
@@ -203,10 +292,7 @@ impl UnsignedTransaction {
             }
         }
 
-        for txout in self.tx_outputs.iter() {
-            if txout.is_change && txout.amount < DUST {
-                continue;
-            }
+        for txout in self.built_outputs() {
             match &txout.note {
                 OutputNote::Transparent { pkh, address } => {
                     let taddr = if *pkh {
@@ -248,16 +334,30 @@ impl UnsignedTransaction {
         }
 
         let transparent_bundle = transparent_builder.build();
-        let prover = PROVER.lock();
-        let prover = prover
-            .as_ref()
-            .ok_or(anyhow::anyhow!("Sapling prover not initialized"))?;
-        let sapling_bundle = sapling_builder
-            .build::<LocalTxProver, LocalTxProver, _, _>(&mut rng)
-            .unwrap()
-            .map(|pair| pair.0);
-        let sapling_bundle =
-            sapling_bundle.map(|sb| sb.create_proofs(prover, prover, &mut rng, ()));
+        let sapling_key = sapling_proof_key(self);
+        let sapling_bundle = match sapling_key.as_ref().and_then(get_sapling_proofs) {
+            Some(sb) => {
+                tracing::info!("Reusing the Sapling proofs");
+                Some(sb)
+            }
+            None => {
+                let prover = PROVER.lock();
+                let prover = prover
+                    .as_ref()
+                    .ok_or(anyhow::anyhow!("Sapling prover not initialized"))?;
+                let sapling_bundle = sapling_builder
+                    .build::<LocalTxProver, LocalTxProver, _, _>(&mut rng)
+                    .unwrap()
+                    .map(|pair| pair.0);
+                let sapling_bundle = sapling_bundle
+                    .map(|sb| create_sapling_proofs(prover, sb, &mut rng, &mut progress))
+                    .transpose()?;
+                if let (Some(key), Some(sb)) = (sapling_key, sapling_bundle.as_ref()) {
+                    store_sapling_proofs(key, sb);
+                }
+                sapling_bundle
+            }
+        };
 
         let has_orchard = self.tx_notes.iter().any(|n| match n.note {
             InputNote::Orchard { .. } => true,
@@ -272,7 +372,7 @@ impl UnsignedTransaction {
             orchard_bundle = orchard_builder.build(&mut rng).unwrap().map(|pair| pair.0);
         }
 
-        let consensus_branch_id = BranchId::for_height(network, BlockHeight::from_u32(self.height));
+        let consensus_branch_id = branch_id;
         let version = TxVersion::suggested_for_branch(consensus_branch_id);
 
         let unauthed_tx: TransactionData<zcash_primitives::transaction::Unauthorized> =
@@ -310,22 +410,106 @@ impl UnsignedTransaction {
         });
 
         let orchard_bundle = unauthed_tx.orchard_bundle().map(|ob| {
-            let sk = ai.orchard.as_ref().and_then(|oi| oi.sk);
-            let sak = sk.map(|sk| SpendAuthorizingKey::from(&sk));
-            let sak = [sak].into_iter().flatten().collect::<Vec<_>>();
-            let proven = ob.clone().create_proof(&ORCHARD_PROVER, &mut rng).unwrap();
-            proven.apply_signatures(&mut rng, sig_hash, &sak).unwrap()
+            let total = ob.actions().len() as u32;
+            report_progress(&mut progress, 2, 0, total);
+            let proven = with_proof_pool(|| ob.clone().create_proof(&ORCHARD_PROVER, &mut rng))
+                .unwrap();
+            report_progress(&mut progress, 2, total, total);
+            // dummy spends are signed here
+            proven.prepare(&mut rng, sig_hash)
         });
 
+        let ptx = PreparedTransaction {
+            account,
+            version,
+            consensus_branch_id,
+            expiration_height,
+            transparent_bundle,
+            sapling_bundle,
+            orchard_bundle,
+            sig_hash,
+            id_notes,
+            message: self.message.clone(),
+            tex_change: self.tex_change(network),
+        };
+        Ok(ptx)
+    }
+}
+
+/// A transaction with its proofs, waiting for
+/// the spend authorization signatures of its Orchard actions
+pub struct PreparedTransaction {
+    pub account: u32,
+    version: TxVersion,
+    consensus_branch_id: BranchId,
+    expiration_height: u32,
+    transparent_bundle: Option<transparent::Bundle<transparent::Authorized>>,
+    sapling_bundle: Option<sapling_crypto::Bundle<sapling_crypto::bundle::Authorized, ZatBalance>>,
+    orchard_bundle:
+        Option<orchard::Bundle<InProgress<orchard::Proof, PartiallyAuthorized>, ZatBalance>>,
+    pub sig_hash: [u8; 32],
+    id_notes: Vec<IdNoteT>,
+    message: Option<String>,
+    tex_change: Option<String>,
+}
+
+impl PreparedTransaction {
+    /// Randomized verification keys (rk) of the actions
+    /// that still need a spend authorization signature,
+    /// with the randomizer (alpha) of their spend authorizing key
+    pub fn unsigned_orchard_spends(&self) -> Vec<(u32, [u8; 32], [u8; 32])> {
+        let Some(ob) = self.orchard_bundle.as_ref() else {
+            return vec![];
+        };
+        ob.actions()
+            .iter()
+            .enumerate()
+            .filter_map(|(i, a)| match a.authorization() {
+                MaybeSigned::SigningMetadata(m) => Some((
+                    i as u32,
+                    <[u8; 32]>::from(a.rk()),
+                    m.parts().alpha().to_repr(),
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn sign_orchard<R: RngCore + CryptoRng>(&mut self, rng: R, sak: &SpendAuthorizingKey) {
+        self.orchard_bundle = self.orchard_bundle.take().map(|ob| ob.sign(rng, sak));
+    }
+
+    /// Add signatures made outside of the wallet.
+    /// Each signature is matched with the action whose rk validates it
+    pub fn append_orchard_signatures(&mut self, signatures: &[[u8; 64]]) -> Result<()> {
+        let signatures = signatures
+            .iter()
+            .map(|s| redpallas::Signature::<SpendAuth>::from(*s))
+            .collect::<Vec<_>>();
+        if let Some(ob) = self.orchard_bundle.take() {
+            let ob = ob
+                .append_signatures(&signatures)
+                .map_err(|e| anyhow::anyhow!("Invalid spend authorization: {:?}", e))?;
+            self.orchard_bundle = Some(ob);
+        }
+        Ok(())
+    }
+
+    pub fn finalize(self) -> Result<TransactionBytesT> {
+        let orchard_bundle = self
+            .orchard_bundle
+            .map(|ob| ob.finalize())
+            .transpose()
+            .map_err(|e| anyhow::anyhow!("Missing spend authorization: {:?}", e))?;
         let tx_data: TransactionData<zcash_primitives::transaction::Authorized> =
             TransactionData::from_parts(
-                version,
-                consensus_branch_id,
+                self.version,
+                self.consensus_branch_id,
                 0,
-                BlockHeight::from_u32(expiration_height),
-                transparent_bundle,
+                BlockHeight::from_u32(self.expiration_height),
+                self.transparent_bundle,
                 None,
-                sapling_bundle,
+                self.sapling_bundle,
                 orchard_bundle,
             );
         let tx = tx_data.freeze().unwrap();
@@ -334,9 +518,10 @@ impl UnsignedTransaction {
         tx.write(&mut tx_bytes).unwrap();
 
         let tx_bytes = TransactionBytesT {
-            notes: Some(id_notes),
+            notes: Some(self.id_notes),
             data: Some(tx_bytes),
-            message: self.message.clone(),
+            message: self.message,
+            tex_change: self.tex_change,
         };
         Ok(tx_bytes)
     }
@@ -357,3 +542,62 @@ pub fn init_sapling_prover_with_location(directory: &Path) -> Result<()> {
     *PROVER.lock() = Some(prover);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use zcash_primitives::memo::MemoBytes;
+
+    use super::{OutputNote, TxOutput, UnsignedTransaction, DUST};
+
+    fn output(pool: u8, amount: u64, is_change: bool, decoy: bool) -> TxOutput {
+        let memo = MemoBytes::empty();
+        let note = match pool {
+            1 => OutputNote::Sapling {
+                address: [0; 43],
+                memo,
+            },
+            _ => OutputNote::Orchard {
+                address: [0; 43],
+                memo,
+            },
+        };
+        TxOutput {
+            address_string: String::new(),
+            pool,
+            amount,
+            note,
+            is_change,
+            decoy,
+        }
+    }
+
+    #[test]
+    fn decoys_are_built() {
+        let utx = UnsignedTransaction {
+            account: 1,
+            account_name: String::new(),
+            height: 0,
+            tx_notes: vec![],
+            tx_outputs: vec![
+                output(2, 100_000, false, false),
+                // no share of the change: other pool
+                output(1, 0, false, true),
+                // small share of the change
+                output(2, DUST - 1, false, true),
+                output(2, 0, false, true),
+                // dust change
+                output(2, DUST - 1, true, false),
+            ],
+            roots: Default::default(),
+            edges: Default::default(),
+            fees: Default::default(),
+            decoy_fee: 0,
+            message: None,
+            sponsor: None,
+        };
+        let built = utx.built_outputs().collect::<Vec<_>>();
+        assert_eq!(built.len(), 4);
+        assert_eq!(built.iter().filter(|o| o.decoy).count(), 3);
+        assert!(built.iter().all(|o| !o.is_change));
+    }
+}
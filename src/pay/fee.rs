@@ -2,6 +2,16 @@ use serde::{Deserialize, Serialize};
 
 use crate::utils::pay::COST_PER_ACTION;
 
+// Serialized sizes of the parts of a v5 transaction
+const TX_HEADER_SIZE: u32 = 4 + 4 + 4 + 4 + 4 + 3 * 3; // + lengths of the bundles
+const TRANSPARENT_INPUT_SIZE: u32 = 32 + 4 + 1 + 107 + 4; // P2PKH
+const TRANSPARENT_OUTPUT_SIZE: u32 = 8 + 1 + 25;
+const SAPLING_SPEND_SIZE: u32 = 32 + 32 + 32 + 192 + 64; // cv, nf, rk, proof, sig
+const SAPLING_OUTPUT_SIZE: u32 = 32 + 32 + 32 + 580 + 80 + 192;
+const SAPLING_BUNDLE_SIZE: u32 = 8 + 32 + 64; // value balance, anchor, binding sig
+const ORCHARD_ACTION_SIZE: u32 = 32 * 5 + 580 + 80 + 64 + 2272; // + proof per action
+const ORCHARD_BUNDLE_SIZE: u32 = 1 + 8 + 32 + 3 + 2720 + 64;
+
 #[derive(Clone, Serialize, Deserialize, Default, Debug)]
 pub struct FeeManager {
     pub num_inputs: [u8; 3],
@@ -59,7 +69,28 @@ impl FeeManager {
         f as u64 * COST_PER_ACTION
     }
 
-    #[allow(dead_code)]
+    /// Number of orchard actions, after padding
+    pub fn orchard_actions(&self) -> u32 {
+        Self::min_actions_padding(self.num_inputs[2].max(self.num_outputs[2])) as u32
+    }
+
+    /// Estimated size of the serialized transaction
+    pub fn tx_size(&self) -> u32 {
+        let mut size = TX_HEADER_SIZE;
+        size += self.num_inputs[0] as u32 * TRANSPARENT_INPUT_SIZE;
+        size += self.num_outputs[0] as u32 * TRANSPARENT_OUTPUT_SIZE;
+        if self.num_inputs[1] > 0 || self.num_outputs[1] > 0 {
+            size += SAPLING_BUNDLE_SIZE;
+            size += self.num_inputs[1] as u32 * SAPLING_SPEND_SIZE;
+            size += self.num_outputs[1].max(2) as u32 * SAPLING_OUTPUT_SIZE;
+        }
+        let actions = self.orchard_actions();
+        if actions > 0 {
+            size += ORCHARD_BUNDLE_SIZE + actions * ORCHARD_ACTION_SIZE;
+        }
+        size
+    }
+
     fn min_actions_padding(a: u8) -> u8 {
         if a == 0 {
             0
@@ -0,0 +1,142 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use parking_lot::Mutex;
+use rand::rngs::OsRng;
+use rusqlite::Connection;
+
+use crate::{
+    data::fb::{
+        ExternalSigningRequest, ExternalSigningRequestT, SpendAuthRequestT, TransactionBytes,
+        TransactionBytesT, TransactionSummaryT,
+    },
+    db::api_keys::check_sign_access,
+    fb_unwrap,
+    network::Network,
+};
+
+use super::{builder::PreparedTransaction, UnsignedTransaction};
+use warp_macros::c_export;
+
+/*
+    Threshold signing (FROST) of Orchard spends.
+    The spend authorization key is split between several participants
+    and the signature is produced by an external multi-round protocol driver.
+
+    1. `start_external_signing` creates the proofs, signs the transparent
+    and sapling inputs, and returns the sighash and the randomized verification
+    key (rk) of every Orchard action that needs a spend authorization, with
+    the randomizer (alpha) that the participants add to their key shares,
+    2. the driver runs the FROST rounds with the participants,
+    3. `complete_external_signing` checks the signatures against the rks
+    and returns the signed transaction.
+
+    Sessions are kept in memory. They do not survive a restart,
+    the transaction must be signed again. They also expire after
+    SIGNING_SESSION_TTL, and the expired sessions are purged whenever
+    the sessions are accessed, so that abandoned sessions do not hold
+    their transactions (and the randomizers) forever.
+*/
+
+/// Lifetime of a signing session
+pub const SIGNING_SESSION_TTL: Duration = Duration::from_secs(60 * 60);
+
+lazy_static::lazy_static! {
+    static ref SESSIONS: Mutex<SigningSessions> = Mutex::new(SigningSessions::default());
+}
+
+#[derive(Default)]
+struct SigningSessions {
+    next_id: u32,
+    sessions: HashMap<u32, (PreparedTransaction, Instant)>,
+}
+
+impl SigningSessions {
+    fn purge_expired(&mut self) {
+        self.sessions
+            .retain(|_, (_, started)| started.elapsed() < SIGNING_SESSION_TTL);
+    }
+}
+
+#[c_export]
+pub fn start_external_signing(
+    network: &Network,
+    connection: &Connection,
+    session: u32,
+    summary: &TransactionSummaryT,
+    expiration_height: u32,
+) -> Result<ExternalSigningRequestT> {
+    let data = fb_unwrap!(summary.data);
+    let unsigned_tx = bincode::deserialize_from::<_, UnsignedTransaction>(&data[..])?;
+    check_sign_access(network, connection, session, &unsigned_tx)?;
+    let ptx = unsigned_tx.prepare_signing(network, connection, expiration_height, OsRng, |_| {})?;
+    let sighash = ptx.sig_hash.to_vec();
+    let spends = ptx
+        .unsigned_orchard_spends()
+        .into_iter()
+        .map(|(index, rk, alpha)| SpendAuthRequestT {
+            index,
+            rk: Some(rk.to_vec()),
+            alpha: Some(alpha.to_vec()),
+            sighash: Some(sighash.clone()),
+        })
+        .collect::<Vec<_>>();
+
+    let mut sessions = SESSIONS.lock();
+    sessions.purge_expired();
+    sessions.next_id += 1;
+    let session = sessions.next_id;
+    sessions.sessions.insert(session, (ptx, Instant::now()));
+
+    Ok(ExternalSigningRequestT {
+        session,
+        sighash: Some(sighash),
+        spends: Some(spends),
+    })
+}
+
+/// End every signing session
+pub fn clear_signing_sessions() {
+    let mut sessions = SESSIONS.lock();
+    sessions.sessions.clear();
+}
+
+/// `signatures` is the concatenation of the 64 byte
+/// spend authorization signatures, in any order.
+/// The session ends even if the signatures are invalid.
+/// Fails if the session has expired
+#[c_export]
+pub fn complete_external_signing(session: u32, signatures: &[u8]) -> Result<TransactionBytesT> {
+    if signatures.len() % 64 != 0 {
+        anyhow::bail!("Signatures must be 64 bytes long");
+    }
+    let signatures = signatures
+        .chunks_exact(64)
+        .map(|s| s.try_into().unwrap())
+        .collect::<Vec<[u8; 64]>>();
+    let mut ptx = {
+        let mut sessions = SESSIONS.lock();
+        sessions.purge_expired();
+        sessions
+            .sessions
+            .remove(&session)
+            .map(|(ptx, _)| ptx)
+            .ok_or(anyhow::anyhow!(
+                "Unknown or expired signing session {}",
+                session
+            ))?
+    };
+    ptx.append_orchard_signatures(&signatures)?;
+    ptx.finalize()
+}
+
+#[c_export]
+pub fn cancel_external_signing(session: u32) -> Result<()> {
+    let mut sessions = SESSIONS.lock();
+    sessions.purge_expired();
+    sessions.sessions.remove(&session);
+    Ok(())
+}
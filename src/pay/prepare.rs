@@ -3,6 +3,7 @@ use super::{
     PaymentBuilder, Result, TxInput, TxOutput, UnsignedTransaction,
 };
 use fpdec::{Dec, Decimal};
+use rand::{rngs::OsRng, RngCore};
 use rusqlite::Connection;
 use zcash_keys::address::Address as RecipientAddress;
 use zcash_primitives::memo::MemoBytes;
@@ -10,20 +11,29 @@ use zcash_primitives::memo::MemoBytes;
 use crate::{
     data::fb::RecipientT,
     db::{
-        account::get_account_info,
+        account::{get_account_info, get_min_confirmations},
         notes::{list_received_notes, list_utxos},
     },
     fb_unwrap,
     network::Network,
+    pay::builder::DUST,
     types::{CheckpointHeight, PoolMask},
-    utils::{pay::COST_PER_ACTION, ua::single_receiver_address},
+    utils::{
+        pay::{COST_PER_ACTION, MAX_ORCHARD_ACTIONS, MAX_TX_SIZE},
+        ua::single_receiver_address,
+    },
     warp::{
         hasher::{OrchardHasher, SaplingHasher},
         legacy::CommitmentTreeFrontier,
+        sync::ReceivedNote,
         UTXO,
     },
+    COINBASE_MATURITY,
 };
 
+/// Smallest share of a split change, so that every note is worth spending
+const MIN_CHANGE_SHARE: u64 = COST_PER_ACTION;
+
 /*
     Use the Payment Builder to make outgoing transactions.
     Follow the steps outlined below:
@@ -32,6 +42,9 @@ use crate::{
     + payment which is a collection of recipients (address, amount, memo)
     + sapling/orchard commitment tree (you get these from lwd with `get_tree_state`)
     The builder records the *outputs* but has no funds yet
+    A recipient with a zero amount and a memo is a memo only output:
+    it goes to a shielded receiver with no value and only pays its
+    share of the fees
     2. add funds to use; either directly with `add_utxos`
     or by using the notes that the account contains with `add_account_funds`
     3. call `set_use_change` with true/false to indicate if the transaction
@@ -39,6 +52,22 @@ use crate::{
     of inputs/outputs, therefore having a change output may affect the fees
     If you set_use_change to false and the transaction needs some change,
     it will fail later
+    A recipient can restrict the pools of the inputs that pay
+    it with `src_pools` (on top of the pools of the payment), for
+    example to pay it only from Orchard and keep its output unlinked
+    from the other pools.
+    Optionally, call `set_decoy_outputs` to add zero value outputs to self
+    that make the transaction look like it has more recipients.
+    They go to the shielded pool used by the transaction and their cost
+    is capped. The extra fee is reported separately in the summary
+    Optionally, call `set_change_outputs` to split a shielded change
+    into several notes, so that the account has notes to spend in
+    parallel later. The extra change outputs are added one by one
+    while their fee is under a cap of their own (`max_change_fee` in
+    the config, independent of `max_decoy_fee`), and `finalize` gives
+    them random shares of the change. A share is never under
+    MIN_CHANGE_SHARE: a small change is split into fewer notes or not
+    at all, and the extra outputs without a share are not built
     3. `prepare` a transaction plan. This picks up enough
     funds to cover the outputs and pay for the fees.
     We have: Inputs = Outputs + Change + Fees (by amount)
@@ -97,19 +126,37 @@ impl PaymentBuilder {
             outputs,
             account_pools: PoolMask::default(),
             src_pools,
+            from_address: None,
+            sponsor: None,
             fee_manager: FeeManager::default(),
             fee: 0,
             available: [0; 3],
             used: [false; 3],
             use_change: true,
             use_unique_change: true,
+            decoy_outputs: 0,
+            max_decoy_fee: 0,
+            decoy_fee: 0,
+            change_outputs: 1,
+            max_change_fee: 0,
+            extra_change_outputs: 0,
             s_edge: s_tree.to_edge(&SaplingHasher::default()),
             o_edge: o_tree.to_edge(&OrchardHasher::default()),
         })
     }
 
+    /// Only spend the UTXOs of this transparent address.
+    /// Must be called before `add_account_funds`
+    pub fn set_from_address(&mut self, from_address: Option<String>) -> Result<()> {
+        self.from_address = from_address.filter(|a| !a.is_empty());
+        Ok(())
+    }
+
     pub fn add_account_funds(&mut self, connection: &Connection) -> Result<()> {
-        let account_pools = self.ai.pools().0 & self.src_pools.0; // exclude pools
+        let mut account_pools = self.ai.pools().0 & self.src_pools.0; // exclude pools
+        if self.from_address.is_some() {
+            account_pools &= 1; // shielded pools off
+        }
         self.account_pools = PoolMask(account_pools);
 
         let has_tex = self.outputs.iter().any(|o| {
@@ -122,8 +169,18 @@ impl PaymentBuilder {
             }
         });
 
+        let min_confirmations = get_min_confirmations(connection, self.account)?;
         let transparent_inputs = if account_pools & 1 != 0 {
-            list_utxos(connection, self.account, CheckpointHeight(self.height))?
+            let mut utxos = list_utxos(
+                connection,
+                self.account,
+                CheckpointHeight(self.height),
+                min_confirmations,
+            )?;
+            if let Some(from_address) = &self.from_address {
+                utxos.retain(|utxo| &utxo.address == from_address);
+            }
+            utxos
         } else {
             vec![]
         };
@@ -133,6 +190,7 @@ impl PaymentBuilder {
                 self.account,
                 CheckpointHeight(self.height),
                 false,
+                min_confirmations,
             )?
         } else {
             vec![]
@@ -143,6 +201,7 @@ impl PaymentBuilder {
                 self.account,
                 CheckpointHeight(self.height),
                 true,
+                min_confirmations,
             )?
         } else {
             vec![]
@@ -153,14 +212,20 @@ impl PaymentBuilder {
                 .iter()
                 .map(|utxo| TxInput::from_utxo(utxo)),
         );
+        // coinbase outputs are locked until they mature
+        let height = self.height;
+        let is_mature =
+            |note: &&ReceivedNote| !note.coinbase || note.height + COINBASE_MATURITY <= height;
         self.inputs[1].extend(
             sapling_inputs
                 .iter()
+                .filter(is_mature)
                 .map(|note| TxInput::from_sapling(note)),
         );
         self.inputs[2].extend(
             orchard_inputs
                 .iter()
+                .filter(is_mature)
                 .map(|note| TxInput::from_orchard(note)),
         );
         tracing::debug!("{:?}", self.inputs);
@@ -173,6 +238,22 @@ impl PaymentBuilder {
         Ok(())
     }
 
+    /// Add up to `count` decoy outputs, as long as they do not
+    /// increase the fee by more than `max_fee`
+    pub fn set_decoy_outputs(&mut self, count: u8, max_fee: u64) -> Result<()> {
+        self.decoy_outputs = count;
+        self.max_decoy_fee = max_fee;
+        Ok(())
+    }
+
+    /// Split the change into up to `count` outputs, as long as
+    /// the extra outputs do not increase the fee by more than `max_fee`
+    pub fn set_change_outputs(&mut self, count: u8, max_fee: u64) -> Result<()> {
+        self.change_outputs = count;
+        self.max_change_fee = max_fee;
+        Ok(())
+    }
+
     pub fn add_utxos(&mut self, utxos: &[UTXO]) -> Result<()> {
         let mut utxos = utxos
             .iter()
@@ -196,12 +277,16 @@ impl PaymentBuilder {
         outputs: &mut [&mut ExtendedRecipient],
     ) -> Result<()> {
         self.calculate_available()?;
+        // a memo only output has nothing to fill but its fee
+        let unpaid = |output: &ExtendedRecipient, fee: u64| {
+            output.remaining > 0 || (output.memo_only && fee > 0)
+        };
         for output in outputs.iter_mut() {
-            if output.pool_mask.to_pool().unwrap() != dst {
+            if output.pool_mask.to_pool().unwrap() != dst || output.src_pools.0 & (1 << src) == 0 {
                 continue;
             }
             for n in self.inputs[src as usize].iter_mut() {
-                if output.remaining > 0 && n.amount > COST_PER_ACTION && n.remaining > 0 {
+                if unpaid(output, self.fee) && n.amount > COST_PER_ACTION && n.remaining > 0 {
                     self.used[src as usize] = true;
                     if n.remaining == n.amount && (output.remaining > 0 || self.fee > 0) {
                         // first time this note is used
@@ -217,7 +302,7 @@ impl PaymentBuilder {
                     output.remaining -= r - r2;
                 }
 
-                if output.remaining == 0 {
+                if !unpaid(output, self.fee) {
                     break;
                 }
             }
@@ -232,6 +317,78 @@ impl PaymentBuilder {
     S  6  1  2
     O  7  3  0
     */
+    /// Zero value outputs to self in the shielded pool used by the transaction.
+    /// They are added one by one until the cap on their fee is reached
+    fn add_decoy_outputs(&mut self) -> Result<Vec<ExtendedRecipient>> {
+        let pool = if self.used[2] {
+            2
+        } else if self.used[1] {
+            1
+        } else if self.ai.orchard.is_some() {
+            2
+        } else {
+            1
+        };
+        let Some(address) = self.ai.to_change_address(&self.network, pool, false) else {
+            return Ok(vec![]);
+        };
+        let base_fee = self.fee_manager.fee();
+        let mut decoys = vec![];
+        for _ in 0..self.decoy_outputs {
+            if !fits_fee_cap(&self.fee_manager, pool, base_fee, self.max_decoy_fee) {
+                break;
+            }
+            let mut decoy = ExtendedRecipient {
+                recipient: RecipientT {
+                    address: Some(address.clone()),
+                    amount: 0,
+                    pools: 1 << pool,
+                    memo: None,
+                    memo_bytes: None,
+                    src_pools: 0,
+                },
+                amount: 0,
+                remaining: 0,
+                pool_mask: PoolMask(1 << pool),
+                is_change: false,
+                decoy: true,
+                memo_only: false,
+                src_pools: PoolMask(7),
+            };
+            self.fill_outputs(std::slice::from_mut(&mut &mut decoy))?;
+            decoys.push(decoy);
+        }
+        self.decoy_fee = self.fee_manager.fee() - base_fee;
+        tracing::info!("{} decoys, fee {}", decoys.len(), self.decoy_fee);
+        Ok(decoys)
+    }
+
+    /// Extra change outputs to new notes of the change pool,
+    /// up to the cap on their fee. A transparent change is not split
+    fn add_change_outputs(&mut self, change: &ExtendedRecipient) -> Result<Vec<ExtendedRecipient>> {
+        let pool = change.pool_mask.to_pool().unwrap();
+        if pool == 0 {
+            return Ok(vec![]);
+        }
+        let base_fee = self.fee_manager.fee();
+        let mut outputs = vec![];
+        for _ in 1..self.change_outputs {
+            if !fits_fee_cap(&self.fee_manager, pool, base_fee, self.max_change_fee) {
+                break;
+            }
+            let mut output = change.clone();
+            self.fill_outputs(std::slice::from_mut(&mut &mut output))?;
+            outputs.push(output);
+        }
+        self.extra_change_outputs = outputs.len();
+        tracing::info!(
+            "{} extra change outputs, fee {}",
+            outputs.len(),
+            self.fee_manager.fee() - base_fee
+        );
+        Ok(outputs)
+    }
+
     fn fill_outputs(&mut self, outputs: &mut [&mut ExtendedRecipient]) -> Result<()> {
         for o in outputs.iter() {
             self.fee += self.fee_manager.add_output(o.pool_mask.to_pool().unwrap());
@@ -299,16 +456,39 @@ impl PaymentBuilder {
                     pools: 1 << change_pool,
                     memo: None,
                     memo_bytes: None,
+                    src_pools: 0,
                 },
                 amount: 0,
                 remaining: 0,
                 pool_mask: PoolMask(1 << change_pool),
                 is_change: true,
+                decoy: false,
+                memo_only: false,
+                src_pools: PoolMask(7),
             };
             self.fill_outputs(std::slice::from_mut(&mut &mut change))?;
             outputs.push(change);
         }
 
+        if self.decoy_outputs > 0 {
+            let decoys = self.add_decoy_outputs()?;
+            // keep the change output last, finalize sets its amount
+            let at = if self.use_change {
+                outputs.len() - 1
+            } else {
+                outputs.len()
+            };
+            outputs.splice(at..at, decoys);
+        }
+
+        if self.use_change && self.change_outputs > 1 {
+            let change = outputs.last().unwrap().clone();
+            let extra_change = self.add_change_outputs(&change)?;
+            // between the decoys and the change output
+            let at = outputs.len() - 1;
+            outputs.splice(at..at, extra_change);
+        }
+
         // Collect the input/output assignments
         let mut tx_notes = vec![];
         for i in 0..3 {
@@ -340,9 +520,12 @@ impl PaymentBuilder {
                 amount,
                 note,
                 is_change: n.is_change,
+                decoy: n.decoy,
             });
         }
 
+        self.check_limits()?;
+
         tracing::debug!("# inputs {}", tx_notes.len());
         tracing::debug!("{:?}", tx_notes);
         tracing::debug!("# outputs {}", tx_outputs.len());
@@ -365,6 +548,78 @@ impl PaymentBuilder {
         Ok(transaction)
     }
 
+    /// Pay the fee with a UTXO of the `sponsor` account instead of the funds
+    /// of the account. The sponsor gets the rest of the UTXO back on a
+    /// transparent address and the account keeps the fee in its change.
+    /// Returns false if no UTXO of the sponsor is large enough
+    pub fn sponsor_fee(
+        &mut self,
+        connection: &Connection,
+        sponsor: u32,
+        utx: &mut AdjustableUnsignedTransaction,
+    ) -> Result<bool> {
+        let sai = get_account_info(&self.network, connection, sponsor)?;
+        let Some(change_address) = sai.to_change_address(&self.network, 0, false) else {
+            return Ok(false);
+        };
+        let min_confirmations = get_min_confirmations(connection, sponsor)?;
+        let mut utxos = list_utxos(
+            connection,
+            sponsor,
+            CheckpointHeight(self.height),
+            min_confirmations,
+        )?;
+        utxos.sort_by_key(|utxo| utxo.value);
+
+        let fee = self.fee_manager.fee();
+        let mut fee_manager = self.fee_manager.clone();
+        fee_manager.add_input(0);
+        fee_manager.add_output(0);
+        let sponsored_fee = fee_manager.fee();
+        // the smallest UTXO that leaves a change above the dust limit
+        let Some(utxo) = utxos
+            .iter()
+            .find(|utxo| utxo.value >= sponsored_fee + DUST)
+        else {
+            return Ok(false);
+        };
+        let sponsor_change = utxo.value - sponsored_fee;
+        let note = OutputNote::from_address(&self.network, &change_address, MemoBytes::empty())?;
+        let sponsor_output = TxOutput {
+            address_string: change_address,
+            pool: 0,
+            amount: sponsor_change,
+            note,
+            is_change: true,
+            decoy: false,
+        };
+        // keep the change output of the account last
+        let at = utx.tx_outputs.len() - 1;
+        utx.tx_outputs.insert(at, sponsor_output);
+        utx.tx_notes.push(TxInput::from_utxo(utxo));
+        utx.sum_ins += utxo.value;
+        utx.sum_outs = utx.sum_outs - fee + utxo.value;
+        utx.change += fee as i64;
+        self.fee_manager = fee_manager;
+        self.sponsor = Some(sponsor);
+        self.check_limits()?;
+        Ok(true)
+    }
+
+    /// Reject the transaction before it is built if it would
+    /// exceed the consensus limits or the mempool size limit
+    fn check_limits(&self) -> Result<()> {
+        let actions = self.fee_manager.orchard_actions();
+        if actions > MAX_ORCHARD_ACTIONS {
+            return Err(Error::TooManyActions(actions, MAX_ORCHARD_ACTIONS));
+        }
+        let size = self.fee_manager.tx_size();
+        if size > MAX_TX_SIZE {
+            return Err(Error::TxTooLarge(size, MAX_TX_SIZE, size.div_ceil(MAX_TX_SIZE)));
+        }
+        Ok(())
+    }
+
     pub fn finalize(
         self,
         mut utx: AdjustableUnsignedTransaction,
@@ -378,18 +633,69 @@ impl PaymentBuilder {
                 let d = d / Dec!(100000000.0);
                 d
             }
+            if let Some(from_address) = &self.from_address {
+                return Err(Error::NotEnoughFundsAtAddress(
+                    from_address.clone(),
+                    to_decimal(utx.sum_outs),
+                    to_decimal(utx.sum_ins),
+                ));
+            }
             return Err(Error::NotEnoughFunds(
                 to_decimal(utx.sum_outs),
                 to_decimal(utx.sum_ins),
                 to_decimal(-change as u64),
             ));
         }
+        // the change of a sponsor is not split with the change of the account
+        let sponsor_output = self.sponsor.map(|_| {
+            let at = utx.tx_outputs.len() - 2;
+            utx.tx_outputs.remove(at)
+        });
         if self.use_change {
-            let change_output = utx.tx_outputs.last_mut().unwrap();
+            let (change_output, others) = utx.tx_outputs.split_last_mut().unwrap();
             change_output.amount = change as u64;
+            // the extra change outputs are the last change outputs before it
+            let n = self.extra_change_outputs;
+            let at = match n {
+                0 => others.len(),
+                n => others
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .filter(|(_, o)| o.is_change)
+                    .nth(n - 1)
+                    .map(|(i, _)| i)
+                    .unwrap(),
+            };
+            let (others, extra_change) = others.split_at_mut(at);
+            // randomly split the change with the decoys of the same pool
+            for decoy in others
+                .iter_mut()
+                .filter(|o| o.decoy && o.pool == change_output.pool)
+            {
+                let a = OsRng.next_u64() % (change_output.amount / 2 + 1);
+                decoy.amount += a;
+                change_output.amount -= a;
+            }
+            // and into shares with the extra change outputs. Those
+            // without a share stay at 0 and are not built
+            let shares = split_change(change_output.amount, n, &mut OsRng);
+            let (change_share, extra_shares) = shares.split_last().unwrap();
+            for (o, share) in extra_change
+                .iter_mut()
+                .filter(|o| o.is_change)
+                .zip(extra_shares.iter())
+            {
+                o.amount = *share;
+            }
+            change_output.amount = *change_share;
         } else if change != 0 {
             return Err(Error::NoChangeOutput);
         }
+        if let Some(sponsor_output) = sponsor_output {
+            let at = utx.tx_outputs.len() - 1;
+            utx.tx_outputs.insert(at, sponsor_output);
+        }
         tracing::debug!("{:?}", utx.tx_outputs);
 
         let utx = UnsignedTransaction {
@@ -407,7 +713,9 @@ impl PaymentBuilder {
             tx_notes: utx.tx_notes,
             tx_outputs: utx.tx_outputs,
             fees: self.fee_manager,
+            decoy_fee: self.decoy_fee,
             message,
+            sponsor: self.sponsor,
         };
 
         Ok(utx)
@@ -435,3 +743,77 @@ impl AdjustableUnsignedTransaction {
         Ok(())
     }
 }
+
+/// Whether one more output to `pool` keeps the fee added
+/// since `base_fee` under `max_fee`
+fn fits_fee_cap(fee_manager: &FeeManager, pool: u8, base_fee: u64, max_fee: u64) -> bool {
+    let mut fee_manager = fee_manager.clone();
+    fee_manager.add_output(pool);
+    fee_manager.fee() - base_fee <= max_fee
+}
+
+/// Shares of `change` for up to `n` extra change outputs, followed by
+/// the share of the change output. The shares have random weights (1 to 2)
+/// and there are fewer of them if one would be under MIN_CHANGE_SHARE
+fn split_change<R: RngCore>(change: u64, n: usize, rng: &mut R) -> Vec<u64> {
+    // a share is at least change / (2 * number of shares)
+    let n = n.min((change / (2 * MIN_CHANGE_SHARE)).saturating_sub(1) as usize);
+    let weights = (0..=n)
+        .map(|_| 1_000 + rng.next_u64() % 1_000)
+        .collect::<Vec<_>>();
+    let sum = weights.iter().sum::<u64>();
+    let mut shares = weights[..n]
+        .iter()
+        .map(|w| (change as u128 * *w as u128 / sum as u128) as u64)
+        .collect::<Vec<_>>();
+    shares.push(change - shares.iter().sum::<u64>());
+    shares
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::OsRng;
+
+    use super::{fits_fee_cap, split_change, FeeManager, MIN_CHANGE_SHARE};
+    use crate::utils::pay::COST_PER_ACTION;
+
+    #[test]
+    fn change_split_sums_to_change() {
+        for change in [0, 5_000, 19_999, 1_000_000, u64::MAX / 4] {
+            for n in 0..=4 {
+                let shares = split_change(change, n, &mut OsRng);
+                assert!(shares.len() <= n + 1);
+                assert_eq!(shares.iter().sum::<u64>(), change);
+                if shares.len() > 1 {
+                    assert!(shares.iter().all(|s| *s >= MIN_CHANGE_SHARE));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn small_change_is_not_split() {
+        let shares = split_change(19_999, 4, &mut OsRng);
+        assert_eq!(shares.len(), 1);
+        let shares = split_change(20_000, 4, &mut OsRng);
+        assert_eq!(shares.len(), 2);
+    }
+
+    #[test]
+    fn change_outputs_respect_fee_cap() {
+        // one orchard spend, a recipient and the change: 2 actions
+        let mut fee_manager = FeeManager::default();
+        fee_manager.add_input(2);
+        fee_manager.add_output(2);
+        fee_manager.add_output(2);
+        let base_fee = fee_manager.fee();
+        let mut count = 0;
+        while fits_fee_cap(&fee_manager, 2, base_fee, COST_PER_ACTION) {
+            fee_manager.add_output(2);
+            count += 1;
+        }
+        assert_eq!(count, 1);
+        assert!(fee_manager.fee() - base_fee <= COST_PER_ACTION);
+        assert!(!fits_fee_cap(&fee_manager, 2, base_fee, COST_PER_ACTION));
+    }
+}
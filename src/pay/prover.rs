@@ -0,0 +1,335 @@
+use anyhow::Result;
+use parking_lot::Mutex;
+use rand::{rngs::OsRng, RngCore};
+use rayon::{prelude::*, ThreadPool, ThreadPoolBuilder};
+use sapling_crypto::{
+    builder::{InProgress, Proven, Unproven, Unsigned},
+    bundle::{GrothProofBytes, GROTH_PROOF_SIZE},
+    circuit,
+    keys::{EphemeralSecretKey, ProofGenerationKey},
+    prover::{OutputProver, SpendProver},
+    value::{NoteValue, ValueCommitTrapdoor},
+    Diversifier, MerklePath, PaymentAddress, Rseed,
+};
+use sha2::{Digest as _, Sha256};
+use std::{
+    collections::HashMap,
+    path::Path,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+use zcash_proofs::prover::LocalTxProver;
+use zcash_protocol::value::ZatBalance;
+
+use super::{InputNote, OutputNote, UnsignedTransaction, ORCHARD_PROVER, PROVER};
+use crate::{data::fb::ProofProgressT, Hash};
+use warp_macros::c_export;
+
+pub(crate) type UnprovenSaplingBundle =
+    sapling_crypto::Bundle<InProgress<Unproven, Unsigned>, ZatBalance>;
+pub(crate) type ProvenSaplingBundle =
+    sapling_crypto::Bundle<InProgress<Proven, Unsigned>, ZatBalance>;
+
+lazy_static::lazy_static! {
+    static ref PROOF_POOL: Mutex<Option<Arc<ThreadPool>>> = Mutex::new(None);
+    static ref PROOF_PROGRESS: Mutex<ProofProgressT> = Mutex::new(ProofProgressT::default());
+    static ref SAPLING_PROOFS: Mutex<HashMap<Hash, (Instant, ProvenSaplingBundle)>> =
+        Mutex::new(HashMap::new());
+}
+
+/// How long the Sapling proofs of a transaction can be reused
+pub const SAPLING_PROOF_TTL: Duration = Duration::from_secs(600);
+
+/*
+    Loading the Sapling parameters and building the Orchard proving key
+    take several seconds. Do it in a background thread at startup
+    so that it is not on the critical path of the first payment.
+*/
+/// `params_dir` has the Sapling parameters if they are not
+/// in the default location. They are loaded only if they are missing
+#[c_export]
+pub fn warm_up_provers(params_dir: &str) -> Result<()> {
+    let params_dir = params_dir.to_string();
+    std::thread::spawn(move || {
+        let has_sapling = load_sapling_params(&params_dir);
+        lazy_static::initialize(&ORCHARD_PROVER);
+        tracing::info!("Provers ready (sapling params loaded: {})", has_sapling);
+    });
+    Ok(())
+}
+
+/// Load the Sapling parameters into PROVER, unless it has them already.
+/// Returns whether PROVER has them
+fn load_sapling_params(params_dir: &str) -> bool {
+    let mut prover = PROVER.lock();
+    if prover.is_none() {
+        *prover = if params_dir.is_empty() {
+            LocalTxProver::with_default_location()
+        } else {
+            let directory = Path::new(params_dir);
+            let spend = directory.join("sapling-spend.params");
+            let output = directory.join("sapling-output.params");
+            (spend.exists() && output.exists()).then(|| LocalTxProver::new(&spend, &output))
+        };
+    }
+    prover.is_some()
+}
+
+/// Use a dedicated thread pool of `threads` threads for proof generation.
+/// 0 uses the global rayon pool
+pub fn set_proof_threads(threads: u32) -> Result<()> {
+    let pool = if threads > 0 {
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(threads as usize)
+            .thread_name(|i| format!("prover-{}", i))
+            .build()?;
+        Some(Arc::new(pool))
+    } else {
+        None
+    };
+    *PROOF_POOL.lock() = pool;
+    Ok(())
+}
+
+pub(crate) fn with_proof_pool<T: Send, F: FnOnce() -> T + Send>(f: F) -> T {
+    let pool = PROOF_POOL.lock().clone();
+    match pool {
+        Some(pool) => pool.install(f),
+        None => f(),
+    }
+}
+
+/// Progress of the proofs of the transaction being built.
+/// `pool` is 1 for Sapling (spends and outputs)
+/// and 2 for Orchard (actions)
+#[c_export]
+pub fn get_proof_progress() -> Result<ProofProgressT> {
+    Ok(PROOF_PROGRESS.lock().clone())
+}
+
+/// Report the progress to the caller of the build and to `get_proof_progress`
+pub(crate) fn report_progress<F: FnMut(ProofProgressT)>(
+    progress: &mut F,
+    pool: u8,
+    current: u32,
+    total: u32,
+) {
+    let p = ProofProgressT {
+        pool,
+        current,
+        total,
+    };
+    *PROOF_PROGRESS.lock() = p.clone();
+    progress(p);
+}
+
+/*
+    Parallel Sapling proofs
+
+    `Bundle::create_proofs` makes the proofs of the spends and outputs
+    one after the other. The circuits are ready after the bundle is built
+    though, so we prove them all at the same time on the proof pool and
+    give the results to `create_proofs` through `PrecomputedProofs`,
+    in the same order.
+*/
+
+/// Create the proofs of a Sapling bundle in parallel
+pub(crate) fn create_sapling_proofs<R: RngCore, F: FnMut(ProofProgressT) + Send>(
+    prover: &LocalTxProver,
+    bundle: UnprovenSaplingBundle,
+    rng: R,
+    progress: &mut F,
+) -> Result<ProvenSaplingBundle> {
+    let spends = bundle
+        .shielded_spends()
+        .iter()
+        .map(|s| s.zkproof().clone())
+        .collect::<Vec<_>>();
+    let outputs = bundle
+        .shielded_outputs()
+        .iter()
+        .map(|o| o.zkproof().clone())
+        .collect::<Vec<_>>();
+    let total = (spends.len() + outputs.len()) as u32;
+    let done = AtomicU32::new(0);
+    let progress = Mutex::new(progress);
+    let notify = || {
+        let current = done.fetch_add(1, Ordering::Relaxed) + 1;
+        let mut progress = progress.lock();
+        report_progress(&mut *progress, 1, current, total);
+    };
+    let (spend_proofs, output_proofs) = with_proof_pool(|| {
+        rayon::join(
+            || {
+                spends
+                    .into_par_iter()
+                    .map(|c| {
+                        let proof = SpendProver::create_proof(prover, c, &mut OsRng);
+                        notify();
+                        <LocalTxProver as SpendProver>::encode_proof(proof)
+                    })
+                    .collect::<Vec<_>>()
+            },
+            || {
+                outputs
+                    .into_par_iter()
+                    .map(|c| {
+                        let proof = OutputProver::create_proof(prover, c, &mut OsRng);
+                        notify();
+                        <LocalTxProver as OutputProver>::encode_proof(proof)
+                    })
+                    .collect::<Vec<_>>()
+            },
+        )
+    });
+    if spend_proofs.len() != bundle.shielded_spends().len()
+        || output_proofs.len() != bundle.shielded_outputs().len()
+    {
+        anyhow::bail!("Missing Sapling proofs");
+    }
+    Ok(bundle.create_proofs(
+        &PrecomputedProofs::new(spend_proofs),
+        &PrecomputedProofs::new(output_proofs),
+        rng,
+        (),
+    ))
+}
+
+/// Prover that returns proofs made beforehand, in order
+struct PrecomputedProofs(Mutex<std::vec::IntoIter<GrothProofBytes>>);
+
+impl PrecomputedProofs {
+    fn new(proofs: Vec<GrothProofBytes>) -> Self {
+        Self(Mutex::new(proofs.into_iter()))
+    }
+
+    /// `create_sapling_proofs` checks that there is a proof
+    /// for every spend and output
+    fn next(&self) -> GrothProofBytes {
+        self.0.lock().next().unwrap_or([0u8; GROTH_PROOF_SIZE])
+    }
+}
+
+impl SpendProver for PrecomputedProofs {
+    type Proof = GrothProofBytes;
+
+    #[allow(clippy::too_many_arguments)]
+    fn prepare_circuit(
+        proof_generation_key: ProofGenerationKey,
+        diversifier: Diversifier,
+        rseed: Rseed,
+        value: NoteValue,
+        alpha: jubjub::Fr,
+        rcv: ValueCommitTrapdoor,
+        anchor: jubjub::Base,
+        merkle_path: MerklePath,
+    ) -> Option<circuit::Spend> {
+        <LocalTxProver as SpendProver>::prepare_circuit(
+            proof_generation_key,
+            diversifier,
+            rseed,
+            value,
+            alpha,
+            rcv,
+            anchor,
+            merkle_path,
+        )
+    }
+
+    fn create_proof<R: RngCore>(&self, _circuit: circuit::Spend, _rng: &mut R) -> Self::Proof {
+        self.next()
+    }
+
+    fn encode_proof(proof: Self::Proof) -> GrothProofBytes {
+        proof
+    }
+}
+
+impl OutputProver for PrecomputedProofs {
+    type Proof = GrothProofBytes;
+
+    fn prepare_circuit(
+        esk: &EphemeralSecretKey,
+        payment_address: PaymentAddress,
+        rcm: jubjub::Fr,
+        value: NoteValue,
+        rcv: ValueCommitTrapdoor,
+    ) -> circuit::Output {
+        <LocalTxProver as OutputProver>::prepare_circuit(esk, payment_address, rcm, value, rcv)
+    }
+
+    fn create_proof<R: RngCore>(&self, _circuit: circuit::Output, _rng: &mut R) -> Self::Proof {
+        self.next()
+    }
+
+    fn encode_proof(proof: Self::Proof) -> GrothProofBytes {
+        proof
+    }
+}
+
+/*
+    Sapling proof cache
+
+    When a transaction is built again with the same inputs and outputs,
+    for instance with a new expiration height after a failed broadcast,
+    its Sapling bundle does not change: the proofs, the value commitments
+    and the randomized keys do not depend on the expiration height or
+    on the sighash. The proven (but unsigned) bundle is kept for
+    SAPLING_PROOF_TTL and a rebuild only signs it again.
+
+    The key is the hash of the account, the anchor and the Sapling
+    spends and outputs. Both transactions spend the same notes,
+    so sharing their randomness does not link anything more.
+*/
+
+/// Key of the Sapling bundle of a transaction,
+/// None if it has no Sapling spends or outputs
+pub(crate) fn sapling_proof_key(utx: &UnsignedTransaction) -> Option<Hash> {
+    let mut hasher = Sha256::new();
+    hasher.update(utx.account_name.as_bytes());
+    hasher.update(utx.roots[0]);
+    let mut n = 0;
+    for txin in utx.tx_notes.iter() {
+        if let InputNote::Sapling {
+            address,
+            rseed,
+            witness,
+        } = &txin.note
+        {
+            hasher.update([0]);
+            hasher.update(address);
+            hasher.update(txin.amount.to_le_bytes());
+            hasher.update(rseed);
+            hasher.update(witness.position.to_le_bytes());
+            n += 1;
+        }
+    }
+    for txout in utx.built_outputs() {
+        if let OutputNote::Sapling { address, memo } = &txout.note {
+            hasher.update([1]);
+            hasher.update(address);
+            hasher.update(txout.amount.to_le_bytes());
+            hasher.update(memo.as_array());
+            n += 1;
+        }
+    }
+    if n == 0 {
+        return None;
+    }
+    Some(hasher.finalize().into())
+}
+
+pub(crate) fn get_sapling_proofs(key: &Hash) -> Option<ProvenSaplingBundle> {
+    let mut proofs = SAPLING_PROOFS.lock();
+    proofs.retain(|_, (created, _)| created.elapsed() < SAPLING_PROOF_TTL);
+    proofs.get(key).map(|(_, bundle)| bundle.clone())
+}
+
+pub(crate) fn store_sapling_proofs(key: Hash, bundle: &ProvenSaplingBundle) {
+    SAPLING_PROOFS
+        .lock()
+        .insert(key, (Instant::now(), bundle.clone()));
+}
@@ -0,0 +1,145 @@
+use std::fmt::Write as _;
+
+use anyhow::Result;
+
+use crate::data::fb::{TransactionSummary, TransactionSummaryT};
+
+use super::{InputNote, UnsignedTransaction};
+use warp_macros::c_export;
+
+/*
+    Text rendering of a transaction summary
+
+    The confirmation screen of the apps and the CLI show the same
+    breakdown of the transaction, in this order:
+    - the account that pays,
+    - the inputs by pool (number of notes and value),
+    - the outputs, with shortened addresses and the change last,
+    - the fee, the change and the net effect on the account,
+    - the anchor height: the notes received after it are not
+    spent by this transaction.
+
+    Every line is "<label>: <value>" or an indented item of the
+    previous label. Labels are the constants below so that a UI can
+    translate them, and amounts are in ZEC with 8 decimals.
+*/
+
+pub const LABEL_ACCOUNT: &str = "Account";
+pub const LABEL_INPUTS: &str = "Inputs";
+pub const LABEL_OUTPUTS: &str = "Outputs";
+pub const LABEL_CHANGE: &str = "Change";
+pub const LABEL_FEE: &str = "Fee";
+pub const LABEL_NET: &str = "Net";
+pub const LABEL_MESSAGE: &str = "Message";
+pub const LABEL_ANCHOR: &str = "Anchor height";
+pub const LABEL_MEMO_ONLY: &str = "memo only";
+pub const POOL_NAMES: [&str; 3] = ["Transparent", "Sapling", "Orchard"];
+
+/// Characters kept at each end of a shortened address
+const ADDRESS_ENDS: usize = 8;
+
+#[c_export]
+pub fn render_summary(summary: &TransactionSummaryT) -> Result<String> {
+    // the plan has the value of the inputs, but the data
+    // is dropped from summaries that were already signed
+    let utx = summary
+        .data
+        .as_ref()
+        .map(|data| bincode::deserialize_from::<_, UnsignedTransaction>(&data[..]))
+        .transpose()?;
+
+    let mut s = String::new();
+    if let Some(utx) = utx.as_ref() {
+        writeln!(s, "{}: {}", LABEL_ACCOUNT, utx.account_name)?;
+    }
+
+    writeln!(s, "{}:", LABEL_INPUTS)?;
+    let mut inputs = [(0u32, 0u64); 3];
+    match utx.as_ref() {
+        Some(utx) => {
+            for i in utx.tx_notes.iter() {
+                let pool = match i.note {
+                    InputNote::Transparent { .. } => 0,
+                    InputNote::Sapling { .. } => 1,
+                    InputNote::Orchard { .. } => 2,
+                };
+                inputs[pool].0 += 1;
+                inputs[pool].1 += i.amount;
+            }
+        }
+        None => {
+            let num_inputs = summary.num_inputs.as_deref().unwrap_or_default();
+            for (pool, n) in num_inputs.iter().take(3).enumerate() {
+                inputs[pool].0 = *n as u32;
+            }
+            inputs[0].1 = summary.transparent_ins;
+        }
+    }
+    for (pool, (n, value)) in inputs.iter().enumerate() {
+        if *n == 0 {
+            continue;
+        }
+        if utx.is_some() || pool == 0 {
+            writeln!(s, "  {} ({}): {}", POOL_NAMES[pool], n, format_zec(*value))?;
+        } else {
+            writeln!(s, "  {} ({})", POOL_NAMES[pool], n)?;
+        }
+    }
+
+    writeln!(s, "{}:", LABEL_OUTPUTS)?;
+    let recipients = summary.recipients.as_deref().unwrap_or_default();
+    let mut sent = 0;
+    let mut change = 0;
+    for r in recipients.iter().filter(|r| !r.change) {
+        let address = r.address.as_deref().unwrap_or_default();
+        if r.memo_only {
+            writeln!(s, "  {}: {}", shorten_address(address), LABEL_MEMO_ONLY)?;
+            continue;
+        }
+        writeln!(
+            s,
+            "  {}: {}",
+            shorten_address(address),
+            format_zec(r.amount)
+        )?;
+        sent += r.amount;
+    }
+    for r in recipients.iter().filter(|r| r.change) {
+        change += r.amount;
+    }
+
+    writeln!(s, "{}: {}", LABEL_FEE, format_zec(summary.fee))?;
+    writeln!(s, "{}: {}", LABEL_CHANGE, format_zec(change))?;
+    writeln!(s, "{}: -{}", LABEL_NET, format_zec(sent + summary.fee))?;
+    if summary.requested_height > summary.height {
+        writeln!(
+            s,
+            "{}: {} (requested {})",
+            LABEL_ANCHOR, summary.height, summary.requested_height
+        )?;
+    } else {
+        writeln!(s, "{}: {}", LABEL_ANCHOR, summary.height)?;
+    }
+    if let Some(message) = summary.message.as_ref() {
+        writeln!(s, "{}: {}", LABEL_MESSAGE, message)?;
+    }
+    Ok(s)
+}
+
+/// Amount in ZEC with 8 decimals
+pub fn format_zec(amount: u64) -> String {
+    format!("{}.{:08}", amount / 100_000_000, amount % 100_000_000)
+}
+
+/// Beginning and end of a long address
+pub fn shorten_address(address: &str) -> String {
+    let chars = address.chars().collect::<Vec<_>>();
+    if chars.len() <= 2 * ADDRESS_ENDS + 3 {
+        return address.to_string();
+    }
+    let start = chars[..ADDRESS_ENDS].iter().collect::<String>();
+    let end = chars[chars.len() - ADDRESS_ENDS..]
+        .iter()
+        .collect::<String>();
+    format!("{start}...{end}")
+}
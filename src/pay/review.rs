@@ -0,0 +1,337 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+use orchard::{keys::Scope, note_encryption::OrchardDomain};
+use rusqlite::{Connection, OptionalExtension as _};
+use sapling_crypto::note_encryption::SaplingDomain;
+use zcash_client_backend::encoding::AddressCodec as _;
+use zcash_note_encryption::try_output_recovery_with_ovk;
+use zcash_primitives::{
+    consensus::{BlockHeight, BranchId},
+    transaction::{components::sapling::zip212_enforcement, Transaction},
+};
+
+use crate::{
+    data::fb::{TransactionMismatchT, TransactionReviewT, TransactionSummaryT},
+    db::{account::get_account_info, account_manager::get_account_by_name},
+    fb_unwrap,
+    network::Network,
+    utils::ua::ua_of_orchard,
+    Hash,
+};
+
+use super::{InputNote, OutputNote, UnsignedTransaction};
+
+/// An output decoded from a signed transaction.
+/// Shielded outputs are recovered with the account OVK,
+/// the same key the builder uses to encrypt them
+struct SignedOutput {
+    pool: u8,
+    address: String,
+    amount: u64,
+    memo: Option<Vec<u8>>,
+}
+
+/*
+    Independent review of a signed transaction
+
+    The signed transaction is decoded and compared against the
+    summary that the user approved. The summary carries the
+    unsigned transaction plan, therefore we check:
+    - the recipients displayed match the plan,
+    - every planned output is present with the same amount and memo,
+    - there are no other outputs that carry value,
+    - the inputs spent are the ones planned,
+    - the fee matches.
+    Shielded dummy outputs (padding) cannot be decrypted, but their
+    value must be zero for the pool value balance to match.
+*/
+pub fn review_signed_transaction(
+    network: &Network,
+    connection: &Connection,
+    summary: &TransactionSummaryT,
+    tx_bytes: &[u8],
+) -> Result<TransactionReviewT> {
+    let data = fb_unwrap!(summary.data);
+    let utx = bincode::deserialize_from::<_, UnsignedTransaction>(&data[..])?;
+    let account = get_account_by_name(connection, &utx.account_name)?
+        .ok_or(anyhow::anyhow!("Account not in wallet"))?;
+    let ai = get_account_info(network, connection, account)?;
+
+    let height = BlockHeight::from_u32(utx.height);
+    let branch_id = BranchId::for_height(network, height);
+    let tx = Transaction::read(tx_bytes, branch_id)?;
+    let txid: Hash = tx.txid().as_ref().clone();
+
+    let mut mismatches = vec![];
+
+    // The recipients shown to the user must be the ones in the plan
+    let recipients = summary.recipients.as_deref().unwrap_or_default();
+    if recipients.len() != utx.tx_outputs.len() {
+        mismatches.push(mismatch(
+            "recipients",
+            None,
+            utx.tx_outputs.len().to_string(),
+            recipients.len().to_string(),
+        ));
+    }
+    for (r, o) in recipients.iter().zip(utx.tx_outputs.iter()) {
+        if r.address.as_deref() != Some(o.address_string.as_str()) || r.amount != o.amount {
+            mismatches.push(mismatch(
+                "recipient",
+                r.address.clone(),
+                format!("{} {}", o.address_string, o.amount),
+                format!("{} {}", fb_unwrap!(r.address), r.amount),
+            ));
+        }
+    }
+
+    let mut outputs = vec![];
+    let mut value_balances = [0i64; 3];
+    let mut nfs = HashSet::<Hash>::new();
+    let mut outpoints = HashSet::<(Hash, u32)>::new();
+    let mut t_out = 0u64;
+    if let Some(b) = tx.transparent_bundle() {
+        for vin in b.vin.iter() {
+            outpoints.insert((vin.prevout.hash().clone(), vin.prevout.n()));
+        }
+        for vout in b.vout.iter() {
+            let amount: u64 = vout.value.into();
+            t_out += amount;
+            outputs.push(SignedOutput {
+                pool: 0,
+                address: vout
+                    .recipient_address()
+                    .map(|a| a.encode(network))
+                    .unwrap_or_default(),
+                amount,
+                memo: None,
+            });
+        }
+    }
+    if let Some(b) = tx.sapling_bundle() {
+        value_balances[1] = i64::from(*b.value_balance());
+        for sin in b.shielded_spends() {
+            nfs.insert(sin.nullifier().0.clone());
+        }
+        if let Some(si) = ai.sapling.as_ref() {
+            let ovk = &si.vk.fvk().ovk;
+            let domain = SaplingDomain::new(zip212_enforcement(network, height));
+            for sout in b.shielded_outputs() {
+                if let Some((n, p, m)) = try_output_recovery_with_ovk(
+                    &domain,
+                    ovk,
+                    sout,
+                    sout.cv(),
+                    sout.out_ciphertext(),
+                ) {
+                    outputs.push(SignedOutput {
+                        pool: 1,
+                        address: p.encode(network),
+                        amount: n.value().inner(),
+                        memo: Some(m.to_vec()),
+                    });
+                }
+            }
+        }
+    }
+    if let Some(b) = tx.orchard_bundle() {
+        value_balances[2] = i64::from(*b.value_balance());
+        for a in b.actions() {
+            nfs.insert(a.nullifier().to_bytes());
+        }
+        if let Some(oi) = ai.orchard.as_ref() {
+            let ovk = oi.vk.to_ovk(Scope::External);
+            for a in b.actions() {
+                let domain = OrchardDomain::for_rho(&a.rho());
+                if let Some((n, p, m)) = try_output_recovery_with_ovk(
+                    &domain,
+                    &ovk,
+                    a,
+                    a.cv_net(),
+                    &a.encrypted_note().out_ciphertext,
+                ) {
+                    outputs.push(SignedOutput {
+                        pool: 2,
+                        address: ua_of_orchard(&p).encode(network),
+                        amount: n.value().inner(),
+                        memo: Some(m.to_vec()),
+                    });
+                }
+            }
+        }
+    }
+
+    // Planned outputs
+    let mut matched = vec![false; outputs.len()];
+    for o in utx.built_outputs() {
+        let address = o.note.to_address(network);
+        let (pool, memo) = match &o.note {
+            OutputNote::Transparent { .. } => (0, None),
+            OutputNote::Sapling { memo, .. } => (1, Some(memo.as_slice())),
+            OutputNote::Orchard { memo, .. } => (2, Some(memo.as_slice())),
+        };
+        let found = outputs.iter().enumerate().position(|(i, so)| {
+            !matched[i] && so.pool == pool && so.address == address && so.amount == o.amount
+        });
+        match found {
+            Some(i) => {
+                matched[i] = true;
+                if outputs[i].memo.as_deref() != memo {
+                    mismatches.push(mismatch(
+                        "memo",
+                        Some(address),
+                        memo.map(hex::encode).unwrap_or_default(),
+                        outputs[i].memo.as_ref().map(hex::encode).unwrap_or_default(),
+                    ));
+                }
+            }
+            None => {
+                mismatches.push(mismatch(
+                    "missing_output",
+                    Some(address),
+                    o.amount.to_string(),
+                    String::new(),
+                ));
+            }
+        }
+    }
+    for (so, m) in outputs.iter().zip(matched.iter()) {
+        if !m {
+            mismatches.push(mismatch(
+                "unexpected_output",
+                Some(so.address.clone()),
+                String::new(),
+                so.amount.to_string(),
+            ));
+        }
+    }
+
+    // Planned inputs
+    let mut ins = [0u64; 3];
+    let mut expected_nfs = HashSet::<Hash>::new();
+    for i in utx.tx_notes.iter() {
+        ins[i.pool as usize] += i.amount;
+        match &i.note {
+            InputNote::Transparent { txid, vout, .. } => {
+                if !outpoints.remove(&(txid.clone(), *vout)) {
+                    mismatches.push(mismatch(
+                        "missing_input",
+                        None,
+                        format!("{}:{}", hex::encode(txid), vout),
+                        String::new(),
+                    ));
+                }
+            }
+            InputNote::Sapling { .. } | InputNote::Orchard { .. } => {
+                let nf = get_note_nf(connection, i.id)?;
+                match nf {
+                    Some(nf) if nfs.contains(&nf) => {
+                        expected_nfs.insert(nf);
+                    }
+                    _ => mismatches.push(mismatch(
+                        "missing_input",
+                        None,
+                        i.id.to_string(),
+                        String::new(),
+                    )),
+                }
+            }
+        }
+    }
+    for (txid, vout) in outpoints.iter() {
+        mismatches.push(mismatch(
+            "unexpected_input",
+            None,
+            String::new(),
+            format!("{}:{}", hex::encode(txid), vout),
+        ));
+    }
+    // Orchard actions always have a spend, and padding spends
+    // are dummy notes. Only flag nullifiers of notes we own
+    for nf in nfs.difference(&expected_nfs) {
+        if is_own_nf(connection, account, nf)? {
+            mismatches.push(mismatch(
+                "unexpected_input",
+                None,
+                String::new(),
+                hex::encode(nf),
+            ));
+        }
+    }
+
+    // Value that went to outputs we could not decrypt
+    for pool in 1..3 {
+        let recovered = outputs
+            .iter()
+            .filter(|so| so.pool == pool as u8)
+            .map(|so| so.amount as i64)
+            .sum::<i64>();
+        let expected_balance = ins[pool] as i64 - recovered;
+        if value_balances[pool] != expected_balance {
+            mismatches.push(mismatch(
+                "value_balance",
+                None,
+                expected_balance.to_string(),
+                value_balances[pool].to_string(),
+            ));
+        }
+    }
+
+    let fee = ins[0] as i64 + value_balances[1] + value_balances[2] - t_out as i64;
+    if fee != summary.fee as i64 {
+        mismatches.push(mismatch(
+            "fee",
+            None,
+            summary.fee.to_string(),
+            fee.to_string(),
+        ));
+    }
+
+    let review = TransactionReviewT {
+        txid: Some(txid.to_vec()),
+        fee: fee.max(0) as u64,
+        mismatches: Some(mismatches),
+    };
+    Ok(review)
+}
+
+fn mismatch(
+    kind: &str,
+    address: Option<String>,
+    expected: String,
+    actual: String,
+) -> TransactionMismatchT {
+    TransactionMismatchT {
+        kind: Some(kind.to_string()),
+        address,
+        expected: Some(expected),
+        actual: Some(actual),
+    }
+}
+
+fn get_note_nf(connection: &Connection, id_note: u32) -> Result<Option<Hash>> {
+    let nf = connection
+        .query_row("SELECT nf FROM notes WHERE id_note = ?1", [id_note], |r| {
+            r.get::<_, Hash>(0)
+        })
+        .optional()?;
+    Ok(nf)
+}
+
+fn is_own_nf(connection: &Connection, account: u32, nf: &Hash) -> Result<bool> {
+    let id = connection
+        .query_row(
+            "SELECT id_note FROM notes WHERE account = ?1 AND nf = ?2",
+            rusqlite::params![account, nf],
+            |r| r.get::<_, u32>(0),
+        )
+        .optional()?;
+    Ok(id.is_some())
+}
+
+impl TransactionReviewT {
+    pub fn is_ok(&self) -> bool {
+        self.mismatches.as_ref().map(|m| m.is_empty()).unwrap_or(true)
+    }
+}
@@ -1,33 +1,54 @@
 use crate::{
+    account::lwd::connect_account_lwd,
+    coin::CoinDef,
+    data::fb::{
+        RecipientT, SweepBundle, SweepBundleT, SweepTransactionT, TransactionBytes,
+        TransactionBytesT,
+    },
     db::{
-        account::get_account_info,
+        account::{get_account_info, list_accounts},
         account_manager::{store_transparent_address, trim_excess_transparent_addresses},
+        api_keys::{check_access, Operation},
+        chain::{get_sync_height, snap_to_checkpoint},
         notes::store_utxo,
+        tx::{
+            clear_transparent_sweep_progress, get_transparent_sweep_progress,
+            store_transparent_sweep_progress,
+        },
     },
+    fb_unwrap,
     keys::export_sk_bip38,
     lwd::get_utxos,
+    lwd_retry,
     network::Network,
-    types::TransparentAccountInfo,
-    Client,
+    types::{PoolMask, TransparentAccountInfo},
+    utils::{chain::get_cached_tree_state, pay::sign},
+    warp::sync::witnesses::rebuild_witnesses,
 };
 use anyhow::Result;
 use rusqlite::Connection;
 use tracing::Level;
 use zcash_client_backend::encoding::AddressCodec as _;
+use zcash_keys::address::Address as RecipientAddress;
 
+use super::{Error, PaymentBuilder};
 use warp_macros::c_export;
 
+/// Store the transparent addresses of the account and their UTXOs
+/// until `gap_limit` addresses in a row have none.
+/// An interrupted scan resumes from the last address checked
 #[c_export]
 pub async fn scan_transparent_addresses(
+    coin: &CoinDef,
     network: &Network,
     connection: &mut Connection,
-    client: &mut Client,
     account: u32,
     external: u32,
     gap_limit: u32,
 ) -> Result<()> {
     let span = tracing::span!(Level::DEBUG, "scan_transparent_addresses");
     let _enter = span.enter();
+    let mut client = connect_account_lwd(coin, connection, account).await?;
     let ai = get_account_info(network, connection, account)?;
     let tvk = ai
         .transparent
@@ -35,8 +56,9 @@ pub async fn scan_transparent_addresses(
         .and_then(|ti| ti.vk.as_ref())
         .ok_or(anyhow::anyhow!("No AccountPubKey"))?;
     let ti = ai.transparent.as_ref().unwrap();
-    let mut addr_index = 0;
-    let mut gap = 0;
+    // resume an interrupted scan
+    let (mut addr_index, mut gap) =
+        get_transparent_sweep_progress(connection, account, external)?.unwrap_or_default();
     while gap < gap_limit {
         let sk = ti.xsk.as_ref().map(|xsk| {
             let sk = TransparentAccountInfo::derive_sk(xsk, external, addr_index);
@@ -53,19 +75,140 @@ pub async fn scan_transparent_addresses(
             sk,
             Some(taddr.clone()),
         )?;
-        let utxos = get_utxos(client, account, external, addr_index, &taddr).await?;
+        let utxos = lwd_retry!(get_utxos(
+            &mut client,
+            account,
+            external,
+            addr_index,
+            &taddr
+        ))?;
+        let db_tx = connection.transaction()?;
         if utxos.is_empty() {
             gap += 1;
         } else {
-            let db_tx = connection.transaction()?;
             for utxo in utxos.iter() {
                 store_utxo(&db_tx, utxo)?;
             }
-            db_tx.commit()?;
             gap = 0;
         }
         addr_index += 1;
+        store_transparent_sweep_progress(&db_tx, account, external, addr_index, gap)?;
+        db_tx.commit()?;
     }
     trim_excess_transparent_addresses(connection, account, external)?;
+    clear_transparent_sweep_progress(connection, account, external)?;
     Ok(())
 }
+
+/*
+    Sweep of the whole wallet, for an emergency migration to cold storage.
+    Every account gets a transaction that spends all its funds with
+    the required confirmations to the destination address, minus the fees.
+    The usual pool rules apply: for example, a TEX destination only
+    takes transparent funds.
+    Accounts with no funds, or not enough to pay for the fees,
+    are skipped.
+*/
+#[c_export]
+pub async fn sweep_wallet(
+    coin: &CoinDef,
+    session: u32,
+    dest_address: &str,
+    confirmations: u32,
+) -> Result<SweepBundleT> {
+    check_access(&coin.connection()?, session, &Operation::Admin)?;
+    let network = &coin.network;
+    let Some(destination) = RecipientAddress::decode(network, dest_address) else {
+        anyhow::bail!("Invalid Address");
+    };
+    // a TEX address cannot receive shielded funds
+    let src_pools = match destination {
+        RecipientAddress::Tex(_) => PoolMask(1),
+        _ => PoolMask(7),
+    };
+    let connection = coin.connection()?;
+    let mut client = coin.connect_lwd()?;
+    let height = get_sync_height(&connection)?.height;
+    let confirmed_height = (height + 1).saturating_sub(confirmations.max(1));
+    let cp_height = snap_to_checkpoint(&connection, confirmed_height)?;
+    rebuild_witnesses(&connection, &mut client, cp_height).await?;
+    let (s_tree, o_tree) = get_cached_tree_state(&connection, &mut client, cp_height).await?;
+
+    let mut txs = vec![];
+    let accounts = list_accounts(coin, &connection)?;
+    for a in accounts.items.unwrap() {
+        let recipient = RecipientT {
+            address: Some(dest_address.to_string()),
+            amount: 0,
+            pools: 7,
+            memo: None,
+            memo_bytes: None,
+            src_pools: 0,
+        };
+        let mut pb = PaymentBuilder::new(
+            network,
+            &connection,
+            a.id,
+            cp_height,
+            &[recipient],
+            src_pools,
+            &s_tree,
+            &o_tree,
+        )?;
+        pb.add_account_funds(&connection)?;
+        let amount = pb.inputs.iter().flatten().map(|i| i.amount).sum::<u64>();
+        if amount == 0 {
+            continue;
+        }
+        let output = &mut pb.outputs[0];
+        output.recipient.amount = amount;
+        output.amount = amount;
+        output.remaining = amount;
+        pb.set_use_change(false)?;
+
+        let mut utx = pb.prepare()?;
+        let fee = pb.fee_manager.fee();
+        match utx.add_to_change(fee as i64) {
+            Err(Error::FeesTooHighForRecipient(_)) => {
+                tracing::warn!("Account {} has {} zats, not enough for the fees", a.id, amount);
+                continue;
+            }
+            r => r?,
+        }
+        let utx = pb.finalize(utx, None)?;
+        let summary = utx.to_summary()?;
+        txs.push(SweepTransactionT {
+            account: a.id,
+            amount: amount - fee,
+            summary: Some(Box::new(summary)),
+        });
+    }
+
+    Ok(SweepBundleT {
+        height: cp_height.0,
+        address: Some(dest_address.to_string()),
+        txs: Some(txs),
+    })
+}
+
+/// Sign every transaction of a sweep bundle.
+/// The signed transactions are in the order of the bundle
+/// and are broadcast separately with `tx_broadcast`
+#[c_export]
+pub fn sign_sweep(
+    network: &Network,
+    connection: &Connection,
+    session: u32,
+    bundle: &SweepBundleT,
+    expiration_height: u32,
+) -> Result<Vec<TransactionBytesT>> {
+    check_access(connection, session, &Operation::Admin)?;
+    let txs = fb_unwrap!(bundle.txs);
+    let mut txbs = vec![];
+    for tx in txs.iter() {
+        let summary = fb_unwrap!(tx.summary);
+        let txb = sign(network, connection, session, summary, expiration_height)?;
+        txbs.push(txb);
+    }
+    Ok(txbs)
+}
@@ -0,0 +1,398 @@
+use rand::rngs::OsRng;
+use rusqlite::{params, Connection};
+use zcash_client_backend::encoding::AddressCodec as _;
+use zcash_keys::address::Address as RecipientAddress;
+use zcash_primitives::{
+    consensus::{BlockHeight, BranchId},
+    legacy::TransparentAddress,
+    transaction::Transaction,
+};
+
+use crate::{
+    coin::CoinDef,
+    data::fb::{PaymentRequestT, RecipientT},
+    db::{
+        account::{get_account_info, get_min_confirmations},
+        chain::{snap_to_checkpoint, SNAP_NEAREST},
+        notes::list_utxos,
+    },
+    fb_unwrap,
+    lwd::get_last_height,
+    network::Network,
+    types::{CheckpointHeight, PoolMask, TransparentAccountInfo},
+    utils::pay::{broadcast_tx, plan_payment, PaymentOptions},
+    warp::{legacy::CommitmentTreeFrontier, UTXO},
+    Client, Hash, EXPIRATION_HEIGHT_DELTA,
+};
+
+use super::{fee::FeeManager, make_payment, Error, PaymentBuilder, Result, UnsignedTransaction};
+
+/*
+    Payments to TEX addresses must be funded by transparent inputs only.
+    When a payment has several TEX recipients, they are batched:
+    1. `make_tex_payment` builds the shielding step. It pays
+    the other recipients and sends the total of the TEX recipients
+    plus the fee of the second transaction to a transparent change
+    address of the account,
+    2. once the first transaction is signed, `make_tex_disclosure` builds
+    a transparent only transaction that spends this output and pays
+    every TEX recipient. It has no change output.
+
+    A payment to a TEX address that has change leaves it on a
+    transparent change address. With the `sweep_tex_change` option,
+    the broadcast remembers that address and, once the payment has
+    the confirmations of the config, a post sync hook shields the
+    UTXOs of the address to the Orchard pool of the account.
+    The hook waits until the wallet has caught up with the server.
+    If the sweep fails, it is retried later, after a delay that
+    doubles every time. The change is skipped only when there
+    is nothing worth shielding.
+*/
+
+const TEX_CHANGE_PENDING: u8 = 0;
+const TEX_CHANGE_SWEPT: u8 = 1;
+const TEX_CHANGE_SKIPPED: u8 = 2;
+
+/// Blocks before retrying a failed sweep the first time
+const TEX_CHANGE_RETRY_DELAY: u32 = 10;
+/// The delay stops doubling after this number of retries
+const TEX_CHANGE_MAX_BACKOFF: u32 = 6;
+
+fn is_tex(network: &Network, recipient: &RecipientT) -> bool {
+    matches!(
+        RecipientAddress::decode(network, fb_unwrap!(recipient.address)),
+        Some(RecipientAddress::Tex(_))
+    )
+}
+
+fn split_tex_recipients(
+    network: &Network,
+    payment: &PaymentRequestT,
+) -> (Vec<RecipientT>, Vec<RecipientT>) {
+    fb_unwrap!(payment.recipients)
+        .iter()
+        .cloned()
+        .partition(|r| is_tex(network, r))
+}
+
+/// Fee of the disclosure transaction: one transparent input
+/// and one transparent output per TEX recipient
+pub fn tex_disclosure_fee(num_recipients: usize) -> u64 {
+    let mut fee_manager = FeeManager::default();
+    fee_manager.add_input(0);
+    for _ in 0..num_recipients {
+        fee_manager.add_output(0);
+    }
+    fee_manager.fee()
+}
+
+/// Transparent change addresses of the account, latest first.
+/// The intermediate output goes to the current one but the
+/// change index may have moved by the time we build the disclosure
+fn change_addresses(
+    network: &Network,
+    connection: &Connection,
+    account: u32,
+) -> Result<Vec<(u32, TransparentAddress)>> {
+    let ai = get_account_info(network, connection, account)?;
+    let ti = ai
+        .transparent
+        .as_ref()
+        .ok_or(anyhow::anyhow!("Account has no transparent address"))?;
+    let vk = ti
+        .vk
+        .as_ref()
+        .ok_or(anyhow::anyhow!("Account has no transparent viewing key"))?;
+    let change_index = ti.change_index.unwrap_or_default();
+    let addresses = (0..=change_index)
+        .rev()
+        .map(|i| (i, TransparentAccountInfo::derive_address(vk, 1, i)))
+        .collect::<Vec<_>>();
+    Ok(addresses)
+}
+
+impl UnsignedTransaction {
+    /// Transparent change address of a payment to a TEX address
+    pub fn tex_change(&self, network: &Network) -> Option<String> {
+        let has_tex = self.tx_outputs.iter().any(|o| {
+            !o.is_change
+                && matches!(
+                    RecipientAddress::decode(network, &o.address_string),
+                    Some(RecipientAddress::Tex(_))
+                )
+        });
+        if !has_tex {
+            return None;
+        }
+        // the change of the account is after the change of a sponsor
+        self.tx_outputs
+            .iter()
+            .rev()
+            .find(|o| o.is_change && o.pool == 0)
+            .map(|o| o.address_string.clone())
+    }
+}
+
+pub fn make_tex_payment(
+    network: &Network,
+    connection: &Connection,
+    account: u32,
+    payment: &PaymentRequestT,
+    s_tree: &CommitmentTreeFrontier,
+    o_tree: &CommitmentTreeFrontier,
+    options: &PaymentOptions,
+) -> Result<UnsignedTransaction> {
+    let (tex, mut others) = split_tex_recipients(network, payment);
+    if tex.is_empty() {
+        return Err(Error::NoRecipient);
+    }
+    if !payment.sender_pay_fees {
+        return Err(anyhow::anyhow!("TEX payments must have fees paid by the sender").into());
+    }
+    let (_, address) = change_addresses(network, connection, account)?[0];
+
+    let amount = tex.iter().map(|r| r.amount).sum::<u64>() + tex_disclosure_fee(tex.len());
+    others.push(RecipientT {
+        address: Some(address.encode(network)),
+        amount,
+        pools: 1,
+        memo: None,
+        memo_bytes: None,
+        src_pools: 0,
+    });
+    let shielding = PaymentRequestT {
+        recipients: Some(others),
+        ..payment.clone()
+    };
+    make_payment(
+        network, connection, account, &shielding, s_tree, o_tree, options,
+    )
+}
+
+pub fn make_tex_disclosure(
+    network: &Network,
+    connection: &Connection,
+    account: u32,
+    payment: &PaymentRequestT,
+    shielding_tx: &[u8],
+    s_tree: &CommitmentTreeFrontier,
+    o_tree: &CommitmentTreeFrontier,
+    redirect: Option<String>,
+) -> Result<UnsignedTransaction> {
+    let (tex, _) = split_tex_recipients(network, payment);
+    if tex.is_empty() {
+        return Err(Error::NoRecipient);
+    }
+    let addresses = change_addresses(network, connection, account)?;
+    let amount = tex.iter().map(|r| r.amount).sum::<u64>() + tex_disclosure_fee(tex.len());
+
+    let height = BlockHeight::from_u32(payment.height);
+    let tx = Transaction::read(shielding_tx, BranchId::for_height(network, height))
+        .map_err(anyhow::Error::new)?;
+    let (vout, addr_index, taddr) = tx
+        .transparent_bundle()
+        .and_then(|b| {
+            b.vout.iter().enumerate().find_map(|(vout, o)| {
+                if u64::from(o.value) != amount {
+                    return None;
+                }
+                let a = o.recipient_address()?;
+                addresses
+                    .iter()
+                    .find(|(_, taddr)| *taddr == a)
+                    .map(|(addr_index, taddr)| (vout, *addr_index, *taddr))
+            })
+        })
+        .ok_or(anyhow::anyhow!("Shielding transaction does not fund the TEX payment"))?;
+    let utxo = UTXO {
+        is_new: true,
+        id: 0,
+        account,
+        external: 1,
+        addr_index,
+        height: payment.height,
+        timestamp: 0,
+        txid: tx.txid().as_ref().clone(),
+        vout: vout as u32,
+        address: taddr.encode(network),
+        value: amount,
+    };
+
+    let mut pb = PaymentBuilder::new(
+        network,
+        connection,
+        account,
+        CheckpointHeight(payment.height),
+        &tex,
+        PoolMask(1),
+        s_tree,
+        o_tree,
+    )?;
+    pb.add_utxos(&[utxo])?;
+    pb.set_use_change(false)?;
+    let utx = pb.prepare()?;
+    let utx = pb.finalize(utx, redirect)?;
+    Ok(utx)
+}
+
+/// Remember the change address of a broadcast TEX payment
+pub fn store_tex_change(connection: &Connection, txid: &Hash, address: &str) -> anyhow::Result<()> {
+    connection.execute(
+        "INSERT INTO tex_changes(account, txid, address, status)
+        SELECT account, ?1, ?2, ?3 FROM t_addresses WHERE address = ?2
+        ON CONFLICT DO NOTHING",
+        params![txid, address, TEX_CHANGE_PENDING],
+    )?;
+    Ok(())
+}
+
+/// Post sync hook: shield the change of the confirmed TEX payments
+pub async fn sweep_tex_change(
+    coin: &CoinDef,
+    connection: &Connection,
+    height: u32,
+) -> anyhow::Result<()> {
+    if !coin.config.sweep_tex_change {
+        return Ok(());
+    }
+    let network = &coin.network;
+    let confirmations = coin.config.confirmations.max(1);
+    let mut s = connection.prepare(
+        "SELECT c.id_tex_change, c.account, c.address, c.retries FROM tex_changes c
+        JOIN txs t ON t.account = c.account AND t.txid = c.txid
+        WHERE c.status = ?1 AND t.height + ?2 - 1 <= ?3 AND c.retry_height <= ?3",
+    )?;
+    let rows = s.query_map(params![TEX_CHANGE_PENDING, confirmations, height], |r| {
+        Ok((
+            r.get::<_, u32>(0)?,
+            r.get::<_, u32>(1)?,
+            r.get::<_, String>(2)?,
+            r.get::<_, u32>(3)?,
+        ))
+    })?;
+    let changes = rows.collect::<Result<Vec<_>, _>>()?;
+    if changes.is_empty() {
+        return Ok(());
+    }
+
+    let mut client = coin.connect_lwd()?;
+    let server_height = get_last_height(&mut client).await?;
+    if height < server_height {
+        return Ok(());
+    }
+    let expiration_height = server_height + EXPIRATION_HEIGHT_DELTA;
+    let cp_height = snap_to_checkpoint(connection, height)?;
+    let options = PaymentOptions::new(coin, SNAP_NEAREST, "");
+    for (id, account, address, retries) in changes {
+        let sweep = async {
+            let utx = shield_tex_change(
+                network,
+                connection,
+                &mut client,
+                account,
+                &address,
+                cp_height,
+                expiration_height,
+                &options,
+            )
+            .await?;
+            let Some(utx) = utx else {
+                return Ok(None);
+            };
+            let txb = utx.build(network, connection, expiration_height, OsRng)?;
+            let txid = broadcast_tx(network, connection, &mut client, &txb).await?;
+            Ok::<_, anyhow::Error>(Some(txid))
+        };
+        let (status, txid) = match sweep.await {
+            Ok(Some(txid)) => {
+                tracing::info!("Shielded the TEX change of {} in {}", address, txid);
+                (TEX_CHANGE_SWEPT, Some(txid))
+            }
+            Ok(None) => (TEX_CHANGE_SKIPPED, None),
+            Err(e) => {
+                let retry_height = height + retry_delay(retries);
+                tracing::warn!(
+                    "Cannot shield the TEX change of {}: {}, retrying at {}",
+                    address,
+                    e,
+                    retry_height
+                );
+                connection.execute(
+                    "UPDATE tex_changes SET retries = retries + 1, retry_height = ?2
+                    WHERE id_tex_change = ?1",
+                    params![id, retry_height],
+                )?;
+                continue;
+            }
+        };
+        connection.execute(
+            "UPDATE tex_changes SET status = ?2, sweep_txid = ?3 WHERE id_tex_change = ?1",
+            params![id, status, txid],
+        )?;
+    }
+    Ok(())
+}
+
+/// Blocks to wait before retrying a sweep that failed `retries` times
+fn retry_delay(retries: u32) -> u32 {
+    TEX_CHANGE_RETRY_DELAY << retries.min(TEX_CHANGE_MAX_BACKOFF)
+}
+
+/// All the UTXOs of `address` to the Orchard address of the account,
+/// minus the fee. The sweep is planned like the payments of the user,
+/// with their checks and fee limits. None if there is nothing worth shielding
+#[allow(clippy::too_many_arguments)]
+async fn shield_tex_change(
+    network: &Network,
+    connection: &Connection,
+    client: &mut Client,
+    account: u32,
+    address: &str,
+    cp_height: CheckpointHeight,
+    expiration_height: u32,
+    options: &PaymentOptions,
+) -> anyhow::Result<Option<UnsignedTransaction>> {
+    let ai = get_account_info(network, connection, account)?;
+    let Some(orchard_address) = ai.to_address(network, PoolMask(4)) else {
+        return Ok(None);
+    };
+    let min_confirmations = get_min_confirmations(connection, account)?;
+    let amount = list_utxos(connection, account, cp_height, min_confirmations)?
+        .iter()
+        .filter(|utxo| utxo.address == address)
+        .map(|utxo| utxo.value)
+        .sum::<u64>();
+    if amount == 0 {
+        return Ok(None);
+    }
+    let recipient = RecipientT {
+        address: Some(orchard_address),
+        amount,
+        pools: 4,
+        memo: None,
+        memo_bytes: None,
+        src_pools: 0,
+    };
+    // the change goes back to the account, it may be
+    // sent from its change address to its own address
+    let payment = PaymentRequestT {
+        recipients: Some(vec![recipient]),
+        src_pools: 1,
+        sender_pay_fees: false,
+        use_change: false,
+        height: cp_height.0,
+        expiration: expiration_height,
+        change_outputs: 1,
+        from_address: Some(address.to_string()),
+        allow_change_address: true,
+        allow_lookalike_address: true,
+        ..PaymentRequestT::default()
+    };
+    match plan_payment(network, connection, client, account, &payment, options).await {
+        Ok(utx) => Ok(Some(utx)),
+        Err(e) => match e.downcast_ref::<Error>() {
+            Some(Error::FeesTooHighForRecipient(_)) => Ok(None),
+            _ => Err(e),
+        },
+    }
+}
@@ -0,0 +1,130 @@
+use std::fmt::Write as _;
+
+use anyhow::Result;
+use rusqlite::{types::ValueRef, Connection};
+
+use crate::db::create_schema;
+
+/*
+    Database snapshots for test fixtures
+
+    `dump_db` writes the content of a wallet database as SQL
+    statements that can be compared with a text diff:
+    - the tables are in the order of their names, the columns in
+    the order of the schema and the rows are sorted by all their
+    columns,
+    - there is one INSERT statement per row,
+    - the tables that depend on the time or on the device (logs,
+    audit trail, backups, prices, api keys, mempool, last sync) are
+    left out, and so are the tables that are rebuilt from other tables,
+    - the wall clock columns of VOLATILE_COLUMNS are replaced by 0
+    when they are not NULL.
+
+    Two databases that went through the same operations with the
+    same server data have the same dump. `load_db` creates the schema
+    and inserts the rows of a dump, so that a test can start from the
+    state of a wallet where a bug was reported.
+
+    A dump has the keys and the seeds of the accounts. Fixtures should
+    only be made from wallets that never had real funds.
+*/
+
+/// Tables that are not in the dumps
+const VOLATILE_TABLES: &[&str] = &[
+    "api_key_accounts",
+    "api_keys",
+    "audit_log",
+    "backup_snapshots",
+    "backup_state",
+    "last_sync",
+    "logs",
+    "mempool_txs",
+    "prices",
+];
+
+/// Filled by the triggers of the notes table
+const DERIVED_TABLES: &[&str] = &["nullifiers"];
+
+/// (table, column) with the time of an operation
+const VOLATILE_COLUMNS: &[(&str, &str)] = &[
+    ("accounts", "deleted"),
+    ("drafts", "timestamp"),
+    ("outbox", "timestamp"),
+    ("swaps", "timestamp"),
+];
+
+/// Canonical SQL dump of the wallet database
+pub fn dump_db(connection: &Connection) -> Result<String> {
+    let mut s = connection.prepare(
+        "SELECT name FROM sqlite_master WHERE type = 'table'
+        AND name NOT LIKE 'sqlite_%' ORDER BY name",
+    )?;
+    let tables = s
+        .query_map([], |r| r.get::<_, String>(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut dump = String::new();
+    for table in tables.iter() {
+        if VOLATILE_TABLES.contains(&table.as_str()) || DERIVED_TABLES.contains(&table.as_str()) {
+            continue;
+        }
+        dump_table(connection, table, &mut dump)?;
+    }
+    Ok(dump)
+}
+
+fn dump_table(connection: &Connection, table: &str, dump: &mut String) -> Result<()> {
+    let mut s = connection.prepare(&format!(
+        "SELECT name FROM pragma_table_info('{table}') ORDER BY cid"
+    ))?;
+    let columns = s
+        .query_map([], |r| r.get::<_, String>(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+    let volatile = columns
+        .iter()
+        .map(|c| VOLATILE_COLUMNS.contains(&(table, c.as_str())))
+        .collect::<Vec<_>>();
+    let column_list = columns.join(", ");
+    let order = (1..=columns.len())
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    writeln!(dump, "-- {table}")?;
+    let mut s = connection.prepare(&format!(
+        "SELECT {column_list} FROM {table} ORDER BY {order}"
+    ))?;
+    let mut rows = s.query([])?;
+    while let Some(r) = rows.next()? {
+        let mut values = vec![];
+        for (i, volatile) in volatile.iter().enumerate() {
+            let v = match r.get_ref(i)? {
+                ValueRef::Null => "NULL".to_string(),
+                _ if *volatile => "0".to_string(),
+                ValueRef::Integer(v) => v.to_string(),
+                ValueRef::Real(v) => format!("{v:?}"),
+                ValueRef::Text(v) => {
+                    format!("'{}'", String::from_utf8_lossy(v).replace('\'', "''"))
+                }
+                ValueRef::Blob(v) => format!("X'{}'", hex::encode(v)),
+            };
+            values.push(v);
+        }
+        writeln!(
+            dump,
+            "INSERT INTO {table}({column_list}) VALUES ({});",
+            values.join(", ")
+        )?;
+    }
+    Ok(())
+}
+
+/// Create the schema in an empty database and insert
+/// the rows of a dump made by `dump_db`
+pub fn load_db(connection: &mut Connection, dump: &str) -> Result<()> {
+    create_schema(connection, "")?;
+    let db_tx = connection.transaction()?;
+    db_tx.execute_batch(dump)?;
+    db_tx.commit()?;
+    Ok(())
+}
@@ -1,4 +1,7 @@
-use std::io::{Read, Write};
+use std::{
+    io::{Read, Write},
+    time::{Duration, Instant},
+};
 
 use anyhow::Result;
 use flate2::{read::ZlibDecoder, write::ZlibEncoder, Compression};
@@ -15,7 +18,10 @@ use zcash_primitives::{
 };
 
 use crate::{
-    account::contacts::{add_contact, ChunkedContactV1, ChunkedMemoDecoder},
+    account::{
+        contacts::{add_contact, ChunkedContactV1, ChunkedMemoDecoder},
+        lwd::{get_account_lwd_url, AccountClients},
+    },
     coin::CoinDef,
     data::fb::{
         InputShieldedT, InputTransparentT, OutputShieldedT, OutputTransparentT, ShieldedMessageT,
@@ -23,9 +29,15 @@ use crate::{
     },
     db::{
         account::{get_account_info, list_account_transparent_addresses},
+        contacts::find_contact_by_address,
+        events::record_fee,
         messages::store_message,
         notes::{get_note_by_nf, list_pending_stxos},
-        tx::{get_tx, list_new_txids, store_tx_details, update_tx_primary_address_memo},
+        tx::{
+            clear_tx_details_failure, get_tx, list_new_txids, list_tx_details_queue,
+            store_tx_addresses, store_tx_details, store_tx_details_failure, update_tx_contact,
+            update_tx_primary_address_memo,
+        },
     },
     fb_unwrap,
     lwd::{get_transaction, get_txin_coins},
@@ -33,10 +45,14 @@ use crate::{
     types::{Addresses, PoolMask},
     utils::ua::ua_of_orchard,
     warp::{
-        sync::{FullPlainNote, PlainNote, ReceivedTx, TransparentNote},
+        sync::{
+            FullPlainNote, PlainNote, ReceivedTx, TransparentNote, ORCHARD_ACTION_SIZE,
+            ORCHARD_BUNDLE_SIZE, SAPLING_OUTPUT_SIZE, SAPLING_SPEND_SIZE, TRANSPARENT_INPUT_SIZE,
+            TRANSPARENT_OUTPUT_SIZE, TX_BASE_SIZE,
+        },
         OutPoint, TxOut2,
     },
-    Hash,
+    Client, Hash,
 };
 
 use warp_macros::c_export;
@@ -378,33 +394,215 @@ pub async fn retrieve_tx_details(
     network: &Network,
     connection: &Connection,
 ) -> Result<()> {
+    if coin.config.lazy_tx_details {
+        // details are fetched when the tx is viewed or by the backfill
+        return Ok(());
+    }
     let connection = Mutex::new(connection);
     let txids = list_new_txids(&connection.lock())?;
-    let mut client = coin.connect_lwd()?;
+    let mut clients = AccountClients::new(coin);
     for (id_tx, account, timestamp, txid) in txids {
-        let ai = get_account_info(network, &connection.lock(), account)?;
-        let account_addrs = ai.to_addresses(network);
-        let rtx = get_tx(&connection.lock(), id_tx)?;
-        let (height, tx) = get_transaction(network, &mut client, &txid).await?;
-        let txd = analyze_raw_transaction(
+        let url = get_account_lwd_url(&connection.lock(), account)?;
+        retrieve_one_tx_details(
             coin,
             network,
-            &connection.lock(),
+            &connection,
+            clients.get(url).await?,
+            id_tx,
             account,
-            height,
             timestamp,
-            tx,
-        )?;
-        let tx_bin = bincode::serialize(&txd)?;
-        store_tx_details(&connection.lock(), id_tx, account, height, &txid, &tx_bin)?;
-        let (tx_address, tx_memo) =
-            get_tx_primary_address_memo(network, &account_addrs, &rtx, &txd)?;
-        update_tx_primary_address_memo(network, &connection.lock(), id_tx, tx_address, tx_memo)?;
-        decode_tx_details(network, &connection.lock(), account, id_tx, &txd)?;
+            &txid,
+        )
+        .await?;
     }
     Ok(())
 }
 
+/*
+    Backfill for the lazy mode. Retrieve the details of at most
+    `batch_size` transactions, most recent first, waiting `interval_ms`
+    between requests to the server.
+    Returns the number of transactions that still have no details.
+*/
+#[c_export]
+pub async fn backfill_tx_details(
+    coin: &CoinDef,
+    network: &Network,
+    connection: &Connection,
+    batch_size: u32,
+    interval_ms: u32,
+) -> Result<u32> {
+    let connection = Mutex::new(connection);
+    let mut txids = list_new_txids(&connection.lock())?;
+    txids.sort_by_key(|(id_tx, ..)| std::cmp::Reverse(*id_tx));
+    let mut clients = AccountClients::new(coin);
+    let mut remaining = txids.len() as u32;
+    for (i, (id_tx, account, timestamp, txid)) in
+        txids.into_iter().take(batch_size as usize).enumerate()
+    {
+        if i > 0 {
+            tokio::time::sleep(Duration::from_millis(interval_ms as u64)).await;
+        }
+        let url = get_account_lwd_url(&connection.lock(), account)?;
+        retrieve_one_tx_details(
+            coin,
+            network,
+            &connection,
+            clients.get(url).await?,
+            id_tx,
+            account,
+            timestamp,
+            &txid,
+        )
+        .await?;
+        remaining -= 1;
+    }
+    Ok(remaining)
+}
+
+/*
+    Background retrieval of the transaction details
+
+    After the restore of a large wallet, thousands of transactions
+    may have no details. JOB_TX_DETAILS retrieves them in the
+    background (see `run_tx_details_worker`):
+    - the most recent transactions first, because they are the
+    ones that the history screen shows,
+    - at `bandwidth` bytes per second at most (0 for no limit),
+    from the estimated size of the transactions,
+    - a transaction that fails goes after the others in the next
+    runs, and is skipped after MAX_TX_DETAILS_ATTEMPTS failures.
+
+    The queue is in the database: the transactions without details
+    and their failures in `tx_details_failures`. The details of a
+    transaction are stored as soon as it is retrieved, so a job that
+    was cancelled or stopped with the app resumes where it was
+    when it is submitted again.
+*/
+
+pub const MAX_TX_DETAILS_ATTEMPTS: u32 = 5;
+
+/// `progress` gets the number of transactions done and the total,
+/// and stops the worker when it returns false.
+/// Returns the number of transactions that failed
+pub async fn run_tx_details_worker<F: Fn(u32, u32) -> bool>(
+    coin: &CoinDef,
+    bandwidth: u32,
+    progress: F,
+) -> Result<u32> {
+    let network = &coin.network;
+    let connection = coin.connection()?;
+    let connection = Mutex::new(&*connection);
+    let queue = list_tx_details_queue(&connection.lock(), MAX_TX_DETAILS_ATTEMPTS)?;
+    let total = queue.len() as u32;
+    let mut clients = AccountClients::new(coin);
+    let start = Instant::now();
+    let mut bytes = 0u64;
+    let mut failed = 0;
+    for (i, (id_tx, account, timestamp, txid)) in queue.into_iter().enumerate() {
+        if !progress(i as u32, total) {
+            return Ok(failed);
+        }
+        let url = get_account_lwd_url(&connection.lock(), account)?;
+        let res = retrieve_one_tx_details(
+            coin,
+            network,
+            &connection,
+            clients.get(url).await?,
+            id_tx,
+            account,
+            timestamp,
+            &txid,
+        )
+        .await;
+        match res {
+            Ok(txd) => {
+                clear_tx_details_failure(&connection.lock(), id_tx)?;
+                bytes += txd.estimated_size() as u64;
+            }
+            Err(e) => {
+                tracing::warn!("No details for tx {}: {}", id_tx, e);
+                store_tx_details_failure(&connection.lock(), id_tx, &e.to_string())?;
+                failed += 1;
+            }
+        }
+        if bandwidth != 0 {
+            let due = Duration::from_secs_f64(bytes as f64 / bandwidth as f64);
+            if let Some(wait) = due.checked_sub(start.elapsed()) {
+                tokio::time::sleep(wait).await;
+            }
+        }
+    }
+    progress(total, total);
+    Ok(failed)
+}
+
+pub async fn retrieve_one_tx_details(
+    coin: &CoinDef,
+    network: &Network,
+    connection: &Mutex<&Connection>,
+    client: &mut Client,
+    id_tx: u32,
+    account: u32,
+    timestamp: u32,
+    txid: &Hash,
+) -> Result<TransactionDetails> {
+    let ai = get_account_info(network, &connection.lock(), account)?;
+    let account_addrs = ai.to_addresses(network);
+    let rtx = get_tx(&connection.lock(), id_tx)?;
+    let (height, tx) = get_transaction(network, client, txid).await?;
+    let value_balance = tx
+        .sapling_bundle()
+        .map(|b| i64::from(*b.value_balance()))
+        .unwrap_or_default()
+        + tx.orchard_bundle()
+            .map(|b| i64::from(*b.value_balance()))
+            .unwrap_or_default();
+    let txd = analyze_raw_transaction(
+        coin,
+        network,
+        &connection.lock(),
+        account,
+        height,
+        timestamp,
+        tx,
+    )?;
+    let tx_bin = bincode::serialize(&txd)?;
+    store_tx_details(&connection.lock(), id_tx, account, height, txid, &tx_bin)?;
+    if let Some(fee) = paid_fee(&txd, value_balance) {
+        record_fee(&connection.lock(), account, height, txid, fee)?;
+    }
+    let (tx_address, tx_memo) = get_tx_primary_address_memo(network, &account_addrs, &rtx, &txd)?;
+    update_tx_primary_address_memo(network, &connection.lock(), id_tx, tx_address, tx_memo)?;
+    decode_tx_details(network, &connection.lock(), account, id_tx, &txd)?;
+    Ok(txd)
+}
+
+/// Fee of a transaction that spends notes of the account. None if
+/// the account spends nothing or if some transparent inputs are not ours,
+/// because their value is unknown
+fn paid_fee(txd: &TransactionDetails, value_balance: i64) -> Option<u64> {
+    let spends = txd.tins.iter().any(|tin| tin.note.is_some())
+        || txd
+            .sins
+            .iter()
+            .chain(txd.oins.iter())
+            .any(|sin| sin.note.is_some());
+    if !spends {
+        return None;
+    }
+    let mut t_in = 0i64;
+    for tin in txd.tins.iter() {
+        t_in += tin.note.as_ref()?.value as i64;
+    }
+    let t_out = txd
+        .touts
+        .iter()
+        .map(|tout| tout.coin.value as i64)
+        .sum::<i64>();
+    u64::try_from(t_in + value_balance - t_out).ok()
+}
+
 pub fn decode_tx_details(
     network: &Network,
     connection: &Connection,
@@ -415,6 +613,10 @@ pub fn decode_tx_details(
     let mut authenticated = false;
     let ai = get_account_info(network, connection, account)?;
     let account_address = ai.to_address(network, PoolMask(7)).unwrap();
+    let change_addresses = [
+        ai.to_change_address(network, 1, false),
+        ai.to_change_address(network, 2, false),
+    ];
     let mut spend_address = None;
     if let Some(taddr) = ai.transparent.as_ref().map(|ti| ti.addr) {
         let taddr = taddr.encode(network);
@@ -436,6 +638,9 @@ pub fn decode_tx_details(
 
     let mut contact_decoder =
         ChunkedMemoDecoder::<ChunkedContactV1>::new(tx.souts.len().max(tx.oouts.len()));
+    // addresses of the other party, the sender of the transparent inputs first
+    let mut counterparties = vec![];
+    counterparties.extend(spend_address.clone());
 
     for (nout, output) in tx
         .souts
@@ -464,8 +669,24 @@ pub fn decode_tx_details(
                 Some(account_address.clone())
             };
             let recipient = note_address;
+            // our own change comes back with the spends, it is not received
+            if fnote.incoming
+                && authenticated
+                && change_addresses[orchard as usize].as_ref() == Some(&recipient)
+            {
+                continue;
+            }
 
             let memo = Memo::from_bytes(&fnote.memo.0)?;
+            if fnote.incoming {
+                // a reply-to address in the memo reveals the sender
+                if let Memo::Text(text) = &memo {
+                    let memo = UserMemoT::from_text(sender.as_deref(), &recipient, text);
+                    counterparties.extend(memo.sender);
+                }
+            } else {
+                counterparties.push(recipient.clone());
+            }
             visit_memo(
                 network,
                 connection,
@@ -482,6 +703,34 @@ pub fn decode_tx_details(
             contact_decoder.add_memo(&memo.into())?;
         }
     }
+    let mut contact = None;
+    for address in counterparties.iter() {
+        contact = find_contact_by_address(network, connection, account, address)?;
+        if contact.is_some() {
+            break;
+        }
+    }
+    update_tx_contact(connection, id_tx, contact)?;
+
+    // the recipients of the transparent outputs that are not ours
+    if authenticated {
+        counterparties.extend(
+            tx.touts
+                .iter()
+                .filter(|o| o.note.is_none())
+                .filter_map(|o| o.coin.address.clone()),
+        );
+    }
+    store_tx_addresses(
+        network,
+        connection,
+        account,
+        id_tx,
+        tx.height,
+        &counterparties,
+        contact,
+    )?;
+
     let contacts = contact_decoder.finalize()?;
     for c in contacts.iter() {
         add_contact(network, connection, account, &c.name, &c.address, true)?;
@@ -624,6 +873,20 @@ pub fn get_tx_primary_address_memo(
 }
 
 impl TransactionDetails {
+    /// Size of the raw transaction, from the number of its components
+    pub fn estimated_size(&self) -> u32 {
+        let mut size = TX_BASE_SIZE
+            + self.tins.len() as u32 * TRANSPARENT_INPUT_SIZE
+            + self.touts.len() as u32 * TRANSPARENT_OUTPUT_SIZE
+            + self.sins.len() as u32 * SAPLING_SPEND_SIZE
+            + self.souts.len() as u32 * SAPLING_OUTPUT_SIZE
+            + self.oouts.len() as u32 * ORCHARD_ACTION_SIZE;
+        if !self.oouts.is_empty() {
+            size += ORCHARD_BUNDLE_SIZE;
+        }
+        size
+    }
+
     pub fn to_transaction_info_ext(self, network: &Network) -> TransactionInfoExtendedT {
         let tins = self
             .tins
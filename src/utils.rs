@@ -1,6 +1,7 @@
 use crate::{
     coin::CoinDef,
     data::fb::{Config, ConfigT},
+    db::logs::DbLogLayer,
     Hash,
 };
 use anyhow::{Context, Result};
@@ -14,15 +15,21 @@ use tracing_subscriber::{
 use crate::coin::COINS;
 use warp_macros::c_export;
 
+pub mod attachment;
+pub mod backup;
 pub mod chain;
 pub mod data_split;
+pub mod doctor;
 pub mod db;
+pub mod ecc_import;
 pub mod keys;
 pub mod messages;
 pub mod pay;
+pub mod qr;
 pub mod tx;
 pub mod ua;
 pub mod uri;
+pub mod witness;
 pub mod zip_db;
 
 type BoxedLayer<S> = Box<dyn Layer<S> + Send + Sync + 'static>;
@@ -86,6 +93,7 @@ pub fn init_tracing() {
         .with(env_layer())
         .with(android_layer())
         .with(ios_layer())
+        .with(DbLogLayer)
         .try_init();
 
     tracing::info!("Tracing initialized");
@@ -124,6 +132,16 @@ macro_rules! fb_vec_to_bytes {
     }};
 }
 
+#[macro_export]
+macro_rules! fb_to_bytes {
+    ($v: expr) => {{
+        let mut builder = FlatBufferBuilder::new();
+        let o = $v.pack(&mut builder);
+        builder.finish(o, None);
+        builder.finished_data().to_vec()
+    }};
+}
+
 pub fn to_txid_str(txid: &Hash) -> String {
     let mut txid = txid.clone();
     txid.reverse();
@@ -155,6 +173,57 @@ impl ConfigT {
         if other.regtest {
             self.regtest = other.regtest;
         }
+        if other.commit_interval > 0 {
+            self.commit_interval = other.commit_interval;
+        }
+        if other.proof_threads > 0 {
+            self.proof_threads = other.proof_threads;
+        }
+        if other.lazy_tx_details {
+            self.lazy_tx_details = other.lazy_tx_details;
+        }
+        if other.db_read_pool_size > 0 {
+            self.db_read_pool_size = other.db_read_pool_size;
+        }
+        if other.db_busy_timeout > 0 {
+            self.db_busy_timeout = other.db_busy_timeout;
+        }
+        if other.rotate_used_address {
+            self.rotate_used_address = other.rotate_used_address;
+        }
+        if other.account_grace_period > 0 {
+            self.account_grace_period = other.account_grace_period;
+        }
+        if other.max_decoy_fee > 0 {
+            self.max_decoy_fee = other.max_decoy_fee;
+        }
+        if other.api_key.is_some() {
+            self.api_key = other.api_key.clone();
+        }
+        if other.lazy_witnesses {
+            self.lazy_witnesses = other.lazy_witnesses;
+        }
+        if other.prefilter_outputs {
+            self.prefilter_outputs = other.prefilter_outputs;
+        }
+        if other.store_block_stats {
+            self.store_block_stats = other.store_block_stats;
+        }
+        if other.sweep_tex_change {
+            self.sweep_tex_change = other.sweep_tex_change;
+        }
+        if other.max_fee_percent > 0 {
+            self.max_fee_percent = other.max_fee_percent;
+        }
+        if other.max_fee > 0 {
+            self.max_fee = other.max_fee;
+        }
+        if other.cli_lock_timeout > 0 {
+            self.cli_lock_timeout = other.cli_lock_timeout;
+        }
+        if other.max_change_fee > 0 {
+            self.max_change_fee = other.max_change_fee;
+        }
     }
 }
 
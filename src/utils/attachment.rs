@@ -0,0 +1,181 @@
+use std::{
+    collections::HashMap,
+    io::{Read as _, Write as _},
+    path::Path,
+};
+
+use anyhow::Result;
+use flate2::{read::ZlibDecoder, write::ZlibEncoder, Compression};
+use rusqlite::Connection;
+use sha2::{Digest as _, Sha256};
+
+use crate::{
+    data::fb::{AttachmentT, PaymentRequestT, RecipientT},
+    db::tx::list_tx_memos,
+};
+use warp_macros::c_export;
+
+/*
+    File attachments
+
+    A small file can go with a payment, in the memos of zero value
+    outputs to the recipient. The file is compressed (zlib) and cut
+    into parts of one memo each. Every memo is arbitrary data (it
+    starts with 0xFF, see ZIP-302) with the header:
+    - ATTACHMENT_MAGIC,
+    - the id of the attachment: the first 8 bytes of the SHA-256
+    of the compressed file,
+    - the index of the part and the number of parts (1 byte each).
+
+    The rest of the memos, concatenated in the order of the parts,
+    is the manifest followed by the compressed file. The manifest is
+    the length of the name (1 byte), the name in UTF-8, the size of
+    the file and the size of the compressed file (u32 LE), and the
+    SHA-256 of the file.
+
+    A file can use up to MAX_ATTACHMENT_PARTS memos. On the receive
+    side, `save_attachments` finds the complete attachments in the
+    memos of a transaction, checks them and writes them in a
+    directory. Only the file name is kept from the name of the
+    attachment.
+*/
+
+pub const ATTACHMENT_MAGIC: [u8; 4] = *b"WAT1";
+pub const MAX_ATTACHMENT_PARTS: usize = 8;
+pub const MAX_ATTACHMENT_NAME: usize = 64;
+
+const MEMO_SIZE: usize = 512;
+const HEADER_SIZE: usize = 1 + ATTACHMENT_MAGIC.len() + 8 + 2;
+const PART_SIZE: usize = MEMO_SIZE - HEADER_SIZE;
+
+/// Add the memos of a file to a payment, for the recipient `address`
+#[c_export]
+pub fn attach_file(
+    payment: &PaymentRequestT,
+    address: &str,
+    name: &str,
+    data: &[u8],
+) -> Result<PaymentRequestT> {
+    if name.is_empty() || name.len() > MAX_ATTACHMENT_NAME {
+        anyhow::bail!(
+            "The attachment name must have 1 to {} bytes",
+            MAX_ATTACHMENT_NAME
+        );
+    }
+    let mut e = ZlibEncoder::new(Vec::new(), Compression::best());
+    e.write_all(data)?;
+    let compressed = e.finish()?;
+
+    let mut content = vec![name.len() as u8];
+    content.extend_from_slice(name.as_bytes());
+    content.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    content.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+    content.extend_from_slice(&Sha256::digest(data));
+    content.extend_from_slice(&compressed);
+
+    let count = content.len().div_ceil(PART_SIZE);
+    if count > MAX_ATTACHMENT_PARTS {
+        anyhow::bail!(
+            "The attachment needs {} memos, the maximum is {}",
+            count,
+            MAX_ATTACHMENT_PARTS
+        );
+    }
+    let id = &Sha256::digest(&compressed)[..8];
+    let mut payment = payment.clone();
+    let recipients = payment.recipients.get_or_insert_with(Vec::new);
+    for (i, part) in content.chunks(PART_SIZE).enumerate() {
+        let mut memo = vec![0xFF];
+        memo.extend_from_slice(&ATTACHMENT_MAGIC);
+        memo.extend_from_slice(id);
+        memo.push(i as u8);
+        memo.push(count as u8);
+        memo.extend_from_slice(part);
+        recipients.push(RecipientT {
+            address: Some(address.to_string()),
+            amount: 0,
+            pools: 6,
+            memo: None,
+            memo_bytes: Some(memo),
+            src_pools: 0,
+        });
+    }
+    Ok(payment)
+}
+
+/// Write the attachments of a transaction in `directory`
+#[c_export]
+pub fn save_attachments(
+    connection: &Connection,
+    account: u32,
+    txid: &[u8],
+    directory: &str,
+) -> Result<Vec<AttachmentT>> {
+    let memos = list_tx_memos(connection, account, txid)?;
+    let mut parts = HashMap::<[u8; 8], Vec<Option<Vec<u8>>>>::new();
+    for memo in memos.iter() {
+        if memo.len() < HEADER_SIZE || memo[0] != 0xFF || memo[1..5] != ATTACHMENT_MAGIC {
+            continue;
+        }
+        let id: [u8; 8] = memo[5..13].try_into().unwrap();
+        let (index, count) = (memo[13] as usize, memo[14] as usize);
+        if index >= count || count > MAX_ATTACHMENT_PARTS {
+            continue;
+        }
+        let p = parts.entry(id).or_insert_with(|| vec![None; count]);
+        if p.len() == count {
+            p[index] = Some(memo[HEADER_SIZE..].to_vec());
+        }
+    }
+
+    let mut attachments = vec![];
+    for (id, p) in parts {
+        if p.iter().any(Option::is_none) {
+            tracing::info!("Incomplete attachment {}", hex::encode(id));
+            continue;
+        }
+        let content = p.into_iter().flatten().flatten().collect::<Vec<_>>();
+        let (name, data) = decode_attachment(&id, &content)?;
+        let file_name = Path::new(&name)
+            .file_name()
+            .ok_or(anyhow::anyhow!("Invalid attachment name {}", name))?;
+        let path = Path::new(directory).join(file_name);
+        std::fs::write(&path, &data)?;
+        attachments.push(AttachmentT {
+            name: Some(name),
+            size: data.len() as u32,
+            path: Some(path.to_string_lossy().to_string()),
+        });
+    }
+    Ok(attachments)
+}
+
+/// Name and data of an attachment
+fn decode_attachment(id: &[u8], content: &[u8]) -> Result<(String, Vec<u8>)> {
+    let invalid = || anyhow::anyhow!("Invalid attachment {}", hex::encode(id));
+    let name_len = *content.first().ok_or_else(invalid)? as usize;
+    let manifest_len = 1 + name_len + 8 + 32;
+    if content.len() < manifest_len {
+        return Err(invalid());
+    }
+    let name = String::from_utf8(content[1..1 + name_len].to_vec())?;
+    let mut p = 1 + name_len;
+    let size = u32::from_le_bytes(content[p..p + 4].try_into().unwrap());
+    p += 4;
+    let compressed_len = u32::from_le_bytes(content[p..p + 4].try_into().unwrap()) as usize;
+    p += 4;
+    let hash = &content[p..p + 32];
+    p += 32;
+    let compressed = content.get(p..p + compressed_len).ok_or_else(invalid)?;
+    if &Sha256::digest(compressed)[..8] != id {
+        return Err(invalid());
+    }
+    let mut data = vec![];
+    ZlibDecoder::new(compressed)
+        .take(size as u64)
+        .read_to_end(&mut data)?;
+    if data.len() != size as usize || Sha256::digest(&data).as_slice() != hash {
+        return Err(invalid());
+    }
+    Ok((name, data))
+}
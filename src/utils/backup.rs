@@ -0,0 +1,446 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs::{File, OpenOptions},
+    io::{Read as _, Write as _},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use age::{secrecy::Secret, Decryptor, Encryptor};
+use anyhow::Result;
+use rand::{rngs::OsRng, RngCore as _};
+use rusqlite::{params, params_from_iter, types::Value, Connection, OptionalExtension as _};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest as _, Sha256};
+use zcash_protocol::consensus::Parameters as _;
+
+use crate::{
+    coin::CoinDef,
+    data::fb::{Config, ConfigT},
+    db::{
+        api_keys::{check_access, Operation},
+        audit::{audit, DB_DECRYPT},
+    },
+    network::Network,
+    Hash,
+};
+
+use super::db::SCHEMA_VERSION;
+use warp_macros::c_export;
+
+/*
+    Wallet backup file
+
+    The file is a sequence of frames: a 4 byte little endian length,
+    the 32 byte key id of the backup and an age (passphrase) encrypted
+    snapshot of that length.
+    The first frame is a full snapshot of every table and the following
+    frames are incremental: they only have the rows that were added or
+    changed since the previous snapshot, and the ids of the deleted rows.
+
+    The key id is random, drawn for every full snapshot, and the
+    snapshots have it too. An incremental snapshot is only appended
+    to a file that starts with the key id of the last snapshot,
+    and a restore refuses the frames of another backup.
+    The backup and the db must have the same SCHEMA_VERSION.
+
+    To know what changed, we keep the hash of every row at the time
+    of the last snapshot in `backup_state`. Rows are dumped in id order
+    and tables in name order, so the same database gives the same snapshot.
+*/
+
+/// Tables that are not backed up: the ones used to track the backups
+/// themselves, the audit log, which cannot be rewritten by a restore,
+/// the API keys of the installation and the nullifier index,
+/// which is rebuilt when the notes are restored
+const EXCLUDED_TABLES: [&str; 6] = [
+    "backup_state",
+    "backup_snapshots",
+    "audit_log",
+    "api_keys",
+    "api_key_accounts",
+    "nullifiers",
+];
+
+/// Length and key id
+const FRAME_HEADER_LEN: usize = 4 + 32;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Snapshot {
+    schema_version: u32,
+    key_id: Hash,
+    network: String,
+    sequence: u32,
+    incremental: bool,
+    timestamp: u64,
+    config: ConfigT,
+    tables: Vec<TableSnapshot>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct TableSnapshot {
+    name: String,
+    id_column: String,
+    columns: Vec<String>,
+    rows: Vec<(i64, Vec<BackupValue>)>,
+    deleted: Vec<i64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+enum BackupValue {
+    Null,
+    Integer(i64),
+    Real(f64),
+    Text(String),
+    Blob(Vec<u8>),
+}
+
+impl From<Value> for BackupValue {
+    fn from(v: Value) -> Self {
+        match v {
+            Value::Null => BackupValue::Null,
+            Value::Integer(i) => BackupValue::Integer(i),
+            Value::Real(r) => BackupValue::Real(r),
+            Value::Text(t) => BackupValue::Text(t),
+            Value::Blob(b) => BackupValue::Blob(b),
+        }
+    }
+}
+
+impl From<BackupValue> for Value {
+    fn from(v: BackupValue) -> Self {
+        match v {
+            BackupValue::Null => Value::Null,
+            BackupValue::Integer(i) => Value::Integer(i),
+            BackupValue::Real(r) => Value::Real(r),
+            BackupValue::Text(t) => Value::Text(t),
+            BackupValue::Blob(b) => Value::Blob(b),
+        }
+    }
+}
+
+fn network_name(network: &Network) -> String {
+    format!("{:?}", network.network_type())
+}
+
+/// Write a snapshot of the wallet db and configuration to `path`.
+/// If `incremental` is set and `path` has the previous snapshot,
+/// only the changes are appended. Otherwise the file is replaced
+/// by a full snapshot
+#[c_export]
+pub fn backup_wallet(
+    coin: &CoinDef,
+    network: &Network,
+    connection: &Connection,
+    session: u32,
+    path: &str,
+    password: &str,
+    incremental: bool,
+) -> Result<()> {
+    check_access(connection, session, &Operation::Admin)?;
+    let last = connection
+        .query_row(
+            "SELECT sequence, path, key_id FROM backup_snapshots
+            ORDER BY sequence DESC LIMIT 1",
+            [],
+            |r| {
+                Ok((
+                    r.get::<_, u32>(0)?,
+                    r.get::<_, String>(1)?,
+                    r.get::<_, Option<Vec<u8>>>(2)?,
+                ))
+            },
+        )
+        .optional()?;
+    let last_key_id = match &last {
+        Some((_, p, Some(key_id))) if p == path => Hash::try_from(&key_id[..]).ok(),
+        _ => None,
+    };
+    let incremental = incremental && last_key_id.is_some() && read_key_id(path) == last_key_id;
+    let sequence = last.map(|(s, _, _)| s + 1).unwrap_or(1);
+    let key_id = match last_key_id {
+        Some(key_id) if incremental => key_id,
+        _ => {
+            let mut key_id = [0u8; 32];
+            OsRng.fill_bytes(&mut key_id);
+            key_id
+        }
+    };
+
+    let db_tx = connection.unchecked_transaction()?;
+    if !incremental {
+        db_tx.execute("DELETE FROM backup_state", [])?;
+    }
+    let mut tables = vec![];
+    for name in list_tables(&db_tx)? {
+        tables.push(snapshot_table(&db_tx, &name)?);
+    }
+    let snapshot = Snapshot {
+        schema_version: SCHEMA_VERSION,
+        key_id,
+        network: network_name(network),
+        sequence,
+        incremental,
+        timestamp: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+        config: coin.config.clone(),
+        tables,
+    };
+
+    let data = bincode::serialize(&snapshot)?;
+    let encryptor = Encryptor::with_user_passphrase(Secret::new(password.to_string()));
+    let mut frame = vec![];
+    {
+        let mut writer = encryptor.wrap_output(&mut frame)?;
+        writer.write_all(&data)?;
+        writer.finish()?;
+    }
+    let mut file = if incremental {
+        OpenOptions::new().append(true).open(path)?
+    } else {
+        File::create(path)?
+    };
+    file.write_all(&(frame.len() as u32).to_le_bytes())?;
+    file.write_all(&key_id)?;
+    file.write_all(&frame)?;
+    file.sync_all()?;
+
+    db_tx.execute(
+        "INSERT INTO backup_snapshots(sequence, path, timestamp, incremental, key_id)
+        VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![sequence, path, snapshot.timestamp, incremental, key_id],
+    )?;
+    db_tx.commit()?;
+    Ok(())
+}
+
+/// Restore the wallet db from a backup file, applying
+/// every snapshot in order. Returns the configuration
+/// saved with the last snapshot
+#[c_export]
+pub fn restore_wallet(
+    network: &Network,
+    connection: &mut Connection,
+    session: u32,
+    path: &str,
+    password: &str,
+) -> Result<ConfigT> {
+    check_access(connection, session, &Operation::Admin)?;
+    let mut data = vec![];
+    File::open(path)?.read_to_end(&mut data)?;
+
+    // decrypt and validate everything before touching the db
+    let mut snapshots = vec![];
+    let mut backup_key_id = None;
+    let mut data = &data[..];
+    while !data.is_empty() {
+        if data.len() < FRAME_HEADER_LEN {
+            anyhow::bail!("Truncated backup file");
+        }
+        let (header, rest) = data.split_at(FRAME_HEADER_LEN);
+        let len = u32::from_le_bytes(header[0..4].try_into().unwrap()) as usize;
+        let key_id: Hash = header[4..].try_into().unwrap();
+        if rest.len() < len {
+            anyhow::bail!("Truncated backup file");
+        }
+        let (frame, rest) = rest.split_at(len);
+        data = rest;
+        if *backup_key_id.get_or_insert(key_id) != key_id {
+            anyhow::bail!("Frame #{} is from another backup", snapshots.len() + 1);
+        }
+
+        let Decryptor::Passphrase(decryptor) = Decryptor::new(frame).map_err(anyhow::Error::msg)?
+        else {
+            anyhow::bail!("Backup is not password protected");
+        };
+        let mut reader = decryptor
+            .decrypt(&Secret::new(password.to_string()), None)
+            .map_err(anyhow::Error::msg)?;
+        let mut plain = vec![];
+        reader.read_to_end(&mut plain)?;
+        let snapshot: Snapshot = bincode::deserialize(&plain)?;
+        if snapshot.schema_version < SCHEMA_VERSION {
+            anyhow::bail!(
+                "Backup schema version {} is older than the wallet ({})",
+                snapshot.schema_version,
+                SCHEMA_VERSION
+            );
+        }
+        if snapshot.schema_version > SCHEMA_VERSION {
+            anyhow::bail!(
+                "Backup schema version {} is newer than the wallet ({})",
+                snapshot.schema_version,
+                SCHEMA_VERSION
+            );
+        }
+        if snapshot.key_id != key_id {
+            anyhow::bail!("Snapshot #{} does not match its frame", snapshot.sequence);
+        }
+        if snapshot.network != network_name(network) {
+            anyhow::bail!("Backup is for the {} network", snapshot.network);
+        }
+        if snapshots.is_empty() && snapshot.incremental {
+            anyhow::bail!("Backup must start with a full snapshot");
+        }
+        if !snapshots.is_empty() && !snapshot.incremental {
+            anyhow::bail!("Unexpected full snapshot #{}", snapshot.sequence);
+        }
+        snapshots.push(snapshot);
+    }
+    let Some(last) = snapshots.last() else {
+        anyhow::bail!("Empty backup file");
+    };
+    let config = last.config.clone();
+
+    let db_tx = connection.transaction()?;
+    for snapshot in snapshots {
+        for table in snapshot.tables {
+            restore_table(&db_tx, snapshot.incremental, table)?;
+        }
+    }
+    // the next backup must be a full snapshot
+    db_tx.execute("DELETE FROM backup_state", [])?;
+    db_tx.execute("DELETE FROM backup_snapshots", [])?;
+    audit(&db_tx, DB_DECRYPT, 0, path)?;
+    db_tx.commit()?;
+    Ok(config)
+}
+
+/// Key id of the first frame of a backup file, if there is one
+fn read_key_id(path: &str) -> Option<Hash> {
+    let mut file = File::open(path).ok()?;
+    let mut header = [0u8; FRAME_HEADER_LEN];
+    file.read_exact(&mut header).ok()?;
+    header[4..].try_into().ok()
+}
+
+fn list_tables(connection: &Connection) -> Result<Vec<String>> {
+    let mut s = connection.prepare(
+        "SELECT name FROM sqlite_master
+        WHERE type = 'table' AND name NOT LIKE 'sqlite_%' ORDER BY name",
+    )?;
+    let rows = s.query_map([], |r| r.get::<_, String>(0))?;
+    let mut tables = vec![];
+    for r in rows {
+        let name = r?;
+        if !EXCLUDED_TABLES.contains(&name.as_str()) {
+            tables.push(name);
+        }
+    }
+    Ok(tables)
+}
+
+/// Column that identifies a row (the INTEGER PRIMARY KEY or the rowid)
+/// and the other columns
+fn table_columns(connection: &Connection, table: &str) -> Result<(String, Vec<String>)> {
+    let mut s = connection.prepare(&format!("PRAGMA table_info({table})"))?;
+    let rows = s.query_map([], |r| {
+        Ok((
+            r.get::<_, String>(1)?,
+            r.get::<_, String>(2)?,
+            r.get::<_, u32>(5)?,
+        ))
+    })?;
+    let mut id_column = None;
+    let mut columns = vec![];
+    for r in rows {
+        let (name, data_type, pk) = r?;
+        if pk == 1 && data_type.eq_ignore_ascii_case("INTEGER") {
+            id_column = Some(name);
+        } else {
+            columns.push(name);
+        }
+    }
+    Ok((id_column.unwrap_or("rowid".to_string()), columns))
+}
+
+fn snapshot_table(connection: &Connection, table: &str) -> Result<TableSnapshot> {
+    let (id_column, columns) = table_columns(connection, table)?;
+
+    let mut previous = HashMap::<i64, Vec<u8>>::new();
+    let mut s = connection.prepare("SELECT id, hash FROM backup_state WHERE tbl = ?1")?;
+    let rows = s.query_map([table], |r| {
+        Ok((r.get::<_, i64>(0)?, r.get::<_, Vec<u8>>(1)?))
+    })?;
+    for r in rows {
+        let (id, hash) = r?;
+        previous.insert(id, hash);
+    }
+
+    let mut select = vec![id_column.clone()];
+    select.extend(columns.iter().cloned());
+    let mut s = connection.prepare(&format!(
+        "SELECT {} FROM {table} ORDER BY 1",
+        select.join(", ")
+    ))?;
+    let mut rs = s.query([])?;
+    let mut rows = vec![];
+    let mut current = HashSet::new();
+    let mut s_state = connection.prepare_cached(
+        "INSERT INTO backup_state(tbl, id, hash) VALUES (?1, ?2, ?3)
+        ON CONFLICT DO UPDATE SET hash = excluded.hash",
+    )?;
+    while let Some(r) = rs.next()? {
+        let id = r.get::<_, i64>(0)?;
+        let mut values = vec![];
+        for i in 0..columns.len() {
+            values.push(BackupValue::from(r.get::<_, Value>(i + 1)?));
+        }
+        let hash: Hash = Sha256::digest(&bincode::serialize(&values)?).into();
+        current.insert(id);
+        if previous.get(&id).map(|h| &h[..]) != Some(&hash[..]) {
+            s_state.execute(params![table, id, &hash[..]])?;
+            rows.push((id, values));
+        }
+    }
+
+    let mut deleted = previous
+        .into_keys()
+        .filter(|id| !current.contains(id))
+        .collect::<Vec<_>>();
+    deleted.sort();
+    let mut s_delete =
+        connection.prepare_cached("DELETE FROM backup_state WHERE tbl = ?1 AND id = ?2")?;
+    for id in deleted.iter() {
+        s_delete.execute(params![table, id])?;
+    }
+
+    Ok(TableSnapshot {
+        name: table.to_string(),
+        id_column,
+        columns,
+        rows,
+        deleted,
+    })
+}
+
+fn restore_table(connection: &Connection, incremental: bool, table: TableSnapshot) -> Result<()> {
+    let TableSnapshot {
+        name,
+        id_column,
+        columns,
+        rows,
+        deleted,
+    } = table;
+    if !incremental {
+        connection.execute(&format!("DELETE FROM {name}"), [])?;
+    }
+    let mut s_delete = connection.prepare(&format!("DELETE FROM {name} WHERE {id_column} = ?1"))?;
+    for id in deleted {
+        s_delete.execute([id])?;
+    }
+    let mut insert_columns = vec![id_column];
+    insert_columns.extend(columns);
+    let placeholders = (1..=insert_columns.len())
+        .map(|i| format!("?{i}"))
+        .collect::<Vec<_>>();
+    let mut s_insert = connection.prepare(&format!(
+        "INSERT OR REPLACE INTO {name}({}) VALUES ({})",
+        insert_columns.join(", "),
+        placeholders.join(", ")
+    ))?;
+    for (id, values) in rows {
+        let mut row = vec![Value::Integer(id)];
+        row.extend(values.into_iter().map(Value::from));
+        s_insert.execute(params_from_iter(row))?;
+    }
+    Ok(())
+}
@@ -1,16 +1,26 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use anyhow::Result;
 use rusqlite::{Connection, DropBehavior};
-use zcash_protocol::consensus::{NetworkUpgrade, Parameters};
+use serde::{Deserialize, Serialize};
+use zcash_protocol::consensus::{BlockHeight, BranchId, NetworkUpgrade, Parameters};
 
 use crate::{
+    data::fb::{ChainStatusT, UpgradeStatus, UpgradeStatusT},
     db::{
         account_manager::get_min_birth,
-        chain::{store_block, truncate_scan},
+        api_keys::{check_access, Operation},
+        chain::{
+            get_block_header, get_cached_checkpoint, get_frontier_at, get_local_chain_status,
+            get_sync_height, list_headers, store_block, store_frontiers, store_headers,
+            truncate_scan,
+        },
     },
-    lwd::{get_compact_block, get_last_height},
+    lwd::{get_compact_block, get_last_height, get_server_branch_id, get_tree_state},
     network::Network,
-    warp::BlockHeader,
-    Client,
+    types::CheckpointHeight,
+    warp::{legacy::CommitmentTreeFrontier, BlockHeader},
+    Client, Hash,
 };
 
 use warp_macros::c_export;
@@ -24,6 +34,16 @@ pub async fn get_activation_date(network: &Network, client: &mut Client) -> Resu
 }
 
 const SEC_PER_DAY: u32 = 24 * 60 * 60;
+const NETWORK_UPGRADES: [NetworkUpgrade; 7] = [
+    NetworkUpgrade::Overwinter,
+    NetworkUpgrade::Sapling,
+    NetworkUpgrade::Blossom,
+    NetworkUpgrade::Heartwood,
+    NetworkUpgrade::Canopy,
+    NetworkUpgrade::Nu5,
+    NetworkUpgrade::Nu6,
+];
+const MAX_CACHED_CHECKPOINT_DISTANCE: u32 = 10_000;
 
 #[c_export]
 pub async fn get_height_by_time(network: &Network, client: &mut Client, time: u32) -> Result<u32> {
@@ -52,6 +72,106 @@ pub async fn get_height_by_time(network: &Network, client: &mut Client, time: u3
     Ok(s)
 }
 
+/// Blocks remaining until the next network upgrade known by the wallet.
+/// `next_upgrade` is empty when no upgrade is scheduled
+#[c_export]
+pub async fn get_upgrade_status(network: &Network, client: &mut Client) -> Result<UpgradeStatusT> {
+    let (height, server_branch_id) = get_server_branch_id(client).await?;
+    let next_height = BlockHeight::from_u32(height + 1);
+    let branch_id = BranchId::for_height(network, next_height);
+    let next_upgrade = NETWORK_UPGRADES.iter().find_map(|nu| {
+        network
+            .activation_height(*nu)
+            .filter(|h| *h > next_height)
+            .map(|h| (*nu, u32::from(h)))
+    });
+    let status = match next_upgrade {
+        Some((nu, activation_height)) => UpgradeStatusT {
+            height,
+            branch_id: branch_id.into(),
+            server_branch_id,
+            next_upgrade: Some(nu.to_string()),
+            activation_height,
+            blocks_remaining: activation_height - height,
+        },
+        None => UpgradeStatusT {
+            height,
+            branch_id: branch_id.into(),
+            server_branch_id,
+            next_upgrade: Some(String::new()),
+            ..UpgradeStatusT::default()
+        },
+    };
+    Ok(status)
+}
+
+/// Check that the server follows the same consensus rules as the wallet
+/// for the next block. Returns the current height
+pub async fn check_server_branch_id(network: &Network, client: &mut Client) -> Result<u32> {
+    let (height, server_branch_id) = get_server_branch_id(client).await?;
+    let branch_id: u32 = BranchId::for_height(network, BlockHeight::from_u32(height + 1)).into();
+    if branch_id != server_branch_id {
+        anyhow::bail!(
+            "Server consensus branch {:08x} does not match the wallet's {:08x}. \
+            The wallet or the server needs to be updated",
+            server_branch_id,
+            branch_id
+        );
+    }
+    Ok(height)
+}
+
+/*
+    Chain status
+
+    How far behind the wallet is, for a "N minutes behind" indicator:
+    - the sync height is the last checkpoint of the wallet,
+    - the server height is the tip reported by lightwalletd,
+    - the estimated height extrapolates the sync height with the
+    time since its block, at one block every 75 s. It does not
+    need the server and shows when the server itself is stuck
+    (`server_lag`), but it drifts by a few blocks over a day.
+    The lag is counted from the server height, or from the estimate
+    when the server cannot be reached. The status also has the
+    duration and the throughput of the last sync that committed
+    blocks.
+*/
+
+/// Target spacing of the blocks since Blossom
+const BLOCK_TIME: u32 = 75;
+
+#[c_export]
+pub async fn get_chain_status(
+    connection: &Connection,
+    client: &mut Client,
+) -> Result<ChainStatusT> {
+    let mut status = get_local_chain_status(connection)?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as u32;
+    let server_height = get_last_height(client).await;
+    if status.sync_timestamp != 0 {
+        status.estimated_height =
+            status.sync_height + now.saturating_sub(status.sync_timestamp) / BLOCK_TIME;
+    }
+    let tip = match server_height {
+        Ok(height) => {
+            status.server_ok = true;
+            status.server_height = height;
+            status.server_lag = status.estimated_height.saturating_sub(height);
+            if status.estimated_height == 0 {
+                status.estimated_height = height;
+            }
+            height
+        }
+        Err(e) => {
+            status.server_error = Some(e.to_string());
+            status.estimated_height
+        }
+    };
+    status.lag = tip.saturating_sub(status.sync_height);
+    status.lag_minutes = status.lag * BLOCK_TIME / 60;
+    Ok(status)
+}
+
 #[c_export]
 pub fn get_activation_height(network: &Network) -> Result<u32> {
     let h = network.activation_height(NetworkUpgrade::Sapling).unwrap();
@@ -87,9 +207,211 @@ pub async fn reset_chain(
         .into();
     let min_birth_height = get_min_birth(&connection)?.unwrap_or(activation);
     let height = height.unwrap_or(min_birth_height).max(activation);
-    let block = get_compact_block(client, height).await?;
+    // Start from a cached checkpoint if there is one close enough
+    let bh = match get_cached_checkpoint(connection, height)? {
+        Some(bh) if height - bh.height <= MAX_CACHED_CHECKPOINT_DISTANCE => bh,
+        _ => {
+            let block = get_compact_block(client, height).await?;
+            BlockHeader::from(&block)
+        }
+    };
+    check_hard_checkpoint(network, &bh)?;
+    start_chain(connection, bh)
+}
+
+fn start_chain(connection: &mut Connection, bh: BlockHeader) -> Result<()> {
     let mut transaction = connection.transaction()?;
     transaction.set_drop_behavior(DropBehavior::Commit);
-    store_block(&transaction, &BlockHeader::from(&block))?;
+    store_block(&transaction, &bh)?;
+    // the header chain starts here
+    store_headers(&transaction, &[bh], &[])?;
     Ok(())
 }
+
+/*
+    Portable checkpoints
+
+    A checkpoint is exported in the JSON format of the treestate
+    of zcashd and zebrad (`z_gettreestate`): the block hash in
+    display order (reversed) and the serialized commitment tree
+    of each pool in `finalState`.
+
+    An imported checkpoint goes to the frontier cache. Then
+    `reset_chain` at its height starts from it without asking
+    the server for the tree state. The checkpoint must come from
+    a trusted source: the wallet cannot check the frontiers.
+    It is only checked against the hard checkpoints.
+*/
+
+#[derive(Serialize, Deserialize)]
+struct TreeStateJson {
+    hash: String,
+    height: u32,
+    time: u32,
+    sapling: PoolTreeStateJson,
+    orchard: PoolTreeStateJson,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct PoolTreeStateJson {
+    commitments: CommitmentsJson,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct CommitmentsJson {
+    #[serde(rename = "finalState", default)]
+    final_state: String,
+}
+
+/// Export the cached checkpoint at or before `height` as a treestate
+#[c_export]
+pub fn export_checkpoint(connection: &Connection, height: u32) -> Result<String> {
+    let bh = get_cached_checkpoint(connection, height)?
+        .ok_or(anyhow::anyhow!("No checkpoint at or before {}", height))?;
+    let (sapling, orchard) = get_frontier_at(connection, bh.height)?
+        .ok_or(anyhow::anyhow!("No tree state at {}", bh.height))?;
+    fn encode_tree(tree: &CommitmentTreeFrontier) -> Result<PoolTreeStateJson> {
+        let mut data = vec![];
+        tree.write(&mut data)?;
+        Ok(PoolTreeStateJson {
+            commitments: CommitmentsJson {
+                final_state: hex::encode(data),
+            },
+        })
+    }
+    let mut hash = bh.hash;
+    hash.reverse();
+    let tree_state = TreeStateJson {
+        hash: hex::encode(hash),
+        height: bh.height,
+        time: bh.timestamp,
+        sapling: encode_tree(&sapling)?,
+        orchard: encode_tree(&orchard)?,
+    };
+    Ok(serde_json::to_string_pretty(&tree_state)?)
+}
+
+/// Import a treestate into the frontier cache and, with `reset`,
+/// restart the scan from it. Returns the height of the checkpoint
+#[c_export]
+pub fn import_checkpoint(
+    network: &Network,
+    connection: &mut Connection,
+    session: u32,
+    tree_state: &str,
+    reset: bool,
+) -> Result<u32> {
+    check_access(connection, session, &Operation::Admin)?;
+    let tree_state: TreeStateJson = serde_json::from_str(tree_state)?;
+    let mut hash: Hash = hex::decode(&tree_state.hash)?
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Invalid block hash"))?;
+    hash.reverse();
+    fn decode_tree(tree: &PoolTreeStateJson) -> Result<CommitmentTreeFrontier> {
+        let s = &tree.commitments.final_state;
+        if s.is_empty() {
+            return Ok(CommitmentTreeFrontier::default());
+        }
+        let data = hex::decode(s)?;
+        Ok(CommitmentTreeFrontier::read(&*data)?)
+    }
+    let sapling = decode_tree(&tree_state.sapling)?;
+    let orchard = decode_tree(&tree_state.orchard)?;
+    let bh = BlockHeader {
+        height: tree_state.height,
+        hash,
+        // not in the treestate, only the hash links the next block
+        prev_hash: [0u8; 32],
+        timestamp: tree_state.time,
+    };
+    check_hard_checkpoint(network, &bh)?;
+    store_frontiers(connection, &bh, &sapling, &orchard)?;
+    if reset {
+        truncate_scan(connection)?;
+        start_chain(connection, bh)?;
+    }
+    Ok(tree_state.height)
+}
+
+/// Tree state at a checkpoint, from the frontier cache or
+/// from the server. The tree state from the server is cached
+/// with the header of the checkpoint, a rewind drops it
+pub async fn get_cached_tree_state(
+    connection: &Connection,
+    client: &mut Client,
+    height: CheckpointHeight,
+) -> Result<(CommitmentTreeFrontier, CommitmentTreeFrontier)> {
+    if let Some(frontiers) = get_frontier_at(connection, height.into())? {
+        return Ok(frontiers);
+    }
+    let (sapling, orchard) = get_tree_state(client, height).await?;
+    if let Ok(bh) = get_block_header(connection, height.into()) {
+        store_frontiers(connection, &bh, &sapling, &orchard)?;
+    }
+    Ok((sapling, orchard))
+}
+
+/// Check a block against the hard checkpoints.
+/// Returns true if it is one of them
+pub fn check_hard_checkpoint(network: &Network, bh: &BlockHeader) -> Result<bool> {
+    match network.hard_checkpoint(bh.height) {
+        Some(hash) if hash != bh.hash => {
+            anyhow::bail!("Block {} does not match the hard checkpoint", bh.height)
+        }
+        Some(_) => Ok(true),
+        None => Ok(false),
+    }
+}
+
+/// Check the header chain against the hard checkpoints and the server,
+/// to detect a server that equivocates.
+/// Returns the height of the last hard checkpoint of the chain,
+/// or 0 if the chain starts after all of them
+#[c_export]
+pub async fn verify_chain(
+    network: &Network,
+    connection: &Connection,
+    client: &mut Client,
+) -> Result<u32> {
+    let headers = list_headers(connection)?;
+    let Some((last, _)) = headers.last() else {
+        anyhow::bail!("No header chain");
+    };
+    let tip = get_sync_height(connection)?;
+    if last.height != tip.height || tip.hash.as_deref() != Some(&last.hash[..]) {
+        anyhow::bail!("The header chain does not end at the sync height {}", tip.height);
+    }
+
+    let mut anchor_height = 0;
+    for (bh, _) in headers.iter() {
+        if check_hard_checkpoint(network, bh)? {
+            anchor_height = bh.height;
+        }
+    }
+    // the recent headers must link
+    for w in headers.windows(2) {
+        let (prev, bh) = (&w[0].0, &w[1].0);
+        if bh.height == prev.height + 1 && bh.prev_hash != prev.hash {
+            anyhow::bail!("The header chain is broken at {}", bh.height);
+        }
+    }
+
+    // the server must be on the chain of the hard checkpoints...
+    for (height, hash) in network.hard_checkpoints() {
+        if height > tip.height {
+            break;
+        }
+        let block = get_compact_block(client, height).await?;
+        if block.hash != hash {
+            anyhow::bail!("The server is not on the chain of the hard checkpoint {}", height);
+        }
+    }
+    // ...and on ours
+    if let Some((bh, _)) = headers.iter().find(|(_, anchor)| !anchor) {
+        let block = get_compact_block(client, bh.height).await?;
+        if block.hash != bh.hash {
+            anyhow::bail!("The server has another block at {}", bh.height);
+        }
+    }
+    Ok(anchor_height)
+}
@@ -1,8 +1,11 @@
 use anyhow::Result;
+use bip39::Mnemonic;
 use raptorq::{Decoder, Encoder, EncodingPacket, ObjectTransmissionInformation};
+use sha2::{Digest as _, Sha256};
 
 use crate::data::fb::{PacketT, Packets, PacketsT};
 use crate::fb_unwrap;
+use crate::keys::{parse_mnemonic, MNEMONIC_LANGUAGES};
 use warp_macros::c_export;
 
 const QR_DATA_SIZE: u16 = 256;
@@ -43,3 +46,238 @@ pub fn merge(parts: &PacketsT) -> Result<Vec<u8>> {
     let data = decoder.get_result();
     Ok(data.unwrap_or_default())
 }
+
+/*
+    Seed shares
+
+    A seed phrase can be split into `count` shares so that any
+    `threshold` of them recover it, and fewer reveal nothing useful
+    (Shamir secret sharing over GF(256), byte by byte). The secret is
+    the index of the language of the phrase in MNEMONIC_LANGUAGES
+    followed by the entropy of the mnemonic. A share is small enough
+    for one QR code:
+    - SEED_SHARE_VERSION (1 byte),
+    - the id of the secret: the first 4 bytes of its SHA-256,
+    - the threshold and the index of the share (1 byte each),
+    - the value of the polynomials at the index (the length of the secret),
+    - a checksum: the first 4 bytes of the SHA-256 of the rest.
+
+    The coefficients of the polynomials are derived from the secret and
+    the threshold, so splitting the same phrase again gives the same
+    shares and a lost share can be made again. The entropy of a seed
+    phrase is at least 128 bits, which keeps the secret out of reach
+    of a search from fewer than `threshold` shares.
+
+    When they are merged, the id checks that the shares come from the
+    same secret and that the recovered secret is the right one.
+*/
+
+pub const SEED_SHARE_VERSION: u8 = 1;
+pub const MAX_SEED_SHARES: u32 = 16;
+
+const SHARE_HEADER_SIZE: usize = 1 + 4 + 2;
+const SHARE_CHECKSUM_SIZE: usize = 4;
+const SHARE_DOMAIN: &[u8] = b"ZcashWarp_SeedShare";
+
+/// Shares of a seed phrase, `len` is the length of the secret
+#[c_export]
+pub fn split_seed_phrase(phrase: &str, threshold: u32, count: u32) -> Result<Vec<PacketT>> {
+    if threshold < 2 || threshold > count || count > MAX_SEED_SHARES {
+        anyhow::bail!(
+            "The threshold must be at least 2 and at most the number of shares (up to {})",
+            MAX_SEED_SHARES
+        );
+    }
+    let (mnemonic, code) = parse_mnemonic(phrase)?;
+    let language = MNEMONIC_LANGUAGES
+        .iter()
+        .position(|(_, c)| *c == code)
+        .ok_or(anyhow::anyhow!("Unsupported seed phrase language"))?;
+    let mut secret = vec![language as u8];
+    secret.extend_from_slice(mnemonic.entropy());
+    let id = &Sha256::digest(&secret)[..4];
+
+    // coefficients[k][i] is the coefficient of degree k+1 for the byte i
+    let coefficients = (1..threshold as u8)
+        .map(|k| share_coefficients(&secret, threshold as u8, k))
+        .collect::<Vec<_>>();
+    let packets = (1..=count as u8)
+        .map(|x| {
+            let mut share = vec![SEED_SHARE_VERSION];
+            share.extend_from_slice(id);
+            share.push(threshold as u8);
+            share.push(x);
+            for (i, s) in secret.iter().enumerate() {
+                // Horner's method
+                let mut y = 0u8;
+                for c in coefficients.iter().rev() {
+                    y = gf_mul(y, x) ^ c[i];
+                }
+                y = gf_mul(y, x) ^ s;
+                share.push(y);
+            }
+            let checksum = Sha256::digest(&share);
+            share.extend_from_slice(&checksum[..SHARE_CHECKSUM_SIZE]);
+            PacketT {
+                data: Some(share),
+                len: secret.len() as u32,
+            }
+        })
+        .collect::<Vec<_>>();
+    Ok(packets)
+}
+
+/// Seed phrase of at least `threshold` shares
+#[c_export]
+pub fn merge_seed_phrase(parts: &PacketsT) -> Result<String> {
+    let mut shares: Vec<(&[u8], u8, u8, &[u8])> = vec![];
+    for p in fb_unwrap!(parts.packets).iter() {
+        let share = fb_unwrap!(p.data);
+        if share.len() <= SHARE_HEADER_SIZE + SHARE_CHECKSUM_SIZE {
+            anyhow::bail!("Invalid seed share");
+        }
+        let (share, checksum) = share.split_at(share.len() - SHARE_CHECKSUM_SIZE);
+        if &Sha256::digest(share)[..SHARE_CHECKSUM_SIZE] != checksum {
+            anyhow::bail!("Invalid seed share checksum");
+        }
+        if share[0] != SEED_SHARE_VERSION {
+            anyhow::bail!("Unsupported seed share version {}", share[0]);
+        }
+        let (id, threshold, x) = (&share[1..5], share[5], share[6]);
+        let y = &share[SHARE_HEADER_SIZE..];
+        if let Some((id0, threshold0, _, y0)) = shares.first() {
+            if id != *id0 || threshold != *threshold0 || y.len() != y0.len() {
+                anyhow::bail!("The seed shares are from different seeds");
+            }
+        }
+        if x == 0 {
+            anyhow::bail!("Invalid seed share index");
+        }
+        if shares.iter().all(|(_, _, x0, _)| *x0 != x) {
+            shares.push((id, threshold, x, y));
+        }
+    }
+    let Some((id, threshold, _, y0)) = shares.first().cloned() else {
+        anyhow::bail!("No seed share");
+    };
+    if shares.len() < threshold as usize {
+        anyhow::bail!(
+            "{} seed shares are needed, there are {}",
+            threshold,
+            shares.len()
+        );
+    }
+    let shares = &shares[..threshold as usize];
+
+    // Lagrange interpolation at 0
+    let mut secret = vec![0u8; y0.len()];
+    for (i, (_, _, xi, yi)) in shares.iter().enumerate() {
+        let mut l = 1u8;
+        for (j, (_, _, xj, _)) in shares.iter().enumerate() {
+            if i != j {
+                l = gf_mul(l, gf_mul(*xj, gf_inv(xj ^ xi)));
+            }
+        }
+        for (s, y) in secret.iter_mut().zip(yi.iter()) {
+            *s ^= gf_mul(l, *y);
+        }
+    }
+    if &Sha256::digest(&secret)[..4] != id {
+        anyhow::bail!("The seed shares do not match");
+    }
+    let (language, _) = MNEMONIC_LANGUAGES
+        .get(secret[0] as usize)
+        .ok_or(anyhow::anyhow!("Invalid seed share language"))?;
+    let mnemonic = Mnemonic::from_entropy(&secret[1..], *language)?;
+    Ok(mnemonic.into_phrase())
+}
+
+fn share_coefficients(secret: &[u8], threshold: u8, k: u8) -> Vec<u8> {
+    let mut coefficients = vec![];
+    let mut block = 0u8;
+    while coefficients.len() < secret.len() {
+        let mut h = Sha256::new();
+        h.update(SHARE_DOMAIN);
+        h.update([threshold, k, block]);
+        h.update(secret);
+        coefficients.extend_from_slice(&h.finalize());
+        block += 1;
+    }
+    coefficients.truncate(secret.len());
+    coefficients
+}
+
+/// Multiplication in GF(256) with the polynomial of AES
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut p = 0u8;
+    while b != 0 {
+        if b & 1 != 0 {
+            p ^= a;
+        }
+        let carry = a & 0x80 != 0;
+        a <<= 1;
+        if carry {
+            a ^= 0x1B;
+        }
+        b >>= 1;
+    }
+    p
+}
+
+/// a^254 = a^-1
+fn gf_inv(a: u8) -> u8 {
+    let mut r = 1u8;
+    for _ in 0..254 {
+        r = gf_mul(r, a);
+    }
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use sha2::{Digest as _, Sha256};
+
+    use super::{merge_seed_phrase, split_seed_phrase, MAX_SEED_SHARES, SHARE_CHECKSUM_SIZE};
+    use crate::data::fb::{PacketT, PacketsT};
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art";
+
+    fn merge(shares: &[PacketT]) -> anyhow::Result<String> {
+        merge_seed_phrase(&PacketsT {
+            packets: Some(shares.to_vec()),
+        })
+    }
+
+    #[test]
+    fn shares_round_trip() {
+        for threshold in 2..=MAX_SEED_SHARES {
+            let shares = split_seed_phrase(PHRASE, threshold, MAX_SEED_SHARES).unwrap();
+            let t = threshold as usize;
+            assert_eq!(merge(&shares[..t]).unwrap(), PHRASE);
+            assert_eq!(merge(&shares[shares.len() - t..]).unwrap(), PHRASE);
+        }
+    }
+
+    #[test]
+    fn tampered_share() {
+        let mut shares = split_seed_phrase(PHRASE, 3, 5).unwrap();
+        let share = shares[1].data.as_mut().unwrap();
+        share[8] ^= 1;
+        assert!(merge(&shares[..3]).is_err());
+
+        // with a valid checksum, the id of the secret does not match
+        let len = share.len() - SHARE_CHECKSUM_SIZE;
+        let checksum = Sha256::digest(&share[..len]);
+        share[len..].copy_from_slice(&checksum[..SHARE_CHECKSUM_SIZE]);
+        assert!(merge(&shares[..3]).is_err());
+    }
+
+    #[test]
+    fn too_few_shares() {
+        let shares = split_seed_phrase(PHRASE, 3, 5).unwrap();
+        assert!(merge(&shares[..2]).is_err());
+        // a duplicate share does not count twice
+        assert!(merge(&[shares[0].clone(), shares[1].clone(), shares[1].clone()]).is_err());
+        assert!(merge(&[]).is_err());
+    }
+}
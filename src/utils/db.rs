@@ -3,11 +3,19 @@ use crate::network::Network;
 use anyhow::Result;
 use rusqlite::{Connection, OptionalExtension as _};
 
-use crate::account::address::get_diversified_address;
-use crate::{data::fb::BackupT, db::account::get_account_info, types::PoolMask};
+use crate::account::address::{get_diversified_address, rotate_used_address};
+use crate::{
+    data::fb::BackupT,
+    db::{
+        account::get_account_info,
+        api_keys::{check_access, Operation},
+        audit::{audit, DB_DECRYPT, KEY_EXPORT, SEED_DISPLAY},
+    },
+    types::PoolMask,
+};
 
 use crate::{
-    coin::COINS,
+    coin::{CoinDef, COINS},
     ffi::{map_result, CResult},
 };
 use std::ffi::{c_char, CStr};
@@ -27,7 +35,17 @@ pub fn check_db_password(path: &str, password: &str) -> Result<u8> {
 }
 
 #[c_export]
-pub fn encrypt_db(connection: &Connection, password: &str, new_db_path: &str) -> Result<()> {
+pub fn encrypt_db(
+    connection: &Connection,
+    session: u32,
+    password: &str,
+    new_db_path: &str,
+) -> Result<()> {
+    check_access(connection, session, &Operation::Admin)?;
+    // without a password, the copy is a plain text database
+    if password.is_empty() {
+        audit(connection, DB_DECRYPT, 0, new_db_path)?;
+    }
     connection.execute(
         "ATTACH DATABASE ?1 AS encrypted_db KEY ?2",
         [new_db_path, password],
@@ -38,14 +56,29 @@ pub fn encrypt_db(connection: &Connection, password: &str, new_db_path: &str) ->
 }
 
 #[c_export]
-pub fn create_backup(network: &Network, connection: &Connection, account: u32) -> Result<BackupT> {
+pub fn create_backup(
+    network: &Network,
+    connection: &Connection,
+    session: u32,
+    account: u32,
+) -> Result<BackupT> {
+    check_access(connection, session, &Operation::Admin)?;
     let ai = get_account_info(network, &connection, account)?;
     let backup = ai.to_backup(network);
+    let operation = if backup.seed.is_some() {
+        SEED_DISPLAY
+    } else {
+        KEY_EXPORT
+    };
+    audit(connection, operation, account, "backup")?;
     Ok(backup)
 }
 
+/// If the config has `rotate_used_address`, the account address
+/// moves to the next diversifier once it has received funds
 #[c_export]
 pub fn get_address(
+    coin: &CoinDef,
     network: &Network,
     connection: &Connection,
     account: u32,
@@ -55,6 +88,9 @@ pub fn get_address(
     let address = if mask & 8 != 0 {
         get_diversified_address(network, connection, account, time, PoolMask(mask))?
     } else {
+        if coin.config.rotate_used_address {
+            rotate_used_address(network, connection, account)?;
+        }
         let ai = get_account_info(network, &connection, account)?;
         ai.to_address(network, PoolMask(mask))
     };
@@ -77,9 +113,30 @@ pub extern "C" fn c_set_db_path_password(
     map_result(res())
 }
 
+/// Open the db without writing to it, see `CoinDef::open_read_only`
+#[no_mangle]
+pub extern "C" fn c_open_read_only(
+    coin: u8,
+    path: *mut c_char,
+    password: *mut c_char,
+) -> CResult<u8> {
+    let res = || {
+        let path = unsafe { CStr::from_ptr(path).to_string_lossy() };
+        let password = unsafe { CStr::from_ptr(password).to_string_lossy() };
+        let mut coin = COINS[coin as usize].lock();
+        coin.open_read_only(&path, &password)?;
+        Ok::<_, anyhow::Error>(0)
+    };
+    map_result(res())
+}
+
+/// Version of the db schema, bumped with every change of its tables.
+/// A backup restores only into a db of the same version
+pub const SCHEMA_VERSION: u32 = 3;
+
 #[no_mangle]
 pub extern "C" fn c_schema_version() -> u32 {
-    2
+    SCHEMA_VERSION
 }
 
 #[c_export]
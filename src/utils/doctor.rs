@@ -0,0 +1,225 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+use rusqlite::Connection;
+use tonic::Request;
+
+use crate::{
+    coin::CoinDef,
+    data::fb::{HealthCheckT, HealthReport, HealthReportT},
+    db::chain::{get_cached_checkpoint, get_frontier_at},
+    lwd::{
+        get_compact_block, get_tree_state,
+        rpc::{Empty, LightdInfo},
+    },
+    pay::PROVER,
+    types::CheckpointHeight,
+    warp::hasher::{OrchardHasher, SaplingHasher},
+    Client,
+};
+
+use super::db::SCHEMA_VERSION;
+use warp_macros::c_export;
+
+/*
+    Wallet health check
+
+    Runs a series of independent checks on the database, the
+    prover parameters and the server, and reports every one of them,
+    so that users can attach the report to a bug report.
+    A failing check does not stop the others. The report has no
+    key, address or amount.
+*/
+
+/// Maximum difference between the time of the last block
+/// of the server and the local clock
+const MAX_CLOCK_SKEW: u32 = 3600;
+
+/// Tables that have an `account` column
+const ACCOUNT_TABLES: &[&str] = &[
+    "t_accounts",
+    "t_addresses",
+    "s_accounts",
+    "o_accounts",
+    "txs",
+    "notes",
+    "note_spends",
+    "witnesses",
+    "utxos",
+    "utxo_spends",
+];
+
+#[c_export]
+pub async fn run_health_check(coin: &CoinDef) -> Result<HealthReportT> {
+    let mut checks = vec![];
+    match coin.read_connection() {
+        Ok(connection) => {
+            checks.push(to_check("database", check_integrity(&connection)));
+            checks.push(to_check("schema", check_schema(&connection)));
+            checks.push(to_check("orphans", check_orphans(&connection)));
+        }
+        Err(e) => checks.push(to_check("database", Err(e))),
+    }
+    checks.push(to_check("prover", check_prover()));
+
+    match coin.connect_lwd() {
+        Ok(mut client) => {
+            let info = get_server_info(&mut client).await;
+            let tip = info.as_ref().map(|i| i.block_height as u32).ok();
+            checks.push(to_check(
+                "server",
+                info.map(|i| {
+                    format!(
+                        "{} {} ({}) at height {}",
+                        i.vendor, i.version, i.chain_name, i.block_height
+                    )
+                }),
+            ));
+            if let Some(tip) = tip {
+                checks.push(to_check("clock", check_clock(&mut client, tip).await));
+            }
+            if let Ok(connection) = coin.read_connection() {
+                checks.push(to_check(
+                    "tree_state",
+                    check_tree_state(&connection, &mut client).await,
+                ));
+            }
+        }
+        Err(e) => checks.push(to_check("server", Err(e))),
+    }
+
+    let ok = checks.iter().all(|c| c.ok);
+    Ok(HealthReportT {
+        version: Some(env!("CARGO_PKG_VERSION").to_string()),
+        coin: coin.coin,
+        timestamp: now(),
+        ok,
+        checks: Some(checks),
+    })
+}
+
+fn to_check(name: &str, res: Result<String>) -> HealthCheckT {
+    let (ok, details) = match res {
+        Ok(details) => (true, details),
+        Err(e) => (false, e.to_string()),
+    };
+    HealthCheckT {
+        name: Some(name.to_string()),
+        ok,
+        details: Some(details),
+    }
+}
+
+fn check_integrity(connection: &Connection) -> Result<String> {
+    let mut s = connection.prepare("PRAGMA integrity_check")?;
+    let rows = s.query_map([], |r| r.get::<_, String>(0))?;
+    let errors = rows.collect::<Result<Vec<_>, _>>()?;
+    if errors != ["ok"] {
+        anyhow::bail!("{}", errors.join("; "));
+    }
+    Ok("ok".to_string())
+}
+
+fn check_schema(connection: &Connection) -> Result<String> {
+    let missing = ACCOUNT_TABLES
+        .iter()
+        .chain(["accounts", "blcks", "frontiers"].iter())
+        .filter(|&&table| {
+            connection
+                .query_row(
+                    "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?1",
+                    [table],
+                    |_| Ok(()),
+                )
+                .is_err()
+        })
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        anyhow::bail!("Schema {}: missing tables {:?}", SCHEMA_VERSION, missing);
+    }
+    Ok(format!("Schema {}", SCHEMA_VERSION))
+}
+
+/// Rows that belong to an account that does not exist
+fn check_orphans(connection: &Connection) -> Result<String> {
+    let mut orphans = vec![];
+    for table in ACCOUNT_TABLES {
+        let count = connection.query_row(
+            &format!(
+                "SELECT COUNT(*) FROM {table} t
+                WHERE NOT EXISTS (SELECT 1 FROM accounts a WHERE a.id_account = t.account)"
+            ),
+            [],
+            |r| r.get::<_, u32>(0),
+        )?;
+        if count > 0 {
+            orphans.push(format!("{table}: {count}"));
+        }
+    }
+    if !orphans.is_empty() {
+        anyhow::bail!("Orphaned rows: {}", orphans.join(", "));
+    }
+    Ok("No orphaned rows".to_string())
+}
+
+fn check_prover() -> Result<String> {
+    if PROVER.lock().is_none() {
+        anyhow::bail!("Sapling parameters not found");
+    }
+    Ok("Sapling parameters loaded".to_string())
+}
+
+async fn get_server_info(client: &mut Client) -> Result<LightdInfo> {
+    let info = client
+        .get_lightd_info(Request::new(Empty {}))
+        .await?
+        .into_inner();
+    Ok(info)
+}
+
+/// Compare the time of the last block with the local clock
+async fn check_clock(client: &mut Client, tip: u32) -> Result<String> {
+    let block = get_compact_block(client, tip).await?;
+    let now = now();
+    let skew = now as i64 - block.time as i64;
+    if skew.unsigned_abs() > MAX_CLOCK_SKEW as u64 {
+        anyhow::bail!(
+            "Local clock is {}s {} the last block",
+            skew.abs(),
+            if skew > 0 { "ahead of" } else { "behind" }
+        );
+    }
+    Ok(format!("{}s", skew))
+}
+
+/// Compare the cached tree state of the last checkpoint
+/// with the tree state of the server
+async fn check_tree_state(connection: &Connection, client: &mut Client) -> Result<String> {
+    let Some(cp) = get_cached_checkpoint(connection, u32::MAX)? else {
+        return Ok("No checkpoint".to_string());
+    };
+    let (s_tree, o_tree) = get_frontier_at(connection, cp.height)?
+        .ok_or(anyhow::anyhow!("No tree state at {}", cp.height))?;
+    let (server_s_tree, server_o_tree) =
+        get_tree_state(client, CheckpointHeight(cp.height)).await?;
+    let sap_hasher = SaplingHasher::default();
+    let orch_hasher = OrchardHasher::default();
+    if s_tree.to_edge(&sap_hasher).root(&sap_hasher)
+        != server_s_tree.to_edge(&sap_hasher).root(&sap_hasher)
+    {
+        anyhow::bail!("Sapling root does not match the server at {}", cp.height);
+    }
+    if o_tree.to_edge(&orch_hasher).root(&orch_hasher)
+        != server_o_tree.to_edge(&orch_hasher).root(&orch_hasher)
+    {
+        anyhow::bail!("Orchard root does not match the server at {}", cp.height);
+    }
+    Ok(format!("Roots match at {}", cp.height))
+}
+
+fn now() -> u32 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as u32)
+        .unwrap_or_default()
+}
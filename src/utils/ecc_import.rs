@@ -0,0 +1,147 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+use rusqlite::{Connection, OpenFlags};
+
+use crate::{
+    db::{
+        account_manager::{
+            account_fingerprints, detect_key, list_account_fingerprints, store_account_keys,
+        },
+        api_keys::{check_access, Operation},
+    },
+    keys::AccountKeys,
+    network::Network,
+};
+use warp_macros::c_export;
+
+/*
+    Import of a zcash_client_sqlite wallet
+
+    The ECC SDKs keep their wallet in a zcash_client_sqlite database.
+    It has the unified full viewing keys and the birth heights of
+    the accounts but not the seed, which stays in the key store of
+    the app. The import reads:
+    - the accounts, with their ZIP-32 index, UFVK, birth height and
+    name (in the recent schemas),
+    - nothing else: their notes have witnesses in a shard tree that
+    warp does not use, so the wallet must be rescanned from the
+    birth height of the new accounts.
+
+    When the app passes the seed phrase, the accounts derived from it
+    whose keys match the UFVK get the seed and can spend. The others
+    are imported as viewing key accounts. Accounts that are already
+    in the wallet and accounts without a UFVK are skipped.
+*/
+
+struct EccAccount {
+    aindex: Option<u32>,
+    name: Option<String>,
+    ufvk: String,
+    birth: u32,
+}
+
+/// Returns the number of accounts imported
+#[c_export]
+pub fn import_ecc_wallet(
+    network: &Network,
+    connection: &mut Connection,
+    session: u32,
+    path: &str,
+    seed: &str,
+    passphrase: &str,
+) -> Result<u32> {
+    check_access(connection, session, &Operation::Admin)?;
+    let ecc = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    let accounts = list_ecc_accounts(&ecc)?;
+
+    let has_passphrase = !seed.is_empty() && !passphrase.is_empty();
+    let db_tx = connection.transaction()?;
+    let known = list_account_fingerprints(&db_tx)?;
+    let mut imported = 0;
+    for (i, a) in accounts.iter().enumerate() {
+        let vk = match detect_key(network, &a.ufvk, "", 0) {
+            Ok(vk) => vk,
+            Err(e) => {
+                tracing::warn!("Account #{} has an invalid UFVK: {}", i, e);
+                continue;
+            }
+        };
+        let fingerprints = account_fingerprints(network, &vk, 6)?;
+        if fingerprints.iter().any(|fp| known.contains_key(fp)) {
+            tracing::info!("Account #{} is already in the wallet", i);
+            continue;
+        }
+        let aindex = a.aindex.unwrap_or_default();
+        let ak = match a.aindex {
+            Some(aindex) if !seed.is_empty() => {
+                let ak = AccountKeys::from_seed(network, seed, passphrase, aindex)?;
+                let seed_fingerprints = account_fingerprints(network, &ak, 6)?
+                    .into_iter()
+                    .collect::<HashSet<_>>();
+                if fingerprints.iter().any(|fp| seed_fingerprints.contains(fp)) {
+                    ak
+                } else {
+                    tracing::warn!("Account #{} is not derived from the seed", i);
+                    vk
+                }
+            }
+            _ => vk,
+        };
+        let name = a
+            .name
+            .clone()
+            .filter(|n| !n.is_empty())
+            .unwrap_or_else(|| format!("Account {aindex}"));
+        store_account_keys(
+            network,
+            &db_tx,
+            &name,
+            &ak,
+            has_passphrase && ak.seed.is_some(),
+            aindex,
+            a.birth,
+            7,
+            false,
+        )?;
+        imported += 1;
+    }
+    db_tx.commit()?;
+    Ok(imported)
+}
+
+/// The accounts table of zcash_client_sqlite, before and after
+/// the support of imported keys (hd_account_index)
+fn list_ecc_accounts(ecc: &Connection) -> Result<Vec<EccAccount>> {
+    let mut s = ecc.prepare("SELECT name FROM pragma_table_info('accounts')")?;
+    let columns = s
+        .query_map([], |r| r.get::<_, String>(0))?
+        .collect::<Result<HashSet<_>, _>>()?;
+    let sql = if columns.contains("hd_account_index") {
+        let name = if columns.contains("name") {
+            "name"
+        } else {
+            "NULL"
+        };
+        format!(
+            "SELECT hd_account_index, {name}, ufvk, birthday_height
+            FROM accounts WHERE ufvk IS NOT NULL ORDER BY id"
+        )
+    } else if columns.contains("ufvk") {
+        "SELECT account, NULL, ufvk, birthday_height
+        FROM accounts ORDER BY account"
+            .to_string()
+    } else {
+        anyhow::bail!("Unsupported zcash_client_sqlite database");
+    };
+    let mut s = ecc.prepare(&sql)?;
+    let rows = s.query_map([], |r| {
+        Ok(EccAccount {
+            aindex: r.get::<_, Option<u32>>(0)?,
+            name: r.get::<_, Option<String>>(1)?,
+            ufvk: r.get::<_, String>(2)?,
+            birth: r.get::<_, u32>(3)?,
+        })
+    })?;
+    Ok(rows.collect::<Result<Vec<_>, _>>()?)
+}
@@ -10,7 +10,11 @@ use zip32::DiversifierIndex;
 
 use crate::{
     data::fb::ZIP32KeysT,
-    db::account::get_account_info,
+    db::{
+        account_manager::get_seed_phrase,
+        api_keys::{check_access, Operation},
+        audit::{audit, KEY_EXPORT},
+    },
     keys::{export_sk_bip38, AccountKeys},
     network::Network,
     types::TransparentAccountInfo,
@@ -21,16 +25,22 @@ use warp_macros::c_export;
 pub fn derive_zip32_keys(
     network: &Network,
     connection: &Connection,
+    session: u32,
     account: u32,
+    passphrase: &str,
     acc_index: u32,
     addr_index: u32,
     use_default: bool,
 ) -> Result<ZIP32KeysT> {
-    let ai = get_account_info(network, connection, account)?;
-    let Some(phrase) = ai.seed else {
-        anyhow::bail!("No Seed")
-    };
-    let ak = AccountKeys::from_seed(network, &phrase, acc_index)?;
+    check_access(connection, session, &Operation::Admin)?;
+    let (phrase, passphrase) = get_seed_phrase(network, connection, account, passphrase)?;
+    let ak = AccountKeys::from_seed(network, &phrase, &passphrase, acc_index)?;
+    audit(
+        connection,
+        KEY_EXPORT,
+        account,
+        &format!("zip32 {acc_index}/{addr_index}"),
+    )?;
     let tsk = ak.txsk.as_ref().map(|txsk| {
         let address_index = NonHardenedChildIndex::from_index(addr_index).unwrap();
         let sk = txsk.derive_external_secret_key(address_index).unwrap();
@@ -1,33 +1,129 @@
 use anyhow::Result;
 use rand::rngs::OsRng;
 use rusqlite::Connection;
+use std::time::{SystemTime, UNIX_EPOCH};
+use zcash_primitives::{
+    consensus::{BlockHeight, BranchId},
+    transaction::Transaction,
+};
 use zcash_protocol::memo::{Memo, MemoBytes};
 
 use crate::{
-    account::contacts::commit_unsaved_contacts, coin::CoinDef, data::fb::{
+    account::{
+        address::change_address_account,
+        contacts::{make_contacts_payment, make_group_payment},
+        lookalike::check_lookalike_address,
+    },
+    coin::CoinDef,
+    data::fb::{
         PaymentRequest, PaymentRequestT, RecipientT, TransactionBytes, TransactionBytesT,
-        TransactionSummary, TransactionSummaryT,
-    }, db::{
-        account::get_account_info, chain::snap_to_checkpoint, notes::mark_notes_unconfirmed_spent,
-    }, fb_unwrap, lwd::{broadcast, get_last_height, get_tree_state}, network::Network, pay::{make_payment, UnsignedTransaction}, Client, PooledSQLConnection, EXPIRATION_HEIGHT_DELTA
+        TransactionReview, TransactionReviewT, TransactionSummary, TransactionSummaryT,
+    },
+    db::{
+        account::{get_account_info, resolve_snap_policy},
+        api_keys::{
+            broadcast_operation, check_access, check_sign_access, payment_amount, Operation,
+        },
+        audit::{audit, BROADCAST},
+        chain::{get_sync_height, snap_with_policy, SNAP_ACCOUNT_DEFAULT},
+        draft::get_draft,
+        notes::mark_notes_unconfirmed_spent,
+        tx::store_broadcast_tx,
+    },
+    fb_unwrap,
+    lwd::{broadcast, get_server_branch_id},
+    network::Network,
+    pay::{
+        make_payment, make_sponsored_payment,
+        review::review_signed_transaction,
+        tex::{make_tex_disclosure, make_tex_payment, store_tex_change},
+        Error, UnsignedTransaction,
+    },
+    types::PoolMask,
+    utils::{
+        chain::{check_server_branch_id, get_cached_tree_state},
+        ua::check_address_expiry,
+        uri::{parse_payment_uri, signature::verify_payment_uri},
+    },
+    warp::{legacy::CommitmentTreeFrontier, sync::witnesses::rebuild_witnesses},
+    Client, Hash, PooledSQLConnection, EXPIRATION_HEIGHT_DELTA,
 };
 
 use warp_macros::c_export;
 
 pub(crate) const COST_PER_ACTION: u64 = 5_000;
+/// Largest transaction accepted by the mempool of zcashd
+pub const MAX_TX_SIZE: u32 = 100_000;
+/// Most orchard actions (with their proof) that fit in MAX_TX_SIZE
+pub const MAX_ORCHARD_ACTIONS: u32 = 30;
+/// Used when the config does not have `max_decoy_fee`
+pub const DEFAULT_MAX_DECOY_FEE: u64 = 2 * COST_PER_ACTION;
+/// Used when the config does not have `max_change_fee`, the cap on
+/// the extra fee of splitting the change into several notes
+pub const DEFAULT_MAX_CHANGE_FEE: u64 = 2 * COST_PER_ACTION;
+/// Fees up to this amount are never more than `max_fee_percent`, so that
+/// small payments and memos are not refused
+pub const MIN_CHECKED_FEE: u64 = 10 * COST_PER_ACTION;
+
+/// Limits of the fee of a payment, from the config. 0 is no limit
+#[derive(Clone, Copy, Default, Debug)]
+pub struct FeeLimits {
+    /// Percentage of the amount sent to the other recipients.
+    /// It does not apply to fees up to MIN_CHECKED_FEE
+    pub max_percent: u32,
+    pub max_fee: u64,
+}
 
+/// How a payment is built, mostly from the config (see `PaymentOptions::new`)
+#[derive(Clone, Default, Debug)]
+pub struct PaymentOptions {
+    /// Cap on the fee of the decoy outputs, 0 for no decoys
+    pub max_decoy_fee: u64,
+    /// Cap on the extra fee of splitting the change, 0 for no split
+    pub max_change_fee: u64,
+    pub fee_limits: FeeLimits,
+    /// Anchor of the spends (see `snap_with_policy`)
+    pub snap_policy: u8,
+    /// Address that receives the outputs instead of the recipients
+    pub redirect: Option<String>,
+}
+
+impl PaymentOptions {
+    /// Options of the config of the coin. An empty `redirect` keeps the recipients
+    pub fn new(coin: &CoinDef, snap_policy: u8, redirect: &str) -> Self {
+        PaymentOptions {
+            max_decoy_fee: max_decoy_fee(coin),
+            max_change_fee: max_change_fee(coin),
+            fee_limits: fee_limits(coin),
+            snap_policy,
+            redirect: Some(redirect.to_string()).filter(|r| !r.is_empty()),
+        }
+    }
+}
+
+/// `snap_policy` selects the anchor of the spends (see `snap_with_policy`)
 #[c_export]
 pub async fn prepare_payment(
     coin: &CoinDef,
+    session: u32,
     account: u32,
     payment: &PaymentRequestT,
     redirect: &str,
+    snap_policy: u8,
 ) -> Result<TransactionSummaryT> {
     let connection = coin.connection()?;
+    let amount = payment_amount(payment);
+    check_access(&connection, session, &Operation::Pay { account, amount })?;
     let mut client = coin.connect_lwd()?;
-    prepare_payment_inner(&coin.network, connection, &mut client, account,
-        payment, redirect,
-    ).await
+    prepare_payment_inner(
+        &coin.network,
+        connection,
+        &mut client,
+        account,
+        payment,
+        &PaymentOptions::new(coin, snap_policy, redirect),
+    )
+    .await
 }
 
 pub async fn prepare_payment_inner(
@@ -36,11 +132,135 @@ pub async fn prepare_payment_inner(
     client: &mut Client,
     account: u32,
     payment: &PaymentRequestT,
+    options: &PaymentOptions,
+) -> Result<TransactionSummaryT> {
+    let snap_policy = resolve_snap_policy(&connection, account, options.snap_policy)?;
+    let requested_height = payment.height;
+    let (payment, s_tree, o_tree) =
+        check_payment(network, &connection, client, account, payment, snap_policy).await?;
+    let unsigned_tx = make_payment(
+        network,
+        &connection,
+        account,
+        &payment,
+        &s_tree,
+        &o_tree,
+        options,
+    )?;
+    let mut summary = unsigned_tx.to_summary()?;
+    check_fee(options.fee_limits, &payment, &summary)?;
+    summary.requested_height = requested_height;
+    summary.snap_policy = snap_policy;
+    Ok(summary)
+}
+
+/// Prepare the payment of a payment uri at the current sync height.
+/// If the uri is signed, the summary has the verification status
+/// of the request and the address of the requester
+#[c_export]
+pub async fn prepare_payment_uri(
+    coin: &CoinDef,
+    session: u32,
+    account: u32,
+    uri: &str,
     redirect: &str,
 ) -> Result<TransactionSummaryT> {
+    let connection = coin.connection()?;
+    let mut client = coin.connect_lwd()?;
+    let request = verify_payment_uri(&coin.network, &connection, account, uri)?;
+    let height = get_sync_height(&connection)?.height;
+    let payment = parse_payment_uri(&coin.network, uri, height, height + EXPIRATION_HEIGHT_DELTA)?;
+    let amount = payment_amount(&payment);
+    check_access(&connection, session, &Operation::Pay { account, amount })?;
+    let mut summary = prepare_payment_inner(
+        &coin.network,
+        connection,
+        &mut client,
+        account,
+        &payment,
+        &PaymentOptions::new(coin, SNAP_ACCOUNT_DEFAULT, redirect),
+    )
+    .await?;
+    summary.request_status = request.status;
+    summary.requester = request.requester;
+    Ok(summary)
+}
+
+/// Pay with the funds of `account` and the fee from the funds of `sponsor`.
+/// If the sponsor has a UTXO that covers the fee, it is an input
+/// of the payment. Otherwise, there are two transactions: the payment,
+/// and a transaction from the sponsor that reimburses the fee to `account`
+#[c_export]
+pub async fn prepare_sponsored_payment(
+    coin: &CoinDef,
+    session: u32,
+    account: u32,
+    sponsor: u32,
+    payment: &PaymentRequestT,
+    redirect: &str,
+) -> Result<Vec<TransactionSummaryT>> {
+    let connection = coin.connection()?;
+    let amount = payment_amount(payment);
+    check_access(&connection, session, &Operation::Pay { account, amount })?;
+    check_access(
+        &connection,
+        session,
+        &Operation::Pay {
+            account: sponsor,
+            amount: 0,
+        },
+    )?;
+    let mut client = coin.connect_lwd()?;
+    let options = PaymentOptions::new(coin, SNAP_ACCOUNT_DEFAULT, redirect);
+    let snap_policy = resolve_snap_policy(&connection, account, options.snap_policy)?;
+    let (payment, s_tree, o_tree) = check_payment(
+        &coin.network,
+        &connection,
+        &mut client,
+        account,
+        payment,
+        snap_policy,
+    )
+    .await?;
+    let unsigned_txs = make_sponsored_payment(
+        &coin.network,
+        &connection,
+        account,
+        sponsor,
+        &payment,
+        &s_tree,
+        &o_tree,
+        &options,
+    )?;
+    let summaries = unsigned_txs
+        .iter()
+        .map(|utx| utx.to_summary())
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    for summary in summaries.iter() {
+        check_fee(options.fee_limits, &payment, summary)?;
+    }
+    Ok(summaries)
+}
+
+/// Checks common to every payment. Returns the payment at
+/// the checkpoint height with the tree states of the checkpoint
+pub(crate) async fn check_payment(
+    network: &Network,
+    connection: &Connection,
+    client: &mut Client,
+    account: u32,
+    payment: &PaymentRequestT,
+    snap_policy: u8,
+) -> Result<(
+    PaymentRequestT,
+    CommitmentTreeFrontier,
+    CommitmentTreeFrontier,
+)> {
     tracing::info!("{:?}", payment);
-    let cp_height = snap_to_checkpoint(&connection, payment.height)?;
-    let (s_tree, o_tree) = get_tree_state(client, cp_height).await?;
+    check_server_branch_id(network, client).await?;
+    let cp_height = snap_with_policy(connection, payment.height, snap_policy)?;
+    rebuild_witnesses(connection, client, cp_height).await?;
+    let (s_tree, o_tree) = get_cached_tree_state(connection, client, cp_height).await?;
     let recipients = payment
         .recipients
         .as_ref()
@@ -48,6 +268,26 @@ pub async fn prepare_payment_inner(
         .iter()
         .map(|r| r.normalize_memo())
         .collect::<Result<Vec<_>>>()?;
+    let expiration_height = payment.expiration.max(cp_height.0);
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    for r in recipients.iter() {
+        let address = fb_unwrap!(r.address);
+        check_address_expiry(address, expiration_height, now)?;
+        if !payment.allow_change_address {
+            if let Some(a) = change_address_account(network, connection, address)? {
+                return Err(Error::ChangeAddress(address.clone(), a).into());
+            }
+        }
+        if !payment.allow_lookalike_address {
+            if let Some(w) = check_lookalike_address(connection, account, address)?.first() {
+                return Err(Error::LookalikeAddress(
+                    address.clone(),
+                    w.similar.clone().unwrap_or_default(),
+                )
+                .into());
+            }
+        }
+    }
     let payment = PaymentRequestT {
         recipients: Some(recipients),
         src_pools: payment.src_pools,
@@ -55,22 +295,289 @@ pub async fn prepare_payment_inner(
         use_change: payment.use_change,
         height: cp_height.0,
         expiration: payment.expiration,
+        decoy_outputs: payment.decoy_outputs,
+        from_address: payment.from_address.clone(),
+        allow_high_fee: payment.allow_high_fee,
+        change_outputs: payment.change_outputs,
+        allow_change_address: payment.allow_change_address,
+        allow_lookalike_address: payment.allow_lookalike_address,
     };
-    let redirect = if redirect.is_empty() {
-        None
-    } else {
-        Some(redirect.to_string())
+    Ok((payment, s_tree, o_tree))
+}
+
+/// Plan a payment that the wallet makes on its own (acknowledgments,
+/// messages, TEX change sweeps) with the checks of the payments of the user
+pub(crate) async fn plan_payment(
+    network: &Network,
+    connection: &Connection,
+    client: &mut Client,
+    account: u32,
+    payment: &PaymentRequestT,
+    options: &PaymentOptions,
+) -> Result<UnsignedTransaction> {
+    let snap_policy = resolve_snap_policy(connection, account, options.snap_policy)?;
+    let (payment, s_tree, o_tree) =
+        check_payment(network, connection, client, account, payment, snap_policy).await?;
+    let utx = make_payment(
+        network, connection, account, &payment, &s_tree, &o_tree, options,
+    )?;
+    check_fee(options.fee_limits, &payment, &utx.to_summary()?)?;
+    Ok(utx)
+}
+
+/// Pay every member of a contact group, at the current sync height.
+/// With `split`, the members share `amount`, otherwise each of them gets `amount`
+#[c_export]
+pub async fn pay_group(
+    coin: &CoinDef,
+    session: u32,
+    account: u32,
+    group: u32,
+    amount: u64,
+    split: bool,
+    redirect: &str,
+) -> Result<TransactionSummaryT> {
+    let connection = coin.connection()?;
+    let mut client = coin.connect_lwd()?;
+    let height = get_sync_height(&connection)?.height;
+    let payment = make_group_payment(&connection, account, group, amount, split, height)?;
+    let amount = payment_amount(&payment);
+    check_access(&connection, session, &Operation::Pay { account, amount })?;
+    prepare_payment_inner(
+        &coin.network,
+        connection,
+        &mut client,
+        account,
+        &payment,
+        &PaymentOptions::new(coin, SNAP_ACCOUNT_DEFAULT, redirect),
+    )
+    .await
+}
+
+/// Move `amount` to another account of the wallet, at the current sync height.
+/// The recipient is the address of `dst_account` restricted to the pools of
+/// `pools` that it has. Once synced, the txs of both accounts are tagged
+/// as a transfer and left out of the spending statistics
+#[c_export]
+pub async fn transfer(
+    coin: &CoinDef,
+    session: u32,
+    src_account: u32,
+    dst_account: u32,
+    amount: u64,
+    pools: u8,
+) -> Result<TransactionSummaryT> {
+    if src_account == dst_account {
+        anyhow::bail!("Cannot transfer to the same account");
+    }
+    let connection = coin.connection()?;
+    check_access(
+        &connection,
+        session,
+        &Operation::Pay {
+            account: src_account,
+            amount,
+        },
+    )?;
+    let mut client = coin.connect_lwd()?;
+    let ai = get_account_info(&coin.network, &connection, dst_account)?;
+    let pools = pools & ai.to_mask();
+    let address = ai
+        .to_address(&coin.network, PoolMask(pools))
+        .ok_or(anyhow::anyhow!(
+            "Account {} has no address in pools {}",
+            dst_account,
+            pools
+        ))?;
+    let height = get_sync_height(&connection)?.height;
+    let recipient = RecipientT {
+        address: Some(address),
+        amount,
+        pools,
+        memo: None,
+        memo_bytes: None,
+        src_pools: 0,
     };
-    let unsigned_tx = make_payment(
+    let payment = PaymentRequestT {
+        recipients: Some(vec![recipient]),
+        src_pools: 7,
+        sender_pay_fees: true,
+        use_change: true,
+        height,
+        expiration: height + EXPIRATION_HEIGHT_DELTA,
+        decoy_outputs: 0,
+        from_address: None,
+        allow_high_fee: false,
+        change_outputs: 0,
+        allow_change_address: false,
+        allow_lookalike_address: false,
+    };
+    prepare_payment_inner(
+        &coin.network,
+        connection,
+        &mut client,
+        src_account,
+        &payment,
+        &PaymentOptions::new(coin, SNAP_ACCOUNT_DEFAULT, ""),
+    )
+    .await
+}
+
+fn max_decoy_fee(coin: &CoinDef) -> u64 {
+    match coin.config.max_decoy_fee {
+        0 => DEFAULT_MAX_DECOY_FEE,
+        fee => fee,
+    }
+}
+
+fn max_change_fee(coin: &CoinDef) -> u64 {
+    match coin.config.max_change_fee {
+        0 => DEFAULT_MAX_CHANGE_FEE,
+        fee => fee,
+    }
+}
+
+fn fee_limits(coin: &CoinDef) -> FeeLimits {
+    FeeLimits {
+        max_percent: coin.config.max_fee_percent,
+        max_fee: coin.config.max_fee,
+    }
+}
+
+/// Refuse a transaction whose fee is above the limits,
+/// unless the payment has `allow_high_fee`
+pub fn check_fee(
+    limits: FeeLimits,
+    payment: &PaymentRequestT,
+    summary: &TransactionSummaryT,
+) -> Result<(), Error> {
+    if payment.allow_high_fee {
+        return Ok(());
+    }
+    let fee = summary.fee;
+    if limits.max_fee > 0 && fee > limits.max_fee {
+        return Err(Error::FeeAboveMaximum(fee, limits.max_fee));
+    }
+    if limits.max_percent > 0 && fee > MIN_CHECKED_FEE {
+        let sent = summary
+            .recipients
+            .iter()
+            .flatten()
+            .filter(|r| !r.change)
+            .map(|r| r.amount)
+            .sum::<u64>();
+        if fee as u128 * 100 > sent as u128 * limits.max_percent as u128 {
+            return Err(Error::FeeAbovePercent(fee, limits.max_percent, sent));
+        }
+    }
+    Ok(())
+}
+
+/// Prepare a transaction from a saved draft, at the current sync height
+#[c_export]
+pub async fn prepare_draft(
+    coin: &CoinDef,
+    session: u32,
+    id: u32,
+    redirect: &str,
+) -> Result<TransactionSummaryT> {
+    let connection = coin.connection()?;
+    let mut client = coin.connect_lwd()?;
+    let draft = get_draft(&connection, id)?;
+    let mut payment = *fb_unwrap!(draft.payment).clone();
+    let (account, amount) = (draft.account, payment_amount(&payment));
+    check_access(&connection, session, &Operation::Pay { account, amount })?;
+    payment.height = get_sync_height(&connection)?.height;
+    prepare_payment_inner(
+        &coin.network,
+        connection,
+        &mut client,
+        draft.account,
+        &payment,
+        &PaymentOptions::new(coin, SNAP_ACCOUNT_DEFAULT, redirect),
+    )
+    .await
+}
+
+/// First step of a payment to several TEX addresses. It pays the other
+/// recipients and funds a transparent output for the TEX recipients
+#[c_export]
+pub async fn prepare_tex_payment(
+    coin: &CoinDef,
+    session: u32,
+    account: u32,
+    payment: &PaymentRequestT,
+    redirect: &str,
+) -> Result<TransactionSummaryT> {
+    let network = &coin.network;
+    let connection = coin.connection()?;
+    let amount = payment_amount(payment);
+    check_access(&connection, session, &Operation::Pay { account, amount })?;
+    let mut client = coin.connect_lwd()?;
+    let options = PaymentOptions::new(coin, SNAP_ACCOUNT_DEFAULT, redirect);
+    let snap_policy = resolve_snap_policy(&connection, account, options.snap_policy)?;
+    let (payment, s_tree, o_tree) = check_payment(
+        network,
+        &connection,
+        &mut client,
+        account,
+        payment,
+        snap_policy,
+    )
+    .await?;
+    let unsigned_tx = make_tex_payment(
         network,
         &connection,
         account,
         &payment,
         &s_tree,
         &o_tree,
-        redirect,
+        &options,
     )?;
     let summary = unsigned_tx.to_summary()?;
+    check_fee(options.fee_limits, &payment, &summary)?;
+    Ok(summary)
+}
+
+/// Second step of a payment to several TEX addresses. It spends the
+/// output of the signed first transaction and pays every TEX recipient
+#[c_export]
+pub async fn prepare_tex_disclosure(
+    coin: &CoinDef,
+    session: u32,
+    account: u32,
+    payment: &PaymentRequestT,
+    txbytes: &TransactionBytesT,
+    redirect: &str,
+) -> Result<TransactionSummaryT> {
+    let network = &coin.network;
+    let connection = coin.connection()?;
+    let amount = payment_amount(payment);
+    check_access(&connection, session, &Operation::Pay { account, amount })?;
+    let mut client = coin.connect_lwd()?;
+    let options = PaymentOptions::new(coin, SNAP_ACCOUNT_DEFAULT, redirect);
+    let snap_policy = resolve_snap_policy(&connection, account, options.snap_policy)?;
+    let (payment, s_tree, o_tree) = check_payment(
+        network,
+        &connection,
+        &mut client,
+        account,
+        payment,
+        snap_policy,
+    )
+    .await?;
+    let unsigned_tx = make_tex_disclosure(
+        network,
+        &connection,
+        account,
+        &payment,
+        fb_unwrap!(txbytes.data),
+        &s_tree,
+        &o_tree,
+        options.redirect,
+    )?;
+    let summary = unsigned_tx.to_summary()?;
+    check_fee(options.fee_limits, &payment, &summary)?;
     Ok(summary)
 }
 
@@ -121,11 +628,13 @@ pub fn can_sign(
 pub fn sign(
     network: &Network,
     connection: &Connection,
+    session: u32,
     summary: &TransactionSummaryT,
     expiration_height: u32,
 ) -> Result<TransactionBytesT> {
     let data = fb_unwrap!(summary.data);
     let unsigned_tx = bincode::deserialize_from::<_, UnsignedTransaction>(&data[..])?;
+    check_sign_access(network, connection, session, &unsigned_tx)?;
     let txb = unsigned_tx.build(network, connection, expiration_height, OsRng)?;
     tracing::info!("TXBLen {}", txb.data.as_ref().unwrap().len());
     Ok(txb)
@@ -133,40 +642,115 @@ pub fn sign(
 
 #[c_export]
 pub async fn tx_broadcast(
+    network: &Network,
     connection: &Connection,
     client: &mut Client,
+    session: u32,
     txbytes: &TransactionBytesT,
 ) -> Result<String> {
-    let bc_height = get_last_height(client).await?;
+    check_access(
+        connection,
+        session,
+        &broadcast_operation(connection, txbytes)?,
+    )?;
+    broadcast_tx(network, connection, client, txbytes).await
+}
+
+/// Broadcast a transaction that the wallet has signed itself
+pub async fn broadcast_tx(
+    network: &Network,
+    connection: &Connection,
+    client: &mut Client,
+    txbytes: &TransactionBytesT,
+) -> Result<String> {
+    let (bc_height, server_branch_id) = get_server_branch_id(client).await?;
+    let data = fb_unwrap!(txbytes.data);
+    let branch_id = BranchId::for_height(network, BlockHeight::from_u32(bc_height + 1));
+    let tx = Transaction::read(&data[..], branch_id)?;
+    let tx_branch_id = u32::from(tx.consensus_branch_id());
+    if tx_branch_id != server_branch_id {
+        anyhow::bail!(
+            "Transaction was built for consensus branch {:08x} but the server is on {:08x}",
+            tx_branch_id,
+            server_branch_id
+        );
+    }
+    // the transaction can be mined until its own expiry height
+    let expiration = u32::from(tx.expiry_height());
     if let Some(id_notes) = txbytes.notes.as_deref() {
-        mark_notes_unconfirmed_spent(connection, id_notes, bc_height + EXPIRATION_HEIGHT_DELTA)?;
+        mark_notes_unconfirmed_spent(connection, id_notes, expiration)?;
     }
     let id = broadcast(client, bc_height, txbytes).await?;
+    audit(connection, BROADCAST, 0, &tx.txid().to_string())?;
+    if let Some(id_notes) = txbytes.notes.as_deref() {
+        let txid: Hash = tx.txid().as_ref().clone();
+        store_broadcast_tx(connection, &txid, id_notes, bc_height, expiration)?;
+    }
+    if let Some(address) = txbytes.tex_change.as_deref() {
+        store_tex_change(connection, tx.txid().as_ref(), address)?;
+    }
     Ok(id)
 }
 
 #[c_export]
-pub async fn save_contacts(
+pub fn review_transaction(
+    network: &Network,
+    connection: &Connection,
+    summary: &TransactionSummaryT,
+    txbytes: &TransactionBytesT,
+) -> Result<TransactionReviewT> {
+    let data = fb_unwrap!(txbytes.data);
+    review_signed_transaction(network, connection, summary, data)
+}
+
+#[c_export]
+pub async fn tx_broadcast_reviewed(
     network: &Network,
     connection: &Connection,
     client: &mut Client,
+    session: u32,
+    summary: &TransactionSummaryT,
+    txbytes: &TransactionBytesT,
+    force: bool,
+) -> Result<String> {
+    let review = review_transaction(network, connection, summary, txbytes)?;
+    if !review.is_ok() {
+        let mismatches = review.mismatches.as_deref().unwrap_or_default();
+        for m in mismatches.iter() {
+            tracing::warn!("{:?}", m);
+        }
+        if !force {
+            anyhow::bail!(
+                "Signed transaction does not match its summary ({} mismatches)",
+                mismatches.len()
+            );
+        }
+    }
+    tx_broadcast(network, connection, client, session, txbytes).await
+}
+
+/// Save the new contacts of the account in memos to itself
+#[c_export]
+pub async fn save_contacts(
+    coin: &CoinDef,
+    session: u32,
     account: u32,
     height: u32,
     redirect: &str,
 ) -> Result<TransactionSummaryT> {
-    let cp_height = snap_to_checkpoint(&connection, height)?;
-    let (s_tree, o_tree) = get_tree_state(client, cp_height).await?;
-    let unsigned_tx = commit_unsaved_contacts(
-        network,
-        &connection,
+    let connection = coin.connection()?;
+    check_access(&connection, session, &Operation::Pay { account, amount: 0 })?;
+    let mut client = coin.connect_lwd()?;
+    let payment = make_contacts_payment(&coin.network, &connection, account, 7, height)?;
+    prepare_payment_inner(
+        &coin.network,
+        connection,
+        &mut client,
         account,
-        7,
-        cp_height,
-        &s_tree,
-        &o_tree,
-        Some(redirect.to_string()),
-    )?;
-    unsigned_tx.to_summary().map_err(anyhow::Error::msg)
+        &payment,
+        &PaymentOptions::new(coin, SNAP_ACCOUNT_DEFAULT, redirect),
+    )
+    .await
 }
 
 impl RecipientT {
@@ -185,6 +769,7 @@ impl RecipientT {
             pools: self.pools,
             memo: None,
             memo_bytes: Some(memo.as_slice().to_vec()),
+            src_pools: self.src_pools,
         };
         Ok(r)
     }
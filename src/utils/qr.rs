@@ -0,0 +1,99 @@
+use std::io::Cursor;
+
+use anyhow::Result;
+use image::{ImageFormat, Luma};
+use qrcode::{render::svg, EcLevel, QrCode};
+use zcash_address::ZcashAddress;
+use zcash_protocol::{memo::MemoBytes, value::Zatoshis};
+
+use crate::{data::fb::PaymentRequestT, network::Network};
+
+use super::{ua::filter_address, uri::make_payment_uri};
+use warp_macros::c_export;
+
+/*
+    QR codes of payment URIs
+
+    `make_payment_qr` renders the ZIP-321 URI of a payment request
+    for the receive screens, as a PNG or an SVG image. Before the
+    encoding, it rejects what `make_payment_uri` would change
+    without a warning:
+    - the amounts must be at most 21M ZEC. The URI has them in
+    ZEC with 8 decimals, so every amount in zatoshis is exact,
+    - the memos must fit in 512 bytes and go to a shielded
+    receiver. The URI drops the memo of a transparent address.
+
+    The error correction level is L, M, Q or H (0 to 3). A higher
+    level survives more damage to the code but holds less data,
+    and a URI that is too long for the level is an error.
+*/
+
+pub const QR_PNG: u8 = 0;
+pub const QR_SVG: u8 = 1;
+
+/// Pixels per module of the PNG images
+const MODULE_SIZE: u32 = 8;
+
+#[c_export]
+pub fn make_payment_qr(
+    network: &Network,
+    payment: &PaymentRequestT,
+    format: u8,
+    ecc: u8,
+) -> Result<Vec<u8>> {
+    check_payment_uri(network, payment)?;
+    let uri = make_payment_uri(network, payment)?;
+    let level = match ecc {
+        0 => EcLevel::L,
+        1 => EcLevel::M,
+        2 => EcLevel::Q,
+        3 => EcLevel::H,
+        _ => anyhow::bail!("Invalid error correction level {}", ecc),
+    };
+    let code = QrCode::with_error_correction_level(uri.as_bytes(), level).map_err(|e| {
+        anyhow::anyhow!(
+            "The payment URI ({} bytes) does not fit in a QR code: {}",
+            uri.len(),
+            e
+        )
+    })?;
+    let image = match format {
+        QR_PNG => {
+            let image = code
+                .render::<Luma<u8>>()
+                .module_dimensions(MODULE_SIZE, MODULE_SIZE)
+                .build();
+            let mut png = Cursor::new(vec![]);
+            image.write_to(&mut png, ImageFormat::Png)?;
+            png.into_inner()
+        }
+        QR_SVG => code.render::<svg::Color>().build().into_bytes(),
+        _ => anyhow::bail!("Invalid image format {}", format),
+    };
+    Ok(image)
+}
+
+fn check_payment_uri(network: &Network, payment: &PaymentRequestT) -> Result<()> {
+    let recipients = payment.recipients.as_deref().unwrap_or_default();
+    if recipients.is_empty() {
+        anyhow::bail!("The payment has no recipient");
+    }
+    for r in recipients.iter() {
+        let address = r.address.as_deref().unwrap_or_default();
+        if Zatoshis::from_u64(r.amount).is_err() {
+            anyhow::bail!("The amount for {} is more than 21M ZEC", address);
+        }
+        let r = r
+            .normalize_memo()
+            .map_err(|e| anyhow::anyhow!("Invalid memo for {}: {}", address, e))?;
+        let memo = r.memo_bytes.unwrap_or_default();
+        if memo.as_slice() == MemoBytes::empty().as_slice() {
+            continue;
+        }
+        let address = filter_address(network, address, r.pools)?;
+        if !ZcashAddress::try_from_encoded(&address)?.can_receive_memo() {
+            anyhow::bail!("{} cannot receive a memo", address);
+        }
+    }
+    Ok(())
+}
@@ -1,30 +1,68 @@
 use crate::{coin::CoinDef, network::Network};
 use anyhow::Result;
+use parking_lot::Mutex;
 use rusqlite::Connection;
 
 use crate::{
+    account::lwd::connect_account_lwd,
     data::fb::TransactionInfoExtendedT,
-    db::tx::{get_txid, store_tx_details},
-    lwd::get_transaction,
-    txdetails::analyze_raw_transaction,
+    db::{
+        api_keys::{check_access, Operation},
+        tx::{
+            clear_tx_details_failure, get_tx_details_account, get_txid, has_tx_details,
+            store_tx_details_failure,
+        },
+    },
+    txdetails::retrieve_one_tx_details,
+    Hash,
 };
 
 use warp_macros::c_export;
 
+/// Details of a transaction, retrieved from the server
+/// the first time it is viewed
 #[c_export]
 pub async fn fetch_tx_details(
     coin: &CoinDef,
     network: &Network,
     connection: &Connection,
+    session: u32,
     account: u32,
     id: u32,
 ) -> Result<TransactionInfoExtendedT> {
-    let mut client = coin.connect_lwd()?;
+    check_access(connection, session, &Operation::Read { account })?;
+    if has_tx_details(connection, id)? {
+        let (_, tx) = get_tx_details_account(connection, id)?;
+        return Ok(tx.to_transaction_info_ext(network));
+    }
     let (txid, timestamp) = get_txid(&connection, id)?;
-    let (height, tx) = get_transaction(network, &mut client, &txid).await?;
-    let tx = analyze_raw_transaction(coin, network, &connection, account, height, timestamp, tx)?;
-    let txb = serde_cbor::to_vec(&tx)?;
-    store_tx_details(&connection, id, account, height, &tx.txid, &txb)?;
+    let txid: Hash = txid
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Invalid txid of tx {}", id))?;
+    let mut client = connect_account_lwd(coin, connection, account).await?;
+    let mutex = Mutex::new(connection);
+    let res = retrieve_one_tx_details(
+        coin,
+        network,
+        &mutex,
+        &mut client,
+        id,
+        account,
+        timestamp,
+        &txid,
+    )
+    .await;
+    // the background worker retries the failures (see `txdetails`)
+    let tx = match res {
+        Ok(tx) => {
+            clear_tx_details_failure(connection, id)?;
+            tx
+        }
+        Err(e) => {
+            store_tx_details_failure(connection, id, &e.to_string())?;
+            return Err(e);
+        }
+    };
     let etx = tx.to_transaction_info_ext(network);
     Ok(etx)
 }
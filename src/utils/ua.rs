@@ -8,10 +8,14 @@ use zcash_keys::{
 };
 use zcash_primitives::legacy::TransparentAddress;
 
-use crate::{data::fb::UAReceiversT, types::PoolMask};
+use crate::{data::fb::UAReceiversT, pay::Error, types::PoolMask};
 
 use warp_macros::c_export;
 
+pub mod diagnostic;
+
+use diagnostic::address_expiry;
+
 pub fn split_address(
     network: &Network,
     address: &str,
@@ -45,15 +49,33 @@ pub fn split_address(
 #[c_export]
 pub fn decode_address(network: &Network, address: &str) -> Result<UAReceiversT> {
     let (t, s, o, tex) = split_address(network, address)?;
+    let expiry = address_expiry(address).unwrap_or_default();
     let ua = UAReceiversT {
         tex,
         transparent: t.map(|t| t.encode(network)),
         sapling: s.map(|s| s.encode(network)),
         orchard: o.map(|o| ua_of_orchard(&o).encode(network)),
+        expiry_height: expiry.height.unwrap_or_default(),
+        expiry_time: expiry.time.unwrap_or_default(),
     };
     Ok(ua)
 }
 
+/// Refuse an address that expires before `expiration_height`
+/// or before `time`
+pub fn check_address_expiry(address: &str, expiration_height: u32, time: u64) -> Result<(), Error> {
+    let Some(expiry) = address_expiry(address) else {
+        return Ok(());
+    };
+    if let Some(height) = expiry.height.filter(|&h| h < expiration_height) {
+        return Err(Error::AddressExpiredHeight(address.to_string(), height));
+    }
+    if let Some(t) = expiry.time.filter(|&t| t <= time) {
+        return Err(Error::AddressExpiredTime(address.to_string(), t));
+    }
+    Ok(())
+}
+
 #[c_export]
 pub fn filter_address(network: &Network, address: &str, pool_mask: u8) -> Result<String> {
     let (t, s, o, _) = split_address(network, address)?;
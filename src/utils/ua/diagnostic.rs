@@ -0,0 +1,243 @@
+use anyhow::Result;
+use bech32::{primitives::decode::CheckedHrpstring, Bech32m};
+use f4jumble::f4jumble_inv;
+use rusqlite::{Connection, OptionalExtension as _};
+use sapling_crypto::PaymentAddress;
+use zcash_keys::{address::Address as RecipientAddress, encoding::AddressCodec as _};
+use zcash_primitives::legacy::TransparentAddress;
+
+use crate::{
+    data::fb::{AddressDiagnostic, AddressDiagnosticT, AddressReceiverT},
+    db::account::get_account_info,
+    fb_unwrap,
+    network::Network,
+};
+use warp_macros::c_export;
+
+/*
+    Address diagnostics
+
+    Breaks an address down to its receivers for display.
+    A unified address is the bech32m encoding of the F4Jumble of
+    its receivers (typecode, length, data) followed by a padding
+    made of its HRP. Revision 1 of ZIP 316 uses HRPs that start
+    with "ur". The diagnostic is built from the raw encoding, so
+    it also describes addresses that the wallet rejects, for
+    example because of an invalid padding or another network.
+
+    Every receiver is matched against the keys of the accounts.
+
+    Revision 1 addresses can also have metadata items, in particular
+    an expiry height (typecode 0xE0, u32 LE) and an expiry time
+    (typecode 0xE1, u64 LE, seconds since the epoch). A payment to
+    an expired address is refused (see `check_address_expiry`).
+*/
+
+const PADDING_LEN: usize = 16;
+const MIN_UA_LEN: usize = 48;
+pub const EXPIRY_HEIGHT_TYPECODE: u32 = 0xE0;
+pub const EXPIRY_TIME_TYPECODE: u32 = 0xE1;
+
+/// Expiry metadata of a unified address
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct AddressExpiry {
+    pub height: Option<u32>,
+    pub time: Option<u64>,
+}
+
+#[c_export]
+pub fn diagnose_address(
+    network: &Network,
+    connection: &Connection,
+    address: &str,
+) -> Result<AddressDiagnosticT> {
+    let mut diagnostic = AddressDiagnosticT {
+        address: Some(address.to_string()),
+        padding_valid: true,
+        ..AddressDiagnosticT::default()
+    };
+    let mut receivers = match RecipientAddress::decode(network, address) {
+        Some(RecipientAddress::Transparent(TransparentAddress::PublicKeyHash(pkh))) => {
+            vec![receiver(0, &pkh)]
+        }
+        Some(RecipientAddress::Transparent(TransparentAddress::ScriptHash(sh))) => {
+            vec![receiver(1, &sh)]
+        }
+        Some(RecipientAddress::Sapling(pa)) => vec![receiver(2, &pa.to_bytes())],
+        Some(RecipientAddress::Tex(pkh)) => {
+            diagnostic.tex = true;
+            vec![receiver(0, &pkh)]
+        }
+        _ => {
+            // unified or not valid for the wallet
+            let (hrp, items, padding_valid) = decode_unified(address)?;
+            diagnostic.unified = true;
+            diagnostic.version = if hrp.starts_with("ur") { 1 } else { 0 };
+            diagnostic.padding_valid = padding_valid;
+            diagnostic.hrp = Some(hrp);
+            parse_receivers(&items)?
+        }
+    };
+    for r in receivers.iter_mut() {
+        r.account = find_owner(network, connection, r)?;
+    }
+    diagnostic.owned = receivers.iter().any(|r| r.account != 0);
+    diagnostic.receivers = Some(receivers);
+    Ok(diagnostic)
+}
+
+/// HRP, items and validity of the padding of a unified address
+fn decode_unified(address: &str) -> Result<(String, Vec<u8>, bool)> {
+    let checked = CheckedHrpstring::new::<Bech32m>(address)
+        .map_err(|e| anyhow::anyhow!("Invalid address: {e}"))?;
+    let hrp = checked.hrp().to_lowercase();
+    let data = checked.byte_iter().collect::<Vec<_>>();
+    if data.len() < MIN_UA_LEN {
+        anyhow::bail!("Unified address too short");
+    }
+    let mut data = f4jumble_inv(&data).map_err(|e| anyhow::anyhow!("Invalid address: {e}"))?;
+    let padding = data.split_off(data.len() - PADDING_LEN);
+    let mut expected = [0u8; PADDING_LEN];
+    let n = hrp.len().min(PADDING_LEN);
+    expected[..n].copy_from_slice(&hrp.as_bytes()[..n]);
+    let padding_valid = hrp.len() <= PADDING_LEN && padding == expected;
+    Ok((hrp, data, padding_valid))
+}
+
+/// Expiry of a unified address, none if it is not a unified
+/// address or if it does not expire
+pub fn address_expiry(address: &str) -> Option<AddressExpiry> {
+    let (_, items, _) = decode_unified(address).ok()?;
+    let items = parse_receivers(&items).ok()?;
+    let mut expiry = AddressExpiry::default();
+    for item in items.iter() {
+        let data = item.data.as_deref().unwrap_or_default();
+        match item.typecode {
+            EXPIRY_HEIGHT_TYPECODE => {
+                expiry.height = data.try_into().ok().map(u32::from_le_bytes);
+            }
+            EXPIRY_TIME_TYPECODE => {
+                expiry.time = data.try_into().ok().map(u64::from_le_bytes);
+            }
+            _ => {}
+        }
+    }
+    if expiry == AddressExpiry::default() {
+        return None;
+    }
+    Some(expiry)
+}
+
+fn receiver(typecode: u32, data: &[u8]) -> AddressReceiverT {
+    let name = match typecode {
+        0 => "p2pkh",
+        1 => "p2sh",
+        2 => "sapling",
+        3 => "orchard",
+        EXPIRY_HEIGHT_TYPECODE => "expiry_height",
+        EXPIRY_TIME_TYPECODE => "expiry_time",
+        _ => "unknown",
+    };
+    AddressReceiverT {
+        typecode,
+        name: Some(name.to_string()),
+        data: Some(data.to_vec()),
+        account: 0,
+    }
+}
+
+fn parse_receivers(mut data: &[u8]) -> Result<Vec<AddressReceiverT>> {
+    let mut receivers = vec![];
+    while !data.is_empty() {
+        let typecode = read_compact_size(&mut data)?;
+        let len = read_compact_size(&mut data)? as usize;
+        if len > data.len() {
+            anyhow::bail!("Truncated receiver {typecode}");
+        }
+        let (value, rest) = data.split_at(len);
+        receivers.push(receiver(typecode as u32, value));
+        data = rest;
+    }
+    Ok(receivers)
+}
+
+fn read_compact_size(data: &mut &[u8]) -> Result<u64> {
+    let (&first, rest) = data
+        .split_first()
+        .ok_or(anyhow::anyhow!("Truncated receiver"))?;
+    let n = match first {
+        0xFD => 2,
+        0xFE => 4,
+        0xFF => 8,
+        _ => 0,
+    };
+    if rest.len() < n {
+        anyhow::bail!("Truncated receiver");
+    }
+    let value = if n == 0 {
+        first as u64
+    } else {
+        let mut b = [0u8; 8];
+        b[..n].copy_from_slice(&rest[..n]);
+        u64::from_le_bytes(b)
+    };
+    *data = &rest[n..];
+    Ok(value)
+}
+
+/// Account that has this receiver, 0 if none
+fn find_owner(network: &Network, connection: &Connection, r: &AddressReceiverT) -> Result<u32> {
+    let data = fb_unwrap!(r.data);
+    match r.typecode {
+        0 | 1 => {
+            let Ok(hash) = <[u8; 20]>::try_from(&data[..]) else {
+                return Ok(0);
+            };
+            let taddr = if r.typecode == 0 {
+                TransparentAddress::PublicKeyHash(hash)
+            } else {
+                TransparentAddress::ScriptHash(hash)
+            };
+            let account = connection
+                .query_row(
+                    "SELECT account FROM t_addresses WHERE address = ?1",
+                    [taddr.encode(network)],
+                    |r| r.get::<_, u32>(0),
+                )
+                .optional()?;
+            Ok(account.unwrap_or_default())
+        }
+        2 | 3 => {
+            let Ok(address) = <[u8; 43]>::try_from(&data[..]) else {
+                return Ok(0);
+            };
+            let mut s = connection.prepare("SELECT id_account FROM accounts WHERE deleted IS NULL")?;
+            let accounts = s
+                .query_map([], |r| r.get::<_, u32>(0))?
+                .collect::<Result<Vec<_>, _>>()?;
+            for account in accounts {
+                let ai = get_account_info(network, connection, account)?;
+                let owned = if r.typecode == 2 {
+                    let pa = PaymentAddress::from_bytes(&address);
+                    match (ai.sapling.as_ref(), pa) {
+                        (Some(si), Some(pa)) => si.vk.decrypt_diversifier(&pa).is_some(),
+                        _ => false,
+                    }
+                } else {
+                    let addr = Option::<orchard::Address>::from(
+                        orchard::Address::from_raw_address_bytes(&address),
+                    );
+                    match (ai.orchard.as_ref(), addr) {
+                        (Some(oi), Some(addr)) => oi.vk.scope_for_address(&addr).is_some(),
+                        _ => false,
+                    }
+                };
+                if owned {
+                    return Ok(account);
+                }
+            }
+            Ok(0)
+        }
+        _ => Ok(0),
+    }
+}
@@ -11,6 +11,9 @@ use crate::data::fb::{PaymentRequest, PaymentRequestT, RecipientT};
 use warp_macros::c_export;
 
 use super::ua::{decode_address, filter_address};
+use signature::strip_signature;
+
+pub mod signature;
 
 #[c_export]
 pub fn make_payment_uri(network: &Network, payment: &PaymentRequestT) -> Result<String> {
@@ -48,6 +51,8 @@ pub fn parse_payment_uri(
     height: u32,
     expiration: u32,
 ) -> Result<PaymentRequestT> {
+    // the signature block is checked by verify_payment_uri
+    let uri = strip_signature(uri)?;
     let treq = TransactionRequest::from_uri(uri)?; // this should include network
     let recipients = treq
         .payments()
@@ -75,6 +80,7 @@ pub fn parse_payment_uri(
                 pools: 7,
                 memo: user_memo,
                 memo_bytes: p.memo().cloned().map(|m| m.as_slice().to_vec()),
+                src_pools: 0,
             }
         })
         .collect::<Vec<_>>();
@@ -85,6 +91,12 @@ pub fn parse_payment_uri(
         use_change: true,
         height,
         expiration,
+        decoy_outputs: 0,
+        from_address: None,
+        allow_high_fee: false,
+        change_outputs: 0,
+        allow_change_address: false,
+        allow_lookalike_address: false,
     };
     Ok(p)
 }
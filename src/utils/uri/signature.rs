@@ -0,0 +1,176 @@
+use anyhow::Result;
+use rusqlite::{params, Connection};
+use secp256k1::{ecdsa::Signature, Message, PublicKey, Secp256k1};
+use sha2::{Digest as _, Sha256};
+use zcash_client_backend::encoding::AddressCodec as _;
+use zcash_primitives::legacy::TransparentAddress;
+
+use crate::{
+    data::fb::{PaymentRequestStatus, PaymentRequestStatusT},
+    db::account::get_account_info,
+    network::Network,
+    warp::script::hash160,
+};
+use warp_macros::c_export;
+
+/*
+    Signed payment requests
+
+    There is no standard for signing a ZIP-321 URI yet. Until
+    there is one, we use an interim scheme: the requester appends
+    its secp256k1 public key and then a signature of everything
+    before it:
+
+        <uri>&signer=<hex public key>&sig=<hex compact signature>
+
+    (`?signer=` if the uri has no parameter). The signature is
+    ECDSA over SHA256d(SIGNED_REQUEST_PREFIX || uri up to the signer key).
+
+    A valid signature only proves that the request was made by the owner
+    of the key. The request is verified if the key is known: its
+    transparent address is an address of a contact or a trusted address,
+    or the key was published in a memo that we received earlier.
+*/
+
+const SIGNED_REQUEST_PREFIX: &[u8] = b"Zcash Signed Payment Request:\n";
+
+pub const REQUEST_UNSIGNED: u8 = 0;
+pub const REQUEST_INVALID_SIGNATURE: u8 = 1;
+pub const REQUEST_UNKNOWN_SIGNER: u8 = 2;
+pub const REQUEST_VERIFIED: u8 = 3;
+
+struct SignatureBlock<'a> {
+    message: &'a str,
+    signer: PublicKey,
+    signature: Signature,
+}
+
+/// Remove the signature block of a payment uri.
+/// Returns the unsigned uri and the signature if there is one
+fn split_signature(uri: &str) -> Result<(&str, Option<SignatureBlock<'_>>)> {
+    let Some((message, signature)) = uri.rsplit_once("&sig=") else {
+        return Ok((uri, None));
+    };
+    let (base, signer) = message
+        .rsplit_once("signer=")
+        .filter(|(base, _)| base.ends_with('?') || base.ends_with('&'))
+        .ok_or(anyhow::anyhow!("Signed payment request without a signer"))?;
+    let base = &base[..base.len() - 1];
+    let signer = PublicKey::from_slice(&hex::decode(signer)?)?;
+    let signature = Signature::from_compact(&hex::decode(signature)?)?;
+    Ok((
+        base,
+        Some(SignatureBlock {
+            message,
+            signer,
+            signature,
+        }),
+    ))
+}
+
+/// Payment uri without its signature block
+pub fn strip_signature(uri: &str) -> Result<&str> {
+    let (uri, _) = split_signature(uri)?;
+    Ok(uri)
+}
+
+fn message_hash(message: &str) -> Result<Message> {
+    let hash = Sha256::new()
+        .chain_update(SIGNED_REQUEST_PREFIX)
+        .chain_update(message)
+        .finalize();
+    let hash = Sha256::digest(hash);
+    let message = Message::from_slice(&hash)?;
+    Ok(message)
+}
+
+/// Sign a payment uri with the transparent key of the account
+#[c_export]
+pub fn sign_payment_uri(
+    network: &Network,
+    connection: &Connection,
+    account: u32,
+    uri: &str,
+) -> Result<String> {
+    let ai = get_account_info(network, connection, account)?;
+    let sk = ai
+        .transparent
+        .as_ref()
+        .and_then(|ti| ti.sk)
+        .ok_or(anyhow::anyhow!(
+            "Account {} has no transparent secret key",
+            account
+        ))?;
+    let secp = Secp256k1::signing_only();
+    let pk = PublicKey::from_secret_key(&secp, &sk);
+    let uri = strip_signature(uri)?;
+    let separator = if uri.contains('?') { '&' } else { '?' };
+    let message = format!("{uri}{separator}signer={}", hex::encode(pk.serialize()));
+    let signature = secp.sign_ecdsa(&message_hash(&message)?, &sk);
+    Ok(format!(
+        "{message}&sig={}",
+        hex::encode(signature.serialize_compact())
+    ))
+}
+
+/// Check the signature of a payment uri and whether its signer is known
+/// to the account
+#[c_export]
+pub fn verify_payment_uri(
+    network: &Network,
+    connection: &Connection,
+    account: u32,
+    uri: &str,
+) -> Result<PaymentRequestStatusT> {
+    let (_, block) = split_signature(uri)?;
+    let Some(block) = block else {
+        return Ok(PaymentRequestStatusT {
+            status: REQUEST_UNSIGNED,
+            signer_key: None,
+            requester: None,
+        });
+    };
+    let signer_key = hex::encode(block.signer.serialize());
+    let taddr = TransparentAddress::PublicKeyHash(hash160(&block.signer.serialize()));
+    let requester = taddr.encode(network);
+    let secp = Secp256k1::verification_only();
+    let status = if secp
+        .verify_ecdsa(
+            &message_hash(block.message)?,
+            &block.signature,
+            &block.signer,
+        )
+        .is_err()
+    {
+        REQUEST_INVALID_SIGNATURE
+    } else if is_known_signer(connection, account, &taddr, &requester, &signer_key)? {
+        REQUEST_VERIFIED
+    } else {
+        REQUEST_UNKNOWN_SIGNER
+    };
+    Ok(PaymentRequestStatusT {
+        status,
+        signer_key: Some(signer_key),
+        requester: Some(requester),
+    })
+}
+
+fn is_known_signer(
+    connection: &Connection,
+    account: u32,
+    taddr: &TransparentAddress,
+    address: &str,
+    signer_key: &str,
+) -> Result<bool> {
+    let known = connection.query_row(
+        "SELECT EXISTS(SELECT 1 FROM contact_receivers
+            WHERE account = ?1 AND pool = 0 AND address = ?2)
+        OR EXISTS(SELECT 1 FROM trusted_addresses
+            WHERE account = ?1 AND address = ?3)
+        OR EXISTS(SELECT 1 FROM msgs
+            WHERE account = ?1 AND incoming AND instr(body, ?4) > 0)",
+        params![account, taddr.script().0, address, signer_key],
+        |r| r.get::<_, bool>(0),
+    )?;
+    Ok(known)
+}
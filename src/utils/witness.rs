@@ -0,0 +1,76 @@
+use anyhow::Result;
+use rusqlite::Connection;
+
+use crate::{
+    data::fb::NoteWitnessT,
+    db::{chain::snap_to_checkpoint, notes::get_received_note},
+    utils::chain::get_cached_tree_state,
+    warp::{
+        hasher::{empty_roots, OrchardHasher, SaplingHasher},
+        sync::witnesses::rebuild_witnesses,
+    },
+    Client,
+};
+use warp_macros::c_export;
+
+/*
+    Witnesses for external builders
+
+    Another tool can build the transactions of an account from the
+    data of the wallet. `get_note_witness` gives it the inputs of the
+    proof of a spent note: the position of the note, its commitment,
+    its merkle path (32 hashes from the leaf to the root) and the
+    anchor, at a checkpoint. The witness is derived at the checkpoint
+    first, like for a payment.
+
+    The note must be unspent at the checkpoint, because the witnesses
+    of the spent notes are no longer updated.
+*/
+
+/// Merkle path of a note to the anchor of the checkpoint
+/// at or before `height`
+#[c_export]
+pub async fn get_note_witness(
+    connection: &Connection,
+    client: &mut Client,
+    id_note: u32,
+    height: u32,
+) -> Result<NoteWitnessT> {
+    let cp_height = snap_to_checkpoint(connection, height)?;
+    rebuild_witnesses(connection, client, cp_height).await?;
+    let (note, witness_height, orchard) = get_received_note(connection, id_note, cp_height)?
+        .ok_or(anyhow::anyhow!(
+            "Note {} has no witness at {}",
+            id_note,
+            cp_height.0
+        ))?;
+    if witness_height != cp_height.0 {
+        anyhow::bail!(
+            "The witness of note {} stops at {} (spent note?)",
+            id_note,
+            witness_height
+        );
+    }
+    let (s_tree, o_tree) = get_cached_tree_state(connection, client, cp_height).await?;
+    let (path, root) = if orchard {
+        let h = OrchardHasher::default();
+        let edge = o_tree.to_edge(&h).to_auth_path(&h);
+        let path = note.witness.build_auth_path(&edge, &empty_roots(&h));
+        (path, note.witness.root(&edge, &h))
+    } else {
+        let h = SaplingHasher::default();
+        let edge = s_tree.to_edge(&h).to_auth_path(&h);
+        let path = note.witness.build_auth_path(&edge, &empty_roots(&h));
+        (path, note.witness.root(&edge, &h))
+    };
+
+    Ok(NoteWitnessT {
+        id_note,
+        pool: if orchard { 2 } else { 1 },
+        height: cp_height.0,
+        position: note.witness.position,
+        cmx: Some(note.witness.value.to_vec()),
+        path: Some(path.0.concat()),
+        root: Some(root.to_vec()),
+    })
+}
@@ -1,13 +1,16 @@
 use std::{
+    ffi::OsStr,
     fs::{self, File},
-    io::{Cursor, Read as _, Write as _},
-    path::PathBuf,
+    io::{self, BufReader, Read, Write},
+    path::{Path, PathBuf},
     str::FromStr as _,
     time,
 };
 
 use age::{secrecy::ExposeSecret as _, Decryptor};
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest as _, Sha256};
 use zip::write::FileOptions;
 
 use crate::data::fb::{AGEKeysT, ZipDbConfig, ZipDbConfigT};
@@ -16,6 +19,44 @@ use rusqlite::{backup::Backup, Connection};
 
 use warp_macros::c_export;
 
+/*
+    Encrypted database backup
+
+    The databases are copied with the sqlite backup API, zipped
+    into a temporary file and encrypted with age. Everything is
+    streamed through files, so the memory usage does not depend
+    on the size of the databases.
+
+    The encrypted data is split into chunks of `chunk_size` bytes
+    (DEFAULT_CHUNK_SIZE if 0) saved next to the target as
+    `<target>.0000`, `<target>.0001`, etc. The target file itself
+    is a JSON manifest with the name, size and SHA-256 of every chunk.
+    The chunks can be uploaded separately: an interrupted upload
+    resumes at the first chunk missing from the remote storage.
+
+    Decryption checks every chunk against the manifest as it reads
+    it, before it restores anything. Older backups, made of a single
+    age file, are still accepted.
+
+    The plaintext temporary files are removed when they go out of
+    scope, including when the backup or the restore fails.
+*/
+
+pub const DEFAULT_CHUNK_SIZE: u32 = 4 * 1024 * 1024;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BackupManifest {
+    pub chunk_size: u32,
+    pub chunks: Vec<BackupChunk>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BackupChunk {
+    pub name: String,
+    pub size: u64,
+    pub hash: String,
+}
+
 #[c_export]
 pub fn encrypt_zip_database_files(zip_db_config: &ZipDbConfigT) -> Result<()> {
     let ZipDbConfigT {
@@ -23,6 +64,7 @@ pub fn encrypt_zip_database_files(zip_db_config: &ZipDbConfigT) -> Result<()> {
         file_list,
         target_path,
         public_key,
+        chunk_size,
     } = zip_db_config.clone();
 
     let directory = PathBuf::from(fb_unwrap!(directory));
@@ -30,44 +72,51 @@ pub fn encrypt_zip_database_files(zip_db_config: &ZipDbConfigT) -> Result<()> {
     zip_directory.push(".tmp");
     let _ = fs::create_dir(zip_directory.clone());
 
-    let zip_data = vec![];
-    let buff = Cursor::new(zip_data);
-    let mut zip_writer = zip::ZipWriter::new(buff);
+    let zip_path = TempFile(zip_directory.join("backup.zip"));
+    let mut zip_writer = zip::ZipWriter::new(File::create(&zip_path.0)?);
 
     let files = fb_unwrap!(file_list);
     for db_name in files.iter() {
+        let db_copy = TempFile(zip_directory.join(db_name));
         {
             let p = directory.join(db_name);
             tracing::info!("Backup {:?}...", p);
             let src = Connection::open(p)?;
-            let mut dst = Connection::open(zip_directory.join(&db_name))?;
+            let mut dst = Connection::open(&db_copy.0)?;
             let backup = Backup::new(&src, &mut dst)?;
             backup.run_to_completion(100, time::Duration::from_millis(10), None)?;
         }
         tracing::info!("Zipping {db_name}...");
         {
             zip_writer.start_file(&db_name, FileOptions::<()>::default())?;
-            let mut f = File::open(zip_directory.join(db_name))?;
-            let mut buffer = Vec::new();
-            f.read_to_end(&mut buffer)?;
-            zip_writer.write_all(&*buffer)?;
+            let mut f = File::open(&db_copy.0)?;
+            io::copy(&mut f, &mut zip_writer)?;
         }
     }
-    let buffer = zip_writer.finish()?;
-    let zip_data = buffer.into_inner();
+    zip_writer.finish()?;
 
-    let target_path = fb_unwrap!(target_path);
+    let target_path = PathBuf::from(fb_unwrap!(target_path));
     let public_key = fb_unwrap!(public_key);
-    tracing::info!("Encrypting {target_path}...");
+    tracing::info!("Encrypting {}...", target_path.display());
     let public_key = age::x25519::Recipient::from_str(public_key).map_err(anyhow::Error::msg)?;
 
-    let mut encrypted_file = File::create(target_path)?;
+    let chunk_size = match chunk_size {
+        0 => DEFAULT_CHUNK_SIZE,
+        s => s,
+    };
+    let mut chunk_writer = ChunkWriter::new(&target_path, chunk_size);
     {
         let encryptor = age::Encryptor::with_recipients(vec![Box::new(public_key)]).unwrap();
-        let mut writer = encryptor.wrap_output(&mut encrypted_file)?;
-        writer.write_all(&*zip_data)?;
+        let mut writer = encryptor.wrap_output(&mut chunk_writer)?;
+        let mut zip_file = File::open(&zip_path.0)?;
+        io::copy(&mut zip_file, &mut writer)?;
         writer.finish()?;
     }
+    let manifest = BackupManifest {
+        chunk_size,
+        chunks: chunk_writer.finish()?,
+    };
+    fs::write(&target_path, serde_json::to_string_pretty(&manifest)?)?;
     Ok(())
 }
 
@@ -78,15 +127,17 @@ pub fn decrypt_zip_database_files(
     secret_key: &str,
 ) -> Result<()> {
     let key = age::x25519::Identity::from_str(secret_key).map_err(anyhow::Error::msg)?;
-    let mut encrypted_data = Vec::new();
-    {
-        let mut f = File::open(file_path)?;
-        f.read_to_end(&mut encrypted_data)?;
-    }
-    let mut zip_data = vec![];
+    let file_path = PathBuf::from(file_path);
+    let mut encrypted_data: Box<dyn Read> = match read_manifest(&file_path)? {
+        Some(manifest) => Box::new(ChunkReader::new(&file_path, manifest)?),
+        None => Box::new(BufReader::new(File::open(&file_path)?)),
+    };
+
+    let target_directory = PathBuf::from(target_directory);
+    let zip_path = TempFile(target_directory.join(".backup.zip"));
     {
         let Decryptor::Recipients(decryptor) =
-            Decryptor::new(&*encrypted_data).map_err(anyhow::Error::msg)?
+            Decryptor::new(&mut encrypted_data).map_err(anyhow::Error::msg)?
         else {
             unreachable!()
         };
@@ -95,12 +146,13 @@ pub fn decrypt_zip_database_files(
         let mut reader = decryptor
             .decrypt(std::iter::once(key))
             .map_err(anyhow::Error::msg)?;
-        reader.read_to_end(&mut zip_data)?;
+        let mut zip_file = File::create(&zip_path.0)?;
+        io::copy(&mut reader, &mut zip_file)?;
     }
+    // the chunks after the end of the encrypted data are checked too
+    io::copy(&mut encrypted_data, &mut io::sink())?;
 
-    let target_directory = PathBuf::from(target_directory);
-    let zip_data = Cursor::new(&*zip_data);
-    let mut zip_reader = zip::ZipArchive::new(zip_data)?;
+    let mut zip_reader = zip::ZipArchive::new(File::open(&zip_path.0)?)?;
     let file_names: Vec<_> = zip_reader.file_names().map(|s| s.to_string()).collect();
     for file_name in file_names {
         let mut zip_file = zip_reader.by_name(&file_name)?;
@@ -123,3 +175,172 @@ pub fn generate_zip_database_keys() -> Result<AGEKeysT> {
     };
     Ok(keys)
 }
+
+/// None if the file is an older backup without manifest
+fn read_manifest(path: &Path) -> Result<Option<BackupManifest>> {
+    let mut header = [0u8; 1];
+    File::open(path)?.read_exact(&mut header)?;
+    if header[0] != b'{' {
+        return Ok(None);
+    }
+    let manifest = serde_json::from_reader(BufReader::new(File::open(path)?))?;
+    Ok(Some(manifest))
+}
+
+/// Removed when dropped, including on the error paths
+struct TempFile(PathBuf);
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+struct Chunk {
+    name: String,
+    file: File,
+    hasher: Sha256,
+    size: u64,
+}
+
+/// Splits the data into files of at most `chunk_size` bytes
+struct ChunkWriter {
+    target_path: PathBuf,
+    chunk_size: u64,
+    chunk: Option<Chunk>,
+    chunks: Vec<BackupChunk>,
+}
+
+impl ChunkWriter {
+    fn new(target_path: &Path, chunk_size: u32) -> Self {
+        ChunkWriter {
+            target_path: target_path.to_path_buf(),
+            chunk_size: chunk_size as u64,
+            chunk: None,
+            chunks: vec![],
+        }
+    }
+
+    fn close_chunk(&mut self) -> io::Result<()> {
+        if let Some(mut chunk) = self.chunk.take() {
+            chunk.file.flush()?;
+            self.chunks.push(BackupChunk {
+                name: chunk.name,
+                size: chunk.size,
+                hash: hex::encode(chunk.hasher.finalize()),
+            });
+        }
+        Ok(())
+    }
+
+    fn finish(mut self) -> io::Result<Vec<BackupChunk>> {
+        self.close_chunk()?;
+        Ok(self.chunks)
+    }
+}
+
+impl Write for ChunkWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let full = self
+            .chunk
+            .as_ref()
+            .map(|c| c.size >= self.chunk_size)
+            .unwrap_or(true);
+        if full {
+            self.close_chunk()?;
+            let file_name = self.target_path.file_name().unwrap_or_default();
+            let name = format!("{}.{:04}", file_name.to_string_lossy(), self.chunks.len());
+            let file = File::create(self.target_path.with_file_name(&name))?;
+            self.chunk = Some(Chunk {
+                name,
+                file,
+                hasher: Sha256::new(),
+                size: 0,
+            });
+        }
+        let chunk = self.chunk.as_mut().unwrap();
+        let len = buf.len().min((self.chunk_size - chunk.size) as usize);
+        let n = chunk.file.write(&buf[..len])?;
+        chunk.hasher.update(&buf[..n]);
+        chunk.size += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if let Some(chunk) = self.chunk.as_mut() {
+            chunk.file.flush()?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads the chunks one after the other and checks the size
+/// and hash of each of them against the manifest
+struct ChunkReader {
+    path: PathBuf,
+    chunks: std::vec::IntoIter<BackupChunk>,
+    chunk: Option<(BackupChunk, Chunk)>,
+}
+
+impl ChunkReader {
+    fn new(path: &Path, manifest: BackupManifest) -> Result<Self> {
+        for chunk in manifest.chunks.iter() {
+            // chunks must be in the directory of the manifest
+            if Path::new(&chunk.name).file_name() != Some(OsStr::new(&chunk.name)) {
+                anyhow::bail!("Invalid chunk name {}", chunk.name);
+            }
+        }
+        Ok(ChunkReader {
+            path: path.to_path_buf(),
+            chunks: manifest.chunks.into_iter(),
+            chunk: None,
+        })
+    }
+}
+
+fn corrupted_chunk(name: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("Chunk {name} is corrupted"),
+    )
+}
+
+impl Read for ChunkReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            if self.chunk.is_none() {
+                let Some(expected) = self.chunks.next() else {
+                    return Ok(0);
+                };
+                let file = File::open(self.path.with_file_name(&expected.name)).map_err(|_| {
+                    io::Error::new(
+                        io::ErrorKind::NotFound,
+                        format!("Missing chunk {}", expected.name),
+                    )
+                })?;
+                let chunk = Chunk {
+                    name: expected.name.clone(),
+                    file,
+                    hasher: Sha256::new(),
+                    size: 0,
+                };
+                self.chunk = Some((expected, chunk));
+            }
+            let (expected, chunk) = self.chunk.as_mut().unwrap();
+            let n = chunk.file.read(buf)?;
+            if n > 0 || buf.is_empty() {
+                chunk.hasher.update(&buf[..n]);
+                chunk.size += n as u64;
+                if chunk.size > expected.size {
+                    return Err(corrupted_chunk(&chunk.name));
+                }
+                return Ok(n);
+            }
+            let (expected, chunk) = self.chunk.take().unwrap();
+            let hash = hex::encode(chunk.hasher.finalize());
+            if chunk.size != expected.size || hash != expected.hash {
+                return Err(corrupted_chunk(&chunk.name));
+            }
+        }
+    }
+}
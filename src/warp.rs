@@ -5,7 +5,10 @@ pub mod legacy;
 pub mod mempool;
 mod orchard;
 mod sapling;
+pub mod script;
 pub mod sync;
+#[cfg(feature = "zsa")]
+pub mod zsa;
 
 use crate::{lwd::rpc::CompactBlock, Hash};
 use secp256k1::SecretKey;
@@ -89,6 +92,16 @@ pub struct TransparentTx {
     pub txid: Hash,
     pub vins: Vec<OutPoint>,
     pub vouts: Vec<TxOut>,
+    /// P2PK and multisig outputs
+    pub script_vouts: Vec<ScriptTxOut>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ScriptTxOut {
+    pub vout: u32,
+    pub value: u64,
+    pub script_type: u8,
+    pub script: Vec<u8>,
 }
 
 #[derive(Debug)]
@@ -100,7 +113,7 @@ pub struct STXO {
     pub value: u64,
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 pub struct UTXO {
     pub is_new: bool,
     pub id: u32,
@@ -121,5 +134,7 @@ pub struct TransparentSK {
     pub sk: SecretKey,
 }
 
-pub use decrypter::{try_orchard_decrypt, try_sapling_decrypt};
+pub use decrypter::{
+    is_orchard_candidate, is_sapling_candidate, try_orchard_decrypt, try_sapling_decrypt,
+};
 use zcash_primitives::legacy::TransparentAddress;
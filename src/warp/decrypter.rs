@@ -15,7 +15,7 @@ use chacha20::{
     cipher::{KeyIvInit, StreamCipher, StreamCipherSeek},
     ChaCha20,
 };
-use group::{ff::PrimeField as _, Curve as _, GroupEncoding};
+use group::{ff::PrimeField as _, Curve as _, Group as _, GroupEncoding};
 use halo2_proofs::pasta::{pallas::Point, Fq};
 use orchard::{
     keys::IncomingViewingKey,
@@ -93,6 +93,7 @@ pub fn try_sapling_decrypt(
                         witness: Witness::default(),
                         nf: [0u8; 32],
                         spent: None,
+                        coinbase: false,
                     };
                     sender.send(note)?;
                 }
@@ -172,6 +173,7 @@ pub fn try_orchard_decrypt(
                         witness: Witness::default(),
                         nf: [0u8; 32],
                         spent: None,
+                        coinbase: false,
                     };
                     sender.send(note)?;
                 }
@@ -180,3 +182,64 @@ pub fn try_orchard_decrypt(
     }
     Ok(())
 }
+
+/*
+    Output pre-filter
+
+    Trial decryption needs a key agreement per IVK, and there is
+    no shortcut to tell whether an output is for one of our keys
+    without it (a bloom filter of the recipients is impossible,
+    they are not in the compact outputs). But some outputs cannot
+    be notes at all: the ciphertext is truncated, the ephemeral key
+    is not a point or has a small order, or the note commitment
+    is not a field element. No honest wallet creates them, but spam
+    transactions do, and they are skipped before the key agreements.
+
+    The checks only reject outputs that the decryption would reject,
+    they never skip a note. They cost a point decompression per output,
+    which is recovered as soon as the wallet has more than one IVK.
+*/
+
+/// False if the output cannot be a valid sapling note
+pub fn is_sapling_candidate(co: &CompactSaplingOutput) -> bool {
+    if co.ciphertext.len() != COMPACT_NOTE_SIZE {
+        return false;
+    }
+    let (Ok(epk), Ok(cmu)) = (
+        <[u8; 32]>::try_from(&co.epk[..]),
+        <[u8; 32]>::try_from(&co.cmu[..]),
+    ) else {
+        return false;
+    };
+    let epk = Option::<jubjub::AffinePoint>::from(jubjub::AffinePoint::from_bytes(epk));
+    let Some(epk) = epk else {
+        return false;
+    };
+    if bool::from(epk.is_small_order()) {
+        return false;
+    }
+    bool::from(sapling_crypto::note::ExtractedNoteCommitment::from_bytes(&cmu).is_some())
+}
+
+/// False if the action cannot have a valid orchard note
+pub fn is_orchard_candidate(ca: &CompactOrchardAction) -> bool {
+    if ca.ciphertext.len() != COMPACT_NOTE_SIZE {
+        return false;
+    }
+    let (Ok(epk), Ok(cmx), Ok(nf)) = (
+        <[u8; 32]>::try_from(&ca.ephemeral_key[..]),
+        <[u8; 32]>::try_from(&ca.cmx[..]),
+        <[u8; 32]>::try_from(&ca.nullifier[..]),
+    ) else {
+        return false;
+    };
+    let epk = Option::<Point>::from(Point::from_bytes(&epk));
+    let Some(epk) = epk else {
+        return false;
+    };
+    if bool::from(epk.is_identity()) {
+        return false;
+    }
+    bool::from(ExtractedNoteCommitment::from_bytes(&cmx).is_some())
+        && bool::from(Rho::from_bytes(&nf).is_some())
+}
@@ -1,5 +1,5 @@
 use crate::Hash;
-use std::io::Read;
+use std::io::{Read, Write};
 use zcash_encoding::{Optional, Vector};
 
 use super::{Edge, Hasher, MERKLE_DEPTH};
@@ -24,6 +24,15 @@ impl CommitmentTreeFrontier {
         })
     }
 
+    pub fn write<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        Optional::write(&mut writer, self.left, |w, h| w.write_all(&h))?;
+        Optional::write(&mut writer, self.right, |w, h| w.write_all(&h))?;
+        Vector::write(&mut writer, &self.parents, |w, p| {
+            Optional::write(w, *p, |w, h| w.write_all(&h))
+        })?;
+        Ok(())
+    }
+
     pub fn size(&self) -> usize {
         self.parents.iter().enumerate().fold(
             match (self.left.as_ref(), self.right.as_ref()) {
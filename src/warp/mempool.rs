@@ -1,23 +1,33 @@
-use std::{sync::Arc, time::Duration};
+use std::{collections::HashSet, sync::Arc, time::Duration};
 
 use anyhow::Result;
+use orchard::keys::Scope;
 use rusqlite::Connection;
 use tokio::sync::Mutex;
 use tokio::{
     runtime::Runtime,
-    sync::mpsc::{self, Sender},
+    sync::mpsc::{self, Receiver, Sender},
 };
-use tonic::Request;
+use tonic::{Code, Request};
 use zcash_primitives::transaction::Transaction;
 use zcash_protocol::consensus::{BlockHeight, BranchId};
 
 use crate::{
     coin::CoinDef,
-    db::mempool::{clear_unconfirmed_tx, store_unconfirmed_tx},
-    lwd::rpc::{Empty, RawTransaction},
+    db::{
+        account::get_account_info,
+        mempool::{clear_unconfirmed_tx, store_unconfirmed_tx},
+        notes::find_notes_by_nf,
+    },
+    lwd::{
+        get_last_height, get_mempool_txs, get_transaction,
+        rpc::{CompactTx, Empty, RawTransaction},
+    },
     network::Network,
     txdetails::analyze_raw_transaction,
     utils::ContextExt,
+    warp::{try_orchard_decrypt, try_sapling_decrypt},
+    Client, Hash,
 };
 
 use crate::coin::COINS;
@@ -48,12 +58,19 @@ impl Mempool {
                     'outer: loop {
                         tracing::info!("mempool open");
                         clear_unconfirmed_tx(&connection)?;
-                        let mut mempool = client
-                            .get_mempool_stream(Request::new(Empty {}))
-                            .await
-                            .with_file_line(|| "get_mempool_stream")?
-                            .into_inner();
+                        let stream = client.get_mempool_stream(Request::new(Empty {})).await;
                         let mut rx = rx.lock().await;
+                        let mut mempool = match stream {
+                            Ok(stream) => stream.into_inner(),
+                            Err(status) if status.code() == Code::Unimplemented => {
+                                tracing::info!("mempool stream not available, polling");
+                                break 'outer poll_mempool(
+                                    &c, &mut client, &connection, &mut rx, account,
+                                )
+                                .await;
+                            }
+                            Err(status) => Err(status).with_file_line(|| "get_mempool_stream")?,
+                        };
                         loop {
                             tokio::select! {
                                 msg = rx.recv() => {
@@ -125,6 +142,17 @@ fn parse_raw_tx(
     let raw_tx = &*raw_tx.data;
     let branch_id = BranchId::for_height(network, BlockHeight::from_u32(height));
     let tx = Transaction::read(raw_tx, branch_id)?;
+    parse_tx(coin, network, connection, account, height, tx)
+}
+
+fn parse_tx(
+    coin: &CoinDef,
+    network: &Network,
+    connection: &Connection,
+    account: u32,
+    height: u32,
+    tx: Transaction,
+) -> Result<ReceivedTx> {
     let txid = tx.txid();
     let txd = analyze_raw_transaction(coin, network, connection, account, height, 0, tx)?;
     let tx = ReceivedTx {
@@ -139,6 +167,176 @@ fn parse_raw_tx(
     Ok(tx)
 }
 
+/// Minimum length of the txid suffixes of the exclude list
+const MIN_EXCLUDE_LEN: usize = 4;
+/// Delay between two polls of the mempool
+const MEMPOOL_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/*
+    Mempool polling
+
+    Servers that do not implement GetMempoolStream are polled with
+    GetMempoolTx. The request has the transactions that we already
+    have in its Exclude list, so that the server only sends the new ones.
+
+    The server compares the exclude entries with the beginning of the
+    txids in display order, i.e. an entry is a suffix of the txid in
+    internal order. Entries are shortened to the length that keeps them
+    unique among the known txids (but no less than MIN_EXCLUDE_LEN).
+    The server does not exclude the transactions that match an entry
+    shared with an unknown transaction: they are all sent again,
+    and we drop the ones that we already have.
+
+    The full transaction is only downloaded if it spends one of our
+    notes or has an output that we can decrypt. Compact transactions
+    have no transparent data, the transparent only transactions
+    are not detected.
+*/
+
+/// Txids of the mempool transactions that we already have
+#[derive(Default, Debug)]
+pub struct KnownTxids {
+    txids: HashSet<Hash>,
+}
+
+impl KnownTxids {
+    /// Returns false if the txid was already known
+    pub fn insert(&mut self, txid: Hash) -> bool {
+        self.txids.insert(txid)
+    }
+
+    pub fn clear(&mut self) {
+        self.txids.clear();
+    }
+
+    /// Shortest unique suffixes of the known txids
+    pub fn exclude_list(&self) -> Vec<Vec<u8>> {
+        // in display order, the suffixes are prefixes
+        let mut keys = self
+            .txids
+            .iter()
+            .map(|txid| {
+                let mut key = *txid;
+                key.reverse();
+                key
+            })
+            .collect::<Vec<_>>();
+        keys.sort();
+        let common =
+            |a: &Hash, b: &Hash| a.iter().zip(b.iter()).take_while(|(x, y)| x == y).count();
+        keys.iter()
+            .enumerate()
+            .map(|(i, key)| {
+                let mut len = MIN_EXCLUDE_LEN;
+                if i > 0 {
+                    len = len.max(common(&keys[i - 1], key) + 1);
+                }
+                if i + 1 < keys.len() {
+                    len = len.max(common(key, &keys[i + 1]) + 1);
+                }
+                let mut suffix = key[..len.min(32)].to_vec();
+                suffix.reverse();
+                suffix
+            })
+            .collect()
+    }
+}
+
+async fn poll_mempool(
+    coin: &CoinDef,
+    client: &mut Client,
+    connection: &Connection,
+    rx: &mut Receiver<MempoolMsg>,
+    mut account: u32,
+) -> Result<()> {
+    let network = &coin.network;
+    let mut known = KnownTxids::default();
+    let mut height = 0;
+    loop {
+        let tip = get_last_height(client).await?;
+        if tip != height {
+            // new block, the transactions of the mempool may have been mined
+            height = tip;
+            known.clear();
+            clear_unconfirmed_tx(connection)?;
+        }
+        if account != 0 {
+            let txs = get_mempool_txs(client, known.exclude_list()).await?;
+            for ctx in txs.iter() {
+                let Ok(txid) = Hash::try_from(ctx.hash.clone()) else {
+                    continue;
+                };
+                if !known.insert(txid) {
+                    continue; // sent again because of a suffix collision
+                }
+                if is_relevant(network, connection, account, height + 1, ctx)? {
+                    let (_, tx) = get_transaction(network, client, &txid).await?;
+                    let tx = parse_tx(coin, network, connection, account, height + 1, tx)?;
+                    if tx.value != 0 {
+                        store_unconfirmed_tx(connection, &tx)?;
+                    }
+                }
+            }
+        }
+        tokio::select! {
+            msg = rx.recv() => {
+                match msg {
+                    Some(MempoolMsg::Account(new_account)) => {
+                        if new_account != account {
+                            account = new_account;
+                            known.clear();
+                            clear_unconfirmed_tx(connection)?;
+                        }
+                    }
+                    None => return Ok(()), // we are shutting down
+                }
+            }
+            _ = tokio::time::sleep(MEMPOOL_POLL_INTERVAL) => {}
+        }
+    }
+}
+
+/// True if the transaction spends a note of the account
+/// or has an output for the account
+fn is_relevant(
+    network: &Network,
+    connection: &Connection,
+    account: u32,
+    height: u32,
+    tx: &CompactTx,
+) -> Result<bool> {
+    let nfs = tx
+        .spends
+        .iter()
+        .map(|s| (&s.nf, false))
+        .chain(tx.actions.iter().map(|a| (&a.nullifier, true)));
+    for (nf, orchard) in nfs {
+        let Ok(nf) = Hash::try_from(nf.as_slice()) else {
+            continue;
+        };
+        let notes = find_notes_by_nf(connection, &nf, orchard)?;
+        if notes.iter().any(|(_, a, _)| *a == account) {
+            return Ok(true);
+        }
+    }
+
+    let ai = get_account_info(network, connection, account)?;
+    let (mut sender, receiver) = std::sync::mpsc::channel();
+    if let Some(si) = ai.sapling.as_ref() {
+        let ivks = [(account, si.vk.fvk().vk.ivk())];
+        for (vout, o) in tx.outputs.iter().enumerate() {
+            try_sapling_decrypt(network, &ivks, height, 0, 0, vout as u32, o, &mut sender)?;
+        }
+    }
+    if let Some(oi) = ai.orchard.as_ref() {
+        let ivks = [(account, oi.vk.to_ivk(Scope::External))];
+        for (vout, a) in tx.actions.iter().enumerate() {
+            try_orchard_decrypt(network, &ivks, height, 0, 0, vout as u32, a, &mut sender)?;
+        }
+    }
+    Ok(receiver.try_recv().is_ok())
+}
+
 #[c_export]
 pub fn mempool_run(coin: &CoinDef) -> Result<()> {
     let mut coin_def = COINS[coin.coin as usize].lock();
@@ -0,0 +1,128 @@
+use ripemd::{Digest as _, Ripemd160};
+use sha2::Sha256;
+
+/*
+    Transparent output scripts
+
+    Addresses only cover the P2PKH and P2SH scripts. An output
+    can also pay a public key directly (P2PK) or m of n public keys
+    (bare multisig). The wallet cannot spend them yet, but the sync
+    matches the keys of their scripts with the transparent addresses
+    of every account and keeps track of them, until a transaction
+    spends them. Anything else is nonstandard.
+
+    The server only indexes transactions by address, so the sync sees
+    these outputs and their spends when the transaction also has
+    an input or an output of one of our addresses.
+*/
+
+const OP_DUP: u8 = 0x76;
+const OP_HASH160: u8 = 0xa9;
+const OP_EQUAL: u8 = 0x87;
+const OP_EQUALVERIFY: u8 = 0x88;
+const OP_CHECKSIG: u8 = 0xac;
+const OP_CHECKMULTISIG: u8 = 0xae;
+const OP_1: u8 = 0x51;
+const OP_16: u8 = 0x60;
+
+pub const SCRIPT_P2PKH: u8 = 0;
+pub const SCRIPT_P2SH: u8 = 1;
+pub const SCRIPT_P2PK: u8 = 2;
+pub const SCRIPT_MULTISIG: u8 = 3;
+pub const SCRIPT_NONSTANDARD: u8 = 4;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ScriptType {
+    PubKeyHash([u8; 20]),
+    ScriptHash([u8; 20]),
+    PubKey(Vec<u8>),
+    Multisig {
+        required: u8,
+        pubkeys: Vec<Vec<u8>>,
+    },
+    NonStandard,
+}
+
+impl ScriptType {
+    pub fn classify(script: &[u8]) -> Self {
+        match script {
+            [OP_DUP, OP_HASH160, 20, pkh @ .., OP_EQUALVERIFY, OP_CHECKSIG] if pkh.len() == 20 => {
+                ScriptType::PubKeyHash(pkh.try_into().unwrap())
+            }
+            [OP_HASH160, 20, sh @ .., OP_EQUAL] if sh.len() == 20 => {
+                ScriptType::ScriptHash(sh.try_into().unwrap())
+            }
+            [len, pk @ .., OP_CHECKSIG] if is_pubkey(pk) && *len as usize == pk.len() => {
+                ScriptType::PubKey(pk.to_vec())
+            }
+            [m @ OP_1..=OP_16, keys @ .., n @ OP_1..=OP_16, OP_CHECKMULTISIG] => {
+                match parse_pubkeys(keys) {
+                    Some(pubkeys) if pubkeys.len() == (n - OP_1 + 1) as usize && m <= n => {
+                        ScriptType::Multisig {
+                            required: m - OP_1 + 1,
+                            pubkeys,
+                        }
+                    }
+                    _ => ScriptType::NonStandard,
+                }
+            }
+            _ => ScriptType::NonStandard,
+        }
+    }
+
+    pub fn code(&self) -> u8 {
+        match self {
+            ScriptType::PubKeyHash(_) => SCRIPT_P2PKH,
+            ScriptType::ScriptHash(_) => SCRIPT_P2SH,
+            ScriptType::PubKey(_) => SCRIPT_P2PK,
+            ScriptType::Multisig { .. } => SCRIPT_MULTISIG,
+            ScriptType::NonStandard => SCRIPT_NONSTANDARD,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ScriptType::PubKeyHash(_) => "P2PKH",
+            ScriptType::ScriptHash(_) => "P2SH",
+            ScriptType::PubKey(_) => "P2PK",
+            ScriptType::Multisig { .. } => "multisig",
+            ScriptType::NonStandard => "nonstandard",
+        }
+    }
+
+    /// Hashes of the public keys that the script pays, to match them
+    /// with the P2PKH addresses of the wallet.
+    /// P2PKH scripts are not included, they have an address
+    pub fn key_hashes(&self) -> Vec<[u8; 20]> {
+        match self {
+            ScriptType::PubKey(pk) => vec![hash160(pk)],
+            ScriptType::Multisig { pubkeys, .. } => pubkeys.iter().map(|pk| hash160(pk)).collect(),
+            _ => vec![],
+        }
+    }
+}
+
+fn is_pubkey(pk: &[u8]) -> bool {
+    match pk.len() {
+        33 => pk[0] == 0x02 || pk[0] == 0x03,
+        65 => pk[0] == 0x04,
+        _ => false,
+    }
+}
+
+fn parse_pubkeys(mut data: &[u8]) -> Option<Vec<Vec<u8>>> {
+    let mut pubkeys = vec![];
+    while let Some((&len, rest)) = data.split_first() {
+        let len = len as usize;
+        if rest.len() < len || !is_pubkey(&rest[..len]) {
+            return None;
+        }
+        pubkeys.push(rest[..len].to_vec());
+        data = &rest[len..];
+    }
+    Some(pubkeys)
+}
+
+pub(crate) fn hash160(data: &[u8]) -> [u8; 20] {
+    *Ripemd160::digest(Sha256::digest(data)).as_ref()
+}
@@ -4,29 +4,55 @@ use std::{
 };
 
 use crate::{
+    account::{
+        ack::process_acks,
+        webhook::{dispatch_webhooks, spawn_delivery},
+    },
     coin::{connect_lwd, CoinDef},
+    data::fb::BlockInfoT,
     db::{
-        account::{list_account_transparent_addresses, list_accounts},
-        account_manager::extend_transparent_addresses,
-        chain::{get_block_header, get_sync_height, rewind_checkpoint, store_block},
+        account::{is_transparent_only, list_account_transparent_addresses, list_accounts},
+        account_manager::{
+            extend_transparent_addresses, purge_deleted_accounts, DEFAULT_ACCOUNT_GRACE_PERIOD,
+        },
+        chain::{
+            clear_headers, get_block_header, get_sync_height, rewind_checkpoint, store_block,
+            store_block_stats, store_frontiers, store_headers, store_last_sync,
+            HEADER_CHAIN_LENGTH,
+        },
+        logs::{flush_logs, SYNC_TARGET},
         notes::{
             mark_shielded_spent, recover_expired_spends, store_received_note,
-            update_account_balances, update_tx_timestamp,
+            tag_change_notes, update_account_balances, update_tx_timestamp,
         },
         tx::{
-            add_tx_value, copy_block_times_from_tx, drop_transparent_data,
-            list_unknown_height_timestamps, store_block_time, update_tx_time, update_tx_values,
+            add_tx_value, clear_transparent_scan_progress, copy_block_times_from_tx,
+            drop_transparent_block, drop_transparent_data, get_transparent_scan_height,
+            has_transparent_scan_progress, list_transparent_blocks, list_unknown_height_timestamps,
+            list_unknown_transparent_blocks, store_block_time, store_transparent_block,
+            store_transparent_scan_height, tag_transfers, update_broadcast_txs, update_tx_time,
+            update_tx_values,
         },
     },
     fb_unwrap,
     lwd::{
-        get_compact_block, get_compact_block_range, get_transparent, get_tree_state,
-        rpc::CompactBlock,
+        get_compact_block, get_compact_block_range, get_pruned_block_range, get_transparent,
+        get_tree_state, has_pruned_block_range,
+        rpc::{Bridge, CompactBlock},
+        LwdError,
     },
+    lwd_retry,
+    metrics::{metrics, ErrorKind},
     network::Network,
+    pay::tex::sweep_tex_change,
     txdetails::CompressedMemo,
     types::CheckpointHeight,
-    utils::chain::{get_activation_height, reset_chain},
+    utils::{
+        chain::{
+            check_hard_checkpoint, get_activation_height, get_cached_tree_state, reset_chain,
+        },
+        to_txid_str,
+    },
     warp::{
         hasher::{OrchardHasher, SaplingHasher},
         BlockHeader,
@@ -37,11 +63,12 @@ use anyhow::Result;
 use header::BlockHeaderStore;
 use lazy_static::lazy_static;
 use prost::Message;
-use rusqlite::Connection;
+use rusqlite::{Connection, Transaction};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use shielded::Synchronizer;
 use std::sync::Arc;
+use subtrees::verify_subtree_roots;
 use thiserror::Error;
 use tokio::sync::{
     mpsc::{channel, Sender},
@@ -61,13 +88,17 @@ use warp_macros::c_export;
 pub mod builder;
 mod header;
 mod shielded;
+pub mod subtrees;
 mod transparent;
+pub mod witnesses;
 
 #[derive(Error, Debug)]
 pub enum SyncError {
     #[error("Reorganization detected at block {0}")]
     Reorg(u32),
     #[error(transparent)]
+    Lwd(#[from] LwdError),
+    #[error(transparent)]
     Other(#[from] anyhow::Error),
 }
 
@@ -89,6 +120,7 @@ pub struct ExtendedReceivedTx {
     pub address: Option<String>,
     pub contact: Option<String>,
     pub memo: Option<String>,
+    pub transfer: Option<u32>,
 }
 
 #[derive(Serialize, Debug)]
@@ -156,6 +188,9 @@ pub struct ReceivedNote {
     pub tx: ReceivedTx,
     pub spent: Option<u32>,
     pub witness: Witness,
+    /// Output of a coinbase transaction (mining reward or funding stream)
+    #[serde(default)]
+    pub coinbase: bool,
 }
 
 pub type SaplingSync = Synchronizer<shielded::sapling::SaplingProtocol>;
@@ -183,40 +218,130 @@ pub async fn download_warp_blocks(
 }
 
 pub trait CompactBlockSource: Clone {
-    fn chunked(&self) -> bool;
-
     fn run(self, start: u32, end: u32, sender: Sender<CompactBlock>) -> Result<()>;
 }
 
 #[derive(Clone)]
 pub struct LWDCompactBlockSource {
     channel: Channel,
+    pruned: bool,
 }
 
 impl LWDCompactBlockSource {
     pub fn new(channel: Channel) -> Result<Self> {
-        Ok(Self { channel })
+        Ok(Self {
+            channel,
+            pruned: false,
+        })
+    }
+
+    /// Download the blocks with `GetPrunedBlockRange`.
+    /// Falls back to `GetBlockRange` if the server does not implement it
+    pub fn new_pruned(channel: Channel) -> Result<Self> {
+        Ok(Self {
+            channel,
+            pruned: true,
+        })
     }
 }
 
 impl CompactBlockSource for LWDCompactBlockSource {
-    fn chunked(&self) -> bool {
-        true
+    fn run(self, start: u32, end: u32, sender: Sender<CompactBlock>) -> Result<()> {
+        tokio::spawn(stream_blocks(self.channel, self.pruned, start, end, sender));
+        Ok(())
     }
+}
 
-    fn run(self, start: u32, end: u32, sender: Sender<CompactBlock>) -> Result<()> {
-        tokio::spawn(async move {
-            let mut client = Client::new(self.channel.clone());
-            let mut range = get_compact_block_range(&mut client, start + 1, end).await?;
-            while let Some(block) = range.message().await? {
-                sender.send(block).await?;
+async fn stream_blocks(
+    channel: Channel,
+    mut pruned: bool,
+    start: u32,
+    end: u32,
+    sender: Sender<CompactBlock>,
+) -> Result<()> {
+    let mut client = Client::new(channel);
+    let mut next = start + 1;
+    loop {
+        let range = if pruned {
+            get_pruned_block_range(&mut client, next, end).await
+        } else {
+            get_compact_block_range(&mut client, next, end).await
+        };
+        let mut range = match range {
+            Err(e) if pruned && e.is_unimplemented() => {
+                tracing::warn!("GetPrunedBlockRange is not implemented, using GetBlockRange");
+                pruned = false;
+                continue;
             }
-            Ok::<_, anyhow::Error>(())
-        });
-        Ok(())
+            r => r?,
+        };
+        loop {
+            match range.message().await {
+                Ok(Some(block)) => {
+                    next = block.height as u32 + 1;
+                    sender.send(block).await?;
+                }
+                Ok(None) => return Ok(()),
+                Err(status) => {
+                    let e = LwdError::from(status);
+                    if !(pruned && e.is_unimplemented()) {
+                        return Err(e.into());
+                    }
+                    // resume from the next block with the regular call
+                    tracing::warn!("GetPrunedBlockRange failed @{}, using GetBlockRange", next);
+                    pruned = false;
+                    break;
+                }
+            }
+        }
     }
 }
 
+/// Block source for the warp server. If the server is not reachable,
+/// use the regular server
+async fn warp_block_source(coin: &CoinDef, height: u32) -> Result<LWDCompactBlockSource> {
+    let url = fb_unwrap!(coin.config.warp_url);
+    tracing::info!("Using Warp block server @ {}", url);
+    let channel = async {
+        let ep = Channel::from_shared(url.clone())?;
+        let channel = ep.connect().await?;
+        Ok::<_, anyhow::Error>(channel)
+    }
+    .await;
+    let channel = match channel {
+        Ok(channel) => channel,
+        Err(e) => {
+            tracing::warn!("Warp block server @ {} is not available: {}", url, e);
+            return LWDCompactBlockSource::new(fb_unwrap!(coin.channel).clone());
+        }
+    };
+    let mut client = Client::new(channel.clone());
+    match has_pruned_block_range(&mut client, height).await {
+        Ok(true) => LWDCompactBlockSource::new_pruned(channel),
+        Ok(false) => {
+            tracing::warn!("Warp block server @ {} does not have pruned blocks", url);
+            LWDCompactBlockSource::new(channel)
+        }
+        Err(e) => {
+            tracing::warn!("Warp block server @ {} failed: {}", url, e);
+            LWDCompactBlockSource::new(fb_unwrap!(coin.channel).clone())
+        }
+    }
+}
+
+/// Used when the config does not have `commit_interval`
+pub const DEFAULT_COMMIT_INTERVAL: u32 = 10_000;
+
+/// End of the blocks that a sync from `start` commits at once.
+/// The notes and the witnesses are kept in memory until then
+fn commit_point(commit_interval: u32, start: u32, end: u32) -> u32 {
+    let interval = match commit_interval {
+        0 => DEFAULT_COMMIT_INTERVAL,
+        i => i,
+    };
+    end.min(start + interval)
+}
+
 pub async fn warp_sync<BS: CompactBlockSource + 'static>(
     coin: &CoinDef,
     start: CheckpointHeight,
@@ -228,9 +353,15 @@ pub async fn warp_sync<BS: CompactBlockSource + 'static>(
     if !permit.is_ok() {
         return Ok(());
     }
+    let sync_start = std::time::Instant::now();
     let mut connection = coin.connection()?;
     let mut client = coin.connect_lwd()?;
-    let (sapling_state, orchard_state) = get_tree_state(&mut client, start.into()).await?;
+    let lazy_witnesses = coin.config.lazy_witnesses;
+    // Intermediate commit point: the sync stops there, for every source,
+    // and the next sync resumes from it
+    let end = commit_point(coin.config.commit_interval, start.0, end);
+    let (sapling_state, orchard_state) =
+        get_cached_tree_state(&connection, &mut client, start).await?;
 
     let sap_hasher = SaplingHasher::default();
     let mut sap_dec = SaplingSync::new(
@@ -283,8 +414,10 @@ pub async fn warp_sync<BS: CompactBlockSource + 'static>(
 
     let mut bs = vec![];
     let mut bh = BlockHeader::default();
+    let mut headers = vec![];
+    let mut anchors = vec![];
+    let mut stats = vec![];
     let mut c = 0;
-    let chunked = source.chunked();
     let (block_sender, mut block_recv) = channel::<CompactBlock>(20);
     source.run(start.0, end, block_sender)?;
     while let Some(block) = block_recv.recv().await {
@@ -299,8 +432,18 @@ pub async fn warp_sync<BS: CompactBlockSource + 'static>(
             return Err(SyncError::Reorg(bh.height));
         }
         prev_hash = bh.hash;
+        if check_hard_checkpoint(&coin.network, &bh)? {
+            anchors.push(bh.clone());
+        }
+        headers.push(bh.clone());
+        if headers.len() >= 2 * HEADER_CHAIN_LENGTH as usize {
+            headers.drain(..HEADER_CHAIN_LENGTH as usize);
+        }
 
         header_dec.process(&bh)?;
+        if coin.config.store_block_stats {
+            stats.push(block_stats(&block));
+        }
         for vtx in block.vtx.iter() {
             c += vtx.outputs.len();
             c += vtx.actions.len();
@@ -317,16 +460,23 @@ pub async fn warp_sync<BS: CompactBlockSource + 'static>(
         if c >= 1000000 {
             info!("Height {}", height);
             sap_dec.add(&bs)?;
+            sap_dec.detect_spends(&connection, &bs)?;
             orch_dec.add(&bs)?;
+            orch_dec.detect_spends(&connection, &bs)?;
+            metrics().on_blocks(bs.len() as u32);
             bs.clear();
             c = 0;
-            if chunked {
-                break;
-            }
         }
     }
     sap_dec.add(&bs)?;
+    sap_dec.detect_spends(&connection, &bs)?;
     orch_dec.add(&bs)?;
+    orch_dec.detect_spends(&connection, &bs)?;
+    metrics().on_blocks(bs.len() as u32);
+    if bh.height == 0 {
+        // the source had no block after the start
+        return Ok(());
+    }
 
     // Verification
     let (s, o) = get_tree_state(&mut client, CheckpointHeight(bh.height as u32)).await?;
@@ -338,17 +488,19 @@ pub async fn warp_sync<BS: CompactBlockSource + 'static>(
     let r2 = orch_dec.tree_state.root(&orch_dec.hasher);
     assert_eq!(r, r2);
     info!("o_root {}", hex::encode(&r));
+    verify_subtree_roots(&connection, &mut client, false, &sap_dec.subtree_roots).await?;
+    verify_subtree_roots(&connection, &mut client, true, &orch_dec.subtree_roots).await?;
 
     if bh.height != 0 {
         let db_tx = connection.transaction().map_err(anyhow::Error::new)?;
 
-        store_received_note(&db_tx, bh.height, &*sap_dec.notes)?;
+        store_received_note(&db_tx, bh.height, &*sap_dec.notes, lazy_witnesses)?;
         for (tx_value, spend) in sap_dec.spends.iter() {
             add_tx_value(&db_tx, tx_value)?;
             mark_shielded_spent(&db_tx, spend)?;
         }
 
-        store_received_note(&db_tx, bh.height, &*orch_dec.notes)?;
+        store_received_note(&db_tx, bh.height, &*orch_dec.notes, lazy_witnesses)?;
         for (tx_value, spend) in orch_dec.spends.iter() {
             add_tx_value(&db_tx, tx_value)?;
             mark_shielded_spent(&db_tx, spend)?;
@@ -359,28 +511,214 @@ pub async fn warp_sync<BS: CompactBlockSource + 'static>(
         update_tx_timestamp(&db_tx, header_dec.heights.values())?;
 
         store_block(&db_tx, &bh)?;
+        store_headers(&db_tx, &headers, &anchors)?;
+        store_block_stats(&db_tx, &stats)?;
+        store_frontiers(&db_tx, &bh, &s, &o)?;
         update_account_balances(&db_tx)?;
+        tag_transfers(&db_tx)?;
+        tag_change_notes(&coin.network, &db_tx, start.0)?;
 
         // Save block times
         header_dec.save(&db_tx)?;
         copy_block_times_from_tx(&db_tx)?;
 
-        let accounts = list_accounts(coin, &db_tx)?;
-        for a in accounts.items.unwrap() {
-            extend_transparent_addresses(&coin.network, &db_tx, a.id, 0)?;
-            extend_transparent_addresses(&coin.network, &db_tx, a.id, 1)?;
+        finish_sync(coin, &db_tx, bh.height)?;
+        db_tx.commit().map_err(anyhow::Error::new)?;
+        info!(target: SYNC_TARGET, "Committed @{}", bh.height);
+
+        run_sync_hooks(coin, &connection, bh.height).await;
+        store_last_sync(
+            &connection,
+            bh.height,
+            bh.height - start.0,
+            sync_start.elapsed(),
+        )?;
+        metrics().on_sync(bh.height - start.0, sync_start.elapsed());
+    }
+    tracing::info!(target: SYNC_TARGET, "Sync finished");
+
+    Ok(())
+}
+
+// Sizes of the v5 transaction components, for the block size estimate
+pub(crate) const TX_BASE_SIZE: u32 = 100;
+pub(crate) const TRANSPARENT_INPUT_SIZE: u32 = 148;
+pub(crate) const TRANSPARENT_OUTPUT_SIZE: u32 = 34;
+pub(crate) const SAPLING_SPEND_SIZE: u32 = 352;
+pub(crate) const SAPLING_OUTPUT_SIZE: u32 = 948;
+pub(crate) const ORCHARD_ACTION_SIZE: u32 = 884 + 2272; // with its share of the proof
+pub(crate) const ORCHARD_BUNDLE_SIZE: u32 = 2720 + 32 + 64 + 8 + 1;
+
+/// Counts and estimated size of a compact block (see `store_block_stats`)
+fn block_stats(block: &CompactBlock) -> BlockInfoT {
+    let mut info = BlockInfoT {
+        height: block.height as u32,
+        hash: Some(block.hash.clone()),
+        timestamp: block.time,
+        num_txs: block.vtx.len() as u32,
+        size: 80 + 1344, // header and equihash solution
+        ..BlockInfoT::default()
+    };
+    let bridge_len = |b: &Option<Bridge>| b.as_ref().map(|b| b.len).unwrap_or_default();
+    for vtx in block.vtx.iter() {
+        let num_spends = vtx.spends.len() as u32;
+        let num_outputs = vtx.outputs.len() as u32 + bridge_len(&vtx.sapling_bridge);
+        let num_actions = vtx.actions.len() as u32 + bridge_len(&vtx.orchard_bridge);
+        info.num_spends += num_spends;
+        info.num_outputs += num_outputs;
+        info.num_actions += num_actions;
+        info.size += TX_BASE_SIZE
+            + num_spends * SAPLING_SPEND_SIZE
+            + num_outputs * SAPLING_OUTPUT_SIZE
+            + num_actions * ORCHARD_ACTION_SIZE;
+        if num_actions > 0 {
+            info.size += ORCHARD_BUNDLE_SIZE;
         }
+    }
+    info
+}
 
-        recover_expired_spends(&db_tx, bh.height)?;
-        db_tx.commit().map_err(anyhow::Error::new)?;
+/*
+    Lightweight sync for wallets that only have transparent accounts
+    (for example, exchange integrations).
+    There are no notes to decrypt and no commitment trees to maintain,
+    so the compact blocks are not downloaded. The transactions come
+    from the address index of the server (GetTaddressTxids) and
+    only the blocks that have one of our transactions are fetched
+    for their timestamp.
+    Frontiers are not stored. If a shielded account is added later,
+    the tree state is requested from the server.
+*/
+pub async fn warp_sync_transparent(
+    coin: &CoinDef,
+    start: CheckpointHeight,
+    end: u32,
+) -> Result<(), SyncError> {
+    tracing::info!("Transparent only {:?}-{}", start, end);
+    let permit = SYNC_LOCK.acquire().await;
+    if !permit.is_ok() {
+        return Ok(());
     }
-    tracing::info!("Sync finished");
+    let mut connection = coin.connection()?;
+    let mut client = coin.connect_lwd()?;
 
+    // The last synced block must still be on the chain
+    let start_bh = get_block_header(&connection, start.into())?;
+    let block = get_compact_block(&mut client, start.0).await?;
+    if block.hash != start_bh.hash {
+        rewind_checkpoint(&coin.network, &mut connection, &mut client).await?;
+        return Err(SyncError::Reorg(start.0));
+    }
+
+    let mut trp_dec = TransparentSync::new(&coin.network, &connection)?;
+    let addresses = trp_dec.addresses.clone();
+    for (path, taddr) in addresses.into_iter() {
+        let txs = get_transparent(
+            &coin.network,
+            &mut client,
+            path.account,
+            path.external,
+            path.addr_index,
+            taddr,
+            start.0 + 1,
+            end,
+        )
+        .await?;
+        let address = taddr.encode(&coin.network);
+        trp_dec.process_txs(&address, &txs)?;
+    }
+
+    let mut header_dec = BlockHeaderStore::new();
+    header_dec.add_heights(trp_dec.heights.iter())?;
+    let mut heights = trp_dec.heights.iter().copied().collect::<Vec<_>>();
+    heights.sort();
+    for h in heights {
+        let block = get_compact_block(&mut client, h).await?;
+        header_dec.process(&BlockHeader::from(&block))?;
+    }
+    let block = get_compact_block(&mut client, end).await?;
+    let bh = BlockHeader::from(&block);
+
+    let db_tx = connection.transaction().map_err(anyhow::Error::new)?;
+    trp_dec.flush(&db_tx)?;
+    update_tx_timestamp(&db_tx, header_dec.heights.values())?;
+    store_block(&db_tx, &bh)?;
+    // the blocks in between are not downloaded,
+    // a new header chain starts at this block
+    clear_headers(&db_tx)?;
+    store_headers(&db_tx, &[bh.clone()], &[])?;
+    update_account_balances(&db_tx)?;
+    tag_transfers(&db_tx)?;
+    tag_change_notes(&coin.network, &db_tx, start.0)?;
+    header_dec.save(&db_tx)?;
+    copy_block_times_from_tx(&db_tx)?;
+    finish_sync(coin, &db_tx, bh.height)?;
+    db_tx.commit().map_err(anyhow::Error::new)?;
+    info!(target: SYNC_TARGET, "Committed @{}", bh.height);
+
+    run_sync_hooks(coin, &connection, bh.height).await;
+    tracing::info!(target: SYNC_TARGET, "Sync finished");
+
+    Ok(())
+}
+
+fn finish_sync(coin: &CoinDef, db_tx: &Transaction, height: u32) -> Result<()> {
+    let grace_period = match coin.config.account_grace_period {
+        0 => DEFAULT_ACCOUNT_GRACE_PERIOD,
+        p => p,
+    };
+    let purged = purge_deleted_accounts(db_tx, grace_period)?;
+    if purged > 0 {
+        info!("Purged {} deleted accounts", purged);
+    }
+
+    let accounts = list_accounts(coin, db_tx)?;
+    for a in accounts.items.unwrap() {
+        extend_transparent_addresses(&coin.network, db_tx, a.id, 0)?;
+        extend_transparent_addresses(&coin.network, db_tx, a.id, 1)?;
+    }
+
+    for (account, txid) in update_broadcast_txs(db_tx, height)? {
+        tracing::warn!(
+            "Transaction {} of account {} expired",
+            to_txid_str(&txid),
+            account
+        );
+    }
+    recover_expired_spends(db_tx, height)?;
+    dispatch_webhooks(coin, db_tx, height)?;
     Ok(())
 }
 
+async fn run_sync_hooks(coin: &CoinDef, connection: &Connection, height: u32) {
+    if let Err(e) = flush_logs(connection) {
+        tracing::warn!("Logs: {}", e);
+    }
+    spawn_delivery(coin);
+    if let Err(e) = process_acks(coin, connection, height).await {
+        tracing::warn!("Acknowledgments: {}", e);
+    }
+    if let Err(e) = sweep_tex_change(coin, connection, height).await {
+        tracing::warn!("TEX change: {}", e);
+    }
+}
+
 #[c_export]
 pub async fn warp_synchronize(coin: &CoinDef, end_height: u32) -> Result<()> {
+    let res = warp_synchronize_inner(coin, end_height).await;
+    if let Err(e) = &res {
+        let kind = match e.downcast_ref::<SyncError>() {
+            Some(SyncError::Reorg(_)) => ErrorKind::Reorg,
+            Some(SyncError::Lwd(_)) => ErrorKind::Lwd,
+            _ => ErrorKind::Other,
+        };
+        metrics().on_error(kind);
+    }
+    res
+}
+
+async fn warp_synchronize_inner(coin: &CoinDef, end_height: u32) -> Result<()> {
+    coin.check_writable()?;
     let mut connection = coin.connection()?;
     let start_height = get_sync_height(&connection)?.height;
     if start_height == 0 {
@@ -394,17 +732,17 @@ pub async fn warp_synchronize(coin: &CoinDef, end_height: u32) -> Result<()> {
         )
         .await?;
     }
+    if start_height < end_height && is_transparent_only(&connection)? {
+        warp_sync_transparent(&coin, CheckpointHeight(start_height), end_height).await?;
+        return Ok(());
+    }
     if start_height < end_height {
         let end_height = (start_height + 100_000).min(end_height);
-        let channel = if end_height < coin.config.warp_end_height {
-            let url = fb_unwrap!(coin.config.warp_url);
-            tracing::info!("Using Warp block server @ {}", url);
-            let ep = Channel::from_shared(url.clone()).unwrap();
-            ep.connect().await?
+        let bs = if end_height < coin.config.warp_end_height {
+            warp_block_source(coin, start_height + 1).await?
         } else {
-            fb_unwrap!(coin.channel).clone()
+            LWDCompactBlockSource::new(fb_unwrap!(coin.channel).clone())?
         };
-        let bs = LWDCompactBlockSource::new(channel)?;
         warp_sync(&coin, CheckpointHeight(start_height), end_height, bs).await?;
     }
     Ok(())
@@ -416,11 +754,7 @@ struct FileCompactBlockSource {
 }
 
 impl CompactBlockSource for FileCompactBlockSource {
-    fn chunked(&self) -> bool {
-        false
-    }
-
-    fn run(self, _start: u32, _end: u32, sender: Sender<CompactBlock>) -> Result<()> {
+    fn run(self, start: u32, end: u32, sender: Sender<CompactBlock>) -> Result<()> {
         tokio::spawn(async move {
             let file = File::open(self.file)?;
             let mut reader = BufReader::new(file);
@@ -428,6 +762,13 @@ impl CompactBlockSource for FileCompactBlockSource {
                 let mut buf = vec![0u8; size as usize];
                 reader.read_exact(&mut buf)?;
                 let block = CompactBlock::decode(&*buf)?;
+                // the blocks before a commit point are already synced
+                if block.height <= start as u64 {
+                    continue;
+                }
+                if block.height > end as u64 {
+                    break;
+                }
                 sender.send(block).await?;
             }
             Ok::<_, anyhow::Error>(())
@@ -438,6 +779,7 @@ impl CompactBlockSource for FileCompactBlockSource {
 
 #[c_export]
 pub async fn warp_synchronize_from_file(coin: &CoinDef, file: &str) -> Result<()> {
+    coin.check_writable()?;
     let source = FileCompactBlockSource {
         file: file.to_string(),
     };
@@ -447,16 +789,35 @@ pub async fn warp_synchronize_from_file(coin: &CoinDef, file: &str) -> Result<()
         let mut client = coin.connect_lwd()?;
         reset_chain(&coin.network, &mut connection, &mut client, activation).await?;
     }
-    warp_sync(
-        &coin,
-        CheckpointHeight(activation),
-        coin.config.warp_end_height,
-        source,
-    )
-    .await?;
+    // resume from the commit points until the end of the file
+    let mut start = activation;
+    loop {
+        warp_sync(
+            &coin,
+            CheckpointHeight(start),
+            coin.config.warp_end_height,
+            source.clone(),
+        )
+        .await?;
+        let connection = coin.connection()?;
+        let height = get_sync_height(&connection)?.height;
+        if height == start || height >= coin.config.warp_end_height {
+            break;
+        }
+        start = height;
+    }
     Ok(())
 }
 
+/// Number of blocks of transparent history fetched in one request
+pub const TRANSPARENT_SCAN_WINDOW: u32 = 100_000;
+
+/// Rebuild the transparent history of the account.
+/// The history of every address is fetched by windows of
+/// TRANSPARENT_SCAN_WINDOW blocks and saved with the height reached.
+/// If the scan is interrupted, the next call resumes from there
+/// instead of starting over from the activation height. Until then,
+/// the transparent history of the account is incomplete.
 #[c_export]
 pub async fn transparent_scan(
     network: &Network,
@@ -465,32 +826,45 @@ pub async fn transparent_scan(
     account: u32,
     end_height: u32,
 ) -> Result<()> {
-    drop_transparent_data(connection, account)?;
-    let mut trp_dec = TransparentSync::new(network, connection)?;
-    let addresses = list_account_transparent_addresses(connection, account)?;
-    let start = get_activation_height(network)?;
-    if start >= end_height {
+    let activation = get_activation_height(network)?;
+    if activation >= end_height {
         return Ok(());
     }
-    let db_tx = connection.transaction()?;
+    if !has_transparent_scan_progress(connection, account)? {
+        drop_transparent_data(connection, account)?;
+    }
+    let addresses = list_account_transparent_addresses(connection, account)?;
     for a in addresses {
         let taddr = a.address.as_deref().unwrap();
         let address = TransparentAddress::decode(network, taddr)?;
-        let txs = get_transparent(
-            network,
-            client,
-            a.account,
-            a.external,
-            a.addr_index,
-            address,
-            start,
-            end_height,
-        )
-        .await?;
-        trp_dec.process_txs(taddr, &*txs)?;
+        let mut start = get_transparent_scan_height(connection, account, taddr)?
+            .map(|h| h + 1)
+            .unwrap_or(activation);
+        while start <= end_height {
+            let end = (start + TRANSPARENT_SCAN_WINDOW - 1).min(end_height);
+            let txs = lwd_retry!(get_transparent(
+                network,
+                client,
+                a.account,
+                a.external,
+                a.addr_index,
+                address,
+                start,
+                end,
+            ))?;
+            // reload the utxos to find the spends of the previous windows
+            let mut trp_dec = TransparentSync::new(network, connection)?;
+            trp_dec.process_txs(taddr, &*txs)?;
+            let db_tx = connection.transaction()?;
+            trp_dec.flush(&db_tx)?;
+            store_transparent_scan_height(&db_tx, account, taddr, end)?;
+            db_tx.commit()?;
+            start = end + 1;
+        }
     }
-    trp_dec.flush(&db_tx)?;
+    let db_tx = connection.transaction()?;
     update_tx_values(&db_tx)?;
+    tag_transfers(&db_tx)?;
 
     // there may be some block heights for which we don't have the time
     update_tx_time(&db_tx)?;
@@ -507,13 +881,141 @@ pub async fn transparent_scan(
     // try again
     update_tx_time(&db_tx)?;
 
+    // the hashes of the blocks, to check them after a reorg
+    let heights = list_unknown_transparent_blocks(&db_tx)?;
+    for h in heights {
+        let cb = get_compact_block(client, h).await?;
+        let bh = BlockHeader::from(&cb);
+        store_transparent_block(&db_tx, h, &bh.hash)?;
+        store_block_time(&db_tx, h, bh.timestamp)?;
+    }
+
     extend_transparent_addresses(network, &db_tx, account, 0)?;
     extend_transparent_addresses(network, &db_tx, account, 1)?;
+    clear_transparent_scan_progress(&db_tx, account)?;
     db_tx.commit()?;
 
     Ok(())
 }
 
+/*
+    Transparent reorgs
+
+    The transparent txs come from the server by address and height,
+    without their block. The wallet keeps the hash of every block
+    that has a transparent tx of its addresses: the sync has it from
+    the headers and `transparent_scan` fetches it.
+
+    A rewind drops everything after its checkpoint, but a reorg can
+    be deeper than the last checkpoint that is kept. After the rewind,
+    `revalidate_transparent_blocks` compares the hashes of the last
+    TRANSPARENT_REORG_WINDOW blocks before the checkpoint with the
+    server. The data of a block that changed is dropped: its UTXOs,
+    the spends of UTXOs and the txs that had nothing else. Then the
+    txs of the addresses at this height are fetched again.
+    Only these blocks are invalidated, instead of a rescan of the
+    whole transparent history.
+*/
+
+pub const TRANSPARENT_REORG_WINDOW: u32 = 100;
+
+/// Check the transparent blocks before `height` against the server,
+/// and replace the txs of the blocks that are no longer on the chain.
+/// Returns the heights of these blocks
+pub async fn revalidate_transparent_blocks(
+    network: &Network,
+    connection: &mut Connection,
+    client: &mut Client,
+    height: u32,
+) -> Result<Vec<u32>> {
+    let start = height.saturating_sub(TRANSPARENT_REORG_WINDOW);
+    let blocks = list_transparent_blocks(connection, start, height)?;
+    let mut stale = vec![];
+    for (h, hash) in blocks {
+        let cb = get_compact_block(client, h).await?;
+        let bh = BlockHeader::from(&cb);
+        if bh.hash != hash {
+            tracing::info!("Transparent block @{h} is no longer on the chain");
+            stale.push(bh);
+        }
+    }
+    if stale.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let db_tx = connection.transaction()?;
+    for bh in stale.iter() {
+        drop_transparent_block(&db_tx, bh.height)?;
+    }
+    db_tx.commit()?;
+
+    let mut trp_dec = TransparentSync::new(network, connection)?;
+    let addresses = trp_dec.addresses.clone();
+    for bh in stale.iter() {
+        for (path, taddr) in addresses.iter() {
+            let txs = get_transparent(
+                network,
+                client,
+                path.account,
+                path.external,
+                path.addr_index,
+                *taddr,
+                bh.height,
+                bh.height,
+            )
+            .await?;
+            let address = taddr.encode(network);
+            trp_dec.process_txs(&address, &txs)?;
+        }
+    }
+
+    let db_tx = connection.transaction()?;
+    trp_dec.flush(&db_tx)?;
+    let headers = stale.iter().cloned().map(Some).collect::<Vec<_>>();
+    update_tx_timestamp(&db_tx, headers.iter())?;
+    for bh in stale.iter() {
+        store_transparent_block(&db_tx, bh.height, &bh.hash)?;
+        store_block_time(&db_tx, bh.height, bh.timestamp)?;
+    }
+    update_tx_values(&db_tx)?;
+    update_account_balances(&db_tx)?;
+    tag_transfers(&db_tx)?;
+    db_tx.commit()?;
+
+    Ok(stale.iter().map(|bh| bh.height).collect())
+}
+
 lazy_static! {
     static ref SYNC_LOCK: Arc<Semaphore> = Arc::new(Semaphore::new(1));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{commit_point, DEFAULT_COMMIT_INTERVAL};
+
+    /// Heights of the commits of the syncs from `start` to `end`
+    fn commits(commit_interval: u32, start: u32, end: u32) -> Vec<u32> {
+        let mut heights = vec![];
+        let mut height = start;
+        while height < end {
+            height = commit_point(commit_interval, height, end);
+            heights.push(height);
+        }
+        heights
+    }
+
+    #[test]
+    fn long_range_is_committed_by_parts() {
+        let heights = commits(0, 1_000_000, 1_100_000);
+        assert_eq!(heights.len(), (100_000 / DEFAULT_COMMIT_INTERVAL) as usize);
+        assert_eq!(heights.last(), Some(&1_100_000));
+
+        let heights = commits(30_000, 1_000_000, 1_100_000);
+        assert_eq!(heights, vec![1_030_000, 1_060_000, 1_090_000, 1_100_000]);
+    }
+
+    #[test]
+    fn short_range_is_committed_once() {
+        assert_eq!(commits(0, 1_000_000, 1_000_100), vec![1_000_100]);
+    }
+}
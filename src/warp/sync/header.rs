@@ -3,7 +3,10 @@ use std::collections::HashMap;
 use anyhow::Result;
 use rusqlite::Connection;
 
-use crate::{db::tx::store_block_time, warp::BlockHeader};
+use crate::{
+    db::tx::{store_block_time, store_transparent_block},
+    warp::BlockHeader,
+};
 
 pub struct BlockHeaderStore {
     pub heights: HashMap<u32, Option<BlockHeader>>,
@@ -35,6 +38,7 @@ impl BlockHeaderStore {
             if let Some(header) = header {
                 let timestamp = header.timestamp;
                 store_block_time(connection, *height, timestamp)?;
+                store_transparent_block(connection, *height, &header.hash)?;
             }
         }
         Ok(())
@@ -1,15 +1,17 @@
 use rusqlite::Connection;
 use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::mpsc::Sender;
+use std::time::Instant;
 use std::{collections::HashMap, mem::swap, sync::mpsc::channel};
 
 use crate::coin::CoinDef;
-use crate::db::notes::list_all_received_notes;
+use crate::db::notes::{find_notes_by_nf, list_all_received_notes};
 use crate::lwd::rpc::CompactTx;
 use crate::network::Network;
 use crate::warp::sync::IdSpent;
 use crate::{
-    db::account::{get_account_info, list_accounts},
+    db::account::{get_account_info, list_account_scan_starts, list_accounts},
     lwd::rpc::{Bridge, CompactBlock},
     types::{AccountInfo, CheckpointHeight},
     Hash,
@@ -20,11 +22,16 @@ use tracing::info;
 
 use crate::warp::{Edge, Hasher, MERKLE_DEPTH};
 
-use super::{ReceivedNote, TxValueUpdate};
+use super::{subtrees::SUBTREE_DEPTH, ReceivedNote, TxValueUpdate};
 
 pub mod orchard;
 pub mod sapling;
 
+/// Approximate number of commitments added to the tree at a time.
+/// The nodes of the tree update take ~50 bytes per commitment,
+/// whatever the size of the batch of blocks
+pub const TREE_STRIP_SIZE: usize = 1 << 16;
+
 pub trait ShieldedProtocol {
     type Hasher: Hasher;
     type IVK: Sync;
@@ -40,6 +47,8 @@ pub trait ShieldedProtocol {
 
     fn extract_nf(i: &Self::Spend) -> Hash;
     fn extract_cmx(o: &Self::Output) -> Hash;
+    /// False if the output cannot have a note (see decrypter)
+    fn is_candidate(o: &Self::Output) -> bool;
 
     fn try_decrypt(
         network: &Network,
@@ -59,11 +68,26 @@ pub struct Synchronizer<P: ShieldedProtocol> {
     pub hasher: P::Hasher,
     pub network: Network,
     pub account_infos: Vec<AccountInfo>,
+    /// Incoming viewing keys of the accounts, derived once
+    /// instead of at every batch of blocks
+    pub ivks: Vec<(u32, P::IVK)>,
+    /// First height to decrypt with each of `ivks`, in increasing
+    /// order. The accounts created new skip the blocks before them
+    pub ivk_starts: Vec<u32>,
+    /// Account id -> index in account_infos
+    pub account_index: HashMap<u32, usize>,
     pub start: u32,
     pub notes: Vec<ReceivedNote>,
+    /// Nullifier -> index in notes, of the notes received during
+    /// this sync. The others are looked up in the nullifiers table
+    pub new_nfs: HashMap<Hash, Vec<usize>>,
     pub spends: Vec<(TxValueUpdate, IdSpent<Hash>)>,
+    /// Skip the outputs that cannot be notes before the trial decryption
+    pub prefilter: bool,
     pub position: u32,
     pub tree_state: Edge,
+    /// (index, root) of the subtrees completed during this sync
+    pub subtree_roots: Vec<(u32, Hash)>,
     pub _data: PhantomData<P>,
 }
 
@@ -89,29 +113,50 @@ impl<P: ShieldedProtocol> Synchronizer<P> {
             let ai = get_account_info(network, connection, a.id)?;
             account_infos.push(ai);
         }
+        let scan_starts = list_account_scan_starts(connection)?;
+        let mut ivks = account_infos
+            .iter()
+            .filter_map(P::extract_ivk)
+            .map(|(account, ivk)| {
+                let start = scan_starts.get(&account).copied().unwrap_or_default();
+                (start, (account, ivk))
+            })
+            .collect::<Vec<_>>();
+        // the keys of a batch of blocks are a prefix of the list
+        ivks.sort_by_key(|(start, _)| *start);
+        let (ivk_starts, ivks): (Vec<_>, Vec<_>) = ivks.into_iter().unzip();
+        let account_index = account_infos
+            .iter()
+            .enumerate()
+            .map(|(i, ai)| (ai.account, i))
+            .collect::<HashMap<_, _>>();
         let is_orchard = P::is_orchard();
-        let notes = list_all_received_notes(connection, start, is_orchard)?;
+        // only the notes of the synchronized accounts need witness updates
+        let notes = list_all_received_notes(connection, start, is_orchard)?
+            .into_iter()
+            .filter(|n| account_index.contains_key(&n.account))
+            .collect::<Vec<_>>();
 
         Ok(Self {
             hasher: P::Hasher::default(),
             network: *network,
             account_infos,
+            ivks,
+            ivk_starts,
+            account_index,
             start: start.into(),
             notes,
+            new_nfs: HashMap::new(),
             spends: vec![],
+            prefilter: coin.config.prefilter_outputs,
             position,
             tree_state,
+            subtree_roots: vec![],
             _data: PhantomData::<P>::default(),
         })
     }
 
     pub fn add(&mut self, blocks: &[CompactBlock]) -> Result<()> {
-        let ivks = self
-            .account_infos
-            .iter()
-            .filter_map(P::extract_ivk)
-            .collect::<Vec<_>>();
-
         let outputs = blocks.into_par_iter().flat_map_iter(|b| {
             b.vtx.iter().enumerate().flat_map(move |(ivtx, vtx)| {
                 P::extract_outputs(vtx)
@@ -121,13 +166,29 @@ impl<P: ShieldedProtocol> Synchronizer<P> {
             })
         });
 
+        let decrypt_start = Instant::now();
+        let skipped = AtomicU32::new(0);
+        let prefilter = self.prefilter;
+        let outputs = outputs.filter(|(.., o)| {
+            let candidate = !prefilter || P::is_candidate(o);
+            if !candidate {
+                skipped.fetch_add(1, Ordering::Relaxed);
+            }
+            candidate
+        });
+
+        // skip the accounts created after these blocks
+        let end = blocks.last().map(|b| b.height as u32).unwrap_or_default();
+        let n_ivks = self.ivk_starts.iter().take_while(|s| **s <= end).count();
+        let ivks = &self.ivks[..n_ivks];
+
         let (sender, receiver) = channel();
         outputs
             .into_par_iter()
             .for_each_with(sender, |sender, (height, time, ivtx, vout, o)| {
                 P::try_decrypt(
                     &self.network,
-                    &ivks,
+                    ivks,
                     height as u32,
                     time,
                     ivtx as u32,
@@ -138,6 +199,12 @@ impl<P: ShieldedProtocol> Synchronizer<P> {
                 .unwrap();
             });
 
+        tracing::debug!(
+            "Trial decryption in {} ms, {} outputs skipped by the prefilter",
+            decrypt_start.elapsed().as_millis(),
+            skipped.load(Ordering::Relaxed)
+        );
+
         let mut notes = vec![];
         while let Ok(mut note) = receiver.recv() {
             let mut position = self.position;
@@ -165,36 +232,136 @@ impl<P: ShieldedProtocol> Synchronizer<P> {
             position += note.vout;
             note.position = position;
 
-            let ai = self
-                .account_infos
-                .iter()
-                .find(|&ai| ai.account == note.account)
-                .unwrap();
-            let txid = cb.vtx[note.tx.ivtx as usize]
-                .hash
-                .clone()
-                .try_into()
-                .unwrap();
+            let ai = &self.account_infos[self.account_index[&note.account]];
+            let tx = &cb.vtx[note.tx.ivtx as usize];
+            let txid = tx.hash.clone().try_into().unwrap();
+            // the coinbase is the first transaction of the block
+            note.coinbase = tx.index == 0;
             P::finalize_received_note(txid, &mut note, ai)?;
             notes.push(note);
         }
 
-        let mut bridges = vec![];
-        let mut p = self.position;
+        tracing::info!("Old notes #{}", self.notes.len());
+        tracing::info!("New notes #{}", notes.len());
+
+        // update the tree by strips of transactions: the nodes of
+        // a strip are dropped before the next one
+        let mut strip = vec![];
+        let mut strip_len = 0;
+        let mut end = self.position;
+        for tx in blocks.iter().flat_map(|cb| cb.vtx.iter()) {
+            let len = P::extract_outputs(tx).len() as u32
+                + P::extract_bridge(tx).map(|b| b.len).unwrap_or_default();
+            strip.push(tx);
+            strip_len += len as usize;
+            end += len;
+            if strip_len >= TREE_STRIP_SIZE {
+                let (strip_notes, rest): (Vec<_>, Vec<_>) =
+                    notes.into_iter().partition(|n| n.position < end);
+                notes = rest;
+                self.update_tree(&strip, strip_notes);
+                strip.clear();
+                strip_len = 0;
+            }
+        }
+        if !strip.is_empty() {
+            self.update_tree(&strip, notes);
+        }
+        self.start += blocks.len() as u32;
+
+        info!("# {}", self.notes.len());
+        tracing::debug!("Peak RSS {:?} kB", peak_rss_kb());
+        // let auth_path = self.tree_state.to_auth_path(&self.hasher);
+        // for note in self.notes.iter() {
+        //     let root = note.witness.root(&auth_path, &self.hasher);
+        //     info!("{}", hex::encode(&root));
+        // }
+
+        Ok(())
+    }
+
+    /// Find the notes spent in `blocks`, among the notes received
+    /// during this sync and the notes of the database. Must be called
+    /// after `add`
+    pub fn detect_spends(
+        &mut self,
+        connection: &Connection,
+        blocks: &[CompactBlock],
+    ) -> Result<()> {
+        // index of the notes loaded from the database, by id
+        let loaded_notes = self
+            .notes
+            .iter()
+            .enumerate()
+            .filter(|(_, n)| !n.is_new)
+            .map(|(i, n)| (n.id, i))
+            .collect::<HashMap<_, _>>();
         for cb in blocks.iter() {
-            for tx in cb.vtx.iter() {
-                p += P::extract_outputs(tx).len() as u32;
-                if let Some(b) = P::extract_bridge(tx) {
-                    let be = BridgeExt {
-                        b,
-                        s: p as i32,
-                        e: (p + b.len - 1) as i32,
-                    };
-                    bridges.push(be);
-                    p += b.len;
+            for vtx in cb.vtx.iter() {
+                let txid: Hash = vtx.hash.clone().try_into().unwrap();
+                for sp in P::extract_inputs(vtx).iter() {
+                    let nf = P::extract_nf(sp);
+                    let mut spent = vec![];
+                    if let Some(ns) = self.new_nfs.get(&nf) {
+                        for &i in ns {
+                            let n = &mut self.notes[i];
+                            n.spent = Some(cb.height as u32);
+                            spent.push((n.id, n.account, n.value));
+                        }
+                    }
+                    let old_notes = find_notes_by_nf(connection, &nf, P::is_orchard())?;
+                    for (id_note, account, value) in old_notes {
+                        // skip the notes of the deleted accounts, like `new`
+                        if !self.account_index.contains_key(&account) {
+                            continue;
+                        }
+                        // the loaded copy must not be treated as unspent
+                        if let Some(&i) = loaded_notes.get(&id_note) {
+                            self.notes[i].spent = Some(cb.height as u32);
+                        }
+                        spent.push((id_note, account, value));
+                    }
+                    for (id_note, account, value) in spent {
+                        let id_spent = IdSpent::<Hash> {
+                            id_note,
+                            account,
+                            height: cb.height as u32, // height at which the spent occurs
+                            txid,
+                            note_ref: nf,
+                        };
+                        let tx = TxValueUpdate {
+                            account,
+                            txid,
+                            value: -(value as i64),
+                            id_tx: 0,
+                            height: cb.height as u32,
+                            timestamp: cb.time,
+                        };
+                        self.spends.push((tx, id_spent));
+                    }
                 }
             }
         }
+        Ok(())
+    }
+
+    /// Append the commitments of `txs` to the tree, and update
+    /// the witnesses of the old notes and of the `notes` received in `txs`
+    fn update_tree(&mut self, txs: &[&CompactTx], mut notes: Vec<ReceivedNote>) {
+        let mut bridges = vec![];
+        let mut p = self.position;
+        for tx in txs.iter() {
+            p += P::extract_outputs(tx).len() as u32;
+            if let Some(b) = P::extract_bridge(tx) {
+                let be = BridgeExt {
+                    b,
+                    s: p as i32,
+                    e: (p + b.len - 1) as i32,
+                };
+                bridges.push(be);
+                p += b.len;
+            }
+        }
 
         let mut cmxs = vec![];
         let mut count_cmxs = 0;
@@ -209,19 +376,17 @@ impl<P: ShieldedProtocol> Synchronizer<P> {
 
             // slightly more efficient than doing it before the insert
             if depth == 0 {
-                for cb in blocks.iter() {
-                    for vtx in cb.vtx.iter() {
-                        for co in P::extract_outputs(vtx).iter() {
-                            let cmx = P::extract_cmx(co);
-                            cmxs.push(Some(cmx));
-                        }
-                        count_cmxs += P::extract_outputs(vtx).len();
-                        if let Some(b) = P::extract_bridge(vtx) {
-                            for _ in 0..b.len {
-                                cmxs.push(None);
-                            }
-                            count_cmxs += b.len as usize;
+                for vtx in txs.iter() {
+                    for co in P::extract_outputs(vtx).iter() {
+                        let cmx = P::extract_cmx(co);
+                        cmxs.push(Some(cmx));
+                    }
+                    count_cmxs += P::extract_outputs(vtx).len();
+                    if let Some(b) = P::extract_bridge(vtx) {
+                        for _ in 0..b.len {
+                            cmxs.push(None);
                         }
+                        count_cmxs += b.len as usize;
                     }
                 }
             }
@@ -299,6 +464,18 @@ impl<P: ShieldedProtocol> Synchronizer<P> {
                 }
             }
 
+            // every node above the leaves is complete, but
+            // the ones inside a bridge are unknown
+            if depth == SUBTREE_DEPTH {
+                let first = self.position >> depth;
+                for (i, n) in cmxs.iter().enumerate() {
+                    let index = position + i as u32;
+                    if let (true, Some(n)) = (index >= first, n) {
+                        self.subtree_roots.push((index, *n));
+                    }
+                }
+            }
+
             // save last node if not a full pair
             if len % 2 == 1 {
                 self.tree_state.0[depth] = cmxs[len - 1];
@@ -312,56 +489,18 @@ impl<P: ShieldedProtocol> Synchronizer<P> {
             swap(&mut cmxs, &mut cmxs2);
         }
 
-        tracing::info!("Old notes #{}", self.notes.len());
-        tracing::info!("New notes #{}", notes.len());
+        let offset = self.notes.len();
+        for (i, n) in notes.iter().enumerate() {
+            self.new_nfs.entry(n.nf).or_default().push(offset + i);
+        }
         self.notes.append(&mut notes);
         self.position += count_cmxs as u32;
-        self.start += blocks.len() as u32;
-
-        // detect spends
-
-        let mut nfs: HashMap<Hash, Vec<&mut ReceivedNote>> = HashMap::new();
-        for n in self.notes.iter_mut() {
-            let e = nfs.entry(n.nf).or_insert(vec![]);
-            e.push(n);
-        }
-
-        for cb in blocks.iter() {
-            for vtx in cb.vtx.iter() {
-                for sp in P::extract_inputs(vtx).iter() {
-                    let nf = P::extract_nf(sp);
-                    if let Some(ns) = nfs.get_mut(&nf) {
-                        for n in ns {
-                            n.spent = Some(cb.height as u32);
-                            let id_spent = IdSpent::<Hash> {
-                                id_note: n.id,
-                                account: n.account,
-                                height: cb.height as u32, // height at which the spent occurs
-                                txid: vtx.hash.clone().try_into().unwrap(),
-                                note_ref: nf.clone().try_into().unwrap(),
-                            };
-                            let tx = TxValueUpdate {
-                                account: n.account,
-                                txid: vtx.hash.clone().try_into().unwrap(),
-                                value: -(n.value as i64),
-                                id_tx: 0,
-                                height: cb.height as u32,
-                                timestamp: cb.time,
-                            };
-                            self.spends.push((tx, id_spent));
-                        }
-                    }
-                }
-            }
-        }
-
-        info!("# {}", self.notes.len());
-        // let auth_path = self.tree_state.to_auth_path(&self.hasher);
-        // for note in self.notes.iter() {
-        //     let root = note.witness.root(&auth_path, &self.hasher);
-        //     info!("{}", hex::encode(&root));
-        // }
-
-        Ok(())
     }
 }
+
+/// Peak resident set size of the process (linux and android only)
+fn peak_rss_kb() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let hwm = status.lines().find_map(|l| l.strip_prefix("VmHWM:"))?;
+    hwm.trim().trim_end_matches("kB").trim().parse().ok()
+}
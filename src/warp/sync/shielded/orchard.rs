@@ -12,7 +12,7 @@ use crate::{
     lwd::rpc::{Bridge, CompactOrchardAction, CompactTx},
     network::Network,
     types::AccountInfo,
-    warp::{hasher::OrchardHasher, sync::ReceivedNote, try_orchard_decrypt},
+    warp::{hasher::OrchardHasher, is_orchard_candidate, sync::ReceivedNote, try_orchard_decrypt},
     Hash,
 };
 
@@ -56,6 +56,10 @@ impl ShieldedProtocol for OrchardProtocol {
         o.cmx.clone().try_into().unwrap()
     }
 
+    fn is_candidate(o: &Self::Output) -> bool {
+        is_orchard_candidate(o)
+    }
+
     fn try_decrypt(
         network: &Network,
         ivks: &[(u32, Self::IVK)],
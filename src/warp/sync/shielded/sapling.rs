@@ -5,7 +5,7 @@ use sapling_crypto::{value::NoteValue, Note, PaymentAddress, Rseed, SaplingIvk};
 use crate::{
     lwd::rpc::{Bridge, CompactSaplingOutput, CompactSaplingSpend, CompactTx},
     types::AccountInfo,
-    warp::{hasher::SaplingHasher, sync::ReceivedNote, try_sapling_decrypt},
+    warp::{hasher::SaplingHasher, is_sapling_candidate, sync::ReceivedNote, try_sapling_decrypt},
     Hash,
 };
 
@@ -49,6 +49,10 @@ impl ShieldedProtocol for SaplingProtocol {
         o.cmu.clone().try_into().unwrap()
     }
 
+    fn is_candidate(o: &Self::Output) -> bool {
+        is_sapling_candidate(o)
+    }
+
     fn try_decrypt(
         network: &crate::network::Network,
         ivks: &[(u32, Self::IVK)],
@@ -0,0 +1,68 @@
+use anyhow::Result;
+use rusqlite::Connection;
+
+use crate::{
+    db::chain::{get_subtree_root, store_subtree_roots},
+    lwd::get_subtree_roots,
+    Client, Hash,
+};
+
+/*
+    Subtree roots
+
+    Servers made for spend-before-sync wallets publish the roots of
+    the completed subtrees of the note commitment trees (2^16 leaves
+    each) with GetSubtreeRoots. The sync computes the same nodes when
+    it appends the commitments to the tree, and compares them with the
+    roots of the server. It is a check of the tree at every subtree
+    boundary, in addition to the check of the root at the end of the sync.
+
+    The roots of the server are fetched when the sync completes a
+    subtree that is not in the database yet, and they are kept until
+    a rewind drops their block. A subtree inside a bridge of a pruned
+    block range is not computed and cannot be checked. A server that
+    does not implement the call is not an error: the sync then relies
+    on the root check only.
+*/
+
+/// Depth of the roots of the subtrees
+pub const SUBTREE_DEPTH: usize = 16;
+
+/// Compare the subtree roots computed by the sync with the roots
+/// of the server
+pub async fn verify_subtree_roots(
+    connection: &Connection,
+    client: &mut Client,
+    orchard: bool,
+    computed: &[(u32, Hash)],
+) -> Result<()> {
+    let pool = if orchard { "Orchard" } else { "Sapling" };
+    let mut missing = None;
+    for (index, _) in computed.iter() {
+        if get_subtree_root(connection, orchard, *index)?.is_none() {
+            missing = Some(*index);
+            break;
+        }
+    }
+    if let Some(start) = missing {
+        match get_subtree_roots(client, orchard, start, 0).await {
+            Ok(roots) => store_subtree_roots(connection, orchard, start, &roots)?,
+            Err(e) if e.is_unimplemented() => {
+                tracing::debug!("The server has no subtree roots");
+                return Ok(());
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    for (index, root) in computed.iter() {
+        match get_subtree_root(connection, orchard, *index)? {
+            Some(server_root) if server_root != *root => {
+                anyhow::bail!("{} subtree root {} does not match the server", pool, index);
+            }
+            Some(_) => tracing::debug!("{} subtree root {} verified", pool, index),
+            None => tracing::warn!("No {} subtree root {} on the server", pool, index),
+        }
+    }
+    Ok(())
+}
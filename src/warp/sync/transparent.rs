@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use anyhow::Result;
 use rusqlite::{Connection, Transaction};
@@ -9,11 +9,14 @@ use zcash_primitives::legacy::TransparentAddress;
 use crate::{
     db::{
         account::{list_transparent_addresses, TransparentDerPath},
-        notes::{list_all_utxos, mark_transparent_spent, store_utxo},
+        notes::{
+            list_all_utxos, list_unspent_script_utxos, mark_script_utxo_spent,
+            mark_transparent_spent, store_script_utxo, store_utxo, ReceivedScriptUtxo,
+        },
         tx::add_tx_value,
     },
     network::Network,
-    warp::{OutPoint, TransparentTx, UTXO},
+    warp::{script::ScriptType, OutPoint, TransparentTx, UTXO},
 };
 
 use super::{IdSpent, ReceivedTx, TxValueUpdate};
@@ -24,6 +27,9 @@ pub struct TransparentSync {
     pub utxos: Vec<UTXO>,
     pub txs: Vec<(ReceivedTx, OutPoint, u64)>,
     pub tx_updates: Vec<(TxValueUpdate, IdSpent<OutPoint>)>,
+    pub script_utxos: Vec<ReceivedScriptUtxo>,
+    pub unspent_script_utxos: Vec<OutPoint>,
+    pub script_spends: Vec<(OutPoint, u32)>,
     pub heights: HashSet<u32>,
 }
 
@@ -41,6 +47,7 @@ impl TransparentSync {
             })
             .collect::<Vec<_>>();
         let utxos = list_all_utxos(connection)?;
+        let unspent_script_utxos = list_unspent_script_utxos(connection)?;
 
         Ok(Self {
             network: network.clone(),
@@ -48,13 +55,34 @@ impl TransparentSync {
             utxos,
             txs: vec![],
             tx_updates: vec![],
+            script_utxos: vec![],
+            unspent_script_utxos,
+            script_spends: vec![],
             heights: HashSet::new(),
         })
     }
 
     pub fn process_txs(&mut self, address: &str, txs: &[TransparentTx]) -> Result<()> {
+        // accounts by the hash of the public key of their P2PKH addresses
+        let keys = self
+            .addresses
+            .iter()
+            .filter_map(|(path, ta)| match ta {
+                TransparentAddress::PublicKeyHash(pkh) => Some((*pkh, path.account)),
+                _ => None,
+            })
+            .collect::<HashMap<_, _>>();
         for tx in txs {
             for vin in tx.vins.iter() {
+                if let Some(i) = self
+                    .unspent_script_utxos
+                    .iter()
+                    .position(|outpoint| outpoint.txid == vin.txid && outpoint.vout == vin.vout)
+                {
+                    let outpoint = self.unspent_script_utxos.swap_remove(i);
+                    self.script_spends.push((outpoint, tx.height));
+                    self.heights.insert(tx.height);
+                }
                 let r = self.utxos.iter().find(|&utxo| {
                     utxo.txid == vin.txid
                         && utxo.vout == vin.vout
@@ -117,6 +145,43 @@ impl TransparentSync {
                 self.utxos.push(utxo);
                 self.heights.insert(tx.height);
             }
+            for out in tx.script_vouts.iter() {
+                let script_type = ScriptType::classify(&out.script);
+                let Some(account) = script_type
+                    .key_hashes()
+                    .iter()
+                    .find_map(|pkh| keys.get(pkh).copied())
+                else {
+                    continue;
+                };
+                let outpoint = OutPoint {
+                    txid: tx.txid,
+                    vout: out.vout,
+                };
+                // the same transaction comes for every address it has
+                if self
+                    .unspent_script_utxos
+                    .iter()
+                    .any(|o| o.txid == outpoint.txid && o.vout == outpoint.vout)
+                {
+                    continue;
+                }
+                tracing::info!(
+                    "Received {} on a {} output of account {}",
+                    out.value,
+                    script_type.name(),
+                    account
+                );
+                self.script_utxos.push(ReceivedScriptUtxo {
+                    account,
+                    height: tx.height,
+                    timestamp: tx.timestamp,
+                    txid: tx.txid,
+                    out: out.clone(),
+                });
+                self.unspent_script_utxos.push(outpoint);
+                self.heights.insert(tx.height);
+            }
         }
 
         Ok(())
@@ -130,6 +195,12 @@ impl TransparentSync {
             add_tx_value(db_tx, &tx)?;
             mark_transparent_spent(db_tx, spend)?;
         }
+        for utxo in self.script_utxos.iter() {
+            store_script_utxo(db_tx, utxo)?;
+        }
+        for (outpoint, height) in self.script_spends.iter() {
+            mark_script_utxo_spent(db_tx, outpoint, *height)?;
+        }
         Ok(())
     }
 }
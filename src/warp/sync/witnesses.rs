@@ -0,0 +1,37 @@
+use anyhow::Result;
+use rusqlite::Connection;
+
+use crate::{
+    db::witnesses::derive_witnesses, types::CheckpointHeight,
+    utils::chain::get_cached_tree_state, Client,
+};
+
+/*
+    Lazy witnesses
+
+    By default, the sync stores the witness of every unspent note
+    at every checkpoint. With `lazy_witnesses`, it only keeps the latest
+    witness of a note. The db is much smaller, and the witnesses at the
+    previous checkpoints are derived from the latest ones and the tree
+    frontiers that the sync stores at every checkpoint (see
+    `derive_witnesses`).
+
+    Before a transaction is built, `rebuild_witnesses` derives them at
+    the anchor checkpoint. No block is downloaded again. When every
+    witness is at the checkpoint, it does nothing.
+*/
+
+/// Derive the witnesses of the unspent notes at the checkpoint `height`.
+/// Returns the number of witnesses rebuilt
+pub async fn rebuild_witnesses(
+    connection: &Connection,
+    client: &mut Client,
+    height: CheckpointHeight,
+) -> Result<u32> {
+    let (s, o) = get_cached_tree_state(connection, client, height).await?;
+    let count = derive_witnesses(connection, height.into(), s.size() as u32, o.size() as u32)?;
+    if count > 0 {
+        tracing::info!("Derived {} witnesses at {}", count, u32::from(height));
+    }
+    Ok(count)
+}
@@ -0,0 +1,42 @@
+use anyhow::Result;
+
+/*
+    Zcash Shielded Assets (ZIP 226 and 227)
+
+    ZSAs are not active on any network yet. The data model already
+    has room for them so that their activation does not need a
+    migration of the wallets:
+    - `notes.asset` is the asset base of a note, NULL for ZEC,
+    - the ZEC balances, the account totals and the note selection
+    of the payments only count the notes without an asset,
+    - `get_balance` has the balances of the other assets in `assets`,
+    by asset base.
+
+    With the `zsa` feature, this module parses the proposed compact
+    format. A compact ZSA action has the same fields as an Orchard
+    action, but the compact note plaintext has the 32 byte asset base
+    after the 52 bytes of an Orchard note. The sync does not decrypt
+    ZSA notes yet.
+*/
+
+pub const COMPACT_NOTE_SIZE: usize = 52;
+pub const COMPACT_ZSA_NOTE_SIZE: usize = COMPACT_NOTE_SIZE + 32;
+
+pub type AssetBase = [u8; 32];
+
+/// True if the compact ciphertext of an action has a ZSA note
+pub fn is_zsa_action(ciphertext: &[u8]) -> bool {
+    ciphertext.len() == COMPACT_ZSA_NOTE_SIZE
+}
+
+/// Asset base of a decrypted compact note plaintext, None for ZEC
+pub fn parse_compact_asset(plaintext: &[u8]) -> Result<Option<AssetBase>> {
+    match plaintext.len() {
+        COMPACT_NOTE_SIZE => Ok(None),
+        COMPACT_ZSA_NOTE_SIZE => {
+            let asset: AssetBase = plaintext[COMPACT_NOTE_SIZE..].try_into().unwrap();
+            Ok(Some(asset))
+        }
+        n => anyhow::bail!("Invalid compact note plaintext of {} bytes", n),
+    }
+}
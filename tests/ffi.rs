@@ -0,0 +1,32 @@
+use std::{fs, path::Path};
+
+/*
+    The build generates the C header and the manifest of the FFI
+    in OUT_DIR (see build.rs). The published copies at the root of
+    the crate must be the same. Run `UPDATE_FFI=1 cargo test --test ffi`
+    to update them after a change of the exported functions.
+*/
+
+fn check_generated(name: &str) {
+    let generated = fs::read_to_string(Path::new(env!("OUT_DIR")).join(name)).unwrap();
+    let published = Path::new(env!("CARGO_MANIFEST_DIR")).join(name);
+    if std::env::var_os("UPDATE_FFI").is_some() {
+        fs::write(&published, &generated).unwrap();
+        return;
+    }
+    let current = fs::read_to_string(&published).unwrap_or_default();
+    assert!(
+        current == generated,
+        "{name} is out of date, run UPDATE_FFI=1 cargo test --test ffi"
+    );
+}
+
+#[test]
+fn header_is_up_to_date() {
+    check_generated("zcash_warp.h");
+}
+
+#[test]
+fn manifest_is_up_to_date() {
+    check_generated("zcash_warp.json");
+}